@@ -6,6 +6,7 @@ use teo_runtime::namespace::Namespace;
 use teo_runtime::traits::named::Named;
 use crate::admin::preferences_ts::ModelForPreferences;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::render_builtin;
 
 pub(self) struct NavItem {
     pub(self) path: String,
@@ -49,6 +50,6 @@ pub(crate) async fn generate_default_preferences_ts(namespace: &Namespace, langu
             fields: m.fields().values().filter(|f| !f.write().is_no_write() && !f.foreign_key()).map(|f| f.name().to_string()).collect(),
         }).collect(),
     };
-    file_util.ensure_directory_and_generate_file("src/lib/generated/defaultPreferences.ts", template.render().unwrap()).await?;
+    file_util.ensure_directory_and_generate_file("src/lib/generated/defaultPreferences.ts", render_builtin("admin/src/lib/generated/defaultPreferences.ts.jinja", &template)?).await?;
     Ok(())
 }
\ No newline at end of file
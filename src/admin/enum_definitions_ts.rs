@@ -5,6 +5,7 @@ use teo_runtime::namespace::Namespace;
 use crate::utils::file::FileUtil;
 use teo_result::Result;
 use teo_runtime::traits::named::Named;
+use crate::utils::templates::render_builtin;
 
 struct EnumDefinition {
     name_pascalcase: String,
@@ -31,6 +32,6 @@ fn fetch_template_data(namespace: &Namespace) -> EnumDefinitionsTsTemplate {
 
 pub(crate) async fn generate_enum_definitions_ts(namespace: &Namespace, file_util: &FileUtil) -> Result<()> {
     let template = fetch_template_data(namespace);
-    file_util.ensure_directory_and_generate_file("src/lib/generated/enumDefinitions.ts", template.render().unwrap()).await?;
+    file_util.ensure_directory_and_generate_file("src/lib/generated/enumDefinitions.ts", render_builtin("admin/src/lib/generated/enumDefinitions.ts.jinja", &template)?).await?;
     Ok(())
 }
\ No newline at end of file
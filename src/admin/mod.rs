@@ -23,9 +23,10 @@ use inflector::Inflector;
 use itertools::Itertools;
 use teo_runtime::config::admin::Admin;
 use teo_runtime::namespace::Namespace;
-use teo_result::Result;
+use std::path::Path;
+use teo_result::{Error, Result};
 use serde::Deserialize;
-use serde_json::json;
+use serde_json::{json, Map, Value};
 use teo_runtime::config::client::{Client, ClientLanguage, TypeScriptHTTPProvider};
 use teo_runtime::config::server::Server;
 use once_cell::sync::Lazy;
@@ -49,8 +50,9 @@ use crate::admin::translations_init_ts::generate_translations_init_ts;
 use crate::admin::translations_lang_index_ts::generate_translations_lang_index_ts;
 use crate::admin::translations_languages_ts::generate_translations_languages_ts;
 use crate::admin::webpack_config_ts::generate_webpack_config_ts;
-use crate::utils::file::FileUtil;
-use crate::utils::update_package_json_version::update_package_json_version;
+use crate::utils::file::{read_file, FileUtil};
+use crate::outline::changes::Bump;
+use crate::utils::update_package_json_version::{parse_package_json, update_package_json_version};
 
 static FILE_ADDRESS: Lazy<String> = Lazy::new(|| {
     format!("https://raw.githubusercontent.com/teodevgroup/teo-admin-dev/{}/", env!("CARGO_PKG_VERSION"))
@@ -153,10 +155,11 @@ pub async fn generate(main_namespace: &Namespace, admin: &Admin, server: &Server
 
     // package.json
     let remote_json_string = fetch_remote_content("package.json").await?;
-    let remote_json_data: serde_json::Value = serde_json::from_str(&remote_json_string).unwrap();
-    let dependencies = remote_json_data.get("dependencies").unwrap();
-    let mut dev_dependencies = remote_json_data.get("devDependencies").unwrap().clone();
-    dev_dependencies.as_object_mut().unwrap().shift_remove("glob").unwrap();
+    let remote_json_path = Path::new(FILE_ADDRESS.as_str()).join("package.json");
+    let remote_json_data = parse_package_json(&remote_json_string, &remote_json_path)?;
+    let dependencies = dependencies_object(&remote_json_data, "dependencies", &remote_json_path)?;
+    let mut dev_dependencies = dependencies_object(&remote_json_data, "devDependencies", &remote_json_path)?;
+    dev_dependencies.shift_remove("glob");
     let new_json_data = json!({
         "name": "admin-dashboard",
         "version": "0.0.1",
@@ -170,9 +173,8 @@ pub async fn generate(main_namespace: &Namespace, admin: &Admin, server: &Server
     });
     if file_util.generate_file_if_not_exist("package.json", serde_json::to_string(&new_json_data).unwrap()).await? {
         // if exists, update package.json with a minor version and deps
-        let json_data = std::fs::read_to_string(file_util.get_file_path("package.json"))
-            .expect("Unable to read package.json");
-        file_util.generate_file("package.json", update_json_version_and_deps(json_data, dependencies, &dev_dependencies)).await?;
+        let path = file_util.get_file_path("package.json");
+        file_util.generate_file("package.json", update_json_version_and_deps(read_file(&path)?, &dependencies, &dev_dependencies, &path)?).await?;
     }
     // generate TypeScript client
     crate::client::generate(main_namespace, &Client {
@@ -186,26 +188,28 @@ pub async fn generate(main_namespace: &Namespace, admin: &Admin, server: &Server
     Ok(())
 }
 
-fn update_json_version_and_deps(json_data: String, dependencies: &serde_json::Value, dev_dependencies: &serde_json::Value) -> String {
-    let version_updated_json_data = update_package_json_version(json_data);
-    let mut json_value: serde_json::Value = serde_json::from_str(&version_updated_json_data).unwrap();
-    let deps = json_value.get_mut("dependencies").unwrap();
-    let deps_object = deps.as_object_mut().unwrap();
-    let source_deps = dependencies.as_object().unwrap();
-    for (k, v) in source_deps {
-        if deps_object.get(k).is_none() {
-            deps_object.insert(k.to_owned(), v.clone());
-        }
+/// The `key` dependencies of a package.json, or none when it has no such key.
+fn dependencies_object(json_value: &Map<String, Value>, key: &str, path: &Path) -> Result<Map<String, Value>> {
+    match json_value.get(key) {
+        Some(Value::Object(dependencies)) => Ok(dependencies.clone()),
+        Some(_) => Err(Error::new(format!("`{}' of `{}' isn't an object", key, path.display()))),
+        None => Ok(Map::new()),
     }
-    let dev_deps = json_value.get_mut("devDependencies").unwrap();
-    let dev_deps_object = dev_deps.as_object_mut().unwrap();
-    let source_dev_deps = dev_dependencies.as_object().unwrap();
-    for (k, v) in source_dev_deps {
-        if dev_deps_object.get(k).is_none() {
-            dev_deps_object.insert(k.to_owned(), v.clone());
+}
+
+fn update_json_version_and_deps(json_data: String, dependencies: &Map<String, Value>, dev_dependencies: &Map<String, Value>, path: &Path) -> Result<String> {
+    let version_updated_json_data = update_package_json_version(json_data, Bump::Patch, path)?;
+    let mut json_value = parse_package_json(&version_updated_json_data, path)?;
+    for (key, source) in [("dependencies", dependencies), ("devDependencies", dev_dependencies)] {
+        let mut deps_object = dependencies_object(&json_value, key, path)?;
+        for (k, v) in source {
+            if deps_object.get(k).is_none() {
+                deps_object.insert(k.to_owned(), v.clone());
+            }
         }
+        json_value.insert(key.to_owned(), Value::Object(deps_object));
     }
-    serde_json::to_string(&json_value).unwrap()
+    Ok(serde_json::to_string(&json_value).unwrap())
 }
 
 async fn fetch_remote_content(location: &str) -> Result<String> {
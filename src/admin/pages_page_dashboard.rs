@@ -2,6 +2,7 @@ use askama::Template;
 use teo_runtime::model::Model;
 use teo_runtime::namespace::Namespace;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::render_builtin;
 
 #[derive(Template)]
 #[template(path = "admin/src/components/generated/pages/page/Dashboard.tsx.jinja", escape = "none")]
@@ -15,7 +16,7 @@ pub(crate) async fn generate_pages_page_dashboard_tsx(_namespace: &Namespace, _m
     };
     file_util.ensure_directory_and_generate_file(
         &format!("src/components/generated/pages/{path}/Dashboard.tsx"),
-        template.render().unwrap()
+        render_builtin("admin/src/components/generated/pages/page/Dashboard.tsx.jinja", &template)?
     ).await?;
     Ok(())
 }
\ No newline at end of file
@@ -7,6 +7,7 @@ use teo_runtime::model::Model;
 use teo_runtime::namespace::Namespace;
 use teo_runtime::traits::named::Named;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::render_builtin;
 
 struct PageFormField {
     display_name: String,
@@ -147,7 +148,7 @@ pub(crate) async fn generate_pages_page_form_tsx(_namespace: &Namespace, model:
     };
     file_util.ensure_directory_and_generate_file(
         &format!("src/components/generated/pages/{path}/Form.tsx"),
-        template.render().unwrap()
+        render_builtin("admin/src/components/generated/pages/page/Form.tsx.jinja", &template)?
     ).await?;
     Ok(())
 }
\ No newline at end of file
@@ -5,7 +5,9 @@ use teo_runtime::model::field::typed::Typed;
 use teo_runtime::model::Model;
 use teo_runtime::namespace::Namespace;
 use teo_runtime::traits::named::Named;
+use teo_result::Error;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::render_builtin;
 
 pub(self) struct RecordsListField {
     title_in_header: String, // Id, Email in i18n form
@@ -61,11 +63,11 @@ pub(crate) async fn generate_pages_page_records_list_tsx(_namespace: &Namespace,
             }
             result
         },
-        primary_fields: model.primary_index().unwrap().items().iter().map(|i| format!("\"{}\"", i.field)).join(", ")
+        primary_fields: model.primary_index().ok_or_else(|| Error::new(format!("model `{}' has no primary index, mark a field with `@id' or the model with `@@id'", model.path().join("."))))?.items().iter().map(|i| format!("\"{}\"", i.field)).join(", ")
     };
     file_util.ensure_directory_and_generate_file(
         &format!("src/components/generated/pages/{path}/RecordsList.tsx"),
-        template.render().unwrap()
+        render_builtin("admin/src/components/generated/pages/page/RecordsList.tsx.jinja", &template)?
     ).await?;
     Ok(())
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ use askama::Template;
 use itertools::Itertools;
 use teo_runtime::namespace::Namespace;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::render_builtin;
 
 #[derive(Template)]
 #[template(path = "admin/src/components/generated/pages/PageStackDefaultItemKeys.tsx.jinja", escape = "none")]
@@ -16,6 +17,6 @@ pub(crate) async fn generate_pages_stack_default_item_keys_tsx(namespace: &Names
             format!("\"{}\" | \"{}Form\"", base, base)
         }).join(" | ")
     };
-    file_util.ensure_directory_and_generate_file("src/components/generated/pages/PageStackDefaultItemKeys.tsx", template.render().unwrap()).await?;
+    file_util.ensure_directory_and_generate_file("src/components/generated/pages/PageStackDefaultItemKeys.tsx", render_builtin("admin/src/components/generated/pages/PageStackDefaultItemKeys.tsx.jinja", &template)?).await?;
     Ok(())
 }
\ No newline at end of file
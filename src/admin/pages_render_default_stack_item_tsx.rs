@@ -3,6 +3,7 @@ use inflector::Inflector;
 use itertools::Itertools;
 use teo_runtime::namespace::Namespace;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::render_builtin;
 
 struct StackItemModel {
     class_name: String,
@@ -24,6 +25,6 @@ pub(crate) async fn generate_pages_render_default_stack_item_tsx(namespace: &Nam
             }
         }).collect()
     };
-    file_util.ensure_directory_and_generate_file("src/components/generated/pages/renderDefaultStackItem.tsx", template.render().unwrap()).await?;
+    file_util.ensure_directory_and_generate_file("src/components/generated/pages/renderDefaultStackItem.tsx", render_builtin("admin/src/components/generated/pages/renderDefaultStackItem.tsx.jinja", &template)?).await?;
     Ok(())
 }
\ No newline at end of file
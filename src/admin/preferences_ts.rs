@@ -5,6 +5,7 @@ use teo_runtime::namespace::Namespace;
 use crate::utils::file::FileUtil;
 use teo_result::Result;
 use teo_runtime::traits::named::Named;
+use crate::utils::templates::render_builtin;
 
 pub(super) struct ModelForPreferences {
     pub(super) key_name: String,
@@ -44,6 +45,6 @@ fn fetch_template_data(namespace: &Namespace) -> PreferencesTsTemplate {
 
 pub(crate) async fn generate_preferences_ts(namespace: &Namespace, file_util: &FileUtil) -> Result<()> {
     let template = fetch_template_data(namespace);
-    file_util.ensure_directory_and_generate_file("src/lib/generated/preferences.ts", template.render().unwrap()).await?;
+    file_util.ensure_directory_and_generate_file("src/lib/generated/preferences.ts", render_builtin("admin/src/lib/generated/preferences.ts.jinja", &template)?).await?;
     Ok(())
 }
\ No newline at end of file
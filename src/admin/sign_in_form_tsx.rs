@@ -6,6 +6,7 @@ use teo_result::Result;
 use teo_runtime::traits::named::Named;
 use crate::admin::preferences_ts::AccountModel;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::render_builtin;
 
 #[derive(Template)]
 #[template(path = "admin/src/components/generated/signInModal/SignInForm.tsx.jinja", escape = "none")]
@@ -33,6 +34,6 @@ fn fetch_template_data(namespace: &Namespace) -> SignInFormTsxTemplate {
 
 pub(crate) async fn generate_sign_in_form_tsx(namespace: &Namespace, file_util: &FileUtil) -> Result<()> {
     let template = fetch_template_data(namespace);
-    file_util.ensure_directory_and_generate_file("src/components/generated/signInModal/SignInForm.tsx", template.render().unwrap()).await?;
+    file_util.ensure_directory_and_generate_file("src/components/generated/signInModal/SignInForm.tsx", render_builtin("admin/src/components/generated/signInModal/SignInForm.tsx.jinja", &template)?).await?;
     Ok(())
 }
\ No newline at end of file
@@ -4,6 +4,7 @@ use itertools::Itertools;
 use teo_runtime::namespace::Namespace;
 use teo_result::Result;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::render_builtin;
 
 struct AccountItem {
     name: String,
@@ -51,6 +52,6 @@ fn fetch_template_data(namespace: &Namespace) -> SignInIndexTsTemplate {
 
 pub(crate) async fn generate_sign_in_index_ts(namespace: &Namespace, file_util: &FileUtil) -> Result<()> {
     let template = fetch_template_data(namespace);
-    file_util.ensure_directory_and_generate_file("src/lib/generated/signIn/index.ts", template.render().unwrap()).await?;
+    file_util.ensure_directory_and_generate_file("src/lib/generated/signIn/index.ts", render_builtin("admin/src/lib/generated/signIn/index.ts.jinja", &template)?).await?;
     Ok(())
 }
\ No newline at end of file
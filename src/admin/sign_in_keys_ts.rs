@@ -4,6 +4,7 @@ use itertools::Itertools;
 use teo_runtime::namespace::Namespace;
 use teo_runtime::traits::named::Named;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::render_builtin;
 
 struct AccountModelField {
     name: String
@@ -45,6 +46,6 @@ fn fetch_template_data(namespace: &Namespace) -> SignInKeysTsTemplate {
 
 pub(crate) async fn generate_sign_in_keys_ts(namespace: &Namespace, file_util: &FileUtil) -> teo_result::Result<()> {
     let template = fetch_template_data(namespace);
-    file_util.ensure_directory_and_generate_file("src/lib/generated/signIn/keys.ts", template.render().unwrap()).await?;
+    file_util.ensure_directory_and_generate_file("src/lib/generated/signIn/keys.ts", render_builtin("admin/src/lib/generated/signIn/keys.ts.jinja", &template)?).await?;
     Ok(())
 }
\ No newline at end of file
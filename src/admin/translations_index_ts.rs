@@ -8,6 +8,7 @@ use teo_runtime::traits::named::Named;
 use crate::utils::file::FileUtil;
 use quoted_string::quote;
 use quoted_string::test_utils::{TestSpec as Spec};
+use crate::utils::templates::render_builtin;
 
 pub(super) struct TranslationEntry {
     pub(super) key: String,
@@ -94,8 +95,8 @@ pub(self) struct TranslationsIndexTsTemplate {
 }
 
 pub(crate) async fn generate_translations_index_ts(namespace: &Namespace, file_util: &FileUtil) -> Result<()> {
-    file_util.ensure_directory_and_generate_file("src/lib/generated/translations/index.ts", TranslationsIndexTsTemplate {
+    file_util.ensure_directory_and_generate_file("src/lib/generated/translations/index.ts", render_builtin("admin/src/lib/generated/translations/index.ts.jinja", &TranslationsIndexTsTemplate {
         entries: fetch_translation_entries(namespace, "enUs")
-    }.render().unwrap()).await?;
+    })?).await?;
     Ok(())
 }
\ No newline at end of file
@@ -3,6 +3,7 @@ use crate::utils::file::FileUtil;
 use askama::Template;
 use teo_result::Result;
 use teo_runtime::admin::language::Language;
+use crate::utils::templates::render_builtin;
 
 #[derive(Template)]
 #[template(path = "admin/src/lib/generated/translations/init.ts.jinja", escape = "none")]
@@ -11,8 +12,8 @@ pub(self) struct TranslationsInitTsTemplate {
 }
 
 pub(crate) async fn generate_translations_init_ts(languages: &Vec<Language>, file_util: &FileUtil) -> Result<()> {
-    file_util.ensure_directory_and_generate_file("src/lib/generated/translations/init.ts", TranslationsInitTsTemplate {
+    file_util.ensure_directory_and_generate_file("src/lib/generated/translations/init.ts", render_builtin("admin/src/lib/generated/translations/init.ts.jinja", &TranslationsInitTsTemplate {
         languages: languages.clone()
-    }.render().unwrap()).await?;
+    })?).await?;
     Ok(())
 }
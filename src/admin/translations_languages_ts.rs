@@ -3,6 +3,7 @@ use askama::Template;
 use itertools::Itertools;
 use teo_result::Result;
 use teo_runtime::admin::language::Language;
+use crate::utils::templates::render_builtin;
 
 struct LanguageItem {
     name: &'static str,
@@ -17,12 +18,12 @@ pub(self) struct TranslationsLanguageTsTemplate {
 }
 
 pub(crate) async fn generate_translations_languages_ts(languages: &Vec<Language>, file_util: &FileUtil) -> Result<()> {
-    file_util.ensure_directory_and_generate_file("src/lib/generated/translations/languages.ts", TranslationsLanguageTsTemplate {
+    file_util.ensure_directory_and_generate_file("src/lib/generated/translations/languages.ts", render_builtin("admin/src/lib/generated/translations/languages.ts.jinja", &TranslationsLanguageTsTemplate {
         languages: languages.iter().map(|l| LanguageItem {
             name: l.as_str(),
             display: l.display(),
         }).collect(),
         joined_languages: languages.iter().map(|l| format!("\"{}\"", l.as_str())).join(" | ")
-    }.render().unwrap()).await?;
+    })?).await?;
     Ok(())
 }
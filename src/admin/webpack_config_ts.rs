@@ -1,6 +1,7 @@
 use askama::Template;
 use crate::utils::file::FileUtil;
 use teo_result::Result;
+use crate::utils::templates::render_builtin;
 
 #[derive(Template)]
 #[template(path = "admin/webpack.config.ts.jinja", escape = "none")]
@@ -12,6 +13,6 @@ pub(crate) async fn generate_webpack_config_ts(port: String, file_util: &FileUti
     let template = WebpackConfigTsTemplate {
         port
     };
-    file_util.generate_file_if_not_exist("webpack.config.ts", template.render().unwrap()).await?;
+    file_util.generate_file_if_not_exist("webpack.config.ts", render_builtin("admin/webpack.config.ts.jinja", &template)?).await?;
     Ok(())
 }
\ No newline at end of file
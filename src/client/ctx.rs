@@ -1,16 +1,55 @@
+use teo_result::Result;
 use teo_runtime::config::client::Client;
 use teo_runtime::namespace::Namespace;
+use crate::client::options::ClientOptions;
+use crate::naming::Naming;
+use crate::outline::changes::ApiChanges;
+use crate::utils::templates::TemplateOverrides;
 
-pub(in crate::client) struct Ctx<'a> {
+pub struct Ctx<'a> {
     pub(in crate::client) conf: &'a Client,
     pub(in crate::client) main_namespace: &'a Namespace,
+    pub(in crate::client) options: &'a ClientOptions,
+    pub(in crate::client) templates: TemplateOverrides,
+    pub(in crate::client) changes: ApiChanges,
+    pub(in crate::client) naming: Naming,
 }
 
 impl<'a> Ctx<'a> {
 
-    pub(in crate::client) fn new(conf: &'a Client, main_namespace: &'a Namespace) -> Self {
-        Self {
-            conf, main_namespace,
-        }
+    pub(in crate::client) fn new(conf: &'a Client, main_namespace: &'a Namespace, options: &'a ClientOptions, changes: ApiChanges, naming: Naming) -> Result<Self> {
+        Ok(Self {
+            conf, main_namespace, options,
+            templates: TemplateOverrides::for_client(options.templates.as_deref(), conf, main_namespace)?,
+            changes,
+            naming,
+        })
+    }
+
+    pub fn conf(&self) -> &'a Client {
+        self.conf
+    }
+
+    pub fn main_namespace(&self) -> &'a Namespace {
+        self.main_namespace
+    }
+
+    pub fn options(&self) -> &'a ClientOptions {
+        self.options
+    }
+
+    /// The API changes since the package was last generated, empty when
+    /// the client isn't generated as a package.
+    pub fn changes(&self) -> &ApiChanges {
+        &self.changes
+    }
+
+    pub fn naming(&self) -> &Naming {
+        &self.naming
+    }
+
+    /// The template overrides of the configured template directory.
+    pub fn templates(&self) -> &TemplateOverrides {
+        &self.templates
     }
 }
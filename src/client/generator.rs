@@ -1,12 +1,24 @@
 use async_trait::async_trait;
 use teo_runtime::config::client::Client;
+use teo_runtime::namespace::Namespace;
 use teo_result::Result;
 use crate::client::ctx::Ctx;
+use crate::naming::Naming;
 use crate::utils::file::FileUtil;
 
+/// # Client generator
+///
+/// Implemented by every client target, built-in or registered by another
+/// crate with [`register_generator`](crate::client::register_generator).
+///
+/// When the client is generated as a package, `generate_package_files` runs
+/// against the destination and the module is written into
+/// `module_directory_in_package`. Otherwise `update_parent_package_files`
+/// runs against the destination and the module is written there directly.
 #[async_trait]
-pub(in crate::client) trait Generator {
+pub trait Generator: Send + Sync {
 
+    /// The directory inside the package the module files are written into.
     fn module_directory_in_package(&self, conf: &Client) -> String;
 
     async fn generate_module_files(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()>;
@@ -16,4 +28,29 @@ pub(in crate::client) trait Generator {
     async fn update_parent_package_files(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()>;
 
     async fn generate_main(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()>;
+
+    /// Run the package's tools over the generated module, like code
+    /// generators. This runs after stale files are removed, so the files the
+    /// tools write aren't taken for stale ones.
+    async fn run_commands(&self, _ctx: &Ctx, _generator: &FileUtil) -> Result<()> {
+        Ok(())
+    }
+
+    /// The version of the package in `generator`, for its changelog.
+    fn package_version(&self, _generator: &FileUtil) -> Option<String> {
+        None
+    }
+
+    /// The naming conventions of the client, unless
+    /// [`ClientOptions::naming`](crate::client::ClientOptions::naming) has
+    /// others for it.
+    fn naming(&self) -> Naming {
+        Naming::default()
+    }
+
+    /// Reject a naming the client can't map back to the schema's names,
+    /// before anything is written.
+    fn check_naming(&self, _naming: &Naming, _main_namespace: &Namespace) -> Result<()> {
+        Ok(())
+    }
 }
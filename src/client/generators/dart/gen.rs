@@ -2,15 +2,19 @@ use async_trait::async_trait;
 use teo_runtime::config::client::Client;
 use crate::client::ctx::Ctx;
 use crate::client::generator::Generator;
-use std::process::Command;
+use tokio::process::Command;
 use askama::Template;
+use crate::outline::interface::Field;
 use crate::outline::outline::{Mode, Outline};
+use crate::naming::{Case, Naming};
 use crate::utils::exts::ClientExt;
-use crate::utils::file::FileUtil;
+use crate::utils::file::{read_file, FileUtil};
+use crate::utils::templates::TemplateOverrides;
 use crate::utils::message::green_message;
 use crate::utils::filters;
+use crate::utils::filters::Language;
 use async_recursion::async_recursion;
-use teo_result::Result;
+use teo_result::{Error, Result};
 use regex::Regex;
 use teo_runtime::namespace::Namespace;
 use tokio::fs;
@@ -25,6 +29,10 @@ use teo_parser::r#type::Type;
 use crate::client::generators::dart::lookup;
 use crate::client::generators::dart::pubspec::updated_pubspec_yaml_for_existing_project;
 use crate::utils::lookup::Lookup;
+use crate::outline::changes::Bump;
+use crate::utils::version::bump_version;
+use crate::utils::tuples::{tuple_class_indices, tuple_class_name};
+use crate::utils::unions::{collect_unions, union_is_sum_type, union_members_without_null, UnionDefinition};
 
 fn import_dots(namespace: &Namespace) -> String {
     if namespace.path().len() <= 1 {
@@ -35,7 +43,22 @@ fn import_dots(namespace: &Namespace) -> String {
 }
 
 fn should_escape(name: &str) -> bool {
-    name.starts_with("_") || ["is", "in", "AND", "OR", "NOT"].contains(&name)
+    Language::Dart.escape(name) != name
+}
+
+/// The arguments of the `@JsonKey` annotation of `field`, empty when it
+/// doesn't need one. Output dates and decimals are parsed from Teo values.
+fn json_key_arguments(field: &Field, output: bool) -> String {
+    let mut arguments = vec![];
+    if field.is_renamed() || should_escape(field.identifier()) {
+        arguments.push(format!("name: '{}'", field.name()));
+    }
+    if output && field.r#type().is_datetime() {
+        arguments.push("fromJson: fromTeoDateTime".to_owned());
+    } else if output && field.r#type().is_decimal() {
+        arguments.push("fromJson: fromTeoDecimal".to_owned());
+    }
+    arguments.join(", ")
 }
 
 fn type_is_not_dynamic(t: &str) -> bool {
@@ -75,6 +98,16 @@ fn from_json_from_type(t: &Type) -> String {
         Type::String | Type::ObjectId => "(p0) => p0 as dynamic".to_owned(),
         Type::Null => "(p0) => null".to_owned(),
         Type::Array(inner) => format!("(p0) => (p0 as List).map({}).toList()", from_json_from_type(inner.as_ref())),
+        Type::EnumVariant(_) => format!("(p0) => {}.fromTeoValue(p0 as String)!", lookup(t).unwrap()),
+        Type::Tuple(types) => format!("(p0) => {}.fromJson(p0 as dynamic{})", tuple_class_name(types.len()).unwrap(), types.iter().map(|t| format!(", {}", from_json_from_type(t))).join("")),
+        Type::Range(inner) => format!("(p0) => TeoRange.fromJson(p0 as dynamic, {})", from_json_from_type(inner.as_ref())),
+        Type::Union(types) => if union_is_sum_type(types) {
+            format!("(p0) => p0 == null ? null : {}.fromJson(p0 as dynamic)", lookup(t).unwrap().trim_end_matches("?"))
+        } else if let Some(member) = union_members_without_null(types).0.first() {
+            from_json_from_type(member)
+        } else {
+            "(p0) => p0 as dynamic".to_owned()
+        },
         _ => {
             let args = t.generic_types().iter().map(|gt| format!(", {}", from_json_from_type(gt))).join("");
             let this_str = lookup(t).unwrap();
@@ -84,15 +117,45 @@ fn from_json_from_type(t: &Type) -> String {
     }
 }
 
-fn append_question(original: String, output: bool) -> String {
-    if output {
-        if !type_is_dynamic(original.as_str()) && !original.ends_with("?") {
-            original + "?"
-        } else {
-            original
+fn union_member_check(t: &Type) -> String {
+    match t {
+        Type::Date => r#"json is Map && json.containsKey("\$date")"#.to_owned(),
+        Type::DateTime => r#"json is Map && json.containsKey("\$datetime")"#.to_owned(),
+        Type::Decimal => r#"json is Map && json.containsKey("\$decimal")"#.to_owned(),
+        Type::Bool => "json is bool".to_owned(),
+        Type::Int | Type::Int64 => "json is int".to_owned(),
+        Type::Float | Type::Float32 => "json is num".to_owned(),
+        Type::EnumVariant(_) => format!("json is String && {}.fromTeoValue(json) != null", lookup(t).unwrap()),
+        Type::String | Type::ObjectId => "json is String".to_owned(),
+        Type::Array(_) | Type::Enumerable(_) | Type::Tuple(_) => "json is List".to_owned(),
+        Type::Range(_) => r#"json is Map && json.containsKey("start") && json.containsKey("end")"#.to_owned(),
+        Type::ModelObject(_) | Type::InterfaceObject(_, _) | Type::SynthesizedShapeReference(_) | Type::DeclaredSynthesizedShape(_, _) => "json is Map<String, dynamic>".to_owned(),
+        Type::Dictionary(_) => "json is Map".to_owned(),
+        _ => "true".to_owned(),
+    }
+}
+
+fn namespace_unions(namespace: &Namespace, outline: &Outline, client: &Client) -> Result<Vec<UnionDefinition>> {
+    let mut unions = vec![];
+    for interface in outline.interfaces() {
+        for field in interface.fields() {
+            collect_unions(&fix_path(field.r#type(), namespace, client)?, &mut unions);
         }
+    }
+    for delegate in outline.delegates() {
+        for request_item in delegate.request_items() {
+            collect_unions(&fix_path(request_item.input_type(), namespace, client)?, &mut unions);
+            collect_unions(&fix_path(request_item.output_type(), namespace, client)?, &mut unions);
+        }
+    }
+    Ok(unions)
+}
+
+fn append_question(original: &str, output: bool) -> String {
+    if output && !type_is_dynamic(original) && !original.ends_with("?") {
+        original.to_owned() + "?"
     } else {
-        original
+        original.to_owned()
     }
 }
 
@@ -160,6 +223,14 @@ fn figure_out_imports_from_type(t: &Type, this_path: &Vec<String>, exist_check_s
                 figure_out_imports_from_type(ty, this_path, exist_check_set, result, client);
             }
         }
+        Type::Union(types) | Type::Tuple(types) => {
+            for ty in types {
+                figure_out_imports_from_type(ty, this_path, exist_check_set, result, client);
+            }
+        }
+        Type::Range(inner) => {
+            figure_out_imports_from_type(inner.as_ref(), this_path, exist_check_set, result, client);
+        }
         Type::SynthesizedShapeReference(s) => figure_out_imports_from_type(s.owner.as_ref(), this_path, exist_check_set, result, client),
         Type::SynthesizedEnumReference(e) => figure_out_imports_from_type(e.owner.as_ref(), this_path, exist_check_set, result, client),
         _ => ()
@@ -197,12 +268,14 @@ pub(self) struct DartReadMeTemplate<'a> {
 #[template(path = "client/dart/pubspec.yaml.jinja", escape = "none")]
 pub(self) struct DartPubspecTemplate<'a> {
     pub(self) conf: &'a Client,
+    pub(self) immutable: bool,
 }
 
 #[derive(Template)]
 #[template(path = "client/dart/helper.dart.jinja", escape = "none")]
 pub(self) struct DartHelperTemplate<'a> {
     pub(self) conf: &'a Client,
+    pub(self) tuple_classes: Vec<Vec<usize>>,
 }
 
 #[derive(Template)]
@@ -212,18 +285,22 @@ pub(self) struct DartMainTemplate<'a> {
     pub(self) outline: &'a Outline,
     pub(self) conf: &'a Client,
     pub(self) should_escape: &'static dyn Fn(&str) -> bool,
+    pub(self) json_key_arguments: &'static dyn Fn(&Field, bool) -> String,
     pub(self) type_is_not_dynamic: &'static dyn Fn(&str) -> bool,
     pub(self) type_is_dynamic: &'static dyn Fn(&str) -> bool,
     pub(self) import_dots: &'static dyn Fn(&Namespace) -> String,
-    pub(self) append_question: &'static dyn Fn(String, bool) -> String,
+    pub(self) append_question: &'static dyn Fn(&str, bool) -> String,
     pub(self) from_json_parameters: &'static dyn Fn(&Vec<String>) -> String,
     pub(self) from_json_arguments: &'static dyn Fn(&Vec<String>) -> String,
     pub(self) to_json_parameters: &'static dyn Fn(&Vec<String>) -> String,
     pub(self) to_json_arguments: &'static dyn Fn(&Vec<String>) -> String,
     pub(self) from_json_from_type: &'static dyn Fn(&Type) -> String,
     pub(self) namespace_imports: &'static dyn Fn(&Namespace, &Outline, &Client) -> String,
-    pub(self) fix_path: &'static dyn Fn(&Type, &Namespace, &Client) -> Type,
+    pub(self) fix_path: &'static dyn Fn(&Type, &Namespace, &Client) -> Result<Type>,
     pub(self) lookup: &'static dyn Lookup,
+    pub(self) immutable: bool,
+    pub(self) unions: Vec<UnionDefinition>,
+    pub(self) union_member_check: &'static dyn Fn(&Type) -> String,
 }
 
 unsafe impl Send for DartMainTemplate<'_> { }
@@ -238,18 +315,21 @@ impl DartGenerator {
     }
 
     #[async_recursion]
-    async fn generate_module_for_namespace(&self, namespace: &Namespace, generator: &FileUtil, main_namespace: &Namespace, conf: &Client) -> Result<()> {
-        let outline = Outline::new(namespace, Mode::Client, main_namespace, false);
+    async fn generate_module_for_namespace(&self, namespace: &Namespace, generator: &FileUtil, main_namespace: &Namespace, conf: &Client, immutable: bool, templates: &TemplateOverrides, naming: &Naming) -> Result<()> {
+        let outline = Outline::new(namespace, Mode::Client, main_namespace, false, naming)?;
+        outline.try_each_type(|t| lookup(&fix_path(t, namespace, conf)?).map(|_| ()))?;
+        let unions = namespace_unions(namespace, &outline, conf)?;
         generator.generate_file(if namespace.path().is_empty() {
             format!("{}.dart", conf.inferred_package_name_snake_case())
         } else {
             generator.ensure_directory(namespace.path().iter().rev().skip(1).rev().join("/")).await?;
             format!("{}.dart", namespace.path().join("/"))
-        }, DartMainTemplate {
+        }, templates.render_for_namespace("client/dart/namespace.dart.jinja", &DartMainTemplate {
             namespace,
             outline: &outline,
             conf,
             should_escape: &should_escape,
+            json_key_arguments: &json_key_arguments,
             type_is_not_dynamic: &type_is_not_dynamic,
             type_is_dynamic: &type_is_dynamic,
             import_dots: &import_dots,
@@ -262,15 +342,18 @@ impl DartGenerator {
             namespace_imports: &namespace_imports,
             fix_path: &fix_path,
             lookup: &lookup,
-        }.render().unwrap()).await?;
+            immutable,
+            unions,
+            union_member_check: &union_member_check,
+        }, namespace)?).await?;
         for child in namespace.namespaces().values() {
-            self.generate_module_for_namespace(child, generator, main_namespace, conf).await?;
+            self.generate_module_for_namespace(child, generator, main_namespace, conf, immutable, templates, naming).await?;
         }
         Ok(())
     }
 
-    async fn generate_helper(&self, generator: &FileUtil, conf: &Client) -> Result<()> {
-        generator.generate_file("_helper.dart", DartHelperTemplate { conf }.render().unwrap()).await?;
+    async fn generate_helper(&self, generator: &FileUtil, conf: &Client, templates: &TemplateOverrides) -> Result<()> {
+        generator.generate_file("_helper.dart", templates.render("client/dart/helper.dart.jinja", &DartHelperTemplate { conf, tuple_classes: tuple_class_indices() })?).await?;
         Ok(())
     }
 }
@@ -282,6 +365,11 @@ impl Generator for DartGenerator {
         "lib".to_owned()
     }
 
+    fn naming(&self) -> Naming {
+        // reserved words like `is` and `in` are escaped, not renamed
+        Naming { enum_members: Case::Camel, ..Naming::default() }
+    }
+
     async fn generate_module_files(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
         generator.clear_root_directory().await?;
         Ok(())
@@ -289,20 +377,19 @@ impl Generator for DartGenerator {
 
     async fn generate_package_files(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
         generator.ensure_root_directory().await?;
-        generator.generate_file(".gitignore", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/client/dart/gitignore"))).await?;
-        generator.generate_file("README.md", DartReadMeTemplate { conf: ctx.conf }.render().unwrap()).await?;
-        if generator.generate_file_if_not_exist("pubspec.yaml", DartPubspecTemplate { conf: ctx.conf }.render().unwrap()).await? {
-            // if exists, update pubspec.yaml with a minor version
-            let yaml_data = std::fs::read_to_string(generator.get_file_path("pubspec.yaml"))
-                .expect("Unable to read pubspec.yaml");
-            generator.generate_file("pubspec.yaml", update_pubspec_yaml_version(yaml_data)).await?;
+        generator.generate_file(".gitignore", ctx.templates.render_static("client/dart/gitignore", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/client/dart/gitignore")))?).await?;
+        generator.generate_file("README.md", ctx.templates.render("client/dart/readme.md.jinja", &DartReadMeTemplate { conf: ctx.conf })?).await?;
+        if generator.generate_file_if_not_exist("pubspec.yaml", ctx.templates.render("client/dart/pubspec.yaml.jinja", &DartPubspecTemplate { conf: ctx.conf, immutable: ctx.options.dart.immutable })?).await? {
+            // if exists, bump the version by the API changes
+            let yaml_data = read_file(&generator.get_file_path("pubspec.yaml"))?;
+            generator.generate_file("pubspec.yaml", update_pubspec_yaml_version(yaml_data, ctx.changes.bump())).await?;
         }
         Ok(())
     }
 
     async fn update_parent_package_files(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
         if let Some(pubspec_yaml) = generator.find_file_upwards("pubspec.yaml") {
-            let yaml_data = std::fs::read_to_string(&pubspec_yaml).expect("Unable to read pubspec.yaml");
+            let yaml_data = read_file(&pubspec_yaml)?;
             let updated_json_data = updated_pubspec_yaml_for_existing_project(yaml_data);
             fs::write(pubspec_yaml, updated_json_data).await?;
         }
@@ -311,60 +398,51 @@ impl Generator for DartGenerator {
 
     async fn generate_main(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()> {
         // module files
-        self.generate_module_for_namespace(ctx.main_namespace, generator, ctx.main_namespace, ctx.conf).await?;
-        self.generate_helper(generator, ctx.conf).await?;
-        // run commands
+        self.generate_module_for_namespace(ctx.main_namespace, generator, ctx.main_namespace, ctx.conf, ctx.options.dart.immutable, &ctx.templates, ctx.naming()).await?;
+        self.generate_helper(generator, ctx.conf, &ctx.templates).await?;
+        Ok(())
+    }
+
+    async fn run_commands(&self, _ctx: &Ctx, generator: &FileUtil) -> Result<()> {
         //println!("debug error?: see base dir: {:?}", generator.get_base_dir());
         if let Some(pubspec_yaml) = generator.find_file_upwards("pubspec.yaml") {
             //println!("debug error?: see pubspec yaml dir: {:?}", pubspec_yaml);
             let project_root = pubspec_yaml.parent().unwrap();
-            std::env::set_current_dir(project_root).unwrap();
             green_message("run", "`dart pub get`".to_owned());
-            Command::new("dart").arg("pub").arg("get").spawn()?.wait()?;
+            Command::new("dart").arg("pub").arg("get").current_dir(project_root).status().await?;
             green_message("run", "`dart run build_runner build --delete-conflicting-outputs`".to_owned());
-            Command::new("dart").arg("run").arg("build_runner").arg("build").arg("--delete-conflicting-outputs").spawn()?.wait()?;
+            Command::new("dart").arg("run").arg("build_runner").arg("build").arg("--delete-conflicting-outputs").current_dir(project_root).status().await?;
         }
         Ok(())
     }
-}
 
-fn update_pubspec_yaml_version(mut content: String) -> String {
-    let regex = Regex::new("version\\s*:\\s*([0-9\\.\\+]+)").unwrap();
-    if let Some(captures) = regex.captures(content.as_str()) {
-        if let Some(capture) = captures.get(1) {
-            let current_version = capture.as_str();
-            content.replace_range(capture.range(), next_minor_version(current_version).as_str());
-            content
-        } else {
-            content
-        }
-    } else {
-        content
+    fn package_version(&self, generator: &FileUtil) -> Option<String> {
+        pubspec_yaml_version(&std::fs::read_to_string(generator.get_file_path("pubspec.yaml")).ok()?)
     }
 }
 
-fn next_minor_version(current: &str) -> String {
-    let regex = Regex::new("([0-9\\.]+)(\\+[0-9]+)").unwrap();
-    if let Some(caps) = regex.captures(current) {
-        if let Some(version_number) = caps.get(1) {
-            let version_number_str = version_number.as_str();
-            let parts = version_number_str.split(".");
-            let last = parts.clone().last().unwrap();
-            match last.parse::<u32>() {
-                Ok(num) => {
-                    let new_last = format!("{}", num + 1);
-                    let vec_parts: Vec<&str> = parts.into_iter().collect();
-                    let new_version = vec_parts.split_last().unwrap().1.join(".") + "." + &new_last;
-                    new_version + "+1"
-                },
-                Err(_) => current.to_owned(),
-            }
-        } else {
-            current.to_owned()
-        }
-    } else {
-        current.to_owned()
+fn pubspec_version_regex() -> Regex {
+    Regex::new("version\\s*:\\s*([0-9\\.\\+]+)").unwrap()
+}
+
+fn update_pubspec_yaml_version(mut content: String, bump: Bump) -> String {
+    let replacement = pubspec_version_regex().captures(content.as_str()).and_then(|captures| captures.get(1)).and_then(|capture| {
+        Some((capture.range(), next_version(capture.as_str(), bump)?))
+    });
+    if let Some((range, next)) = replacement {
+        content.replace_range(range, next.as_str());
     }
+    content
+}
+
+fn next_version(current: &str, bump: Bump) -> Option<String> {
+    let version = bump_version(current, bump)?;
+    // the build number restarts with every version
+    Some(if current.contains("+") { version + "+1" } else { version })
+}
+
+fn pubspec_yaml_version(content: &str) -> Option<String> {
+    pubspec_version_regex().captures(content).and_then(|captures| captures.get(1)).map(|capture| capture.as_str().to_owned())
 }
 
 fn fix_path_inner(components: &Vec<String>, namespace: &Namespace, client: &Client) -> Vec<String> {
@@ -384,23 +462,23 @@ fn fix_path_inner(components: &Vec<String>, namespace: &Namespace, client: &Clie
     }
 }
 
-fn fix_path_enum_reference(enum_reference: &SynthesizedEnumReference, namespace: &Namespace, client: &Client) -> SynthesizedEnumReference {
-    SynthesizedEnumReference {
+fn fix_path_enum_reference(enum_reference: &SynthesizedEnumReference, namespace: &Namespace, client: &Client) -> Result<SynthesizedEnumReference> {
+    Ok(SynthesizedEnumReference {
         kind: enum_reference.kind,
-        owner: Box::new(fix_path(enum_reference.owner.as_ref(), namespace, client)),
-    }
+        owner: Box::new(fix_path(enum_reference.owner.as_ref(), namespace, client)?),
+    })
 }
 
-fn fix_path_shape_reference(shape_reference: &SynthesizedShapeReference, namespace: &Namespace, client: &Client) -> SynthesizedShapeReference {
-    SynthesizedShapeReference {
+fn fix_path_shape_reference(shape_reference: &SynthesizedShapeReference, namespace: &Namespace, client: &Client) -> Result<SynthesizedShapeReference> {
+    Ok(SynthesizedShapeReference {
         kind: shape_reference.kind,
-        owner: Box::new(fix_path(shape_reference.owner.as_ref(), namespace, client)),
+        owner: Box::new(fix_path(shape_reference.owner.as_ref(), namespace, client)?),
         without: shape_reference.without.clone(),
-    }
+    })
 }
 
-fn fix_path(t: &Type, namespace: &Namespace, client: &Client) -> Type {
-    match t {
+fn fix_path(t: &Type, namespace: &Namespace, client: &Client) -> Result<Type> {
+    Ok(match t {
         Type::Undetermined => t.clone(),
         Type::Ignored => t.clone(),
         Type::Any => t.clone(),
@@ -419,22 +497,22 @@ fn fix_path(t: &Type, namespace: &Namespace, client: &Client) -> Type {
         Type::Regex => t.clone(),
         Type::Model => t.clone(),
         Type::DataSet => t.clone(),
-        Type::Enumerable(inner) => Type::Enumerable(Box::new(fix_path(inner.as_ref(), namespace, client))),
-        Type::Array(inner) => Type::Array(Box::new(fix_path(inner.as_ref(), namespace, client))),
-        Type::Dictionary(inner) => Type::Dictionary(Box::new(fix_path(inner.as_ref(), namespace, client))),
-        Type::Tuple(types) => Type::Tuple(types.iter().map(|t| fix_path(t, namespace, client)).collect()),
-        Type::Range(inner) => Type::Range(Box::new(fix_path(inner.as_ref(), namespace, client))),
-        Type::Union(types) => Type::Union(types.iter().map(|t| fix_path(t, namespace, client)).collect()),
+        Type::Enumerable(inner) => Type::Enumerable(Box::new(fix_path(inner.as_ref(), namespace, client)?)),
+        Type::Array(inner) => Type::Array(Box::new(fix_path(inner.as_ref(), namespace, client)?)),
+        Type::Dictionary(inner) => Type::Dictionary(Box::new(fix_path(inner.as_ref(), namespace, client)?)),
+        Type::Tuple(types) => Type::Tuple(types.iter().map(|t| fix_path(t, namespace, client)).collect::<Result<Vec<Type>>>()?),
+        Type::Range(inner) => Type::Range(Box::new(fix_path(inner.as_ref(), namespace, client)?)),
+        Type::Union(types) => Type::Union(types.iter().map(|t| fix_path(t, namespace, client)).collect::<Result<Vec<Type>>>()?),
         Type::EnumVariant(reference) => Type::EnumVariant(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, client))),
-        Type::InterfaceObject(reference, types) => Type::InterfaceObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, client)), types.iter().map(|t| fix_path(t, namespace, client)).collect()),
+        Type::InterfaceObject(reference, types) => Type::InterfaceObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, client)), types.iter().map(|t| fix_path(t, namespace, client)).collect::<Result<Vec<Type>>>()?),
         Type::ModelObject(reference) => Type::ModelObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, client))),
-        Type::StructObject(reference, types) => Type::StructObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, client)), types.iter().map(|t| fix_path(t, namespace, client)).collect()),
+        Type::StructObject(reference, types) => Type::StructObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, client)), types.iter().map(|t| fix_path(t, namespace, client)).collect::<Result<Vec<Type>>>()?),
         Type::GenericItem(name) => Type::GenericItem(name.clone()),
         Type::Keyword(keyword) => Type::Keyword(keyword.clone()),
-        Type::Optional(inner) => Type::Optional(Box::new(fix_path(inner.as_ref(), namespace, client))),
-        Type::SynthesizedShapeReference(shape_reference) => Type::SynthesizedShapeReference(fix_path_shape_reference(shape_reference, namespace, client)),
-        Type::SynthesizedEnumReference(enum_reference) => Type::SynthesizedEnumReference(fix_path_enum_reference(enum_reference, namespace, client)),
-        Type::DeclaredSynthesizedShape(reference, inner) => Type::DeclaredSynthesizedShape(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, client)), Box::new(fix_path(inner, namespace, client))),
-        _ => panic!(),
-    }
+        Type::Optional(inner) => Type::Optional(Box::new(fix_path(inner.as_ref(), namespace, client)?)),
+        Type::SynthesizedShapeReference(shape_reference) => Type::SynthesizedShapeReference(fix_path_shape_reference(shape_reference, namespace, client)?),
+        Type::SynthesizedEnumReference(enum_reference) => Type::SynthesizedEnumReference(fix_path_enum_reference(enum_reference, namespace, client)?),
+        Type::DeclaredSynthesizedShape(reference, inner) => Type::DeclaredSynthesizedShape(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, client)), Box::new(fix_path(inner, namespace, client)?)),
+        _ => Err(Error::new(format!("cannot generate type `{}' in namespace `{}'", t, namespace.path().join("."))))?,
+    })
 }
\ No newline at end of file
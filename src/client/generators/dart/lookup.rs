@@ -4,12 +4,29 @@ use crate::outline::outline::Mode;
 use crate::utils::declared_shape_lookup::declared_shape_lookup;
 use crate::utils::enum_reference_lookup::enum_reference_lookup;
 use crate::utils::shape_reference_lookup::shape_reference_lookup;
+use crate::utils::tuples::tuple_class_name;
+use crate::utils::unions::{union_members_without_null, union_name};
 
 pub(in crate::client) fn lookup(t: &Type) -> Result<String> {
     Ok(match t {
         Type::Undetermined => Err(Error::new("encountered undetermined"))?,
         Type::Ignored => Err(Error::new("encountered ignored"))?,
-        Type::Any | Type::Union(_) | Type::Enumerable(_) => "dynamic".to_owned(),
+        Type::Any | Type::Enumerable(_) => "dynamic".to_owned(),
+        Type::Union(types) => {
+            let (members, has_null) = union_members_without_null(types);
+            let result = if members.is_empty() {
+                "dynamic".to_owned()
+            } else if members.len() == 1 {
+                lookup(members.first().unwrap())?
+            } else {
+                union_name(types)
+            };
+            if has_null {
+                nullable(result)
+            } else {
+                result
+            }
+        },
         Type::Optional(t) => {
             let result = lookup(t)? + "?";
             if result.ends_with("?") {
@@ -33,8 +50,8 @@ pub(in crate::client) fn lookup(t: &Type) -> Result<String> {
         Type::Regex => Err(Error::new("encountered regex"))?,
         Type::Array(inner) => format!("List<{}>", lookup(inner)?),
         Type::Dictionary(inner) => format!("Map<String, {}>", lookup(inner)?),
-        Type::Tuple(_) => Err(Error::new("encountered tuple"))?,
-        Type::Range(_) => Err(Error::new("encountered range"))?,
+        Type::Tuple(types) => format!("{}<{}>", tuple_class_name(types.len())?, types.iter().map(|t| lookup(t)).collect::<Result<Vec<String>>>()?.join(", ")),
+        Type::Range(inner) => format!("TeoRange<{}>", lookup(inner)?),
         Type::SynthesizedShapeReference(r) => dart_path_replace_fix(shape_reference_lookup(r, ".", Mode::Client)?),
         Type::DeclaredSynthesizedShape(reference, model_type) => dart_path_replace_fix(declared_shape_lookup(reference, model_type.as_ref(), ".")?),
         Type::EnumVariant(reference) => dart_path_join(reference.string_path()),
@@ -49,6 +66,14 @@ pub(in crate::client) fn lookup(t: &Type) -> Result<String> {
     })
 }
 
+fn nullable(result: String) -> String {
+    if result.ends_with("?") || result == "dynamic" {
+        result
+    } else {
+        result + "?"
+    }
+}
+
 fn dart_path_join(items: &Vec<String>) -> String {
     let mut result = "".to_owned();
     for (index, item) in items.iter().enumerate() {
@@ -0,0 +1,312 @@
+use askama::Template;
+use async_trait::async_trait;
+use inflector::Inflector;
+use itertools::Itertools;
+use serde_json::Value;
+use teo_parser::r#type::Type;
+use teo_result::Result;
+use teo_runtime::config::client::{Client, ClientLanguage};
+use teo_runtime::namespace::Namespace;
+use teo_runtime::traits::named::Named;
+use crate::client::ctx::Ctx;
+use crate::client::generator::Generator;
+use crate::client::options::{ClientOptions, DocsFormat};
+use crate::client::registry::{builtin_generator_name, registered_generator};
+use crate::outline::delegate::RequestItem;
+use crate::outline::json::{type_json, type_name};
+use crate::naming::Naming;
+use crate::outline::outline::{Mode, Outline};
+use crate::utils::file::FileUtil;
+use crate::utils::located::Located;
+
+pub(self) struct DocsNamespace {
+    pub(self) name: String,
+    pub(self) anchor: String,
+    pub(self) interfaces: Vec<DocsInterface>,
+    pub(self) enums: Vec<DocsEnum>,
+    pub(self) handlers: Vec<DocsHandler>,
+}
+
+pub(self) struct DocsInterface {
+    pub(self) name: String,
+    pub(self) anchor: String,
+    pub(self) title: String,
+    pub(self) desc: String,
+    pub(self) extends: Vec<DocsType>,
+    pub(self) fields: Vec<DocsField>,
+}
+
+pub(self) struct DocsField {
+    pub(self) name: String,
+    pub(self) title: String,
+    pub(self) desc: String,
+    pub(self) field_type: DocsType,
+}
+
+pub(self) struct DocsEnum {
+    pub(self) name: String,
+    pub(self) anchor: String,
+    pub(self) title: String,
+    pub(self) desc: String,
+    pub(self) members: Vec<DocsMember>,
+}
+
+pub(self) struct DocsMember {
+    pub(self) name: String,
+    pub(self) title: String,
+    pub(self) desc: String,
+}
+
+pub(self) struct DocsHandler {
+    pub(self) name: String,
+    pub(self) anchor: String,
+    pub(self) method: String,
+    pub(self) url: String,
+    pub(self) input: Option<DocsType>,
+    pub(self) output: DocsType,
+    pub(self) snippets: Vec<Snippet>,
+}
+
+/// A type as it's written in a schema, and the anchor of the interface or
+/// the enum it's named after.
+pub(self) struct DocsType {
+    pub(self) name: String,
+    pub(self) anchor: Option<String>,
+}
+
+pub(self) struct Snippet {
+    pub(self) language: &'static str,
+    pub(self) highlight: &'static str,
+    pub(self) code: String,
+}
+
+#[derive(Template)]
+#[template(path = "client/docs/reference.md.jinja", escape = "none")]
+pub(self) struct DocsMarkdownTemplate<'a> {
+    pub(self) conf: &'a Client,
+    pub(self) namespaces: &'a Vec<DocsNamespace>,
+    pub(self) table_cell: &'static dyn Fn(&str) -> String,
+}
+
+#[derive(Template)]
+#[template(path = "client/docs/index.html.jinja", escape = "html")]
+pub(self) struct DocsHtmlTemplate<'a> {
+    pub(self) conf: &'a Client,
+    pub(self) namespaces: &'a Vec<DocsNamespace>,
+}
+
+unsafe impl Send for DocsMarkdownTemplate<'_> { }
+unsafe impl Sync for DocsMarkdownTemplate<'_> { }
+
+/// Descriptions may span lines and contain pipes, which end a table row.
+fn table_cell(text: &str) -> String {
+    text.lines().map(str::trim).filter(|l| !l.is_empty()).join(" ").replace("|", "\\|")
+}
+
+/// The anchor of an interface, an enum or a namespace. Interfaces and enums
+/// share one scope, a model is documented by its result interface.
+fn anchor(path: &[String]) -> String {
+    if path.is_empty() {
+        "main".to_owned()
+    } else {
+        path.iter().map(|p| p.to_kebab_case()).join("-")
+    }
+}
+
+fn docs_type(t: &Type) -> Result<DocsType> {
+    let value = type_json(t, Mode::Client)?;
+    Ok(DocsType { name: type_name(&value), anchor: named_anchor(&value) })
+}
+
+/// Optional and array types link to the type they wrap.
+fn named_anchor(value: &Value) -> Option<String> {
+    match value["kind"].as_str()? {
+        "optional" | "array" => named_anchor(&value["inner"]),
+        "interface" | "model" | "enum" => {
+            let path: Vec<String> = value["path"].as_array()?.iter().filter_map(|p| p.as_str().map(ToOwned::to_owned)).collect();
+            Some(anchor(&path))
+        },
+        _ => None,
+    }
+}
+
+/// How `request_item` is called from the client for `language`, `None` for
+/// clients which aren't generated yet.
+fn snippet(language: &ClientLanguage, receiver: &str, request_item: &RequestItem) -> Option<Snippet> {
+    let mut arguments = vec![];
+    if request_item.has_custom_url_args() {
+        arguments.push("pathArgs");
+    }
+    if request_item.has_body_input() {
+        arguments.push("input");
+    }
+    let call = format!("{}.{}", receiver, request_item.identifier());
+    Some(match language {
+        ClientLanguage::TypeScript(_) => Snippet {
+            language: "TypeScript",
+            highlight: "ts",
+            code: format!("const result = await {}({})", call, arguments.join(", ")),
+        },
+        ClientLanguage::Swift => Snippet {
+            language: "Swift",
+            highlight: "swift",
+            code: format!("let result = try await {}({})", call, arguments.iter().map(|a| format!("{}: {}", a, a)).join(", ")),
+        },
+        ClientLanguage::Kotlin => Snippet {
+            language: "Kotlin",
+            highlight: "kotlin",
+            code: format!("val result = {}({})", call, arguments.join(", ")),
+        },
+        ClientLanguage::Dart => {
+            // headers and the query of custom handlers are positional
+            arguments.push("null");
+            if !request_item.is_builtin() {
+                arguments.push("null");
+            }
+            Snippet {
+                language: "Dart",
+                highlight: "dart",
+                code: format!("final result = await {}({});", call, arguments.join(", ")),
+            }
+        },
+        ClientLanguage::CSharp => return None,
+    })
+}
+
+/// The naming of the client for `language`, so snippets call handlers by the
+/// identifiers that client declares.
+fn language_naming(language: &ClientLanguage, options: &ClientOptions) -> Naming {
+    let name = builtin_generator_name(language);
+    options.naming.get(name).cloned()
+        .or_else(|| registered_generator(name).map(|generator| generator.naming()))
+        .unwrap_or_default()
+}
+
+fn docs_namespaces(namespace: &Namespace, main_namespace: &Namespace, conf: &Client, languages: &[(&ClientLanguage, Naming)], naming: &Naming, namespaces: &mut Vec<DocsNamespace>) -> Result<()> {
+    let outline = Outline::new(namespace, Mode::Client, main_namespace, false, naming)?;
+    // the outline's ordering doesn't depend on the naming, so the delegates
+    // and request items of these line up with the docs' own
+    let language_outlines = languages.iter()
+        .map(|(language, naming)| Ok((*language, Outline::new(namespace, Mode::Client, main_namespace, false, naming)?)))
+        .collect::<Result<Vec<(&ClientLanguage, Outline)>>>()?;
+    let mut interfaces = vec![];
+    for interface in outline.interfaces() {
+        let location = || format!("interface `{}'", interface.path().join("."));
+        interfaces.push(DocsInterface {
+            name: interface.name().to_owned(),
+            anchor: anchor(interface.path()),
+            title: interface.title().to_owned(),
+            desc: interface.desc().to_owned(),
+            extends: interface.extends().iter().map(docs_type).collect::<Result<Vec<DocsType>>>().located(location)?,
+            fields: interface.fields().iter().map(|field| Ok(DocsField {
+                name: field.name().to_owned(),
+                title: field.title().to_owned(),
+                desc: field.desc().to_owned(),
+                field_type: docs_type(field.r#type()).located(|| format!("field `{}'", field.name()))?,
+            })).collect::<Result<Vec<DocsField>>>().located(location)?,
+        });
+    }
+    let enums = outline.enums().iter().map(|r#enum| DocsEnum {
+        name: r#enum.name().to_owned(),
+        anchor: anchor(r#enum.path()),
+        title: r#enum.title().to_owned(),
+        desc: r#enum.desc().to_owned(),
+        members: r#enum.members().iter().map(|member| DocsMember {
+            name: member.name().to_owned(),
+            title: member.title().to_owned(),
+            desc: member.desc().to_owned(),
+        }).collect(),
+    }).collect();
+    let mut handlers = vec![];
+    for (delegate_index, delegate) in outline.delegates().iter().enumerate() {
+        let receiver = outline.delegate_receiver(&conf.object_name, namespace.path(), delegate);
+        for (item_index, request_item) in delegate.request_items().iter().enumerate() {
+            handlers.push(DocsHandler {
+                name: format!("{}.{}", receiver, request_item.identifier()),
+                anchor: format!("handler-{}", request_item.path().split('/').map(|p| p.to_kebab_case()).join("-")),
+                method: request_item.method().to_owned(),
+                url: format!("/{}", request_item.path().trim_start_matches('/')),
+                input: if request_item.has_body_input() {
+                    Some(docs_type(request_item.input_type()).located(|| format!("input of handler `{}'", request_item.path()))?)
+                } else {
+                    None
+                },
+                output: docs_type(request_item.output_type()).located(|| format!("output of handler `{}'", request_item.path()))?,
+                snippets: language_outlines.iter().filter_map(|(language, language_outline)| {
+                    let delegate = &language_outline.delegates()[delegate_index];
+                    let receiver = language_outline.delegate_receiver(&conf.object_name, namespace.path(), delegate);
+                    snippet(language, &receiver, &delegate.request_items()[item_index])
+                }).collect(),
+            });
+        }
+    }
+    namespaces.push(DocsNamespace {
+        name: if namespace.is_main() { "main".to_owned() } else { namespace.path().join(".") },
+        anchor: format!("namespace-{}", anchor(namespace.path())),
+        interfaces,
+        enums,
+        handlers,
+    });
+    for child in namespace.namespaces().values().sorted_by_key(|n| n.name()) {
+        if !child.is_std() {
+            docs_namespaces(child, main_namespace, conf, languages, naming, namespaces)?;
+        }
+    }
+    Ok(())
+}
+
+/// # Docs generator
+///
+/// Writes an API reference of the schema, with the interfaces, enums and
+/// handlers the clients expose and how each handler is called from them.
+pub(in crate::client) struct DocsGenerator { }
+
+impl DocsGenerator {
+
+    pub fn new() -> Self {
+        Self { }
+    }
+}
+
+#[async_trait]
+impl Generator for DocsGenerator {
+
+    fn module_directory_in_package(&self, _conf: &Client) -> String {
+        ".".to_owned()
+    }
+
+    async fn generate_module_files(&self, _ctx: &Ctx, generator: &FileUtil) -> Result<()> {
+        generator.ensure_root_directory().await
+    }
+
+    async fn generate_package_files(&self, _ctx: &Ctx, generator: &FileUtil) -> Result<()> {
+        generator.ensure_root_directory().await
+    }
+
+    async fn update_parent_package_files(&self, _ctx: &Ctx, _generator: &FileUtil) -> Result<()> {
+        Ok(())
+    }
+
+    async fn generate_main(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()> {
+        let options = &ctx.options.docs;
+        let languages = if options.languages.is_empty() {
+            std::slice::from_ref(&ctx.conf.provider)
+        } else {
+            options.languages.as_slice()
+        };
+        let languages: Vec<(&ClientLanguage, Naming)> = languages.iter().map(|language| (language, language_naming(language, ctx.options))).collect();
+        let mut namespaces = vec![];
+        docs_namespaces(ctx.main_namespace, ctx.main_namespace, ctx.conf, &languages, ctx.naming(), &mut namespaces)?;
+        match options.format {
+            DocsFormat::Markdown => generator.generate_file("reference.md", ctx.templates.render("client/docs/reference.md.jinja", &DocsMarkdownTemplate {
+                conf: ctx.conf,
+                namespaces: &namespaces,
+                table_cell: &table_cell,
+            })?).await,
+            DocsFormat::Html => generator.generate_file("index.html", ctx.templates.render("client/docs/index.html.jinja", &DocsHtmlTemplate {
+                conf: ctx.conf,
+                namespaces: &namespaces,
+            })?).await,
+        }
+    }
+}
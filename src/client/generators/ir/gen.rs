@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use teo_result::Result;
+use teo_runtime::config::client::Client;
+use crate::client::ctx::Ctx;
+use crate::client::generator::Generator;
+use crate::outline::json::outline_document;
+use crate::outline::outline::Mode;
+use crate::utils::file::FileUtil;
+
+/// # IR generator
+///
+/// Writes the client outline of the schema as `outline.json`, for docs sites,
+/// linters and mock servers which aren't written in Rust.
+pub(in crate::client) struct IrGenerator { }
+
+impl IrGenerator {
+
+    pub fn new() -> Self {
+        Self { }
+    }
+}
+
+#[async_trait]
+impl Generator for IrGenerator {
+
+    fn module_directory_in_package(&self, _conf: &Client) -> String {
+        ".".to_owned()
+    }
+
+    async fn generate_module_files(&self, _ctx: &Ctx, generator: &FileUtil) -> Result<()> {
+        generator.ensure_root_directory().await
+    }
+
+    async fn generate_package_files(&self, _ctx: &Ctx, generator: &FileUtil) -> Result<()> {
+        generator.ensure_root_directory().await
+    }
+
+    async fn update_parent_package_files(&self, _ctx: &Ctx, _generator: &FileUtil) -> Result<()> {
+        Ok(())
+    }
+
+    async fn generate_main(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()> {
+        let document = outline_document(ctx.main_namespace, Mode::Client)?;
+        generator.generate_file("outline.json", serde_json::to_string_pretty(&document).unwrap() + "\n").await
+    }
+}
@@ -0,0 +1 @@
+pub(in crate::client) mod gen;
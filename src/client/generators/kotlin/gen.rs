@@ -1,9 +1,10 @@
-use std::env;
 use crate::utils::filters;
-use std::process::Command;
+use tokio::process::Command;
+use regex::Regex;
 use askama::Template;
 use async_trait::async_trait;
 use teo_parser::r#type::Type;
+use teo_result::Result;
 use teo_runtime::config::client::Client;
 use teo_runtime::namespace::Namespace;
 use teo_runtime::traits::named::Named;
@@ -11,10 +12,17 @@ use crate::client::ctx::Ctx;
 use crate::client::generator::Generator;
 use crate::client::generators::kotlin::lookup;
 use crate::outline::outline::{Mode, Outline};
+use crate::outline::interface::field::Field;
+use crate::naming::{Case, Naming};
 use crate::utils::exts::ClientExt;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::TemplateOverrides;
 use crate::utils::lookup::Lookup;
 use crate::utils::message::green_message;
+use crate::outline::changes::Bump;
+use crate::utils::version::bump_version;
+use crate::utils::tuples::tuple_class_indices;
+use crate::utils::unions::{collect_outline_unions, UnionDefinition};
 
 fn package_name_from_ctx_conf(ctx: &Ctx, package_mode: bool, inferred: String) -> String {
     if package_mode {
@@ -39,14 +47,33 @@ fn maybe_any_prefix(t: &Type) -> &'static str {
     }
 }
 
-fn maybe_underscore(name: &str) -> String {
-    if name.starts_with("_") {
-        format!("@SerialName(\"{}\") ", name)
+/// Properties are declared without a leading underscore, so those are mapped
+/// back to the field name like renamed ones.
+fn maybe_serial_name(field: &Field) -> String {
+    if field.is_renamed() || field.identifier().starts_with("_") {
+        format!("@SerialName(\"{}\") ", field.name())
     } else {
         "".to_owned()
     }
 }
 
+/// The JSON shape a union member is decoded from.
+fn union_member_check(t: &Type) -> &'static str {
+    match t {
+        Type::Date => "element is JsonObject && element.containsKey(\"\\$date\")",
+        Type::DateTime => "element is JsonObject && element.containsKey(\"\\$datetime\")",
+        Type::Decimal => "element is JsonObject && element.containsKey(\"\\$decimal\")",
+        Type::Bool => "element is JsonPrimitive && !element.isString && element.booleanOrNull != null",
+        Type::Int | Type::Int64 => "element is JsonPrimitive && !element.isString && element.longOrNull != null",
+        Type::Float | Type::Float32 => "element is JsonPrimitive && !element.isString && element.doubleOrNull != null",
+        Type::String | Type::ObjectId | Type::EnumVariant(_) | Type::SynthesizedEnumReference(_) => "element is JsonPrimitive && element.isString",
+        Type::Array(_) | Type::Enumerable(_) | Type::Tuple(_) => "element is JsonArray",
+        Type::Range(_) => "element is JsonObject && element.containsKey(\"start\") && element.containsKey(\"end\")",
+        Type::Dictionary(_) | Type::ModelObject(_) | Type::InterfaceObject(_, _) | Type::SynthesizedShapeReference(_) | Type::DeclaredSynthesizedShape(_, _) => "element is JsonObject",
+        _ => "true",
+    }
+}
+
 #[derive(Template)]
 #[template(path = "client/kotlin/readme.md.jinja", escape = "none")]
 pub(self) struct KotlinReadMeTemplate<'a> {
@@ -73,9 +100,13 @@ pub(self) struct KotlinNamespaceTemplate<'a> {
     pub(self) outline: &'a Outline,
     pub(self) conf: &'a Client,
     pub(self) lookup: &'static dyn Lookup,
-    pub(crate) render_namespace: &'static dyn Fn(&Namespace, &Client, &Namespace) -> String,
+    pub(crate) render_namespace: &'static dyn Fn(&Namespace, &Client, &Namespace, &TemplateOverrides, &Naming) -> Result<String>,
+    pub(self) templates: &'a TemplateOverrides,
+    pub(self) naming: &'a Naming,
     pub(self) maybe_any_prefix: &'static dyn Fn(&Type) -> &'static str,
-    pub(self) maybe_underscore: &'static dyn Fn(&str) -> String,
+    pub(self) maybe_serial_name: &'static dyn Fn(&Field) -> String,
+    pub(self) unions: Vec<UnionDefinition>,
+    pub(self) union_member_check: &'static dyn Fn(&Type) -> &'static str,
 }
 
 #[derive(Template)]
@@ -86,7 +117,10 @@ pub(self) struct KotlinMainTemplate<'a> {
     pub(self) outline: &'a Outline,
     pub(self) conf: &'a Client,
     pub(self) lookup: &'static dyn Lookup,
-    pub(crate) render_namespace: &'static dyn Fn(&Namespace, &Client, &Namespace) -> String,
+    pub(crate) render_namespace: &'static dyn Fn(&Namespace, &Client, &Namespace, &TemplateOverrides, &Naming) -> Result<String>,
+    pub(self) templates: &'a TemplateOverrides,
+    pub(self) naming: &'a Naming,
+    pub(self) tuple_classes: Vec<Vec<usize>>,
 }
 
 unsafe impl Send for KotlinMainTemplate<'_> { }
@@ -94,22 +128,28 @@ unsafe impl Sync for KotlinMainTemplate<'_> { }
 unsafe impl Send for KotlinNamespaceTemplate<'_> { }
 unsafe impl Sync for KotlinNamespaceTemplate<'_> { }
 
-pub(crate) fn render_namespace(namespace: &Namespace, conf: &Client, main_namespace: &Namespace) -> String {
-    let content = KotlinNamespaceTemplate {
+pub(crate) fn render_namespace(namespace: &Namespace, conf: &Client, main_namespace: &Namespace, templates: &TemplateOverrides, naming: &Naming) -> Result<String> {
+    let outline = Outline::new(namespace, Mode::Client, main_namespace, true, naming)?;
+    outline.try_each_type(|t| lookup(t).map(|_| ()))?;
+    let content = templates.render_for_namespace("client/kotlin/namespace.kt.jinja", &KotlinNamespaceTemplate {
         conf,
         namespace,
         render_namespace: &render_namespace,
-        outline: &Outline::new(namespace, Mode::Client, main_namespace, true),
+        templates,
+        naming,
+        unions: collect_outline_unions(&outline),
+        outline: &outline,
         lookup: &lookup,
         main_namespace,
         maybe_any_prefix: &maybe_any_prefix,
-        maybe_underscore: &maybe_underscore,
-    }.render().unwrap();
-    if namespace.path().is_empty() {
+        maybe_serial_name: &maybe_serial_name,
+        union_member_check: &union_member_check,
+    }, namespace)?;
+    Ok(if namespace.path().is_empty() {
         content
     } else {
         format!("class {} {{\n", namespace.name()) + &indent::indent_by(4, content.as_str()) + "\n}"
-    }
+    })
 }
 
 pub(in crate::client) struct KotlinGenerator { }
@@ -135,22 +175,20 @@ impl Generator for KotlinGenerator {
     async fn generate_package_files(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
         generator.ensure_root_directory().await?;
         let base = generator.get_base_dir();
-        let mut has_project = false;
-        for file in ["build.gradle", "build.gradle.kts"] {
-            let proj_file = base.join(file);
-            if proj_file.exists() { has_project = true; break; }
-        }
-        if !has_project {
-            let saved_cwd = env::current_dir().unwrap();
-            env::set_current_dir(base).unwrap();
+        if let Some(build_file) = gradle_build_file(generator) {
+            // bump the version by the API changes
+            let content = std::fs::read_to_string(generator.get_file_path(build_file))?;
+            if let Some(updated) = update_gradle_version(&content, ctx.changes.bump()) {
+                generator.generate_file(build_file, updated).await?;
+            }
+        } else {
             green_message("run", format!("`gradle init --type basic --dsl kotlin --project-name {}`", ctx.conf.inferred_package_name_camel_case()));
-            let exit_status = Command::new("gradle").arg("init").arg("--type").arg("basic").arg("--dsl").arg("kotlin").arg("--project-name").arg(ctx.conf.inferred_package_name_camel_case()).spawn()?.wait()?;
+            let exit_status = Command::new("gradle").arg("init").arg("--type").arg("basic").arg("--dsl").arg("kotlin").arg("--project-name").arg(ctx.conf.inferred_package_name_camel_case()).current_dir(base).status().await?;
             if exit_status.success() {
-                env::set_current_dir(saved_cwd).unwrap();
-                generator.generate_file(".gitignore", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/client/kotlin/gitignore"))).await?;
-                generator.generate_file("README.md", KotlinReadMeTemplate { conf: ctx.conf }.render().unwrap()).await?;
-                generator.generate_file("build.gradle.kts", KotlinBuildGradleTemplate { conf: ctx.conf }.render().unwrap()).await?;
-                generator.generate_file("settings.gradle.kts", KotlinSettingsGradleTemplate { conf: ctx.conf }.render().unwrap()).await?;
+                generator.generate_file(".gitignore", ctx.templates.render_static("client/kotlin/gitignore", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/client/kotlin/gitignore")))?).await?;
+                generator.generate_file("README.md", ctx.templates.render("client/kotlin/readme.md.jinja", &KotlinReadMeTemplate { conf: ctx.conf })?).await?;
+                generator.generate_file("build.gradle.kts", ctx.templates.render("client/kotlin/build.gradle.kts.jinja", &KotlinBuildGradleTemplate { conf: ctx.conf })?).await?;
+                generator.generate_file("settings.gradle.kts", ctx.templates.render("client/kotlin/settings.gradle.kts.jinja", &KotlinSettingsGradleTemplate { conf: ctx.conf })?).await?;
             }
         }
         Ok(())
@@ -161,15 +199,71 @@ impl Generator for KotlinGenerator {
     }
 
     async fn generate_main(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
-        let outline = Outline::new(ctx.main_namespace, Mode::Client, ctx.main_namespace, true);
-        generator.generate_file(format!("{}.kt", ctx.conf.inferred_package_name_camel_case()), KotlinMainTemplate {
+        let outline = Outline::new(ctx.main_namespace, Mode::Client, ctx.main_namespace, true, ctx.naming())?;
+        generator.generate_file(format!("{}.kt", ctx.conf.inferred_package_name_camel_case()), ctx.templates.render("client/kotlin/teo.kt.jinja", &KotlinMainTemplate {
             package_name: package_name_from_ctx_conf(ctx, ctx.conf.package, ctx.conf.inferred_package_name_camel_case()),
             lookup: &lookup::lookup,
             outline: &outline,
             conf: ctx.conf,
             namespace: ctx.main_namespace,
             render_namespace: &render_namespace,
-        }.render().unwrap()).await?;
+            templates: &ctx.templates,
+            naming: ctx.naming(),
+            tuple_classes: tuple_class_indices(),
+        })?).await?;
         Ok(())
     }
+
+    fn naming(&self) -> Naming {
+        Naming { enum_members: Case::ScreamingSnake, ..Naming::default() }
+    }
+
+    fn package_version(&self, generator: &FileUtil) -> Option<String> {
+        let content = std::fs::read_to_string(generator.get_file_path(gradle_build_file(generator)?)).ok()?;
+        gradle_version_regex().captures(&content)?.get(1).map(|version| version.as_str().to_owned())
+    }
+}
+
+fn gradle_build_file(generator: &FileUtil) -> Option<&'static str> {
+    ["build.gradle", "build.gradle.kts"].into_iter().find(|file| generator.get_file_path(file).exists())
+}
+
+fn gradle_version_regex() -> Regex {
+    Regex::new(r#"(?m)^version\s*=?\s*["']([^"']+)["']"#).unwrap()
+}
+
+fn update_gradle_version(content: &str, bump: Bump) -> Option<String> {
+    let version = gradle_version_regex().captures(content)?.get(1)?;
+    let next = bump_version(version.as_str(), bump)?;
+    let mut updated = content.to_owned();
+    updated.replace_range(version.range(), next.as_str());
+    Some(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn updates_the_version_assignment() {
+        let content = "group = \"io.teocloud\"\nversion = \"1.0.0\"\n";
+        assert_eq!(update_gradle_version(content, Bump::Minor).as_deref(), Some("group = \"io.teocloud\"\nversion = \"1.1.0\"\n"));
+    }
+
+    #[test]
+    fn updates_groovy_versions() {
+        assert_eq!(update_gradle_version("version '0.2.0'\n", Bump::Major).as_deref(), Some("version '0.3.0'\n"));
+    }
+
+    #[test]
+    fn leaves_other_versions_alone() {
+        let content = "dependencies {\n    implementation(\"io.ktor:ktor-client-core:2.3.0\")\n}\nversion = \"1.0.0\"\n";
+        assert_eq!(update_gradle_version(content, Bump::Patch).as_deref(), Some("dependencies {\n    implementation(\"io.ktor:ktor-client-core:2.3.0\")\n}\nversion = \"1.0.1\"\n"));
+    }
+
+    #[test]
+    fn needs_a_numeric_version() {
+        assert_eq!(update_gradle_version("group = \"io.teocloud\"\n", Bump::Patch), None);
+        assert_eq!(update_gradle_version("version = \"SNAPSHOT\"\n", Bump::Patch), None);
+    }
 }
@@ -4,6 +4,8 @@ use crate::outline::outline::Mode;
 use crate::utils::declared_shape_lookup::declared_shape_lookup;
 use crate::utils::enum_reference_lookup::enum_reference_lookup;
 use crate::utils::shape_reference_lookup::shape_reference_lookup;
+use crate::utils::tuples::tuple_class_name;
+use crate::utils::unions::{union_members_without_null, union_name};
 
 pub(in crate::client) fn lookup(t: &Type) -> Result<String> {
     lookup_inner(t, false)
@@ -12,12 +14,14 @@ pub(in crate::client) fn lookup(t: &Type) -> Result<String> {
 fn lookup_inner(t: &Type, contextual: bool) -> Result<String> {
     Ok(match t {
         Type::Any => if contextual { "@Contextual Any".to_string() } else { "Any".to_string() },
-        Type::Union(detailed) => {
-            if contextual {
-                "@Contextual Any".to_string()
-            } else {
-                "Any".to_string()
-            }
+        Type::Union(types) => {
+            let (members, has_null) = union_members_without_null(types);
+            let result = match members.len() {
+                0 => if contextual { "@Contextual Any".to_string() } else { "Any".to_string() },
+                1 => lookup_inner(members.first().unwrap(), contextual)?,
+                _ => union_name(types),
+            };
+            if has_null && !result.ends_with("?") { result + "?" } else { result }
         },
         Type::ObjectId => "String".to_string(),
         Type::Bool => "Boolean".to_string(),
@@ -48,6 +52,8 @@ fn lookup_inner(t: &Type, contextual: bool) -> Result<String> {
         } else {
             reference.string_path().join(".") + "<" + &types.iter().map(|t| lookup_inner(t, true)).collect::<Result<Vec<String>>>()?.join(", ") + ">"
         },
+        Type::Tuple(types) => format!("{}<{}>", tuple_class_name(types.len())?, types.iter().map(|t| lookup_inner(t, true)).collect::<Result<Vec<String>>>()?.join(", ")),
+        Type::Range(inner) => format!("TeoRange<{}>", lookup_inner(inner, true)?),
         _ => Err(Error::new("encountered an unsupported type"))?,
     })
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 pub(in crate::client) mod csharp;
 pub(in crate::client) mod dart;
+pub(in crate::client) mod docs;
+pub(in crate::client) mod ir;
 pub(in crate::client) mod kotlin;
 pub(in crate::client) mod swift;
 pub(in crate::client) mod ts;
\ No newline at end of file
@@ -1,6 +1,7 @@
 use askama::Template;
 use async_trait::async_trait;
 use teo_parser::r#type::Type;
+use teo_result::Result;
 use teo_runtime::config::client::Client;
 use teo_runtime::namespace::Namespace;
 use teo_runtime::traits::named::Named;
@@ -9,10 +10,14 @@ use crate::client::generator::Generator;
 use crate::outline::outline::{Mode, Outline};
 use crate::utils::exts::ClientExt;
 use crate::utils::file::FileUtil;
+use crate::utils::templates::TemplateOverrides;
 use crate::utils::filters;
 use crate::utils::lookup::Lookup;
 use crate::client::generators::swift::lookup;
 use crate::outline::interface::Interface;
+use crate::naming::Naming;
+use crate::utils::tuples::tuple_class_indices;
+use crate::utils::unions::{collect_outline_unions, UnionDefinition};
 
 fn where_codable(interface: &Interface) -> String {
     if interface.generic_names().len() > 0 {
@@ -22,6 +27,11 @@ fn where_codable(interface: &Interface) -> String {
     }
 }
 
+/// `AnyCodable` aborts on unknown input, so it's never tried as a union member.
+fn union_member_decodable(t: &Type) -> bool {
+    !matches!(t, Type::Any)
+}
+
 #[derive(Template)]
 #[template(path = "client/swift/readme.md.jinja", escape = "none")]
 pub(self) struct SwiftReadMeTemplate<'a> {
@@ -42,8 +52,12 @@ pub(self) struct SwiftNamespaceTemplate<'a> {
     pub(self) outline: &'a Outline,
     pub(self) conf: &'a Client,
     pub(self) lookup: &'static dyn Lookup,
-    pub(crate) render_namespace: &'static dyn Fn(&Namespace, &Client, &Namespace) -> String,
+    pub(crate) render_namespace: &'static dyn Fn(&Namespace, &Client, &Namespace, &TemplateOverrides, &Naming) -> Result<String>,
+    pub(self) templates: &'a TemplateOverrides,
+    pub(self) naming: &'a Naming,
     pub(self) where_codable: &'static dyn Fn(&Interface) -> String,
+    pub(self) unions: Vec<UnionDefinition>,
+    pub(self) union_member_decodable: &'static dyn Fn(&Type) -> bool,
 }
 
 #[derive(Template)]
@@ -53,7 +67,10 @@ pub(self) struct SwiftMainTemplate<'a> {
     pub(self) outline: &'a Outline,
     pub(self) conf: &'a Client,
     pub(self) lookup: &'static dyn Lookup,
-    pub(crate) render_namespace: &'static dyn Fn(&Namespace, &Client, &Namespace) -> String,
+    pub(crate) render_namespace: &'static dyn Fn(&Namespace, &Client, &Namespace, &TemplateOverrides, &Naming) -> Result<String>,
+    pub(self) templates: &'a TemplateOverrides,
+    pub(self) naming: &'a Naming,
+    pub(self) tuple_classes: Vec<Vec<usize>>,
 }
 
 unsafe impl Send for SwiftMainTemplate<'_> { }
@@ -61,21 +78,27 @@ unsafe impl Sync for SwiftMainTemplate<'_> { }
 unsafe impl Send for SwiftNamespaceTemplate<'_> { }
 unsafe impl Sync for SwiftNamespaceTemplate<'_> { }
 
-pub(crate) fn render_namespace(namespace: &Namespace, conf: &Client, main_namespace: &Namespace) -> String {
-    let content = SwiftNamespaceTemplate {
+pub(crate) fn render_namespace(namespace: &Namespace, conf: &Client, main_namespace: &Namespace, templates: &TemplateOverrides, naming: &Naming) -> Result<String> {
+    let outline = Outline::new(namespace, Mode::Client, main_namespace, false, naming)?;
+    outline.try_each_type(|t| lookup(t).map(|_| ()))?;
+    let content = templates.render_for_namespace("client/swift/namespace.swift.jinja", &SwiftNamespaceTemplate {
         conf,
         namespace,
         render_namespace: &render_namespace,
-        outline: &Outline::new(namespace, Mode::Client, main_namespace, false),
+        templates,
+        naming,
+        unions: collect_outline_unions(&outline),
+        outline: &outline,
         lookup: &lookup,
         main_namespace,
         where_codable: &where_codable,
-    }.render().unwrap();
-    if namespace.path().is_empty() {
+        union_member_decodable: &union_member_decodable,
+    }, namespace)?;
+    Ok(if namespace.path().is_empty() {
         content
     } else {
         format!("public struct {} {{\n", namespace.name()) + &indent::indent_by(4, content.as_str()) + "\n}"
-    }
+    })
 }
 
 pub(in crate::client) struct SwiftGenerator { }
@@ -100,9 +123,9 @@ impl Generator for SwiftGenerator {
 
     async fn generate_package_files(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
         generator.clear_root_directory().await?;
-        generator.generate_file(".gitignore", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/client/swift/gitignore"))).await?;
-        generator.generate_file("README.md", SwiftReadMeTemplate { conf: ctx.conf }.render().unwrap()).await?;
-        generator.generate_file("Package.swift", SwiftPackageSwiftTemplate { conf: ctx.conf }.render().unwrap()).await?;
+        generator.generate_file(".gitignore", ctx.templates.render_static("client/swift/gitignore", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/client/swift/gitignore")))?).await?;
+        generator.generate_file("README.md", ctx.templates.render("client/swift/readme.md.jinja", &SwiftReadMeTemplate { conf: ctx.conf })?).await?;
+        generator.generate_file("Package.swift", ctx.templates.render("client/swift/package.swift.jinja", &SwiftPackageSwiftTemplate { conf: ctx.conf })?).await?;
         Ok(())
     }
 
@@ -111,14 +134,17 @@ impl Generator for SwiftGenerator {
     }
 
     async fn generate_main(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
-        let outline = Outline::new(ctx.main_namespace, Mode::Client, ctx.main_namespace, false);
-        generator.generate_file(format!("{}.swift", ctx.conf.inferred_package_name()), SwiftMainTemplate {
+        let outline = Outline::new(ctx.main_namespace, Mode::Client, ctx.main_namespace, false, ctx.naming())?;
+        generator.generate_file(format!("{}.swift", ctx.conf.inferred_package_name()), ctx.templates.render("client/swift/teo.swift.jinja", &SwiftMainTemplate {
             lookup: &lookup::lookup,
             outline: &outline,
             conf: ctx.conf,
             namespace: ctx.main_namespace,
             render_namespace: &render_namespace,
-        }.render().unwrap()).await?;
+            templates: &ctx.templates,
+            naming: ctx.naming(),
+            tuple_classes: tuple_class_indices(),
+        })?).await?;
         Ok(())
     }
 }
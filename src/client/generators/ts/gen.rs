@@ -3,10 +3,12 @@ use async_trait::async_trait;
 use teo_runtime::config::client::Client;
 use teo_runtime::namespace::Namespace;
 use crate::client::ctx::Ctx;
+use crate::client::TsInt64Strategy;
 use crate::client::generator::Generator;
+use crate::client::generators::ts::int64_shapes::Int64Shapes;
 use crate::client::generators::ts::package_json::generate_package_json;
-use crate::client::ts::package_json::updated_package_json_for_existing_project;
-use crate::utils::file::FileUtil;
+use crate::client::generators::ts::package_json::updated_package_json_for_existing_project;
+use crate::utils::file::{read_file, FileUtil};
 use crate::utils::filters;
 use crate::utils::exts::ClientExt;
 use indent;
@@ -14,9 +16,10 @@ use teo_runtime::handler::Handler;
 use teo_runtime::request::Method;
 use tokio::fs;
 use crate::outline::outline::Mode;
-use crate::shared::ts::conf::TsConf;
+use crate::naming::Naming;
+use crate::shared::ts::conf::{check_naming, TsConf};
 use crate::shared::ts::templates::{render_namespace, TsIndexDTsTemplate};
-use crate::utils::update_package_json_version::update_package_json_version;
+use crate::utils::update_package_json_version::{package_json_version, update_package_json_version};
 
 #[derive(Template)]
 #[template(path = "client/ts/readme.md.jinja", escape = "none")]
@@ -31,6 +34,9 @@ pub(self) struct TsIndexJsTemplate<'a> {
     pub(self) conf: &'a Client,
     pub(self) group_delegate_map: &'static dyn Fn(&Namespace) -> String,
     pub(self) custom_handler_map: &'static dyn Fn(&Namespace) -> String,
+    pub(self) int64: TsInt64Strategy,
+    pub(self) int64_shapes: Option<Int64Shapes>,
+    pub(self) branded_types: bool,
 }
 
 unsafe impl Send for TsIndexJsTemplate<'_> { }
@@ -119,40 +125,50 @@ impl Generator for TSGenerator {
 
     async fn generate_package_files(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
         generator.ensure_root_directory().await?;
-        generator.generate_file(".gitignore", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/client/ts/gitignore"))).await?;
-        generator.generate_file("tsconfig.json", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/client/ts/tsconfig.json"))).await?;
-        generator.generate_file("README.md", TsReadMeTemplate { conf: ctx.conf }.render().unwrap()).await?;
+        generator.generate_file(".gitignore", ctx.templates.render_static("client/ts/gitignore", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/client/ts/gitignore")))?).await?;
+        generator.generate_file("tsconfig.json", ctx.templates.render_static("client/ts/tsconfig.json", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/client/ts/tsconfig.json")))?).await?;
+        generator.generate_file("README.md", ctx.templates.render("client/ts/readme.md.jinja", &TsReadMeTemplate { conf: ctx.conf })?).await?;
         if generator.generate_file_if_not_exist("package.json", generate_package_json(generator.get_base_dir())).await? {
-            // if exists, update package.json with a minor version
-            let json_data = std::fs::read_to_string(generator.get_file_path("package.json"))
-                .expect("Unable to read package.json");
-            generator.generate_file("package.json", update_package_json_version(json_data)).await?;
+            // if exists, bump the version by the API changes
+            let path = generator.get_file_path("package.json");
+            generator.generate_file("package.json", update_package_json_version(read_file(&path)?, ctx.changes.bump(), &path)?).await?;
         }
         Ok(())
     }
 
     async fn update_parent_package_files(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
         if let Some(package_json) = generator.find_file_upwards("package.json") {
-            let json_data = std::fs::read_to_string(&package_json).expect("Unable to read package.json");
-            let updated_json_data = updated_package_json_for_existing_project(json_data);
+            let json_data = read_file(&package_json)?;
+            let updated_json_data = updated_package_json_for_existing_project(json_data, &package_json)?;
             fs::write(package_json, updated_json_data).await?;
         }
         Ok(())
     }
 
     async fn generate_main(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
-        generator.generate_file("index.d.ts", TsIndexDTsTemplate {
+        generator.generate_file("index.d.ts", ctx.templates.render("shared/ts/index.d.ts.jinja", &TsIndexDTsTemplate {
             main_namespace: ctx.main_namespace,
-            conf: &TsConf::new(ctx.conf.object_name.clone(), ctx.conf.class_name(), true),
+            conf: &TsConf::new(ctx.conf.object_name.clone(), ctx.conf.class_name(), true, ctx.options.ts.clone(), ctx.templates.clone(), ctx.naming().clone()),
             render_namespace: &render_namespace,
             mode: Mode::Client,
-        }.render().unwrap()).await?;
-        generator.generate_file("index.js", TsIndexJsTemplate {
+        })?).await?;
+        generator.generate_file("index.js", ctx.templates.render("client/ts/index.js.jinja", &TsIndexJsTemplate {
             main_namespace: ctx.main_namespace,
             conf: ctx.conf,
             group_delegate_map: &group_delegate_map,
             custom_handler_map: &custom_handler_map,
-        }.render().unwrap()).await?;
+            int64: ctx.options.ts.int64,
+            int64_shapes: if ctx.options.ts.int64.is_number() { None } else { Some(Int64Shapes::new(ctx.main_namespace)?) },
+            branded_types: ctx.options.ts.branded_types,
+        })?).await?;
         Ok(())
     }
+
+    fn package_version(&self, generator: &FileUtil) -> Option<String> {
+        package_json_version(&std::fs::read_to_string(generator.get_file_path("package.json")).ok()?)
+    }
+
+    fn check_naming(&self, naming: &Naming, _main_namespace: &Namespace) -> teo_result::Result<()> {
+        check_naming(naming, "the TypeScript client")
+    }
 }
@@ -0,0 +1,266 @@
+use std::collections::{BTreeMap, BTreeSet};
+use serde_json::Value;
+use teo_result::Result;
+use teo_runtime::namespace::Namespace;
+use crate::naming::Naming;
+use crate::outline::json::{members, path_string, string, type_json};
+use crate::outline::outline::{Mode, Outline};
+
+/// Generic interfaces are expanded at their uses, this bounds the expansion of
+/// one that is used within itself.
+const MAX_GENERIC_DEPTH: usize = 8;
+
+/// Where the `Int64` values are in a type. The client encodes and decodes
+/// them by these rather than by their JSON values, which can't tell an
+/// `Int64` from another number, or from a string.
+///
+/// In `index.js`, `1` is an `Int64`, `"Name"` refers to the shape of an
+/// interface, `[shape]` is an array, `{ "*": shape }` a dictionary,
+/// `{ "|": [shapes] }` a union and other objects list the fields which have
+/// `Int64` values.
+#[derive(Clone, PartialEq)]
+enum Shape {
+    Int64,
+    Array(Box<Shape>),
+    Dictionary(Box<Shape>),
+    Object(BTreeMap<String, Shape>),
+    Reference(String),
+    Union(Vec<Shape>),
+}
+
+struct Declaration {
+    generic_names: Vec<String>,
+    extends: Vec<Value>,
+    fields: Vec<(String, Value)>,
+}
+
+struct HandlerShapes {
+    input: Option<Shape>,
+    output: Option<Shape>,
+}
+
+/// The shapes of the interfaces with `Int64` values and of the handlers' input
+/// and output, as the `int64Shapes` and `int64Handlers` maps of `index.js`.
+pub(super) struct Int64Shapes {
+    interfaces: BTreeMap<String, Shape>,
+    handlers: BTreeMap<String, HandlerShapes>,
+}
+
+impl Int64Shapes {
+
+    pub(super) fn new(main_namespace: &Namespace) -> Result<Self> {
+        let mut declarations = BTreeMap::new();
+        let mut handler_types = BTreeMap::new();
+        collect_namespace(main_namespace, main_namespace, &mut declarations, &mut handler_types)?;
+        let builder = Builder { declarations };
+        let raw: BTreeMap<String, Shape> = builder.declarations.iter()
+            .filter(|(_, declaration)| declaration.generic_names.is_empty())
+            .filter_map(|(path, _)| builder.interface_shape(path, &BTreeMap::new(), 0).map(|shape| (path.clone(), shape)))
+            .collect();
+        let useful = useful_references(&raw);
+        let interfaces = raw.iter()
+            .filter_map(|(path, shape)| prune(shape, &useful).map(|shape| (path.clone(), shape)))
+            .collect();
+        let handlers = handler_types.into_iter().filter_map(|(key, (input, output))| {
+            let input = builder.shape(&input, &BTreeMap::new(), 0).and_then(|shape| prune(&shape, &useful));
+            let output = builder.shape(&output, &BTreeMap::new(), 0).and_then(|shape| prune(&shape, &useful));
+            if input.is_none() && output.is_none() {
+                None
+            } else {
+                Some((key, HandlerShapes { input, output }))
+            }
+        }).collect();
+        Ok(Self { interfaces, handlers })
+    }
+
+    pub(super) fn interfaces_map(&self) -> String {
+        let entries: Vec<String> = self.interfaces.iter().map(|(path, shape)| {
+            format!("    {}: {}", json_string(path), shape_js(shape))
+        }).collect();
+        js_map(entries)
+    }
+
+    pub(super) fn handlers_map(&self) -> String {
+        let entries: Vec<String> = self.handlers.iter().map(|(key, shapes)| {
+            let mut parts = vec![];
+            if let Some(input) = &shapes.input {
+                parts.push(format!("input: {}", shape_js(input)));
+            }
+            if let Some(output) = &shapes.output {
+                parts.push(format!("output: {}", shape_js(output)));
+            }
+            format!("    {}: {{ {} }}", json_string(key), parts.join(", "))
+        }).collect();
+        js_map(entries)
+    }
+}
+
+fn collect_namespace(namespace: &Namespace, main_namespace: &Namespace, declarations: &mut BTreeMap<String, Declaration>, handler_types: &mut BTreeMap<String, (Value, Value)>) -> Result<()> {
+    // the names on the wire are the schema's, whatever a generator's naming
+    let outline = Outline::new(namespace, Mode::Client, main_namespace, false, &Naming::default())?;
+    for interface in outline.interfaces() {
+        declarations.insert(interface.path().join("."), Declaration {
+            generic_names: interface.generic_names().clone(),
+            extends: interface.extends().iter().map(|t| type_json(t, Mode::Client)).collect::<Result<Vec<Value>>>()?,
+            fields: interface.fields().iter().map(|field| Ok((field.name().to_owned(), type_json(field.r#type(), Mode::Client)?))).collect::<Result<Vec<(String, Value)>>>()?,
+        });
+    }
+    // the namespace delegate is the last, its handlers are the namespace's own
+    if let Some((namespace_delegate, delegates)) = outline.delegates().split_last() {
+        for delegate in delegates {
+            for item in delegate.request_items() {
+                let key = namespace.path().iter().map(String::as_str).chain([delegate.schema_name(), item.name()]).collect::<Vec<&str>>().join(".");
+                handler_types.insert(key, (type_json(item.input_type(), Mode::Client)?, type_json(item.output_type(), Mode::Client)?));
+            }
+        }
+        for item in namespace_delegate.request_items() {
+            let key = namespace.path().iter().map(String::as_str).chain([item.name()]).collect::<Vec<&str>>().join(".");
+            handler_types.insert(key, (type_json(item.input_type(), Mode::Client)?, type_json(item.output_type(), Mode::Client)?));
+        }
+    }
+    for child in namespace.namespaces().values() {
+        collect_namespace(child, main_namespace, declarations, handler_types)?;
+    }
+    Ok(())
+}
+
+struct Builder {
+    declarations: BTreeMap<String, Declaration>,
+}
+
+impl Builder {
+
+    fn shape(&self, t: &Value, arguments: &BTreeMap<String, Option<Shape>>, depth: usize) -> Option<Shape> {
+        match t["kind"].as_str().unwrap_or_default() {
+            "int64" => Some(Shape::Int64),
+            "optional" => self.shape(&t["inner"], arguments, depth),
+            "array" => self.shape(&t["inner"], arguments, depth).map(|inner| Shape::Array(Box::new(inner))),
+            "dictionary" => self.shape(&t["inner"], arguments, depth).map(|inner| Shape::Dictionary(Box::new(inner))),
+            "enumerable" => self.shape(&t["inner"], arguments, depth).and_then(|inner| union(vec![inner.clone(), Shape::Array(Box::new(inner))])),
+            "tuple" => union(members(t).iter().filter_map(|member| self.shape(member, arguments, depth)).collect()).map(|inner| Shape::Array(Box::new(inner))),
+            "union" => union(members(t).iter().filter_map(|member| self.shape(member, arguments, depth)).collect()),
+            "generic" => arguments.get(&string(&t["name"])).cloned().flatten(),
+            "model" => Some(Shape::Reference(path_string(&t["path"]))),
+            "interface" => {
+                let path = path_string(&t["path"]);
+                let type_arguments = t["arguments"].as_array().cloned().unwrap_or_default();
+                if type_arguments.is_empty() {
+                    Some(Shape::Reference(path))
+                } else if depth < MAX_GENERIC_DEPTH {
+                    let declaration = self.declarations.get(&path)?;
+                    let arguments = declaration.generic_names.iter().cloned()
+                        .zip(type_arguments.iter().map(|argument| self.shape(argument, arguments, depth)))
+                        .collect();
+                    self.interface_shape(&path, &arguments, depth + 1)
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn interface_shape(&self, path: &str, arguments: &BTreeMap<String, Option<Shape>>, depth: usize) -> Option<Shape> {
+        let declaration = self.declarations.get(path)?;
+        let fields: BTreeMap<String, Shape> = declaration.fields.iter()
+            .filter_map(|(name, t)| self.shape(t, arguments, depth).map(|shape| (name.clone(), shape)))
+            .collect();
+        let mut shapes: Vec<Shape> = declaration.extends.iter().filter_map(|t| self.shape(t, arguments, depth)).collect();
+        if !fields.is_empty() {
+            shapes.push(Shape::Object(fields));
+        }
+        union(shapes)
+    }
+}
+
+/// The interfaces whose shape has an `Int64` somewhere, directly or through
+/// the interfaces it refers to.
+fn useful_references(shapes: &BTreeMap<String, Shape>) -> BTreeSet<String> {
+    let mut useful = BTreeSet::new();
+    loop {
+        let found: Vec<String> = shapes.iter()
+            .filter(|(path, shape)| !useful.contains(*path) && prune(shape, &useful).is_some())
+            .map(|(path, _)| path.clone())
+            .collect();
+        if found.is_empty() {
+            return useful;
+        }
+        useful.extend(found);
+    }
+}
+
+/// The shape without the references to interfaces which have no `Int64`.
+fn prune(shape: &Shape, useful: &BTreeSet<String>) -> Option<Shape> {
+    match shape {
+        Shape::Int64 => Some(Shape::Int64),
+        Shape::Array(inner) => prune(inner, useful).map(|inner| Shape::Array(Box::new(inner))),
+        Shape::Dictionary(inner) => prune(inner, useful).map(|inner| Shape::Dictionary(Box::new(inner))),
+        Shape::Object(fields) => {
+            let fields: BTreeMap<String, Shape> = fields.iter().filter_map(|(name, shape)| prune(shape, useful).map(|shape| (name.clone(), shape))).collect();
+            if fields.is_empty() { None } else { Some(Shape::Object(fields)) }
+        },
+        Shape::Reference(path) => useful.contains(path).then(|| Shape::Reference(path.clone())),
+        Shape::Union(shapes) => union(shapes.iter().filter_map(|shape| prune(shape, useful)).collect()),
+    }
+}
+
+/// One shape for all of `shapes`, with the arrays, dictionaries and objects
+/// among them merged, since a value can only be one of these.
+fn union(shapes: Vec<Shape>) -> Option<Shape> {
+    let mut flattened = vec![];
+    for shape in shapes {
+        match shape {
+            Shape::Union(inner) => flattened.extend(inner),
+            shape => flattened.push(shape),
+        }
+    }
+    let mut result = vec![];
+    let mut elements = vec![];
+    let mut values = vec![];
+    let mut fields: BTreeMap<String, Vec<Shape>> = BTreeMap::new();
+    for shape in flattened {
+        match shape {
+            Shape::Array(inner) => elements.push(*inner),
+            Shape::Dictionary(inner) => values.push(*inner),
+            Shape::Object(object) => for (name, shape) in object {
+                fields.entry(name).or_default().push(shape);
+            },
+            shape => if !result.contains(&shape) {
+                result.push(shape);
+            },
+        }
+    }
+    if !elements.is_empty() {
+        result.extend(union(elements).map(|inner| Shape::Array(Box::new(inner))));
+    }
+    if !values.is_empty() {
+        result.extend(union(values).map(|inner| Shape::Dictionary(Box::new(inner))));
+    }
+    if !fields.is_empty() {
+        result.push(Shape::Object(fields.into_iter().filter_map(|(name, shapes)| union(shapes).map(|shape| (name, shape))).collect()));
+    }
+    match result.len() {
+        0 => None,
+        1 => result.pop(),
+        _ => Some(Shape::Union(result)),
+    }
+}
+
+fn shape_js(shape: &Shape) -> String {
+    match shape {
+        Shape::Int64 => "1".to_owned(),
+        Shape::Array(inner) => format!("[{}]", shape_js(inner)),
+        Shape::Dictionary(inner) => format!("{{ \"*\": {} }}", shape_js(inner)),
+        Shape::Object(fields) => format!("{{ {} }}", fields.iter().map(|(name, shape)| format!("{}: {}", json_string(name), shape_js(shape))).collect::<Vec<String>>().join(", ")),
+        Shape::Reference(path) => json_string(path),
+        Shape::Union(shapes) => format!("{{ \"|\": [{}] }}", shapes.iter().map(shape_js).collect::<Vec<String>>().join(", ")),
+    }
+}
+
+fn json_string(value: &str) -> String {
+    Value::String(value.to_owned()).to_string()
+}
+
+fn js_map(entries: Vec<String>) -> String {
+    if entries.is_empty() { "{}".to_string() } else { "{\n".to_owned() + &entries.join(",\n") + "\n}" }
+}
@@ -1,2 +1,3 @@
 pub(in crate::client) mod gen;
+pub(in crate::client) mod int64_shapes;
 pub(in crate::client) mod package_json;
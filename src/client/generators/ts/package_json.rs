@@ -1,6 +1,8 @@
 use std::path::Path;
 use inflector::Inflector;
 use serde_json::{json, Value};
+use teo_result::{Error, Result};
+use crate::utils::update_package_json_version::parse_package_json;
 
 pub(crate) fn generate_package_json(path: &Path) -> String {
     let name = path.file_name().unwrap().to_str().unwrap().to_kebab_case();
@@ -25,16 +27,18 @@ pub(crate) fn generate_package_json(path: &Path) -> String {
     serde_json::to_string_pretty(&json).unwrap() + "\n"
 }
 
-pub(crate) fn updated_package_json_for_existing_project(content: String) -> String {
-    let mut json_value: Value = serde_json::from_str(&content).unwrap();
-    if let Some(dependencies) = json_value.get_mut("dependencies") {
-        if dependencies.get("decimal.js").is_none() {
-            dependencies.as_object_mut().unwrap().insert("decimal.js".to_owned(), Value::String("^10.4.3".to_owned()));
-        }
-    } else {
-        json_value.as_object_mut().unwrap().insert("dependencies".to_owned(), json!({
-            "decimal.js": "^10.4.3"
-        }));
+pub(crate) fn updated_package_json_for_existing_project(content: String, path: &Path) -> Result<String> {
+    let mut json_value = parse_package_json(&content, path)?;
+    match json_value.get_mut("dependencies") {
+        Some(Value::Object(dependencies)) => if dependencies.get("decimal.js").is_none() {
+            dependencies.insert("decimal.js".to_owned(), Value::String("^10.4.3".to_owned()));
+        },
+        Some(_) => Err(Error::new(format!("`dependencies' of `{}' isn't an object", path.display())))?,
+        None => {
+            json_value.insert("dependencies".to_owned(), json!({
+                "decimal.js": "^10.4.3"
+            }));
+        },
     }
-    serde_json::to_string_pretty(&json_value).unwrap() + "\n"
+    Ok(serde_json::to_string_pretty(&json_value).unwrap() + "\n")
 }
\ No newline at end of file
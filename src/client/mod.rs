@@ -1,86 +1,103 @@
 mod generators;
 mod ctx;
 mod generator;
+mod options;
+mod registry;
+mod release;
 
-use std::path::Path;
-use std::process::Command;
-use teo_result::Result;
-use teo_runtime::config::client::{Client, ClientLanguage};
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+use teo_result::{Error, Result};
+use teo_runtime::config::client::Client;
 use teo_runtime::namespace::Namespace;
-use generators::*;
-use crate::client::ctx::Ctx;
-use crate::client::generator::Generator;
+use crate::client::registry::builtin_generator_name;
+use crate::client::release::Release;
 use crate::utils::file::FileUtil;
 use crate::utils::message::green_message;
 
+pub use ctx::Ctx;
+pub use generator::Generator;
+pub use options::{ClientOptions, DartOptions, DocsFormat, DocsOptions, TsOptions, TsInt64Strategy};
+pub use registry::{register_generator, registered_generator, registered_generator_names};
+
 pub async fn generate(main_namespace: &Namespace, client: &Client) -> Result<()> {
-    match client.provider {
-        ClientLanguage::TypeScript(_) => {
-            let ctx = Ctx::new(client, main_namespace);
-            let generator = ts::gen::TSGenerator::new();
-            gen(generator, &ctx).await
-        }
-        ClientLanguage::Swift => {
-            let ctx = Ctx::new(client, main_namespace);
-            let generator = swift::gen::SwiftGenerator::new();
-            gen(generator, &ctx).await
-        }
-        ClientLanguage::Kotlin => {
-            let ctx = Ctx::new(client, main_namespace);
-            let generator = kotlin::gen::KotlinGenerator::new();
-            gen(generator, &ctx).await
-        }
-        ClientLanguage::CSharp => {
-            let ctx = Ctx::new(client, main_namespace);
-            let generator = csharp::gen::CSharpGenerator::new();
-            gen(generator, &ctx).await
-        }
-        ClientLanguage::Dart => {
-            let ctx = Ctx::new(client, main_namespace);
-            let generator = dart::gen::DartGenerator::new();
-            gen(generator, &ctx).await
-        }
-    }?;
-    std::process::exit(0);
+    generate_with_options(main_namespace, client, &ClientOptions::default()).await
+}
+
+/// Generate a client with the generator registered under the name of the
+/// built-in one for `client.provider`. The schema can only select the
+/// built-in names, so a generator registered under another name runs
+/// through [`generate_with_generator`], or replaces a built-in one by
+/// registering under its name.
+pub async fn generate_with_options(main_namespace: &Namespace, client: &Client, options: &ClientOptions) -> Result<()> {
+    generate_with_generator(main_namespace, builtin_generator_name(&client.provider), client, options).await
+}
+
+/// Generate a client with the generator registered as `name`. The provider
+/// in `client` is ignored.
+pub async fn generate_with_generator(main_namespace: &Namespace, name: &str, client: &Client, options: &ClientOptions) -> Result<()> {
+    let Some(generator) = registered_generator(name) else {
+        return Err(Error::new(format!("client generator `{}' is not registered", name)));
+    };
+    let naming = options.naming.get(name).cloned().unwrap_or_else(|| generator.naming());
+    generator.check_naming(&naming, main_namespace)?;
+    let release = if client.package {
+        Some(Release::prepare(main_namespace, &FileUtil::new(dest_dir(client)?))?)
+    } else {
+        None
+    };
+    let changes = release.as_ref().map(|r| r.changes().clone()).unwrap_or_default();
+    let ctx = Ctx::new(client, main_namespace, options, changes, naming)?;
+    gen(generator.as_ref(), &ctx, release.as_ref()).await
 }
 
-async fn gen<T: Generator>(client_generator: T, ctx: &Ctx<'_>) -> Result<()> {
-    let dest_dir = std::env::current_dir()?.join(&ctx.conf.dest);
+fn dest_dir(client: &Client) -> Result<PathBuf> {
+    Ok(std::env::current_dir()?.join(&client.dest))
+}
+
+async fn gen(client_generator: &dyn Generator, ctx: &Ctx<'_>, release: Option<&Release>) -> Result<()> {
+    let dest_dir = dest_dir(ctx.conf)?;
     let package = ctx.conf.package;
     let git_commit = ctx.conf.git_commit;
     let mut module_dest = dest_dir.clone();
     let should_git_init = !dest_dir.exists();
+    let package_generator = FileUtil::new(dest_dir.clone());
     if package {
-        let package_generator = FileUtil::new(dest_dir.clone());
         client_generator.generate_package_files(ctx, &package_generator).await?;
         module_dest.push(Path::new(client_generator.module_directory_in_package(ctx.conf).as_str()));
     } else {
-        let package_generator = FileUtil::new(dest_dir.clone());
         client_generator.update_parent_package_files(ctx, &package_generator).await?;
     }
-    let module_generator = FileUtil::new(module_dest);
+    let module_generator = package_generator.with_base_dir(module_dest);
     client_generator.generate_module_files(ctx, &module_generator).await?;
     client_generator.generate_main(ctx, &module_generator).await?;
+    if let Some(release) = release {
+        release.record(&package_generator, client_generator.package_version(&package_generator)).await?;
+    }
+    package_generator.remove_stale_files().await?;
+    client_generator.run_commands(ctx, &module_generator).await?;
     if git_commit && package {
-        std::env::set_current_dir(&dest_dir).unwrap();
         if should_git_init {
             // git init
             Command::new("git")
                 .arg("init")
-                .output().unwrap();
+                .current_dir(&dest_dir)
+                .output().await?;
             green_message("Run", "`git init`".to_string());
         }
         // git add -A
         Command::new("git")
             .arg("add")
             .arg("-A")
-            .output().unwrap();
+            .current_dir(&dest_dir)
+            .output().await?;
         green_message("Run", "`git add -A`".to_string());
         Command::new("git")
             .arg("commit")
             .arg("-m")
             .arg("generated by teo")
-            .output().unwrap();
+            .current_dir(&dest_dir)
+            .output().await?;
         // git commit
         green_message("Run", "`git commit -m 'generated by teo'`".to_string());
     }
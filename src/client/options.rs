@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use teo_runtime::config::client::ClientLanguage;
+use crate::naming::Naming;
+
+/// # Client options
+///
+/// Generator specific options which are not part of the schema's `client` config.
+#[derive(Clone, Default)]
+pub struct ClientOptions {
+    pub dart: DartOptions,
+    pub ts: TsOptions,
+    pub docs: DocsOptions,
+    /// A directory of templates overriding the built-in ones at the same
+    /// relative path, e.g. `client/swift/teo.swift.jinja`.
+    pub templates: Option<PathBuf>,
+    /// Naming conventions replacing the generator's own, by the name the
+    /// generator is registered as, e.g. `"kotlin"`.
+    pub naming: BTreeMap<String, Naming>,
+}
+
+#[derive(Clone, Default)]
+pub struct DartOptions {
+    /// Generate immutable classes with `copyWith`, value equality and `hashCode`.
+    /// Union types become sealed classes, which requires Dart 3.
+    pub immutable: bool,
+}
+
+#[derive(Clone, Default)]
+pub struct TsOptions {
+    /// How `Int64` values are represented in the TypeScript client.
+    pub int64: TsInt64Strategy,
+    /// Declare `ObjectId` and `DateOnly` as branded strings instead of plain `string`.
+    pub branded_types: bool,
+    /// Brand the primary key fields of model types, so `User['id']` is not
+    /// assignable to another model's id.
+    pub branded_model_ids: bool,
+}
+
+#[derive(Clone, Default)]
+pub struct DocsOptions {
+    pub format: DocsFormat,
+    /// The clients usage snippets are shown for. When empty, snippets are
+    /// shown for the provider of the client config the docs are generated with.
+    /// Each snippet calls handlers by the identifiers of its client's naming,
+    /// [`ClientOptions::naming`] or the client's own.
+    pub languages: Vec<ClientLanguage>,
+}
+
+/// # Docs format
+///
+/// A single `reference.md`, or a static `index.html` which needs no build step.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum DocsFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+
+/// # TypeScript Int64 strategy
+///
+/// JavaScript numbers lose precision beyond 2^53. With `BigInt` or `String`,
+/// every `Int64` is a `bigint` or a `string`. The client finds them by the
+/// schema types of each handler's input and output, and sends them as plain
+/// JSON integers.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum TsInt64Strategy {
+    #[default]
+    Number,
+    BigInt,
+    String,
+}
+
+impl TsInt64Strategy {
+
+    pub fn is_number(&self) -> bool {
+        *self == TsInt64Strategy::Number
+    }
+
+    pub fn is_bigint(&self) -> bool {
+        *self == TsInt64Strategy::BigInt
+    }
+
+    pub fn is_string(&self) -> bool {
+        *self == TsInt64Strategy::String
+    }
+}
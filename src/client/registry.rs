@@ -0,0 +1,44 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+use once_cell::sync::Lazy;
+use teo_runtime::config::client::ClientLanguage;
+use crate::client::generator::Generator;
+use crate::client::generators::*;
+
+static GENERATORS: Lazy<RwLock<BTreeMap<String, Arc<dyn Generator>>>> = Lazy::new(|| {
+    let mut generators: BTreeMap<String, Arc<dyn Generator>> = BTreeMap::new();
+    generators.insert("typescript".to_owned(), Arc::new(ts::gen::TSGenerator::new()));
+    generators.insert("swift".to_owned(), Arc::new(swift::gen::SwiftGenerator::new()));
+    generators.insert("kotlin".to_owned(), Arc::new(kotlin::gen::KotlinGenerator::new()));
+    generators.insert("csharp".to_owned(), Arc::new(csharp::gen::CSharpGenerator::new()));
+    generators.insert("dart".to_owned(), Arc::new(dart::gen::DartGenerator::new()));
+    generators.insert("ir".to_owned(), Arc::new(ir::gen::IrGenerator::new()));
+    generators.insert("docs".to_owned(), Arc::new(docs::gen::DocsGenerator::new()));
+    RwLock::new(generators)
+});
+
+/// Register `generator` as `name`. A generator registered with a built-in
+/// name replaces the built-in one.
+pub fn register_generator(name: impl Into<String>, generator: impl Generator + 'static) {
+    GENERATORS.write().unwrap().insert(name.into(), Arc::new(generator));
+}
+
+pub fn registered_generator(name: &str) -> Option<Arc<dyn Generator>> {
+    GENERATORS.read().unwrap().get(name).cloned()
+}
+
+/// The names of the built-in and registered generators, sorted.
+pub fn registered_generator_names() -> Vec<String> {
+    GENERATORS.read().unwrap().keys().cloned().collect()
+}
+
+/// The name the built-in generator for `language` is registered as.
+pub(in crate::client) fn builtin_generator_name(language: &ClientLanguage) -> &'static str {
+    match language {
+        ClientLanguage::TypeScript(_) => "typescript",
+        ClientLanguage::Swift => "swift",
+        ClientLanguage::Kotlin => "kotlin",
+        ClientLanguage::CSharp => "csharp",
+        ClientLanguage::Dart => "dart",
+    }
+}
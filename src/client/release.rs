@@ -0,0 +1,75 @@
+use serde_json::Value;
+use teo_result::{Error, Result};
+use teo_runtime::namespace::Namespace;
+use crate::outline::changes::{compare_outline_documents, ApiChanges};
+use crate::outline::json::outline_document;
+use crate::outline::outline::Mode;
+use crate::utils::file::FileUtil;
+use crate::utils::message::yellow_message;
+
+/// Where a client package records the API it was last generated with.
+const SNAPSHOT_FILE: &str = ".teo/outline.json";
+
+const CHANGELOG_FILE: &str = "CHANGELOG.md";
+
+const CHANGELOG_HEADER: &str = "# Changelog\n";
+
+/// # Release
+///
+/// The API of a client package, and how it changed since the previous
+/// generation recorded in the package.
+pub(super) struct Release {
+    document: Option<Value>,
+    changes: ApiChanges,
+}
+
+impl Release {
+
+    /// Compare the API of `main_namespace` with the snapshot in the package.
+    /// Without a snapshot there are no changes, and the version gets a patch
+    /// bump like before snapshots were recorded.
+    pub(super) fn prepare(main_namespace: &Namespace, package: &FileUtil) -> Result<Self> {
+        let document = match outline_document(main_namespace, Mode::Client) {
+            Ok(document) => document,
+            Err(e) => {
+                yellow_message("skip", format!("API change detection: {}", e));
+                return Ok(Self { document: None, changes: ApiChanges::default() });
+            },
+        };
+        let snapshot = package.get_file_path(SNAPSHOT_FILE);
+        let changes = if snapshot.exists() {
+            let content = std::fs::read_to_string(&snapshot)?;
+            let previous: Value = serde_json::from_str(&content).map_err(|e| Error::new(format!("`{}' has invalid content: {}, delete it to start over", snapshot.display(), e)))?;
+            compare_outline_documents(&previous, &document)
+        } else {
+            ApiChanges::default()
+        };
+        Ok(Self { document: Some(document), changes })
+    }
+
+    pub(super) fn changes(&self) -> &ApiChanges {
+        &self.changes
+    }
+
+    /// Save the snapshot for the next generation, and add the changes to the
+    /// changelog under `version`.
+    pub(super) async fn record(&self, package: &FileUtil, version: Option<String>) -> Result<()> {
+        let Some(document) = &self.document else {
+            return Ok(());
+        };
+        if !self.changes.is_empty() {
+            let changelog = package.get_file_path(CHANGELOG_FILE);
+            let previous = if changelog.exists() { std::fs::read_to_string(&changelog)? } else { String::new() };
+            let entries = previous.strip_prefix(CHANGELOG_HEADER).unwrap_or(previous.as_str()).trim_start();
+            let entry = self.changes.changelog_entry(version.as_deref());
+            let content = if entries.is_empty() {
+                format!("{}\n{}", CHANGELOG_HEADER, entry)
+            } else {
+                format!("{}\n{}\n{}", CHANGELOG_HEADER, entry, entries)
+            };
+            package.generate_file(CHANGELOG_FILE, content).await?;
+        }
+        package.ensure_directory(".teo").await?;
+        package.generate_file(SNAPSHOT_FILE, serde_json::to_string_pretty(document).unwrap() + "\n").await
+    }
+}
@@ -1,18 +1,48 @@
+use teo_result::Result;
 use teo_runtime::config::entity::Entity;
 use teo_runtime::namespace::Namespace;
-use crate::outline::outline::Outline;
+use crate::entity::options::EntityOptions;
+use crate::naming::Naming;
+use crate::utils::templates::TemplateOverrides;
 
-pub(crate) struct Ctx<'a> {
+pub struct Ctx<'a> {
     pub(crate) conf: &'a Entity,
     pub(crate) main_namespace: &'a Namespace,
+    pub(crate) options: &'a EntityOptions,
+    pub(crate) templates: TemplateOverrides,
+    pub(crate) naming: Naming,
 }
 
 impl<'a> Ctx<'a> {
 
-    pub(crate) fn new(conf: &'a Entity, main_namespace: &'a Namespace) -> Self {
-        Self {
+    pub(crate) fn new(conf: &'a Entity, main_namespace: &'a Namespace, options: &'a EntityOptions, naming: Naming) -> Result<Self> {
+        Ok(Self {
             main_namespace,
             conf,
-        }
+            options,
+            templates: TemplateOverrides::for_entity(options.templates.as_deref(), conf, main_namespace)?,
+            naming,
+        })
+    }
+
+    pub fn conf(&self) -> &'a Entity {
+        self.conf
+    }
+
+    pub fn main_namespace(&self) -> &'a Namespace {
+        self.main_namespace
+    }
+
+    pub fn options(&self) -> &'a EntityOptions {
+        self.options
+    }
+
+    pub fn naming(&self) -> &Naming {
+        &self.naming
+    }
+
+    /// The template overrides of the configured template directory.
+    pub fn templates(&self) -> &TemplateOverrides {
+        &self.templates
     }
 }
@@ -1,10 +1,30 @@
 use async_trait::async_trait;
 use teo_result::Result;
+use teo_runtime::namespace::Namespace;
 use crate::entity::ctx::Ctx;
+use crate::naming::Naming;
 use crate::utils::file::FileUtil;
 
+/// # Entity generator
+///
+/// Implemented by every entity target, built-in or registered by another
+/// crate with [`register_generator`](crate::entity::register_generator).
+/// `generator` points at the destination, which already exists.
 #[async_trait]
-pub(crate) trait Generator {
+pub trait Generator: Send + Sync {
 
     async fn generate_entity_files(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()>;
+
+    /// The naming conventions of the entities, unless
+    /// [`EntityOptions::naming`](crate::entity::EntityOptions::naming) has
+    /// others for them.
+    fn naming(&self) -> Naming {
+        Naming::default()
+    }
+
+    /// Reject a naming the entities can't map back to the schema's names,
+    /// before anything is written.
+    fn check_naming(&self, _naming: &Naming, _main_namespace: &Namespace) -> Result<()> {
+        Ok(())
+    }
 }
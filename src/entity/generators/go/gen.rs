@@ -0,0 +1,296 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use askama::Template;
+use async_recursion::async_recursion;
+use async_trait::async_trait;
+use inflector::Inflector;
+use teo_parser::r#type::Type;
+use teo_result::{Error, Result};
+use teo_runtime::namespace::Namespace;
+use teo_runtime::model::field::typed::Typed;
+use teo_runtime::traits::named::Named;
+use teo_runtime::traits::documentable::Documentable;
+use tokio::fs;
+use crate::entity::ctx::Ctx;
+use crate::entity::generator::Generator;
+use crate::entity::generators::go::lookup::{collect_references, lookup, package_alias, package_name, qualify};
+use crate::naming::{Case, Naming};
+use crate::outline::outline::{Mode, Outline};
+use crate::utils::file::FileUtil;
+use crate::utils::filters;
+use crate::utils::templates::TemplateOverrides;
+
+fn go_field_name(name: &str) -> String {
+    let name = name.to_pascal_case();
+    if name.is_empty() { "Value".to_owned() } else { name }
+}
+
+fn generics_declaration(names: &Vec<String>) -> String {
+    if names.is_empty() {
+        "".to_owned()
+    } else {
+        "[".to_owned() + &names.iter().map(|n| format!("{} any", n)).collect::<Vec<String>>().join(", ") + "]"
+    }
+}
+
+fn json_tag(name: &str, optional: bool) -> String {
+    if optional {
+        format!("`json:\"{},omitempty\"`", name)
+    } else {
+        format!("`json:\"{}\"`", name)
+    }
+}
+
+/// The argument type of a model method, falling back to `any` when the
+/// model doesn't synthesize the shape.
+fn args_type(outline: &Outline, model_name: &str, suffix: &str) -> String {
+    if outline.interfaces().iter().any(|interface| interface.model_name() == model_name && interface.name().strip_prefix(model_name) == Some(suffix)) {
+        format!("{}{}", model_name, suffix)
+    } else {
+        "any".to_owned()
+    }
+}
+
+fn wrap_fn(model_path: &Vec<String>, namespace_path: &Vec<String>) -> String {
+    let mut components = model_path.clone();
+    let name = components.pop().unwrap();
+    components.push(format!("Wrap{}", name));
+    qualify(&components.join("."), namespace_path)
+}
+
+fn model_path_literal(path: &Vec<String>) -> String {
+    format!("[]string{{{}}}", path.iter().map(|p| format!("\"{}\"", p)).collect::<Vec<String>>().join(", "))
+}
+
+#[derive(Template)]
+#[template(path = "entity/go/package.go.jinja", escape = "none")]
+pub(self) struct GoPackageTemplate<'a> {
+    pub(self) namespace: &'a Namespace,
+    pub(self) outline: Outline,
+    pub(self) package_name: String,
+    pub(self) imports: Vec<(String, String)>,
+    pub(self) accessors: Vec<Vec<String>>,
+    pub(self) lookup: &'static dyn Fn(&Type, &Vec<String>) -> Result<String>,
+    pub(self) go_field_name: &'static dyn Fn(&str) -> String,
+    pub(self) generics_declaration: &'static dyn Fn(&Vec<String>) -> String,
+    pub(self) json_tag: &'static dyn Fn(&str, bool) -> String,
+    pub(self) args_type: &'static dyn Fn(&Outline, &str, &str) -> String,
+    pub(self) wrap_fn: &'static dyn Fn(&Vec<String>, &Vec<String>) -> String,
+    pub(self) model_path_literal: &'static dyn Fn(&Vec<String>) -> String,
+    pub(self) package_alias: &'static dyn Fn(&[String]) -> String,
+}
+
+unsafe impl Send for GoPackageTemplate<'_> { }
+unsafe impl Sync for GoPackageTemplate<'_> { }
+
+impl<'a> GoPackageTemplate<'a> {
+
+    fn new(namespace: &'a Namespace, outline: Outline, package: &GoPackage, main_package_name: &str, import_base: &str, runtime_module: &str) -> Self {
+        let mut imports = vec![];
+        if package.uses_time {
+            imports.push(("".to_owned(), "time".to_owned()));
+        }
+        imports.push(("teo".to_owned(), runtime_module.to_owned()));
+        for path in &package.imports {
+            let import_path = if path.is_empty() {
+                import_base.to_owned()
+            } else {
+                format!("{}/{}", import_base, path.iter().map(|p| package_name(std::slice::from_ref(p))).collect::<Vec<String>>().join("/"))
+            };
+            imports.push((package_alias(path), import_path));
+        }
+        Self {
+            namespace,
+            outline,
+            package_name: if namespace.is_main() { main_package_name.to_owned() } else { package_name(namespace.path()) },
+            imports,
+            accessors: package.accessors.clone(),
+            lookup: &lookup,
+            go_field_name: &go_field_name,
+            generics_declaration: &generics_declaration,
+            json_tag: &json_tag,
+            args_type: &args_type,
+            wrap_fn: &wrap_fn,
+            model_path_literal: &model_path_literal,
+            package_alias: &package_alias,
+        }
+    }
+}
+
+/// The packages a namespace's package imports and the child namespaces it
+/// has accessors for.
+struct GoPackage {
+    imports: BTreeSet<Vec<String>>,
+    accessors: Vec<Vec<String>>,
+    uses_time: bool,
+}
+
+impl GoPackage {
+
+    fn new(namespace: &Namespace, outline: &Outline) -> Result<Self> {
+        let mut imports = BTreeSet::new();
+        let mut uses_time = false;
+        for interface in outline.interfaces() {
+            for t in interface.extends() {
+                collect_references(t, &mut imports, &mut uses_time)?;
+            }
+            for field in interface.fields() {
+                collect_references(field.r#type(), &mut imports, &mut uses_time)?;
+            }
+        }
+        for model in namespace.models().values() {
+            if !model.generate_entity() {
+                continue;
+            }
+            for field in model.fields().values() {
+                collect_references(field.r#type(), &mut imports, &mut uses_time)?;
+            }
+            for relation in model.relations().values() {
+                collect_references(relation.r#type(), &mut imports, &mut uses_time)?;
+            }
+            for property in model.properties().values() {
+                if property.getter().is_some() || property.setter().is_some() {
+                    collect_references(property.r#type(), &mut imports, &mut uses_time)?;
+                }
+            }
+        }
+        imports.remove(namespace.path());
+        Ok(Self { imports, accessors: vec![], uses_time })
+    }
+}
+
+fn namespace_display(path: &Vec<String>) -> String {
+    if path.is_empty() { "main".to_owned() } else { path.join(".") }
+}
+
+fn imports_transitively(packages: &BTreeMap<Vec<String>, GoPackage>, from: &Vec<String>, to: &Vec<String>) -> bool {
+    let mut visited = BTreeSet::new();
+    let mut pending = vec![from];
+    while let Some(path) = pending.pop() {
+        if path == to {
+            return true;
+        }
+        if visited.insert(path) {
+            if let Some(package) = packages.get(path) {
+                pending.extend(package.imports.iter());
+            }
+        }
+    }
+    false
+}
+
+/// Go packages can't import each other, report the first pair of
+/// namespaces whose types refer to each other.
+fn check_import_cycles(packages: &BTreeMap<Vec<String>, GoPackage>) -> Result<()> {
+    for (path, package) in packages {
+        for import in &package.imports {
+            if imports_transitively(packages, import, path) {
+                return Err(Error::new(format!("namespaces `{}' and `{}' refer to each other's types, which forms a Go import cycle", namespace_display(path), namespace_display(import))));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Add accessors for the child namespaces which don't import their parent,
+/// directly or through other packages.
+fn add_accessors(namespace: &Namespace, packages: &mut BTreeMap<Vec<String>, GoPackage>) {
+    for child in namespace.namespaces().values() {
+        if child.is_std() {
+            continue;
+        }
+        if !imports_transitively(packages, child.path(), namespace.path()) {
+            let package = packages.get_mut(namespace.path()).unwrap();
+            package.imports.insert(child.path().clone());
+            package.accessors.push(child.path().clone());
+        }
+        add_accessors(child, packages);
+    }
+}
+
+/// # Go entity generator
+///
+/// Generates one Go package per namespace for services embedding the Teo
+/// runtime through its C ABI with the `teo-go` binding. A package only
+/// imports the namespaces its types refer to, so namespaces referring to
+/// each other's types are an error. Parents have accessors for the child
+/// namespaces which don't refer back to them.
+pub(crate) struct GoGenerator { }
+
+impl GoGenerator {
+
+    pub fn new() -> Self {
+        Self { }
+    }
+
+    /// The import path of the destination directory, derived from the
+    /// enclosing `go.mod`.
+    async fn import_base(&self, generator: &FileUtil, main_package_name: &str) -> Result<String> {
+        let go_mod = match generator.find_file_upwards("go.mod") {
+            Some(path) => path,
+            None => return Ok(main_package_name.to_owned()),
+        };
+        let content = fs::read_to_string(&go_mod).await?;
+        let module = content.lines().find_map(|line| line.trim().strip_prefix("module ").map(|m| m.trim().trim_matches('"').to_owned()));
+        let module = match module {
+            Some(module) => module,
+            None => return Ok(main_package_name.to_owned()),
+        };
+        let relative = pathdiff::diff_paths(generator.get_base_dir(), go_mod.parent().unwrap()).unwrap_or_default();
+        let relative = relative.components().map(|c| c.as_os_str().to_string_lossy().to_string()).collect::<Vec<String>>();
+        Ok(if relative.is_empty() { module } else { format!("{}/{}", module, relative.join("/")) })
+    }
+
+    fn collect_packages(&self, namespace: &Namespace, main_namespace: &Namespace, naming: &Naming, packages: &mut BTreeMap<Vec<String>, GoPackage>) -> Result<()> {
+        let outline = Outline::new(namespace, Mode::Entity, main_namespace, false, naming)?;
+        packages.insert(namespace.path().clone(), GoPackage::new(namespace, &outline)?);
+        for child in namespace.namespaces().values() {
+            self.collect_packages(child, main_namespace, naming, packages)?;
+        }
+        Ok(())
+    }
+
+    #[async_recursion]
+    async fn generate_package_for_namespace(&self, namespace: &Namespace, generator: &FileUtil, main_namespace: &Namespace, packages: &BTreeMap<Vec<String>, GoPackage>, main_package_name: &str, import_base: &str, runtime_module: &str, templates: &TemplateOverrides, naming: &Naming) -> Result<()> {
+        let outline = Outline::new(namespace, Mode::Entity, main_namespace, false, naming)?;
+        let template = GoPackageTemplate::new(namespace, outline, packages.get(namespace.path()).unwrap(), main_package_name, import_base, runtime_module);
+        let dir = namespace.path().iter().map(|p| package_name(std::slice::from_ref(p))).collect::<Vec<String>>().join("/");
+        if !namespace.is_main() {
+            generator.ensure_directory(dir.as_str()).await?;
+        }
+        let file = if namespace.is_main() {
+            format!("{}.go", template.package_name)
+        } else {
+            format!("{}/{}.go", dir, template.package_name)
+        };
+        generator.generate_file(file, templates.render_for_namespace("entity/go/package.go.jinja", &template, namespace)?).await?;
+        for child in namespace.namespaces().values() {
+            self.generate_package_for_namespace(child, generator, main_namespace, packages, main_package_name, import_base, runtime_module, templates, naming).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Generator for GoGenerator {
+
+    fn naming(&self) -> Naming {
+        // exported identifiers start in upper case
+        Naming { fields: Case::Pascal, enum_members: Case::Pascal, ..Naming::default() }
+    }
+
+    async fn generate_entity_files(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()> {
+        let main_package_name = package_name(&[last_path_component(&ctx.conf.dest)]);
+        let import_base = self.import_base(generator, &main_package_name).await?;
+        let mut packages = BTreeMap::new();
+        self.collect_packages(ctx.main_namespace, ctx.main_namespace, ctx.naming(), &mut packages)?;
+        check_import_cycles(&packages)?;
+        add_accessors(ctx.main_namespace, &mut packages);
+        self.generate_package_for_namespace(ctx.main_namespace, generator, ctx.main_namespace, &packages, &main_package_name, &import_base, ctx.options.go.runtime_module(), &ctx.templates, ctx.naming()).await
+    }
+}
+
+fn last_path_component(dest: &str) -> String {
+    let path = Path::new(dest);
+    path.components().last().unwrap().as_os_str().to_str().unwrap().to_owned()
+}
@@ -0,0 +1,117 @@
+use std::collections::BTreeSet;
+use teo_result::{Result, Error};
+use teo_parser::r#type::Type;
+use crate::outline::outline::Mode;
+use crate::utils::declared_shape_lookup::declared_shape_lookup;
+use crate::utils::enum_reference_lookup::enum_reference_lookup;
+use crate::utils::shape_reference_lookup::shape_reference_lookup;
+
+/// The Go package name of the namespace at `path`. The main namespace has no
+/// path and is named by the caller.
+pub(crate) fn package_name(path: &[String]) -> String {
+    match path.last() {
+        Some(name) if name.as_str() == "std" => "stdlib".to_owned(),
+        Some(name) => name.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase(),
+        None => "".to_owned(),
+    }
+}
+
+/// The name other packages import the namespace at `path` as.
+pub(crate) fn package_alias(path: &[String]) -> String {
+    if path.is_empty() {
+        "root".to_owned()
+    } else {
+        path.iter().map(|p| package_name(std::slice::from_ref(p))).collect::<Vec<String>>().join("_")
+    }
+}
+
+/// Qualify a dotted schema path as seen from the namespace at `namespace_path`.
+pub(crate) fn qualify(dotted: &str, namespace_path: &Vec<String>) -> String {
+    let components: Vec<String> = dotted.split(".").map(ToOwned::to_owned).collect();
+    let (name, path) = components.split_last().unwrap();
+    if path == namespace_path.as_slice() {
+        name.clone()
+    } else {
+        format!("{}.{}", package_alias(path), name)
+    }
+}
+
+fn dotted(t: &Type) -> Result<Option<String>> {
+    Ok(match t {
+        Type::EnumVariant(reference) => Some(reference.string_path().join(".")),
+        Type::InterfaceObject(reference, _) => Some(reference.string_path().join(".")),
+        Type::ModelObject(reference) => Some(reference.string_path().join(".")),
+        Type::SynthesizedShapeReference(shape_reference) => Some(shape_reference_lookup(shape_reference, ".", Mode::Entity)?),
+        Type::SynthesizedEnumReference(enum_reference) => Some(enum_reference_lookup(enum_reference, ".")?),
+        Type::DeclaredSynthesizedShape(reference, owner) => Some(declared_shape_lookup(reference, owner, ".")?),
+        _ => None,
+    })
+}
+
+fn is_nilable(go_type: &str) -> bool {
+    go_type.starts_with("*") || go_type.starts_with("[]") || go_type.starts_with("map[") || go_type == "teo.Value"
+}
+
+pub(crate) fn lookup(t: &Type, namespace_path: &Vec<String>) -> Result<String> {
+    Ok(match t {
+        Type::Undetermined => Err(Error::new("encountered undetermined"))?,
+        Type::Ignored => Err(Error::new("encountered ignored"))?,
+        Type::Any => "teo.Value".to_owned(),
+        Type::Null => "teo.Value".to_owned(),
+        Type::Bool => "bool".to_owned(),
+        Type::Int => "int32".to_owned(),
+        Type::Int64 => "int64".to_owned(),
+        Type::Float32 => "float32".to_owned(),
+        Type::Float => "float64".to_owned(),
+        Type::Decimal => "teo.Decimal".to_owned(),
+        Type::String => "string".to_owned(),
+        Type::ObjectId => "teo.ObjectId".to_owned(),
+        Type::Date => "teo.Date".to_owned(),
+        Type::DateTime => "time.Time".to_owned(),
+        Type::File => "teo.File".to_owned(),
+        Type::Regex => Err(Error::new("encountered regex"))?,
+        Type::Model => Err(Error::new("encountered model"))?,
+        Type::DataSet => Err(Error::new("encountered dataset"))?,
+        Type::Enumerable(_) => "teo.Value".to_owned(),
+        Type::Array(inner) => format!("[]{}", lookup(inner.as_ref(), namespace_path)?),
+        Type::Dictionary(inner) => format!("map[string]{}", lookup(inner.as_ref(), namespace_path)?),
+        Type::Tuple(_) => "[]teo.Value".to_owned(),
+        Type::Range(inner) => format!("teo.Range[{}]", lookup(inner.as_ref(), namespace_path)?),
+        Type::Union(_) => "teo.Value".to_owned(),
+        Type::InterfaceObject(_, types) if !types.is_empty() => {
+            qualify(&dotted(t)?.unwrap(), namespace_path) + "[" + &types.iter().map(|t| lookup(t, namespace_path)).collect::<Result<Vec<String>>>()?.join(", ") + "]"
+        },
+        Type::ModelObject(_) => "*".to_owned() + &qualify(&dotted(t)?.unwrap(), namespace_path),
+        Type::EnumVariant(_) |
+        Type::InterfaceObject(_, _) |
+        Type::SynthesizedShapeReference(_) |
+        Type::SynthesizedEnumReference(_) |
+        Type::DeclaredSynthesizedShape(_, _) => qualify(&dotted(t)?.unwrap(), namespace_path),
+        Type::GenericItem(i) => i.to_owned(),
+        Type::Optional(inner) => {
+            let inner = lookup(inner.as_ref(), namespace_path)?;
+            if is_nilable(&inner) { inner } else { format!("*{}", inner) }
+        },
+        _ => Err(Error::new(format!("encountered unhandled type `{}' in lookup", t)))?,
+    })
+}
+
+/// Collect the namespaces `t` refers to, and whether it needs the `time` package.
+pub(crate) fn collect_references(t: &Type, namespaces: &mut BTreeSet<Vec<String>>, uses_time: &mut bool) -> Result<()> {
+    match t {
+        Type::DateTime => *uses_time = true,
+        Type::Optional(inner) | Type::Array(inner) | Type::Dictionary(inner) | Type::Range(inner) => collect_references(inner.as_ref(), namespaces, uses_time)?,
+        Type::InterfaceObject(_, types) => {
+            for t in types {
+                collect_references(t, namespaces, uses_time)?;
+            }
+        },
+        _ => (),
+    }
+    if let Some(dotted) = dotted(t)? {
+        let mut components: Vec<String> = dotted.split(".").map(ToOwned::to_owned).collect();
+        components.pop();
+        namespaces.insert(components);
+    }
+    Ok(())
+}
@@ -0,0 +1,2 @@
+pub(crate) mod lookup;
+pub(crate) mod gen;
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use teo_result::Result;
+use crate::entity::ctx::Ctx;
+use crate::entity::generator::Generator;
+use crate::outline::json::outline_document;
+use crate::outline::outline::Mode;
+use crate::utils::file::FileUtil;
+
+/// # IR generator
+///
+/// Writes the entity outline of the schema as `outline.json`.
+pub(crate) struct IrGenerator { }
+
+impl IrGenerator {
+
+    pub fn new() -> Self {
+        Self { }
+    }
+}
+
+#[async_trait]
+impl Generator for IrGenerator {
+
+    async fn generate_entity_files(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()> {
+        let document = outline_document(ctx.main_namespace, Mode::Entity)?;
+        generator.generate_file("outline.json", serde_json::to_string_pretty(&document).unwrap() + "\n").await
+    }
+}
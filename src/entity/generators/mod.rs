@@ -1,3 +1,5 @@
+pub(crate) mod go;
+pub(crate) mod ir;
 pub(crate) mod node;
 pub(crate) mod python;
 pub(crate) mod rust;
\ No newline at end of file
@@ -1,17 +1,136 @@
 use askama::Template;
+use serde_json::{json, Map, Value};
 use async_trait::async_trait;
 use teo_result::Result;
 use teo_runtime::namespace::Namespace;
 use crate::entity::ctx::Ctx;
 use crate::entity::generator::Generator;
 use crate::outline::outline::Mode;
-use crate::shared::ts::conf::TsConf;
+use crate::naming::Naming;
+use crate::shared::ts::conf::{check_naming, TsConf};
+use crate::client::TsOptions;
 use crate::shared::ts::templates::{render_namespace, TsIndexDTsTemplate};
-use crate::utils::file::FileUtil;
+use crate::utils::file::{read_file, FileUtil};
+use crate::utils::filters;
+use crate::utils::templates::TemplateOverrides;
+use crate::utils::update_package_json_version::parse_package_json;
+use teo_runtime::model::field::typed::Typed;
+use teo_runtime::traits::named::Named;
+
+/// The module format of a generated JavaScript entry point.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(self) enum ModuleFormat {
+    Esm,
+    Cjs,
+}
+
+impl ModuleFormat {
+
+    pub(self) fn is_cjs(&self) -> bool {
+        *self == ModuleFormat::Cjs
+    }
+}
 
 #[derive(Template)]
 #[template(path = "entity/nodejs/index.js.jinja", escape = "none")]
-pub(self) struct TsIndexJsTemplate { }
+pub(self) struct TsIndexJsTemplate<'a> {
+    pub(self) main_namespace: &'a Namespace,
+    pub(self) format: ModuleFormat,
+    pub(self) render_js_namespace: &'static dyn Fn(&Namespace, &TemplateOverrides) -> Result<String>,
+    pub(self) templates: &'a TemplateOverrides,
+    pub(self) js_exports: &'static dyn Fn(&Namespace) -> Vec<String>,
+}
+
+unsafe impl Send for TsIndexJsTemplate<'_> { }
+unsafe impl Sync for TsIndexJsTemplate<'_> { }
+
+#[derive(Template)]
+#[template(path = "entity/nodejs/namespace.js.jinja", escape = "none")]
+pub(self) struct JsNamespaceTemplate<'a> {
+    pub(self) namespace: &'a Namespace,
+    pub(self) render_js_namespace: &'static dyn Fn(&Namespace, &TemplateOverrides) -> Result<String>,
+    pub(self) templates: &'a TemplateOverrides,
+    pub(self) js_exports: &'static dyn Fn(&Namespace) -> Vec<String>,
+}
+
+unsafe impl Send for JsNamespaceTemplate<'_> { }
+unsafe impl Sync for JsNamespaceTemplate<'_> { }
+
+fn render_js_namespace(namespace: &Namespace, templates: &TemplateOverrides) -> Result<String> {
+    let content = templates.render_for_namespace("entity/nodejs/namespace.js.jinja", &JsNamespaceTemplate {
+        namespace,
+        render_js_namespace: &render_js_namespace,
+        templates,
+        js_exports: &js_exports,
+    }, namespace)?;
+    Ok(if namespace.is_main() {
+        content
+    } else {
+        indent::indent_by(4, content.as_str())
+    })
+}
+
+/// Names a namespace's generated JavaScript module exports.
+fn js_exports(namespace: &Namespace) -> Vec<String> {
+    let mut exports = vec![];
+    for model in namespace.models().values() {
+        if model.generate_entity() {
+            exports.push(format!("{}Model", model.name()));
+            exports.push(model.name().to_owned());
+        }
+    }
+    for child in namespace.namespaces().values() {
+        if !child.is_std() {
+            exports.push(child.name().to_owned());
+        }
+    }
+    exports.push(if namespace.is_main() { "Teo".to_owned() } else { format!("{}Namespace", capitalize(namespace.name())) });
+    exports
+}
+
+/// Same as askama's `capitalize` filter, which names namespace classes.
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(f) => f.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+    }
+}
+
+fn package_json(name: &str) -> Value {
+    json!({
+        "name": name,
+        "private": true,
+        "main": "./index.cjs",
+        "module": "./index.mjs",
+        "types": "./index.d.ts",
+        "exports": {
+            ".": {
+                "types": "./index.d.ts",
+                "import": "./index.mjs",
+                "require": "./index.cjs",
+            }
+        }
+    })
+}
+
+/// Point an existing `package.json` at the generated entry points. Its name,
+/// dependencies, scripts and everything else belong to the user.
+fn update_package_json(object: &mut Map<String, Value>) {
+    let generated = package_json("");
+    for key in ["main", "module", "types", "exports"] {
+        object.insert(key.to_owned(), generated[key].clone());
+    }
+}
+
+/// The format the package's `.js` files are loaded as.
+fn js_format(object: &Map<String, Value>) -> ModuleFormat {
+    if object.get("type").and_then(Value::as_str) == Some("module") {
+        ModuleFormat::Esm
+    } else {
+        ModuleFormat::Cjs
+    }
+}
 
 pub(crate) struct NodeGenerator {}
 
@@ -21,17 +140,46 @@ impl NodeGenerator {
         Self {}
     }
 
-    pub async fn generate_index_js(&self, generator: &FileUtil) -> Result<()> {
-        generator.generate_file("index.js", TsIndexJsTemplate { }.render().unwrap()).await
+    pub(self) async fn generate_index_js(&self, main_namespace: &Namespace, generator: &FileUtil, templates: &TemplateOverrides, js_format: ModuleFormat) -> Result<()> {
+        for (file, format) in [("index.mjs", ModuleFormat::Esm), ("index.cjs", ModuleFormat::Cjs)] {
+            generator.generate_file(file, templates.render("entity/nodejs/index.js.jinja", &TsIndexJsTemplate {
+                main_namespace,
+                format,
+                render_js_namespace: &render_js_namespace,
+                templates,
+                js_exports: &js_exports,
+            })?).await?;
+        }
+        // kept for imports of `index.js` by path, in the package's format
+        generator.generate_file("index.js", match js_format {
+            ModuleFormat::Esm => "export * from \"./index.mjs\"\n",
+            ModuleFormat::Cjs => "module.exports = require(\"./index.cjs\")\n",
+        }).await
     }
 
-    pub async fn generate_index_d_ts(&self, main_namespace: &Namespace, generator: &FileUtil) -> Result<()> {
-        generator.generate_file("index.d.ts", TsIndexDTsTemplate {
+    /// Write `package.json`, or update the user's one, and return the format
+    /// of the package's `.js` files.
+    pub(self) async fn generate_package_json(&self, generator: &FileUtil) -> Result<ModuleFormat> {
+        let name = generator.get_base_dir().file_name().and_then(|n| n.to_str()).unwrap_or("entities").to_owned();
+        if generator.generate_file_if_not_exist("package.json", serde_json::to_string_pretty(&package_json(&name)).unwrap() + "\n").await? {
+            let path = generator.get_file_path("package.json");
+            let mut object = parse_package_json(&read_file(&path)?, &path)?;
+            update_package_json(&mut object);
+            generator.generate_file("package.json", serde_json::to_string_pretty(&object).unwrap() + "\n").await?;
+            Ok(js_format(&object))
+        } else {
+            // the generated one has no `type`
+            Ok(ModuleFormat::Cjs)
+        }
+    }
+
+    pub async fn generate_index_d_ts(&self, main_namespace: &Namespace, generator: &FileUtil, templates: &TemplateOverrides, naming: &Naming) -> Result<()> {
+        generator.generate_file("index.d.ts", templates.render("shared/ts/index.d.ts.jinja", &TsIndexDTsTemplate {
             main_namespace,
-            conf: &TsConf::new("teo".to_string(), "Teo".to_string(), false),
+            conf: &TsConf::new("teo".to_string(), "Teo".to_string(), false, TsOptions::default(), templates.clone(), naming.clone()),
             render_namespace: &render_namespace,
             mode: Mode::Entity,
-        }.render().unwrap()).await
+        })?).await
     }
 }
 
@@ -39,12 +187,18 @@ impl NodeGenerator {
 impl Generator for NodeGenerator {
 
     async fn generate_entity_files(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()> {
-        // index.js
-        self.generate_index_js(generator).await?;
+        // package.json
+        let js_format = self.generate_package_json(generator).await?;
+        // index.mjs, index.cjs and index.js
+        self.generate_index_js(ctx.main_namespace, generator, &ctx.templates, js_format).await?;
         // index.d.ts
-        self.generate_index_d_ts(ctx.main_namespace, generator).await?;
+        self.generate_index_d_ts(ctx.main_namespace, generator, &ctx.templates, ctx.naming()).await?;
         Ok(())
     }
+
+    fn check_naming(&self, naming: &Naming, _main_namespace: &Namespace) -> Result<()> {
+        check_naming(naming, "Node.js entities")
+    }
 }
 
 
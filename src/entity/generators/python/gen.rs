@@ -7,8 +7,11 @@ use teo_runtime::namespace::Namespace;
 use std::borrow::Borrow;
 use crate::entity::ctx::Ctx;
 use crate::entity::generator::Generator;
+use crate::naming::{check_collisions, Case, Naming};
+use crate::outline::interface::field::Field;
 use crate::outline::outline::{Mode, Outline};
 use crate::utils::file::FileUtil;
+use crate::utils::templates::TemplateOverrides;
 use std::str::FromStr;
 use inflector::Inflector;
 use teo_parser::r#type::reference::Reference;
@@ -17,11 +20,12 @@ use teo_parser::r#type::synthesized_shape_reference::SynthesizedShapeReference;
 use teo_parser::r#type::Type;
 use teo_runtime::traits::named::Named;
 use teo_runtime::model::field::typed::Typed;
-use crate::entity::generators::python::lookup;
+use teo_result::{Error, Result};
+use crate::entity::generators::python::{lookup, pydantic_lookup};
 use crate::utils::filters;
 use crate::utils::lookup::Lookup;
 
-fn typed_dict_not_required(original: String) -> String {
+fn typed_dict_not_required(original: &str) -> String {
     if original.starts_with("Optional[") {
         format!("\"NotRequired[{}]\"", original)
     } else {
@@ -29,6 +33,34 @@ fn typed_dict_not_required(original: String) -> String {
     }
 }
 
+/// Pydantic treats attributes with a leading underscore as private, they're
+/// declared without it and aliased.
+fn pydantic_attribute_name(field: &Field) -> String {
+    filters::escape_python(field.identifier().trim_start_matches('_')).unwrap()
+}
+
+fn pydantic_field_default(field: &Field, type_name: &str) -> String {
+    let optional = type_name.starts_with("Optional[");
+    if pydantic_attribute_name(field) == field.name() {
+        if optional { " = None".to_owned() } else { "".to_owned() }
+    } else {
+        if optional {
+            format!(" = Field(default=None, alias=\"{}\")", field.name())
+        } else {
+            format!(" = Field(alias=\"{}\")", field.name())
+        }
+    }
+}
+
+/// Relative module path of the generated `_teon.py` from `namespace`.
+fn teon_module(namespace: &Namespace) -> String {
+    if namespace.is_main() || !namespace.namespaces().is_empty() {
+        dots(namespace.path().len() + 1)
+    } else {
+        dots(namespace.path().len())
+    }
+}
+
 fn fix_path_inner(components: &Vec<String>, namespace: &Namespace, root_module_name: &str) -> Vec<String> {
     let namespace_path = namespace.path();
     let components_without_last: Vec<String> = components.iter().rev().skip(1).rev().map(Clone::clone).collect();
@@ -45,23 +77,23 @@ fn fix_path_inner(components: &Vec<String>, namespace: &Namespace, root_module_n
     }
 }
 
-fn fix_path_enum_reference(enum_reference: &SynthesizedEnumReference, namespace: &Namespace, root_module_name: &str) -> SynthesizedEnumReference {
-    SynthesizedEnumReference {
+fn fix_path_enum_reference(enum_reference: &SynthesizedEnumReference, namespace: &Namespace, root_module_name: &str) -> Result<SynthesizedEnumReference> {
+    Ok(SynthesizedEnumReference {
         kind: enum_reference.kind,
-        owner: Box::new(fix_path(enum_reference.owner.as_ref(), namespace, root_module_name)),
-    }
+        owner: Box::new(fix_path(enum_reference.owner.as_ref(), namespace, root_module_name)?),
+    })
 }
 
-fn fix_path_shape_reference(shape_reference: &SynthesizedShapeReference, namespace: &Namespace, root_module_name: &str) -> SynthesizedShapeReference {
-    SynthesizedShapeReference {
+fn fix_path_shape_reference(shape_reference: &SynthesizedShapeReference, namespace: &Namespace, root_module_name: &str) -> Result<SynthesizedShapeReference> {
+    Ok(SynthesizedShapeReference {
         kind: shape_reference.kind,
-        owner: Box::new(fix_path(shape_reference.owner.as_ref(), namespace, root_module_name)),
+        owner: Box::new(fix_path(shape_reference.owner.as_ref(), namespace, root_module_name)?),
         without: shape_reference.without.clone(),
-    }
+    })
 }
 
-fn fix_path(t: &Type, namespace: &Namespace, root_module_name: &str) -> Type {
-    match t {
+fn fix_path(t: &Type, namespace: &Namespace, root_module_name: &str) -> Result<Type> {
+    Ok(match t {
         Type::Undetermined => t.clone(),
         Type::Ignored => t.clone(),
         Type::Any => t.clone(),
@@ -80,24 +112,24 @@ fn fix_path(t: &Type, namespace: &Namespace, root_module_name: &str) -> Type {
         Type::Regex => t.clone(),
         Type::Model => t.clone(),
         Type::DataSet => t.clone(),
-        Type::Enumerable(inner) => Type::Enumerable(Box::new(fix_path(inner.as_ref(), namespace, root_module_name))),
-        Type::Array(inner) => Type::Array(Box::new(fix_path(inner.as_ref(), namespace, root_module_name))),
-        Type::Dictionary(inner) => Type::Dictionary(Box::new(fix_path(inner.as_ref(), namespace, root_module_name))),
-        Type::Tuple(types) => Type::Tuple(types.iter().map(|t| fix_path(t, namespace, root_module_name)).collect()),
-        Type::Range(inner) => Type::Range(Box::new(fix_path(inner.as_ref(), namespace, root_module_name))),
-        Type::Union(types) => Type::Union(types.iter().map(|t| fix_path(t, namespace, root_module_name)).collect()),
+        Type::Enumerable(inner) => Type::Enumerable(Box::new(fix_path(inner.as_ref(), namespace, root_module_name)?)),
+        Type::Array(inner) => Type::Array(Box::new(fix_path(inner.as_ref(), namespace, root_module_name)?)),
+        Type::Dictionary(inner) => Type::Dictionary(Box::new(fix_path(inner.as_ref(), namespace, root_module_name)?)),
+        Type::Tuple(types) => Type::Tuple(types.iter().map(|t| fix_path(t, namespace, root_module_name)).collect::<Result<Vec<Type>>>()?),
+        Type::Range(inner) => Type::Range(Box::new(fix_path(inner.as_ref(), namespace, root_module_name)?)),
+        Type::Union(types) => Type::Union(types.iter().map(|t| fix_path(t, namespace, root_module_name)).collect::<Result<Vec<Type>>>()?),
         Type::EnumVariant(reference) => Type::EnumVariant(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, root_module_name))),
-        Type::InterfaceObject(reference, types) => Type::InterfaceObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, root_module_name)), types.iter().map(|t| fix_path(t, namespace, root_module_name)).collect()),
+        Type::InterfaceObject(reference, types) => Type::InterfaceObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, root_module_name)), types.iter().map(|t| fix_path(t, namespace, root_module_name)).collect::<Result<Vec<Type>>>()?),
         Type::ModelObject(reference) => Type::ModelObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, root_module_name))),
-        Type::StructObject(reference, types) => Type::StructObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, root_module_name)), types.iter().map(|t| fix_path(t, namespace, root_module_name)).collect()),
+        Type::StructObject(reference, types) => Type::StructObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, root_module_name)), types.iter().map(|t| fix_path(t, namespace, root_module_name)).collect::<Result<Vec<Type>>>()?),
         Type::GenericItem(name) => Type::GenericItem(name.clone()),
         Type::Keyword(keyword) => Type::Keyword(keyword.clone()),
-        Type::Optional(inner) => Type::Optional(Box::new(fix_path(inner.as_ref(), namespace, root_module_name))),
-        Type::SynthesizedShapeReference(shape_reference) => Type::SynthesizedShapeReference(fix_path_shape_reference(shape_reference, namespace, root_module_name)),
-        Type::SynthesizedEnumReference(enum_reference) => Type::SynthesizedEnumReference(fix_path_enum_reference(enum_reference, namespace, root_module_name)),
-        Type::DeclaredSynthesizedShape(reference, inner) => Type::DeclaredSynthesizedShape(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, root_module_name)), Box::new(fix_path(inner, namespace, root_module_name))),
-        _ => panic!(),
-    }
+        Type::Optional(inner) => Type::Optional(Box::new(fix_path(inner.as_ref(), namespace, root_module_name)?)),
+        Type::SynthesizedShapeReference(shape_reference) => Type::SynthesizedShapeReference(fix_path_shape_reference(shape_reference, namespace, root_module_name)?),
+        Type::SynthesizedEnumReference(enum_reference) => Type::SynthesizedEnumReference(fix_path_enum_reference(enum_reference, namespace, root_module_name)?),
+        Type::DeclaredSynthesizedShape(reference, inner) => Type::DeclaredSynthesizedShape(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, root_module_name)), Box::new(fix_path(inner, namespace, root_module_name)?)),
+        _ => Err(Error::new(format!("cannot generate type `{}' in namespace `{}'", t, namespace.path().join("."))))?,
+    })
 }
 
 #[derive(Template)]
@@ -107,9 +139,13 @@ pub(self) struct PythonModuleTemplate<'a> {
     pub(self) namespace: &'a Namespace,
     pub(self) outline: Outline,
     pub(self) lookup: &'static dyn Lookup,
-    pub(self) fix_path: &'static dyn Fn(&Type, &Namespace, &str) -> Type,
+    pub(self) fix_path: &'static dyn Fn(&Type, &Namespace, &str) -> Result<Type>,
     pub(self) dots: &'static dyn Fn(usize) -> String,
-    pub(self) typed_dict_not_required: &'static dyn Fn(String) -> String,
+    pub(self) typed_dict_not_required: &'static dyn Fn(&str) -> String,
+    pub(self) pydantic: bool,
+    pub(self) pydantic_attribute_name: &'static dyn Fn(&Field) -> String,
+    pub(self) pydantic_field_default: &'static dyn Fn(&Field, &str) -> String,
+    pub(self) teon_module: &'static dyn Fn(&Namespace) -> String,
 }
 
 unsafe impl Send for PythonModuleTemplate<'_> { }
@@ -117,16 +153,20 @@ unsafe impl Sync for PythonModuleTemplate<'_> { }
 
 impl<'a> PythonModuleTemplate<'a> {
 
-    fn new(namespace: &'a Namespace, main_namespace: &'a Namespace, last_path_component: String) -> Self {
-        Self {
+    fn new(namespace: &'a Namespace, main_namespace: &'a Namespace, last_path_component: String, pydantic: bool, naming: &Naming) -> teo_result::Result<Self> {
+        Ok(Self {
             namespace,
-            outline: Outline::new(namespace, Mode::Entity, main_namespace, false),
-            lookup: &lookup,
+            outline: Outline::new(namespace, Mode::Entity, main_namespace, false, naming)?,
+            lookup: if pydantic { &pydantic_lookup as &dyn Lookup } else { &lookup },
             fix_path: &fix_path,
             root_module_name: last_path_component,
             dots: &dots,
             typed_dict_not_required: &typed_dict_not_required,
-        }
+            pydantic,
+            pydantic_attribute_name: &pydantic_attribute_name,
+            pydantic_field_default: &pydantic_field_default,
+            teon_module: &teon_module,
+        })
     }
 }
 
@@ -138,14 +178,15 @@ impl PythonGenerator {
         Self { }
     }
 
-    async fn generate_module_file(&self, namespace: &Namespace, filename: impl AsRef<Path>, generator: &FileUtil, main_namespace: &Namespace, last_path_component: &str) -> teo_result::Result<()> {
-        let template = PythonModuleTemplate::new(namespace, main_namespace, last_path_component.to_owned());
-        generator.generate_file(filename.as_ref(), template.render().unwrap()).await?;
+    async fn generate_module_file(&self, namespace: &Namespace, filename: impl AsRef<Path>, generator: &FileUtil, main_namespace: &Namespace, last_path_component: &str, pydantic: bool, templates: &TemplateOverrides, naming: &Naming) -> teo_result::Result<()> {
+        let template = PythonModuleTemplate::new(namespace, main_namespace, last_path_component.to_owned(), pydantic, naming)?;
+        template.outline.try_each_type(|t| template.lookup.call(&fix_path(t, namespace, last_path_component)?).map(|_| ()))?;
+        generator.generate_file(filename.as_ref(), templates.render_for_namespace("entity/python/__init__.py.jinja", &template, namespace)?).await?;
         Ok(())
     }
 
     #[async_recursion]
-    async fn generate_module_for_namespace(&self, namespace: &Namespace, generator: &FileUtil, main_namespace: &Namespace, last_path_component: &str) -> teo_result::Result<()> {
+    async fn generate_module_for_namespace(&self, namespace: &Namespace, generator: &FileUtil, main_namespace: &Namespace, last_path_component: &str, pydantic: bool, templates: &TemplateOverrides, naming: &Naming) -> teo_result::Result<()> {
         if namespace.is_main() || !namespace.namespaces().is_empty() {
             // create dir and create mod.rs
             if !namespace.is_main() {
@@ -157,6 +198,9 @@ impl PythonGenerator {
                 generator,
                 main_namespace,
                 last_path_component,
+                pydantic,
+                templates,
+                naming,
             ).await?;
         } else {
             // create file
@@ -166,10 +210,13 @@ impl PythonGenerator {
                 generator,
                 main_namespace,
                 last_path_component,
+                pydantic,
+                templates,
+                naming,
             ).await?;
         }
         for namespace in namespace.namespaces().values() {
-            self.generate_module_for_namespace(namespace, generator, main_namespace, last_path_component).await?;
+            self.generate_module_for_namespace(namespace, generator, main_namespace, last_path_component, pydantic, templates, naming).await?;
         }
         Ok(())
     }
@@ -178,9 +225,21 @@ impl PythonGenerator {
 #[async_trait]
 impl Generator for PythonGenerator {
 
+    fn naming(&self) -> Naming {
+        Naming { fields: Case::Snake, ..Naming::default() }
+    }
+
+    fn check_naming(&self, naming: &Naming, main_namespace: &Namespace) -> Result<()> {
+        check_collisions(naming, main_namespace, Mode::Entity, "Python entities")
+    }
+
     async fn generate_entity_files(&self, ctx: &Ctx, generator: &FileUtil) -> teo_result::Result<()> {
         // module files
-        self.generate_module_for_namespace(ctx.main_namespace, generator, ctx.main_namespace, last_path_component(&ctx.conf.dest).as_str()).await?;
+        let pydantic = ctx.options.python.pydantic;
+        self.generate_module_for_namespace(ctx.main_namespace, generator, ctx.main_namespace, last_path_component(&ctx.conf.dest).as_str(), pydantic, &ctx.templates, ctx.naming()).await?;
+        if pydantic {
+            generator.generate_file("_teon.py", ctx.templates.render_static("entity/python/_teon.py.jinja", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/entity/python/_teon.py.jinja")))?).await?;
+        }
         Ok(())
     }
 }
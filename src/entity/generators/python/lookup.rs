@@ -8,6 +8,16 @@ use crate::utils::enum_reference_lookup::enum_reference_lookup;
 use crate::utils::shape_reference_lookup::shape_reference_lookup;
 
 pub(crate) fn lookup(t: &Type) -> Result<String> {
+    lookup_with(t, false)
+}
+
+/// Lookup for Pydantic models, where TEON scalars are validated through the
+/// adapters in `_teon.py`.
+pub(crate) fn pydantic_lookup(t: &Type) -> Result<String> {
+    lookup_with(t, true)
+}
+
+fn lookup_with(t: &Type, pydantic: bool) -> Result<String> {
     Ok(match t {
         Type::Undetermined => Err(Error::new("encountered undetermined"))?,
         Type::Ignored => Err(Error::new("encountered ignored"))?,
@@ -18,33 +28,33 @@ pub(crate) fn lookup(t: &Type) -> Result<String> {
         Type::Int64 => "int".to_owned(),
         Type::Float32 => "float".to_owned(),
         Type::Float => "float".to_owned(),
-        Type::Decimal => "Decimal".to_owned(),
+        Type::Decimal => if pydantic { "TeonDecimal" } else { "Decimal" }.to_owned(),
         Type::String => "str".to_owned(),
-        Type::ObjectId => "ObjectId".to_owned(),
-        Type::Date => "date".to_owned(),
-        Type::DateTime => "datetime".to_owned(),
+        Type::ObjectId => if pydantic { "TeonObjectId" } else { "ObjectId" }.to_owned(),
+        Type::Date => if pydantic { "TeonDate" } else { "date" }.to_owned(),
+        Type::DateTime => if pydantic { "TeonDateTime" } else { "datetime" }.to_owned(),
         Type::File => "File".to_owned(),
         Type::Regex => "Pattern".to_owned(),
         Type::Model => Err(Error::new("encountered model"))?,
         Type::DataSet => Err(Error::new("encountered dataset"))?,
-        Type::Enumerable(inner) => format!("Enumerable[{}]", lookup(inner.as_ref())?),
-        Type::Array(inner) => format!("list[{}]", lookup(inner.as_ref())?),
-        Type::Dictionary(inner) => format!("dict[str, {}]", lookup(inner.as_ref())?),
-        Type::Tuple(t) => format!("tuple[{}]", t.iter().map(|t| lookup(t)).collect::<Result<Vec<String>>>()?.join(", ")),
+        Type::Enumerable(inner) => format!("Enumerable[{}]", lookup_with(inner.as_ref(), pydantic)?),
+        Type::Array(inner) => format!("list[{}]", lookup_with(inner.as_ref(), pydantic)?),
+        Type::Dictionary(inner) => format!("dict[str, {}]", lookup_with(inner.as_ref(), pydantic)?),
+        Type::Tuple(t) => format!("tuple[{}]", t.iter().map(|t| lookup_with(t, pydantic)).collect::<Result<Vec<String>>>()?.join(", ")),
         Type::Range(_) => "Range".to_owned(),
-        Type::Union(types) => types.iter().map(|t| Ok(lookup(t)?)).collect::<Result<Vec<String>>>()?.join(" | "),
+        Type::Union(types) => types.iter().map(|t| Ok(lookup_with(t, pydantic)?)).collect::<Result<Vec<String>>>()?.join(" | "),
         Type::EnumVariant(reference) => reference.string_path().iter().enumerate().map(|(i, s)| if i == reference.string_path().len() - 1 { s.clone() } else { s.to_snake_case() }).join("."),
         Type::InterfaceObject(reference, types) => if types.is_empty() {
             reference.string_path().join(".")
         } else {
-            reference.string_path().join(".") + "[" + &types.iter().map(|t| lookup(t)).collect::<Result<Vec<String>>>()?.join(", ") + "]"
+            reference.string_path().join(".") + "[" + &types.iter().map(|t| lookup_with(t, pydantic)).collect::<Result<Vec<String>>>()?.join(", ") + "]"
         },
         Type::ModelObject(reference) => reference.string_path().iter().enumerate().map(|(i, s)| if i == reference.string_path().len() - 1 { s.clone() } else { s.to_snake_case() }).join("."),
         Type::GenericItem(i) => i.to_owned(),
-        Type::Optional(inner) => format!("Optional[{}]", lookup(inner.as_ref())?),
+        Type::Optional(inner) => format!("Optional[{}]", lookup_with(inner.as_ref(), pydantic)?),
         Type::SynthesizedShapeReference(shape_reference) => shape_reference_lookup(shape_reference, ".", Mode::Entity)?,
         Type::SynthesizedEnumReference(enum_reference) => enum_reference_lookup(enum_reference, ".")?,
         Type::DeclaredSynthesizedShape(reference, owner) => declared_shape_lookup(reference, owner, ".")?,
-        _ => Err(Error::new("encountered unhandled type in lookup"))?,
+        _ => Err(Error::new(format!("encountered unhandled type `{}' in lookup", t)))?,
     })
 }
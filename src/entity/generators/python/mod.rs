@@ -2,3 +2,4 @@ pub(crate) mod lookup;
 pub(crate) mod gen;
 
 pub(crate) use lookup::lookup;
+pub(crate) use lookup::pydantic_lookup;
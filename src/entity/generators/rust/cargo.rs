@@ -0,0 +1,186 @@
+use std::path::{Path, PathBuf};
+use teo_result::{Error, Result};
+use tokio::fs;
+use toml_edit::{Array, Document, InlineTable, Item, table, value};
+
+/// The version requirement the generated entities need for `name`.
+pub(crate) fn crate_version(name: &str) -> &'static str {
+    match name {
+        "chrono" => "0.4",
+        "bigdecimal" => "=0.3.1",
+        "bson" => "2.9.0",
+        "indexmap" => "2.2.6",
+        "serde" => "1.0",
+        "serde_json" => "1.0",
+        _ => "*",
+    }
+}
+
+/// A `Cargo.toml` edited in place, keeping the user's formatting.
+pub(crate) struct CargoManifest {
+    path: PathBuf,
+    doc: Document,
+    workspace_dependencies: Option<Item>,
+}
+
+impl CargoManifest {
+
+    pub(crate) async fn load(path: &Path) -> Result<Self> {
+        let doc = read_manifest(path).await?;
+        let workspace_dependencies = match workspace_dependencies(&doc) {
+            Some(dependencies) => Some(dependencies),
+            None => match find_workspace_manifest(path).await? {
+                Some(workspace) => workspace_dependencies(&workspace),
+                None => None,
+            }
+        };
+        Ok(Self { path: path.to_owned(), doc, workspace_dependencies })
+    }
+
+    pub(crate) async fn save(&self) -> Result<()> {
+        fs::write(&self.path, self.doc.to_string()).await?;
+        Ok(())
+    }
+
+    pub(crate) fn add_dependency(&mut self, name: &str, version: &str) -> Result<()> {
+        self.insert_dependency(name, version, false)
+    }
+
+    pub(crate) fn add_optional_dependency(&mut self, name: &str, version: &str) -> Result<()> {
+        self.insert_dependency(name, version, true)
+    }
+
+    /// Add `feature` enabling the optional ones of `dependencies`, unless the
+    /// user has already declared it.
+    pub(crate) fn add_feature(&mut self, feature: &str, dependencies: &[&str]) {
+        let mut enabled = Array::new();
+        for name in dependencies {
+            let optional = self.doc.get("dependencies").and_then(|deps| deps.get(name)).and_then(|dep| dep.get("optional")).and_then(|o| o.as_bool());
+            if optional == Some(true) {
+                enabled.push(format!("dep:{}", name));
+            }
+        }
+        if self.doc.get("features").is_none() {
+            self.doc["features"] = table();
+        }
+        if self.doc["features"].get(feature).is_none() {
+            self.doc["features"][feature] = value(enabled);
+        }
+    }
+
+    fn insert_dependency(&mut self, name: &str, version: &str, optional: bool) -> Result<()> {
+        let path = self.path.display().to_string();
+        let workspace_dependency = self.workspace_dependencies.as_ref().and_then(|deps| deps.get(name)).cloned();
+        if self.doc.get("dependencies").is_none() {
+            self.doc["dependencies"] = table();
+        }
+        let deps = self.doc["dependencies"].as_table_like_mut().ok_or_else(|| Error::new(format!("`{}': `dependencies' is not a table", path)))?;
+        if let Some(existing) = deps.get(name) {
+            let specified = if is_workspace_dependency(existing) {
+                workspace_dependency.as_ref().and_then(dependency_version).map(ToOwned::to_owned)
+            } else {
+                dependency_version(existing).map(ToOwned::to_owned)
+            };
+            return check_version(&path, name, specified.as_deref(), version);
+        }
+        if let Some(workspace_dependency) = workspace_dependency.as_ref() {
+            check_version(&path, name, dependency_version(workspace_dependency), version)?;
+            let mut dependency = InlineTable::new();
+            dependency.insert("workspace", true.into());
+            if optional {
+                dependency.insert("optional", true.into());
+            }
+            deps.insert(name, value(dependency));
+        } else if optional {
+            let mut dependency = InlineTable::new();
+            dependency.insert("version", version.into());
+            dependency.insert("optional", true.into());
+            deps.insert(name, value(dependency));
+        } else {
+            deps.insert(name, value(version));
+        }
+        Ok(())
+    }
+}
+
+async fn read_manifest(path: &Path) -> Result<Document> {
+    let content = fs::read_to_string(path).await?;
+    content.parse::<Document>().map_err(|e| Error::new(format!("`{}' has invalid content: {}", path.display(), e)))
+}
+
+fn workspace_dependencies(doc: &Document) -> Option<Item> {
+    doc.get("workspace").and_then(|workspace| workspace.get("dependencies")).cloned()
+}
+
+/// Find the manifest of the workspace `path` belongs to, if any.
+async fn find_workspace_manifest(path: &Path) -> Result<Option<Document>> {
+    let mut dir = path.parent().and_then(Path::parent);
+    while let Some(current) = dir {
+        let candidate = current.join("Cargo.toml");
+        if candidate.is_file() {
+            let doc = read_manifest(&candidate).await?;
+            if doc.get("workspace").is_some() {
+                return Ok(Some(doc));
+            }
+        }
+        dir = current.parent();
+    }
+    Ok(None)
+}
+
+fn is_workspace_dependency(item: &Item) -> bool {
+    item.get("workspace").and_then(|w| w.as_bool()) == Some(true)
+}
+
+fn dependency_version(item: &Item) -> Option<&str> {
+    match item.as_str() {
+        Some(version) => Some(version),
+        None => item.get("version").and_then(|v| v.as_str()),
+    }
+}
+
+fn check_version(path: &str, name: &str, specified: Option<&str>, required: &str) -> Result<()> {
+    match specified {
+        Some(specified) if !versions_compatible(specified, required) => Err(Error::new(format!(
+            "`{}': generated entities require `{} = \"{}\"', which conflicts with the specified version \"{}\"",
+            path, name, required, specified
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Whether a dependency specified as `specified` satisfies `required`. Only
+/// plain and `=` requirements are compared, anything fancier is trusted.
+fn versions_compatible(specified: &str, required: &str) -> bool {
+    let (Some((specified_exact, specified)), Some((required_exact, required))) = (parse_requirement(specified), parse_requirement(required)) else {
+        return true;
+    };
+    if compatibility_key(&specified) != compatibility_key(&required) {
+        return false;
+    }
+    match (specified_exact, required_exact) {
+        (true, true) => specified == required,
+        (true, false) => specified >= required,
+        (false, true) => specified <= required,
+        (false, false) => true,
+    }
+}
+
+fn parse_requirement(requirement: &str) -> Option<(bool, Vec<u64>)> {
+    let requirement = requirement.trim();
+    if requirement.contains([',', '*', '<', '>', '~']) {
+        return None;
+    }
+    let exact = requirement.starts_with('=');
+    let mut numbers = requirement.trim_start_matches(['=', '^']).trim().split('.').map(|n| n.parse::<u64>().ok()).collect::<Option<Vec<u64>>>()?;
+    numbers.resize(3, 0);
+    Some((exact, numbers))
+}
+
+/// Versions sharing this key are semver compatible.
+fn compatibility_key(version: &[u64]) -> &[u64] {
+    match version.iter().position(|n| *n != 0) {
+        Some(index) => &version[..=index],
+        None => version,
+    }
+}
@@ -6,25 +6,29 @@ use std::path::{Path, PathBuf};
 use askama::Template;
 use teo_parser::r#type::Type;
 use teo_runtime::namespace::Namespace;
-use teo_result::Result;
+use teo_runtime::model::relation::Relation;
+use teo_result::{Error, Result};
 use teo_runtime::model::field::typed::Typed;
 use teo_runtime::traits::documentable::Documentable;
 use teo_runtime::traits::named::Named;
 use teo_runtime::model::field::is_optional::IsOptional;
 use std::str::FromStr;
-use maplit::btreeset;
+use maplit::{btreemap, btreeset};
 use teo_parser::r#type::reference::Reference;
 use teo_parser::r#type::synthesized_enum_reference::SynthesizedEnumReference;
 use teo_parser::r#type::synthesized_shape_reference::SynthesizedShapeReference;
-use tokio::fs;
-use toml_edit::{Document, value};
 use crate::entity::ctx::Ctx;
 use crate::entity::generator::Generator;
 use crate::entity::generators::rust;
+use crate::entity::generators::rust::cargo::{CargoManifest, crate_version};
+use crate::naming::{check_collisions, Case, Naming};
 use crate::outline::outline::{Mode, Outline};
 use crate::utils::file::FileUtil;
+use crate::utils::templates::TemplateOverrides;
 use crate::utils::filters;
+use crate::utils::filters::Language;
 use crate::utils::lookup::Lookup;
+use inflector::Inflector;
 
 fn format_model_path(path: &Vec<String>) -> String {
     "vec![".to_owned() + &path.iter().map(|p| format!("\"{}\".to_owned()", p)).collect::<Vec<String>>().join(", ") + "]"
@@ -38,19 +42,7 @@ fn super_keywords(path: &Vec<String>) -> String {
     }
 }
 
-fn fix_stdlib_new(path: &Vec<String>) -> Vec<String> {
-    path.iter().map(|s| if s.as_str() == "std" { "stdlib".to_owned() } else { s.to_string() }).collect()
-}
-
-fn fix_stdlib(name: &str) -> &str {
-    if name == "std" {
-        "stdlib"
-    } else {
-        name
-    }
-}
-
-fn fix_path_inner(components: &Vec<String>, namespace: &Namespace) -> Vec<String> {
+fn fix_path_inner(components: &Vec<String>, namespace: &Namespace, naming: &Naming) -> Vec<String> {
     let mut results = vec![];
     let namespace_path = namespace.path();
     let mut left = namespace_path.len();
@@ -59,10 +51,10 @@ fn fix_path_inner(components: &Vec<String>, namespace: &Namespace) -> Vec<String
             if component == ns_component {
                 left -= 1;
             } else {
-                results.push(fix_stdlib(component).to_owned());
+                results.push(naming.namespace_name(component));
             }
         } else {
-            results.push(fix_stdlib(component).to_owned());
+            results.push(naming.namespace_name(component));
         }
     }
     for _ in 0..left {
@@ -71,19 +63,19 @@ fn fix_path_inner(components: &Vec<String>, namespace: &Namespace) -> Vec<String
     results
 }
 
-fn fix_path_enum_reference(enum_reference: &SynthesizedEnumReference, namespace: &Namespace) -> SynthesizedEnumReference {
-    SynthesizedEnumReference {
+fn fix_path_enum_reference(enum_reference: &SynthesizedEnumReference, namespace: &Namespace, naming: &Naming) -> Result<SynthesizedEnumReference> {
+    Ok(SynthesizedEnumReference {
         kind: enum_reference.kind,
-        owner: Box::new(fix_path(enum_reference.owner.as_ref(), namespace)),
-    }
+        owner: Box::new(fix_path(enum_reference.owner.as_ref(), namespace, naming)?),
+    })
 }
 
-fn fix_path_shape_reference(shape_reference: &SynthesizedShapeReference, namespace: &Namespace) -> SynthesizedShapeReference {
-    SynthesizedShapeReference {
+fn fix_path_shape_reference(shape_reference: &SynthesizedShapeReference, namespace: &Namespace, naming: &Naming) -> Result<SynthesizedShapeReference> {
+    Ok(SynthesizedShapeReference {
         kind: shape_reference.kind,
-        owner: Box::new(fix_path(shape_reference.owner.as_ref(), namespace)),
+        owner: Box::new(fix_path(shape_reference.owner.as_ref(), namespace, naming)?),
         without: shape_reference.without.clone(),
-    }
+    })
 }
 
 fn fix_type_param(o: &String) -> String {
@@ -100,8 +92,8 @@ fn fix_type_param(o: &String) -> String {
     }
 }
 
-fn fix_path(t: &Type, namespace: &Namespace) -> Type {
-    match t {
+fn fix_path(t: &Type, namespace: &Namespace, naming: &Naming) -> Result<Type> {
+    Ok(match t {
         Type::Undetermined => t.clone(),
         Type::Ignored => t.clone(),
         Type::Any => t.clone(),
@@ -120,24 +112,24 @@ fn fix_path(t: &Type, namespace: &Namespace) -> Type {
         Type::Regex => t.clone(),
         Type::Model => t.clone(),
         Type::DataSet => t.clone(),
-        Type::Enumerable(inner) => Type::Enumerable(Box::new(fix_path(inner.as_ref(), namespace))),
-        Type::Array(inner) => Type::Array(Box::new(fix_path(inner.as_ref(), namespace))),
-        Type::Dictionary(inner) => Type::Dictionary(Box::new(fix_path(inner.as_ref(), namespace))),
-        Type::Tuple(types) => Type::Tuple(types.iter().map(|t| fix_path(t, namespace)).collect()),
-        Type::Range(inner) => Type::Range(Box::new(fix_path(inner.as_ref(), namespace))),
-        Type::Union(types) => Type::Union(types.iter().map(|t| fix_path(t, namespace)).collect()),
-        Type::EnumVariant(reference) => Type::EnumVariant(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace))),
-        Type::InterfaceObject(reference, types) => Type::InterfaceObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace)), types.iter().map(|t| fix_path(t, namespace)).collect()),
-        Type::ModelObject(reference) => Type::ModelObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace))),
-        Type::StructObject(reference, types) => Type::StructObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace)), types.iter().map(|t| fix_path(t, namespace)).collect()),
+        Type::Enumerable(inner) => Type::Enumerable(Box::new(fix_path(inner.as_ref(), namespace, naming)?)),
+        Type::Array(inner) => Type::Array(Box::new(fix_path(inner.as_ref(), namespace, naming)?)),
+        Type::Dictionary(inner) => Type::Dictionary(Box::new(fix_path(inner.as_ref(), namespace, naming)?)),
+        Type::Tuple(types) => Type::Tuple(types.iter().map(|t| fix_path(t, namespace, naming)).collect::<Result<Vec<Type>>>()?),
+        Type::Range(inner) => Type::Range(Box::new(fix_path(inner.as_ref(), namespace, naming)?)),
+        Type::Union(types) => Type::Union(types.iter().map(|t| fix_path(t, namespace, naming)).collect::<Result<Vec<Type>>>()?),
+        Type::EnumVariant(reference) => Type::EnumVariant(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, naming))),
+        Type::InterfaceObject(reference, types) => Type::InterfaceObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, naming)), types.iter().map(|t| fix_path(t, namespace, naming)).collect::<Result<Vec<Type>>>()?),
+        Type::ModelObject(reference) => Type::ModelObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, naming))),
+        Type::StructObject(reference, types) => Type::StructObject(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, naming)), types.iter().map(|t| fix_path(t, namespace, naming)).collect::<Result<Vec<Type>>>()?),
         Type::GenericItem(name) => Type::GenericItem(name.clone()),
         Type::Keyword(keyword) => Type::Keyword(keyword.clone()),
-        Type::Optional(inner) => Type::Optional(Box::new(fix_path(inner.as_ref(), namespace))),
-        Type::SynthesizedShapeReference(shape_reference) => Type::SynthesizedShapeReference(fix_path_shape_reference(shape_reference, namespace)),
-        Type::SynthesizedEnumReference(enum_reference) => Type::SynthesizedEnumReference(fix_path_enum_reference(enum_reference, namespace)),
-        Type::DeclaredSynthesizedShape(reference, inner) => Type::DeclaredSynthesizedShape(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace)), Box::new(fix_path(inner, namespace))),
-        _ => panic!(),
-    }
+        Type::Optional(inner) => Type::Optional(Box::new(fix_path(inner.as_ref(), namespace, naming)?)),
+        Type::SynthesizedShapeReference(shape_reference) => Type::SynthesizedShapeReference(fix_path_shape_reference(shape_reference, namespace, naming)?),
+        Type::SynthesizedEnumReference(enum_reference) => Type::SynthesizedEnumReference(fix_path_enum_reference(enum_reference, namespace, naming)?),
+        Type::DeclaredSynthesizedShape(reference, inner) => Type::DeclaredSynthesizedShape(Reference::new(reference.path().clone(), fix_path_inner(reference.string_path(), namespace, naming)), Box::new(fix_path(inner, namespace, naming)?)),
+        _ => Err(Error::new(format!("cannot generate type `{}' in namespace `{}'", t, namespace.path().join("."))))?,
+    })
 }
 
 fn where_generics_declaration(names: &Vec<String>) -> String {
@@ -190,15 +182,15 @@ fn phantom_generics(names: &Vec<String>) -> String {
     }
 }
 
-fn unwrap_extend(extend: &Type, namespace: &Namespace) -> Result<String> {
-    let interface_path = (fix_path_inner(extend.as_interface_object().unwrap().0.string_path(), namespace)).join("::");
-    let a = extend.as_interface_object().unwrap().1;
+fn unwrap_extend(extend: &Type, namespace: &Namespace, naming: &Naming) -> Result<String> {
+    let (reference, a) = extend.as_interface_object().ok_or_else(|| Error::new(format!("cannot extend `{}', only interfaces can be extended", extend)))?;
+    let interface_path = (fix_path_inner(reference.string_path(), namespace, naming)).join("::");
     Ok(if a.is_empty() {
         interface_path + "Trait"
     } else {
         interface_path + "Trait" + "<" + &a.iter().map(|e| {
             if e.is_interface_object() {
-                unwrap_extend(e, namespace)
+                unwrap_extend(e, namespace, naming)
             } else {
                 Ok(rust::lookup(e)?)
             }
@@ -206,12 +198,203 @@ fn unwrap_extend(extend: &Type, namespace: &Namespace) -> Result<String> {
     })
 }
 
-fn unwrap_extends(extends: &Vec<Type>, namespace: &Namespace) -> Result<Vec<String>> {
+fn unwrap_extends(extends: &Vec<Type>, namespace: &Namespace, naming: &Naming) -> Result<Vec<String>> {
     Ok(extends.iter().map(|extend| {
-        unwrap_extend(extend, namespace)
+        unwrap_extend(extend, namespace, naming)
     }).collect::<Result<Vec<String>>>()?)
 }
 
+/// The keys of a relation through a join model. The join model refers to
+/// the owner's `local_fields` with `local_references`, and to the related
+/// model's `foreign_references` with `foreign_fields`.
+pub(self) struct ThroughKeys {
+    join_path: Vec<String>,
+    local_fields: Vec<String>,
+    local_references: Vec<String>,
+    foreign_fields: Vec<String>,
+    foreign_references: Vec<String>,
+}
+
+impl ThroughKeys {
+
+    pub(self) fn join_path(&self) -> &Vec<String> {
+        &self.join_path
+    }
+
+    pub(self) fn local_fields(&self) -> &Vec<String> {
+        &self.local_fields
+    }
+
+    pub(self) fn local_references(&self) -> &Vec<String> {
+        &self.local_references
+    }
+
+    pub(self) fn foreign_fields(&self) -> &Vec<String> {
+        &self.foreign_fields
+    }
+
+    pub(self) fn foreign_references(&self) -> &Vec<String> {
+        &self.foreign_references
+    }
+}
+
+fn through_keys(relation: &Relation, main_namespace: &Namespace) -> Result<ThroughKeys> {
+    let Some(join_path) = relation.through_path() else {
+        return Err(Error::new(format!("relation `{}' doesn't go through a join model", relation.name())));
+    };
+    let Some(join) = main_namespace.model_at_path(join_path) else {
+        return Err(Error::new(format!("cannot find the join model `{}' of relation `{}'", join_path.join("."), relation.name())));
+    };
+    let join_relation = |name: Option<&str>| name.and_then(|name| join.relation(name)).ok_or_else(|| {
+        Error::new(format!("join model `{}' of relation `{}' doesn't relate to both sides", join_path.join("."), relation.name()))
+    });
+    let local = join_relation(relation.local())?;
+    let foreign = join_relation(relation.foreign())?;
+    Ok(ThroughKeys {
+        join_path: join_path.clone(),
+        local_fields: local.references().clone(),
+        local_references: local.fields().clone(),
+        foreign_fields: foreign.fields().clone(),
+        foreign_references: foreign.references().clone(),
+    })
+}
+
+/// A typed query builder on a model, built from the model's `{action}Args` shape.
+pub(self) struct QueryBuilder {
+    name: &'static str,
+    method: &'static str,
+    args: String,
+    exec: &'static str,
+    output: String,
+    returns_objects: bool,
+}
+
+impl QueryBuilder {
+
+    pub(self) fn name(&self) -> &str {
+        self.name
+    }
+
+    pub(self) fn method(&self) -> &str {
+        self.method
+    }
+
+    pub(self) fn args(&self) -> &str {
+        self.args.as_str()
+    }
+
+    pub(self) fn exec(&self) -> &str {
+        self.exec
+    }
+
+    pub(self) fn output(&self) -> &str {
+        self.output.as_str()
+    }
+
+    pub(self) fn returns_objects(&self) -> bool {
+        self.returns_objects
+    }
+}
+
+fn query_builders(model_name: &str) -> Vec<QueryBuilder> {
+    vec![
+        QueryBuilder { name: "FindMany", method: "find_many", args: format!("{model_name}FindManyArgs"), exec: "find_many_objects", output: format!("Vec<{model_name}>"), returns_objects: true },
+        QueryBuilder { name: "FindUnique", method: "find_unique", args: format!("{model_name}FindUniqueArgs"), exec: "find_unique_object", output: format!("Option<{model_name}>"), returns_objects: true },
+        QueryBuilder { name: "FindFirst", method: "find_first", args: format!("{model_name}FindFirstArgs"), exec: "find_first_object", output: format!("Option<{model_name}>"), returns_objects: true },
+        QueryBuilder { name: "Count", method: "count", args: format!("{model_name}CountArgs"), exec: "count_objects", output: "usize".to_owned(), returns_objects: false },
+        // `aggregate` and `group_by` already run a query from a value
+        QueryBuilder { name: "Aggregate", method: "aggregate_query", args: format!("{model_name}AggregateArgs"), exec: "aggregate", output: format!("{model_name}AggregateResult"), returns_objects: false },
+        QueryBuilder { name: "GroupBy", method: "group_by_query", args: format!("{model_name}GroupByArgs"), exec: "group_by", output: format!("Vec<{model_name}AggregateResult>"), returns_objects: false },
+        QueryBuilder { name: "Create", method: "create", args: format!("{model_name}CreateArgs"), exec: "create_object_from_args", output: model_name.to_owned(), returns_objects: false },
+    ]
+}
+
+fn builder_method_name(name: &str) -> String {
+    let name = name.to_snake_case();
+    if Language::Rust.is_reserved(&name) {
+        name + "_"
+    } else {
+        name
+    }
+}
+
+fn has_interface(outline: &Outline, model_name: &str, suffix: &str) -> bool {
+    outline.interfaces().iter().any(|interface| interface.model_name() == model_name && interface.name().strip_prefix(model_name) == Some(suffix))
+}
+
+/// Which third party types a generated module refers to.
+#[derive(Default)]
+pub(self) struct TypeUsage {
+    date: bool,
+    datetime: bool,
+    decimal: bool,
+    object_id: bool,
+}
+
+impl TypeUsage {
+
+    fn record_namespace(&mut self, namespace: &Namespace, outline: &Outline) {
+        for model in namespace.models().values() {
+            for field in model.fields().values() {
+                self.record(field.r#type());
+            }
+            for property in model.properties().values() {
+                self.record(property.r#type());
+            }
+        }
+        for interface in outline.interfaces() {
+            for field in interface.fields() {
+                self.record(field.r#type());
+            }
+        }
+    }
+
+    fn record(&mut self, t: &Type) {
+        match t {
+            Type::Date => self.date = true,
+            Type::DateTime => self.datetime = true,
+            Type::Decimal => self.decimal = true,
+            Type::ObjectId => self.object_id = true,
+            Type::Optional(inner) | Type::Array(inner) | Type::Enumerable(inner) | Type::Dictionary(inner) | Type::Range(inner) => self.record(inner.as_ref()),
+            Type::Tuple(types) | Type::Union(types) | Type::InterfaceObject(_, types) => for t in types {
+                self.record(t);
+            },
+            _ => (),
+        }
+    }
+
+    fn package_requirements(&self) -> BTreeSet<&'static str> {
+        let mut requirements = btreeset!["indexmap", "serde"];
+        if self.date || self.datetime {
+            requirements.insert("chrono");
+        }
+        if self.decimal {
+            requirements.insert("bigdecimal");
+        }
+        if self.object_id {
+            requirements.insert("bson");
+        }
+        requirements
+    }
+}
+
+#[derive(Template)]
+#[template(path = "entity/rust/helpers/interface.rs.jinja", escape = "none")]
+pub(self) struct RustInterfaceHelperTemplate {
+    pub(self) has_date: bool,
+    pub(self) has_datetime: bool,
+    pub(self) has_decimal: bool,
+    pub(self) has_object_id: bool,
+}
+
+#[derive(Template)]
+#[template(path = "entity/rust/helpers/teon_serde.rs.jinja", escape = "none")]
+pub(self) struct RustTeonSerdeHelperTemplate {
+    pub(self) has_date: bool,
+    pub(self) has_datetime: bool,
+    pub(self) has_decimal: bool,
+}
+
 #[derive(Template)]
 #[template(path = "entity/rust/mod.rs.jinja", escape = "none")]
 pub(self) struct RustModuleTemplate<'a> {
@@ -231,10 +414,15 @@ pub(self) struct RustModuleTemplate<'a> {
     pub(self) where_generics_declaration: &'static dyn Fn(&Vec<String>) -> String,
     pub(self) where_generics_declaration_a: &'static dyn Fn(&Vec<String>) -> String,
     pub(self) phantom_generics: &'static dyn Fn(&Vec<String>) -> String,
-    pub(self) unwrap_extends: &'static dyn Fn(&Vec<Type>, &Namespace) -> Result<Vec<String>>,
+    pub(self) unwrap_extends: &'static dyn Fn(&Vec<Type>, &Namespace, &Naming) -> Result<Vec<String>>,
     pub(self) super_keywords: &'static dyn Fn(&Vec<String>) -> String,
-    pub(self) fix_path: &'static dyn Fn(&Type, &Namespace) -> Type,
+    pub(self) fix_path: &'static dyn Fn(&Type, &Namespace, &Naming) -> Result<Type>,
     pub(self) fix_type_param: &'static dyn Fn(&String) -> String,
+    pub(self) query_builders: &'static dyn Fn(&str) -> Vec<QueryBuilder>,
+    pub(self) builder_method_name: &'static dyn Fn(&str) -> String,
+    pub(self) has_interface: &'static dyn Fn(&Outline, &str, &str) -> bool,
+    pub(self) main_namespace: &'a Namespace,
+    pub(self) through_keys: &'static dyn Fn(&Relation, &Namespace) -> Result<ThroughKeys>,
 }
 
 unsafe impl Send for RustModuleTemplate<'_> { }
@@ -242,47 +430,17 @@ unsafe impl Sync for RustModuleTemplate<'_> { }
 
 impl<'a> RustModuleTemplate<'a> {
 
-    fn new(namespace: &'a Namespace, main_namespace: &'a Namespace) -> Self {
-        let mut has_date = false;
-        let mut has_datetime = false;
-        let mut has_decimal = false;
-        let mut has_object_id = false;
-        if !namespace.is_std() {
-            namespace.models().values().for_each(|c| c.fields().values().for_each(|f| {
-                if f.r#type().test(&Type::Date) {
-                    has_date = true;
-                } else if f.r#type().test(&Type::DateTime) {
-                    has_datetime = true;
-                } else if f.r#type().test(&Type::Decimal) {
-                    has_decimal = true;
-                } else if f.r#type().test(&Type::ObjectId) {
-                    has_object_id = true;
-                }
-            }));
-            namespace.interfaces().values().for_each(|c| c.fields().values().for_each(|f| {
-                if f.r#type().test(&Type::Date) {
-                    has_date = true;
-                } else if f.r#type().test(&Type::DateTime) {
-                    has_datetime = true;
-                } else if f.r#type().test(&Type::Decimal) {
-                    has_decimal = true;
-                } else if f.r#type().test(&Type::ObjectId) {
-                    has_object_id = true;
-                }
-            }));
-        } else {
-            has_date = true;
-            has_datetime = true;
-            has_decimal = true;
-            has_object_id = true;
-        }
-        Self {
+    fn new(namespace: &'a Namespace, main_namespace: &'a Namespace, naming: &Naming) -> Result<Self> {
+        let outline = Outline::new(namespace, Mode::Entity, main_namespace, false, naming)?;
+        let mut usage = TypeUsage::default();
+        usage.record_namespace(namespace, &outline);
+        Ok(Self {
             namespace,
-            outline: Outline::new(namespace, Mode::Entity, main_namespace, false),
-            has_date,
-            has_datetime,
-            has_decimal,
-            has_object_id,
+            outline,
+            has_date: usage.date,
+            has_datetime: usage.datetime,
+            has_decimal: usage.decimal,
+            has_object_id: usage.object_id,
             lookup: &rust::lookup,
             lookup_ref: &rust::lookup_ref,
             lookup_ref_mut: &rust::lookup_ref_mut,
@@ -297,7 +455,12 @@ impl<'a> RustModuleTemplate<'a> {
             super_keywords: &super_keywords,
             fix_path: &fix_path,
             fix_type_param: &fix_type_param,
-        }
+            query_builders: &query_builders,
+            builder_method_name: &builder_method_name,
+            has_interface: &has_interface,
+            main_namespace,
+            through_keys: &through_keys,
+        })
     }
 }
 
@@ -314,63 +477,64 @@ impl RustGenerator {
             Some(path) => path,
             None => return Ok(()),
         };
-        let toml = fs::read_to_string(&cargo_toml).await.unwrap();
-        let mut doc = toml.parse::<Document>().expect("`Cargo.toml' has invalid content");
-        let deps = doc.get_mut("dependencies").unwrap();
-        if package_requirements.contains(&"chrono") {
-            if deps.get("chrono").is_none() {
-                deps["chrono"]["version"] = value("0.4");
-            }
-        }
-        if package_requirements.contains(&"bson") {
-            if deps.get("bson").is_none() {
-                deps["bson"]["version"] = value("2.9.0");
-            }
-        }
-        if package_requirements.contains(&"bigdecimal") {
-            if deps.get("bigdecimal").is_none() {
-                deps["bigdecimal"]["version"] = value("=0.3.1");
+        let mut manifest = CargoManifest::load(&cargo_toml).await?;
+        for name in package_requirements {
+            if *name == "serde" {
+                // serde impls are gated behind the `teo-serde` feature
+                manifest.add_optional_dependency("serde", "1.0")?;
+                manifest.add_optional_dependency("serde_json", "1.0")?;
+                manifest.add_feature("teo-serde", &["serde", "serde_json"]);
+            } else {
+                manifest.add_dependency(name, crate_version(name))?;
             }
         }
-        if package_requirements.contains(&"indexmap") {
-            if deps.get("indexmap").is_none() {
-                deps["indexmap"]["version"] = value("2.2.6");
-            }
+        manifest.save().await
+    }
+
+    fn collect_type_usage(&self, namespace: &Namespace, main_namespace: &Namespace, naming: &Naming, usage: &mut TypeUsage) -> Result<()> {
+        let outline = Outline::new(namespace, Mode::Entity, main_namespace, false, naming)?;
+        usage.record_namespace(namespace, &outline);
+        for child in namespace.namespaces().values() {
+            self.collect_type_usage(child, main_namespace, naming, usage)?;
         }
-        fs::write(cargo_toml, doc.to_string()).await?;
         Ok(())
     }
 
-    async fn generate_module_file(&self, namespace: &Namespace, filename: impl AsRef<Path>, generator: &FileUtil, main_namespace: &Namespace) -> Result<()> {
-        let template = RustModuleTemplate::new(namespace, main_namespace);
-        generator.generate_file(filename.as_ref(), template.render().unwrap()).await?;
+    async fn generate_module_file(&self, namespace: &Namespace, filename: impl AsRef<Path>, generator: &FileUtil, main_namespace: &Namespace, templates: &TemplateOverrides, naming: &Naming) -> Result<()> {
+        let template = RustModuleTemplate::new(namespace, main_namespace, naming)?;
+        template.outline.try_each_type(|t| template.lookup.call(&fix_path(t, namespace, naming)?).map(|_| ()))?;
+        generator.generate_file(filename.as_ref(), templates.render_for_namespace("entity/rust/mod.rs.jinja", &template, namespace)?).await?;
         Ok(())
     }
 
     #[async_recursion]
-    async fn generate_module_for_namespace(&self, namespace: &Namespace, generator: &FileUtil, main_namespace: &Namespace) -> Result<()> {
+    async fn generate_module_for_namespace(&self, namespace: &Namespace, generator: &FileUtil, main_namespace: &Namespace, templates: &TemplateOverrides, naming: &Naming) -> Result<()> {
         if namespace.is_main() || !namespace.namespaces().is_empty() {
             // create dir and create mod.rs
             if !namespace.is_main() {
-                generator.ensure_directory(fix_stdlib_new(namespace.path()).join("/")).await?;
+                generator.ensure_directory(naming.namespace_path(namespace.path()).join("/")).await?;
             }
             self.generate_module_file(
                 namespace,
-                PathBuf::from_str(&fix_stdlib_new(namespace.path()).join("/")).unwrap().join("mod.rs"),
+                PathBuf::from_str(&naming.namespace_path(namespace.path()).join("/")).unwrap().join("mod.rs"),
                 generator,
-                main_namespace
+                main_namespace,
+                templates,
+                naming,
             ).await?;
         } else {
             // create file
             self.generate_module_file(
                 namespace,
-                PathBuf::from_str(&fix_stdlib_new(&namespace.path().iter().rev().skip(1).rev().map(Clone::clone).collect::<Vec<String>>()).join("/")).unwrap().join(fix_stdlib(namespace.path().last().unwrap()).to_string() + ".rs"),
+                PathBuf::from_str(&naming.namespace_path(&namespace.path()[..namespace.path().len() - 1]).join("/")).unwrap().join(naming.namespace_name(namespace.path().last().unwrap()) + ".rs"),
                 generator,
                 main_namespace,
+                templates,
+                naming,
             ).await?;
         }
         for namespace in namespace.namespaces().values() {
-            self.generate_module_for_namespace(namespace, generator, main_namespace).await?;
+            self.generate_module_for_namespace(namespace, generator, main_namespace, templates, naming).await?;
         }
         Ok(())
     }
@@ -379,19 +543,43 @@ impl RustGenerator {
 #[async_trait]
 impl Generator for RustGenerator {
 
+    fn naming(&self) -> Naming {
+        // `std` would shadow the standard library
+        Naming {
+            fields: Case::Snake,
+            enum_members: Case::Snake,
+            namespace_renames: btreemap! { "std".to_owned() => "stdlib".to_owned() },
+            ..Naming::default()
+        }
+    }
+
+    fn check_naming(&self, naming: &Naming, main_namespace: &Namespace) -> Result<()> {
+        check_collisions(naming, main_namespace, Mode::Entity, "Rust entities")
+    }
+
     async fn generate_entity_files(&self, ctx: &Ctx, generator: &FileUtil) -> Result<()> {
         // module files
-        self.generate_module_for_namespace(ctx.main_namespace, generator, ctx.main_namespace).await?;
-        // helpers
+        self.generate_module_for_namespace(ctx.main_namespace, generator, ctx.main_namespace, &ctx.templates, ctx.naming()).await?;
+        // helpers import only the crates the schema's types need
+        let mut usage = TypeUsage::default();
+        self.collect_type_usage(ctx.main_namespace, ctx.main_namespace, ctx.naming(), &mut usage)?;
         generator.ensure_directory("helpers").await?;
-        generator.generate_file("helpers/mod.rs", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/entity/rust/helpers/mod.rs.jinja"))).await?;
-        generator.generate_file("helpers/interface.rs", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/entity/rust/helpers/interface.rs.jinja"))).await?;
+        generator.generate_file("helpers/mod.rs", ctx.templates.render_static("entity/rust/helpers/mod.rs.jinja", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/entity/rust/helpers/mod.rs.jinja")))?).await?;
+        generator.generate_file("helpers/interface.rs", ctx.templates.render("entity/rust/helpers/interface.rs.jinja", &RustInterfaceHelperTemplate {
+            has_date: usage.date,
+            has_datetime: usage.datetime,
+            has_decimal: usage.decimal,
+            has_object_id: usage.object_id,
+        })?).await?;
+        generator.generate_file("helpers/query.rs", ctx.templates.render_static("entity/rust/helpers/query.rs.jinja", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/entity/rust/helpers/query.rs.jinja")))?).await?;
+        generator.generate_file("helpers/relations.rs", ctx.templates.render_static("entity/rust/helpers/relations.rs.jinja", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/entity/rust/helpers/relations.rs.jinja")))?).await?;
+        generator.generate_file("helpers/teon_serde.rs", ctx.templates.render("entity/rust/helpers/teon_serde.rs.jinja", &RustTeonSerdeHelperTemplate {
+            has_date: usage.date,
+            has_datetime: usage.datetime,
+            has_decimal: usage.decimal,
+        })?).await?;
         // Modify files
-        let mut package_requirements = btreeset![];
-        package_requirements.insert("chrono");
-        package_requirements.insert("bigdecimal");
-        package_requirements.insert("bson");
-        package_requirements.insert("indexmap");
+        let package_requirements = usage.package_requirements();
         self.find_and_update_cargo_toml(&package_requirements, generator).await?;
         Ok(())
     }
@@ -43,7 +43,7 @@ pub(crate) fn lookup(t: &Type) -> Result<String> {
         Type::SynthesizedShapeReference(shape_reference) => shape_reference_lookup(shape_reference, "::", Mode::Entity)?,
         Type::SynthesizedEnumReference(enum_reference) => enum_reference_lookup(enum_reference, "::")?,
         Type::DeclaredSynthesizedShape(reference, owner) => declared_shape_lookup(reference, owner, "::")?,
-        _ => Err(Error::new("encountered unhandled type in lookup"))?,
+        _ => Err(Error::new(format!("encountered unhandled type `{}' in lookup", t)))?,
     })
 }
 
@@ -85,7 +85,7 @@ pub(crate) fn lookup_ref(t: &Type) -> Result<String> {
         Type::SynthesizedShapeReference(shape_reference) => "&".to_owned() + &shape_reference_lookup(shape_reference, "::", Mode::Entity)?,
         Type::SynthesizedEnumReference(enum_reference) => "&".to_owned() + &enum_reference_lookup(enum_reference, "::")?,
         Type::DeclaredSynthesizedShape(reference, owner) => "&".to_owned() + &declared_shape_lookup(reference, owner, ".")?,
-        _ => Err(Error::new("encountered unhandled type in lookup"))?,
+        _ => Err(Error::new(format!("encountered unhandled type `{}' in lookup", t)))?,
     })
 }
 
@@ -123,6 +123,6 @@ pub(crate) fn lookup_ref_mut(t: &Type) -> Result<String> {
         Type::SynthesizedShapeReference(shape_reference) => shape_reference_lookup(shape_reference, "::", Mode::Entity)?,
         Type::SynthesizedEnumReference(enum_reference) => enum_reference_lookup(enum_reference, "::")?,
         Type::DeclaredSynthesizedShape(reference, owner) => declared_shape_lookup(reference, owner, ".")?,
-        _ => Err(Error::new("encountered unhandled type in lookup"))?,
+        _ => Err(Error::new(format!("encountered unhandled type `{}' in lookup", t)))?,
     })
 }
\ No newline at end of file
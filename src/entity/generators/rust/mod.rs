@@ -1,5 +1,6 @@
 pub(crate) mod lookup;
 pub(crate) mod gen;
+pub(crate) mod cargo;
 
 pub(crate) use lookup::lookup;
 pub(crate) use lookup::lookup_ref;
@@ -1,41 +1,56 @@
 mod generators;
 mod ctx;
 mod generator;
+mod options;
+mod registry;
 
-use teo_result::Result;
-use teo_runtime::config::entity::{Entity, Runtime};
+use teo_result::{Error, Result};
+use teo_runtime::config::entity::Entity;
 use teo_runtime::namespace::Namespace;
-use generators::*;
-use crate::entity::ctx::Ctx;
-use crate::entity::generator::Generator;
+use crate::entity::registry::builtin_generator_name;
 use crate::utils::file::FileUtil;
 
+pub use ctx::Ctx;
+pub use generator::Generator;
+pub use options::{EntityOptions, GoOptions, PythonOptions};
+pub use registry::{register_generator, registered_generator, registered_generator_names};
+
 pub async fn generate(main_namespace: &Namespace, entity: &Entity) -> Result<()> {
-    match entity.provider {
-        Runtime::Rust => {
-            let ctx = Ctx::new(entity, main_namespace);
-            let generator = rust::gen::RustGenerator::new();
-            gen(generator, &ctx).await
-        }
-        Runtime::Node => {
-            let ctx = Ctx::new(entity, main_namespace);
-            let generator = node::gen::NodeGenerator::new();
-            gen(generator, &ctx).await
-        }
-        Runtime::Python => {
-            let ctx = Ctx::new(entity, main_namespace);
-            let generator = python::gen::PythonGenerator::new();
-            gen(generator, &ctx).await
-        }
-    }?;
-    std::process::exit(0);
+    generate_with_options(main_namespace, entity, &EntityOptions::default()).await
+}
+
+/// Generate entities with the generator registered under the name of the
+/// built-in one for `entity.provider`. The schema can only select the
+/// built-in names, so a generator registered under another name runs
+/// through [`generate_with_generator`], or replaces a built-in one by
+/// registering under its name.
+pub async fn generate_with_options(main_namespace: &Namespace, entity: &Entity, options: &EntityOptions) -> Result<()> {
+    generate_with_generator(main_namespace, builtin_generator_name(&entity.provider), entity, options).await
+}
+
+/// Generate Go entities. The entity config has no Go runtime yet, so the
+/// provider in `entity` is ignored.
+pub async fn generate_go(main_namespace: &Namespace, entity: &Entity, options: &EntityOptions) -> Result<()> {
+    generate_with_generator(main_namespace, "go", entity, options).await
+}
+
+/// Generate entities with the generator registered as `name`. The provider
+/// in `entity` is ignored.
+pub async fn generate_with_generator(main_namespace: &Namespace, name: &str, entity: &Entity, options: &EntityOptions) -> Result<()> {
+    let Some(generator) = registered_generator(name) else {
+        return Err(Error::new(format!("entity generator `{}' is not registered", name)));
+    };
+    let naming = options.naming.get(name).cloned().unwrap_or_else(|| generator.naming());
+    generator.check_naming(&naming, main_namespace)?;
+    let ctx = Ctx::new(entity, main_namespace, options, naming)?;
+    gen(generator.as_ref(), &ctx).await
 }
 
-async fn gen<T: Generator>(entity_generator: T, ctx: &Ctx<'_>) -> Result<()> {
+async fn gen(entity_generator: &dyn Generator, ctx: &Ctx<'_>) -> Result<()> {
     let dest = &ctx.conf.dest;
     let dest_dir = std::env::current_dir()?.join(dest);
     let generator = FileUtil::new(&dest_dir);
     generator.ensure_root_directory().await?;
     entity_generator.generate_entity_files(ctx, &generator).await?;
-    Ok(())
+    generator.remove_stale_files().await
 }
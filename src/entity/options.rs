@@ -0,0 +1,39 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use crate::naming::Naming;
+
+/// # Entity options
+///
+/// Generator specific options which are not part of the schema's `entity` config.
+#[derive(Clone, Default)]
+pub struct EntityOptions {
+    pub python: PythonOptions,
+    pub go: GoOptions,
+    /// A directory of templates overriding the built-in ones at the same
+    /// relative path, e.g. `entity/rust/helpers/query.rs.jinja`.
+    pub templates: Option<PathBuf>,
+    /// Naming conventions replacing the generator's own, by the name the
+    /// generator is registered as, e.g. `"python"`.
+    pub naming: BTreeMap<String, Naming>,
+}
+
+#[derive(Clone, Default)]
+pub struct PythonOptions {
+    /// Generate Pydantic v2 models for interfaces and synthesized shapes
+    /// instead of `TypedDict`s.
+    pub pydantic: bool,
+}
+
+#[derive(Clone, Default)]
+pub struct GoOptions {
+    /// The module path of the Go runtime binding the generated packages
+    /// import as `teo`, `github.com/teodevgroup/teo-go` when not set.
+    pub runtime_module: Option<String>,
+}
+
+impl GoOptions {
+
+    pub fn runtime_module(&self) -> &str {
+        self.runtime_module.as_deref().unwrap_or("github.com/teodevgroup/teo-go")
+    }
+}
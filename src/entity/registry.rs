@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+use once_cell::sync::Lazy;
+use teo_runtime::config::entity::Runtime;
+use crate::entity::generator::Generator;
+use crate::entity::generators::*;
+
+static GENERATORS: Lazy<RwLock<BTreeMap<String, Arc<dyn Generator>>>> = Lazy::new(|| {
+    let mut generators: BTreeMap<String, Arc<dyn Generator>> = BTreeMap::new();
+    generators.insert("rust".to_owned(), Arc::new(rust::gen::RustGenerator::new()));
+    generators.insert("node".to_owned(), Arc::new(node::gen::NodeGenerator::new()));
+    generators.insert("python".to_owned(), Arc::new(python::gen::PythonGenerator::new()));
+    generators.insert("go".to_owned(), Arc::new(go::gen::GoGenerator::new()));
+    generators.insert("ir".to_owned(), Arc::new(ir::gen::IrGenerator::new()));
+    RwLock::new(generators)
+});
+
+/// Register `generator` as `name`. A generator registered with a built-in
+/// name replaces the built-in one.
+pub fn register_generator(name: impl Into<String>, generator: impl Generator + 'static) {
+    GENERATORS.write().unwrap().insert(name.into(), Arc::new(generator));
+}
+
+pub fn registered_generator(name: &str) -> Option<Arc<dyn Generator>> {
+    GENERATORS.read().unwrap().get(name).cloned()
+}
+
+/// The names of the built-in and registered generators, sorted.
+pub fn registered_generator_names() -> Vec<String> {
+    GENERATORS.read().unwrap().keys().cloned().collect()
+}
+
+/// The name the built-in generator for `runtime` is registered as.
+pub(crate) fn builtin_generator_name(runtime: &Runtime) -> &'static str {
+    match runtime {
+        Runtime::Rust => "rust",
+        Runtime::Node => "node",
+        Runtime::Python => "python",
+    }
+}
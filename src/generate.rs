@@ -0,0 +1,151 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use teo_result::{Error, Result};
+use teo_runtime::namespace::Namespace;
+use tokio::task::JoinSet;
+use crate::client::{self, ClientOptions};
+use crate::entity::{self, EntityOptions};
+use crate::admin;
+use crate::utils::message::red_message;
+
+/// # Generate options
+#[derive(Clone)]
+pub struct GenerateOptions {
+    pub client: ClientOptions,
+    pub entity: EntityOptions,
+    /// Generate the admin dashboard configured in the schema. It fetches its
+    /// sources over the network.
+    pub admin: bool,
+}
+
+impl Default for GenerateOptions {
+
+    fn default() -> Self {
+        Self {
+            client: ClientOptions::default(),
+            entity: EntityOptions::default(),
+            admin: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetKind {
+    Client,
+    Entity,
+    Admin,
+}
+
+impl Display for TargetKind {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TargetKind::Client => "client",
+            TargetKind::Entity => "entity",
+            TargetKind::Admin => "admin",
+        })
+    }
+}
+
+/// The outcome of generating one configured target.
+pub struct TargetReport {
+    pub kind: TargetKind,
+    pub name: String,
+    pub duration: Duration,
+    pub result: Result<()>,
+}
+
+/// # Generate report
+///
+/// The targets are in the order they're configured in, not the order they
+/// finished in.
+pub struct GenerateReport {
+    pub targets: Vec<TargetReport>,
+}
+
+impl GenerateReport {
+
+    pub fn succeeded(&self) -> bool {
+        self.targets.iter().all(|target| target.result.is_ok())
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &TargetReport> {
+        self.targets.iter().filter(|target| target.result.is_err())
+    }
+
+    /// One error naming every target which failed, or `Ok` when all of them
+    /// succeeded.
+    pub fn into_result(self) -> Result<()> {
+        let messages: Vec<String> = self.failures().map(|target| match &target.result {
+            Err(e) => format!("{} `{}': {}", target.kind, target.name, e),
+            Ok(()) => unreachable!(),
+        }).collect();
+        if messages.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::new(messages.join("\n")))
+        }
+    }
+}
+
+/// Generate every client, entity and admin dashboard configured in the schema
+/// concurrently. A failing target doesn't stop the others, its error is in the
+/// report.
+///
+/// Generators don't change the working directory or other process state, so
+/// targets are free to run on any worker thread. Each target still needs a
+/// destination of its own.
+pub async fn generate_all(main_namespace: Arc<Namespace>, options: &GenerateOptions) -> GenerateReport {
+    let mut tasks = JoinSet::new();
+    let mut targets = vec![];
+    for (name, client) in main_namespace.clients() {
+        let (namespace, client, options) = (main_namespace.clone(), client.clone(), options.client.clone());
+        let index = targets.len();
+        targets.push((TargetKind::Client, name.to_string()));
+        tasks.spawn(async move {
+            let start = Instant::now();
+            let result = client::generate_with_options(&namespace, &client, &options).await;
+            (index, start.elapsed(), result)
+        });
+    }
+    for (name, entity) in main_namespace.entities() {
+        let (namespace, entity, options) = (main_namespace.clone(), entity.clone(), options.entity.clone());
+        let index = targets.len();
+        targets.push((TargetKind::Entity, name.to_string()));
+        tasks.spawn(async move {
+            let start = Instant::now();
+            let result = entity::generate_with_options(&namespace, &entity, &options).await;
+            (index, start.elapsed(), result)
+        });
+    }
+    if options.admin {
+        if let Some(admin) = main_namespace.admin() {
+            let (namespace, admin, server) = (main_namespace.clone(), admin.clone(), main_namespace.server().cloned());
+            let index = targets.len();
+            targets.push((TargetKind::Admin, "admin".to_owned()));
+            tasks.spawn(async move {
+                let start = Instant::now();
+                let result = match server {
+                    Some(server) => admin::generate(&namespace, &admin, &server).await,
+                    None => Err(Error::new("the admin dashboard requires a server config")),
+                };
+                (index, start.elapsed(), result)
+            });
+        }
+    }
+    let mut results: Vec<Option<(Duration, Result<()>)>> = targets.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((index, duration, result)) => results[index] = Some((duration, result)),
+            // the index of a panicked task is lost, it's reported below
+            Err(e) => red_message("error", format!("generator task failed: {}", e)),
+        }
+    }
+    GenerateReport {
+        targets: targets.into_iter().zip(results).map(|((kind, name), result)| {
+            let (duration, result) = result.unwrap_or_else(|| (Duration::ZERO, Err(Error::new("the generator panicked"))));
+            TargetReport { kind, name, duration, result }
+        }).collect(),
+    }
+}
@@ -1,6 +1,13 @@
 pub mod client;
 pub mod entity;
 pub mod admin;
+pub mod generate;
+pub mod naming;
 pub mod outline;
+pub mod watch;
 pub(crate) mod shared;
-pub(crate) mod utils;
\ No newline at end of file
+pub(crate) mod utils;
+
+pub use utils::file::FileUtil;
+pub use utils::filters;
+pub use utils::templates::TemplateOverrides;
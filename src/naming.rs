@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use inflector::Inflector;
+use teo_result::{Error, Result};
+use teo_runtime::namespace::Namespace;
+use crate::outline::outline::{Mode, Outline};
+
+/// # Case
+///
+/// How a schema name is converted into an identifier. Leading underscores
+/// are kept, so `_count` doesn't become `count`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum Case {
+    /// The name as it's written in the schema.
+    #[default]
+    Preserve,
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+}
+
+impl Case {
+
+    pub fn apply(&self, name: &str) -> String {
+        let rest = name.trim_start_matches('_');
+        let underscores = &name[..name.len() - rest.len()];
+        let converted = match self {
+            Case::Preserve => return name.to_owned(),
+            Case::Camel => rest.to_camel_case(),
+            Case::Pascal => rest.to_pascal_case(),
+            Case::Snake => rest.to_snake_case(),
+            Case::ScreamingSnake => rest.to_snake_case().to_uppercase(),
+        };
+        format!("{}{}", underscores, converted)
+    }
+}
+
+/// # Naming
+///
+/// The identifiers a generator declares for schema items. Every generator has
+/// its own conventions, see `Generator::naming`, which
+/// [`ClientOptions::naming`](crate::client::ClientOptions::naming) and
+/// [`EntityOptions::naming`](crate::entity::EntityOptions::naming) replace.
+///
+/// Only identifiers change, requests and responses keep the schema's names.
+/// Where an identifier differs from its schema name, the generator maps it
+/// back with the language's annotation, like `@SerialName`, `@JsonKey` or a
+/// `CodingKeys` raw value. Generators which can't map a name reject a naming
+/// which changes it.
+///
+/// Types keep their schema names, since types refer to each other by their
+/// schema paths. Delegate types are named after their model, handler group
+/// or namespace with a suffix.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Naming {
+    /// Appended to the delegate type of a model or a handler group.
+    pub delegate_suffix: String,
+    /// Appended to the delegate type of a namespace.
+    pub namespace_delegate_suffix: String,
+    /// Fields of interfaces and synthesized shapes.
+    pub fields: Case,
+    /// Properties delegates expose models, handler groups and child
+    /// namespaces by, like `teo.user`.
+    pub properties: Case,
+    /// Delegate methods, one for each handler.
+    pub methods: Case,
+    pub enum_members: Case,
+    /// Modules and directories of namespaces.
+    pub namespaces: Case,
+    /// Identifiers used for fields, methods and enum members with these
+    /// schema names, in place of their case.
+    pub renames: BTreeMap<String, String>,
+    /// Identifiers used for namespaces with these schema names, in place of
+    /// their case.
+    pub namespace_renames: BTreeMap<String, String>,
+}
+
+impl Default for Naming {
+
+    fn default() -> Self {
+        Self {
+            delegate_suffix: "Delegate".to_owned(),
+            namespace_delegate_suffix: "NamespaceDelegate".to_owned(),
+            fields: Case::Preserve,
+            properties: Case::Camel,
+            methods: Case::Preserve,
+            enum_members: Case::Preserve,
+            namespaces: Case::Preserve,
+            renames: BTreeMap::new(),
+            namespace_renames: BTreeMap::new(),
+        }
+    }
+}
+
+impl Naming {
+
+    /// The delegate type of the model or handler group `name`.
+    pub fn delegate_name(&self, name: &str) -> String {
+        name.to_owned() + &self.delegate_suffix
+    }
+
+    /// The delegate type of the namespace `name`. Namespaces are usually
+    /// named in lower case, so it's in Pascal case.
+    pub fn namespace_delegate_name(&self, name: &str) -> String {
+        name.to_pascal_case() + &self.namespace_delegate_suffix
+    }
+
+    pub fn field_name(&self, name: &str) -> String {
+        self.renamed(name, self.fields)
+    }
+
+    pub fn property_name(&self, name: &str) -> String {
+        self.properties.apply(name)
+    }
+
+    pub fn method_name(&self, name: &str) -> String {
+        self.renamed(name, self.methods)
+    }
+
+    pub fn enum_member_name(&self, name: &str) -> String {
+        self.renamed(name, self.enum_members)
+    }
+
+    pub fn namespace_name(&self, name: &str) -> String {
+        match self.namespace_renames.get(name) {
+            Some(renamed) => renamed.clone(),
+            None => self.namespaces.apply(name),
+        }
+    }
+
+    /// The module path of the namespace at `path`.
+    pub fn namespace_path(&self, path: &[String]) -> Vec<String> {
+        path.iter().map(|name| self.namespace_name(name)).collect()
+    }
+
+    fn renamed(&self, name: &str, case: Case) -> String {
+        match self.renames.get(name) {
+            Some(renamed) => renamed.clone(),
+            None => case.apply(name),
+        }
+    }
+}
+
+/// Reject a naming under which two names of the same scope get one
+/// identifier, like `userName` and `user_name` both becoming `user_name`:
+/// the fields, relations and properties of a model, the fields of an
+/// interface, the members of an enum, the methods and properties of a
+/// delegate and the child namespaces of a namespace.
+pub(crate) fn check_collisions(naming: &Naming, main_namespace: &Namespace, mode: Mode, target: &str) -> Result<()> {
+    check_namespace_collisions(naming, main_namespace, main_namespace, mode, target)
+}
+
+fn check_namespace_collisions(naming: &Naming, namespace: &Namespace, main_namespace: &Namespace, mode: Mode, target: &str) -> Result<()> {
+    for model in namespace.models().values() {
+        let names = model.fields().keys().chain(model.relations().keys()).chain(model.properties().keys());
+        check_scope(names.map(|name| (name.as_str(), naming.field_name(name))), || format!("model `{}'", model.path().join(".")), target)?;
+    }
+    let outline = Outline::new(namespace, mode, main_namespace, false, naming)?;
+    for interface in outline.interfaces() {
+        check_scope(interface.fields().iter().map(|field| (field.name(), field.identifier().to_owned())), || format!("interface `{}'", interface.path().join(".")), target)?;
+    }
+    for r#enum in outline.enums() {
+        check_scope(r#enum.members().iter().map(|member| (member.name(), member.identifier().to_owned())), || format!("enum `{}'", r#enum.path().join(".")), target)?;
+    }
+    for delegate in outline.delegates() {
+        let methods = delegate.request_items().iter().map(|item| (item.name(), item.identifier().to_owned()));
+        let properties = delegate.group_items().iter().map(|item| (item.name(), item.property_name().to_owned()))
+            .chain(delegate.namespace_items().iter().map(|item| (item.name(), item.property_name().to_owned())));
+        check_scope(methods.chain(properties), || format!("delegate `{}'", delegate.name()), target)?;
+    }
+    check_scope(namespace.namespaces().keys().map(|name| (name.as_str(), naming.namespace_name(name))), || format!("namespace `{}'", namespace.path().join(".")), target)?;
+    for child in namespace.namespaces().values() {
+        check_namespace_collisions(naming, child, main_namespace, mode, target)?;
+    }
+    Ok(())
+}
+
+fn check_scope<'a, L: Display>(names: impl Iterator<Item=(&'a str, String)>, scope: impl Fn() -> L, target: &str) -> Result<()> {
+    let mut identifiers: BTreeMap<String, &str> = BTreeMap::new();
+    for (name, identifier) in names {
+        if let Some(other) = identifiers.insert(identifier.clone(), name) {
+            if other != name {
+                return Err(Error::new(format!("{}: `{}' and `{}' of {} are both named `{}'", target, other, name, scope(), identifier)));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,451 @@
+use std::collections::{BTreeMap, BTreeSet};
+use serde_json::Value;
+use crate::outline::json::{members, path_string, string, type_name};
+
+/// How far a version has to move for a set of changes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Bump {
+    Patch,
+    Minor,
+    Major,
+}
+
+/// One change of the generated API, described in markdown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    bump: Bump,
+    description: String,
+}
+
+impl Change {
+
+    fn new(bump: Bump, description: String) -> Self {
+        Self { bump, description }
+    }
+
+    pub fn bump(&self) -> Bump {
+        self.bump
+    }
+
+    pub fn description(&self) -> &str {
+        self.description.as_str()
+    }
+}
+
+/// # API changes
+///
+/// The changes between the outline documents of two generations, see
+/// [`compare_outline_documents`].
+#[derive(Clone, Debug, Default)]
+pub struct ApiChanges {
+    changes: Vec<Change>,
+}
+
+impl ApiChanges {
+
+    pub fn changes(&self) -> &Vec<Change> {
+        &self.changes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The bump these changes require. Generating again without changes is a
+    /// patch.
+    pub fn bump(&self) -> Bump {
+        self.changes.iter().map(|c| c.bump).max().unwrap_or(Bump::Patch)
+    }
+
+    /// A `CHANGELOG.md` entry listing the changes under `version`.
+    pub fn changelog_entry(&self, version: Option<&str>) -> String {
+        let mut entry = format!("## {}\n", version.unwrap_or("Unreleased"));
+        for (bump, title) in [(Bump::Major, "Breaking changes"), (Bump::Minor, "Additions"), (Bump::Patch, "Other changes")] {
+            let changes = self.changes.iter().filter(|c| c.bump == bump).collect::<Vec<&Change>>();
+            if !changes.is_empty() {
+                entry += &format!("\n### {}\n\n", title);
+                for change in changes {
+                    entry += &format!("- {}\n", change.description);
+                }
+            }
+        }
+        entry
+    }
+
+    fn push(&mut self, bump: Bump, description: String) {
+        self.changes.push(Change::new(bump, description));
+    }
+}
+
+/// # Compare outline documents
+///
+/// Classify the changes from `previous` to `current`, both made by
+/// [`outline_document`](crate::outline::outline_document):
+///
+/// * removing anything, adding a required field, narrowing a type like
+///   making it required, or changing a type or a handler's method is major;
+/// * adding a namespace, an interface, an enum, an enum member, an optional
+///   field or a handler, and widening a type like making it optional is
+///   minor;
+/// * changing documentation is a patch.
+///
+/// Widening a field of an interface handlers return, like a model's own
+/// type, is major though, since code reading it may not handle the new
+/// values.
+///
+/// The generated interfaces serve as both inputs and outputs, so a change
+/// is major whenever it breaks either.
+pub fn compare_outline_documents(previous: &Value, current: &Value) -> ApiChanges {
+    let mut changes = ApiChanges::default();
+    let outputs = output_interfaces(current);
+    let previous_namespaces = keyed(&previous["namespaces"], |n| path_string(&n["path"]));
+    let current_namespaces = keyed(&current["namespaces"], |n| path_string(&n["path"]));
+    for (path, previous_namespace) in &previous_namespaces {
+        match current_namespaces.get(path) {
+            Some(current_namespace) => compare_namespaces(previous_namespace, current_namespace, &outputs, &mut changes),
+            None => changes.push(Bump::Major, format!("removed namespace `{}`", path)),
+        }
+    }
+    for path in current_namespaces.keys().filter(|p| !previous_namespaces.contains_key(*p)) {
+        changes.push(Bump::Minor, format!("added namespace `{}`", path));
+    }
+    changes
+}
+
+fn compare_namespaces(previous: &Value, current: &Value, outputs: &BTreeSet<String>, changes: &mut ApiChanges) {
+    let previous_interfaces = keyed(&previous["interfaces"], |i| path_string(&i["path"]));
+    let current_interfaces = keyed(&current["interfaces"], |i| path_string(&i["path"]));
+    // the shapes synthesized for a model change together, they're reported
+    // once per model, except for the model's own fields
+    let mut models: BTreeMap<String, ModelShapes> = BTreeMap::new();
+    for (path, previous_interface) in &previous_interfaces {
+        let current_interface = current_interfaces.get(path);
+        match synthesized_for(previous_interface) {
+            Some((model, is_result)) => {
+                let shapes = models.entry(model).or_default();
+                shapes.previous = true;
+                match current_interface {
+                    Some(current_interface) => compare_interfaces(path, previous_interface, current_interface, outputs, if is_result { &mut *changes } else { &mut shapes.changes }),
+                    None => shapes.changes.push(Bump::Major, format!("removed interface `{}`", path)),
+                }
+            },
+            None => match current_interface {
+                Some(current_interface) => compare_interfaces(path, previous_interface, current_interface, outputs, changes),
+                None => changes.push(Bump::Major, format!("removed interface `{}`", path)),
+            },
+        }
+    }
+    for (path, current_interface) in &current_interfaces {
+        let added = !previous_interfaces.contains_key(path);
+        match synthesized_for(current_interface) {
+            Some((model, _)) => {
+                let shapes = models.entry(model).or_default();
+                shapes.current = true;
+                if added {
+                    shapes.changes.push(Bump::Minor, format!("added interface `{}`", path));
+                }
+            },
+            None => if added {
+                changes.push(Bump::Minor, format!("added interface `{}`", path));
+            },
+        }
+    }
+    let previous_enums = keyed(&previous["enums"], |e| path_string(&e["path"]));
+    let current_enums = keyed(&current["enums"], |e| path_string(&e["path"]));
+    for (path, previous_enum) in &previous_enums {
+        match current_enums.get(path) {
+            Some(current_enum) => compare_enums(path, previous_enum, current_enum, changes),
+            None => changes.push(Bump::Major, format!("removed enum `{}`", path)),
+        }
+    }
+    for path in current_enums.keys().filter(|p| !previous_enums.contains_key(*p)) {
+        changes.push(Bump::Minor, format!("added enum `{}`", path));
+    }
+    let previous_handlers = handlers(previous);
+    let current_handlers = handlers(current);
+    for (url, previous_handler) in &previous_handlers {
+        let changes = match builtin_for(url, previous_handler) {
+            Some(model) => &mut models.entry(model).or_default().changes,
+            None => &mut *changes,
+        };
+        match current_handlers.get(url) {
+            Some(current_handler) => compare_handlers(url, previous_handler, current_handler, changes),
+            None => changes.push(Bump::Major, format!("removed handler `{}`", url)),
+        }
+    }
+    for (url, current_handler) in current_handlers.iter().filter(|(u, _)| !previous_handlers.contains_key(*u)) {
+        let changes = match builtin_for(url, current_handler) {
+            Some(model) => &mut models.entry(model).or_default().changes,
+            None => &mut *changes,
+        };
+        changes.push(Bump::Minor, format!("added handler `{}`", url));
+    }
+    for (model, shapes) in models {
+        match (shapes.previous, shapes.current) {
+            (true, false) => changes.push(Bump::Major, format!("removed model `{}`", model)),
+            (false, true) => changes.push(Bump::Minor, format!("added model `{}`", model)),
+            _ => if !shapes.changes.is_empty() {
+                changes.push(shapes.changes.bump(), format!("changed the types generated for model `{}`", model));
+            },
+        }
+    }
+}
+
+#[derive(Default)]
+struct ModelShapes {
+    previous: bool,
+    current: bool,
+    changes: ApiChanges,
+}
+
+/// The model an interface is synthesized for, and whether it's the model's
+/// own type.
+fn synthesized_for(interface: &Value) -> Option<(String, bool)> {
+    let model = interface["synthesized"]["model"].as_str()?;
+    Some((model.to_owned(), interface["synthesized"]["kind"] == "Result"))
+}
+
+/// The model a built-in handler like `User/findMany` belongs to.
+fn builtin_for(url: &str, handler: &Value) -> Option<String> {
+    if handler["isBuiltin"] != true {
+        return None;
+    }
+    url.rsplit('/').nth(1).map(ToOwned::to_owned)
+}
+
+/// The interfaces handlers return, directly or through the fields of other
+/// returned interfaces. Models are returned as their own types.
+fn output_interfaces(document: &Value) -> BTreeSet<String> {
+    let mut interfaces = BTreeMap::new();
+    let mut pending = vec![];
+    for namespace in document["namespaces"].as_array().into_iter().flatten() {
+        interfaces.extend(keyed(&namespace["interfaces"], |i| path_string(&i["path"])));
+        for handler in handlers(namespace).values() {
+            referenced_interfaces(&handler["outputType"], &mut pending);
+        }
+    }
+    for (path, interface) in &interfaces {
+        if interface["synthesized"]["kind"] == "Result" {
+            pending.push(path.clone());
+        }
+    }
+    let mut outputs = BTreeSet::new();
+    while let Some(path) = pending.pop() {
+        if !outputs.insert(path.clone()) {
+            continue;
+        }
+        if let Some(interface) = interfaces.get(&path) {
+            for t in interface["extends"].as_array().into_iter().flatten() {
+                referenced_interfaces(t, &mut pending);
+            }
+            for field in interface["fields"].as_array().into_iter().flatten() {
+                referenced_interfaces(&field["type"], &mut pending);
+            }
+        }
+    }
+    outputs
+}
+
+fn referenced_interfaces(t: &Value, paths: &mut Vec<String>) {
+    match t["kind"].as_str().unwrap_or_default() {
+        "interface" | "model" => {
+            paths.push(path_string(&t["path"]));
+            for argument in t["arguments"].as_array().into_iter().flatten() {
+                referenced_interfaces(argument, paths);
+            }
+        },
+        "optional" | "array" | "dictionary" | "range" | "enumerable" => referenced_interfaces(&t["inner"], paths),
+        "tuple" | "union" => for member in members(t) {
+            referenced_interfaces(&member, paths);
+        },
+        _ => (),
+    }
+}
+
+fn compare_interfaces(path: &str, previous: &Value, current: &Value, outputs: &BTreeSet<String>, changes: &mut ApiChanges) {
+    if previous["genericNames"] != current["genericNames"] {
+        changes.push(Bump::Major, format!("changed the generic parameters of `{}`", path));
+    }
+    if previous["extends"] != current["extends"] {
+        changes.push(Bump::Major, format!("changed the interfaces `{}` extends", path));
+    }
+    compare_docs(&format!("interface `{}`", path), previous, current, changes);
+    let previous_fields = keyed(&previous["fields"], |f| string(&f["name"]));
+    let current_fields = keyed(&current["fields"], |f| string(&f["name"]));
+    for (name, previous_field) in &previous_fields {
+        let Some(current_field) = current_fields.get(name) else {
+            changes.push(Bump::Major, format!("removed field `{}` of `{}`", name, path));
+            continue;
+        };
+        let (previous_type, current_type) = (&previous_field["type"], &current_field["type"]);
+        if previous_type != current_type {
+            let (bump, verb) = if widens(previous_type, current_type) {
+                (if outputs.contains(path) { Bump::Major } else { Bump::Minor }, "widened")
+            } else if widens(current_type, previous_type) {
+                (Bump::Major, "narrowed")
+            } else {
+                (Bump::Major, "changed")
+            };
+            changes.push(bump, format!("{} the type of field `{}` of `{}` from `{}` to `{}`", verb, name, path, type_name(previous_type), type_name(current_type)));
+        }
+        compare_docs(&format!("field `{}` of `{}`", name, path), previous_field, current_field, changes);
+    }
+    for (name, current_field) in current_fields.iter().filter(|(n, _)| !previous_fields.contains_key(*n)) {
+        if current_field["type"]["kind"] == "optional" {
+            changes.push(Bump::Minor, format!("added optional field `{}` to `{}`", name, path));
+        } else {
+            changes.push(Bump::Major, format!("added required field `{}` to `{}`", name, path));
+        }
+    }
+}
+
+fn compare_enums(path: &str, previous: &Value, current: &Value, changes: &mut ApiChanges) {
+    compare_docs(&format!("enum `{}`", path), previous, current, changes);
+    let previous_members = keyed(&previous["members"], |m| string(&m["name"]));
+    let current_members = keyed(&current["members"], |m| string(&m["name"]));
+    for (name, previous_member) in &previous_members {
+        match current_members.get(name) {
+            Some(current_member) => compare_docs(&format!("member `{}` of `{}`", name, path), previous_member, current_member, changes),
+            None => changes.push(Bump::Major, format!("removed member `{}` of `{}`", name, path)),
+        }
+    }
+    for name in current_members.keys().filter(|n| !previous_members.contains_key(*n)) {
+        changes.push(Bump::Minor, format!("added member `{}` to `{}`", name, path));
+    }
+}
+
+fn compare_handlers(url: &str, previous: &Value, current: &Value, changes: &mut ApiChanges) {
+    if previous["method"] != current["method"] {
+        changes.push(Bump::Major, format!("changed the method of handler `{}` from {} to {}", url, string(&previous["method"]), string(&current["method"])));
+    }
+    if previous["isForm"] != current["isForm"] || previous["hasBodyInput"] != current["hasBodyInput"] || previous["pathArguments"] != current["pathArguments"] {
+        changes.push(Bump::Major, format!("changed how handler `{}` is requested", url));
+    }
+    for (key, what) in [("inputType", "input"), ("outputType", "output")] {
+        if previous[key] != current[key] {
+            changes.push(Bump::Major, format!("changed the {} of handler `{}` from `{}` to `{}`", what, url, type_name(&previous[key]), type_name(&current[key])));
+        }
+    }
+}
+
+fn compare_docs(item: &str, previous: &Value, current: &Value, changes: &mut ApiChanges) {
+    if previous["title"] != current["title"] || previous["desc"] != current["desc"] {
+        changes.push(Bump::Patch, format!("updated the documentation of {}", item));
+    }
+}
+
+/// Whether every value of `narrower` is a value of `wider`.
+fn widens(narrower: &Value, wider: &Value) -> bool {
+    if narrower == wider {
+        return true;
+    }
+    match (narrower["kind"].as_str(), wider["kind"].as_str()) {
+        (Some("optional"), Some("optional")) => widens(&narrower["inner"], &wider["inner"]),
+        (_, Some("optional")) => widens(narrower, &wider["inner"]),
+        (Some("union"), Some("union")) => members(narrower).iter().all(|n| members(wider).iter().any(|w| widens(n, w))),
+        (_, Some("union")) => members(wider).iter().any(|w| widens(narrower, w)),
+        _ => false,
+    }
+}
+
+fn handlers(namespace: &Value) -> BTreeMap<String, Value> {
+    let mut handlers = BTreeMap::new();
+    for delegate in namespace["delegates"].as_array().into_iter().flatten() {
+        for item in delegate["requestItems"].as_array().into_iter().flatten() {
+            handlers.insert(string(&item["url"]), item.clone());
+        }
+    }
+    handlers
+}
+
+fn keyed(values: &Value, key: impl Fn(&Value) -> String) -> BTreeMap<String, Value> {
+    values.as_array().into_iter().flatten().map(|v| (key(v), v.clone())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use super::*;
+
+    fn document(interfaces: Value, delegates: Value) -> Value {
+        json!({
+            "version": 1,
+            "mode": "client",
+            "namespaces": [{ "path": [], "interfaces": interfaces, "enums": [], "delegates": delegates, "pathArguments": [] }],
+        })
+    }
+
+    fn interface(name: &str, fields: Value) -> Value {
+        json!({ "name": name, "path": [name], "title": name, "desc": "", "genericNames": [], "extends": [], "fields": fields, "synthesized": null })
+    }
+
+    fn field(name: &str, r#type: Value) -> Value {
+        json!({ "name": name, "title": name, "desc": "", "type": r#type })
+    }
+
+    fn returning(name: &str, output: Value) -> Value {
+        json!([{ "name": "Api", "groupItems": [], "namespaceItems": [], "requestItems": [{
+            "name": name, "method": "POST", "url": format!("Api/{}", name),
+            "inputType": { "kind": "any" }, "outputType": output,
+            "isForm": false, "hasBodyInput": true, "pathArguments": null, "isBuiltin": false,
+        }] }])
+    }
+
+    fn bumps(previous: &Value, current: &Value) -> Vec<Bump> {
+        compare_outline_documents(previous, current).changes().iter().map(Change::bump).collect()
+    }
+
+    #[test]
+    fn unchanged_documents_have_no_changes() {
+        let document = document(json!([interface("Point", json!([field("x", json!({ "kind": "int" }))]))]), json!([]));
+        assert!(compare_outline_documents(&document, &document).is_empty());
+    }
+
+    #[test]
+    fn removing_and_adding_fields() {
+        let previous = document(json!([interface("Point", json!([field("x", json!({ "kind": "int" }))]))]), json!([]));
+        let removed = document(json!([interface("Point", json!([]))]), json!([]));
+        let optional = document(json!([interface("Point", json!([field("x", json!({ "kind": "int" })), field("y", json!({ "kind": "optional", "inner": { "kind": "int" } }))]))]), json!([]));
+        let required = document(json!([interface("Point", json!([field("x", json!({ "kind": "int" })), field("y", json!({ "kind": "int" }))]))]), json!([]));
+        assert_eq!(bumps(&previous, &removed), vec![Bump::Major]);
+        assert_eq!(bumps(&previous, &optional), vec![Bump::Minor]);
+        assert_eq!(bumps(&previous, &required), vec![Bump::Major]);
+    }
+
+    #[test]
+    fn widening_input_fields_is_minor() {
+        let previous = document(json!([interface("Point", json!([field("x", json!({ "kind": "int" }))]))]), json!([]));
+        let current = document(json!([interface("Point", json!([field("x", json!({ "kind": "optional", "inner": { "kind": "int" } }))]))]), json!([]));
+        assert_eq!(bumps(&previous, &current), vec![Bump::Minor]);
+        assert_eq!(bumps(&current, &previous), vec![Bump::Major]);
+    }
+
+    #[test]
+    fn widening_output_fields_is_major() {
+        let output = json!({ "kind": "array", "inner": { "kind": "interface", "path": ["Point"], "arguments": [] } });
+        let previous = document(json!([interface("Point", json!([field("x", json!({ "kind": "int" }))]))]), returning("points", output.clone()));
+        let current = document(json!([interface("Point", json!([field("x", json!({ "kind": "optional", "inner": { "kind": "int" } }))]))]), returning("points", output));
+        assert_eq!(bumps(&previous, &current), vec![Bump::Major]);
+    }
+
+    #[test]
+    fn documentation_changes_are_patches() {
+        let previous = document(json!([interface("Point", json!([]))]), json!([]));
+        let mut current = previous.clone();
+        current["namespaces"][0]["interfaces"][0]["desc"] = json!("A point.");
+        let changes = compare_outline_documents(&previous, &current);
+        assert_eq!(changes.bump(), Bump::Patch);
+        assert_eq!(changes.changes()[0].description(), "updated the documentation of interface `Point`");
+    }
+
+    #[test]
+    fn handler_changes() {
+        let output = json!({ "kind": "int" });
+        let previous = document(json!([]), returning("count", output.clone()));
+        let mut changed = previous.clone();
+        changed["namespaces"][0]["delegates"][0]["requestItems"][0]["method"] = json!("GET");
+        assert_eq!(bumps(&previous, &changed), vec![Bump::Major]);
+        assert_eq!(bumps(&previous, &document(json!([]), json!([]))), vec![Bump::Major]);
+        assert_eq!(bumps(&document(json!([]), json!([])), &previous), vec![Bump::Minor]);
+    }
+}
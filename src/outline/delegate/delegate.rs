@@ -2,6 +2,7 @@ use crate::outline::delegate::{GroupItem, NamespaceItem, RequestItem};
 
 pub struct Delegate {
     name: String,
+    schema_name: String,
     group_items: Vec<GroupItem>,
     namespace_items: Vec<NamespaceItem>,
     request_items: Vec<RequestItem>,
@@ -9,8 +10,20 @@ pub struct Delegate {
 
 impl Delegate {
 
-    pub fn new(name: String, group_items: Vec<GroupItem>, namespace_items: Vec<NamespaceItem>, request_items: Vec<RequestItem>) -> Self {
-        Self { name, group_items, namespace_items, request_items }
+    pub fn new(name: String, schema_name: String, group_items: Vec<GroupItem>, namespace_items: Vec<NamespaceItem>, request_items: Vec<RequestItem>) -> Self {
+        Self { name, schema_name, group_items, namespace_items, request_items }
+    }
+
+    /// The delegate's name, empty for the namespace delegate of the main
+    /// namespace.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// The name of the model, the handler group or the namespace the delegate
+    /// is for, empty for the namespace delegate of the main namespace.
+    pub fn schema_name(&self) -> &str {
+        self.schema_name.as_str()
     }
 
     pub fn group_items(&self) -> &Vec<GroupItem> {
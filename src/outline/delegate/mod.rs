@@ -1,9 +1,9 @@
-pub(crate) mod delegate;
-pub(crate) mod group_item;
-pub(crate) mod namespace_item;
-pub(crate) mod request_item;
+pub mod delegate;
+pub mod group_item;
+pub mod namespace_item;
+pub mod request_item;
 
-pub(crate) use delegate::Delegate;
-pub(crate) use group_item::GroupItem;
-pub(crate) use namespace_item::NamespaceItem;
-pub(crate) use request_item::RequestItem;
+pub use delegate::Delegate;
+pub use group_item::GroupItem;
+pub use namespace_item::NamespaceItem;
+pub use request_item::RequestItem;
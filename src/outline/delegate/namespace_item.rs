@@ -2,6 +2,7 @@ pub struct NamespaceItem {
     pub(crate) name: String,
     pub(crate) path: Vec<String>,
     pub(crate) property_name: String,
+    pub(crate) is_std: bool,
 }
 
 impl NamespaceItem {
@@ -19,6 +20,6 @@ impl NamespaceItem {
     }
 
     pub fn is_std(&self) -> bool {
-        self.property_name() == "std"
+        self.is_std
     }
 }
\ No newline at end of file
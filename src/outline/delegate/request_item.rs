@@ -2,6 +2,7 @@ use teo_parser::r#type::Type;
 
 pub struct RequestItem {
     pub name: String,
+    pub identifier: String,
     pub input_type: Type,
     pub output_type: Type,
     pub has_custom_url_args: bool,
@@ -14,6 +15,7 @@ pub struct RequestItem {
     pub path: String,
     pub custom_url_args_path: Option<Vec<String>>,
     pub is_builtin: bool,
+    pub deprecated: Option<String>,
 }
 
 impl RequestItem {
@@ -22,6 +24,12 @@ impl RequestItem {
         self.name.as_str()
     }
 
+    /// The method the handler is called by. Requests go to its path, so it
+    /// needs no mapping back to the name.
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
     pub fn input_type(&self) -> &Type {
         &self.input_type
     }
@@ -65,4 +73,10 @@ impl RequestItem {
     pub fn is_builtin(&self) -> bool {
         self.is_builtin
     }
+
+    /// The handlers of a model are deprecated with it. Handlers have no
+    /// documentation in the runtime to be deprecated in themselves.
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
 }
\ No newline at end of file
@@ -1,8 +1,9 @@
 use crate::outline::r#enum::Member;
 
-pub(crate) struct Enum {
+pub struct Enum {
     pub(crate) title: String,
     pub(crate) desc: String,
+    pub(crate) deprecated: Option<String>,
     pub(crate) path: Vec<String>,
     pub(crate) name: String,
     pub(crate) members: Vec<Member>,
@@ -10,23 +11,27 @@ pub(crate) struct Enum {
 
 impl Enum {
 
-    pub(crate) fn title(&self) -> &str {
+    pub fn title(&self) -> &str {
         self.title.as_str()
     }
 
-    pub(crate) fn desc(&self) -> &str {
+    pub fn desc(&self) -> &str {
         self.desc.as_str()
     }
 
-    pub(crate) fn path(&self) -> &Vec<String> {
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
+
+    pub fn path(&self) -> &Vec<String> {
         &self.path
     }
 
-    pub(crate) fn name(&self) -> &str {
+    pub fn name(&self) -> &str {
         self.name.as_str()
     }
 
-    pub(crate) fn members(&self) -> &Vec<Member> {
+    pub fn members(&self) -> &Vec<Member> {
         &self.members
     }
 
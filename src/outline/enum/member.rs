@@ -1,20 +1,36 @@
-pub(crate) struct Member {
+pub struct Member {
     pub(in super::super) title: String,
     pub(in super::super) desc: String,
+    pub(in super::super) deprecated: Option<String>,
     pub(in super::super) name: String,
+    pub(in super::super) identifier: String,
 }
 
 impl Member {
 
-    pub(crate) fn title(&self) -> &str {
+    pub fn title(&self) -> &str {
         self.title.as_str()
     }
 
-    pub(crate) fn desc(&self) -> &str {
+    pub fn desc(&self) -> &str {
         self.desc.as_str()
     }
 
-    pub(crate) fn name(&self) -> &str {
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
+
+    pub fn name(&self) -> &str {
         self.name.as_str()
     }
+
+    /// The identifier the member is declared by, its name on the wire is
+    /// still `name`.
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    pub fn is_renamed(&self) -> bool {
+        self.identifier != self.name
+    }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
-pub(crate) mod r#enum;
-pub(crate) mod member;
+pub mod r#enum;
+pub mod member;
 
-pub(crate) use member::Member;
-pub(crate) use r#enum::Enum;
\ No newline at end of file
+pub use member::Member;
+pub use r#enum::Enum;
\ No newline at end of file
@@ -1,32 +1,53 @@
 use teo_parser::r#type::synthesized_shape_reference::SynthesizedShapeReferenceKind;
 use teo_parser::r#type::Type;
 
-pub(crate) struct Field {
+pub struct Field {
     pub(in super::super) title: String,
     pub(in super::super) desc: String,
+    pub(in super::super) deprecated: Option<String>,
     pub(in super::super) name: String,
+    pub(in super::super) identifier: String,
     pub(in super::super) r#type: Type,
 }
 
 impl Field {
 
-    pub(crate) fn title(&self) -> &str {
+    pub fn title(&self) -> &str {
         self.title.as_str()
     }
 
-    pub(crate) fn desc(&self) -> &str {
+    pub fn desc(&self) -> &str {
         self.desc.as_str()
     }
 
-    pub(crate) fn name(&self) -> &str {
+    /// The reason given with `@deprecated` in the schema, empty when there's
+    /// none. `None` when it isn't deprecated.
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
+
+    pub fn name(&self) -> &str {
         self.name.as_str()
     }
 
-    pub(crate) fn r#type(&self) -> &Type {
+    /// The identifier the field is declared by, see
+    /// [`Naming::fields`](crate::naming::Naming::fields). Requests and
+    /// responses still carry `name`.
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    /// Whether the identifier differs from the name, so the generator has to
+    /// map it back.
+    pub fn is_renamed(&self) -> bool {
+        self.identifier != self.name
+    }
+
+    pub fn r#type(&self) -> &Type {
         &self.r#type
     }
 
-    pub(crate) fn is_relation(&self) -> bool {
+    pub fn is_relation(&self) -> bool {
         self.r#type().unwrap_optional().unwrap_array().unwrap_optional().is_model_object() ||
             (self.r#type().unwrap_optional().unwrap_array().unwrap_optional().is_synthesized_shape_reference() &&
                 self.r#type().unwrap_optional().unwrap_array().unwrap_optional().as_synthesized_shape_reference().unwrap().kind == SynthesizedShapeReferenceKind::Result
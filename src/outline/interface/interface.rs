@@ -3,9 +3,10 @@ use itertools::Itertools;
 use teo_parser::r#type::Type;
 use crate::outline::interface::Field;
 
-pub(crate) struct Interface {
+pub struct Interface {
     pub(in super::super) title: String,
     pub(in super::super) desc: String,
+    pub(in super::super) deprecated: Option<String>,
     pub(in super::super) path: Vec<String>,
     pub(in super::super) name: String,
     pub(in super::super) generic_names: Vec<String>,
@@ -17,39 +18,48 @@ pub(crate) struct Interface {
 
 impl Interface {
 
-    pub(crate) fn title(&self) -> &str {
+    pub fn title(&self) -> &str {
         self.title.as_str()
     }
 
-    pub(crate) fn desc(&self) -> &str {
+    pub fn desc(&self) -> &str {
         self.desc.as_str()
     }
 
-    pub(crate) fn path(&self) -> &Vec<String> {
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
+
+    pub fn path(&self) -> &Vec<String> {
         &self.path
     }
 
-    pub(crate) fn name(&self) -> &str {
+    pub fn name(&self) -> &str {
         self.name.as_str()
     }
 
-    pub(crate) fn generic_names(&self) -> &Vec<String> {
+    pub fn generic_names(&self) -> &Vec<String> {
         &self.generic_names
     }
 
-    pub(crate) fn extends(&self) -> &Vec<Type> {
+    pub fn extends(&self) -> &Vec<Type> {
         &self.extends
     }
 
-    pub(crate) fn fields(&self) -> &Vec<Field> {
+    pub fn fields(&self) -> &Vec<Field> {
         &self.fields
     }
 
+    /// Whether a field is declared by an identifier other than its name.
+    pub fn has_renamed_fields(&self) -> bool {
+        self.fields.iter().any(Field::is_renamed)
+    }
+
     pub(crate) fn fields_optional_at_last(&self) -> Vec<&Field> {
         self.fields.iter().sorted_by(|a, _b| if a.r#type().is_optional() { Ordering::Greater } else { Ordering::Less }).collect()
     }
 
-    pub(crate) fn synthesized(&self) -> &Option<(String, Option<String>)> {
+    pub fn synthesized(&self) -> &Option<(String, Option<String>)> {
         &self.synthesized
     }
 
@@ -73,7 +83,7 @@ impl Interface {
         }
     }
 
-    pub(crate) fn model_name(&self) -> &str {
+    pub fn model_name(&self) -> &str {
         if let Some(model_name) = &self.model_name {
             model_name.as_str()
         } else {
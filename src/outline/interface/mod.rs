@@ -1,5 +1,5 @@
-pub(crate) mod interface;
-pub(crate) mod field;
+pub mod interface;
+pub mod field;
 
-pub(crate) use interface::Interface;
-pub(crate) use field::Field;
\ No newline at end of file
+pub use interface::Interface;
+pub use field::Field;
\ No newline at end of file
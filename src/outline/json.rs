@@ -0,0 +1,262 @@
+use itertools::Itertools;
+use serde_json::{json, Value};
+use teo_parser::r#type::Type;
+use teo_result::{Error, Result};
+use teo_runtime::namespace::Namespace;
+use crate::outline::delegate::{Delegate, RequestItem};
+use crate::outline::interface::Interface;
+use crate::outline::outline::{Mode, Outline};
+use crate::outline::r#enum::Enum;
+use crate::naming::Naming;
+use crate::utils::declared_shape_lookup::declared_shape_lookup;
+use crate::utils::enum_reference_lookup::enum_reference_lookup;
+use crate::utils::shape_reference_lookup::shape_reference_lookup;
+
+/// The version of the JSON outline format. It's bumped whenever a key is
+/// removed or changes meaning, adding keys keeps it.
+pub const OUTLINE_JSON_VERSION: u32 = 1;
+
+/// # Outline document
+///
+/// The outlines of `main_namespace` and all of its descendants in JSON, for
+/// tools which aren't written in Rust:
+///
+/// ```json
+/// { "version": 1, "mode": "client", "namespaces": [{ "path": [], "interfaces": [], "enums": [], "delegates": [], "pathArguments": [] }] }
+/// ```
+///
+/// Namespaces are listed depth first in name order. Types are objects with a
+/// `kind`, see [`Outline::to_json`]. Delegates are named by the default
+/// [`Naming`], so the document doesn't depend on a generator's conventions.
+pub fn outline_document(main_namespace: &Namespace, mode: Mode) -> Result<Value> {
+    let mut namespaces = vec![];
+    collect_namespaces(main_namespace, main_namespace, mode, &mut namespaces)?;
+    Ok(json!({
+        "version": OUTLINE_JSON_VERSION,
+        "mode": mode_name(mode),
+        "namespaces": namespaces,
+    }))
+}
+
+fn collect_namespaces(namespace: &Namespace, main_namespace: &Namespace, mode: Mode, namespaces: &mut Vec<Value>) -> Result<()> {
+    let mut value = Outline::new(namespace, mode, main_namespace, false, &Naming::default())?.to_json()?;
+    value["path"] = json!(namespace.path());
+    namespaces.push(value);
+    let mut children: Vec<&Namespace> = namespace.namespaces().values().collect();
+    children.sort_by(|a, b| a.path().cmp(b.path()));
+    for child in children {
+        collect_namespaces(child, main_namespace, mode, namespaces)?;
+    }
+    Ok(())
+}
+
+fn mode_name(mode: Mode) -> &'static str {
+    match mode {
+        Mode::Client => "client",
+        Mode::Entity => "entity",
+    }
+}
+
+impl Outline {
+
+    /// Serialize this outline in the format of [`outline_document`].
+    ///
+    /// Types are serialized by `kind`: scalars are just a kind like `"int"` or
+    /// `"dateTime"`; `optional`, `array`, `dictionary`, `range` and
+    /// `enumerable` wrap `inner`; `tuple` and `union` list `members`;
+    /// `interface` has the full `path` and generic `arguments`; `model` and
+    /// `enum` have the full `path`; `generic` has a `name`. Synthesized shapes
+    /// and enums are referenced like declared ones.
+    pub fn to_json(&self) -> Result<Value> {
+        Ok(json!({
+            "interfaces": self.interfaces().iter().map(|i| interface_json(i, self.mode())).collect::<Result<Vec<Value>>>()?,
+            "enums": self.enums().iter().map(enum_json).collect::<Vec<Value>>(),
+            "delegates": self.delegates().iter().map(|d| delegate_json(d, self.mode())).collect::<Result<Vec<Value>>>()?,
+            "pathArguments": self.path_arguments().iter().map(|p| json!({
+                "name": p.name(),
+                "items": p.items(),
+            })).collect::<Vec<Value>>(),
+        }))
+    }
+}
+
+fn interface_json(interface: &Interface, mode: Mode) -> Result<Value> {
+    Ok(json!({
+        "name": interface.name(),
+        "path": interface.path(),
+        "title": interface.title(),
+        "desc": interface.desc(),
+        "deprecated": interface.deprecated(),
+        "genericNames": interface.generic_names(),
+        "extends": interface.extends().iter().map(|t| type_json(t, mode)).collect::<Result<Vec<Value>>>()?,
+        "fields": interface.fields().iter().map(|field| Ok(json!({
+            "name": field.name(),
+            "title": field.title(),
+            "desc": field.desc(),
+            "deprecated": field.deprecated(),
+            "type": type_json(field.r#type(), mode)?,
+        }))).collect::<Result<Vec<Value>>>()?,
+        "synthesized": interface.synthesized().as_ref().map(|(kind, without)| json!({
+            "model": interface.model_name(),
+            "kind": kind,
+            "without": without,
+        })),
+    }))
+}
+
+fn enum_json(r#enum: &Enum) -> Value {
+    json!({
+        "name": r#enum.name(),
+        "path": r#enum.path(),
+        "title": r#enum.title(),
+        "desc": r#enum.desc(),
+        "deprecated": r#enum.deprecated(),
+        "members": r#enum.members().iter().map(|member| json!({
+            "name": member.name(),
+            "title": member.title(),
+            "desc": member.desc(),
+            "deprecated": member.deprecated(),
+        })).collect::<Vec<Value>>(),
+    })
+}
+
+fn delegate_json(delegate: &Delegate, mode: Mode) -> Result<Value> {
+    Ok(json!({
+        "name": delegate.name(),
+        "groupItems": delegate.group_items().iter().map(|item| json!({
+            "name": item.name(),
+            "path": item.path(),
+            "propertyName": item.property_name(),
+        })).collect::<Vec<Value>>(),
+        "namespaceItems": delegate.namespace_items().iter().map(|item| json!({
+            "name": item.name(),
+            "path": item.path(),
+            "propertyName": item.property_name(),
+        })).collect::<Vec<Value>>(),
+        "requestItems": delegate.request_items().iter().map(|item| request_item_json(item, mode)).collect::<Result<Vec<Value>>>()?,
+    }))
+}
+
+fn request_item_json(item: &RequestItem, mode: Mode) -> Result<Value> {
+    Ok(json!({
+        "name": item.name(),
+        "method": item.method(),
+        "url": item.path(),
+        "inputType": type_json(item.input_type(), mode)?,
+        "outputType": type_json(item.output_type(), mode)?,
+        "isForm": item.is_form(),
+        "hasBodyInput": item.has_body_input(),
+        "hasPathArguments": item.has_custom_url_args(),
+        "pathArguments": item.custom_url_args_path,
+        "isBuiltin": item.is_builtin(),
+        "isAggregate": item.is_aggregate(),
+        "isGroupBy": item.is_group_by(),
+        "isCount": item.is_count(),
+        "deprecated": item.deprecated(),
+    }))
+}
+
+fn kind(kind: &str) -> Value {
+    json!({ "kind": kind })
+}
+
+fn wrapping(kind: &str, inner: &Type, mode: Mode) -> Result<Value> {
+    Ok(json!({ "kind": kind, "inner": type_json(inner, mode)? }))
+}
+
+fn listing(kind: &str, members: &Vec<Type>, mode: Mode) -> Result<Value> {
+    Ok(json!({ "kind": kind, "members": members.iter().map(|t| type_json(t, mode)).collect::<Result<Vec<Value>>>()? }))
+}
+
+fn dotted_path(dotted: String) -> Vec<String> {
+    dotted.split(".").map(ToOwned::to_owned).collect()
+}
+
+pub(crate) fn type_json(t: &Type, mode: Mode) -> Result<Value> {
+    Ok(match t {
+        Type::Undetermined => Err(Error::new("encountered undetermined"))?,
+        Type::Ignored => Err(Error::new("encountered ignored"))?,
+        Type::Any => kind("any"),
+        Type::Null => kind("null"),
+        Type::Bool => kind("bool"),
+        Type::Int => kind("int"),
+        Type::Int64 => kind("int64"),
+        Type::Float32 => kind("float32"),
+        Type::Float => kind("float"),
+        Type::Decimal => kind("decimal"),
+        Type::String => kind("string"),
+        Type::ObjectId => kind("objectId"),
+        Type::Date => kind("date"),
+        Type::DateTime => kind("dateTime"),
+        Type::File => kind("file"),
+        Type::Regex => kind("regex"),
+        Type::Optional(inner) => wrapping("optional", inner.as_ref(), mode)?,
+        Type::Array(inner) => wrapping("array", inner.as_ref(), mode)?,
+        Type::Dictionary(inner) => wrapping("dictionary", inner.as_ref(), mode)?,
+        Type::Range(inner) => wrapping("range", inner.as_ref(), mode)?,
+        Type::Enumerable(inner) => wrapping("enumerable", inner.as_ref(), mode)?,
+        Type::Tuple(members) => listing("tuple", members, mode)?,
+        Type::Union(members) => listing("union", members, mode)?,
+        Type::InterfaceObject(reference, arguments) => json!({
+            "kind": "interface",
+            "path": reference.string_path(),
+            "arguments": arguments.iter().map(|t| type_json(t, mode)).collect::<Result<Vec<Value>>>()?,
+        }),
+        Type::SynthesizedShapeReference(shape_reference) => json!({
+            "kind": "interface",
+            "path": dotted_path(shape_reference_lookup(shape_reference, ".", mode)?),
+            "arguments": [],
+        }),
+        Type::DeclaredSynthesizedShape(reference, owner) => json!({
+            "kind": "interface",
+            "path": dotted_path(declared_shape_lookup(reference, owner, ".")?),
+            "arguments": [],
+        }),
+        Type::ModelObject(reference) => json!({ "kind": "model", "path": reference.string_path() }),
+        Type::EnumVariant(reference) => json!({ "kind": "enum", "path": reference.string_path() }),
+        Type::SynthesizedEnumReference(enum_reference) => json!({
+            "kind": "enum",
+            "path": dotted_path(enum_reference_lookup(enum_reference, ".")?),
+        }),
+        Type::GenericItem(name) => json!({ "kind": "generic", "name": name }),
+        _ => Err(Error::new(format!("encountered unhandled type `{}' in outline serialization", t)))?,
+    })
+}
+
+pub(crate) fn members(t: &Value) -> Vec<Value> {
+    t["members"].as_array().cloned().unwrap_or_default()
+}
+
+/// A type of an outline document as it's written in a schema.
+pub(crate) fn type_name(t: &Value) -> String {
+    let inner = || type_name(&t["inner"]);
+    let listed = |separator: &str| members(t).iter().map(type_name).join(separator);
+    match t["kind"].as_str().unwrap_or_default() {
+        "optional" => format!("{}?", inner()),
+        "array" => format!("{}[]", inner()),
+        "dictionary" => format!("{{String: {}}}", inner()),
+        "range" => format!("Range<{}>", inner()),
+        "enumerable" => format!("Enumerable<{}>", inner()),
+        "tuple" => format!("({})", listed(", ")),
+        "union" => listed(" | "),
+        "interface" => {
+            let arguments = t["arguments"].as_array().cloned().unwrap_or_default();
+            if arguments.is_empty() {
+                path_string(&t["path"])
+            } else {
+                format!("{}<{}>", path_string(&t["path"]), arguments.iter().map(type_name).join(", "))
+            }
+        },
+        "model" | "enum" => path_string(&t["path"]),
+        "generic" => string(&t["name"]),
+        kind => kind.to_owned(),
+    }
+}
+
+pub(crate) fn path_string(path: &Value) -> String {
+    path.as_array().into_iter().flatten().map(string).join(".")
+}
+
+pub(crate) fn string(value: &Value) -> String {
+    value.as_str().unwrap_or_default().to_owned()
+}
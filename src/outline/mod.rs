@@ -1,5 +1,14 @@
-pub(crate) mod outline;
-pub(crate) mod r#enum;
-pub(crate) mod interface;
-pub(crate) mod delegate;
-pub(crate) mod path_arguments;
\ No newline at end of file
+//! The language independent view of a namespace generators render from. It
+//! is read only and shared with generators registered by other crates.
+
+pub mod outline;
+pub mod r#enum;
+pub mod interface;
+pub mod delegate;
+pub mod path_arguments;
+pub mod json;
+pub mod changes;
+
+pub use outline::{Mode, Outline};
+pub use json::{outline_document, OUTLINE_JSON_VERSION};
+pub use changes::{compare_outline_documents, ApiChanges, Bump, Change};
@@ -11,13 +11,17 @@ use teo_runtime::namespace::extensions::SynthesizedShapeReferenceExtension;
 use teo_runtime::namespace::Namespace;
 use teo_runtime::traits::documentable::Documentable;
 use teo_runtime::traits::named::Named;
+use teo_result::{Error, Result};
 use crate::outline::delegate::{Delegate, GroupItem, NamespaceItem, RequestItem};
 use crate::outline::interface::{Field, Interface};
 use crate::outline::path_arguments::PathArguments;
 use crate::outline::r#enum::{Enum, Member};
+use crate::naming::Naming;
+use crate::utils::deprecation::split_deprecation;
+use crate::utils::located::Located;
 
 #[derive(Copy, Clone, PartialEq, Eq)]
-pub(crate) enum Mode {
+pub enum Mode {
     Client,
     Entity,
 }
@@ -39,7 +43,31 @@ impl Mode {
     }
 }
 
-pub(crate) struct Outline {
+/// # Outline
+///
+/// The language independent view of a namespace that generators render. Its
+/// ordering is part of the generated output, so it doesn't depend on the
+/// iteration order of the runtime's maps:
+///
+/// * enums: declared enums by name, then each model's synthesized enums, models
+///   by name and enums by name
+/// * interfaces: declared interfaces by name, then each model's synthesized
+///   shapes, models by name and shapes by name
+/// * delegates: model delegates by model name, handler group delegates by
+///   group name, then the namespace delegate
+/// * request items: builtin handlers in the runtime's action order, then custom
+///   handlers by name
+/// * group items and namespace items: by name
+/// * path arguments: by name
+///
+/// Enum members, and fields of both declared and synthesized interfaces, keep
+/// their declaration order.
+///
+/// Delegates, their properties and the identifiers of fields, enum members
+/// and request items follow the generator's [`Naming`].
+pub struct Outline {
+    mode: Mode,
+    naming: Naming,
     interfaces: Vec<Interface>,
     enums: Vec<Enum>,
     path_arguments: Vec<PathArguments>,
@@ -48,32 +76,37 @@ pub(crate) struct Outline {
 
 impl Outline {
 
-    pub fn new(namespace: &Namespace, mode: Mode, main_namespace: &Namespace, ignores_empty: bool) -> Self {
+    pub fn new(namespace: &Namespace, mode: Mode, main_namespace: &Namespace, ignores_empty: bool, naming: &Naming) -> Result<Self> {
         let mut interfaces = vec![];
         let mut enums = vec![];
         // enums
-        for r#enum in namespace.enums().values() {
+        for r#enum in namespace.enums().values().sorted_by_key(|e| e.name()) {
             if !r#enum.interface() && !r#enum.option() {
                 if ignores_empty && r#enum.members().is_empty() {
                     continue
                 }
+                let (desc, deprecated) = split_deprecation(r#enum.desc());
                 enums.push(Enum {
                     title: r#enum.title(),
-                    desc: r#enum.desc(),
+                    desc,
+                    deprecated,
                     path: r#enum.path().clone(),
                     name: r#enum.name().to_owned(),
                     members: r#enum.members().iter().map(|member| {
+                        let (desc, deprecated) = split_deprecation(member.desc());
                         Member {
                             title: member.title(),
-                            desc: member.desc(),
+                            desc,
+                            deprecated,
                             name: member.name().to_owned(),
+                            identifier: naming.enum_member_name(member.name()),
                         }
                     }).collect()
                 });
             }
         }
         // interfaces
-        for interface in namespace.interfaces().values().sorted_by_key(|i| i.parser_path().last().unwrap()) {
+        for interface in namespace.interfaces().values().sorted_by_key(|i| i.name()) {
             let generate = match mode {
                 Mode::Client => interface.generate_client(),
                 Mode::Entity => interface.generate_entity(),
@@ -82,18 +115,23 @@ impl Outline {
                 if ignores_empty && interface.fields().is_empty() {
                     continue
                 }
+                let (desc, deprecated) = split_deprecation(interface.desc());
                 interfaces.push(Interface {
                     title: interface.title(),
-                    desc: interface.desc(),
+                    desc,
+                    deprecated,
                     path: interface.path().clone(),
                     name: interface.name().to_owned(),
                     generic_names: interface.generic_names().clone(),
                     extends: interface.extends().clone(),
                     fields: interface.fields().values().map(|field| {
+                        let (desc, deprecated) = split_deprecation(field.desc());
                         Field {
                             title: field.title(),
-                            desc: field.desc(),
+                            desc,
+                            deprecated,
                             name: field.name().to_owned(),
+                            identifier: naming.field_name(field.name()),
                             r#type: field.r#type().clone(),
                         }
                     }).collect(),
@@ -103,38 +141,46 @@ impl Outline {
             }
         }
         // model caches
-        for model in namespace.models().values() {
+        for model in namespace.models().values().sorted_by_key(|m| m.name()) {
             if (mode == Mode::Entity && model.generate_entity()) || (mode == Mode::Client && model.generate_client()) {
+                let mut model_interfaces = vec![];
+                let mut model_enums = vec![];
                 for ((shape_name, shape_without), input) in &model.cache().shape.shapes {
                     if let Some(shape) = input.as_synthesized_shape() {
                         if !(ignores_empty && shape.is_empty()) {
-                            interfaces.push(shape_interface_from_cache(shape, &shape_name.to_string(), shape_without, model, mode, ignores_empty, main_namespace));
+                            model_interfaces.push(shape_interface_from_cache(shape, &shape_name.to_string(), shape_without, model, mode, ignores_empty, main_namespace, naming)?);
                         }
                     } else if let Some(union) = input.as_union() {
                         let shape = make_shape_from_union(union);
-                        interfaces.push(shape_interface_from_cache(&shape, &shape_name.to_string(), shape_without, model, mode, ignores_empty, main_namespace));
+                        model_interfaces.push(shape_interface_from_cache(&shape, &shape_name.to_string(), shape_without, model, mode, ignores_empty, main_namespace, naming)?);
                     }
                 }
                 for (enum_name, input) in &model.cache().shape.enums {
-                    enums.push(shape_enum_from_cache(input, &enum_name.to_string(), model));
+                    model_enums.push(shape_enum_from_cache(input, &enum_name.to_string(), model, naming));
                 }
-                for ((def_path, shape)) in &model.cache().shape.declared_shapes {
-                    interfaces.push(shape_interface_from_cache(shape, def_path.last().unwrap(), &None, model, mode, ignores_empty, main_namespace));
+                for (def_path, shape) in &model.cache().shape.declared_shapes {
+                    model_interfaces.push(shape_interface_from_cache(shape, def_path.last().unwrap(), &None, model, mode, ignores_empty, main_namespace, naming)?);
                 }
+                model_interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+                model_enums.sort_by(|a, b| a.name.cmp(&b.name));
+                interfaces.extend(model_interfaces);
+                enums.extend(model_enums);
             }
         }
         // delegates
         let mut delegates = vec![];
-        for model in namespace.models().values() {
+        for model in namespace.models().values().sorted_by_key(|m| m.name()) {
             if !(model.generate_client() && model.synthesize_shapes()) {
                 continue
             }
+            let model_deprecation = split_deprecation(model.desc()).1;
             let mut request_items = vec![];
             for action in model.builtin_handlers() {
                 let input_type = model.input_type_for_builtin_handler(*action);
                 let output_type = model.output_type_for_builtin_handler(*action, main_namespace);
                 request_items.push(RequestItem {
                     name: action.as_handler_str().to_owned(),
+                    identifier: naming.method_name(action.as_handler_str()),
                     input_type,
                     output_type,
                     has_custom_url_args: false,
@@ -147,13 +193,15 @@ impl Outline {
                     method: "POST".to_owned(),
                     custom_url_args_path: None,
                     is_builtin: true,
+                    deprecated: model_deprecation.clone(),
                 });
             }
             if let Some(handler_group) = namespace.model_handler_groups().get(model.name()) {
-                for (name, handler) in handler_group.handlers() {
+                for (name, handler) in handler_group.handlers().iter().sorted_by_key(|(name, _)| *name) {
                     if !handler.nonapi() {
                         request_items.push(RequestItem {
                             name: name.to_owned(),
+                            identifier: naming.method_name(name),
                             input_type: handler.input_type().clone(),
                             output_type: handler.output_type().clone(),
                             has_custom_url_args: handler.has_custom_url_args(),
@@ -166,19 +214,21 @@ impl Outline {
                             method: handler.method().as_str().to_owned(),
                             custom_url_args_path: handler.custom_url_args_path(),
                             is_builtin: false,
+                            deprecated: model_deprecation.clone(),
                         });
                     }
                 }
             }
-            let delegate = Delegate::new(model.name().to_owned() + "Delegate", vec![], vec![], request_items);
+            let delegate = Delegate::new(naming.delegate_name(model.name()), model.name().to_owned(), vec![], vec![], request_items);
             delegates.push(delegate);
         }
-        for handler_group in namespace.handler_groups().values() {
+        for handler_group in namespace.handler_groups().values().sorted_by_key(|g| g.name()) {
             let mut request_items = vec![];
-            for (name, handler) in handler_group.handlers() {
+            for (name, handler) in handler_group.handlers().iter().sorted_by_key(|(name, _)| *name) {
                 if !handler.nonapi() {
                     request_items.push(RequestItem {
                         name: name.to_owned(),
+                        identifier: naming.method_name(name),
                         input_type: handler.input_type().clone(),
                         output_type: handler.output_type().clone(),
                         has_custom_url_args: handler.has_custom_url_args(),
@@ -191,39 +241,41 @@ impl Outline {
                         method: handler.method().as_str().to_owned(),
                         custom_url_args_path: handler.custom_url_args_path(),
                         is_builtin: false,
+                        deprecated: None,
                     });
                 }
             }
-            let delegate = Delegate::new(handler_group.path().last().unwrap().to_owned() + "Delegate", vec![], vec![], request_items);
+            let group_name = handler_group.path().last().unwrap();
+            let delegate = Delegate::new(naming.delegate_name(group_name), group_name.to_owned(), vec![], vec![], request_items);
             delegates.push(delegate);
         }
-        let self_delegate_name = if namespace.path().is_empty() {
-            "".to_owned()
-        } else {
-            namespace.path().last().unwrap().to_pascal_case() + "NamespaceDelegate"
+        let self_delegate_name = match namespace.path().last() {
+            Some(name) => naming.namespace_delegate_name(name),
+            None => "".to_owned(),
         };
         let mut model_items = vec![];
         let mut namespace_items = vec![];
         let mut request_items = vec![];
-        for model in namespace.models().values() {
+        for model in namespace.models().values().sorted_by_key(|m| m.name()) {
             if !(model.generate_entity() && model.synthesize_shapes()) {
                 continue
             }
             model_items.push(GroupItem {
-                name: model.name().to_owned() + "Delegate",
+                name: naming.delegate_name(model.name()),
                 path: {
                     let mut path = model.path().clone();
                     path.pop();
-                    path.push(model.name().to_owned() + "Delegate");
+                    path.push(naming.delegate_name(model.name()));
                     path
                 },
-                property_name: model.name().to_camel_case(),
+                property_name: naming.property_name(model.name()),
             })
         }
-        for handler in namespace.handlers().values() {
+        for handler in namespace.handlers().values().sorted_by_key(|h| h.name()) {
             if !handler.nonapi() {
                 request_items.push(RequestItem {
                     name: handler.name().to_owned(),
+                    identifier: naming.method_name(handler.name()),
                     input_type: handler.input_type().clone(),
                     output_type: handler.output_type().clone(),
                     has_custom_url_args: handler.has_custom_url_args(),
@@ -236,33 +288,36 @@ impl Outline {
                     method: handler.method().as_str().to_owned(),
                     custom_url_args_path: handler.custom_url_args_path(),
                     is_builtin: false,
+                    deprecated: None,
                 });
             }
         }
-        for handler_group in namespace.handler_groups().values() {
+        for handler_group in namespace.handler_groups().values().sorted_by_key(|g| g.name()) {
             model_items.push(GroupItem {
-                name: handler_group.name().to_owned() + "Delegate",
+                name: naming.delegate_name(handler_group.name()),
                 path: {
                     let mut path = handler_group.path().clone();
                     path.pop();
-                    path.push(handler_group.name().to_owned() + "Delegate");
+                    path.push(naming.delegate_name(handler_group.name()));
                     path
                 },
-                property_name: handler_group.name().to_camel_case(),
+                property_name: naming.property_name(handler_group.name()),
             })
         }
-        for child_ns in namespace.namespaces().values() {
+        for child_ns in namespace.namespaces().values().sorted_by_key(|n| n.name()) {
             namespace_items.push(NamespaceItem {
-                name: child_ns.name().to_owned() + "NamespaceDelegate",
+                name: naming.namespace_delegate_name(child_ns.name()),
                 path: {
                     let mut path = child_ns.path().clone();
-                    path.push(child_ns.name().to_pascal_case() + "NamespaceDelegate");
+                    path.push(naming.namespace_delegate_name(child_ns.name()));
                     path
                 },
-                property_name: child_ns.name().to_camel_case(),
+                property_name: naming.property_name(child_ns.name()),
+                is_std: child_ns.is_std(),
             })
         }
-        delegates.push(Delegate::new(self_delegate_name, model_items, namespace_items, request_items));
+        let self_name = namespace.path().last().cloned().unwrap_or_default();
+        delegates.push(Delegate::new(self_delegate_name, self_name, model_items, namespace_items, request_items));
         // path arguments
         let mut path_arguments = vec![];
         for handler in namespace.handlers().values() {
@@ -278,24 +333,71 @@ impl Outline {
                 install_path_arguments(&mut path_arguments, handler);
             }
         }
-        Self { interfaces, enums, delegates, path_arguments }
+        path_arguments.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Self { mode, naming: naming.clone(), interfaces, enums, delegates, path_arguments })
     }
 
-    pub(crate) fn interfaces(&self) -> &Vec<Interface> {
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn naming(&self) -> &Naming {
+        &self.naming
+    }
+
+    pub fn interfaces(&self) -> &Vec<Interface> {
         &self.interfaces
     }
 
-    pub(crate) fn enums(&self) -> &Vec<Enum> {
+    pub fn enums(&self) -> &Vec<Enum> {
         &self.enums
     }
 
-    pub(crate) fn delegates(&self) -> &Vec<Delegate> {
+    pub fn delegates(&self) -> &Vec<Delegate> {
         &self.delegates
     }
 
-    pub(crate) fn path_arguments(&self) -> &Vec<PathArguments> {
+    pub fn path_arguments(&self) -> &Vec<PathArguments> {
         &self.path_arguments
     }
+
+    /// The expression `delegate` is reached by from the client object, like
+    /// `teo.admin.user`. `namespace_path` is the path of this outline's
+    /// namespace.
+    pub(crate) fn delegate_receiver(&self, object_name: &str, namespace_path: &Vec<String>, delegate: &Delegate) -> String {
+        let mut receiver = vec![object_name.to_owned()];
+        receiver.extend(namespace_path.iter().map(|p| self.naming.property_name(p)));
+        let namespace_delegate = self.delegates.last();
+        if !namespace_delegate.is_some_and(|d| std::ptr::eq(d, delegate)) {
+            let group_item = namespace_delegate.and_then(|d| d.group_items().iter().find(|item| item.name() == delegate.name()));
+            receiver.push(match group_item {
+                Some(item) => item.property_name().to_owned(),
+                None => self.naming.property_name(delegate.schema_name()),
+            });
+        }
+        receiver.join(".")
+    }
+
+    /// Call `f` with every type an interface or a handler refers to, so a
+    /// generator can reject types it cannot express before rendering. Errors
+    /// name the field or the handler the type belongs to.
+    pub(crate) fn try_each_type<F>(&self, mut f: F) -> Result<()> where F: FnMut(&Type) -> Result<()> {
+        for interface in &self.interfaces {
+            for extend in interface.extends() {
+                f(extend).located(|| format!("interface `{}' extends `{}'", interface.path().join("."), extend))?;
+            }
+            for field in interface.fields() {
+                f(field.r#type()).located(|| format!("field `{}' of interface `{}'", field.name(), interface.path().join(".")))?;
+            }
+        }
+        for delegate in &self.delegates {
+            for request_item in delegate.request_items() {
+                f(request_item.input_type()).located(|| format!("input of handler `{}'", request_item.path()))?;
+                f(request_item.output_type()).located(|| format!("output of handler `{}'", request_item.path()))?;
+            }
+        }
+        Ok(())
+    }
 }
 
 fn install_path_arguments(path_arguments: &mut Vec<PathArguments>, handler: &Handler) {
@@ -307,9 +409,10 @@ fn install_path_arguments(path_arguments: &mut Vec<PathArguments>, handler: &Han
     }
 }
 
-fn shape_interface_from_cache(shape: &SynthesizedShape, shape_name: &String, shape_without: &Option<String>, model: &Model, mode: Mode, ignores_empty: bool, main_namespace: &Namespace) -> Interface {
+fn shape_interface_from_cache(shape: &SynthesizedShape, shape_name: &String, shape_without: &Option<String>, model: &Model, mode: Mode, ignores_empty: bool, main_namespace: &Namespace, naming: &Naming) -> Result<Interface> {
     let name = if let Some(without) = shape_without {
-        model.name().to_owned() + shape_name.as_str().strip_suffix("InputWithout").unwrap() + "Without" + &without.to_pascal_case() + "Input"
+        let base = shape_name.as_str().strip_suffix("InputWithout").ok_or_else(|| Error::new(format!("synthesized shape `{}' of model `{}' has a without field `{}' but isn't an input without shape", shape_name, model.path().join("."), without)))?;
+        model.name().to_owned() + base + "Without" + &without.to_pascal_case() + "Input"
     } else {
         if mode == Mode::Client && shape_name == "Result" {
             model.name().to_owned()
@@ -317,9 +420,24 @@ fn shape_interface_from_cache(shape: &SynthesizedShape, shape_name: &String, sha
             model.name().to_owned() + shape_name
         }
     };
-    Interface {
+    let mut fields = vec![];
+    for (field_name, r#type) in shape.iter() {
+        if ignores_empty && shape_field_is_empty(r#type, main_namespace).located(|| format!("field `{}' of synthesized interface `{}'", field_name, name))? {
+            continue
+        }
+        fields.push(Field {
+            title: field_name.to_title_case(),
+            desc: "This synthesized field doesn't have a description.".to_owned(),
+            deprecated: model_member_deprecation(model, field_name),
+            name: field_name.clone(),
+            identifier: naming.field_name(field_name),
+            r#type: r#type.clone(),
+        });
+    }
+    Ok(Interface {
         title: name.to_sentence_case(),
         desc: "This synthesized interface doesn't have a description".to_owned(),
+        deprecated: split_deprecation(model.desc()).1,
         path: {
             let mut result = model.path().clone();
             result.pop();
@@ -329,52 +447,60 @@ fn shape_interface_from_cache(shape: &SynthesizedShape, shape_name: &String, sha
         name,
         generic_names: vec![],
         extends: vec![],
-        fields: shape.iter().filter(|(name, r#type)| {
-            if ignores_empty {
-                let main_type = r#type.unwrap_optional().unwrap_array().unwrap_optional();
-                if let Type::SynthesizedShapeReference(reference) = main_type {
-                    if let Some(definition) = reference.fetch_synthesized_definition_for_namespace(main_namespace) {
-                        if let Type::SynthesizedShape(shape) = definition {
-                            return !shape.is_empty()
-                        } else if let Type::Union(_) = definition {
-                            true
-                        } else {
-                            false
-                        }
-                    } else {
-                        true
-                    }
-                } else if let Type::DeclaredSynthesizedShape(reference, boxed) = main_type {
-                    let model = main_namespace.model_at_path(boxed.as_model_object().unwrap().string_path()).unwrap();
-                    if let Some(val) = model.cache().shape.declared_shapes.get(reference.string_path()) {
-                        !val.is_empty()
-                    } else {
-                        false
-                    }
-                } else {
-                    true
-                }
-            } else {
-                true
-            }
-        }).map(|(name, r#type)| {
-            Field {
-                title: name.to_title_case(),
-                desc: "This synthesized field doesn't have a description.".to_owned(),
-                name: name.clone(),
-                r#type: r#type.clone(),
-            }
-        }).collect(),
+        fields,
         synthesized: Some((shape_name.clone(), shape_without.clone())),
         model_name: Some(model.name().to_owned()),
-    }
+    })
 }
 
-fn shape_enum_from_cache(r#enum: &SynthesizedEnum, shape_name: &String, model: &Model) -> Enum {
+/// Whether a synthesized field refers to a shape without fields, which is
+/// left out when empty shapes are ignored.
+fn shape_field_is_empty(r#type: &Type, main_namespace: &Namespace) -> Result<bool> {
+    let main_type = r#type.unwrap_optional().unwrap_array().unwrap_optional();
+    Ok(if let Type::SynthesizedShapeReference(reference) = main_type {
+        match reference.fetch_synthesized_definition_for_namespace(main_namespace) {
+            Some(Type::SynthesizedShape(shape)) => shape.is_empty(),
+            Some(Type::Union(_)) => false,
+            Some(_) => true,
+            None => false,
+        }
+    } else if let Type::DeclaredSynthesizedShape(reference, owner) = main_type {
+        let Some(owner) = owner.as_model_object() else {
+            return Err(Error::new(format!("declared shape `{}' isn't declared on a model", reference.string_path().join("."))));
+        };
+        let Some(model) = main_namespace.model_at_path(owner.string_path()) else {
+            return Err(Error::new(format!("model `{}' of declared shape `{}' is not found", owner.string_path().join("."), reference.string_path().join("."))));
+        };
+        match model.cache().shape.declared_shapes.get(reference.string_path()) {
+            Some(shape) => shape.is_empty(),
+            None => true,
+        }
+    } else {
+        false
+    })
+}
+
+/// Synthesized fields are deprecated with the model field, relation or
+/// property they're named after.
+fn model_member_deprecation(model: &Model, name: &str) -> Option<String> {
+    let desc = if let Some(field) = model.fields().get(name) {
+        field.desc()
+    } else if let Some(relation) = model.relations().get(name) {
+        relation.desc()
+    } else if let Some(property) = model.properties().get(name) {
+        property.desc()
+    } else {
+        return None;
+    };
+    split_deprecation(desc).1
+}
+
+fn shape_enum_from_cache(r#enum: &SynthesizedEnum, shape_name: &String, model: &Model, naming: &Naming) -> Enum {
     let name = model.name().to_owned() + shape_name;
     Enum {
         title: name.to_sentence_case(),
         desc: "This synthesized enum doesn't have a description.".to_owned(),
+        deprecated: None,
         path: {
             let mut result = model.path().clone();
             result.pop();
@@ -386,7 +512,9 @@ fn shape_enum_from_cache(r#enum: &SynthesizedEnum, shape_name: &String, model: &
             Member {
                 title: member.name.to_sentence_case(),
                 desc: "This synthesized enum member doesn't have a description.".to_owned(),
-                name: member.name.clone()
+                deprecated: None,
+                name: member.name.clone(),
+                identifier: naming.enum_member_name(&member.name),
             }
         }).collect(),
     }
@@ -423,4 +551,67 @@ fn path_for_custom_handler(handler: &Handler) -> String {
     } else {
         handler.path().join("/") + "/"
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use teo_runtime::app::data::AppData;
+    use teo_runtime::app::entrance::Entrance;
+    use teo_runtime::app::runtime_version::RuntimeVersion;
+    use teo_runtime::namespace;
+    use teo_runtime::response::Response;
+    use teo_runtime::schema::load::load_schema::load_schema;
+    use super::*;
+
+    const CONFIG: &str = "connector { provider: .postgres, url: \"postgres://127.0.0.1:5432/teo\" }\nserver { bind: (\"0.0.0.0\", 5050) }\n";
+    const ITEMS: [&str; 8] = [
+        "enum Color { red green }",
+        "enum Animal { cat dog }",
+        "model User { @id @autoIncrement id: Int name: String color: Color? }",
+        "model Article { @id @autoIncrement id: Int title: String }",
+        "interface Point { x: Int y: Int }",
+        "interface Area { width: Int height: Int }",
+        "declare handler group Search { declare handler users(Point): Area }",
+        "declare handler group Export { declare handler articles(Area): Point }",
+    ];
+
+    async fn load_namespace(items: Vec<&str>) -> Namespace {
+        let dir = std::env::temp_dir().join(format!("teo-generator-outline-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.teo", items[0].split_whitespace().nth(1).unwrap()));
+        std::fs::write(&path, CONFIG.to_owned() + &items.join("\n")).unwrap();
+        let (schema, diagnostics) = teo_parser::parse(path.to_str().unwrap(), None, None);
+        assert!(!diagnostics.has_errors(), "{:?}", diagnostics);
+        let builder = namespace::Builder::main(AppData::new(Entrance::APP, RuntimeVersion::Rust(env!("CARGO_PKG_VERSION"))));
+        teo_runtime::stdlib::load::load(&builder);
+        // the declared handlers are loaded only with their implementations
+        for (group, handler) in [("Search", "users"), ("Export", "articles")] {
+            builder.define_handler_group(group, |group| {
+                group.define_handler(handler, || async { Ok(Response::empty()) });
+                Ok(())
+            }).unwrap();
+        }
+        load_schema(&builder, &schema, false).await.unwrap();
+        builder.build()
+    }
+
+    fn names(namespace: &Namespace) -> (Vec<String>, Vec<String>, Vec<String>) {
+        let outline = Outline::new(namespace, Mode::Client, namespace, false, &Naming::default()).unwrap();
+        (
+            outline.interfaces().iter().map(|i| i.name().to_owned()).collect(),
+            outline.enums().iter().map(|e| e.name().to_owned()).collect(),
+            outline.delegates().iter().map(|d| d.name().to_owned()).collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn ordering_doesnt_depend_on_declaration_order() {
+        let forward = load_namespace(ITEMS.to_vec()).await;
+        let backward = load_namespace(ITEMS.iter().rev().cloned().collect()).await;
+        let (interfaces, enums, delegates) = names(&forward);
+        assert_eq!((interfaces.clone(), enums.clone(), delegates.clone()), names(&backward));
+        assert_eq!(&interfaces[..2], ["Area", "Point"]);
+        assert_eq!(&enums[..2], ["Animal", "Color"]);
+        // the main namespace's delegate is named after the client's object
+        assert_eq!(delegates, ["ArticleDelegate", "UserDelegate", "ExportDelegate", "SearchDelegate", ""]);
+    }
+}
@@ -1,17 +1,17 @@
 use regex::Regex;
 
-pub(crate) struct PathArguments {
+pub struct PathArguments {
     pub(in super::super) name: String,
     pub(in super::super) items: Vec<String>,
 }
 
 impl PathArguments {
 
-    pub(crate) fn name(&self) -> &str {
+    pub fn name(&self) -> &str {
         self.name.as_str()
     }
 
-    pub(crate) fn items(&self) -> Vec<&str> {
+    pub fn items(&self) -> Vec<&str> {
         self.items.iter().map(AsRef::as_ref).collect()
     }
 
@@ -1,16 +1,27 @@
+use teo_result::{Error, Result};
+use crate::client::{TsInt64Strategy, TsOptions};
+use crate::naming::Naming;
+use crate::utils::templates::TemplateOverrides;
+
 pub(crate) struct TsConf {
     pub(crate) object_name: String,
     pub(crate) class_name: String,
     is_client: bool,
+    options: TsOptions,
+    templates: TemplateOverrides,
+    naming: Naming,
 }
 
 impl TsConf {
 
-    pub fn new(object_name: String, class_name: String, is_client: bool) -> Self {
+    pub fn new(object_name: String, class_name: String, is_client: bool, options: TsOptions, templates: TemplateOverrides, naming: Naming) -> Self {
         TsConf {
             object_name,
             class_name,
             is_client,
+            options,
+            templates,
+            naming,
         }
     }
 
@@ -29,4 +40,52 @@ impl TsConf {
     pub fn is_entity(&self) -> bool {
         !self.is_client
     }
+
+    pub(crate) fn templates(&self) -> &TemplateOverrides {
+        &self.templates
+    }
+
+    pub(crate) fn naming(&self) -> &Naming {
+        &self.naming
+    }
+
+    pub fn int64(&self) -> TsInt64Strategy {
+        self.options.int64
+    }
+
+    pub fn branded_types(&self) -> bool {
+        self.options.branded_types
+    }
+
+    pub fn branded_model_ids(&self) -> bool {
+        self.options.branded_model_ids
+    }
+
+    /// The declared type of the `Int64` alias.
+    pub fn int64_type(&self) -> &'static str {
+        match self.options.int64 {
+            TsInt64Strategy::Number => "number",
+            TsInt64Strategy::BigInt => "bigint",
+            TsInt64Strategy::String => "string",
+        }
+    }
+}
+
+/// JavaScript objects are sent as they are, and the runtime resolves
+/// properties and methods by their schema names, so only delegate types
+/// declared in `index.d.ts` can be renamed.
+pub(crate) fn check_naming(naming: &Naming, target: &str) -> Result<()> {
+    let default = Naming::default();
+    let renames_delegates_only = naming.fields == default.fields &&
+        naming.properties == default.properties &&
+        naming.methods == default.methods &&
+        naming.enum_members == default.enum_members &&
+        naming.namespaces == default.namespaces &&
+        naming.renames.is_empty() &&
+        naming.namespace_renames.is_empty();
+    if renames_delegates_only {
+        Ok(())
+    } else {
+        Err(Error::new(format!("{} can only rename delegate types, other names have no mapping to the schema's", target)))
+    }
 }
@@ -0,0 +1,153 @@
+use inflector::Inflector;
+use teo_runtime::namespace::Namespace;
+use crate::outline::delegate::{Delegate, RequestItem};
+use crate::outline::outline::Outline;
+use crate::outline::path_arguments::PathArguments;
+use crate::shared::ts::conf::TsConf;
+
+/// What a builtin handler does, like `find users matching a filter`.
+fn builtin_summary(handler: &str, model: &str) -> String {
+    let singular = model.to_word_case();
+    let plural = singular.to_plural();
+    match handler {
+        "findUnique" => format!("find a unique {singular}"),
+        "findFirst" => format!("find the first {singular} matching a filter"),
+        "findMany" => format!("find {plural} matching a filter"),
+        "create" => format!("create {}", singular.articlize()),
+        "update" => format!("update a unique {singular}"),
+        "upsert" => format!("update a unique {singular}, or create it if it doesn't exist"),
+        "copy" => format!("copy a unique {singular}"),
+        "delete" => format!("delete a unique {singular}"),
+        "createMany" => format!("create many {plural}"),
+        "updateMany" => format!("update {plural} matching a filter"),
+        "copyMany" => format!("copy {plural} matching a filter"),
+        "deleteMany" => format!("delete {plural} matching a filter"),
+        "count" => format!("count {plural} matching a filter"),
+        "aggregate" => format!("aggregate on {plural} matching a filter"),
+        "groupBy" => format!("group {plural} by fields and aggregate on each group"),
+        "signIn" => format!("sign in as {}", singular.articlize()),
+        "identity" => format!("get the {singular} of the current identity"),
+        _ => format!("{} {plural}", handler.to_word_case()),
+    }
+}
+
+/// The arguments of a builtin handler in its example, with what to put there.
+fn builtin_arguments(handler: &str) -> Vec<(&'static str, &'static str)> {
+    let unique = ("where: {},", "a unique filter");
+    let filter = ("where: {},", "a filter");
+    match handler {
+        "findUnique" | "delete" => vec![unique],
+        "findFirst" => vec![filter, ("orderBy: {},", "the order to find in")],
+        "findMany" => vec![filter, ("orderBy: {},", "the order to find in"), ("skip: 0,", "records to skip"), ("take: 10,", "records to take")],
+        "create" => vec![("create: {},", "the data to create")],
+        "update" => vec![unique, ("update: {},", "the data to update")],
+        "upsert" => vec![unique, ("create: {},", "the data to create"), ("update: {},", "the data to update")],
+        "copy" => vec![unique, ("copy: {},", "the data to change on the copy")],
+        "createMany" => vec![("create: [],", "the data to create")],
+        "updateMany" => vec![filter, ("update: {},", "the data to update")],
+        "copyMany" => vec![filter, ("copy: {},", "the data to change on the copies")],
+        "deleteMany" | "count" => vec![filter],
+        "aggregate" => vec![filter, ("_count: { _all: true },", "aggregations like _avg, _sum, _min and _max")],
+        "groupBy" => vec![("by: [],", "the fields to group by"), ("_count: { _all: true },", "aggregations of each group")],
+        "signIn" => vec![("credentials: {},", "an identity field and a checker field")],
+        _ => vec![],
+    }
+}
+
+fn example_call(call: &str, arguments: &Vec<(&'static str, &'static str)>) -> Vec<String> {
+    if arguments.is_empty() {
+        return vec![format!("const {{ data }} = await {call}({{}})")];
+    }
+    let mut lines = vec![format!("const {{ data }} = await {call}({{")];
+    lines.extend(arguments.iter().map(|(argument, comment)| format!("    {argument} // {comment}")));
+    lines.push("})".to_owned());
+    lines
+}
+
+/// `{ id: "..." }` for the path arguments in `url`.
+fn example_path_arguments(url: &String) -> String {
+    let items = PathArguments::fetch_items(Some(url));
+    format!("{{ {} }}", items.iter().map(|item| format!("{item}: \"...\"")).collect::<Vec<String>>().join(", "))
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn doc_comment(lines: Vec<String>) -> String {
+    let mut result = vec!["/**".to_owned()];
+    result.extend(lines.into_iter().map(|line| if line.is_empty() { " *".to_owned() } else { format!(" * {line}") }));
+    result.push(" */".to_owned());
+    result.join("\n    ")
+}
+
+fn deprecated_line(reason: &str) -> String {
+    if reason.is_empty() {
+        "@deprecated".to_owned()
+    } else {
+        format!("@deprecated {reason}")
+    }
+}
+
+/// The JSDoc of a delegate method, with an example of calling it. It's
+/// indented for the delegate interface.
+pub(super) fn request_item_doc(namespace: &Namespace, outline: &Outline, delegate: &Delegate, request_item: &RequestItem, conf: &TsConf) -> String {
+    let receiver = outline.delegate_receiver(conf.object_name(), namespace.path(), delegate);
+    let call = format!("{}.{}", receiver, request_item.identifier());
+    let mut lines = vec![];
+    if request_item.is_builtin() {
+        let summary = capitalize(&builtin_summary(request_item.name(), delegate.schema_name()));
+        lines.push(format!("{}.", summary));
+        lines.push("".to_owned());
+        lines.push("@example".to_owned());
+        lines.push("```ts".to_owned());
+        lines.push(format!("// {}", summary));
+        lines.extend(example_call(&call, &builtin_arguments(request_item.name())));
+    } else {
+        lines.push(format!("Request `{} /{}`.", request_item.method(), request_item.path().trim_start_matches('/')));
+        lines.push("".to_owned());
+        lines.push("@example".to_owned());
+        lines.push("```ts".to_owned());
+        let mut arguments = vec![];
+        if request_item.has_custom_url_args() {
+            arguments.push(example_path_arguments(&request_item.path));
+        }
+        if request_item.has_body_input() {
+            arguments.push(if request_item.is_form() { "formData".to_owned() } else { "input".to_owned() });
+        }
+        lines.push(format!("const result = await {}({})", call, arguments.join(", ")));
+    }
+    lines.push("```".to_owned());
+    if let Some(reason) = request_item.deprecated() {
+        lines.push(deprecated_line(reason));
+    }
+    doc_comment(lines)
+}
+
+/// The example in the JSDoc of the client object, fetching the records of
+/// the first model.
+pub(super) fn main_object_example(outline: &Outline, conf: &TsConf) -> String {
+    let found = outline.delegates().iter().find_map(|delegate| {
+        delegate.request_items().iter().find(|item| item.is_builtin() && item.name() == "findMany").map(|item| (delegate, item))
+    });
+    let Some((delegate, request_item)) = found else {
+        return "".to_owned();
+    };
+    let call = format!("{}.{}", outline.delegate_receiver(conf.object_name(), &vec![], delegate), request_item.identifier());
+    let mut lines = vec!["@example".to_owned(), "```ts".to_owned(), format!("// Fetch {}", delegate.schema_name().to_word_case().to_plural())];
+    lines.extend(example_call(&call, &vec![]));
+    lines.push("```".to_owned());
+    lines.into_iter().map(|line| format!(" * {line}\n")).collect::<String>() + " *\n"
+}
+
+/// The `@deprecated` line of a JSDoc, indented by `indent` spaces, or nothing.
+pub(super) fn deprecated_tag(deprecated: Option<&str>, indent: usize) -> String {
+    match deprecated {
+        Some(reason) => format!("\n{} * {}", " ".repeat(indent), deprecated_line(reason)),
+        None => "".to_owned(),
+    }
+}
@@ -14,13 +14,13 @@ pub(crate) fn lookup(t: &Type, ts_result_mode: bool, mode: Mode) -> Result<Strin
         Type::Null => "null".to_owned(),
         Type::Bool => "boolean".to_owned(),
         Type::Int => "number".to_owned(),
-        Type::Int64 => "number".to_owned(),
+        Type::Int64 => if mode.is_client() { "Int64".to_owned() } else { "number".to_owned() },
         Type::Float32 => "number".to_owned(),
         Type::Float => "number".to_owned(),
         Type::Decimal => "Decimal".to_owned(),
         Type::String => "string".to_owned(),
-        Type::ObjectId => if mode.is_client() { "string".to_owned() } else { "ObjectId".to_owned() },
-        Type::Date => if mode.is_client() { "string".to_owned() } else { "DateOnly".to_owned() },
+        Type::ObjectId => "ObjectId".to_owned(),
+        Type::Date => "DateOnly".to_owned(),
         Type::DateTime => "Date".to_owned(),
         Type::File => "File".to_owned(),
         Type::Regex => Err(Error::new("encountered regex"))?,
@@ -70,29 +70,21 @@ pub(crate) fn lookup(t: &Type, ts_result_mode: bool, mode: Mode) -> Result<Strin
         },
         Type::SynthesizedEnumReference(enum_reference) => enum_reference_lookup(enum_reference, ".")?,
         Type::DeclaredSynthesizedShape(reference, model_type) => declared_shape_lookup(reference, model_type.as_ref(), ".")?,
-        _ => Err(Error::new("encountered unhandled type in lookup"))?,
+        _ => Err(Error::new(format!("encountered unhandled type `{}' in lookup", t)))?,
     })
 }
 
 pub(crate) fn ts_result_shape_reference_lookup(shape_reference: &SynthesizedShapeReference) -> Result<String> {
+    let base = shape_reference.owner.as_model_object().ok_or_else(|| Error::new(format!("synthesized shape `{}' requires a model, found `{}'", shape_reference.kind, shape_reference.owner)))?.string_path().join(".");
     Ok(match shape_reference.kind {
-        SynthesizedShapeReferenceKind::Result => {
-            let base = shape_reference.owner.as_model_object().unwrap().string_path().join(".");
-            format!("CheckSelectInclude<T, {base}, {base}GetPayload<T>>")
-        },
-        SynthesizedShapeReferenceKind::CountAggregateResult => format!("{}CountAggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(".")),
-        SynthesizedShapeReferenceKind::SumAggregateResult => format!("{}SumAggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(".")),
-        SynthesizedShapeReferenceKind::AvgAggregateResult => format!("{}AvgAggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(".")),
-        SynthesizedShapeReferenceKind::MinAggregateResult => format!("{}MinAggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(".")),
-        SynthesizedShapeReferenceKind::MaxAggregateResult => format!("{}MaxAggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(".")),
-        SynthesizedShapeReferenceKind::AggregateResult => {
-            let base = shape_reference.owner.as_model_object().unwrap().string_path().join(".");
-            format!("Get{base}AggregateType<T>")
-        },
-        SynthesizedShapeReferenceKind::GroupByResult => {
-            let base = shape_reference.owner.as_model_object().unwrap().string_path().join(".");
-            format!("{{}} extends InputErrors ? Get{base}GroupByPayload<T> : InputErrors")
-        },
+        SynthesizedShapeReferenceKind::Result => format!("CheckSelectInclude<T, {base}, {base}GetPayload<T>>"),
+        SynthesizedShapeReferenceKind::CountAggregateResult => format!("{}CountAggregateResult", base),
+        SynthesizedShapeReferenceKind::SumAggregateResult => format!("{}SumAggregateResult", base),
+        SynthesizedShapeReferenceKind::AvgAggregateResult => format!("{}AvgAggregateResult", base),
+        SynthesizedShapeReferenceKind::MinAggregateResult => format!("{}MinAggregateResult", base),
+        SynthesizedShapeReferenceKind::MaxAggregateResult => format!("{}MaxAggregateResult", base),
+        SynthesizedShapeReferenceKind::AggregateResult => format!("Get{base}AggregateType<T>"),
+        SynthesizedShapeReferenceKind::GroupByResult => format!("{{}} extends InputErrors ? Get{base}GroupByPayload<T> : InputErrors"),
         _ => shape_reference_lookup(shape_reference, ".", Mode::Client)?,
     })
 }
\ No newline at end of file
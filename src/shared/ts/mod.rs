@@ -1,3 +1,4 @@
 pub(crate) mod lookup;
 pub(crate) mod conf;
+pub(crate) mod docs;
 pub(crate) mod templates;
\ No newline at end of file
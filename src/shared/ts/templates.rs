@@ -3,8 +3,11 @@ use teo_parser::r#type::Type;
 use teo_runtime::namespace::Namespace;
 use teo_result::Result;
 use teo_runtime::traits::named::Named;
+use crate::outline::interface::Interface;
 use crate::outline::outline::{Mode, Outline};
+use crate::outline::delegate::{Delegate, RequestItem};
 use crate::shared::ts::conf::TsConf;
+use crate::shared::ts::docs::{deprecated_tag, main_object_example, request_item_doc};
 use crate::shared::ts::lookup::lookup;
 use crate::utils::filters;
 use teo_runtime::traits::documentable::Documentable;
@@ -16,7 +19,7 @@ pub(crate) struct TsIndexDTsTemplate<'a> {
     pub(crate) main_namespace: &'a Namespace,
     pub(crate) conf: &'a TsConf,
     pub(crate) mode: Mode,
-    pub(crate) render_namespace: &'static dyn Fn(&Namespace, &TsConf, &Namespace, Mode) -> String,
+    pub(crate) render_namespace: &'static dyn Fn(&Namespace, &TsConf, &Namespace, Mode) -> Result<String>,
 }
 
 unsafe impl Send for TsIndexDTsTemplate<'_> { }
@@ -27,7 +30,7 @@ unsafe impl Sync for TsIndexDTsTemplate<'_> { }
 pub(crate) struct TsNamespaceTemplate<'a> {
     pub(self) conf: &'a TsConf,
     pub(self) namespace: &'a Namespace,
-    pub(self) render_namespace: &'static dyn Fn(&Namespace, &TsConf, &Namespace, Mode) -> String,
+    pub(self) render_namespace: &'static dyn Fn(&Namespace, &TsConf, &Namespace, Mode) -> Result<String>,
     pub(self) outline: &'a Outline,
     pub(self) lookup: &'static dyn Fn(&Type, bool, Mode) -> Result<String>,
     pub(self) get_payload_suffix: &'static dyn Fn(&Type) -> &'static str,
@@ -36,6 +39,10 @@ pub(crate) struct TsNamespaceTemplate<'a> {
     pub(self) mode: Mode,
     pub(self) optional_strategy: &'static dyn Fn(&String) -> String,
     pub(self) group_by_generics: &'static dyn Fn(String) -> String,
+    pub(self) model_id_brand: &'static dyn Fn(&Namespace, &Interface, &str, &TsConf) -> String,
+    pub(self) request_item_doc: &'static dyn Fn(&Namespace, &Outline, &Delegate, &RequestItem, &TsConf) -> String,
+    pub(self) main_object_example: &'static dyn Fn(&Outline, &TsConf) -> String,
+    pub(self) deprecated_tag: &'static dyn Fn(Option<&str>, usize) -> String,
 }
 
 unsafe impl Send for TsNamespaceTemplate<'_> { }
@@ -49,6 +56,21 @@ fn ts_extends(extends: &Vec<Type>, mode: Mode) -> String {
     }
 }
 
+fn model_id_brand(namespace: &Namespace, interface: &Interface, field_name: &str, conf: &TsConf) -> String {
+    if !conf.is_client() || !conf.branded_model_ids() || !interface.is_output_result() {
+        return "".to_owned();
+    }
+    let Some(model) = namespace.models().get(interface.model_name()) else {
+        return "".to_owned();
+    };
+    let is_primary = model.primary_index().map_or(false, |index| index.items().iter().any(|item| item.field.as_str() == field_name));
+    if is_primary {
+        format!(" & {{ __model: '{}' }}", model.name())
+    } else {
+        "".to_owned()
+    }
+}
+
 fn get_payload_suffix(t: &Type) -> &'static str {
     if t.is_array() {
         "[]"
@@ -126,12 +148,14 @@ fn optional_strategy(original: &String) -> String {
     }
 }
 
-pub(crate) fn render_namespace(namespace: &Namespace, conf: &TsConf, main_namespace: &Namespace, mode: Mode) -> String {
-    let content = TsNamespaceTemplate {
+pub(crate) fn render_namespace(namespace: &Namespace, conf: &TsConf, main_namespace: &Namespace, mode: Mode) -> Result<String> {
+    let outline = Outline::new(namespace, mode, main_namespace, false, conf.naming())?;
+    outline.try_each_type(|t| lookup(t, false, mode).map(|_| ()))?;
+    let content = conf.templates().render_for_namespace("shared/ts/namespace.partial.jinja", &TsNamespaceTemplate {
         conf,
         namespace,
         render_namespace: &render_namespace,
-        outline: &Outline::new(namespace, mode, main_namespace, false),
+        outline: &outline,
         lookup: &lookup,
         get_payload_suffix: &get_payload_suffix,
         ts_extends: &ts_extends,
@@ -139,10 +163,14 @@ pub(crate) fn render_namespace(namespace: &Namespace, conf: &TsConf, main_namesp
         mode,
         optional_strategy: &optional_strategy,
         group_by_generics: &group_by_generics,
-    }.render().unwrap();
-    if namespace.path().is_empty() {
+        model_id_brand: &model_id_brand,
+        request_item_doc: &request_item_doc,
+        main_object_example: &main_object_example,
+        deprecated_tag: &deprecated_tag,
+    }, namespace)?;
+    Ok(if namespace.path().is_empty() {
         content
     } else {
         format!("export namespace {} {{\n", namespace.name()) + &indent::indent_by(4, content.as_str()) + "\n}"
-    }
+    })
 }
\ No newline at end of file
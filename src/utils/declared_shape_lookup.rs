@@ -8,8 +8,9 @@ pub(crate) fn declared_shape_lookup(
     path_separator: &str,
 ) -> Result<String> {
     if let Some(owner) = owner.as_model_object() {
-        Ok(owner.string_path().join(path_separator) + declared.string_path().last().unwrap())
+        let name = declared.string_path().last().ok_or_else(|| Error::new(format!("declared shape of `{}' has an empty path", owner.string_path().join("."))))?;
+        Ok(owner.string_path().join(path_separator) + name)
     } else {
-        Err(Error::new("owner is not model"))
+        Err(Error::new(format!("declared shape `{}' requires a model, found `{}'", declared.string_path().join("."), owner)))
     }
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+/// Split the `@deprecated` line off a schema description, like
+///
+/// ```teo
+/// /// The user's nickname.
+/// /// @deprecated Use `displayName` instead.
+/// ```
+///
+/// The reason is empty when the tag has none.
+pub(crate) fn split_deprecation(desc: String) -> (String, Option<String>) {
+    let mut deprecated = None;
+    let mut lines = vec![];
+    for line in desc.lines() {
+        match line.trim().strip_prefix("@deprecated") {
+            Some(reason) if reason.is_empty() || reason.starts_with(char::is_whitespace) => deprecated = Some(reason.trim().to_owned()),
+            _ => lines.push(line),
+        }
+    }
+    if deprecated.is_none() {
+        return (desc, None);
+    }
+    (lines.join("\n").trim().to_owned(), deprecated)
+}
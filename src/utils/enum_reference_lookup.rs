@@ -1,15 +1,16 @@
 use teo_parser::r#type::synthesized_enum_reference::{SynthesizedEnumReference, SynthesizedEnumReferenceKind};
-use teo_result::Result;
+use teo_result::{Error, Result};
 
 pub(crate) fn enum_reference_lookup(
     enum_reference: &SynthesizedEnumReference,
     path_separator: &str,
 ) -> Result<String> {
+    let owner = enum_reference.owner.as_model_object().ok_or_else(|| Error::new(format!("synthesized enum `{}' requires a model, found `{}'", enum_reference.kind, enum_reference.owner)))?.string_path().join(path_separator);
     Ok(match enum_reference.kind {
-        SynthesizedEnumReferenceKind::ScalarFields => format!("{}ScalarFields", enum_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedEnumReferenceKind::SerializableScalarFields => format!("{}SerializableScalarFields", enum_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedEnumReferenceKind::Relations => format!("{}Relations", enum_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedEnumReferenceKind::DirectRelations => format!("{}DirectRelations", enum_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedEnumReferenceKind::IndirectRelations => format!("{}IndirectRelations", enum_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
+        SynthesizedEnumReferenceKind::ScalarFields => format!("{}ScalarFields", owner),
+        SynthesizedEnumReferenceKind::SerializableScalarFields => format!("{}SerializableScalarFields", owner),
+        SynthesizedEnumReferenceKind::Relations => format!("{}Relations", owner),
+        SynthesizedEnumReferenceKind::DirectRelations => format!("{}DirectRelations", owner),
+        SynthesizedEnumReferenceKind::IndirectRelations => format!("{}IndirectRelations", owner),
     })
 }
\ No newline at end of file
@@ -1,83 +1,132 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::fs::{File};
 use std::io::Write;
 use std::fs::create_dir_all;
-use std::fs::remove_dir_all;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 use super::message::{green_message, red_message, yellow_message};
 use pathdiff::diff_paths;
-use teo_result::Result;
+use teo_result::{Error, Result};
 
-pub(crate) struct FileUtil {
+/// Writes generated files under a base directory, reporting each one.
+///
+/// A file is only written when its content changes, so tools watching the
+/// output don't see untouched files. Clearing a directory doesn't delete its
+/// files right away, the ones which aren't generated again are deleted by
+/// [`remove_stale_files`](Self::remove_stale_files). Files modified since the
+/// clear by something else, like a build tool, are kept.
+pub struct FileUtil {
     base_dir: PathBuf,
+    stale: Arc<Mutex<Stale>>,
+}
+
+#[derive(Default)]
+struct Stale {
+    cleared: Vec<PathBuf>,
+    /// The files found when clearing, with their modification times then.
+    files: BTreeMap<PathBuf, Option<SystemTime>>,
 }
 
 impl FileUtil {
 
-    pub(crate) fn new(base_dir: impl Into<PathBuf>) -> Self {
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+            stale: Arc::new(Mutex::new(Stale::default())),
+        }
+    }
+
+    /// A file util for `base_dir` which removes stale files together with
+    /// this one, for a generation writing into several directories.
+    pub fn with_base_dir(&self, base_dir: impl Into<PathBuf>) -> Self {
         Self {
-            base_dir: base_dir.into()
+            base_dir: base_dir.into(),
+            stale: self.stale.clone(),
         }
     }
 
-    pub(crate) async fn ensure_root_directory(&self) -> Result<()> {
+    pub async fn ensure_root_directory(&self) -> Result<()> {
         if !self.base_dir.exists() {
-            yellow_message("create", diff_paths(&self.base_dir, std::env::current_dir().unwrap()).unwrap().to_str().unwrap().to_string());
+            yellow_message("create", display_path(&self.base_dir));
             create_dir_all(&self.base_dir)?;
         }
         Ok(())
     }
 
-    pub(crate) async fn ensure_directory<D: Into<String>>(&self, dir_name: D) -> Result<()> {
+    pub async fn ensure_directory<D: Into<String>>(&self, dir_name: D) -> Result<()> {
         let dirname = self.base_dir.join(dir_name.into());
         if !dirname.exists() {
-            yellow_message("create", diff_paths(&dirname, std::env::current_dir().unwrap()).unwrap().to_str().unwrap().to_string());
+            yellow_message("create", display_path(&dirname));
             Ok(create_dir_all(dirname)?)
         } else {
             Ok(())
         }
     }
 
-    pub(crate) async fn clear_root_directory(&self) -> Result<()> {
-        if !&self.base_dir.exists() {
-            yellow_message("create", diff_paths(&self.base_dir, std::env::current_dir().unwrap()).unwrap().to_str().unwrap().to_string());
-            Ok(create_dir_all(&self.base_dir)?)
-        } else {
-            red_message("clear", diff_paths(&self.base_dir, std::env::current_dir().unwrap()).unwrap().to_str().unwrap().to_string());
-            remove_dir_all(&self.base_dir)?;
-            Ok(create_dir_all(&self.base_dir)?)
+    pub async fn clear_root_directory(&self) -> Result<()> {
+        self.clear(&self.base_dir)
+    }
+
+    pub async fn clear_directory<D: Into<String>>(&self, dir_name: D) -> Result<()> {
+        self.clear(&self.base_dir.join(dir_name.into()))
+    }
+
+    fn clear(&self, dir: &Path) -> Result<()> {
+        if !dir.exists() {
+            yellow_message("create", display_path(dir));
+            return Ok(create_dir_all(dir)?);
         }
+        let mut stale = self.stale.lock().unwrap();
+        collect_files(dir, &mut stale.files)?;
+        stale.cleared.push(dir.to_path_buf());
+        Ok(())
     }
 
-    pub(crate) async fn clear_directory<D: Into<String>>(&self, dir_name: D) -> Result<()> {
-        let dirname = self.base_dir.join(dir_name.into());
-        if !&dirname.exists() {
-            yellow_message("create", diff_paths(&dirname, std::env::current_dir().unwrap()).unwrap().to_str().unwrap().to_string());
-            Ok(create_dir_all(&dirname)?)
-        } else {
-            red_message("clear", diff_paths(&dirname, std::env::current_dir().unwrap()).unwrap().to_str().unwrap().to_string());
-            remove_dir_all(&dirname)?;
-            Ok(create_dir_all(&dirname)?)
+    /// Delete the files in cleared directories which weren't generated
+    /// again, and the directories left empty.
+    pub async fn remove_stale_files(&self) -> Result<()> {
+        let mut stale = std::mem::take(&mut *self.stale.lock().unwrap());
+        for (file, modified) in std::mem::take(&mut stale.files) {
+            if file.exists() && modified_time(&file) == modified {
+                red_message("remove", display_path(&file));
+                std::fs::remove_file(&file)?;
+            }
+        }
+        for dir in &stale.cleared {
+            remove_empty_directories(dir)?;
         }
+        Ok(())
+    }
+
+    fn is_stale(&self, path: &Path) -> bool {
+        self.stale.lock().unwrap().files.contains_key(path)
     }
 
-    pub(crate) async fn ensure_directory_and_generate_file<F: AsRef<Path>, S: AsRef<str>>(&self, file_name: F, content: S) -> Result<()> {
+    pub async fn ensure_directory_and_generate_file<F: AsRef<Path>, S: AsRef<str>>(&self, file_name: F, content: S) -> Result<()> {
         let path = file_name.as_ref();
         if let Some(dir) = path.parent() {
-            self.ensure_directory(dir.as_os_str().to_str().unwrap()).await?;
+            self.ensure_directory(dir.to_string_lossy()).await?;
         }
         self.generate_file(file_name, content).await
     }
 
-    pub(crate) async fn generate_file<F: AsRef<Path>, S: AsRef<str>>(&self, file_name: F, content: S) -> Result<()> {
+    pub async fn generate_file<F: AsRef<Path>, S: AsRef<str>>(&self, file_name: F, content: S) -> Result<()> {
         let filename = self.base_dir.join(file_name.as_ref());
-        let mut output_file = File::create(&filename)?;
-        green_message("create", diff_paths(&filename, std::env::current_dir().unwrap()).unwrap().to_str().unwrap().to_string());
+        self.stale.lock().unwrap().files.remove(&filename);
+        let label = match std::fs::read(&filename) {
+            Ok(existing) if existing == content.as_ref().as_bytes() => return Ok(()),
+            Ok(_) => "update",
+            Err(_) => "create",
+        };
+        let mut output_file = File::create(&filename).map_err(|e| Error::new(format!("cannot create `{}': {}", filename.display(), e)))?;
+        green_message(label, display_path(&filename));
         Ok(write!(output_file, "{}", content.as_ref())?)
     }
 
-    pub(crate) async fn generate_file_if_not_exist<F: AsRef<str>, S: AsRef<str>>(&self, file_name: F, content: S) -> Result<bool> {
+    pub async fn generate_file_if_not_exist<F: AsRef<str>, S: AsRef<str>>(&self, file_name: F, content: S) -> Result<bool> {
         let filename = self.base_dir.join(PathBuf::from(file_name.as_ref()));
-        if !filename.exists() {
+        if !filename.exists() || self.is_stale(&filename) {
             self.generate_file(file_name.as_ref().to_owned(), content.as_ref().to_owned()).await?;
             Ok(false)
         } else {
@@ -85,7 +134,7 @@ impl FileUtil {
         }
     }
 
-    pub(crate) fn find_file_upwards(&self, name: impl AsRef<str>) -> Option<PathBuf> {
+    pub fn find_file_upwards(&self, name: impl AsRef<str>) -> Option<PathBuf> {
         let mut path: PathBuf = self.base_dir.clone();
         let file = Path::new(name.as_ref());
         loop {
@@ -101,11 +150,98 @@ impl FileUtil {
         }
     }
 
-    pub(crate) fn get_base_dir(&self) -> &Path {
+    pub fn get_base_dir(&self) -> &Path {
         &self.base_dir
     }
 
-    pub(crate) fn get_file_path(&self, name: impl AsRef<str>) -> PathBuf {
+    pub fn get_file_path(&self, name: impl AsRef<str>) -> PathBuf {
         self.base_dir.join(name.as_ref())
     }
 }
+
+/// The content of an existing file the generator updates, like a package
+/// manifest.
+pub(crate) fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| Error::new(format!("cannot read `{}': {}", path.display(), e)))
+}
+
+/// The files in `dir`, leaving out a package's git repository.
+fn collect_files(dir: &Path, files: &mut BTreeMap<PathBuf, Option<SystemTime>>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.file_name().is_some_and(|name| name == ".git") {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            let modified = modified_time(&path);
+            files.insert(path, modified);
+        }
+    }
+    Ok(())
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// Remove the empty directories inside `dir`, `true` when `dir` is empty
+/// afterwards.
+fn remove_empty_directories(dir: &Path) -> Result<bool> {
+    if !dir.is_dir() {
+        return Ok(false);
+    }
+    let mut empty = true;
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !(path.is_dir() && remove_empty_directories(&path)? && std::fs::remove_dir(&path).is_ok()) {
+            empty = false;
+        }
+    }
+    Ok(empty)
+}
+
+/// The path relative to the working directory for messages, or the path as is
+/// when there's no relative form, like on another drive.
+fn display_path(path: &Path) -> String {
+    std::env::current_dir().ok()
+        .and_then(|cwd| diff_paths(path, cwd))
+        .unwrap_or_else(|| path.to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::*;
+
+    fn write_at(path: &Path, content: &str, modified: SystemTime) {
+        std::fs::write(path, content).unwrap();
+        File::options().write(true).open(path).unwrap().set_modified(modified).unwrap();
+    }
+
+    #[tokio::test]
+    async fn files_written_after_clear_survive() {
+        let dir = std::env::temp_dir().join(format!("teo-generator-file-util-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        create_dir_all(&dir).unwrap();
+        let earlier = SystemTime::now() - Duration::from_secs(60);
+        write_at(&dir.join("generated.dart"), "old", earlier);
+        write_at(&dir.join("generated.g.dart"), "old", earlier);
+        write_at(&dir.join("removed.dart"), "old", earlier);
+        let file_util = FileUtil::new(&dir);
+        file_util.clear_root_directory().await.unwrap();
+        file_util.generate_file("generated.dart", "new").await.unwrap();
+        // written by a build tool rather than the generator
+        std::fs::write(dir.join("generated.g.dart"), "new").unwrap();
+        std::fs::write(dir.join("created.g.dart"), "new").unwrap();
+        file_util.remove_stale_files().await.unwrap();
+        assert_eq!(std::fs::read_to_string(dir.join("generated.dart")).unwrap(), "new");
+        assert_eq!(std::fs::read_to_string(dir.join("generated.g.dart")).unwrap(), "new");
+        assert!(dir.join("created.g.dart").exists());
+        assert!(!dir.join("removed.dart").exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -64,73 +64,144 @@ pub fn constantize<T: std::fmt::Display>(s: T) -> ::askama::Result<String> {
 }
 
 pub fn escape_rust<T: std::fmt::Display>(s: T) -> ::askama::Result<String> {
-    let s = s.to_string();
-    if vec!["where", "in", "struct", "enum", "for", "if", "else", "virtual", "let", "mut", "async", "break", "continue", "loop", "return", "type"].contains(&s.as_str()) {
-        Ok(format!("r#{}", s))
-    } else {
-        Ok(s)
-    }
+    Ok(Language::Rust.escape(&s.to_string()))
 }
 
 pub fn escape_python<T: std::fmt::Display>(s: T) -> ::askama::Result<String> {
-    let s = s.to_string();
-    if vec!["is", "not", "in", "break", "catch", "default", "do", "else", "for", "return", "throw"].contains(&s.as_str()) {
-        Ok(format!("{}_", s))
-    } else {
-        Ok(s)
-    }
+    Ok(Language::Python.escape(&s.to_string()))
 }
 
 pub fn escape_swift<T: std::fmt::Display>(s: T) -> ::askama::Result<String> {
-    let s = s.to_string();
-    if vec!["is", "where", "break", "case", "continue", "catch", "default", "defer", "do", "else", "for", "fallthrough", "for", "in", "repeat", "guard", "while", "return", "throw"].contains(&s.as_str()) {
-        Ok(format!("`{}`", s))
-    } else {
-        Ok(s)
-    }
+    Ok(Language::Swift.escape(&s.to_string()))
 }
 
 pub fn escape_ts<T: std::fmt::Display>(s: T) -> ::askama::Result<String> {
-    let s = s.to_string();
-    if vec!["for", "break", "case", "continue", "catch", "do", "else", "for", "while", "return", "throw"].contains(&s.as_str()) {
-        Ok(format!("'{}'", s))
-    } else {
-        Ok(s)
-    }
+    Ok(Language::TypeScript.escape(&s.to_string()))
 }
 
 pub fn escape_csharp<T: std::fmt::Display>(s: T) -> ::askama::Result<String> {
-    let s = s.to_string();
-    if vec!["is", "where"].contains(&s.as_str()) {
-        Ok(format!("@{}", s))
-    } else {
-        Ok(s)
-    }
+    Ok(Language::CSharp.escape(&s.to_string()))
 }
 
 pub fn escape_dart<T: std::fmt::Display>(s: T) -> ::askama::Result<String> {
-    let s = s.to_string();
-    if s.starts_with("_") {
-        Ok(s.replace("_", "$"))
-    } else {
-        match s.as_str() {
-            "is" => Ok("matches".to_owned()),
-            "in" => Ok("isOneOf".to_owned()),
-            "AND" => Ok("$and".to_owned()),
-            "OR" => Ok("$or".to_owned()),
-            "NOT" => Ok("$not".to_owned()),
-            "default" => Ok("defaultMode".to_owned()),
-            _ => Ok(s),
+    Ok(Language::Dart.escape(&s.to_string()))
+}
+
+pub fn escape_kotlin<T: std::fmt::Display>(s: T) -> ::askama::Result<String> {
+    Ok(Language::Kotlin.escape(&s.to_string()))
+}
+
+/// # Reserved words
+///
+/// Names from the schema which are reserved in a target language are escaped
+/// with the language's own mechanism where it has one (`r#`, backticks, `@`,
+/// quotes), so the identifier still reads as the schema name and serializes
+/// under it. Python appends `_`, and Dart, which can't escape at all, renames;
+/// generators map these back to the schema name with `alias` or `@JsonKey`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Language {
+    Rust,
+    Python,
+    Swift,
+    TypeScript,
+    Kotlin,
+    Dart,
+    CSharp,
+}
+
+impl Language {
+
+    pub(crate) fn reserved_words(&self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => &[
+                "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+                "false", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+                "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+                "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+                "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+            ],
+            Language::Python => &[
+                "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+                "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in",
+                "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+            ],
+            Language::Swift => &[
+                "associatedtype", "class", "deinit", "enum", "extension", "fileprivate", "func", "import", "init",
+                "inout", "internal", "let", "open", "operator", "private", "precedencegroup", "protocol", "public",
+                "rethrows", "static", "struct", "subscript", "typealias", "var", "break", "case", "catch",
+                "continue", "default", "defer", "do", "else", "fallthrough", "for", "guard", "if", "in", "repeat",
+                "return", "throw", "switch", "where", "while", "Any", "as", "await", "false", "is", "nil", "self",
+                "Self", "super", "throws", "true", "try",
+            ],
+            Language::TypeScript => &[
+                "break", "case", "catch", "class", "const", "continue", "debugger", "default", "delete", "do",
+                "else", "enum", "export", "extends", "false", "finally", "for", "function", "if", "import", "in",
+                "instanceof", "new", "null", "return", "super", "switch", "this", "throw", "true", "try", "typeof",
+                "var", "void", "while", "with", "implements", "interface", "let", "package", "private",
+                "protected", "public", "static", "yield",
+            ],
+            Language::Kotlin => &[
+                "as", "break", "class", "continue", "do", "else", "false", "for", "fun", "if", "in", "interface",
+                "is", "null", "object", "package", "return", "super", "this", "throw", "true", "try", "typealias",
+                "typeof", "val", "var", "when", "while",
+            ],
+            Language::Dart => &[
+                "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else", "enum",
+                "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null", "rethrow",
+                "return", "super", "switch", "this", "throw", "true", "try", "var", "void", "while", "with",
+            ],
+            Language::CSharp => &[
+                "abstract", "as", "base", "bool", "break", "byte", "case", "catch", "char", "checked", "class",
+                "const", "continue", "decimal", "default", "delegate", "do", "double", "else", "enum", "event",
+                "explicit", "extern", "false", "finally", "fixed", "float", "for", "foreach", "goto", "if",
+                "implicit", "in", "int", "interface", "internal", "is", "lock", "long", "namespace", "new", "null",
+                "object", "operator", "out", "override", "params", "private", "protected", "public", "readonly",
+                "ref", "return", "sbyte", "sealed", "short", "sizeof", "stackalloc", "static", "string", "struct",
+                "switch", "this", "throw", "true", "try", "typeof", "uint", "ulong", "unchecked", "unsafe",
+                "ushort", "using", "virtual", "void", "volatile", "where", "while",
+            ],
+        }
+    }
+
+    pub(crate) fn is_reserved(&self, name: &str) -> bool {
+        self.reserved_words().contains(&name)
+    }
+
+    /// The identifier to use for the schema name `name`.
+    pub(crate) fn escape(&self, name: &str) -> String {
+        if *self == Language::Dart {
+            return escape_dart_identifier(name);
+        }
+        if !self.is_reserved(name) {
+            return name.to_owned();
+        }
+        match self {
+            // these can't be raw identifiers
+            Language::Rust if ["self", "Self", "super", "crate"].contains(&name) => format!("{}_", name),
+            Language::Rust => format!("r#{}", name),
+            Language::Python => format!("{}_", name),
+            Language::Swift | Language::Kotlin => format!("`{}`", name),
+            Language::TypeScript => format!("'{}'", name),
+            Language::CSharp => format!("@{}", name),
+            Language::Dart => unreachable!(),
         }
     }
 }
 
-pub fn escape_kotlin<T: std::fmt::Display>(s: T) -> ::askama::Result<String> {
-    let s = s.to_string();
-    if ["is", "in"].contains(&s.as_str()) {
-        Ok(format!("`{}`", s.as_str()))
-    } else {
-        Ok(s)
+fn escape_dart_identifier(name: &str) -> String {
+    if name.starts_with("_") {
+        return name.replace("_", "$");
+    }
+    match name {
+        "AND" => "$and".to_owned(),
+        "OR" => "$or".to_owned(),
+        "NOT" => "$not".to_owned(),
+        "default" => "defaultMode".to_owned(),
+        _ => if Language::Dart.is_reserved(name) {
+            format!("{}Value", name)
+        } else {
+            name.to_owned()
+        },
     }
 }
 
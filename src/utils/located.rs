@@ -0,0 +1,15 @@
+use std::fmt::Display;
+use teo_result::{Error, Result};
+
+/// Prefix an error with the schema item it was raised for, like
+/// "field `posts' of interface `User': ...", so it can be fixed without
+/// reading generator code.
+pub(crate) trait Located<T> {
+    fn located<L: Display>(self, location: impl FnOnce() -> L) -> Result<T>;
+}
+
+impl<T> Located<T> for Result<T> {
+    fn located<L: Display>(self, location: impl FnOnce() -> L) -> Result<T> {
+        self.map_err(|e| Error::new(format!("{}: {}", location(), e)))
+    }
+}
@@ -1,9 +1,15 @@
 pub(crate) mod file;
-pub(crate) mod filters;
+pub mod filters;
 pub(crate) mod message;
 pub(crate) mod lookup;
 pub(crate) mod exts;
 pub(crate) mod shape_reference_lookup;
 pub(crate) mod enum_reference_lookup;
 pub(crate) mod declared_shape_lookup;
-pub(crate) mod update_package_json_version;
\ No newline at end of file
+pub(crate) mod update_package_json_version;
+pub(crate) mod unions;
+pub(crate) mod tuples;
+pub mod templates;
+pub(crate) mod located;
+pub(crate) mod version;
+pub(crate) mod deprecation;
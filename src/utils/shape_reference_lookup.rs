@@ -1,6 +1,6 @@
 use inflector::Inflector;
 use teo_parser::r#type::synthesized_shape_reference::{SynthesizedShapeReference, SynthesizedShapeReferenceKind};
-use teo_result::Result;
+use teo_result::{Error, Result};
 use crate::outline::outline::Mode;
 
 pub(crate) fn shape_reference_lookup(
@@ -8,68 +8,76 @@ pub(crate) fn shape_reference_lookup(
     path_separator: &str,
     mode: Mode,
 ) -> Result<String> {
+    let owner = || -> Result<String> {
+        let reference = shape_reference.owner.as_model_object().ok_or_else(|| Error::new(format!("synthesized shape `{}' requires a model, found `{}'", shape_reference.kind, shape_reference.owner)))?;
+        Ok(reference.string_path().join(path_separator))
+    };
+    let without = || -> Result<String> {
+        let without = shape_reference.without.as_ref().ok_or_else(|| Error::new(format!("synthesized shape `{}' of `{}' requires a relation name", shape_reference.kind, shape_reference.owner)))?;
+        Ok(without.to_pascal_case())
+    };
     Ok(match shape_reference.kind {
-        SynthesizedShapeReferenceKind::Args => format!("{}Args", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::FindManyArgs => format!("{}FindManyArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::FindFirstArgs => format!("{}FindFirstArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::FindUniqueArgs => format!("{}FindUniqueArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::CreateArgs => format!("{}CreateArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::UpdateArgs => format!("{}UpdateArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::UpsertArgs => format!("{}UpsertArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::CopyArgs => format!("{}CopyArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::DeleteArgs => format!("{}DeleteArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::CreateManyArgs => format!("{}CreateManyArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::UpdateManyArgs => format!("{}UpdateManyArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::CopyManyArgs => format!("{}CopyManyArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::DeleteManyArgs => format!("{}DeleteManyArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::CountArgs => format!("{}CountArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::AggregateArgs => format!("{}AggregateArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::GroupByArgs => format!("{}GroupByArgs", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::RelationFilter => format!("{}RelationFilter", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::ListRelationFilter => format!("{}ListRelationFilter", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::WhereInput => format!("{}WhereInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::WhereUniqueInput => format!("{}WhereUniqueInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::ScalarWhereWithAggregatesInput => format!("{}ScalarWhereWithAggregatesInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::CountAggregateInputType => format!("{}CountAggregateInputType", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::SumAggregateInputType => format!("{}SumAggregateInputType", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::AvgAggregateInputType => format!("{}AvgAggregateInputType", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::MaxAggregateInputType => format!("{}MaxAggregateInputType", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::MinAggregateInputType => format!("{}MinAggregateInputType", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::CreateInput => format!("{}CreateInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::CreateInputWithout => format!("{}CreateWithout{}Input", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator), shape_reference.without.as_ref().unwrap().to_pascal_case()),
-        SynthesizedShapeReferenceKind::CreateNestedOneInput => format!("{}CreateNestedOneInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::CreateNestedOneInputWithout => format!("{}CreateNestedOneWithout{}Input", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator), shape_reference.without.as_ref().unwrap().to_pascal_case()),
-        SynthesizedShapeReferenceKind::CreateNestedManyInput => format!("{}CreateNestedManyInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::CreateNestedManyInputWithout => format!("{}CreateNestedManyWithout{}Input", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator), shape_reference.without.as_ref().unwrap().to_pascal_case()),
-        SynthesizedShapeReferenceKind::UpdateInput => format!("{}UpdateInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::UpdateInputWithout => format!("{}UpdateWithout{}Input", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator), shape_reference.without.as_ref().unwrap().to_pascal_case()),
-        SynthesizedShapeReferenceKind::UpdateNestedOneInput => format!("{}UpdateNestedOneInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::UpdateNestedOneInputWithout => format!("{}UpdateNestedOneWithout{}Input", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator), shape_reference.without.as_ref().unwrap().to_pascal_case()),
-        SynthesizedShapeReferenceKind::UpdateNestedManyInput => format!("{}UpdateNestedManyInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::UpdateNestedManyInputWithout => format!("{}UpdateNestedManyWithout{}Input", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator), shape_reference.without.as_ref().unwrap().to_pascal_case()),
-        SynthesizedShapeReferenceKind::ConnectOrCreateInput => format!("{}ConnectOrCreateInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::ConnectOrCreateInputWithout => format!("{}ConnectOrCreateWithout{}Input", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator), shape_reference.without.as_ref().unwrap().to_pascal_case()),
-        SynthesizedShapeReferenceKind::UpdateWithWhereUniqueInput => format!("{}UpdateWithWhereUniqueInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::UpdateWithWhereUniqueInputWithout => format!("{}UpdateWithWhereUniqueWithout{}Input", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator), shape_reference.without.as_ref().unwrap().to_pascal_case()),
-        SynthesizedShapeReferenceKind::UpsertWithWhereUniqueInput => format!("{}UpsertWithWhereUniqueInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::UpsertWithWhereUniqueInputWithout => format!("{}UpsertWithWhereUniqueWithout{}Input", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator), shape_reference.without.as_ref().unwrap().to_pascal_case()),
-        SynthesizedShapeReferenceKind::UpdateManyWithWhereInput => format!("{}UpdateManyWithWhereInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::UpdateManyWithWhereInputWithout => format!("{}UpdateManyWithWhereWithout{}Input", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator), shape_reference.without.as_ref().unwrap().to_pascal_case()),
-        SynthesizedShapeReferenceKind::Select => format!("{}Select", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::Include => format!("{}Include", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::OrderByInput => format!("{}OrderByInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
+        SynthesizedShapeReferenceKind::Args => format!("{}Args", owner()?),
+        SynthesizedShapeReferenceKind::FindManyArgs => format!("{}FindManyArgs", owner()?),
+        SynthesizedShapeReferenceKind::FindFirstArgs => format!("{}FindFirstArgs", owner()?),
+        SynthesizedShapeReferenceKind::FindUniqueArgs => format!("{}FindUniqueArgs", owner()?),
+        SynthesizedShapeReferenceKind::CreateArgs => format!("{}CreateArgs", owner()?),
+        SynthesizedShapeReferenceKind::UpdateArgs => format!("{}UpdateArgs", owner()?),
+        SynthesizedShapeReferenceKind::UpsertArgs => format!("{}UpsertArgs", owner()?),
+        SynthesizedShapeReferenceKind::CopyArgs => format!("{}CopyArgs", owner()?),
+        SynthesizedShapeReferenceKind::DeleteArgs => format!("{}DeleteArgs", owner()?),
+        SynthesizedShapeReferenceKind::CreateManyArgs => format!("{}CreateManyArgs", owner()?),
+        SynthesizedShapeReferenceKind::UpdateManyArgs => format!("{}UpdateManyArgs", owner()?),
+        SynthesizedShapeReferenceKind::CopyManyArgs => format!("{}CopyManyArgs", owner()?),
+        SynthesizedShapeReferenceKind::DeleteManyArgs => format!("{}DeleteManyArgs", owner()?),
+        SynthesizedShapeReferenceKind::CountArgs => format!("{}CountArgs", owner()?),
+        SynthesizedShapeReferenceKind::AggregateArgs => format!("{}AggregateArgs", owner()?),
+        SynthesizedShapeReferenceKind::GroupByArgs => format!("{}GroupByArgs", owner()?),
+        SynthesizedShapeReferenceKind::RelationFilter => format!("{}RelationFilter", owner()?),
+        SynthesizedShapeReferenceKind::ListRelationFilter => format!("{}ListRelationFilter", owner()?),
+        SynthesizedShapeReferenceKind::WhereInput => format!("{}WhereInput", owner()?),
+        SynthesizedShapeReferenceKind::WhereUniqueInput => format!("{}WhereUniqueInput", owner()?),
+        SynthesizedShapeReferenceKind::ScalarWhereWithAggregatesInput => format!("{}ScalarWhereWithAggregatesInput", owner()?),
+        SynthesizedShapeReferenceKind::CountAggregateInputType => format!("{}CountAggregateInputType", owner()?),
+        SynthesizedShapeReferenceKind::SumAggregateInputType => format!("{}SumAggregateInputType", owner()?),
+        SynthesizedShapeReferenceKind::AvgAggregateInputType => format!("{}AvgAggregateInputType", owner()?),
+        SynthesizedShapeReferenceKind::MaxAggregateInputType => format!("{}MaxAggregateInputType", owner()?),
+        SynthesizedShapeReferenceKind::MinAggregateInputType => format!("{}MinAggregateInputType", owner()?),
+        SynthesizedShapeReferenceKind::CreateInput => format!("{}CreateInput", owner()?),
+        SynthesizedShapeReferenceKind::CreateInputWithout => format!("{}CreateWithout{}Input", owner()?, without()?),
+        SynthesizedShapeReferenceKind::CreateNestedOneInput => format!("{}CreateNestedOneInput", owner()?),
+        SynthesizedShapeReferenceKind::CreateNestedOneInputWithout => format!("{}CreateNestedOneWithout{}Input", owner()?, without()?),
+        SynthesizedShapeReferenceKind::CreateNestedManyInput => format!("{}CreateNestedManyInput", owner()?),
+        SynthesizedShapeReferenceKind::CreateNestedManyInputWithout => format!("{}CreateNestedManyWithout{}Input", owner()?, without()?),
+        SynthesizedShapeReferenceKind::UpdateInput => format!("{}UpdateInput", owner()?),
+        SynthesizedShapeReferenceKind::UpdateInputWithout => format!("{}UpdateWithout{}Input", owner()?, without()?),
+        SynthesizedShapeReferenceKind::UpdateNestedOneInput => format!("{}UpdateNestedOneInput", owner()?),
+        SynthesizedShapeReferenceKind::UpdateNestedOneInputWithout => format!("{}UpdateNestedOneWithout{}Input", owner()?, without()?),
+        SynthesizedShapeReferenceKind::UpdateNestedManyInput => format!("{}UpdateNestedManyInput", owner()?),
+        SynthesizedShapeReferenceKind::UpdateNestedManyInputWithout => format!("{}UpdateNestedManyWithout{}Input", owner()?, without()?),
+        SynthesizedShapeReferenceKind::ConnectOrCreateInput => format!("{}ConnectOrCreateInput", owner()?),
+        SynthesizedShapeReferenceKind::ConnectOrCreateInputWithout => format!("{}ConnectOrCreateWithout{}Input", owner()?, without()?),
+        SynthesizedShapeReferenceKind::UpdateWithWhereUniqueInput => format!("{}UpdateWithWhereUniqueInput", owner()?),
+        SynthesizedShapeReferenceKind::UpdateWithWhereUniqueInputWithout => format!("{}UpdateWithWhereUniqueWithout{}Input", owner()?, without()?),
+        SynthesizedShapeReferenceKind::UpsertWithWhereUniqueInput => format!("{}UpsertWithWhereUniqueInput", owner()?),
+        SynthesizedShapeReferenceKind::UpsertWithWhereUniqueInputWithout => format!("{}UpsertWithWhereUniqueWithout{}Input", owner()?, without()?),
+        SynthesizedShapeReferenceKind::UpdateManyWithWhereInput => format!("{}UpdateManyWithWhereInput", owner()?),
+        SynthesizedShapeReferenceKind::UpdateManyWithWhereInputWithout => format!("{}UpdateManyWithWhereWithout{}Input", owner()?, without()?),
+        SynthesizedShapeReferenceKind::Select => format!("{}Select", owner()?),
+        SynthesizedShapeReferenceKind::Include => format!("{}Include", owner()?),
+        SynthesizedShapeReferenceKind::OrderByInput => format!("{}OrderByInput", owner()?),
         SynthesizedShapeReferenceKind::Result => if mode == Mode::Client {
-            shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)
+            owner()?
         } else {
-            format!("{}Result", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator))
+            format!("{}Result", owner()?)
         },
-        SynthesizedShapeReferenceKind::CountAggregateResult => format!("{}CountAggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::SumAggregateResult => format!("{}SumAggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::AvgAggregateResult => format!("{}AvgAggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::MinAggregateResult => format!("{}MinAggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::MaxAggregateResult => format!("{}MaxAggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::AggregateResult => format!("{}AggregateResult", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::GroupByResult => format!("{}GroupByResult", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
-        SynthesizedShapeReferenceKind::ScalarUpdateInput => format!("{}ScalarUpdateInput", shape_reference.owner.as_model_object().unwrap().string_path().join(path_separator)),
+        SynthesizedShapeReferenceKind::CountAggregateResult => format!("{}CountAggregateResult", owner()?),
+        SynthesizedShapeReferenceKind::SumAggregateResult => format!("{}SumAggregateResult", owner()?),
+        SynthesizedShapeReferenceKind::AvgAggregateResult => format!("{}AvgAggregateResult", owner()?),
+        SynthesizedShapeReferenceKind::MinAggregateResult => format!("{}MinAggregateResult", owner()?),
+        SynthesizedShapeReferenceKind::MaxAggregateResult => format!("{}MaxAggregateResult", owner()?),
+        SynthesizedShapeReferenceKind::AggregateResult => format!("{}AggregateResult", owner()?),
+        SynthesizedShapeReferenceKind::GroupByResult => format!("{}GroupByResult", owner()?),
+        SynthesizedShapeReferenceKind::ScalarUpdateInput => format!("{}ScalarUpdateInput", owner()?),
     })
 }
\ No newline at end of file
@@ -0,0 +1,132 @@
+use std::path::Path;
+use askama::Template;
+use minijinja::{Environment, ErrorKind, path_loader};
+use serde_json::{json, Value};
+use teo_result::{Error, Result};
+use teo_runtime::config::client::Client;
+use teo_runtime::config::entity::Entity;
+use teo_runtime::namespace::Namespace;
+use teo_runtime::traits::named::Named;
+use crate::outline::json::outline_document;
+use crate::outline::outline::Mode;
+use crate::utils::exts::ClientExt;
+use crate::utils::located::Located;
+
+/// # Template overrides
+///
+/// Generated files come from the built-in templates. When a template
+/// directory is configured, a template found there at the same path as a
+/// built-in one, like `client/swift/teo.swift.jinja` or
+/// `shared/ts/namespace.partial.jinja`, is rendered with minijinja instead.
+///
+/// Override templates receive `conf`, the schema's
+/// [`outline_document`](crate::outline::outline_document) as `outline`,
+/// `namespace` with its own entry of that document as `namespace.outline`
+/// for templates rendered per namespace, and the built-in output as
+/// `builtin`, so an override which only adds a license header is
+/// `{{ header }}{{ builtin }}`.
+///
+/// Registered generators render through
+/// [`Ctx::templates`](crate::client::Ctx::templates) too, so their
+/// templates can be overridden the same way.
+#[derive(Clone)]
+pub struct TemplateOverrides {
+    env: Option<Environment<'static>>,
+    context: Value,
+}
+
+impl TemplateOverrides {
+
+    pub(crate) fn new(dir: Option<&Path>, context: Value) -> Self {
+        Self {
+            env: dir.map(|dir| {
+                let mut env = Environment::new();
+                env.set_loader(path_loader(dir));
+                env.set_keep_trailing_newline(true);
+                env
+            }),
+            context,
+        }
+    }
+
+    pub(crate) fn for_client(dir: Option<&Path>, conf: &Client, main_namespace: &Namespace) -> Result<Self> {
+        Ok(Self::new(dir, json!({
+            "conf": {
+                "object_name": conf.object_name,
+                "class_name": conf.class_name(),
+                "package_name": conf.inferred_package_name(),
+                "dest": conf.dest,
+                "package": conf.package,
+                "host": conf.host.to_host_string(),
+            },
+            "outline": override_outline(dir, main_namespace, Mode::Client)?,
+        })))
+    }
+
+    pub(crate) fn for_entity(dir: Option<&Path>, conf: &Entity, main_namespace: &Namespace) -> Result<Self> {
+        Ok(Self::new(dir, json!({
+            "conf": {
+                "dest": conf.dest,
+            },
+            "outline": override_outline(dir, main_namespace, Mode::Entity)?,
+        })))
+    }
+
+    pub fn render<T: Template>(&self, path: &str, template: &T) -> Result<String> {
+        self.render_with(path, template, None)
+    }
+
+    pub fn render_for_namespace<T: Template>(&self, path: &str, template: &T, namespace: &Namespace) -> Result<String> {
+        self.render_with(path, template, Some(namespace))
+    }
+
+    /// Render a built-in file which isn't a template, like a runtime helper.
+    pub fn render_static(&self, path: &str, content: &str) -> Result<String> {
+        self.render_override(path, content.to_owned(), None)
+    }
+
+    fn render_with<T: Template>(&self, path: &str, template: &T, namespace: Option<&Namespace>) -> Result<String> {
+        let builtin = match namespace {
+            Some(namespace) if !namespace.is_main() => render_builtin(path, template).located(|| format!("namespace `{}'", namespace.path().join(".")))?,
+            _ => render_builtin(path, template)?,
+        };
+        self.render_override(path, builtin, namespace)
+    }
+
+    fn render_override(&self, path: &str, builtin: String, namespace: Option<&Namespace>) -> Result<String> {
+        let Some(env) = &self.env else {
+            return Ok(builtin);
+        };
+        let template = match env.get_template(path) {
+            Ok(template) => template,
+            Err(e) if e.kind() == ErrorKind::TemplateNotFound => return Ok(builtin),
+            Err(e) => return Err(Error::new(format!("cannot load template override `{}': {}", path, e))),
+        };
+        let mut context = self.context.clone();
+        if let Some(namespace) = namespace {
+            let outline = self.context["outline"]["namespaces"].as_array().and_then(|namespaces| {
+                namespaces.iter().find(|n| n["path"] == json!(namespace.path())).cloned()
+            });
+            context["namespace"] = json!({
+                "path": namespace.path(),
+                "name": namespace.name(),
+                "is_main": namespace.is_main(),
+                "outline": outline,
+            });
+        }
+        context["builtin"] = Value::String(builtin);
+        template.render(context).map_err(|e| Error::new(format!("cannot render template override `{}': {}", path, e)))
+    }
+}
+
+/// The outline document of the schema, only built when there are overrides
+/// to render it in.
+fn override_outline(dir: Option<&Path>, main_namespace: &Namespace, mode: Mode) -> Result<Value> {
+    Ok(if dir.is_some() { outline_document(main_namespace, mode)? } else { Value::Null })
+}
+
+/// Render a built-in template which can't be overridden, `path` names it in
+/// the error.
+pub(crate) fn render_builtin<T: Template>(path: &str, template: &T) -> Result<String> {
+    template.render().map_err(|e| Error::new(format!("cannot render `{}': {}", path, e)))
+}
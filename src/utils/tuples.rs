@@ -0,0 +1,18 @@
+use teo_result::{Error, Result};
+
+/// Client runtimes ship fixed-arity tuple classes, from `TeoTuple1` to this arity.
+pub(crate) const MAX_TUPLE_ARITY: usize = 8;
+
+/// The name of the runtime tuple class for a tuple with `arity` items.
+pub(crate) fn tuple_class_name(arity: usize) -> Result<String> {
+    if arity == 0 || arity > MAX_TUPLE_ARITY {
+        Err(Error::new(format!("tuples with {} items are not supported, the maximum is {}", arity, MAX_TUPLE_ARITY)))
+    } else {
+        Ok(format!("TeoTuple{}", arity))
+    }
+}
+
+/// The item indices of every runtime tuple class, for rendering them in templates.
+pub(crate) fn tuple_class_indices() -> Vec<Vec<usize>> {
+    (1..=MAX_TUPLE_ARITY).map(|arity| (0..arity).collect()).collect()
+}
@@ -0,0 +1,185 @@
+use inflector::Inflector;
+use itertools::Itertools;
+use teo_parser::r#type::Type;
+use crate::outline::outline::{Mode, Outline};
+use crate::utils::shape_reference_lookup::shape_reference_lookup;
+use crate::utils::enum_reference_lookup::enum_reference_lookup;
+
+/// # Union definition
+///
+/// A named union type collected from a generated file. Languages without
+/// anonymous union types render one sum type per definition.
+///
+/// Teo serializes a union value as the plain TEON value of its member, without
+/// any tag. Decoders discriminate members by content, trying them in
+/// [`UnionDefinition::members_in_check_order`].
+pub(crate) struct UnionDefinition {
+    name: String,
+    members: Vec<UnionMember>,
+}
+
+impl UnionDefinition {
+
+    pub(crate) fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub(crate) fn members(&self) -> &Vec<UnionMember> {
+        &self.members
+    }
+
+    /// Members in decoding order. TEON tagged objects and primitives are
+    /// unambiguous, so they are tried before strings, lists and objects.
+    pub(crate) fn members_in_check_order(&self) -> Vec<&UnionMember> {
+        self.members.iter().sorted_by_key(|m| check_order(m.r#type())).collect()
+    }
+}
+
+pub(crate) struct UnionMember {
+    name: String,
+    r#type: Type,
+}
+
+impl UnionMember {
+
+    pub(crate) fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub(crate) fn r#type(&self) -> &Type {
+        &self.r#type
+    }
+}
+
+/// Returns the members of a union without `null`, and whether `null` is a member.
+pub(crate) fn union_members_without_null(types: &Vec<Type>) -> (Vec<&Type>, bool) {
+    let mut has_null = false;
+    let mut members = vec![];
+    for t in types {
+        if t.is_null() {
+            has_null = true;
+        } else if let Type::Optional(inner) = t {
+            has_null = true;
+            members.push(inner.as_ref());
+        } else {
+            members.push(t);
+        }
+    }
+    (members, has_null)
+}
+
+/// Whether this union needs a named sum type, or is just an optional of a single type.
+pub(crate) fn union_is_sum_type(types: &Vec<Type>) -> bool {
+    union_members_without_null(types).0.len() > 1
+}
+
+/// The language independent name of a union, e.g. `StringOrIntArray`.
+pub(crate) fn union_name(types: &Vec<Type>) -> String {
+    union_members_without_null(types).0.iter().map(|t| union_member_name(t)).collect::<Vec<String>>().join("Or")
+}
+
+pub(crate) fn union_member_name(t: &Type) -> String {
+    match t {
+        Type::Any => "Any".to_owned(),
+        Type::Null => "Null".to_owned(),
+        Type::Bool => "Bool".to_owned(),
+        Type::Int => "Int".to_owned(),
+        Type::Int64 => "Int64".to_owned(),
+        Type::Float32 => "Float32".to_owned(),
+        Type::Float => "Float".to_owned(),
+        Type::Decimal => "Decimal".to_owned(),
+        Type::String => "String".to_owned(),
+        Type::ObjectId => "ObjectId".to_owned(),
+        Type::Date => "Date".to_owned(),
+        Type::DateTime => "DateTime".to_owned(),
+        Type::File => "File".to_owned(),
+        Type::Optional(inner) => union_member_name(inner.as_ref()),
+        Type::Array(inner) => union_member_name(inner.as_ref()) + "Array",
+        Type::Enumerable(inner) => union_member_name(inner.as_ref()) + "Enumerable",
+        Type::Dictionary(inner) => union_member_name(inner.as_ref()) + "Dictionary",
+        Type::Tuple(types) => types.iter().map(|t| union_member_name(t)).collect::<Vec<String>>().join("And") + "Tuple",
+        Type::Range(inner) => union_member_name(inner.as_ref()) + "Range",
+        Type::Union(types) => union_name(types),
+        Type::EnumVariant(reference) => qualified_name(reference.string_path()),
+        Type::ModelObject(reference) => qualified_name(reference.string_path()),
+        Type::InterfaceObject(reference, types) => qualified_name(reference.string_path()) + &types.iter().map(|t| union_member_name(t)).collect::<Vec<String>>().join(""),
+        Type::GenericItem(name) => name.to_pascal_case(),
+        Type::SynthesizedShapeReference(shape_reference) => qualified_name(&split_path(shape_reference_lookup(shape_reference, ".", Mode::Client).unwrap_or_default())),
+        Type::SynthesizedEnumReference(enum_reference) => qualified_name(&split_path(enum_reference_lookup(enum_reference, ".").unwrap_or_default())),
+        Type::DeclaredSynthesizedShape(reference, _) => qualified_name(reference.string_path()),
+        _ => "Value".to_owned(),
+    }
+}
+
+/// Collect every union which requires a named sum type in `t`, deduplicated by name.
+pub(crate) fn collect_unions(t: &Type, unions: &mut Vec<UnionDefinition>) {
+    match t {
+        Type::Optional(inner) | Type::Array(inner) | Type::Enumerable(inner) | Type::Dictionary(inner) | Type::Range(inner) => collect_unions(inner.as_ref(), unions),
+        Type::Tuple(types) | Type::InterfaceObject(_, types) => for t in types {
+            collect_unions(t, unions);
+        },
+        Type::Union(types) => {
+            for t in types {
+                collect_unions(t, unions);
+            }
+            if union_is_sum_type(types) {
+                let name = union_name(types);
+                if unions.iter().find(|u| u.name == name).is_none() {
+                    let mut members: Vec<UnionMember> = vec![];
+                    for member in union_members_without_null(types).0 {
+                        let mut member_name = union_member_name(member);
+                        if members.iter().find(|m| m.name == member_name).is_some() {
+                            member_name = format!("{}{}", member_name, members.len());
+                        }
+                        members.push(UnionMember { name: member_name, r#type: member.clone() });
+                    }
+                    unions.push(UnionDefinition { name, members });
+                }
+            }
+        },
+        _ => (),
+    }
+}
+
+/// Collect unions used by interfaces and request items of an outline.
+pub(crate) fn collect_outline_unions(outline: &Outline) -> Vec<UnionDefinition> {
+    let mut unions = vec![];
+    for interface in outline.interfaces() {
+        for field in interface.fields() {
+            collect_unions(field.r#type(), &mut unions);
+        }
+    }
+    for delegate in outline.delegates() {
+        for request_item in delegate.request_items() {
+            collect_unions(request_item.input_type(), &mut unions);
+            collect_unions(request_item.output_type(), &mut unions);
+        }
+    }
+    unions
+}
+
+fn check_order(t: &Type) -> u8 {
+    match t {
+        Type::Date | Type::DateTime | Type::Decimal => 0,
+        Type::Bool | Type::Int | Type::Int64 => 1,
+        Type::Float | Type::Float32 => 2,
+        Type::EnumVariant(_) => 3,
+        Type::String | Type::ObjectId => 4,
+        Type::Array(_) | Type::Enumerable(_) | Type::Tuple(_) => 5,
+        Type::Range(_) => 6,
+        Type::ModelObject(_) | Type::InterfaceObject(_, _) | Type::SynthesizedShapeReference(_) | Type::DeclaredSynthesizedShape(_, _) => 6,
+        Type::Dictionary(_) => 7,
+        _ => 8,
+    }
+}
+
+/// Every component of a reference's path is part of the name, so `admin.User`
+/// and `User` don't collide. Generators which make paths relative to the
+/// current namespace get unqualified names for its own types.
+fn qualified_name(path: &Vec<String>) -> String {
+    path.iter().map(|component| component.to_pascal_case()).collect::<Vec<String>>().join("")
+}
+
+fn split_path(path: String) -> Vec<String> {
+    path.split(".").map(|component| component.to_owned()).collect()
+}
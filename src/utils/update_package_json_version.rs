@@ -1,26 +1,35 @@
-use serde_json::Value;
+use std::path::Path;
+use serde_json::{Map, Value};
+use teo_result::{Error, Result};
+use crate::outline::changes::Bump;
+use crate::utils::version::bump_version;
 
-pub(crate) fn update_package_json_version(content: String) -> String {
-    let mut json_value: Value = serde_json::from_str(&content).unwrap();
+/// The `content` of the package.json at `path`, which must be an object.
+pub(crate) fn parse_package_json(content: &str, path: &Path) -> Result<Map<String, Value>> {
+    match serde_json::from_str(content) {
+        Ok(Value::Object(object)) => Ok(object),
+        Ok(_) => Err(Error::new(format!("`{}' isn't a JSON object", path.display()))),
+        Err(e) => Err(Error::new(format!("cannot parse `{}': {}", path.display(), e))),
+    }
+}
+
+pub(crate) fn update_package_json_version(content: String, bump: Bump, path: &Path) -> Result<String> {
+    let mut json_value = parse_package_json(&content, path)?;
     let version = json_value.get("version");
     match version {
         Some(v) => {
-            let previous = v.as_str().unwrap();
-            let parts = previous.split(".");
-            let last = parts.clone().last().unwrap();
-            match last.parse::<u32>() {
-                Ok(num) => {
-                    let new_last = format!("{}", num + 1);
-                    let vec_parts: Vec<&str> = parts.into_iter().collect();
-                    let new_version = vec_parts.split_last().unwrap().1.join(".") + "." + &new_last;
-                    json_value.as_object_mut().unwrap().insert("version".to_owned(), Value::String(new_version));
-                },
-                Err(_) => (),
+            if let Some(new_version) = v.as_str().and_then(|previous| bump_version(previous, bump)) {
+                json_value.insert("version".to_owned(), Value::String(new_version));
             }
         },
         None => {
-            json_value.as_object_mut().unwrap().insert("version".to_owned(), Value::String("0.1.1".to_owned()));
+            json_value.insert("version".to_owned(), Value::String("0.1.1".to_owned()));
         },
     }
-    serde_json::to_string_pretty(&json_value).unwrap() + "\n"
+    Ok(serde_json::to_string_pretty(&json_value).unwrap() + "\n")
+}
+
+pub(crate) fn package_json_version(content: &str) -> Option<String> {
+    let json_value: Value = serde_json::from_str(content).ok()?;
+    json_value.get("version")?.as_str().map(ToOwned::to_owned)
 }
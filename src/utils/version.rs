@@ -0,0 +1,58 @@
+use crate::outline::changes::Bump;
+
+/// Bump a `major.minor.patch` version, dropping any pre-release or build
+/// suffix. Before 1.0.0 breaking changes bump the minor version and
+/// additions the patch version. `None` when `version` isn't numeric.
+pub(crate) fn bump_version(version: &str, bump: Bump) -> Option<String> {
+    let core = version.split(['-', '+']).next()?;
+    let mut parts = core.split(".").map(|p| p.parse::<u64>().ok()).collect::<Option<Vec<u64>>>()?;
+    if parts.is_empty() || parts.len() > 3 {
+        return None;
+    }
+    parts.resize(3, 0);
+    let bump = match (parts[0], bump) {
+        (0, Bump::Major) => Bump::Minor,
+        (0, Bump::Minor) => Bump::Patch,
+        (_, bump) => bump,
+    };
+    let (major, minor, patch) = (parts[0], parts[1], parts[2]);
+    Some(match bump {
+        Bump::Major => format!("{}.0.0", major + 1),
+        Bump::Minor => format!("{}.{}.0", major, minor + 1),
+        Bump::Patch => format!("{}.{}.{}", major, minor, patch + 1),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bumps_stable_versions() {
+        assert_eq!(bump_version("1.2.3", Bump::Major).as_deref(), Some("2.0.0"));
+        assert_eq!(bump_version("1.2.3", Bump::Minor).as_deref(), Some("1.3.0"));
+        assert_eq!(bump_version("1.2.3", Bump::Patch).as_deref(), Some("1.2.4"));
+    }
+
+    #[test]
+    fn bumps_initial_development_versions_one_place_lower() {
+        assert_eq!(bump_version("0.3.1", Bump::Major).as_deref(), Some("0.4.0"));
+        assert_eq!(bump_version("0.3.1", Bump::Minor).as_deref(), Some("0.3.2"));
+        assert_eq!(bump_version("0.3.1", Bump::Patch).as_deref(), Some("0.3.2"));
+    }
+
+    #[test]
+    fn completes_short_versions_and_drops_suffixes() {
+        assert_eq!(bump_version("1.2", Bump::Patch).as_deref(), Some("1.2.1"));
+        assert_eq!(bump_version("2", Bump::Minor).as_deref(), Some("2.1.0"));
+        assert_eq!(bump_version("1.2.3-beta.1", Bump::Patch).as_deref(), Some("1.2.4"));
+        assert_eq!(bump_version("1.2.3+build.5", Bump::Major).as_deref(), Some("2.0.0"));
+    }
+
+    #[test]
+    fn rejects_non_numeric_versions() {
+        assert_eq!(bump_version("latest", Bump::Patch), None);
+        assert_eq!(bump_version("1.2.3.4", Bump::Patch), None);
+        assert_eq!(bump_version("", Bump::Patch), None);
+    }
+}
@@ -0,0 +1,98 @@
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use teo_result::{Error, Result};
+use teo_runtime::namespace::Namespace;
+use tokio::sync::mpsc::unbounded_channel;
+use tokio::time::timeout;
+use crate::generate::{generate_all, GenerateOptions};
+use crate::utils::message::{green_message, red_message, yellow_message};
+
+/// # Watch options
+#[derive(Clone)]
+pub struct WatchOptions {
+    /// Schema files, and directories whose `.teo` files are watched
+    /// recursively.
+    pub paths: Vec<PathBuf>,
+    /// How long the schema has to stay unchanged before it's reloaded, so
+    /// saving several files at once regenerates once.
+    pub debounce: Duration,
+    pub generate: GenerateOptions,
+}
+
+impl Default for WatchOptions {
+
+    fn default() -> Self {
+        Self {
+            paths: vec![],
+            debounce: Duration::from_millis(300),
+            generate: GenerateOptions::default(),
+        }
+    }
+}
+
+/// Generate the clients, entities and admin dashboard configured in the
+/// schema, then again whenever a schema file changes, until the process
+/// ends. `loader` builds the main namespace from the schema files.
+///
+/// Errors of loading and generating are reported and the previous output
+/// is kept, only errors of watching the files are returned. Files whose
+/// content didn't change aren't written again.
+pub async fn watch<L, F>(options: &WatchOptions, loader: L) -> Result<()> where L: Fn() -> F, F: Future<Output = Result<Namespace>> {
+    let (sender, mut receiver) = unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        if let Ok(event) = event {
+            if is_schema_change(&event) {
+                let _ = sender.send(());
+            }
+        }
+    }).map_err(|e| Error::new(format!("cannot watch the schema: {}", e)))?;
+    for path in &options.paths {
+        // editors replace files on save, so files are watched by their directory
+        let (target, mode) = if path.is_dir() {
+            (path.as_path(), RecursiveMode::Recursive)
+        } else {
+            (path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new(".")), RecursiveMode::NonRecursive)
+        };
+        watcher.watch(target, mode).map_err(|e| Error::new(format!("cannot watch `{}': {}", path.display(), e)))?;
+    }
+    regenerate(options, &loader).await;
+    while receiver.recv().await.is_some() {
+        loop {
+            match timeout(options.debounce, receiver.recv()).await {
+                Ok(Some(())) => continue,
+                Ok(None) => return Ok(()),
+                Err(_) => break,
+            }
+        }
+        yellow_message("change", "regenerating".to_owned());
+        regenerate(options, &loader).await;
+    }
+    Ok(())
+}
+
+fn is_schema_change(event: &Event) -> bool {
+    matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)) &&
+        event.paths.iter().any(|path| path.extension().is_some_and(|extension| extension == "teo"))
+}
+
+async fn regenerate<L, F>(options: &WatchOptions, loader: &L) where L: Fn() -> F, F: Future<Output = Result<Namespace>> {
+    let namespace = match loader().await {
+        Ok(namespace) => namespace,
+        Err(e) => {
+            red_message("error", format!("cannot load the schema: {}", e));
+            return;
+        }
+    };
+    let report = generate_all(Arc::new(namespace), &options.generate).await;
+    for target in report.failures() {
+        if let Err(e) = &target.result {
+            red_message("error", format!("{} `{}': {}", target.kind, target.name, e));
+        }
+    }
+    if report.succeeded() {
+        green_message("watch", "waiting for schema changes".to_owned());
+    }
+}
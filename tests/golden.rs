@@ -0,0 +1,290 @@
+//! Golden output tests.
+//!
+//! Every schema under `tests/schemas` is loaded into a `Namespace` and fed to
+//! each client and entity generator. The files written are compared with the
+//! ones checked in under `tests/golden/<schema>/<target>`.
+//!
+//! Run with `UPDATE_GOLDEN=1` to record the current output as the new golden
+//! files after an intended change, and review the diff before committing.
+//!
+//! The Dart and Kotlin clients run their toolchains after generating, so
+//! their cases are ignored unless run with `--include-ignored`.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use teo_generator::client::{self, ClientOptions, DocsOptions, TsInt64Strategy};
+use teo_generator::entity::{self, EntityOptions};
+use teo_generator::naming::{Case, Naming};
+use teo_runtime::app::data::AppData;
+use teo_runtime::app::entrance::Entrance;
+use teo_runtime::app::runtime_version::RuntimeVersion;
+use teo_runtime::config::client::{Client, ClientHost, ClientLanguage, TypeScriptHTTPProvider};
+use teo_runtime::config::entity::{Entity, Runtime};
+use teo_runtime::namespace::{self, Namespace};
+use teo_runtime::schema::load::load_schema::load_schema;
+
+const UPDATE_ENV: &str = "UPDATE_GOLDEN";
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+async fn load_namespace(schema: &str) -> Namespace {
+    let path = manifest_dir().join("tests/schemas").join(format!("{}.teo", schema));
+    let (schema, diagnostics) = teo_parser::parse(path.to_str().unwrap(), None, None);
+    if diagnostics.has_errors() {
+        panic!("`{}' has errors: {:?}", path.display(), diagnostics);
+    }
+    let builder = namespace::Builder::main(AppData::new(Entrance::APP, RuntimeVersion::Rust(env!("CARGO_PKG_VERSION"))));
+    teo_runtime::stdlib::load::load(&builder);
+    load_schema(&builder, &schema, true).await.unwrap();
+    builder.build()
+}
+
+/// A fresh output directory. Its last component is fixed since some
+/// generators derive package names from it.
+fn output_dir(schema: &str, target: &str) -> PathBuf {
+    let dir = env::temp_dir()
+        .join(format!("teo-generator-golden-{}", std::process::id()))
+        .join(schema)
+        .join(target)
+        .join("generated");
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    dir
+}
+
+fn read_tree(root: &Path) -> BTreeMap<String, String> {
+    let mut files = BTreeMap::new();
+    if root.exists() {
+        collect_files(root, root, &mut files);
+    }
+    files
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut BTreeMap<String, String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_files(root, &path, files);
+        } else {
+            let relative = path.strip_prefix(root).unwrap().components().map(|c| c.as_os_str().to_string_lossy().to_string()).collect::<Vec<String>>().join("/");
+            files.insert(relative, fs::read_to_string(&path).unwrap());
+        }
+    }
+}
+
+fn assert_golden(schema: &str, target: &str, output: &Path) {
+    let golden = manifest_dir().join("tests/golden").join(schema).join(target);
+    let actual = read_tree(output);
+    if env::var_os(UPDATE_ENV).is_some() {
+        if golden.exists() {
+            fs::remove_dir_all(&golden).unwrap();
+        }
+        for (relative, content) in &actual {
+            let path = golden.join(relative);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        return;
+    }
+    let expected = read_tree(&golden);
+    if expected.is_empty() {
+        panic!("no golden output at `{}', run with {}=1 to record it", golden.display(), UPDATE_ENV);
+    }
+    let mut problems = vec![];
+    for relative in expected.keys().filter(|k| !actual.contains_key(*k)) {
+        problems.push(format!("missing `{}'", relative));
+    }
+    for relative in actual.keys().filter(|k| !expected.contains_key(*k)) {
+        problems.push(format!("unexpected `{}'", relative));
+    }
+    for (relative, content) in &actual {
+        if let Some(expected_content) = expected.get(relative) {
+            if let Some(line) = first_difference(expected_content, content) {
+                problems.push(format!("`{}' differs at line {}:\n  expected: {}\n    actual: {}", relative, line.0, line.1, line.2));
+            }
+        }
+    }
+    if !problems.is_empty() {
+        panic!("{}/{} doesn't match its golden output, run with {}=1 to update it if this is intended:\n{}", schema, target, UPDATE_ENV, problems.join("\n"));
+    }
+}
+
+fn first_difference(expected: &str, actual: &str) -> Option<(usize, String, String)> {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut number = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => return None,
+            (e, a) if e != a => return Some((number, e.unwrap_or("<end of file>").to_owned(), a.unwrap_or("<end of file>").to_owned())),
+            _ => number += 1,
+        }
+    }
+}
+
+fn client_conf(provider: ClientLanguage, output: &Path) -> Client {
+    Client {
+        provider,
+        dest: output.to_str().unwrap().to_owned(),
+        package: false,
+        host: ClientHost::String("http://127.0.0.1:5050".to_owned()),
+        object_name: "teo".to_owned(),
+        git_commit: false,
+    }
+}
+
+async fn check_client(schema: &str, target: &str, provider: ClientLanguage) {
+    check_client_with_options(schema, target, provider, &ClientOptions::default()).await;
+}
+
+async fn check_client_with_options(schema: &str, target: &str, provider: ClientLanguage, options: &ClientOptions) {
+    let namespace = load_namespace(schema).await;
+    let output = output_dir(schema, target);
+    client::generate_with_options(&namespace, &client_conf(provider, &output), options).await.unwrap();
+    assert_golden(schema, target, &output);
+}
+
+async fn check_client_generator(schema: &str, target: &str, name: &str) {
+    check_client_generator_with_options(schema, target, name, &ClientOptions::default()).await;
+}
+
+async fn check_client_generator_with_options(schema: &str, target: &str, name: &str, options: &ClientOptions) {
+    let namespace = load_namespace(schema).await;
+    let output = output_dir(schema, target);
+    let conf = client_conf(ClientLanguage::TypeScript(TypeScriptHTTPProvider::Fetch), &output);
+    client::generate_with_generator(&namespace, name, &conf, options).await.unwrap();
+    assert_golden(schema, target, &output);
+}
+
+/// Runs the entity generator for `provider`, or the Go one when it's `None`
+/// since the entity config has no Go runtime.
+async fn check_entity(schema: &str, target: &str, provider: Option<Runtime>) {
+    let namespace = load_namespace(schema).await;
+    let output = output_dir(schema, target);
+    let go = provider.is_none();
+    let entity = Entity {
+        provider: provider.unwrap_or(Runtime::Rust),
+        dest: output.to_str().unwrap().to_owned(),
+    };
+    if go {
+        entity::generate_go(&namespace, &entity, &EntityOptions::default()).await.unwrap();
+    } else {
+        entity::generate_with_options(&namespace, &entity, &EntityOptions::default()).await.unwrap();
+    }
+    assert_golden(schema, target, &output);
+}
+
+// The C# client generator isn't implemented yet, so it has no golden tests.
+macro_rules! golden_tests {
+    ($schema:ident) => {
+        mod $schema {
+            use super::*;
+
+            #[tokio::test]
+            async fn client_ts() {
+                check_client(stringify!($schema), "client_ts", ClientLanguage::TypeScript(TypeScriptHTTPProvider::Fetch)).await;
+            }
+
+            #[tokio::test]
+            async fn client_ts_bigint() {
+                let mut options = ClientOptions::default();
+                options.ts.int64 = TsInt64Strategy::BigInt;
+                check_client_with_options(stringify!($schema), "client_ts_bigint", ClientLanguage::TypeScript(TypeScriptHTTPProvider::Fetch), &options).await;
+            }
+
+            #[tokio::test]
+            async fn client_swift() {
+                check_client(stringify!($schema), "client_swift", ClientLanguage::Swift).await;
+            }
+
+            #[tokio::test]
+            #[ignore = "runs `gradle init`"]
+            async fn client_kotlin() {
+                check_client(stringify!($schema), "client_kotlin", ClientLanguage::Kotlin).await;
+            }
+
+            #[tokio::test]
+            #[ignore = "runs `dart pub get` and `build_runner`"]
+            async fn client_dart() {
+                check_client(stringify!($schema), "client_dart", ClientLanguage::Dart).await;
+            }
+
+            #[tokio::test]
+            async fn client_ir() {
+                check_client_generator(stringify!($schema), "client_ir", "ir").await;
+            }
+
+            #[tokio::test]
+            async fn client_docs() {
+                check_client_generator(stringify!($schema), "client_docs", "docs").await;
+            }
+
+            #[tokio::test]
+            async fn client_docs_snippets() {
+                let mut options = ClientOptions::default();
+                options.docs = DocsOptions {
+                    languages: vec![ClientLanguage::TypeScript(TypeScriptHTTPProvider::Fetch), ClientLanguage::Swift, ClientLanguage::Dart],
+                    ..DocsOptions::default()
+                };
+                options.naming.insert("swift".to_owned(), Naming { methods: Case::Snake, properties: Case::Snake, ..Naming::default() });
+                check_client_generator_with_options(stringify!($schema), "client_docs_snippets", "docs", &options).await;
+            }
+
+            #[tokio::test]
+            async fn entity_rust() {
+                check_entity(stringify!($schema), "entity_rust", Some(Runtime::Rust)).await;
+            }
+
+            #[tokio::test]
+            async fn entity_node() {
+                check_entity(stringify!($schema), "entity_node", Some(Runtime::Node)).await;
+            }
+
+            #[tokio::test]
+            async fn entity_python() {
+                check_entity(stringify!($schema), "entity_python", Some(Runtime::Python)).await;
+            }
+
+            #[tokio::test]
+            async fn entity_go() {
+                check_entity(stringify!($schema), "entity_go", None).await;
+            }
+        }
+    };
+}
+
+#[tokio::test]
+async fn entity_naming_collision() {
+    let namespace = load_namespace("blog").await;
+    let output = output_dir("blog", "entity_naming_collision");
+    let entity = Entity { provider: Runtime::Python, dest: output.to_str().unwrap().to_owned() };
+    let mut options = EntityOptions::default();
+    let mut naming = Naming::default();
+    naming.renames.insert("name".to_owned(), "email".to_owned());
+    options.naming.insert("python".to_owned(), naming);
+    let error = entity::generate_with_options(&namespace, &entity, &options).await.unwrap_err();
+    assert!(error.message().contains("`email'"), "{}", error.message());
+    assert!(!output.exists());
+}
+
+#[tokio::test]
+async fn entity_node_module_package() {
+    let namespace = load_namespace("blog").await;
+    let output = output_dir("blog", "entity_node_module_package");
+    fs::create_dir_all(&output).unwrap();
+    fs::write(output.join("package.json"), "{\"name\": \"entities\", \"type\": \"module\"}\n").unwrap();
+    let entity = Entity { provider: Runtime::Node, dest: output.to_str().unwrap().to_owned() };
+    entity::generate_with_options(&namespace, &entity, &EntityOptions::default()).await.unwrap();
+    let package_json: serde_json::Value = serde_json::from_str(&fs::read_to_string(output.join("package.json")).unwrap()).unwrap();
+    assert_eq!(package_json["type"], "module");
+    assert_eq!(package_json["types"], "./index.d.ts");
+    assert_eq!(fs::read_to_string(output.join("index.js")).unwrap(), "export * from \"./index.mjs\"\n");
+}
+
+golden_tests!(blog);
+golden_tests!(nested);
@@ -0,0 +1,5647 @@
+import Foundation
+
+public protocol Reflectable : CustomStringConvertible { }
+
+extension Reflectable {
+
+    public var description: String {
+        let mirror = Mirror(reflecting: self)
+        var str = "\(mirror.subjectType)("
+        var first = true
+        for (label, value) in mirror.children {
+            if let label = label {
+                if first {
+                    first = false
+                } else {
+                    str += ", "
+                }
+                str += label
+                str += ": "
+                str += "\(value)"
+            }
+        }
+        str += ")"
+        return str
+    }
+}
+
+public struct TeoError: Error, LocalizedError, Decodable {
+    public let type: String
+    public let message: String
+    public let errors: Dictionary<String, String>?
+}
+
+public struct AnyCodable: Codable {
+    public init(from: any Decoder) throws {
+        fatalError()
+    }
+    public init(value: any Codable) {
+        self.value = value
+    }
+    static func fromValue(_ value: Codable?) -> Self? {
+        if let value {
+            return Self(value: value)
+        } else {
+            return nil
+        }
+    }
+    let value: any Codable
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(value)
+    }
+}
+
+public struct Null: Codable {
+    fileprivate init() { }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encodeNil()
+    }
+}
+
+public let null = Null()
+
+/// A range of values, encoded as `{"start": start, "end": end, "closed": closed}`.
+public struct TeoRange<T: Codable>: Codable {
+    public let start: T
+    public let end: T
+    public let closed: Bool
+    public init(start: T, end: T, closed: Bool = false) {
+        self.start = start
+        self.end = end
+        self.closed = closed
+    }
+}
+
+/// A tuple of 1 item, encoded as a JSON array.
+public struct TeoTuple1<T0: Codable>: Codable {
+    public let item0: T0
+    public init(_ item0: T0) {
+        self.item0 = item0
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+    }
+}
+
+/// A tuple of 2 items, encoded as a JSON array.
+public struct TeoTuple2<T0: Codable, T1: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public init(_ item0: T0, _ item1: T1) {
+        self.item0 = item0
+        self.item1 = item1
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+    }
+}
+
+/// A tuple of 3 items, encoded as a JSON array.
+public struct TeoTuple3<T0: Codable, T1: Codable, T2: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public init(_ item0: T0, _ item1: T1, _ item2: T2) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+    }
+}
+
+/// A tuple of 4 items, encoded as a JSON array.
+public struct TeoTuple4<T0: Codable, T1: Codable, T2: Codable, T3: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public let item3: T3
+    public init(_ item0: T0, _ item1: T1, _ item2: T2, _ item3: T3) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+        self.item3 = item3
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+        self.item3 = try container.decode(T3.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+        try container.encode(item3)
+    }
+}
+
+/// A tuple of 5 items, encoded as a JSON array.
+public struct TeoTuple5<T0: Codable, T1: Codable, T2: Codable, T3: Codable, T4: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public let item3: T3
+    public let item4: T4
+    public init(_ item0: T0, _ item1: T1, _ item2: T2, _ item3: T3, _ item4: T4) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+        self.item3 = item3
+        self.item4 = item4
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+        self.item3 = try container.decode(T3.self)
+        self.item4 = try container.decode(T4.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+        try container.encode(item3)
+        try container.encode(item4)
+    }
+}
+
+/// A tuple of 6 items, encoded as a JSON array.
+public struct TeoTuple6<T0: Codable, T1: Codable, T2: Codable, T3: Codable, T4: Codable, T5: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public let item3: T3
+    public let item4: T4
+    public let item5: T5
+    public init(_ item0: T0, _ item1: T1, _ item2: T2, _ item3: T3, _ item4: T4, _ item5: T5) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+        self.item3 = item3
+        self.item4 = item4
+        self.item5 = item5
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+        self.item3 = try container.decode(T3.self)
+        self.item4 = try container.decode(T4.self)
+        self.item5 = try container.decode(T5.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+        try container.encode(item3)
+        try container.encode(item4)
+        try container.encode(item5)
+    }
+}
+
+/// A tuple of 7 items, encoded as a JSON array.
+public struct TeoTuple7<T0: Codable, T1: Codable, T2: Codable, T3: Codable, T4: Codable, T5: Codable, T6: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public let item3: T3
+    public let item4: T4
+    public let item5: T5
+    public let item6: T6
+    public init(_ item0: T0, _ item1: T1, _ item2: T2, _ item3: T3, _ item4: T4, _ item5: T5, _ item6: T6) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+        self.item3 = item3
+        self.item4 = item4
+        self.item5 = item5
+        self.item6 = item6
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+        self.item3 = try container.decode(T3.self)
+        self.item4 = try container.decode(T4.self)
+        self.item5 = try container.decode(T5.self)
+        self.item6 = try container.decode(T6.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+        try container.encode(item3)
+        try container.encode(item4)
+        try container.encode(item5)
+        try container.encode(item6)
+    }
+}
+
+/// A tuple of 8 items, encoded as a JSON array.
+public struct TeoTuple8<T0: Codable, T1: Codable, T2: Codable, T3: Codable, T4: Codable, T5: Codable, T6: Codable, T7: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public let item3: T3
+    public let item4: T4
+    public let item5: T5
+    public let item6: T6
+    public let item7: T7
+    public init(_ item0: T0, _ item1: T1, _ item2: T2, _ item3: T3, _ item4: T4, _ item5: T5, _ item6: T6, _ item7: T7) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+        self.item3 = item3
+        self.item4 = item4
+        self.item5 = item5
+        self.item6 = item6
+        self.item7 = item7
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+        self.item3 = try container.decode(T3.self)
+        self.item4 = try container.decode(T4.self)
+        self.item5 = try container.decode(T5.self)
+        self.item6 = try container.decode(T6.self)
+        self.item7 = try container.decode(T7.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+        try container.encode(item3)
+        try container.encode(item4)
+        try container.encode(item5)
+        try container.encode(item6)
+        try container.encode(item7)
+    }
+}
+
+struct FormRequestBuilder {
+    private let boundary: String = UUID().uuidString
+    var httpBody = Data()
+
+    mutating func addTextField(named name: String, value: String) {
+        httpBody.append(textFormField(named: name, value: value).data(using: .utf8)!)
+    }
+
+    private func textFormField(named name: String, value: String) -> String {
+        var fieldString = "--\(boundary)\r\n"
+        fieldString += "Content-Disposition: form-data; name=\"\(name)\"\r\n"
+        fieldString += "Content-Type: text/plain; charset=utf8\r\n"
+        fieldString += "Content-Transfer-Encoding: 8bit\r\n"
+        fieldString += "\r\n"
+        fieldString += "\(value)\r\n"
+
+        return fieldString
+    }
+
+    mutating func addDataField(named name: String, filename: String, data: Data, mimeType: String? = nil) {
+        httpBody.append(dataFormField(named: name, filename: filename, data: data, mimeType: mimeType))
+    }
+
+    private func dataFormField(named name: String,
+                               filename: String,
+                               data: Data,
+                               mimeType: String? = nil) -> Data {
+        var fieldData = Data()
+
+        fieldData.append("--\(boundary)\r\n".data(using: .utf8)!)
+        fieldData.append("Content-Disposition: form-data; name=\"\(name)\"; filename=\"\(filename)\"\r\n".data(using: .utf8)!)
+        if let mimeType {
+            fieldData.append("Content-Type: \(mimeType)\r\n".data(using: .utf8)!)
+        }
+        fieldData.append("\r\n".data(using: .utf8)!)
+        fieldData.append(data)
+        fieldData.append("\r\n".data(using: .utf8)!)
+
+        return fieldData as Data
+    }
+}
+
+public struct File {
+    public let url: URL
+}
+
+private func formRequest<O: Decodable>(_ method: String, _ path: String, _ input: Dictionary<String, Any>, _ headers: Dictionary<String, String>? = nil, _ query: String? = nil) async throws -> O {
+    let url = URL(string: "http://127.0.0.1:5050/" + path)!
+    var request = URLRequest(url: url)
+    if let headers {
+        headers.forEach { (key, value) in
+            request.setValue(value, forHTTPHeaderField: key)
+        }
+    }
+    request.httpMethod = method
+    var builder = FormRequestBuilder()
+    try input.forEach { (key, value) in
+        if let value = value as? String {
+            builder.addTextField(named: key, value: value)
+        } else if let value = value as? File {
+            builder.addDataField(named: key, filename: value.url.lastPathComponent, data: try Data(contentsOf: value.url), mimeType: nil)
+        }
+    }
+    request.httpBody = builder.httpBody
+    let (data, response) = try await URLSession.shared.data(for: request)
+    guard response is HTTPURLResponse else { fatalError("response format is unexpected") }
+    return try JSONDecoder().decode(O.self, from: data)
+}
+
+private func jsonRequest<I: Encodable, O: Decodable>(_ method: String, _ path: String, _ input: I, _ headers: Dictionary<String, String>? = nil, _ query: String? = nil) async throws -> O {
+    let url = URL(string: "http://127.0.0.1:5050/" + path)!
+    var request = URLRequest(url: url)
+    request.setValue("application/json", forHTTPHeaderField: "content-type")
+    if let headers {
+        headers.forEach { (key, value) in
+            request.setValue(value, forHTTPHeaderField: key)
+        }
+    }
+    request.httpMethod = method
+    request.httpBody = try JSONEncoder().encode(input)
+    let (data, response) = try await URLSession.shared.data(for: request)
+    guard response is HTTPURLResponse else { fatalError("response format is unexpected") }
+    return try JSONDecoder().decode(O.self, from: data)
+}
+
+
+/// ## Sex
+///
+/// Sex The sex of a user.
+public enum Sex: String, Codable {
+
+    /// ### Male
+    ///
+    /// This enum member doesn't have a description.
+    case male = "male"
+
+    /// ### Female
+    ///
+    /// This enum member doesn't have a description.
+    case female = "female"
+}
+/// ## Post direct relations
+///
+/// This synthesized enum doesn't have a description.
+public enum PostDirectRelations: String, Codable {
+
+    /// ### Author
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case author = "author"
+}
+/// ## Post relations
+///
+/// This synthesized enum doesn't have a description.
+public enum PostRelations: String, Codable {
+
+    /// ### Author
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case author = "author"
+}
+/// ## Post scalar fields
+///
+/// This synthesized enum doesn't have a description.
+public enum PostScalarFields: String, Codable {
+
+    /// ### Author id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case authorId = "authorId"
+
+    /// ### Content
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case content = "content"
+
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case id = "id"
+
+    /// ### Tags
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case tags = "tags"
+
+    /// ### Title
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case title = "title"
+
+    /// ### Views
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case views = "views"
+}
+/// ## Post serializable scalar fields
+///
+/// This synthesized enum doesn't have a description.
+public enum PostSerializableScalarFields: String, Codable {
+
+    /// ### Author id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case authorId = "authorId"
+
+    /// ### Content
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case content = "content"
+
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case id = "id"
+
+    /// ### Tags
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case tags = "tags"
+
+    /// ### Title
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case title = "title"
+
+    /// ### Views
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case views = "views"
+}
+/// ## User direct relations
+///
+/// This synthesized enum doesn't have a description.
+public enum UserDirectRelations: String, Codable {
+
+    /// ### Posts
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case posts = "posts"
+}
+/// ## User relations
+///
+/// This synthesized enum doesn't have a description.
+public enum UserRelations: String, Codable {
+
+    /// ### Posts
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case posts = "posts"
+}
+/// ## User scalar fields
+///
+/// This synthesized enum doesn't have a description.
+public enum UserScalarFields: String, Codable {
+
+    /// ### Balance
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case balance = "balance"
+
+    /// ### Birthday
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case birthday = "birthday"
+
+    /// ### Created at
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case createdAt = "createdAt"
+
+    /// ### Email
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case email = "email"
+
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case id = "id"
+
+    /// ### Name
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case name = "name"
+
+    /// ### Sex
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case sex = "sex"
+}
+/// ## User serializable scalar fields
+///
+/// This synthesized enum doesn't have a description.
+public enum UserSerializableScalarFields: String, Codable {
+
+    /// ### Balance
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case balance = "balance"
+
+    /// ### Birthday
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case birthday = "birthday"
+
+    /// ### Created at
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case createdAt = "createdAt"
+
+    /// ### Email
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case email = "email"
+
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case id = "id"
+
+    /// ### Name
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case name = "name"
+
+    /// ### Sex
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case sex = "sex"
+}
+public class PostPathArguments: Codable, Reflectable {
+    public let id: String
+    public init(
+        id: String
+    ) {
+        self.id = id,
+    }
+}
+/**
+ * **Keyword**
+ *
+ * This interface doesn't have a description.
+ */
+public class Keyword : Codable, Reflectable {
+    public let value: StringOrInt
+    public init(
+        value: StringOrInt
+    ) {
+        self.value = value
+    }
+}
+/**
+ * **Page**
+ *
+ * This interface doesn't have a description.
+ */
+public class Page<T> : Codable, Reflectable where T: Codable {
+    public let total: Int64
+    public let items: Array<T>
+    public init(
+        total: Int64,
+        items: Array<T>
+    ) {
+        self.total = total
+        self.items = items
+    }
+}
+/**
+ * **Post path arguments**
+ *
+ * This interface doesn't have a description.
+ */
+public class PostPathArguments : Codable, Reflectable {
+    public let id: Int32
+    public init(
+        id: Int32
+    ) {
+        self.id = id
+    }
+}
+/**
+ * **Search input**
+ *
+ * This interface doesn't have a description.
+ */
+public class SearchInput : Codable, Reflectable {
+    public let keyword: String
+    public let sex: Sex?
+    public let tags: Array<String>?
+    public init(
+        keyword: String,
+        sex: Sex? = nil,
+        tags: Array<String>? = nil
+    ) {
+        self.keyword = keyword
+        self.sex = sex
+        self.tags = tags
+    }
+}
+/**
+ * **Upload input**
+ *
+ * This interface doesn't have a description.
+ */
+public class UploadInput : Codable, Reflectable {
+    public let title: String
+    public let caption: String?
+    public init(
+        title: String,
+        caption: String? = nil
+    ) {
+        self.title = title
+        self.caption = caption
+    }
+}
+/**
+ * **Upload output**
+ *
+ * This interface doesn't have a description.
+ */
+public class UploadOutput : Codable, Reflectable {
+    public let path: String
+    public init(
+        path: String
+    ) {
+        self.path = path
+    }
+}
+/**
+ * **Post**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class Post : Codable, Reflectable {
+    public let views: Int64?
+    public let title: String?
+    public let tags: Array<String>?
+    public let id: Int32?
+    public let authorId: Int32?
+    public let author: User?
+    public let content: String?
+    public init(
+        views: Int64? = nil,
+        title: String? = nil,
+        tags: Array<String>? = nil,
+        id: Int32? = nil,
+        authorId: Int32? = nil,
+        author: User? = nil,
+        content: String? = nil
+    ) {
+        self.views = views
+        self.title = title
+        self.tags = tags
+        self.id = id
+        self.authorId = authorId
+        self.author = author
+        self.content = content
+    }
+}
+/**
+ * **Post aggregate args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostAggregateArgs : Codable, Reflectable {
+    public let _avg: PostAvgAggregateInputType?
+    public let _count: PostCountAggregateInputType?
+    public let _max: PostMaxAggregateInputType?
+    public let _min: PostMinAggregateInputType?
+    public let _sum: PostSumAggregateInputType?
+    public let cursor: PostWhereUniqueInput?
+    public let distinct: PostSerializableScalarFields?
+    public let orderBy: Array<PostOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: PostWhereInput?
+    public init(
+        _avg: PostAvgAggregateInputType? = nil,
+        _count: PostCountAggregateInputType? = nil,
+        _max: PostMaxAggregateInputType? = nil,
+        _min: PostMinAggregateInputType? = nil,
+        _sum: PostSumAggregateInputType? = nil,
+        cursor: PostWhereUniqueInput? = nil,
+        distinct: PostSerializableScalarFields? = nil,
+        orderBy: Array<PostOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: PostWhereInput? = nil
+    ) {
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+        self.cursor = cursor
+        self.distinct = distinct
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **Post aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostAggregateResult : Codable, Reflectable {
+    public let _avg: PostAvgAggregateResult?
+    public let _count: PostCountAggregateResult?
+    public let _max: PostMaxAggregateResult?
+    public let _min: PostMinAggregateResult?
+    public let _sum: PostSumAggregateResult?
+    public init(
+        _avg: PostAvgAggregateResult? = nil,
+        _count: PostCountAggregateResult? = nil,
+        _max: PostMaxAggregateResult? = nil,
+        _min: PostMinAggregateResult? = nil,
+        _sum: PostSumAggregateResult? = nil
+    ) {
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+    }
+}
+/**
+ * **Post args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostArgs : Codable, Reflectable {
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post avg aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostAvgAggregateInputType : Codable, Reflectable {
+    public let authorId: Bool?
+    public let id: Bool?
+    public let views: Bool?
+    public init(
+        authorId: Bool? = nil,
+        id: Bool? = nil,
+        views: Bool? = nil
+    ) {
+        self.authorId = authorId
+        self.id = id
+        self.views = views
+    }
+}
+/**
+ * **Post avg aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostAvgAggregateResult : Codable, Reflectable {
+    public let authorId: Double?
+    public let id: Double?
+    public let views: Double?
+    public init(
+        authorId: Double? = nil,
+        id: Double? = nil,
+        views: Double? = nil
+    ) {
+        self.authorId = authorId
+        self.id = id
+        self.views = views
+    }
+}
+/**
+ * **Post connect or create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostConnectOrCreateInput : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let create: PostCreateInput
+    public init(
+        `where`: PostWhereUniqueInput,
+        create: PostCreateInput
+    ) {
+        self.where = `where`
+        self.create = create
+    }
+}
+/**
+ * **Post connect or create without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostConnectOrCreateWithoutAuthorInput : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let create: PostCreateInput
+    public init(
+        `where`: PostWhereUniqueInput,
+        create: PostCreateInput
+    ) {
+        self.where = `where`
+        self.create = create
+    }
+}
+/**
+ * **Post copy args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCopyArgs : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let copy: PostUpdateInput
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        `where`: PostWhereUniqueInput,
+        copy: PostUpdateInput,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.where = `where`
+        self.copy = copy
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post copy many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCopyManyArgs : Codable, Reflectable {
+    public let `where`: PostWhereInput
+    public let copy: PostUpdateInput
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        `where`: PostWhereInput,
+        copy: PostUpdateInput,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.where = `where`
+        self.copy = copy
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post count aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCountAggregateInputType : Codable, Reflectable {
+    public let _all: Bool?
+    public let authorId: Bool?
+    public let content: Bool?
+    public let id: Bool?
+    public let tags: Bool?
+    public let title: Bool?
+    public let views: Bool?
+    public init(
+        _all: Bool? = nil,
+        authorId: Bool? = nil,
+        content: Bool? = nil,
+        id: Bool? = nil,
+        tags: Bool? = nil,
+        title: Bool? = nil,
+        views: Bool? = nil
+    ) {
+        self._all = _all
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post count aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCountAggregateResult : Codable, Reflectable {
+    public let _all: Int64?
+    public let authorId: Int64?
+    public let content: Int64?
+    public let id: Int64?
+    public let tags: Int64?
+    public let title: Int64?
+    public let views: Int64?
+    public init(
+        _all: Int64? = nil,
+        authorId: Int64? = nil,
+        content: Int64? = nil,
+        id: Int64? = nil,
+        tags: Int64? = nil,
+        title: Int64? = nil,
+        views: Int64? = nil
+    ) {
+        self._all = _all
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post count args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCountArgs : Codable, Reflectable {
+    public let cursor: PostWhereUniqueInput?
+    public let distinct: PostSerializableScalarFields?
+    public let orderBy: Array<PostOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let select: PostCountAggregateInputType?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: PostWhereInput?
+    public init(
+        cursor: PostWhereUniqueInput? = nil,
+        distinct: PostSerializableScalarFields? = nil,
+        orderBy: Array<PostOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        select: PostCountAggregateInputType? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: PostWhereInput? = nil
+    ) {
+        self.cursor = cursor
+        self.distinct = distinct
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.select = select
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **Post create args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCreateArgs : Codable, Reflectable {
+    public let create: PostCreateInput
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        create: PostCreateInput,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.create = create
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCreateInput : Codable, Reflectable {
+    public let views: Int64
+    public let title: String
+    public let tags: Array<String>
+    public let author: UserCreateNestedOneWithoutPostsInput?
+    public let authorId: Int32?
+    public let content: String?
+    public init(
+        views: Int64,
+        title: String,
+        tags: Array<String>,
+        author: UserCreateNestedOneWithoutPostsInput? = nil,
+        authorId: Int32? = nil,
+        content: String? = nil
+    ) {
+        self.views = views
+        self.title = title
+        self.tags = tags
+        self.author = author
+        self.authorId = authorId
+        self.content = content
+    }
+}
+/**
+ * **Post create many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCreateManyArgs : Codable, Reflectable {
+    public let create: Array<PostCreateInput>
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        create: Array<PostCreateInput>,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.create = create
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post create nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCreateNestedManyInput : Codable, Reflectable {
+    public let connect: Array<PostWhereUniqueInput>?
+    public let connectOrCreate: Array<PostConnectOrCreateInput>?
+    public let create: Array<PostCreateInput>?
+    public init(
+        connect: Array<PostWhereUniqueInput>? = nil,
+        connectOrCreate: Array<PostConnectOrCreateInput>? = nil,
+        create: Array<PostCreateInput>? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+    }
+}
+/**
+ * **Post create nested many without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCreateNestedManyWithoutAuthorInput : Codable, Reflectable {
+    public let connect: Array<PostWhereUniqueInput>?
+    public let connectOrCreate: Array<PostConnectOrCreateWithoutAuthorInput>?
+    public let create: Array<PostCreateInput>?
+    public init(
+        connect: Array<PostWhereUniqueInput>? = nil,
+        connectOrCreate: Array<PostConnectOrCreateWithoutAuthorInput>? = nil,
+        create: Array<PostCreateInput>? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+    }
+}
+/**
+ * **Post create nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCreateNestedOneInput : Codable, Reflectable {
+    public let connect: PostWhereUniqueInput?
+    public let connectOrCreate: PostConnectOrCreateInput?
+    public let create: PostCreateInput?
+    public init(
+        connect: PostWhereUniqueInput? = nil,
+        connectOrCreate: PostConnectOrCreateInput? = nil,
+        create: PostCreateInput? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+    }
+}
+/**
+ * **Post create nested one without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCreateNestedOneWithoutAuthorInput : Codable, Reflectable {
+    public let connect: PostWhereUniqueInput?
+    public let connectOrCreate: PostConnectOrCreateWithoutAuthorInput?
+    public let create: PostCreateInput?
+    public init(
+        connect: PostWhereUniqueInput? = nil,
+        connectOrCreate: PostConnectOrCreateWithoutAuthorInput? = nil,
+        create: PostCreateInput? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+    }
+}
+/**
+ * **Post create without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostCreateWithoutAuthorInput : Codable, Reflectable {
+    public let views: Int64
+    public let title: String
+    public let tags: Array<String>
+    public let authorId: Int32?
+    public let content: String?
+    public init(
+        views: Int64,
+        title: String,
+        tags: Array<String>,
+        authorId: Int32? = nil,
+        content: String? = nil
+    ) {
+        self.views = views
+        self.title = title
+        self.tags = tags
+        self.authorId = authorId
+        self.content = content
+    }
+}
+/**
+ * **Post delete args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostDeleteArgs : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        `where`: PostWhereUniqueInput,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.where = `where`
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post delete many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostDeleteManyArgs : Codable, Reflectable {
+    public let `where`: PostWhereInput
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        `where`: PostWhereInput,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.where = `where`
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post find first args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostFindFirstArgs : Codable, Reflectable {
+    public let cursor: PostWhereUniqueInput?
+    public let distinct: PostSerializableScalarFields?
+    public let include: PostInclude?
+    public let orderBy: Array<PostOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let select: PostSelect?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: PostWhereInput?
+    public init(
+        cursor: PostWhereUniqueInput? = nil,
+        distinct: PostSerializableScalarFields? = nil,
+        include: PostInclude? = nil,
+        orderBy: Array<PostOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        select: PostSelect? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: PostWhereInput? = nil
+    ) {
+        self.cursor = cursor
+        self.distinct = distinct
+        self.include = include
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.select = select
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **Post find many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostFindManyArgs : Codable, Reflectable {
+    public let cursor: PostWhereUniqueInput?
+    public let distinct: PostSerializableScalarFields?
+    public let include: PostInclude?
+    public let orderBy: Array<PostOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let select: PostSelect?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: PostWhereInput?
+    public init(
+        cursor: PostWhereUniqueInput? = nil,
+        distinct: PostSerializableScalarFields? = nil,
+        include: PostInclude? = nil,
+        orderBy: Array<PostOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        select: PostSelect? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: PostWhereInput? = nil
+    ) {
+        self.cursor = cursor
+        self.distinct = distinct
+        self.include = include
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.select = select
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **Post find unique args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostFindUniqueArgs : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        `where`: PostWhereUniqueInput,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.where = `where`
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post group by args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostGroupByArgs : Codable, Reflectable {
+    public let by: Array<PostSerializableScalarFields>
+    public let _avg: PostAvgAggregateInputType?
+    public let _count: PostCountAggregateInputType?
+    public let _max: PostMaxAggregateInputType?
+    public let _min: PostMinAggregateInputType?
+    public let _sum: PostSumAggregateInputType?
+    public let cursor: PostWhereUniqueInput?
+    public let distinct: PostSerializableScalarFields?
+    public let having: PostScalarWhereWithAggregatesInput?
+    public let orderBy: Array<PostOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: PostWhereInput?
+    public init(
+        by: Array<PostSerializableScalarFields>,
+        _avg: PostAvgAggregateInputType? = nil,
+        _count: PostCountAggregateInputType? = nil,
+        _max: PostMaxAggregateInputType? = nil,
+        _min: PostMinAggregateInputType? = nil,
+        _sum: PostSumAggregateInputType? = nil,
+        cursor: PostWhereUniqueInput? = nil,
+        distinct: PostSerializableScalarFields? = nil,
+        having: PostScalarWhereWithAggregatesInput? = nil,
+        orderBy: Array<PostOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: PostWhereInput? = nil
+    ) {
+        self.by = by
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+        self.cursor = cursor
+        self.distinct = distinct
+        self.having = having
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **Post group by result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostGroupByResult : Codable, Reflectable {
+    public let _avg: PostAvgAggregateResult?
+    public let _count: PostCountAggregateResult?
+    public let _max: PostMaxAggregateResult?
+    public let _min: PostMinAggregateResult?
+    public let _sum: PostSumAggregateResult?
+    public let authorId: Int32?
+    public let content: String?
+    public let id: Int32?
+    public let tags: Array<String>?
+    public let title: String?
+    public let views: Int64?
+    public init(
+        _avg: PostAvgAggregateResult? = nil,
+        _count: PostCountAggregateResult? = nil,
+        _max: PostMaxAggregateResult? = nil,
+        _min: PostMinAggregateResult? = nil,
+        _sum: PostSumAggregateResult? = nil,
+        authorId: Int32? = nil,
+        content: String? = nil,
+        id: Int32? = nil,
+        tags: Array<String>? = nil,
+        title: String? = nil,
+        views: Int64? = nil
+    ) {
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post include**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostInclude : Codable, Reflectable {
+    public let author: UserArgsOrBool?
+    public init(
+        author: UserArgsOrBool? = nil
+    ) {
+        self.author = author
+    }
+}
+/**
+ * **Post list relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostListRelationFilter : Codable, Reflectable {
+    public let every: PostWhereInput?
+    public let none: PostWhereInput?
+    public let some: PostWhereInput?
+    public init(
+        every: PostWhereInput? = nil,
+        none: PostWhereInput? = nil,
+        some: PostWhereInput? = nil
+    ) {
+        self.every = every
+        self.none = none
+        self.some = some
+    }
+}
+/**
+ * **Post max aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostMaxAggregateInputType : Codable, Reflectable {
+    public let authorId: Bool?
+    public let content: Bool?
+    public let id: Bool?
+    public let tags: Bool?
+    public let title: Bool?
+    public let views: Bool?
+    public init(
+        authorId: Bool? = nil,
+        content: Bool? = nil,
+        id: Bool? = nil,
+        tags: Bool? = nil,
+        title: Bool? = nil,
+        views: Bool? = nil
+    ) {
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post max aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostMaxAggregateResult : Codable, Reflectable {
+    public let authorId: Int32?
+    public let content: String?
+    public let id: Int32?
+    public let tags: Array<String>?
+    public let title: String?
+    public let views: Int64?
+    public init(
+        authorId: Int32? = nil,
+        content: String? = nil,
+        id: Int32? = nil,
+        tags: Array<String>? = nil,
+        title: String? = nil,
+        views: Int64? = nil
+    ) {
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post min aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostMinAggregateInputType : Codable, Reflectable {
+    public let authorId: Bool?
+    public let content: Bool?
+    public let id: Bool?
+    public let tags: Bool?
+    public let title: Bool?
+    public let views: Bool?
+    public init(
+        authorId: Bool? = nil,
+        content: Bool? = nil,
+        id: Bool? = nil,
+        tags: Bool? = nil,
+        title: Bool? = nil,
+        views: Bool? = nil
+    ) {
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post min aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostMinAggregateResult : Codable, Reflectable {
+    public let authorId: Int32?
+    public let content: String?
+    public let id: Int32?
+    public let tags: Array<String>?
+    public let title: String?
+    public let views: Int64?
+    public init(
+        authorId: Int32? = nil,
+        content: String? = nil,
+        id: Int32? = nil,
+        tags: Array<String>? = nil,
+        title: String? = nil,
+        views: Int64? = nil
+    ) {
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+public enum PostOrderByInput: Codable {
+    case authorId(std.Sort)
+    case content(std.Sort)
+    case id(std.Sort)
+    case tags(std.Sort)
+    case title(std.Sort)
+    case views(std.Sort)
+    enum CodingKeys: String, CodingKey {
+        case authorId = "authorId"
+        case content = "content"
+        case id = "id"
+        case tags = "tags"
+        case title = "title"
+        case views = "views"
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .authorId(let a0):
+            try container.encode(a0, forKey: .authorId)
+        case .content(let a0):
+            try container.encode(a0, forKey: .content)
+        case .id(let a0):
+            try container.encode(a0, forKey: .id)
+        case .tags(let a0):
+            try container.encode(a0, forKey: .tags)
+        case .title(let a0):
+            try container.encode(a0, forKey: .title)
+        case .views(let a0):
+            try container.encode(a0, forKey: .views)
+        }
+    }
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        for key in container.allKeys {
+            switch key {
+            case .authorId:
+                self = Self.authorId(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .content:
+                self = Self.content(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .id:
+                self = Self.id(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .tags:
+                self = Self.tags(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .title:
+                self = Self.title(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .views:
+                self = Self.views(try container.decode(std.Sort.self, forKey: key))
+                return
+            }
+        }
+        fatalError("invalid input for enum value decoder")
+    }
+}
+/**
+ * **Post relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostRelationFilter : Codable, Reflectable {
+    public let `is`: PostWhereInput?
+    public let isNot: PostWhereInput?
+    public init(
+        `is`: PostWhereInput? = nil,
+        isNot: PostWhereInput? = nil
+    ) {
+        self.is = `is`
+        self.isNot = isNot
+    }
+}
+/**
+ * **Post scalar update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostScalarUpdateInput : Codable, Reflectable {
+    public let authorId: Int32?
+    public let content: String?
+    public let id: Int32?
+    public let tags: Array<String>?
+    public let title: String?
+    public let views: Int64?
+    public init(
+        authorId: Int32? = nil,
+        content: String? = nil,
+        id: Int32? = nil,
+        tags: Array<String>? = nil,
+        title: String? = nil,
+        views: Int64? = nil
+    ) {
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post scalar where with aggregates input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostScalarWhereWithAggregatesInput : Codable, Reflectable {
+    public let AND: Array<PostWhereInput>?
+    public let NOT: PostWhereInput?
+    public let OR: Array<PostWhereInput>?
+    public let authorId: IntOrStdIntNumberWithAggregatesFilterInt?
+    public let content: StringOrStdStringNullableWithAggregatesFilter??
+    public let id: IntOrStdIntNumberWithAggregatesFilterInt?
+    public let tags: StringArrayOrStdArrayWithAggregatesFilterString?
+    public let title: StringOrStdStringWithAggregatesFilter?
+    public let views: Int64OrStdIntNumberWithAggregatesFilterInt64?
+    public init(
+        AND: Array<PostWhereInput>? = nil,
+        NOT: PostWhereInput? = nil,
+        OR: Array<PostWhereInput>? = nil,
+        authorId: IntOrStdIntNumberWithAggregatesFilterInt? = nil,
+        content: StringOrStdStringNullableWithAggregatesFilter?? = nil,
+        id: IntOrStdIntNumberWithAggregatesFilterInt? = nil,
+        tags: StringArrayOrStdArrayWithAggregatesFilterString? = nil,
+        title: StringOrStdStringWithAggregatesFilter? = nil,
+        views: Int64OrStdIntNumberWithAggregatesFilterInt64? = nil
+    ) {
+        self.AND = AND
+        self.NOT = NOT
+        self.OR = OR
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post select**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostSelect : Codable, Reflectable {
+    public let authorId: Bool?
+    public let content: Bool?
+    public let id: Bool?
+    public let tags: Bool?
+    public let title: Bool?
+    public let views: Bool?
+    public init(
+        authorId: Bool? = nil,
+        content: Bool? = nil,
+        id: Bool? = nil,
+        tags: Bool? = nil,
+        title: Bool? = nil,
+        views: Bool? = nil
+    ) {
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post sign in args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostSignInArgs : Codable, Reflectable {
+    public init(
+    ) {
+    }
+}
+/**
+ * **Post sign in checker companions**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostSignInCheckerCompanions : Codable, Reflectable {
+    public init(
+    ) {
+    }
+}
+/**
+ * **Post sign in checker ids**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostSignInCheckerIds : Codable, Reflectable {
+    public init(
+    ) {
+    }
+}
+/**
+ * **Post sign in input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostSignInInput : Codable, Reflectable {
+    public let credentials: PostSignInArgs
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        credentials: PostSignInArgs,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.credentials = credentials
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post sum aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostSumAggregateInputType : Codable, Reflectable {
+    public let authorId: Bool?
+    public let id: Bool?
+    public let views: Bool?
+    public init(
+        authorId: Bool? = nil,
+        id: Bool? = nil,
+        views: Bool? = nil
+    ) {
+        self.authorId = authorId
+        self.id = id
+        self.views = views
+    }
+}
+/**
+ * **Post sum aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostSumAggregateResult : Codable, Reflectable {
+    public let authorId: Int64?
+    public let id: Int64?
+    public let views: Int64?
+    public init(
+        authorId: Int64? = nil,
+        id: Int64? = nil,
+        views: Int64? = nil
+    ) {
+        self.authorId = authorId
+        self.id = id
+        self.views = views
+    }
+}
+/**
+ * **Post update args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateArgs : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let update: PostUpdateInput
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        `where`: PostWhereUniqueInput,
+        update: PostUpdateInput,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.where = `where`
+        self.update = update
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateInput : Codable, Reflectable {
+    public let author: UserUpdateNestedOneWithoutPostsInput?
+    public let authorId: Int32?
+    public let content: String?
+    public let tags: Array<String>?
+    public let title: String?
+    public let views: Int64?
+    public init(
+        author: UserUpdateNestedOneWithoutPostsInput? = nil,
+        authorId: Int32? = nil,
+        content: String? = nil,
+        tags: Array<String>? = nil,
+        title: String? = nil,
+        views: Int64? = nil
+    ) {
+        self.author = author
+        self.authorId = authorId
+        self.content = content
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post update many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateManyArgs : Codable, Reflectable {
+    public let `where`: PostWhereInput
+    public let update: PostUpdateInput
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        `where`: PostWhereInput,
+        update: PostUpdateInput,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.where = `where`
+        self.update = update
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post update many with where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateManyWithWhereInput : Codable, Reflectable {
+    public let `where`: PostWhereInput
+    public let update: PostUpdateInput
+    public init(
+        `where`: PostWhereInput,
+        update: PostUpdateInput
+    ) {
+        self.where = `where`
+        self.update = update
+    }
+}
+/**
+ * **Post update many with where without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateManyWithWhereWithoutAuthorInput : Codable, Reflectable {
+    public let `where`: PostWhereInput
+    public let update: PostUpdateInput
+    public init(
+        `where`: PostWhereInput,
+        update: PostUpdateInput
+    ) {
+        self.where = `where`
+        self.update = update
+    }
+}
+/**
+ * **Post update nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateNestedManyInput : Codable, Reflectable {
+    public let connect: Array<PostWhereUniqueInput>?
+    public let connectOrCreate: Array<PostConnectOrCreateInput>?
+    public let create: Array<PostCreateInput>?
+    public let delete: Array<PostWhereUniqueInput>?
+    public let deleteMany: Array<PostWhereInput>?
+    public let disconnect: Array<PostWhereUniqueInput>?
+    public let set: Array<PostWhereUniqueInput>?
+    public let update: Array<PostUpdateWithWhereUniqueInput>?
+    public let updateMany: Array<PostUpdateManyWithWhereInput>?
+    public let upsert: Array<PostUpsertWithWhereUniqueInput>?
+    public init(
+        connect: Array<PostWhereUniqueInput>? = nil,
+        connectOrCreate: Array<PostConnectOrCreateInput>? = nil,
+        create: Array<PostCreateInput>? = nil,
+        delete: Array<PostWhereUniqueInput>? = nil,
+        deleteMany: Array<PostWhereInput>? = nil,
+        disconnect: Array<PostWhereUniqueInput>? = nil,
+        set: Array<PostWhereUniqueInput>? = nil,
+        update: Array<PostUpdateWithWhereUniqueInput>? = nil,
+        updateMany: Array<PostUpdateManyWithWhereInput>? = nil,
+        upsert: Array<PostUpsertWithWhereUniqueInput>? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+        self.delete = delete
+        self.deleteMany = deleteMany
+        self.disconnect = disconnect
+        self.set = set
+        self.update = update
+        self.updateMany = updateMany
+        self.upsert = upsert
+    }
+}
+/**
+ * **Post update nested many without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateNestedManyWithoutAuthorInput : Codable, Reflectable {
+    public let connect: Array<PostWhereUniqueInput>?
+    public let connectOrCreate: Array<PostConnectOrCreateWithoutAuthorInput>?
+    public let create: Array<PostCreateInput>?
+    public let delete: Array<PostWhereUniqueInput>?
+    public let deleteMany: Array<PostWhereInput>?
+    public let disconnect: Array<PostWhereUniqueInput>?
+    public let set: Array<PostWhereUniqueInput>?
+    public let update: Array<PostUpdateWithWhereUniqueWithoutAuthorInput>?
+    public let updateMany: Array<PostUpdateManyWithWhereWithoutAuthorInput>?
+    public let upsert: Array<PostUpsertWithWhereUniqueWithoutAuthorInput>?
+    public init(
+        connect: Array<PostWhereUniqueInput>? = nil,
+        connectOrCreate: Array<PostConnectOrCreateWithoutAuthorInput>? = nil,
+        create: Array<PostCreateInput>? = nil,
+        delete: Array<PostWhereUniqueInput>? = nil,
+        deleteMany: Array<PostWhereInput>? = nil,
+        disconnect: Array<PostWhereUniqueInput>? = nil,
+        set: Array<PostWhereUniqueInput>? = nil,
+        update: Array<PostUpdateWithWhereUniqueWithoutAuthorInput>? = nil,
+        updateMany: Array<PostUpdateManyWithWhereWithoutAuthorInput>? = nil,
+        upsert: Array<PostUpsertWithWhereUniqueWithoutAuthorInput>? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+        self.delete = delete
+        self.deleteMany = deleteMany
+        self.disconnect = disconnect
+        self.set = set
+        self.update = update
+        self.updateMany = updateMany
+        self.upsert = upsert
+    }
+}
+/**
+ * **Post update nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateNestedOneInput : Codable, Reflectable {
+    public let connect: PostWhereUniqueInput?
+    public let connectOrCreate: PostConnectOrCreateInput?
+    public let create: PostCreateInput?
+    public let delete: Bool?
+    public let disconnect: Bool?
+    public let set: PostWhereUniqueInput?
+    public let update: PostUpdateInput?
+    public let upsert: PostUpsertWithWhereUniqueInput?
+    public init(
+        connect: PostWhereUniqueInput? = nil,
+        connectOrCreate: PostConnectOrCreateInput? = nil,
+        create: PostCreateInput? = nil,
+        delete: Bool? = nil,
+        disconnect: Bool? = nil,
+        set: PostWhereUniqueInput? = nil,
+        update: PostUpdateInput? = nil,
+        upsert: PostUpsertWithWhereUniqueInput? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+        self.delete = delete
+        self.disconnect = disconnect
+        self.set = set
+        self.update = update
+        self.upsert = upsert
+    }
+}
+/**
+ * **Post update nested one without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateNestedOneWithoutAuthorInput : Codable, Reflectable {
+    public let connect: PostWhereUniqueInput?
+    public let connectOrCreate: PostConnectOrCreateWithoutAuthorInput?
+    public let create: PostCreateInput?
+    public let delete: Bool?
+    public let disconnect: Bool?
+    public let set: PostWhereUniqueInput?
+    public let update: PostUpdateInput?
+    public let upsert: PostUpsertWithWhereUniqueWithoutAuthorInput?
+    public init(
+        connect: PostWhereUniqueInput? = nil,
+        connectOrCreate: PostConnectOrCreateWithoutAuthorInput? = nil,
+        create: PostCreateInput? = nil,
+        delete: Bool? = nil,
+        disconnect: Bool? = nil,
+        set: PostWhereUniqueInput? = nil,
+        update: PostUpdateInput? = nil,
+        upsert: PostUpsertWithWhereUniqueWithoutAuthorInput? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+        self.delete = delete
+        self.disconnect = disconnect
+        self.set = set
+        self.update = update
+        self.upsert = upsert
+    }
+}
+/**
+ * **Post update with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateWithWhereUniqueInput : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let update: PostUpdateInput
+    public init(
+        `where`: PostWhereUniqueInput,
+        update: PostUpdateInput
+    ) {
+        self.where = `where`
+        self.update = update
+    }
+}
+/**
+ * **Post update with where unique without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateWithWhereUniqueWithoutAuthorInput : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let update: PostUpdateInput
+    public init(
+        `where`: PostWhereUniqueInput,
+        update: PostUpdateInput
+    ) {
+        self.where = `where`
+        self.update = update
+    }
+}
+/**
+ * **Post update without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpdateWithoutAuthorInput : Codable, Reflectable {
+    public let authorId: Int32?
+    public let content: String?
+    public let tags: Array<String>?
+    public let title: String?
+    public let views: Int64?
+    public init(
+        authorId: Int32? = nil,
+        content: String? = nil,
+        tags: Array<String>? = nil,
+        title: String? = nil,
+        views: Int64? = nil
+    ) {
+        self.authorId = authorId
+        self.content = content
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post upsert args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpsertArgs : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let update: PostUpdateInput
+    public let create: PostCreateInput
+    public let include: PostInclude?
+    public let select: PostSelect?
+    public init(
+        `where`: PostWhereUniqueInput,
+        update: PostUpdateInput,
+        create: PostCreateInput,
+        include: PostInclude? = nil,
+        select: PostSelect? = nil
+    ) {
+        self.where = `where`
+        self.update = update
+        self.create = create
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Post upsert with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpsertWithWhereUniqueInput : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let update: PostUpdateInput
+    public let create: PostCreateInput
+    public init(
+        `where`: PostWhereUniqueInput,
+        update: PostUpdateInput,
+        create: PostCreateInput
+    ) {
+        self.where = `where`
+        self.update = update
+        self.create = create
+    }
+}
+/**
+ * **Post upsert with where unique without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostUpsertWithWhereUniqueWithoutAuthorInput : Codable, Reflectable {
+    public let `where`: PostWhereUniqueInput
+    public let update: PostUpdateInput
+    public let create: PostCreateInput
+    public init(
+        `where`: PostWhereUniqueInput,
+        update: PostUpdateInput,
+        create: PostCreateInput
+    ) {
+        self.where = `where`
+        self.update = update
+        self.create = create
+    }
+}
+/**
+ * **Post where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostWhereInput : Codable, Reflectable {
+    public let AND: Array<PostWhereInput>?
+    public let NOT: PostWhereInput?
+    public let OR: Array<PostWhereInput>?
+    public let author: UserRelationFilter?
+    public let authorId: IntOrStdFilterInt?
+    public let content: StringOrStdStringNullableFilter??
+    public let id: IntOrStdFilterInt?
+    public let tags: StringArrayOrStdArrayFilterString?
+    public let title: StringOrStdStringFilter?
+    public let views: Int64OrStdFilterInt64?
+    public init(
+        AND: Array<PostWhereInput>? = nil,
+        NOT: PostWhereInput? = nil,
+        OR: Array<PostWhereInput>? = nil,
+        author: UserRelationFilter? = nil,
+        authorId: IntOrStdFilterInt? = nil,
+        content: StringOrStdStringNullableFilter?? = nil,
+        id: IntOrStdFilterInt? = nil,
+        tags: StringArrayOrStdArrayFilterString? = nil,
+        title: StringOrStdStringFilter? = nil,
+        views: Int64OrStdFilterInt64? = nil
+    ) {
+        self.AND = AND
+        self.NOT = NOT
+        self.OR = OR
+        self.author = author
+        self.authorId = authorId
+        self.content = content
+        self.id = id
+        self.tags = tags
+        self.title = title
+        self.views = views
+    }
+}
+/**
+ * **Post where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class PostWhereUniqueInput : Codable, Reflectable {
+    public let id: Int32
+    public init(
+        id: Int32
+    ) {
+        self.id = id
+    }
+}
+/**
+ * **User**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class User : Codable, Reflectable {
+    public let posts: Array<Post>?
+    public let id: Int32?
+    public let email: String?
+    public let createdAt: Date?
+    public let balance: Decimal?
+    public let birthday: String?
+    public let displayName: String?
+    public let name: String?
+    public let sex: Sex?
+    public init(
+        posts: Array<Post>? = nil,
+        id: Int32? = nil,
+        email: String? = nil,
+        createdAt: Date? = nil,
+        balance: Decimal? = nil,
+        birthday: String? = nil,
+        displayName: String? = nil,
+        name: String? = nil,
+        sex: Sex? = nil
+    ) {
+        self.posts = posts
+        self.id = id
+        self.email = email
+        self.createdAt = createdAt
+        self.balance = balance
+        self.birthday = birthday
+        self.displayName = displayName
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User aggregate args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserAggregateArgs : Codable, Reflectable {
+    public let _avg: UserAvgAggregateInputType?
+    public let _count: UserCountAggregateInputType?
+    public let _max: UserMaxAggregateInputType?
+    public let _min: UserMinAggregateInputType?
+    public let _sum: UserSumAggregateInputType?
+    public let cursor: UserWhereUniqueInput?
+    public let distinct: UserSerializableScalarFields?
+    public let orderBy: Array<UserOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: UserWhereInput?
+    public init(
+        _avg: UserAvgAggregateInputType? = nil,
+        _count: UserCountAggregateInputType? = nil,
+        _max: UserMaxAggregateInputType? = nil,
+        _min: UserMinAggregateInputType? = nil,
+        _sum: UserSumAggregateInputType? = nil,
+        cursor: UserWhereUniqueInput? = nil,
+        distinct: UserSerializableScalarFields? = nil,
+        orderBy: Array<UserOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: UserWhereInput? = nil
+    ) {
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+        self.cursor = cursor
+        self.distinct = distinct
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **User aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserAggregateResult : Codable, Reflectable {
+    public let _avg: UserAvgAggregateResult?
+    public let _count: UserCountAggregateResult?
+    public let _max: UserMaxAggregateResult?
+    public let _min: UserMinAggregateResult?
+    public let _sum: UserSumAggregateResult?
+    public init(
+        _avg: UserAvgAggregateResult? = nil,
+        _count: UserCountAggregateResult? = nil,
+        _max: UserMaxAggregateResult? = nil,
+        _min: UserMinAggregateResult? = nil,
+        _sum: UserSumAggregateResult? = nil
+    ) {
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+    }
+}
+/**
+ * **User args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserArgs : Codable, Reflectable {
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User avg aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserAvgAggregateInputType : Codable, Reflectable {
+    public let id: Bool?
+    public init(
+        id: Bool? = nil
+    ) {
+        self.id = id
+    }
+}
+/**
+ * **User avg aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserAvgAggregateResult : Codable, Reflectable {
+    public let id: Double?
+    public init(
+        id: Double? = nil
+    ) {
+        self.id = id
+    }
+}
+/**
+ * **User connect or create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserConnectOrCreateInput : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let create: UserCreateInput
+    public init(
+        `where`: UserWhereUniqueInput,
+        create: UserCreateInput
+    ) {
+        self.where = `where`
+        self.create = create
+    }
+}
+/**
+ * **User connect or create without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserConnectOrCreateWithoutPostsInput : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let create: UserCreateInput
+    public init(
+        `where`: UserWhereUniqueInput,
+        create: UserCreateInput
+    ) {
+        self.where = `where`
+        self.create = create
+    }
+}
+/**
+ * **User copy args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCopyArgs : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let copy: UserUpdateInput
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        `where`: UserWhereUniqueInput,
+        copy: UserUpdateInput,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.where = `where`
+        self.copy = copy
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User copy many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCopyManyArgs : Codable, Reflectable {
+    public let `where`: UserWhereInput
+    public let copy: UserUpdateInput
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        `where`: UserWhereInput,
+        copy: UserUpdateInput,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.where = `where`
+        self.copy = copy
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User count aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCountAggregateInputType : Codable, Reflectable {
+    public let _all: Bool?
+    public let balance: Bool?
+    public let birthday: Bool?
+    public let createdAt: Bool?
+    public let email: Bool?
+    public let id: Bool?
+    public let name: Bool?
+    public let sex: Bool?
+    public init(
+        _all: Bool? = nil,
+        balance: Bool? = nil,
+        birthday: Bool? = nil,
+        createdAt: Bool? = nil,
+        email: Bool? = nil,
+        id: Bool? = nil,
+        name: Bool? = nil,
+        sex: Bool? = nil
+    ) {
+        self._all = _all
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.email = email
+        self.id = id
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User count aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCountAggregateResult : Codable, Reflectable {
+    public let _all: Int64?
+    public let balance: Int64?
+    public let birthday: Int64?
+    public let createdAt: Int64?
+    public let email: Int64?
+    public let id: Int64?
+    public let name: Int64?
+    public let sex: Int64?
+    public init(
+        _all: Int64? = nil,
+        balance: Int64? = nil,
+        birthday: Int64? = nil,
+        createdAt: Int64? = nil,
+        email: Int64? = nil,
+        id: Int64? = nil,
+        name: Int64? = nil,
+        sex: Int64? = nil
+    ) {
+        self._all = _all
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.email = email
+        self.id = id
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User count args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCountArgs : Codable, Reflectable {
+    public let cursor: UserWhereUniqueInput?
+    public let distinct: UserSerializableScalarFields?
+    public let orderBy: Array<UserOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let select: UserCountAggregateInputType?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: UserWhereInput?
+    public init(
+        cursor: UserWhereUniqueInput? = nil,
+        distinct: UserSerializableScalarFields? = nil,
+        orderBy: Array<UserOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        select: UserCountAggregateInputType? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: UserWhereInput? = nil
+    ) {
+        self.cursor = cursor
+        self.distinct = distinct
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.select = select
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **User create args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCreateArgs : Codable, Reflectable {
+    public let create: UserCreateInput
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        create: UserCreateInput,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.create = create
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCreateInput : Codable, Reflectable {
+    public let email: String
+    public let balance: Decimal?
+    public let birthday: String?
+    public let name: String?
+    public let posts: PostCreateNestedManyWithoutAuthorInput?
+    public let sex: Sex?
+    public init(
+        email: String,
+        balance: Decimal? = nil,
+        birthday: String? = nil,
+        name: String? = nil,
+        posts: PostCreateNestedManyWithoutAuthorInput? = nil,
+        sex: Sex? = nil
+    ) {
+        self.email = email
+        self.balance = balance
+        self.birthday = birthday
+        self.name = name
+        self.posts = posts
+        self.sex = sex
+    }
+}
+/**
+ * **User create many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCreateManyArgs : Codable, Reflectable {
+    public let create: Array<UserCreateInput>
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        create: Array<UserCreateInput>,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.create = create
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User create nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCreateNestedManyInput : Codable, Reflectable {
+    public let connect: Array<UserWhereUniqueInput>?
+    public let connectOrCreate: Array<UserConnectOrCreateInput>?
+    public let create: Array<UserCreateInput>?
+    public init(
+        connect: Array<UserWhereUniqueInput>? = nil,
+        connectOrCreate: Array<UserConnectOrCreateInput>? = nil,
+        create: Array<UserCreateInput>? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+    }
+}
+/**
+ * **User create nested many without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCreateNestedManyWithoutPostsInput : Codable, Reflectable {
+    public let connect: Array<UserWhereUniqueInput>?
+    public let connectOrCreate: Array<UserConnectOrCreateWithoutPostsInput>?
+    public let create: Array<UserCreateInput>?
+    public init(
+        connect: Array<UserWhereUniqueInput>? = nil,
+        connectOrCreate: Array<UserConnectOrCreateWithoutPostsInput>? = nil,
+        create: Array<UserCreateInput>? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+    }
+}
+/**
+ * **User create nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCreateNestedOneInput : Codable, Reflectable {
+    public let connect: UserWhereUniqueInput?
+    public let connectOrCreate: UserConnectOrCreateInput?
+    public let create: UserCreateInput?
+    public init(
+        connect: UserWhereUniqueInput? = nil,
+        connectOrCreate: UserConnectOrCreateInput? = nil,
+        create: UserCreateInput? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+    }
+}
+/**
+ * **User create nested one without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCreateNestedOneWithoutPostsInput : Codable, Reflectable {
+    public let connect: UserWhereUniqueInput?
+    public let connectOrCreate: UserConnectOrCreateWithoutPostsInput?
+    public let create: UserCreateInput?
+    public init(
+        connect: UserWhereUniqueInput? = nil,
+        connectOrCreate: UserConnectOrCreateWithoutPostsInput? = nil,
+        create: UserCreateInput? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+    }
+}
+/**
+ * **User create without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserCreateWithoutPostsInput : Codable, Reflectable {
+    public let email: String
+    public let balance: Decimal?
+    public let birthday: String?
+    public let name: String?
+    public let sex: Sex?
+    public init(
+        email: String,
+        balance: Decimal? = nil,
+        birthday: String? = nil,
+        name: String? = nil,
+        sex: Sex? = nil
+    ) {
+        self.email = email
+        self.balance = balance
+        self.birthday = birthday
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User delete args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserDeleteArgs : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        `where`: UserWhereUniqueInput,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.where = `where`
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User delete many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserDeleteManyArgs : Codable, Reflectable {
+    public let `where`: UserWhereInput
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        `where`: UserWhereInput,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.where = `where`
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User find first args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserFindFirstArgs : Codable, Reflectable {
+    public let cursor: UserWhereUniqueInput?
+    public let distinct: UserSerializableScalarFields?
+    public let include: UserInclude?
+    public let orderBy: Array<UserOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let select: UserSelect?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: UserWhereInput?
+    public init(
+        cursor: UserWhereUniqueInput? = nil,
+        distinct: UserSerializableScalarFields? = nil,
+        include: UserInclude? = nil,
+        orderBy: Array<UserOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        select: UserSelect? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: UserWhereInput? = nil
+    ) {
+        self.cursor = cursor
+        self.distinct = distinct
+        self.include = include
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.select = select
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **User find many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserFindManyArgs : Codable, Reflectable {
+    public let cursor: UserWhereUniqueInput?
+    public let distinct: UserSerializableScalarFields?
+    public let include: UserInclude?
+    public let orderBy: Array<UserOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let select: UserSelect?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: UserWhereInput?
+    public init(
+        cursor: UserWhereUniqueInput? = nil,
+        distinct: UserSerializableScalarFields? = nil,
+        include: UserInclude? = nil,
+        orderBy: Array<UserOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        select: UserSelect? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: UserWhereInput? = nil
+    ) {
+        self.cursor = cursor
+        self.distinct = distinct
+        self.include = include
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.select = select
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **User find unique args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserFindUniqueArgs : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        `where`: UserWhereUniqueInput,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.where = `where`
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User group by args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserGroupByArgs : Codable, Reflectable {
+    public let by: Array<UserSerializableScalarFields>
+    public let _avg: UserAvgAggregateInputType?
+    public let _count: UserCountAggregateInputType?
+    public let _max: UserMaxAggregateInputType?
+    public let _min: UserMinAggregateInputType?
+    public let _sum: UserSumAggregateInputType?
+    public let cursor: UserWhereUniqueInput?
+    public let distinct: UserSerializableScalarFields?
+    public let having: UserScalarWhereWithAggregatesInput?
+    public let orderBy: Array<UserOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: UserWhereInput?
+    public init(
+        by: Array<UserSerializableScalarFields>,
+        _avg: UserAvgAggregateInputType? = nil,
+        _count: UserCountAggregateInputType? = nil,
+        _max: UserMaxAggregateInputType? = nil,
+        _min: UserMinAggregateInputType? = nil,
+        _sum: UserSumAggregateInputType? = nil,
+        cursor: UserWhereUniqueInput? = nil,
+        distinct: UserSerializableScalarFields? = nil,
+        having: UserScalarWhereWithAggregatesInput? = nil,
+        orderBy: Array<UserOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: UserWhereInput? = nil
+    ) {
+        self.by = by
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+        self.cursor = cursor
+        self.distinct = distinct
+        self.having = having
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **User group by result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserGroupByResult : Codable, Reflectable {
+    public let _avg: UserAvgAggregateResult?
+    public let _count: UserCountAggregateResult?
+    public let _max: UserMaxAggregateResult?
+    public let _min: UserMinAggregateResult?
+    public let _sum: UserSumAggregateResult?
+    public let balance: Decimal?
+    public let birthday: String?
+    public let createdAt: Date?
+    public let email: String?
+    public let id: Int32?
+    public let name: String?
+    public let sex: Sex?
+    public init(
+        _avg: UserAvgAggregateResult? = nil,
+        _count: UserCountAggregateResult? = nil,
+        _max: UserMaxAggregateResult? = nil,
+        _min: UserMinAggregateResult? = nil,
+        _sum: UserSumAggregateResult? = nil,
+        balance: Decimal? = nil,
+        birthday: String? = nil,
+        createdAt: Date? = nil,
+        email: String? = nil,
+        id: Int32? = nil,
+        name: String? = nil,
+        sex: Sex? = nil
+    ) {
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.email = email
+        self.id = id
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User include**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserInclude : Codable, Reflectable {
+    public let posts: PostFindManyArgsOrBool?
+    public init(
+        posts: PostFindManyArgsOrBool? = nil
+    ) {
+        self.posts = posts
+    }
+}
+/**
+ * **User list relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserListRelationFilter : Codable, Reflectable {
+    public let every: UserWhereInput?
+    public let none: UserWhereInput?
+    public let some: UserWhereInput?
+    public init(
+        every: UserWhereInput? = nil,
+        none: UserWhereInput? = nil,
+        some: UserWhereInput? = nil
+    ) {
+        self.every = every
+        self.none = none
+        self.some = some
+    }
+}
+/**
+ * **User max aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserMaxAggregateInputType : Codable, Reflectable {
+    public let balance: Bool?
+    public let birthday: Bool?
+    public let createdAt: Bool?
+    public let email: Bool?
+    public let id: Bool?
+    public let name: Bool?
+    public let sex: Bool?
+    public init(
+        balance: Bool? = nil,
+        birthday: Bool? = nil,
+        createdAt: Bool? = nil,
+        email: Bool? = nil,
+        id: Bool? = nil,
+        name: Bool? = nil,
+        sex: Bool? = nil
+    ) {
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.email = email
+        self.id = id
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User max aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserMaxAggregateResult : Codable, Reflectable {
+    public let balance: Decimal?
+    public let birthday: String?
+    public let createdAt: Date?
+    public let email: String?
+    public let id: Int32?
+    public let name: String?
+    public let sex: Sex?
+    public init(
+        balance: Decimal? = nil,
+        birthday: String? = nil,
+        createdAt: Date? = nil,
+        email: String? = nil,
+        id: Int32? = nil,
+        name: String? = nil,
+        sex: Sex? = nil
+    ) {
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.email = email
+        self.id = id
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User min aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserMinAggregateInputType : Codable, Reflectable {
+    public let balance: Bool?
+    public let birthday: Bool?
+    public let createdAt: Bool?
+    public let email: Bool?
+    public let id: Bool?
+    public let name: Bool?
+    public let sex: Bool?
+    public init(
+        balance: Bool? = nil,
+        birthday: Bool? = nil,
+        createdAt: Bool? = nil,
+        email: Bool? = nil,
+        id: Bool? = nil,
+        name: Bool? = nil,
+        sex: Bool? = nil
+    ) {
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.email = email
+        self.id = id
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User min aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserMinAggregateResult : Codable, Reflectable {
+    public let balance: Decimal?
+    public let birthday: String?
+    public let createdAt: Date?
+    public let email: String?
+    public let id: Int32?
+    public let name: String?
+    public let sex: Sex?
+    public init(
+        balance: Decimal? = nil,
+        birthday: String? = nil,
+        createdAt: Date? = nil,
+        email: String? = nil,
+        id: Int32? = nil,
+        name: String? = nil,
+        sex: Sex? = nil
+    ) {
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.email = email
+        self.id = id
+        self.name = name
+        self.sex = sex
+    }
+}
+public enum UserOrderByInput: Codable {
+    case balance(std.Sort)
+    case birthday(std.Sort)
+    case createdAt(std.Sort)
+    case email(std.Sort)
+    case id(std.Sort)
+    case name(std.Sort)
+    case sex(std.Sort)
+    enum CodingKeys: String, CodingKey {
+        case balance = "balance"
+        case birthday = "birthday"
+        case createdAt = "createdAt"
+        case email = "email"
+        case id = "id"
+        case name = "name"
+        case sex = "sex"
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .balance(let a0):
+            try container.encode(a0, forKey: .balance)
+        case .birthday(let a0):
+            try container.encode(a0, forKey: .birthday)
+        case .createdAt(let a0):
+            try container.encode(a0, forKey: .createdAt)
+        case .email(let a0):
+            try container.encode(a0, forKey: .email)
+        case .id(let a0):
+            try container.encode(a0, forKey: .id)
+        case .name(let a0):
+            try container.encode(a0, forKey: .name)
+        case .sex(let a0):
+            try container.encode(a0, forKey: .sex)
+        }
+    }
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        for key in container.allKeys {
+            switch key {
+            case .balance:
+                self = Self.balance(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .birthday:
+                self = Self.birthday(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .createdAt:
+                self = Self.createdAt(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .email:
+                self = Self.email(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .id:
+                self = Self.id(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .name:
+                self = Self.name(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .sex:
+                self = Self.sex(try container.decode(std.Sort.self, forKey: key))
+                return
+            }
+        }
+        fatalError("invalid input for enum value decoder")
+    }
+}
+/**
+ * **User relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserRelationFilter : Codable, Reflectable {
+    public let `is`: UserWhereInput?
+    public let isNot: UserWhereInput?
+    public init(
+        `is`: UserWhereInput? = nil,
+        isNot: UserWhereInput? = nil
+    ) {
+        self.is = `is`
+        self.isNot = isNot
+    }
+}
+/**
+ * **User scalar update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserScalarUpdateInput : Codable, Reflectable {
+    public let balance: Decimal?
+    public let birthday: String?
+    public let createdAt: Date?
+    public let email: String?
+    public let id: Int32?
+    public let name: String?
+    public let sex: Sex?
+    public init(
+        balance: Decimal? = nil,
+        birthday: String? = nil,
+        createdAt: Date? = nil,
+        email: String? = nil,
+        id: Int32? = nil,
+        name: String? = nil,
+        sex: Sex? = nil
+    ) {
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.email = email
+        self.id = id
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User scalar where with aggregates input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserScalarWhereWithAggregatesInput : Codable, Reflectable {
+    public let AND: Array<UserWhereInput>?
+    public let NOT: UserWhereInput?
+    public let OR: Array<UserWhereInput>?
+    public let balance: DecimalOrStdDecimalNullableWithAggregatesFilterDecimal??
+    public let birthday: DateOrStdNullableAggregatesFilterDate??
+    public let createdAt: DateTimeOrStdAggregatesFilterDateTime?
+    public let email: StringOrStdStringWithAggregatesFilter?
+    public let id: IntOrStdIntNumberWithAggregatesFilterInt?
+    public let name: StringOrStdStringNullableWithAggregatesFilter??
+    public let sex: SexOrStdEnumNullableWithAggregatesFilterSex??
+    public init(
+        AND: Array<UserWhereInput>? = nil,
+        NOT: UserWhereInput? = nil,
+        OR: Array<UserWhereInput>? = nil,
+        balance: DecimalOrStdDecimalNullableWithAggregatesFilterDecimal?? = nil,
+        birthday: DateOrStdNullableAggregatesFilterDate?? = nil,
+        createdAt: DateTimeOrStdAggregatesFilterDateTime? = nil,
+        email: StringOrStdStringWithAggregatesFilter? = nil,
+        id: IntOrStdIntNumberWithAggregatesFilterInt? = nil,
+        name: StringOrStdStringNullableWithAggregatesFilter?? = nil,
+        sex: SexOrStdEnumNullableWithAggregatesFilterSex?? = nil
+    ) {
+        self.AND = AND
+        self.NOT = NOT
+        self.OR = OR
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.email = email
+        self.id = id
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User select**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserSelect : Codable, Reflectable {
+    public let balance: Bool?
+    public let birthday: Bool?
+    public let createdAt: Bool?
+    public let displayName: Bool?
+    public let email: Bool?
+    public let id: Bool?
+    public let name: Bool?
+    public let sex: Bool?
+    public init(
+        balance: Bool? = nil,
+        birthday: Bool? = nil,
+        createdAt: Bool? = nil,
+        displayName: Bool? = nil,
+        email: Bool? = nil,
+        id: Bool? = nil,
+        name: Bool? = nil,
+        sex: Bool? = nil
+    ) {
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.displayName = displayName
+        self.email = email
+        self.id = id
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User sign in args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserSignInArgs : Codable, Reflectable {
+    public init(
+    ) {
+    }
+}
+/**
+ * **User sign in checker companions**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserSignInCheckerCompanions : Codable, Reflectable {
+    public init(
+    ) {
+    }
+}
+/**
+ * **User sign in checker ids**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserSignInCheckerIds : Codable, Reflectable {
+    public init(
+    ) {
+    }
+}
+/**
+ * **User sign in input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserSignInInput : Codable, Reflectable {
+    public let credentials: UserSignInArgs
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        credentials: UserSignInArgs,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.credentials = credentials
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User sum aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserSumAggregateInputType : Codable, Reflectable {
+    public let id: Bool?
+    public init(
+        id: Bool? = nil
+    ) {
+        self.id = id
+    }
+}
+/**
+ * **User sum aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserSumAggregateResult : Codable, Reflectable {
+    public let id: Int64?
+    public init(
+        id: Int64? = nil
+    ) {
+        self.id = id
+    }
+}
+/**
+ * **User update args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateArgs : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let update: UserUpdateInput
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        `where`: UserWhereUniqueInput,
+        update: UserUpdateInput,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.where = `where`
+        self.update = update
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateInput : Codable, Reflectable {
+    public let balance: Decimal?
+    public let birthday: String?
+    public let email: String?
+    public let name: String?
+    public let posts: PostUpdateNestedManyWithoutAuthorInput?
+    public let sex: Sex?
+    public init(
+        balance: Decimal? = nil,
+        birthday: String? = nil,
+        email: String? = nil,
+        name: String? = nil,
+        posts: PostUpdateNestedManyWithoutAuthorInput? = nil,
+        sex: Sex? = nil
+    ) {
+        self.balance = balance
+        self.birthday = birthday
+        self.email = email
+        self.name = name
+        self.posts = posts
+        self.sex = sex
+    }
+}
+/**
+ * **User update many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateManyArgs : Codable, Reflectable {
+    public let `where`: UserWhereInput
+    public let update: UserUpdateInput
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        `where`: UserWhereInput,
+        update: UserUpdateInput,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.where = `where`
+        self.update = update
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User update many with where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateManyWithWhereInput : Codable, Reflectable {
+    public let `where`: UserWhereInput
+    public let update: UserUpdateInput
+    public init(
+        `where`: UserWhereInput,
+        update: UserUpdateInput
+    ) {
+        self.where = `where`
+        self.update = update
+    }
+}
+/**
+ * **User update many with where without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateManyWithWhereWithoutPostsInput : Codable, Reflectable {
+    public let `where`: UserWhereInput
+    public let update: UserUpdateInput
+    public init(
+        `where`: UserWhereInput,
+        update: UserUpdateInput
+    ) {
+        self.where = `where`
+        self.update = update
+    }
+}
+/**
+ * **User update nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateNestedManyInput : Codable, Reflectable {
+    public let connect: Array<UserWhereUniqueInput>?
+    public let connectOrCreate: Array<UserConnectOrCreateInput>?
+    public let create: Array<UserCreateInput>?
+    public let delete: Array<UserWhereUniqueInput>?
+    public let deleteMany: Array<UserWhereInput>?
+    public let disconnect: Array<UserWhereUniqueInput>?
+    public let set: Array<UserWhereUniqueInput>?
+    public let update: Array<UserUpdateWithWhereUniqueInput>?
+    public let updateMany: Array<UserUpdateManyWithWhereInput>?
+    public let upsert: Array<UserUpsertWithWhereUniqueInput>?
+    public init(
+        connect: Array<UserWhereUniqueInput>? = nil,
+        connectOrCreate: Array<UserConnectOrCreateInput>? = nil,
+        create: Array<UserCreateInput>? = nil,
+        delete: Array<UserWhereUniqueInput>? = nil,
+        deleteMany: Array<UserWhereInput>? = nil,
+        disconnect: Array<UserWhereUniqueInput>? = nil,
+        set: Array<UserWhereUniqueInput>? = nil,
+        update: Array<UserUpdateWithWhereUniqueInput>? = nil,
+        updateMany: Array<UserUpdateManyWithWhereInput>? = nil,
+        upsert: Array<UserUpsertWithWhereUniqueInput>? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+        self.delete = delete
+        self.deleteMany = deleteMany
+        self.disconnect = disconnect
+        self.set = set
+        self.update = update
+        self.updateMany = updateMany
+        self.upsert = upsert
+    }
+}
+/**
+ * **User update nested many without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateNestedManyWithoutPostsInput : Codable, Reflectable {
+    public let connect: Array<UserWhereUniqueInput>?
+    public let connectOrCreate: Array<UserConnectOrCreateWithoutPostsInput>?
+    public let create: Array<UserCreateInput>?
+    public let delete: Array<UserWhereUniqueInput>?
+    public let deleteMany: Array<UserWhereInput>?
+    public let disconnect: Array<UserWhereUniqueInput>?
+    public let set: Array<UserWhereUniqueInput>?
+    public let update: Array<UserUpdateWithWhereUniqueWithoutPostsInput>?
+    public let updateMany: Array<UserUpdateManyWithWhereWithoutPostsInput>?
+    public let upsert: Array<UserUpsertWithWhereUniqueWithoutPostsInput>?
+    public init(
+        connect: Array<UserWhereUniqueInput>? = nil,
+        connectOrCreate: Array<UserConnectOrCreateWithoutPostsInput>? = nil,
+        create: Array<UserCreateInput>? = nil,
+        delete: Array<UserWhereUniqueInput>? = nil,
+        deleteMany: Array<UserWhereInput>? = nil,
+        disconnect: Array<UserWhereUniqueInput>? = nil,
+        set: Array<UserWhereUniqueInput>? = nil,
+        update: Array<UserUpdateWithWhereUniqueWithoutPostsInput>? = nil,
+        updateMany: Array<UserUpdateManyWithWhereWithoutPostsInput>? = nil,
+        upsert: Array<UserUpsertWithWhereUniqueWithoutPostsInput>? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+        self.delete = delete
+        self.deleteMany = deleteMany
+        self.disconnect = disconnect
+        self.set = set
+        self.update = update
+        self.updateMany = updateMany
+        self.upsert = upsert
+    }
+}
+/**
+ * **User update nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateNestedOneInput : Codable, Reflectable {
+    public let connect: UserWhereUniqueInput?
+    public let connectOrCreate: UserConnectOrCreateInput?
+    public let create: UserCreateInput?
+    public let delete: Bool?
+    public let disconnect: Bool?
+    public let set: UserWhereUniqueInput?
+    public let update: UserUpdateInput?
+    public let upsert: UserUpsertWithWhereUniqueInput?
+    public init(
+        connect: UserWhereUniqueInput? = nil,
+        connectOrCreate: UserConnectOrCreateInput? = nil,
+        create: UserCreateInput? = nil,
+        delete: Bool? = nil,
+        disconnect: Bool? = nil,
+        set: UserWhereUniqueInput? = nil,
+        update: UserUpdateInput? = nil,
+        upsert: UserUpsertWithWhereUniqueInput? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+        self.delete = delete
+        self.disconnect = disconnect
+        self.set = set
+        self.update = update
+        self.upsert = upsert
+    }
+}
+/**
+ * **User update nested one without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateNestedOneWithoutPostsInput : Codable, Reflectable {
+    public let connect: UserWhereUniqueInput?
+    public let connectOrCreate: UserConnectOrCreateWithoutPostsInput?
+    public let create: UserCreateInput?
+    public let delete: Bool?
+    public let disconnect: Bool?
+    public let set: UserWhereUniqueInput?
+    public let update: UserUpdateInput?
+    public let upsert: UserUpsertWithWhereUniqueWithoutPostsInput?
+    public init(
+        connect: UserWhereUniqueInput? = nil,
+        connectOrCreate: UserConnectOrCreateWithoutPostsInput? = nil,
+        create: UserCreateInput? = nil,
+        delete: Bool? = nil,
+        disconnect: Bool? = nil,
+        set: UserWhereUniqueInput? = nil,
+        update: UserUpdateInput? = nil,
+        upsert: UserUpsertWithWhereUniqueWithoutPostsInput? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+        self.delete = delete
+        self.disconnect = disconnect
+        self.set = set
+        self.update = update
+        self.upsert = upsert
+    }
+}
+/**
+ * **User update with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateWithWhereUniqueInput : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let update: UserUpdateInput
+    public init(
+        `where`: UserWhereUniqueInput,
+        update: UserUpdateInput
+    ) {
+        self.where = `where`
+        self.update = update
+    }
+}
+/**
+ * **User update with where unique without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateWithWhereUniqueWithoutPostsInput : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let update: UserUpdateInput
+    public init(
+        `where`: UserWhereUniqueInput,
+        update: UserUpdateInput
+    ) {
+        self.where = `where`
+        self.update = update
+    }
+}
+/**
+ * **User update without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpdateWithoutPostsInput : Codable, Reflectable {
+    public let balance: Decimal?
+    public let birthday: String?
+    public let email: String?
+    public let name: String?
+    public let sex: Sex?
+    public init(
+        balance: Decimal? = nil,
+        birthday: String? = nil,
+        email: String? = nil,
+        name: String? = nil,
+        sex: Sex? = nil
+    ) {
+        self.balance = balance
+        self.birthday = birthday
+        self.email = email
+        self.name = name
+        self.sex = sex
+    }
+}
+/**
+ * **User upsert args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpsertArgs : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let update: UserUpdateInput
+    public let create: UserCreateInput
+    public let include: UserInclude?
+    public let select: UserSelect?
+    public init(
+        `where`: UserWhereUniqueInput,
+        update: UserUpdateInput,
+        create: UserCreateInput,
+        include: UserInclude? = nil,
+        select: UserSelect? = nil
+    ) {
+        self.where = `where`
+        self.update = update
+        self.create = create
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **User upsert with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpsertWithWhereUniqueInput : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let update: UserUpdateInput
+    public let create: UserCreateInput
+    public init(
+        `where`: UserWhereUniqueInput,
+        update: UserUpdateInput,
+        create: UserCreateInput
+    ) {
+        self.where = `where`
+        self.update = update
+        self.create = create
+    }
+}
+/**
+ * **User upsert with where unique without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserUpsertWithWhereUniqueWithoutPostsInput : Codable, Reflectable {
+    public let `where`: UserWhereUniqueInput
+    public let update: UserUpdateInput
+    public let create: UserCreateInput
+    public init(
+        `where`: UserWhereUniqueInput,
+        update: UserUpdateInput,
+        create: UserCreateInput
+    ) {
+        self.where = `where`
+        self.update = update
+        self.create = create
+    }
+}
+/**
+ * **User where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserWhereInput : Codable, Reflectable {
+    public let AND: Array<UserWhereInput>?
+    public let NOT: UserWhereInput?
+    public let OR: Array<UserWhereInput>?
+    public let balance: DecimalOrStdNullableFilterDecimal??
+    public let birthday: DateOrStdNullableFilterDate??
+    public let createdAt: DateTimeOrStdFilterDateTime?
+    public let email: StringOrStdStringFilter?
+    public let id: IntOrStdFilterInt?
+    public let name: StringOrStdStringNullableFilter??
+    public let posts: PostListRelationFilter?
+    public let sex: SexOrStdEnumNullableFilterSex??
+    public init(
+        AND: Array<UserWhereInput>? = nil,
+        NOT: UserWhereInput? = nil,
+        OR: Array<UserWhereInput>? = nil,
+        balance: DecimalOrStdNullableFilterDecimal?? = nil,
+        birthday: DateOrStdNullableFilterDate?? = nil,
+        createdAt: DateTimeOrStdFilterDateTime? = nil,
+        email: StringOrStdStringFilter? = nil,
+        id: IntOrStdFilterInt? = nil,
+        name: StringOrStdStringNullableFilter?? = nil,
+        posts: PostListRelationFilter? = nil,
+        sex: SexOrStdEnumNullableFilterSex?? = nil
+    ) {
+        self.AND = AND
+        self.NOT = NOT
+        self.OR = OR
+        self.balance = balance
+        self.birthday = birthday
+        self.createdAt = createdAt
+        self.email = email
+        self.id = id
+        self.name = name
+        self.posts = posts
+        self.sex = sex
+    }
+}
+/**
+ * **User where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class UserWhereUniqueInput : Codable, Reflectable {
+    public let email: String?
+    public let id: Int32?
+    public init(
+        email: String? = nil,
+        id: Int32? = nil
+    ) {
+        self.email = email
+        self.id = id
+    }
+}
+/// ## StringOrInt
+///
+/// One of `String`, `Int32`.
+public enum StringOrInt: Codable {
+    case string(String)
+    case int(Int32)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Int32.self) {
+            self = .int(value)
+            return
+        }
+        if let value = try? container.decode(String.self) {
+            self = .string(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrInt")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .string(let value):
+            try container.encode(value)
+        case .int(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## UserArgsOrBool
+///
+/// One of `UserArgs`, `Bool`.
+public enum UserArgsOrBool: Codable {
+    case userArgs(UserArgs)
+    case bool(Bool)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+            return
+        }
+        if let value = try? container.decode(UserArgs.self) {
+            self = .userArgs(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of UserArgsOrBool")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .userArgs(let value):
+            try container.encode(value)
+        case .bool(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## IntOrStdIntNumberWithAggregatesFilterInt
+///
+/// One of `Int32`, `std.IntNumberWithAggregatesFilter<Int32>`.
+public enum IntOrStdIntNumberWithAggregatesFilterInt: Codable {
+    case int(Int32)
+    case stdIntNumberWithAggregatesFilterInt(std.IntNumberWithAggregatesFilter<Int32>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Int32.self) {
+            self = .int(value)
+            return
+        }
+        if let value = try? container.decode(std.IntNumberWithAggregatesFilter<Int32>.self) {
+            self = .stdIntNumberWithAggregatesFilterInt(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of IntOrStdIntNumberWithAggregatesFilterInt")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .int(let value):
+            try container.encode(value)
+        case .stdIntNumberWithAggregatesFilterInt(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## StringOrStdStringNullableWithAggregatesFilter
+///
+/// One of `String`, `std.StringNullableWithAggregatesFilter`.
+public enum StringOrStdStringNullableWithAggregatesFilter: Codable {
+    case string(String)
+    case stdStringNullableWithAggregatesFilter(std.StringNullableWithAggregatesFilter)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(String.self) {
+            self = .string(value)
+            return
+        }
+        if let value = try? container.decode(std.StringNullableWithAggregatesFilter.self) {
+            self = .stdStringNullableWithAggregatesFilter(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringNullableWithAggregatesFilter")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .string(let value):
+            try container.encode(value)
+        case .stdStringNullableWithAggregatesFilter(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## StringArrayOrStdArrayWithAggregatesFilterString
+///
+/// One of `Array<String>`, `std.ArrayWithAggregatesFilter<String>`.
+public enum StringArrayOrStdArrayWithAggregatesFilterString: Codable {
+    case stringArray(Array<String>)
+    case stdArrayWithAggregatesFilterString(std.ArrayWithAggregatesFilter<String>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Array<String>.self) {
+            self = .stringArray(value)
+            return
+        }
+        if let value = try? container.decode(std.ArrayWithAggregatesFilter<String>.self) {
+            self = .stdArrayWithAggregatesFilterString(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringArrayOrStdArrayWithAggregatesFilterString")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .stringArray(let value):
+            try container.encode(value)
+        case .stdArrayWithAggregatesFilterString(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## StringOrStdStringWithAggregatesFilter
+///
+/// One of `String`, `std.StringWithAggregatesFilter`.
+public enum StringOrStdStringWithAggregatesFilter: Codable {
+    case string(String)
+    case stdStringWithAggregatesFilter(std.StringWithAggregatesFilter)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(String.self) {
+            self = .string(value)
+            return
+        }
+        if let value = try? container.decode(std.StringWithAggregatesFilter.self) {
+            self = .stdStringWithAggregatesFilter(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringWithAggregatesFilter")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .string(let value):
+            try container.encode(value)
+        case .stdStringWithAggregatesFilter(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## Int64OrStdIntNumberWithAggregatesFilterInt64
+///
+/// One of `Int64`, `std.IntNumberWithAggregatesFilter<Int64>`.
+public enum Int64OrStdIntNumberWithAggregatesFilterInt64: Codable {
+    case int64(Int64)
+    case stdIntNumberWithAggregatesFilterInt64(std.IntNumberWithAggregatesFilter<Int64>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Int64.self) {
+            self = .int64(value)
+            return
+        }
+        if let value = try? container.decode(std.IntNumberWithAggregatesFilter<Int64>.self) {
+            self = .stdIntNumberWithAggregatesFilterInt64(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of Int64OrStdIntNumberWithAggregatesFilterInt64")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .int64(let value):
+            try container.encode(value)
+        case .stdIntNumberWithAggregatesFilterInt64(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## IntOrStdFilterInt
+///
+/// One of `Int32`, `std.Filter<Int32>`.
+public enum IntOrStdFilterInt: Codable {
+    case int(Int32)
+    case stdFilterInt(std.Filter<Int32>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Int32.self) {
+            self = .int(value)
+            return
+        }
+        if let value = try? container.decode(std.Filter<Int32>.self) {
+            self = .stdFilterInt(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of IntOrStdFilterInt")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .int(let value):
+            try container.encode(value)
+        case .stdFilterInt(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## StringOrStdStringNullableFilter
+///
+/// One of `String`, `std.StringNullableFilter`.
+public enum StringOrStdStringNullableFilter: Codable {
+    case string(String)
+    case stdStringNullableFilter(std.StringNullableFilter)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(String.self) {
+            self = .string(value)
+            return
+        }
+        if let value = try? container.decode(std.StringNullableFilter.self) {
+            self = .stdStringNullableFilter(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringNullableFilter")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .string(let value):
+            try container.encode(value)
+        case .stdStringNullableFilter(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## StringArrayOrStdArrayFilterString
+///
+/// One of `Array<String>`, `std.ArrayFilter<String>`.
+public enum StringArrayOrStdArrayFilterString: Codable {
+    case stringArray(Array<String>)
+    case stdArrayFilterString(std.ArrayFilter<String>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Array<String>.self) {
+            self = .stringArray(value)
+            return
+        }
+        if let value = try? container.decode(std.ArrayFilter<String>.self) {
+            self = .stdArrayFilterString(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringArrayOrStdArrayFilterString")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .stringArray(let value):
+            try container.encode(value)
+        case .stdArrayFilterString(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## StringOrStdStringFilter
+///
+/// One of `String`, `std.StringFilter`.
+public enum StringOrStdStringFilter: Codable {
+    case string(String)
+    case stdStringFilter(std.StringFilter)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(String.self) {
+            self = .string(value)
+            return
+        }
+        if let value = try? container.decode(std.StringFilter.self) {
+            self = .stdStringFilter(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringFilter")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .string(let value):
+            try container.encode(value)
+        case .stdStringFilter(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## Int64OrStdFilterInt64
+///
+/// One of `Int64`, `std.Filter<Int64>`.
+public enum Int64OrStdFilterInt64: Codable {
+    case int64(Int64)
+    case stdFilterInt64(std.Filter<Int64>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Int64.self) {
+            self = .int64(value)
+            return
+        }
+        if let value = try? container.decode(std.Filter<Int64>.self) {
+            self = .stdFilterInt64(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of Int64OrStdFilterInt64")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .int64(let value):
+            try container.encode(value)
+        case .stdFilterInt64(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## PostFindManyArgsOrBool
+///
+/// One of `PostFindManyArgs`, `Bool`.
+public enum PostFindManyArgsOrBool: Codable {
+    case postFindManyArgs(PostFindManyArgs)
+    case bool(Bool)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+            return
+        }
+        if let value = try? container.decode(PostFindManyArgs.self) {
+            self = .postFindManyArgs(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of PostFindManyArgsOrBool")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .postFindManyArgs(let value):
+            try container.encode(value)
+        case .bool(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## DecimalOrStdDecimalNullableWithAggregatesFilterDecimal
+///
+/// One of `Decimal`, `std.DecimalNullableWithAggregatesFilter<Decimal>`.
+public enum DecimalOrStdDecimalNullableWithAggregatesFilterDecimal: Codable {
+    case decimal(Decimal)
+    case stdDecimalNullableWithAggregatesFilterDecimal(std.DecimalNullableWithAggregatesFilter<Decimal>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Decimal.self) {
+            self = .decimal(value)
+            return
+        }
+        if let value = try? container.decode(std.DecimalNullableWithAggregatesFilter<Decimal>.self) {
+            self = .stdDecimalNullableWithAggregatesFilterDecimal(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of DecimalOrStdDecimalNullableWithAggregatesFilterDecimal")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .decimal(let value):
+            try container.encode(value)
+        case .stdDecimalNullableWithAggregatesFilterDecimal(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## DateOrStdNullableAggregatesFilterDate
+///
+/// One of `String`, `std.NullableAggregatesFilter<String>`.
+public enum DateOrStdNullableAggregatesFilterDate: Codable {
+    case date(String)
+    case stdNullableAggregatesFilterDate(std.NullableAggregatesFilter<String>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(String.self) {
+            self = .date(value)
+            return
+        }
+        if let value = try? container.decode(std.NullableAggregatesFilter<String>.self) {
+            self = .stdNullableAggregatesFilterDate(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of DateOrStdNullableAggregatesFilterDate")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .date(let value):
+            try container.encode(value)
+        case .stdNullableAggregatesFilterDate(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## DateTimeOrStdAggregatesFilterDateTime
+///
+/// One of `Date`, `std.AggregatesFilter<Date>`.
+public enum DateTimeOrStdAggregatesFilterDateTime: Codable {
+    case dateTime(Date)
+    case stdAggregatesFilterDateTime(std.AggregatesFilter<Date>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Date.self) {
+            self = .dateTime(value)
+            return
+        }
+        if let value = try? container.decode(std.AggregatesFilter<Date>.self) {
+            self = .stdAggregatesFilterDateTime(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of DateTimeOrStdAggregatesFilterDateTime")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .dateTime(let value):
+            try container.encode(value)
+        case .stdAggregatesFilterDateTime(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## SexOrStdEnumNullableWithAggregatesFilterSex
+///
+/// One of `Sex`, `std.EnumNullableWithAggregatesFilter<Sex>`.
+public enum SexOrStdEnumNullableWithAggregatesFilterSex: Codable {
+    case sex(Sex)
+    case stdEnumNullableWithAggregatesFilterSex(std.EnumNullableWithAggregatesFilter<Sex>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Sex.self) {
+            self = .sex(value)
+            return
+        }
+        if let value = try? container.decode(std.EnumNullableWithAggregatesFilter<Sex>.self) {
+            self = .stdEnumNullableWithAggregatesFilterSex(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of SexOrStdEnumNullableWithAggregatesFilterSex")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .sex(let value):
+            try container.encode(value)
+        case .stdEnumNullableWithAggregatesFilterSex(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## DecimalOrStdNullableFilterDecimal
+///
+/// One of `Decimal`, `std.NullableFilter<Decimal>`.
+public enum DecimalOrStdNullableFilterDecimal: Codable {
+    case decimal(Decimal)
+    case stdNullableFilterDecimal(std.NullableFilter<Decimal>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Decimal.self) {
+            self = .decimal(value)
+            return
+        }
+        if let value = try? container.decode(std.NullableFilter<Decimal>.self) {
+            self = .stdNullableFilterDecimal(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of DecimalOrStdNullableFilterDecimal")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .decimal(let value):
+            try container.encode(value)
+        case .stdNullableFilterDecimal(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## DateOrStdNullableFilterDate
+///
+/// One of `String`, `std.NullableFilter<String>`.
+public enum DateOrStdNullableFilterDate: Codable {
+    case date(String)
+    case stdNullableFilterDate(std.NullableFilter<String>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(String.self) {
+            self = .date(value)
+            return
+        }
+        if let value = try? container.decode(std.NullableFilter<String>.self) {
+            self = .stdNullableFilterDate(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of DateOrStdNullableFilterDate")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .date(let value):
+            try container.encode(value)
+        case .stdNullableFilterDate(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## DateTimeOrStdFilterDateTime
+///
+/// One of `Date`, `std.Filter<Date>`.
+public enum DateTimeOrStdFilterDateTime: Codable {
+    case dateTime(Date)
+    case stdFilterDateTime(std.Filter<Date>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Date.self) {
+            self = .dateTime(value)
+            return
+        }
+        if let value = try? container.decode(std.Filter<Date>.self) {
+            self = .stdFilterDateTime(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of DateTimeOrStdFilterDateTime")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .dateTime(let value):
+            try container.encode(value)
+        case .stdFilterDateTime(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## SexOrStdEnumNullableFilterSex
+///
+/// One of `Sex`, `std.EnumNullableFilter<Sex>`.
+public enum SexOrStdEnumNullableFilterSex: Codable {
+    case sex(Sex)
+    case stdEnumNullableFilterSex(std.EnumNullableFilter<Sex>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Sex.self) {
+            self = .sex(value)
+            return
+        }
+        if let value = try? container.decode(std.EnumNullableFilter<Sex>.self) {
+            self = .stdEnumNullableFilterSex(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of SexOrStdEnumNullableFilterSex")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .sex(let value):
+            try container.encode(value)
+        case .stdEnumNullableFilterSex(let value):
+            try container.encode(value)
+        }
+    }
+}
+public class PostDelegate {
+    public let _headers: Dictionary<String, String>?
+    public init(_ headers: Dictionary<String, String>? = nil) {
+        self._headers = headers
+    }
+    public func findUnique(input: PostFindUniqueArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Post> {
+      return try await jsonRequest("POST", "Post/findUnique", input, headers ?? _headers, nil)
+    }
+    public func findFirst(input: PostFindFirstArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Post> {
+      return try await jsonRequest("POST", "Post/findFirst", input, headers ?? _headers, nil)
+    }
+    public func findMany(input: PostFindManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<Post>, std.PagingInfo> {
+      return try await jsonRequest("POST", "Post/findMany", input, headers ?? _headers, nil)
+    }
+    public func create(input: PostCreateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Post> {
+      return try await jsonRequest("POST", "Post/create", input, headers ?? _headers, nil)
+    }
+    public func update(input: PostUpdateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Post> {
+      return try await jsonRequest("POST", "Post/update", input, headers ?? _headers, nil)
+    }
+    public func upsert(input: PostUpsertArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Post> {
+      return try await jsonRequest("POST", "Post/upsert", input, headers ?? _headers, nil)
+    }
+    public func copy(input: PostCopyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Post> {
+      return try await jsonRequest("POST", "Post/copy", input, headers ?? _headers, nil)
+    }
+    public func delete(input: PostDeleteArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Post> {
+      return try await jsonRequest("POST", "Post/delete", input, headers ?? _headers, nil)
+    }
+    public func createMany(input: PostCreateManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<Post>, std.PagingInfo> {
+      return try await jsonRequest("POST", "Post/createMany", input, headers ?? _headers, nil)
+    }
+    public func updateMany(input: PostUpdateManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<Post>, std.PagingInfo> {
+      return try await jsonRequest("POST", "Post/updateMany", input, headers ?? _headers, nil)
+    }
+    public func copyMany(input: PostCopyManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<Post>, std.PagingInfo> {
+      return try await jsonRequest("POST", "Post/copyMany", input, headers ?? _headers, nil)
+    }
+    public func deleteMany(input: PostDeleteManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<Post>, std.PagingInfo> {
+      return try await jsonRequest("POST", "Post/deleteMany", input, headers ?? _headers, nil)
+    }
+    public func count(input: PostCountArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Int64> {
+      return try await jsonRequest("POST", "Post/count", input, headers ?? _headers, nil)
+    }
+    public func aggregate(input: PostAggregateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<PostAggregateResult> {
+      return try await jsonRequest("POST", "Post/aggregate", input, headers ?? _headers, nil)
+    }
+    public func groupBy(input: PostGroupByArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<PostGroupByResult> {
+      return try await jsonRequest("POST", "Post/groupBy", input, headers ?? _headers, nil)
+    }
+}
+
+public class UserDelegate {
+    public let _headers: Dictionary<String, String>?
+    public init(_ headers: Dictionary<String, String>? = nil) {
+        self._headers = headers
+    }
+    public func findUnique(input: UserFindUniqueArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<User> {
+      return try await jsonRequest("POST", "User/findUnique", input, headers ?? _headers, nil)
+    }
+    public func findFirst(input: UserFindFirstArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<User> {
+      return try await jsonRequest("POST", "User/findFirst", input, headers ?? _headers, nil)
+    }
+    public func findMany(input: UserFindManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<User>, std.PagingInfo> {
+      return try await jsonRequest("POST", "User/findMany", input, headers ?? _headers, nil)
+    }
+    public func create(input: UserCreateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<User> {
+      return try await jsonRequest("POST", "User/create", input, headers ?? _headers, nil)
+    }
+    public func update(input: UserUpdateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<User> {
+      return try await jsonRequest("POST", "User/update", input, headers ?? _headers, nil)
+    }
+    public func upsert(input: UserUpsertArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<User> {
+      return try await jsonRequest("POST", "User/upsert", input, headers ?? _headers, nil)
+    }
+    public func copy(input: UserCopyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<User> {
+      return try await jsonRequest("POST", "User/copy", input, headers ?? _headers, nil)
+    }
+    public func delete(input: UserDeleteArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<User> {
+      return try await jsonRequest("POST", "User/delete", input, headers ?? _headers, nil)
+    }
+    public func createMany(input: UserCreateManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<User>, std.PagingInfo> {
+      return try await jsonRequest("POST", "User/createMany", input, headers ?? _headers, nil)
+    }
+    public func updateMany(input: UserUpdateManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<User>, std.PagingInfo> {
+      return try await jsonRequest("POST", "User/updateMany", input, headers ?? _headers, nil)
+    }
+    public func copyMany(input: UserCopyManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<User>, std.PagingInfo> {
+      return try await jsonRequest("POST", "User/copyMany", input, headers ?? _headers, nil)
+    }
+    public func deleteMany(input: UserDeleteManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<User>, std.PagingInfo> {
+      return try await jsonRequest("POST", "User/deleteMany", input, headers ?? _headers, nil)
+    }
+    public func count(input: UserCountArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Int64> {
+      return try await jsonRequest("POST", "User/count", input, headers ?? _headers, nil)
+    }
+    public func aggregate(input: UserAggregateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<UserAggregateResult> {
+      return try await jsonRequest("POST", "User/aggregate", input, headers ?? _headers, nil)
+    }
+    public func groupBy(input: UserGroupByArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<UserGroupByResult> {
+      return try await jsonRequest("POST", "User/groupBy", input, headers ?? _headers, nil)
+    }
+}
+
+public class Teo {
+    public let _headers: Dictionary<String, String>?
+    lazy var post: PostDelegate = { PostDelegate(_headers) }();
+    lazy var user: UserDelegate = { UserDelegate(_headers) }();
+    public init(_ headers: Dictionary<String, String>? = nil) {
+        self._headers = headers
+    }
+}
+
+public struct std {
+
+    /// ## Sort Order
+    ///
+    /// Represents the sort order
+    public enum Sort: String, Codable {
+
+        /// ### Asc
+        ///
+        /// This enum member doesn't have a description.
+        case asc = "asc"
+
+        /// ### Desc
+        ///
+        /// This enum member doesn't have a description.
+        case desc = "desc"
+    }
+    /// ## String Match Mode
+    ///
+    /// Whether the string query is case sensitive or not
+    public enum StringMatchMode: String, Codable {
+
+        /// ### Default
+        ///
+        /// This enum member doesn't have a description.
+        case `default` = "default"
+
+        /// ### Case insensitive
+        ///
+        /// This enum member doesn't have a description.
+        case caseInsensitive = "caseInsensitive"
+    }
+    /**
+     * **Aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class AggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.Filter<T>?
+        public let _max: std.Filter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.Filter<T>? = nil,
+            _max: std.Filter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    public enum ArrayAtomicUpdateOperationInput<T> : Codable where T: Codable {
+        case push(T)
+        enum CodingKeys: String, CodingKey {
+            case push = "push"
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.container(keyedBy: ArrayAtomicUpdateOperationInput<T>.CodingKeys.self)
+            switch self {
+            case .push(let a0):
+                try container.encode(a0, forKey: .push)
+            }
+        }
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.container(keyedBy: CodingKeys.self)
+            for key in container.allKeys {
+                switch key {
+                case .push:
+                    self = Self.push(try container.decode(T.self, forKey: key))
+                    return
+                }
+            }
+            fatalError("invalid input for enum value decoder")
+        }
+    }
+    /**
+     * **Array filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class ArrayFilter<T> : Codable, Reflectable where T: Codable {
+        public let equals: Array<T>?
+        public let has: T?
+        public let hasSome: Array<T>?
+        public let hasEvery: Array<T>?
+        public let isEmpty: Bool?
+        public let length: Int32?
+        public init(
+            equals: Array<T>? = nil,
+            has: T? = nil,
+            hasSome: Array<T>? = nil,
+            hasEvery: Array<T>? = nil,
+            isEmpty: Bool? = nil,
+            length: Int32? = nil
+        ) {
+            self.equals = equals
+            self.has = has
+            self.hasSome = hasSome
+            self.hasEvery = hasEvery
+            self.isEmpty = isEmpty
+            self.length = length
+        }
+    }
+    /**
+     * **Array nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class ArrayNullableFilter<T> : Codable, Reflectable where T: Codable {
+        public let equals: Array<T>??
+        public let has: T?
+        public let hasSome: Array<T>?
+        public let hasEvery: Array<T>?
+        public let isEmpty: Bool?
+        public let length: Int32?
+        public init(
+            equals: Array<T>?? = nil,
+            has: T? = nil,
+            hasSome: Array<T>? = nil,
+            hasEvery: Array<T>? = nil,
+            isEmpty: Bool? = nil,
+            length: Int32? = nil
+        ) {
+            self.equals = equals
+            self.has = has
+            self.hasSome = hasSome
+            self.hasEvery = hasEvery
+            self.isEmpty = isEmpty
+            self.length = length
+        }
+    }
+    /**
+     * **Array nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class ArrayNullableWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.ArrayNullableFilter<T>?
+        public let _max: std.ArrayNullableFilter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.ArrayNullableFilter<T>? = nil,
+            _max: std.ArrayNullableFilter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Array with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class ArrayWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.ArrayFilter<T>?
+        public let _max: std.ArrayFilter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.ArrayFilter<T>? = nil,
+            _max: std.ArrayFilter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Bool filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class BoolFilter : Codable, Reflectable {
+        public let equals: Bool?
+        public let not: BoolOrStdBoolFilter?
+        public init(
+            equals: Bool? = nil,
+            not: BoolOrStdBoolFilter? = nil
+        ) {
+            self.equals = equals
+            self.not = not
+        }
+    }
+    /**
+     * **Bool nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class BoolNullableFilter : Codable, Reflectable {
+        public let equals: Bool??
+        public let not: BoolOrStdBoolNullableFilter?
+        public init(
+            equals: Bool?? = nil,
+            not: BoolOrStdBoolNullableFilter? = nil
+        ) {
+            self.equals = equals
+            self.not = not
+        }
+    }
+    /**
+     * **Bool nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class BoolNullableWithAggregatesFilter : Codable, Reflectable {
+        public let _count: Int64?
+        public let _min: std.BoolNullableFilter?
+        public let _max: std.BoolNullableFilter?
+        public init(
+            _count: Int64? = nil,
+            _min: std.BoolNullableFilter? = nil,
+            _max: std.BoolNullableFilter? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Bool with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class BoolWithAggregatesFilter : Codable, Reflectable {
+        public let _count: Int64?
+        public let _min: std.BoolFilter?
+        public let _max: std.BoolFilter?
+        public init(
+            _count: Int64? = nil,
+            _min: std.BoolFilter? = nil,
+            _max: std.BoolFilter? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Data**
+     *
+     * This interface is common for action output
+     */
+    public class Data<T> : Codable, Reflectable where T: Codable {
+        public let data: T
+        public init(
+            data: T
+        ) {
+            self.data = data
+        }
+    }
+    /**
+     * **Data and Meta**
+     *
+     * This interface is common for action output with meta information
+     */
+    public class DataMeta<T, U> : Codable, Reflectable where T: Codable, U: Codable {
+        public let meta: U
+        public let data: T
+        public init(
+            meta: U,
+            data: T
+        ) {
+            self.meta = meta
+            self.data = data
+        }
+    }
+    /**
+     * **Decimal nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class DecimalNullableWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.NullableFilter<Decimal>?
+        public let _max: std.NullableFilter<Decimal>?
+        public let _avg: std.NullableFilter<Decimal>?
+        public let _sum: std.NullableFilter<Decimal>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.NullableFilter<Decimal>? = nil,
+            _max: std.NullableFilter<Decimal>? = nil,
+            _avg: std.NullableFilter<Decimal>? = nil,
+            _sum: std.NullableFilter<Decimal>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Decimal with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class DecimalWithAggregatesFilter : Codable, Reflectable {
+        public let _count: Int64?
+        public let _min: std.Filter<Decimal>?
+        public let _max: std.Filter<Decimal>?
+        public let _avg: std.Filter<Decimal>?
+        public let _sum: std.Filter<Decimal>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.Filter<Decimal>? = nil,
+            _max: std.Filter<Decimal>? = nil,
+            _avg: std.Filter<Decimal>? = nil,
+            _sum: std.Filter<Decimal>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Empty**
+     *
+     * The empty interface
+     */
+    public class Empty : Codable, Reflectable {
+        public init(
+        ) {
+        }
+    }
+    /**
+     * **Enum filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class EnumFilter<T> : Codable, Reflectable where T: Codable {
+        public let equals: T?
+        public let `in`: Array<T>?
+        public let notIn: Array<T>?
+        public let not: TOrStdEnumFilterT?
+        public init(
+            equals: T? = nil,
+            `in`: Array<T>? = nil,
+            notIn: Array<T>? = nil,
+            not: TOrStdEnumFilterT? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.not = not
+        }
+    }
+    /**
+     * **Enum nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class EnumNullableFilter<T> : Codable, Reflectable where T: Codable {
+        public let equals: T??
+        public let `in`: Array<T?>?
+        public let notIn: Array<T?>?
+        public let not: TOrStdEnumNullableFilterT?
+        public init(
+            equals: T?? = nil,
+            `in`: Array<T?>? = nil,
+            notIn: Array<T?>? = nil,
+            not: TOrStdEnumNullableFilterT? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.not = not
+        }
+    }
+    /**
+     * **Enum nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class EnumNullableWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.EnumNullableFilter<T>?
+        public let _max: std.EnumNullableFilter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.EnumNullableFilter<T>? = nil,
+            _max: std.EnumNullableFilter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Enum with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class EnumWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.EnumFilter<T>?
+        public let _max: std.EnumFilter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.EnumFilter<T>? = nil,
+            _max: std.EnumFilter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class Filter<T> : Codable, Reflectable where T: Codable {
+        public let equals: T?
+        public let `in`: Array<T>?
+        public let notIn: Array<T>?
+        public let lt: T?
+        public let lte: T?
+        public let gt: T?
+        public let gte: T?
+        public let not: TOrStdFilterT?
+        public init(
+            equals: T? = nil,
+            `in`: Array<T>? = nil,
+            notIn: Array<T>? = nil,
+            lt: T? = nil,
+            lte: T? = nil,
+            gt: T? = nil,
+            gte: T? = nil,
+            not: TOrStdFilterT? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.lt = lt
+            self.lte = lte
+            self.gt = gt
+            self.gte = gte
+            self.not = not
+        }
+    }
+    /**
+     * **Float number nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class FloatNumberNullableWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.NullableFilter<T>?
+        public let _max: std.NullableFilter<T>?
+        public let _avg: std.NullableFilter<Double>?
+        public let _sum: std.NullableFilter<Double>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.NullableFilter<T>? = nil,
+            _max: std.NullableFilter<T>? = nil,
+            _avg: std.NullableFilter<Double>? = nil,
+            _sum: std.NullableFilter<Double>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Float number with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class FloatNumberWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.Filter<T>?
+        public let _max: std.Filter<T>?
+        public let _avg: std.Filter<Double>?
+        public let _sum: std.Filter<Double>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.Filter<T>? = nil,
+            _max: std.Filter<T>? = nil,
+            _avg: std.Filter<Double>? = nil,
+            _sum: std.Filter<Double>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Int number nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class IntNumberNullableWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.NullableFilter<T>?
+        public let _max: std.NullableFilter<T>?
+        public let _avg: std.NullableFilter<Double>?
+        public let _sum: std.NullableFilter<Int64>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.NullableFilter<T>? = nil,
+            _max: std.NullableFilter<T>? = nil,
+            _avg: std.NullableFilter<Double>? = nil,
+            _sum: std.NullableFilter<Int64>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Int number with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class IntNumberWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.Filter<T>?
+        public let _max: std.Filter<T>?
+        public let _avg: std.Filter<Double>?
+        public let _sum: std.Filter<Int64>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.Filter<T>? = nil,
+            _max: std.Filter<T>? = nil,
+            _avg: std.Filter<Double>? = nil,
+            _sum: std.Filter<Int64>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Nullable aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class NullableAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.NullableFilter<T>?
+        public let _max: std.NullableFilter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.NullableFilter<T>? = nil,
+            _max: std.NullableFilter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class NullableFilter<T> : Codable, Reflectable where T: Codable {
+        public let equals: T??
+        public let `in`: Array<T?>?
+        public let notIn: Array<T?>?
+        public let lt: T?
+        public let lte: T?
+        public let gt: T?
+        public let gte: T?
+        public let not: TOrStdNullableFilterT?
+        public init(
+            equals: T?? = nil,
+            `in`: Array<T?>? = nil,
+            notIn: Array<T?>? = nil,
+            lt: T? = nil,
+            lte: T? = nil,
+            gt: T? = nil,
+            gte: T? = nil,
+            not: TOrStdNullableFilterT? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.lt = lt
+            self.lte = lte
+            self.gt = gt
+            self.gte = gte
+            self.not = not
+        }
+    }
+    public enum NumberAtomicUpdateOperationInput<T> : Codable where T: Codable {
+        case increment(T)
+        case decrement(T)
+        case multiply(T)
+        case divide(T)
+        enum CodingKeys: String, CodingKey {
+            case increment = "increment"
+            case decrement = "decrement"
+            case multiply = "multiply"
+            case divide = "divide"
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.container(keyedBy: NumberAtomicUpdateOperationInput<T>.CodingKeys.self)
+            switch self {
+            case .increment(let a0):
+                try container.encode(a0, forKey: .increment)
+            case .decrement(let a0):
+                try container.encode(a0, forKey: .decrement)
+            case .multiply(let a0):
+                try container.encode(a0, forKey: .multiply)
+            case .divide(let a0):
+                try container.encode(a0, forKey: .divide)
+            }
+        }
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.container(keyedBy: CodingKeys.self)
+            for key in container.allKeys {
+                switch key {
+                case .increment:
+                    self = Self.increment(try container.decode(T.self, forKey: key))
+                    return
+                case .decrement:
+                    self = Self.decrement(try container.decode(T.self, forKey: key))
+                    return
+                case .multiply:
+                    self = Self.multiply(try container.decode(T.self, forKey: key))
+                    return
+                case .divide:
+                    self = Self.divide(try container.decode(T.self, forKey: key))
+                    return
+                }
+            }
+            fatalError("invalid input for enum value decoder")
+        }
+    }
+    /**
+     * **Paging info**
+     *
+     * This interface doesn't have a description.
+     */
+    public class PagingInfo : Codable, Reflectable {
+        public let count: Int64
+        public let numberOfPages: Int64?
+        public init(
+            count: Int64,
+            numberOfPages: Int64? = nil
+        ) {
+            self.count = count
+            self.numberOfPages = numberOfPages
+        }
+    }
+    /**
+     * **Response error**
+     *
+     * This interface doesn't have a description.
+     */
+    public class ResponseError : Codable, Reflectable {
+        public let fields: Dictionary<String, String>?
+        public let message: String
+        public let type: String
+        public init(
+            fields: Dictionary<String, String>?,
+            message: String,
+            type: String
+        ) {
+            self.fields = fields
+            self.message = message
+            self.type = type
+        }
+    }
+    /**
+     * **String filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class StringFilter : Codable, Reflectable {
+        public let equals: String?
+        public let `in`: Array<String>?
+        public let notIn: Array<String>?
+        public let lt: String?
+        public let lte: String?
+        public let gt: String?
+        public let gte: String?
+        public let contains: String?
+        public let startsWith: String?
+        public let endsWith: String?
+        public let matches: String?
+        public let mode: std.StringMatchMode?
+        public let not: StringOrStdStringFilter?
+        public init(
+            equals: String? = nil,
+            `in`: Array<String>? = nil,
+            notIn: Array<String>? = nil,
+            lt: String? = nil,
+            lte: String? = nil,
+            gt: String? = nil,
+            gte: String? = nil,
+            contains: String? = nil,
+            startsWith: String? = nil,
+            endsWith: String? = nil,
+            matches: String? = nil,
+            mode: std.StringMatchMode? = nil,
+            not: StringOrStdStringFilter? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.lt = lt
+            self.lte = lte
+            self.gt = gt
+            self.gte = gte
+            self.contains = contains
+            self.startsWith = startsWith
+            self.endsWith = endsWith
+            self.matches = matches
+            self.mode = mode
+            self.not = not
+        }
+    }
+    /**
+     * **String nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class StringNullableFilter : Codable, Reflectable {
+        public let equals: String??
+        public let `in`: Array<String?>?
+        public let notIn: Array<String?>?
+        public let lt: String?
+        public let lte: String?
+        public let gt: String?
+        public let gte: String?
+        public let contains: String?
+        public let startsWith: String?
+        public let endsWith: String?
+        public let matches: String?
+        public let mode: std.StringMatchMode?
+        public let not: StringOrStdStringNullableFilter?
+        public init(
+            equals: String?? = nil,
+            `in`: Array<String?>? = nil,
+            notIn: Array<String?>? = nil,
+            lt: String? = nil,
+            lte: String? = nil,
+            gt: String? = nil,
+            gte: String? = nil,
+            contains: String? = nil,
+            startsWith: String? = nil,
+            endsWith: String? = nil,
+            matches: String? = nil,
+            mode: std.StringMatchMode? = nil,
+            not: StringOrStdStringNullableFilter? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.lt = lt
+            self.lte = lte
+            self.gt = gt
+            self.gte = gte
+            self.contains = contains
+            self.startsWith = startsWith
+            self.endsWith = endsWith
+            self.matches = matches
+            self.mode = mode
+            self.not = not
+        }
+    }
+    /**
+     * **String nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class StringNullableWithAggregatesFilter : Codable, Reflectable {
+        public let _count: Int64?
+        public let _min: std.StringNullableFilter?
+        public let _max: std.StringNullableFilter?
+        public init(
+            _count: Int64? = nil,
+            _min: std.StringNullableFilter? = nil,
+            _max: std.StringNullableFilter? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **String with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class StringWithAggregatesFilter : Codable, Reflectable {
+        public let _count: Int64?
+        public let _min: std.StringFilter?
+        public let _max: std.StringFilter?
+        public init(
+            _count: Int64? = nil,
+            _min: std.StringFilter? = nil,
+            _max: std.StringFilter? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /// ## BoolOrStdBoolFilter
+    ///
+    /// One of `Bool`, `std.BoolFilter`.
+    public enum BoolOrStdBoolFilter: Codable {
+        case bool(Bool)
+        case stdBoolFilter(std.BoolFilter)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(Bool.self) {
+                self = .bool(value)
+                return
+            }
+            if let value = try? container.decode(std.BoolFilter.self) {
+                self = .stdBoolFilter(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of BoolOrStdBoolFilter")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .bool(let value):
+                try container.encode(value)
+            case .stdBoolFilter(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## BoolOrStdBoolNullableFilter
+    ///
+    /// One of `Bool?`, `std.BoolNullableFilter`.
+    public enum BoolOrStdBoolNullableFilter: Codable {
+        case bool(Bool?)
+        case stdBoolNullableFilter(std.BoolNullableFilter)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.BoolNullableFilter.self) {
+                self = .stdBoolNullableFilter(value)
+                return
+            }
+            if let value = try? container.decode(Bool?.self) {
+                self = .bool(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of BoolOrStdBoolNullableFilter")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .bool(let value):
+                try container.encode(value)
+            case .stdBoolNullableFilter(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## TOrStdEnumFilterT
+    ///
+    /// One of `T`, `std.EnumFilter<T>`.
+    public enum TOrStdEnumFilterT: Codable {
+        case t(T)
+        case stdEnumFilterT(std.EnumFilter<T>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.EnumFilter<T>.self) {
+                self = .stdEnumFilterT(value)
+                return
+            }
+            if let value = try? container.decode(T.self) {
+                self = .t(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of TOrStdEnumFilterT")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .t(let value):
+                try container.encode(value)
+            case .stdEnumFilterT(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## TOrStdEnumNullableFilterT
+    ///
+    /// One of `T?`, `std.EnumNullableFilter<T>`.
+    public enum TOrStdEnumNullableFilterT: Codable {
+        case t(T?)
+        case stdEnumNullableFilterT(std.EnumNullableFilter<T>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.EnumNullableFilter<T>.self) {
+                self = .stdEnumNullableFilterT(value)
+                return
+            }
+            if let value = try? container.decode(T?.self) {
+                self = .t(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of TOrStdEnumNullableFilterT")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .t(let value):
+                try container.encode(value)
+            case .stdEnumNullableFilterT(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## TOrStdFilterT
+    ///
+    /// One of `T`, `std.Filter<T>`.
+    public enum TOrStdFilterT: Codable {
+        case t(T)
+        case stdFilterT(std.Filter<T>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.Filter<T>.self) {
+                self = .stdFilterT(value)
+                return
+            }
+            if let value = try? container.decode(T.self) {
+                self = .t(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of TOrStdFilterT")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .t(let value):
+                try container.encode(value)
+            case .stdFilterT(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## TOrStdNullableFilterT
+    ///
+    /// One of `T?`, `std.NullableFilter<T>`.
+    public enum TOrStdNullableFilterT: Codable {
+        case t(T?)
+        case stdNullableFilterT(std.NullableFilter<T>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.NullableFilter<T>.self) {
+                self = .stdNullableFilterT(value)
+                return
+            }
+            if let value = try? container.decode(T?.self) {
+                self = .t(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of TOrStdNullableFilterT")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .t(let value):
+                try container.encode(value)
+            case .stdNullableFilterT(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## StringOrStdStringFilter
+    ///
+    /// One of `String`, `std.StringFilter`.
+    public enum StringOrStdStringFilter: Codable {
+        case string(String)
+        case stdStringFilter(std.StringFilter)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(String.self) {
+                self = .string(value)
+                return
+            }
+            if let value = try? container.decode(std.StringFilter.self) {
+                self = .stdStringFilter(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringFilter")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .string(let value):
+                try container.encode(value)
+            case .stdStringFilter(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## StringOrStdStringNullableFilter
+    ///
+    /// One of `String?`, `std.StringNullableFilter`.
+    public enum StringOrStdStringNullableFilter: Codable {
+        case string(String?)
+        case stdStringNullableFilter(std.StringNullableFilter)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.StringNullableFilter.self) {
+                self = .stdStringNullableFilter(value)
+                return
+            }
+            if let value = try? container.decode(String?.self) {
+                self = .string(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringNullableFilter")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .string(let value):
+                try container.encode(value)
+            case .stdStringNullableFilter(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    public class StdNamespaceDelegate {
+        public let _headers: Dictionary<String, String>?
+        lazy var admin: std.admin.AdminNamespaceDelegate = { std.admin.AdminNamespaceDelegate(_headers) }();
+        lazy var bcrypt: std.bcrypt.BcryptNamespaceDelegate = { std.bcrypt.BcryptNamespaceDelegate(_headers) }();
+        lazy var identity: std.identity.IdentityNamespaceDelegate = { std.identity.IdentityNamespaceDelegate(_headers) }();
+        public init(_ headers: Dictionary<String, String>? = nil) {
+            self._headers = headers
+        }
+    }
+
+    public struct admin {
+
+        public class AdminNamespaceDelegate {
+            public let _headers: Dictionary<String, String>?
+            public init(_ headers: Dictionary<String, String>? = nil) {
+                self._headers = headers
+            }
+        }
+
+    }
+    public struct bcrypt {
+
+        public class BcryptNamespaceDelegate {
+            public let _headers: Dictionary<String, String>?
+            public init(_ headers: Dictionary<String, String>? = nil) {
+                self._headers = headers
+            }
+        }
+
+    }
+    public struct identity {
+
+        /**
+         * **Token info**
+         *
+         * This interface doesn't have a description.
+         */
+        public class TokenInfo : Codable, Reflectable {
+            public let token: String
+            public init(
+                token: String
+            ) {
+                self.token = token
+            }
+        }
+        public class IdentityNamespaceDelegate {
+            public let _headers: Dictionary<String, String>?
+            public init(_ headers: Dictionary<String, String>? = nil) {
+                self._headers = headers
+            }
+        }
+
+    }
+}
+
+public let teo = Teo()
\ No newline at end of file
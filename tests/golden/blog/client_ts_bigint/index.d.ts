@@ -0,0 +1,8421 @@
+// @typescript-eslint/no-unused-vars
+
+import Decimal from "decimal.js"
+
+export type ExistKeys<T> = {
+    [key in keyof T]: T[key] extends false | undefined | null ? never : key
+}[keyof T]
+
+type HasSelect = {
+    select: any
+}
+
+type HasInclude = {
+    include: any
+}
+
+export type CheckSelectInclude<T, S, U> = T extends HasSelect
+    ? U
+    : T extends HasInclude
+    ? U
+    : S
+
+export type SelectSubset<T, U> = U extends HasSelect
+    ? {
+        [K in ExistKeys<U['select']>]: K extends keyof T ? T[K] : never
+    }
+    : T
+
+export type Enumerable<T> = T | Array<T>
+
+export type Subset<T, U> = {
+    [key in keyof T]: key extends keyof U ? T[key] : never
+}
+
+export type GetScalarType<T, O> = O extends object ? {
+    [P in keyof T]: P extends keyof O
+      ? O[P]
+      : never
+} : never
+
+type Teo__Pick<T, K extends keyof T> = {
+    [P in K]: T[P];
+}
+
+type PickEnumerable<T, K extends Enumerable<keyof T> | keyof T> = Teo__Pick<T, MaybeTupleToUnion<K>>
+
+export type Boolean = True | False
+
+export type True = 1
+
+export type False = 0
+
+export type Not<B extends Boolean> = {
+  0: 1
+  1: 0
+}[B]
+
+type NoExpand<T> = T extends unknown ? T : never;
+
+type Key = string | number | symbol;
+type AtBasic<O extends object, K extends Key> = K extends keyof O ? O[K] : never;
+type AtStrict<O extends object, K extends Key> = O[K & keyof O];
+type AtLoose<O extends object, K extends Key> = O extends unknown ? AtStrict<O, K> : never;
+export type At<O extends object, K extends Key, strict extends Boolean = 1> = {
+    1: AtStrict<O, K>;
+    0: AtLoose<O, K>;
+}[strict];
+
+export type IntersectOf<U extends Union> = (
+  U extends unknown ? (k: U) => void : never
+) extends (k: infer I) => void
+  ? I
+  : never
+
+export type Overwrite<O extends object, O1 extends object> = {
+    [K in keyof O]: K extends keyof O1 ? O1[K] : O[K];
+} & {};
+
+type _Merge<U extends object> = IntersectOf<Overwrite<U, {
+    [K in keyof U]-?: At<U, K>;
+}>>;
+
+export type ComputeRaw<A extends any> = A extends Function ? A : {
+  [K in keyof A]: A[K];
+} & {};
+
+export type OptionalFlat<O> = {
+  [K in keyof O]?: O[K];
+} & {};
+
+type _Record<K extends keyof any, T> = {
+  [P in K]: T;
+};
+
+type AtLeast<O extends object, K extends string> = NoExpand<
+  O extends unknown
+  ? | (K extends keyof O ? { [P in K]: O[P] } & O : O)
+    | {[P in keyof O as P extends K ? K : never]-?: O[P]} & O
+  : never>;
+
+type _Strict<U, _U = U> = U extends unknown ? U & OptionalFlat<_Record<Exclude<Keys<_U>, keyof U>, never>> : never;
+
+export type Strict<U extends object> = ComputeRaw<_Strict<U>>;
+
+export type Merge<U extends object> = ComputeRaw<_Merge<Strict<U>>>;
+
+type ExcludeUnderscoreKeys<T extends string> = T extends `_${string}` ? never : T
+
+export type Union = any
+
+export type Extends<A1 extends any, A2 extends any> = [A1] extends [never]
+  ? 0 // anything `never` is false
+  : A1 extends A2
+  ? 1
+  : 0
+
+export type Has<U extends Union, U1 extends Union> = Not<
+  Extends<Exclude<U1, U>, U1>
+>
+
+export type Or<B1 extends Boolean, B2 extends Boolean> = {
+  0: {
+    0: 0
+    1: 1
+  }
+  1: {
+    0: 1
+    1: 1
+  }
+}[B1][B2]
+
+export type Keys<U extends Union> = U extends unknown ? keyof U : never
+
+type Cast<A, B> = A extends B ? A : B;
+
+type IsObject<T extends any> = T extends Array<any>
+? False
+: T extends Date
+? False
+: T extends Uint8Array
+? False
+: T extends object
+? True
+: False
+
+type FieldPaths<
+  T,
+  U = Omit<T, '_avg' | '_sum' | '_count' | '_min' | '_max'>
+> = IsObject<T> extends True ? U : T
+
+type GetHavingFields<T> = {
+  [K in keyof T]: Or<
+    Or<Extends<'OR', K>, Extends<'AND', K>>,
+    Extends<'NOT', K>
+  > extends True
+    ? // infer is only needed to not hit TS limit
+      // based on the brilliant idea of Pierre-Antoine Mills
+      // https://github.com/microsoft/TypeScript/issues/30188#issuecomment-478938437
+      T[K] extends infer TK
+      ? GetHavingFields<UnEnumerate<TK> extends object ? Merge<UnEnumerate<TK>> : never>
+      : never
+    : {} extends FieldPaths<T[K]>
+    ? never
+    : K
+}[keyof T]
+
+export type UnEnumerate<T extends unknown> = T extends Array<infer U> ? U : T
+
+export type SubsetIntersection<T, U, K> = {
+  [key in keyof T]: key extends keyof U ? T[key] : never
+} & K
+
+type _TupleToUnion<T> = T extends (infer E)[] ? E : never
+type TupleToUnion<K extends readonly any[]> = _TupleToUnion<K>
+type MaybeTupleToUnion<T> = T extends any[] ? TupleToUnion<T> : T
+
+export type Int64 = bigint
+
+export type ObjectId = string
+
+export type DateOnly = string
+
+export declare class TeoError extends Error {
+    type: string
+
+    errors: {[key: string]: string} | null
+
+    constructor(responseError: std.ResponseError)
+
+    get name(): string
+}
+
+/**
+ * **Sex**
+ *
+ * Sex The sex of a user.
+ */
+export type Sex = "male" | "female"
+
+/**
+ * **Post direct relations**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type PostDirectRelations = "author"
+
+/**
+ * **Post indirect relations**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type PostIndirectRelations = undefined
+
+/**
+ * **Post relations**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type PostRelations = "author"
+
+/**
+ * **Post scalar fields**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type PostScalarFields = "authorId" | "content" | "id" | "tags" | "title" | "views"
+
+/**
+ * **Post serializable scalar fields**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type PostSerializableScalarFields = "authorId" | "content" | "id" | "tags" | "title" | "views"
+
+/**
+ * **User direct relations**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type UserDirectRelations = "posts"
+
+/**
+ * **User indirect relations**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type UserIndirectRelations = undefined
+
+/**
+ * **User relations**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type UserRelations = "posts"
+
+/**
+ * **User scalar fields**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type UserScalarFields = "balance" | "birthday" | "createdAt" | "email" | "id" | "name" | "sex"
+
+/**
+ * **User serializable scalar fields**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type UserSerializableScalarFields = "balance" | "birthday" | "createdAt" | "email" | "id" | "name" | "sex"
+
+/**
+ * ## Sex
+ *
+ * Sex The sex of a user.
+ */
+export const enum SexEnumType {
+
+    /**
+     * ### Male
+     *
+     * This enum member doesn't have a description.
+     */
+    male = "male",
+
+    /**
+     * ### Female
+     *
+     * This enum member doesn't have a description.
+     */
+    female = "female",
+}
+
+/**
+ * ## Post direct relations
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum PostDirectRelationsEnumType {
+
+    /**
+     * ### Author
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    author = "author",
+}
+
+/**
+ * ## Post indirect relations
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum PostIndirectRelationsEnumType {
+}
+
+/**
+ * ## Post relations
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum PostRelationsEnumType {
+
+    /**
+     * ### Author
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    author = "author",
+}
+
+/**
+ * ## Post scalar fields
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum PostScalarFieldsEnumType {
+
+    /**
+     * ### Author id
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    authorId = "authorId",
+
+    /**
+     * ### Content
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    content = "content",
+
+    /**
+     * ### Id
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    id = "id",
+
+    /**
+     * ### Tags
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    tags = "tags",
+
+    /**
+     * ### Title
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    title = "title",
+
+    /**
+     * ### Views
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    views = "views",
+}
+
+/**
+ * ## Post serializable scalar fields
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum PostSerializableScalarFieldsEnumType {
+
+    /**
+     * ### Author id
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    authorId = "authorId",
+
+    /**
+     * ### Content
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    content = "content",
+
+    /**
+     * ### Id
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    id = "id",
+
+    /**
+     * ### Tags
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    tags = "tags",
+
+    /**
+     * ### Title
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    title = "title",
+
+    /**
+     * ### Views
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    views = "views",
+}
+
+/**
+ * ## User direct relations
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum UserDirectRelationsEnumType {
+
+    /**
+     * ### Posts
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    posts = "posts",
+}
+
+/**
+ * ## User indirect relations
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum UserIndirectRelationsEnumType {
+}
+
+/**
+ * ## User relations
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum UserRelationsEnumType {
+
+    /**
+     * ### Posts
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    posts = "posts",
+}
+
+/**
+ * ## User scalar fields
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum UserScalarFieldsEnumType {
+
+    /**
+     * ### Balance
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    balance = "balance",
+
+    /**
+     * ### Birthday
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    birthday = "birthday",
+
+    /**
+     * ### Created at
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    createdAt = "createdAt",
+
+    /**
+     * ### Email
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    email = "email",
+
+    /**
+     * ### Id
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    id = "id",
+
+    /**
+     * ### Name
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    name = "name",
+
+    /**
+     * ### Sex
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    sex = "sex",
+}
+
+/**
+ * ## User serializable scalar fields
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum UserSerializableScalarFieldsEnumType {
+
+    /**
+     * ### Balance
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    balance = "balance",
+
+    /**
+     * ### Birthday
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    birthday = "birthday",
+
+    /**
+     * ### Created at
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    createdAt = "createdAt",
+
+    /**
+     * ### Email
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    email = "email",
+
+    /**
+     * ### Id
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    id = "id",
+
+    /**
+     * ### Name
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    name = "name",
+
+    /**
+     * ### Sex
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    sex = "sex",
+}
+
+
+/**
+ * **Keyword**
+ *
+ * This interface doesn't have a description.
+ */
+export type Keyword = {
+    
+    /**
+     * **Value**
+     *
+     * This interface field doesn't have a description.
+     */
+     value: string | number
+    
+}
+
+
+/**
+ * **Page**
+ *
+ * This interface doesn't have a description.
+ */
+export type Page<T> = {
+    
+    /**
+     * **Items**
+     *
+     * This interface field doesn't have a description.
+     */
+     items: T[]
+    
+    /**
+     * **Total**
+     *
+     * This interface field doesn't have a description.
+     */
+     total: Int64
+    
+}
+
+
+/**
+ * **Post path arguments**
+ *
+ * This interface doesn't have a description.
+ */
+export type PostPathArguments = {
+    
+    /**
+     * **Id**
+     *
+     * This interface field doesn't have a description.
+     */
+     id: number
+    
+}
+
+
+/**
+ * **Search input**
+ *
+ * This interface doesn't have a description.
+ */
+export type SearchInput = {
+    
+    /**
+     * **Keyword**
+     *
+     * This interface field doesn't have a description.
+     */
+     keyword: string
+    
+    /**
+     * **Sex**
+     *
+     * This interface field doesn't have a description.
+     */
+     sex?: Sex
+    
+    /**
+     * **Tags**
+     *
+     * This interface field doesn't have a description.
+     */
+     tags?: string[]
+    
+}
+
+
+/**
+ * **Upload input**
+ *
+ * This interface doesn't have a description.
+ */
+export type UploadInput = {
+    
+    /**
+     * **Title**
+     *
+     * This interface field doesn't have a description.
+     */
+     title: string
+    
+    /**
+     * **Caption**
+     *
+     * This interface field doesn't have a description.
+     */
+     caption?: string
+    
+}
+
+
+/**
+ * **Upload output**
+ *
+ * This interface doesn't have a description.
+ */
+export type UploadOutput = {
+    
+    /**
+     * **Path**
+     *
+     * This interface field doesn't have a description.
+     */
+     path: string
+    
+}
+
+
+/**
+ * **Post**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type Post = {
+    
+    /**
+     * **Author**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     author: User
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId: number
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id: number
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags: string[]
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title: string
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views: Int64
+    
+}
+export type PostGetPayload<S extends boolean | null | undefined | PostArgs, U = keyof S> = S extends true
+    ? Post
+    : S extends undefined
+        ? never
+        : S extends PostArgs | PostFindManyArgs
+            ? 'include' extends U
+                ? SelectSubset<Post, S> & {
+                    [P in ExistKeys<S['include']>]:
+                        P extends 'author' ? UserGetPayload<S['include'][P]> :
+                    never
+                }
+                : SelectSubset<Post, S>
+            : Post
+
+export type GetPostAggregateType<T extends PostAggregateArgs> = {
+    [P in keyof T & keyof PostAggregateResult]: P extends '_count' | 'count'
+  ? T[P] extends true
+    ? number
+    : GetScalarType<T[P], PostAggregateResult[P]>
+  : GetScalarType<T[P], PostAggregateResult[P]>
+}
+
+export type GetPostGroupByPayload<T extends PostGroupByArgs> =
+  Array<
+    PickEnumerable<PostGroupByResult, T['by']> &
+      {
+        [P in ((keyof T) & (keyof PostGroupByResult))]: P extends '_count'
+          ? T[P] extends boolean
+            ? number
+            : GetScalarType<T[P], PostGroupByResult[P]>
+          : GetScalarType<T[P], PostGroupByResult[P]>
+      }
+    >
+
+
+/**
+ * **Post aggregate args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostAggregateArgs = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: PostAvgAggregateInputType
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: PostCountAggregateInputType
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: PostMaxAggregateInputType
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: PostMinAggregateInputType
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: PostSumAggregateInputType
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: PostWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: PostSerializableScalarFields
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<PostOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: PostWhereInput
+    
+}
+
+
+/**
+ * **Post aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostAggregateResult = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: PostAvgAggregateResult
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: PostCountAggregateResult
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: PostMaxAggregateResult
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: PostMinAggregateResult
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: PostSumAggregateResult
+    
+}
+
+
+/**
+ * **Post args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+}
+
+
+/**
+ * **Post avg aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostAvgAggregateInputType = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: boolean
+    
+}
+
+
+/**
+ * **Post avg aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostAvgAggregateResult = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: number
+    
+}
+
+
+/**
+ * **Post connect or create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostConnectOrCreateInput = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: PostCreateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post connect or create without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostConnectOrCreateWithoutAuthorInput = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: PostCreateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post copy args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCopyArgs = {
+    
+    /**
+     * **Copy**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     copy: PostUpdateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post copy many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCopyManyArgs = {
+    
+    /**
+     * **Copy**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     copy: PostUpdateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereInput
+    
+}
+
+
+/**
+ * **Post count aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCountAggregateInputType = {
+    
+    /**
+     * **All**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _all?: boolean
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: boolean
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: boolean
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: boolean
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: boolean
+    
+}
+
+
+/**
+ * **Post count aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCountAggregateResult = {
+    
+    /**
+     * **All**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _all?: Int64
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: Int64
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: Int64
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: Int64
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: Int64
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: Int64
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: Int64
+    
+}
+
+
+/**
+ * **Post count args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCountArgs = {
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: PostWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: PostSerializableScalarFields
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<PostOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostCountAggregateInputType
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: PostWhereInput
+    
+}
+
+
+/**
+ * **Post create args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCreateArgs = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: PostCreateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+}
+
+
+/**
+ * **Post create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCreateInput = {
+    
+    /**
+     * **Author**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     author?: UserCreateNestedOneWithoutPostsInput
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags: string[]
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title: string
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views: Int64
+    
+}
+
+
+/**
+ * **Post create many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCreateManyArgs = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: Enumerable<PostCreateInput>
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+}
+
+
+/**
+ * **Post create nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCreateNestedManyInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: Enumerable<PostWhereUniqueInput>
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: Enumerable<PostConnectOrCreateInput>
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: Enumerable<PostCreateInput>
+    
+}
+
+
+/**
+ * **Post create nested many without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCreateNestedManyWithoutAuthorInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: Enumerable<PostWhereUniqueInput>
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: Enumerable<PostConnectOrCreateWithoutAuthorInput>
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: Enumerable<PostCreateInput>
+    
+}
+
+
+/**
+ * **Post create nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCreateNestedOneInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: PostWhereUniqueInput
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: PostConnectOrCreateInput
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: PostCreateInput
+    
+}
+
+
+/**
+ * **Post create nested one without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCreateNestedOneWithoutAuthorInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: PostWhereUniqueInput
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: PostConnectOrCreateWithoutAuthorInput
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: PostCreateInput
+    
+}
+
+
+/**
+ * **Post create without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostCreateWithoutAuthorInput = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags: string[]
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title: string
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views: Int64
+    
+}
+
+
+/**
+ * **Post delete args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostDeleteArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post delete many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostDeleteManyArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereInput
+    
+}
+
+
+/**
+ * **Post find first args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostFindFirstArgs = {
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: PostWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: PostSerializableScalarFields
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<PostOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: PostWhereInput
+    
+}
+
+
+/**
+ * **Post find many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostFindManyArgs = {
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: PostWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: PostSerializableScalarFields
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<PostOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: PostWhereInput
+    
+}
+
+
+/**
+ * **Post find unique args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostFindUniqueArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post group by args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostGroupByArgs = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: PostAvgAggregateInputType
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: PostCountAggregateInputType
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: PostMaxAggregateInputType
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: PostMinAggregateInputType
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: PostSumAggregateInputType
+    
+    /**
+     * **By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     by: Enumerable<PostSerializableScalarFields>
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: PostWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: PostSerializableScalarFields
+    
+    /**
+     * **Having**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     having?: PostScalarWhereWithAggregatesInput
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<PostOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: PostWhereInput
+    
+}
+
+
+/**
+ * **Post group by result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostGroupByResult = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: PostAvgAggregateResult
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: PostCountAggregateResult
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: PostMaxAggregateResult
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: PostMinAggregateResult
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: PostSumAggregateResult
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: string[]
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: string
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: Int64
+    
+}
+
+
+/**
+ * **Post include**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostInclude = {
+    
+    /**
+     * **Author**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     author?: UserArgs | boolean
+    
+}
+
+
+/**
+ * **Post list relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostListRelationFilter = {
+    
+    /**
+     * **Every**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     every?: PostWhereInput
+    
+    /**
+     * **None**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     none?: PostWhereInput
+    
+    /**
+     * **Some**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     some?: PostWhereInput
+    
+}
+
+
+/**
+ * **Post max aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostMaxAggregateInputType = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: boolean
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: boolean
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: boolean
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: boolean
+    
+}
+
+
+/**
+ * **Post max aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostMaxAggregateResult = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: string[]
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: string
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: Int64
+    
+}
+
+
+/**
+ * **Post min aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostMinAggregateInputType = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: boolean
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: boolean
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: boolean
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: boolean
+    
+}
+
+
+/**
+ * **Post min aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostMinAggregateResult = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: string[]
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: string
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: Int64
+    
+}
+
+
+/**
+ * **Post order by input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostOrderByInput = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: std.Sort
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: std.Sort
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: std.Sort
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: std.Sort
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: std.Sort
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: std.Sort
+    
+}
+
+
+/**
+ * **Post relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostRelationFilter = {
+    
+    /**
+     * **Is**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     is?: PostWhereInput
+    
+    /**
+     * **Is Not**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     isNot?: PostWhereInput
+    
+}
+
+
+/**
+ * **Post scalar update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostScalarUpdateInput = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: string[]
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: string
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: Int64
+    
+}
+
+
+/**
+ * **Post scalar where with aggregates input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostScalarWhereWithAggregatesInput = {
+    
+    /**
+     * **And**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     AND?: PostWhereInput[]
+    
+    /**
+     * **Not**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     NOT?: PostWhereInput
+    
+    /**
+     * **Or**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     OR?: PostWhereInput[]
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number | std.IntNumberWithAggregatesFilter<number>
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string | null | std.StringNullableWithAggregatesFilter
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number | std.IntNumberWithAggregatesFilter<number>
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: string[] | std.ArrayWithAggregatesFilter<string>
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: string | std.StringWithAggregatesFilter
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: Int64 | std.IntNumberWithAggregatesFilter<Int64>
+    
+}
+
+
+/**
+ * **Post select**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostSelect = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: boolean
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: boolean
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: boolean
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: boolean
+    
+}
+
+
+/**
+ * **Post sign in args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostSignInArgs = {
+    
+}
+
+
+/**
+ * **Post sign in checker companions**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostSignInCheckerCompanions = {
+    
+}
+
+
+/**
+ * **Post sign in checker ids**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostSignInCheckerIds = {
+    
+}
+
+
+/**
+ * **Post sign in input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostSignInInput = {
+    
+    /**
+     * **Credentials**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     credentials: PostSignInArgs
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+}
+
+
+/**
+ * **Post sum aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostSumAggregateInputType = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: boolean
+    
+}
+
+
+/**
+ * **Post sum aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostSumAggregateResult = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: Int64
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: Int64
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: Int64
+    
+}
+
+
+/**
+ * **Post update args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: PostUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateInput = {
+    
+    /**
+     * **Author**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     author?: UserUpdateNestedOneWithoutPostsInput
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: string[]
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: string
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: Int64
+    
+}
+
+
+/**
+ * **Post update many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateManyArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: PostUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereInput
+    
+}
+
+
+/**
+ * **Post update many with where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateManyWithWhereInput = {
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: PostUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereInput
+    
+}
+
+
+/**
+ * **Post update many with where without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateManyWithWhereWithoutAuthorInput = {
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: PostUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereInput
+    
+}
+
+
+/**
+ * **Post update nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateNestedManyInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: Enumerable<PostWhereUniqueInput>
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: Enumerable<PostConnectOrCreateInput>
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: Enumerable<PostCreateInput>
+    
+    /**
+     * **Delete**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     delete?: Enumerable<PostWhereUniqueInput>
+    
+    /**
+     * **Delete Many**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     deleteMany?: Enumerable<PostWhereInput>
+    
+    /**
+     * **Disconnect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     disconnect?: Enumerable<PostWhereUniqueInput>
+    
+    /**
+     * **Set**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     set?: Enumerable<PostWhereUniqueInput>
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update?: Enumerable<PostUpdateWithWhereUniqueInput>
+    
+    /**
+     * **Update Many**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     updateMany?: Enumerable<PostUpdateManyWithWhereInput>
+    
+    /**
+     * **Upsert**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     upsert?: Enumerable<PostUpsertWithWhereUniqueInput>
+    
+}
+
+
+/**
+ * **Post update nested many without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateNestedManyWithoutAuthorInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: Enumerable<PostWhereUniqueInput>
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: Enumerable<PostConnectOrCreateWithoutAuthorInput>
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: Enumerable<PostCreateInput>
+    
+    /**
+     * **Delete**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     delete?: Enumerable<PostWhereUniqueInput>
+    
+    /**
+     * **Delete Many**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     deleteMany?: Enumerable<PostWhereInput>
+    
+    /**
+     * **Disconnect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     disconnect?: Enumerable<PostWhereUniqueInput>
+    
+    /**
+     * **Set**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     set?: Enumerable<PostWhereUniqueInput>
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update?: Enumerable<PostUpdateWithWhereUniqueWithoutAuthorInput>
+    
+    /**
+     * **Update Many**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     updateMany?: Enumerable<PostUpdateManyWithWhereWithoutAuthorInput>
+    
+    /**
+     * **Upsert**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     upsert?: Enumerable<PostUpsertWithWhereUniqueWithoutAuthorInput>
+    
+}
+
+
+/**
+ * **Post update nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateNestedOneInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: PostWhereUniqueInput
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: PostConnectOrCreateInput
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: PostCreateInput
+    
+    /**
+     * **Delete**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     delete?: boolean
+    
+    /**
+     * **Disconnect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     disconnect?: boolean
+    
+    /**
+     * **Set**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     set?: PostWhereUniqueInput
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update?: PostUpdateInput
+    
+    /**
+     * **Upsert**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     upsert?: PostUpsertWithWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post update nested one without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateNestedOneWithoutAuthorInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: PostWhereUniqueInput
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: PostConnectOrCreateWithoutAuthorInput
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: PostCreateInput
+    
+    /**
+     * **Delete**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     delete?: boolean
+    
+    /**
+     * **Disconnect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     disconnect?: boolean
+    
+    /**
+     * **Set**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     set?: PostWhereUniqueInput
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update?: PostUpdateInput
+    
+    /**
+     * **Upsert**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     upsert?: PostUpsertWithWhereUniqueWithoutAuthorInput
+    
+}
+
+
+/**
+ * **Post update with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateWithWhereUniqueInput = {
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: PostUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post update with where unique without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateWithWhereUniqueWithoutAuthorInput = {
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: PostUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post update without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpdateWithoutAuthorInput = {
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: string[]
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: string
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: Int64
+    
+}
+
+
+/**
+ * **Post upsert args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpsertArgs = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: PostCreateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: PostInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: PostSelect
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: PostUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post upsert with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpsertWithWhereUniqueInput = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: PostCreateInput
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: PostUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post upsert with where unique without author input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostUpsertWithWhereUniqueWithoutAuthorInput = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: PostCreateInput
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: PostUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: PostWhereUniqueInput
+    
+}
+
+
+/**
+ * **Post where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostWhereInput = {
+    
+    /**
+     * **And**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     AND?: PostWhereInput[]
+    
+    /**
+     * **Not**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     NOT?: PostWhereInput
+    
+    /**
+     * **Or**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     OR?: PostWhereInput[]
+    
+    /**
+     * **Author**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     author?: UserRelationFilter
+    
+    /**
+     * **Author Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     authorId?: number | std.Filter<number>
+    
+    /**
+     * **Content**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     content?: string | null | std.StringNullableFilter
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number | std.Filter<number>
+    
+    /**
+     * **Tags**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     tags?: string[] | std.ArrayFilter<string>
+    
+    /**
+     * **Title**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     title?: string | std.StringFilter
+    
+    /**
+     * **Views**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     views?: Int64 | std.Filter<Int64>
+    
+}
+
+
+/**
+ * **Post where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type PostWhereUniqueInput = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id: number
+    
+}
+
+
+/**
+ * **User**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type User = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt: Date
+    
+    /**
+     * **Display Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     displayName?: string
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id: number
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Posts**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     posts: Post[]
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex
+    
+}
+export type UserGetPayload<S extends boolean | null | undefined | UserArgs, U = keyof S> = S extends true
+    ? User
+    : S extends undefined
+        ? never
+        : S extends UserArgs | UserFindManyArgs
+            ? 'include' extends U
+                ? SelectSubset<User, S> & {
+                    [P in ExistKeys<S['include']>]:
+                        P extends 'posts' ? PostGetPayload<S['include'][P]>[] :
+                    never
+                }
+                : SelectSubset<User, S>
+            : User
+
+export type GetUserAggregateType<T extends UserAggregateArgs> = {
+    [P in keyof T & keyof UserAggregateResult]: P extends '_count' | 'count'
+  ? T[P] extends true
+    ? number
+    : GetScalarType<T[P], UserAggregateResult[P]>
+  : GetScalarType<T[P], UserAggregateResult[P]>
+}
+
+export type GetUserGroupByPayload<T extends UserGroupByArgs> =
+  Array<
+    PickEnumerable<UserGroupByResult, T['by']> &
+      {
+        [P in ((keyof T) & (keyof UserGroupByResult))]: P extends '_count'
+          ? T[P] extends boolean
+            ? number
+            : GetScalarType<T[P], UserGroupByResult[P]>
+          : GetScalarType<T[P], UserGroupByResult[P]>
+      }
+    >
+
+
+/**
+ * **User aggregate args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserAggregateArgs = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: UserAvgAggregateInputType
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: UserCountAggregateInputType
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: UserMaxAggregateInputType
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: UserMinAggregateInputType
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: UserSumAggregateInputType
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: UserWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: UserSerializableScalarFields
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<UserOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: UserWhereInput
+    
+}
+
+
+/**
+ * **User aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserAggregateResult = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: UserAvgAggregateResult
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: UserCountAggregateResult
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: UserMaxAggregateResult
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: UserMinAggregateResult
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: UserSumAggregateResult
+    
+}
+
+
+/**
+ * **User args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+}
+
+
+/**
+ * **User avg aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserAvgAggregateInputType = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+}
+
+
+/**
+ * **User avg aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserAvgAggregateResult = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+}
+
+
+/**
+ * **User connect or create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserConnectOrCreateInput = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: UserCreateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User connect or create without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserConnectOrCreateWithoutPostsInput = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: UserCreateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User copy args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCopyArgs = {
+    
+    /**
+     * **Copy**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     copy: UserUpdateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User copy many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCopyManyArgs = {
+    
+    /**
+     * **Copy**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     copy: UserUpdateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereInput
+    
+}
+
+
+/**
+ * **User count aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCountAggregateInputType = {
+    
+    /**
+     * **All**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _all?: boolean
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: boolean
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: boolean
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: boolean
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: boolean
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: boolean
+    
+}
+
+
+/**
+ * **User count aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCountAggregateResult = {
+    
+    /**
+     * **All**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _all?: Int64
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Int64
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: Int64
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: Int64
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: Int64
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: Int64
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: Int64
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Int64
+    
+}
+
+
+/**
+ * **User count args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCountArgs = {
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: UserWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: UserSerializableScalarFields
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<UserOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserCountAggregateInputType
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: UserWhereInput
+    
+}
+
+
+/**
+ * **User create args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCreateArgs = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: UserCreateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+}
+
+
+/**
+ * **User create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCreateInput = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email: string
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Posts**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     posts?: PostCreateNestedManyWithoutAuthorInput
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex
+    
+}
+
+
+/**
+ * **User create many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCreateManyArgs = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: Enumerable<UserCreateInput>
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+}
+
+
+/**
+ * **User create nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCreateNestedManyInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: Enumerable<UserWhereUniqueInput>
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: Enumerable<UserConnectOrCreateInput>
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: Enumerable<UserCreateInput>
+    
+}
+
+
+/**
+ * **User create nested many without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCreateNestedManyWithoutPostsInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: Enumerable<UserWhereUniqueInput>
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: Enumerable<UserConnectOrCreateWithoutPostsInput>
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: Enumerable<UserCreateInput>
+    
+}
+
+
+/**
+ * **User create nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCreateNestedOneInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: UserWhereUniqueInput
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: UserConnectOrCreateInput
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: UserCreateInput
+    
+}
+
+
+/**
+ * **User create nested one without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCreateNestedOneWithoutPostsInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: UserWhereUniqueInput
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: UserConnectOrCreateWithoutPostsInput
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: UserCreateInput
+    
+}
+
+
+/**
+ * **User create without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserCreateWithoutPostsInput = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email: string
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex
+    
+}
+
+
+/**
+ * **User delete args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserDeleteArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User delete many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserDeleteManyArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereInput
+    
+}
+
+
+/**
+ * **User find first args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserFindFirstArgs = {
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: UserWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: UserSerializableScalarFields
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<UserOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: UserWhereInput
+    
+}
+
+
+/**
+ * **User find many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserFindManyArgs = {
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: UserWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: UserSerializableScalarFields
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<UserOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: UserWhereInput
+    
+}
+
+
+/**
+ * **User find unique args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserFindUniqueArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User group by args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserGroupByArgs = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: UserAvgAggregateInputType
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: UserCountAggregateInputType
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: UserMaxAggregateInputType
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: UserMinAggregateInputType
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: UserSumAggregateInputType
+    
+    /**
+     * **By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     by: Enumerable<UserSerializableScalarFields>
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: UserWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: UserSerializableScalarFields
+    
+    /**
+     * **Having**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     having?: UserScalarWhereWithAggregatesInput
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<UserOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: UserWhereInput
+    
+}
+
+
+/**
+ * **User group by result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserGroupByResult = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: UserAvgAggregateResult
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: UserCountAggregateResult
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: UserMaxAggregateResult
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: UserMinAggregateResult
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: UserSumAggregateResult
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: Date
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex
+    
+}
+
+
+/**
+ * **User include**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserInclude = {
+    
+    /**
+     * **Posts**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     posts?: PostFindManyArgs | boolean
+    
+}
+
+
+/**
+ * **User list relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserListRelationFilter = {
+    
+    /**
+     * **Every**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     every?: UserWhereInput
+    
+    /**
+     * **None**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     none?: UserWhereInput
+    
+    /**
+     * **Some**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     some?: UserWhereInput
+    
+}
+
+
+/**
+ * **User max aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserMaxAggregateInputType = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: boolean
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: boolean
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: boolean
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: boolean
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: boolean
+    
+}
+
+
+/**
+ * **User max aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserMaxAggregateResult = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: Date
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex
+    
+}
+
+
+/**
+ * **User min aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserMinAggregateInputType = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: boolean
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: boolean
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: boolean
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: boolean
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: boolean
+    
+}
+
+
+/**
+ * **User min aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserMinAggregateResult = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: Date
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex
+    
+}
+
+
+/**
+ * **User order by input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserOrderByInput = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: std.Sort
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: std.Sort
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: std.Sort
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: std.Sort
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: std.Sort
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: std.Sort
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: std.Sort
+    
+}
+
+
+/**
+ * **User relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserRelationFilter = {
+    
+    /**
+     * **Is**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     is?: UserWhereInput
+    
+    /**
+     * **Is Not**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     isNot?: UserWhereInput
+    
+}
+
+
+/**
+ * **User scalar update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserScalarUpdateInput = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: Date
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex
+    
+}
+
+
+/**
+ * **User scalar where with aggregates input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserScalarWhereWithAggregatesInput = {
+    
+    /**
+     * **And**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     AND?: UserWhereInput[]
+    
+    /**
+     * **Not**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     NOT?: UserWhereInput
+    
+    /**
+     * **Or**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     OR?: UserWhereInput[]
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal | null | std.DecimalNullableWithAggregatesFilter<Decimal>
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly | null | std.NullableAggregatesFilter<DateOnly>
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: Date | std.AggregatesFilter<Date>
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: string | std.StringWithAggregatesFilter
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number | std.IntNumberWithAggregatesFilter<number>
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string | null | std.StringNullableWithAggregatesFilter
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex | null | std.EnumNullableWithAggregatesFilter<Sex>
+    
+}
+
+
+/**
+ * **User select**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserSelect = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: boolean
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: boolean
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: boolean
+    
+    /**
+     * **Display Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     displayName?: boolean
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: boolean
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: boolean
+    
+}
+
+
+/**
+ * **User sign in args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserSignInArgs = {
+    
+}
+
+
+/**
+ * **User sign in checker companions**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserSignInCheckerCompanions = {
+    
+}
+
+
+/**
+ * **User sign in checker ids**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserSignInCheckerIds = {
+    
+}
+
+
+/**
+ * **User sign in input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserSignInInput = {
+    
+    /**
+     * **Credentials**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     credentials: UserSignInArgs
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+}
+
+
+/**
+ * **User sum aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserSumAggregateInputType = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+}
+
+
+/**
+ * **User sum aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserSumAggregateResult = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: Int64
+    
+}
+
+
+/**
+ * **User update args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: UserUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateInput = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: string
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Posts**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     posts?: PostUpdateNestedManyWithoutAuthorInput
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex
+    
+}
+
+
+/**
+ * **User update many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateManyArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: UserUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereInput
+    
+}
+
+
+/**
+ * **User update many with where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateManyWithWhereInput = {
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: UserUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereInput
+    
+}
+
+
+/**
+ * **User update many with where without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateManyWithWhereWithoutPostsInput = {
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: UserUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereInput
+    
+}
+
+
+/**
+ * **User update nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateNestedManyInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: Enumerable<UserWhereUniqueInput>
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: Enumerable<UserConnectOrCreateInput>
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: Enumerable<UserCreateInput>
+    
+    /**
+     * **Delete**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     delete?: Enumerable<UserWhereUniqueInput>
+    
+    /**
+     * **Delete Many**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     deleteMany?: Enumerable<UserWhereInput>
+    
+    /**
+     * **Disconnect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     disconnect?: Enumerable<UserWhereUniqueInput>
+    
+    /**
+     * **Set**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     set?: Enumerable<UserWhereUniqueInput>
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update?: Enumerable<UserUpdateWithWhereUniqueInput>
+    
+    /**
+     * **Update Many**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     updateMany?: Enumerable<UserUpdateManyWithWhereInput>
+    
+    /**
+     * **Upsert**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     upsert?: Enumerable<UserUpsertWithWhereUniqueInput>
+    
+}
+
+
+/**
+ * **User update nested many without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateNestedManyWithoutPostsInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: Enumerable<UserWhereUniqueInput>
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: Enumerable<UserConnectOrCreateWithoutPostsInput>
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: Enumerable<UserCreateInput>
+    
+    /**
+     * **Delete**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     delete?: Enumerable<UserWhereUniqueInput>
+    
+    /**
+     * **Delete Many**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     deleteMany?: Enumerable<UserWhereInput>
+    
+    /**
+     * **Disconnect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     disconnect?: Enumerable<UserWhereUniqueInput>
+    
+    /**
+     * **Set**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     set?: Enumerable<UserWhereUniqueInput>
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update?: Enumerable<UserUpdateWithWhereUniqueWithoutPostsInput>
+    
+    /**
+     * **Update Many**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     updateMany?: Enumerable<UserUpdateManyWithWhereWithoutPostsInput>
+    
+    /**
+     * **Upsert**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     upsert?: Enumerable<UserUpsertWithWhereUniqueWithoutPostsInput>
+    
+}
+
+
+/**
+ * **User update nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateNestedOneInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: UserWhereUniqueInput
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: UserConnectOrCreateInput
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: UserCreateInput
+    
+    /**
+     * **Delete**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     delete?: boolean
+    
+    /**
+     * **Disconnect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     disconnect?: boolean
+    
+    /**
+     * **Set**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     set?: UserWhereUniqueInput
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update?: UserUpdateInput
+    
+    /**
+     * **Upsert**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     upsert?: UserUpsertWithWhereUniqueInput
+    
+}
+
+
+/**
+ * **User update nested one without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateNestedOneWithoutPostsInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: UserWhereUniqueInput
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: UserConnectOrCreateWithoutPostsInput
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: UserCreateInput
+    
+    /**
+     * **Delete**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     delete?: boolean
+    
+    /**
+     * **Disconnect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     disconnect?: boolean
+    
+    /**
+     * **Set**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     set?: UserWhereUniqueInput
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update?: UserUpdateInput
+    
+    /**
+     * **Upsert**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     upsert?: UserUpsertWithWhereUniqueWithoutPostsInput
+    
+}
+
+
+/**
+ * **User update with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateWithWhereUniqueInput = {
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: UserUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User update with where unique without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateWithWhereUniqueWithoutPostsInput = {
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: UserUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User update without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpdateWithoutPostsInput = {
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: string
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex
+    
+}
+
+
+/**
+ * **User upsert args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpsertArgs = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: UserCreateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: UserInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: UserSelect
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: UserUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User upsert with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpsertWithWhereUniqueInput = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: UserCreateInput
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: UserUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User upsert with where unique without posts input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserUpsertWithWhereUniqueWithoutPostsInput = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: UserCreateInput
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: UserUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: UserWhereUniqueInput
+    
+}
+
+
+/**
+ * **User where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserWhereInput = {
+    
+    /**
+     * **And**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     AND?: UserWhereInput[]
+    
+    /**
+     * **Not**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     NOT?: UserWhereInput
+    
+    /**
+     * **Or**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     OR?: UserWhereInput[]
+    
+    /**
+     * **Balance**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     balance?: Decimal | null | std.NullableFilter<Decimal>
+    
+    /**
+     * **Birthday**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     birthday?: DateOnly | null | std.NullableFilter<DateOnly>
+    
+    /**
+     * **Created At**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     createdAt?: Date | std.Filter<Date>
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: string | std.StringFilter
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number | std.Filter<number>
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string | null | std.StringNullableFilter
+    
+    /**
+     * **Posts**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     posts?: PostListRelationFilter
+    
+    /**
+     * **Sex**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     sex?: Sex | null | std.EnumNullableFilter<Sex>
+    
+}
+
+
+/**
+ * **User where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type UserWhereUniqueInput = {
+    
+    /**
+     * **Email**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     email?: string
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+}
+
+
+
+export namespace std {
+
+
+    /**
+     * **Sort Order**
+     *
+     * Represents the sort order
+     */
+    export type Sort = "asc" | "desc"
+
+    /**
+     * **String Match Mode**
+     *
+     * Whether the string query is case sensitive or not
+     */
+    export type StringMatchMode = "default" | "caseInsensitive"
+
+    /**
+     * ## Sort Order
+     *
+     * Represents the sort order
+     */
+    export const enum SortEnumType {
+
+        /**
+         * ### Asc
+         *
+         * This enum member doesn't have a description.
+         */
+        asc = "asc",
+
+        /**
+         * ### Desc
+         *
+         * This enum member doesn't have a description.
+         */
+        desc = "desc",
+    }
+
+    /**
+     * ## String Match Mode
+     *
+     * Whether the string query is case sensitive or not
+     */
+    export const enum StringMatchModeEnumType {
+
+        /**
+         * ### Default
+         *
+         * This enum member doesn't have a description.
+         */
+        default = "default",
+
+        /**
+         * ### Case insensitive
+         *
+         * This enum member doesn't have a description.
+         */
+        caseInsensitive = "caseInsensitive",
+    }
+
+
+    /**
+     * **Aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type AggregatesFilter<T> = std.Filter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.Filter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.Filter<T>
+        
+    }
+
+
+    /**
+     * **Array atomic update operation input**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ArrayAtomicUpdateOperationInput<T> = {
+        
+        /**
+         * **Push**
+         *
+         * This interface field doesn't have a description.
+         */
+         push?: T
+        
+    }
+
+
+    /**
+     * **Array filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ArrayFilter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T[]
+        
+        /**
+         * **Has**
+         *
+         * This interface field doesn't have a description.
+         */
+         has?: T
+        
+        /**
+         * **Has some**
+         *
+         * This interface field doesn't have a description.
+         */
+         hasSome?: T[]
+        
+        /**
+         * **Has every**
+         *
+         * This interface field doesn't have a description.
+         */
+         hasEvery?: T[]
+        
+        /**
+         * **Is empty**
+         *
+         * This interface field doesn't have a description.
+         */
+         isEmpty?: boolean
+        
+        /**
+         * **Length**
+         *
+         * This interface field doesn't have a description.
+         */
+         length?: number
+        
+    }
+
+
+    /**
+     * **Array nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ArrayNullableFilter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T[] | null
+        
+        /**
+         * **Has**
+         *
+         * This interface field doesn't have a description.
+         */
+         has?: T
+        
+        /**
+         * **Has some**
+         *
+         * This interface field doesn't have a description.
+         */
+         hasSome?: T[]
+        
+        /**
+         * **Has every**
+         *
+         * This interface field doesn't have a description.
+         */
+         hasEvery?: T[]
+        
+        /**
+         * **Is empty**
+         *
+         * This interface field doesn't have a description.
+         */
+         isEmpty?: boolean
+        
+        /**
+         * **Length**
+         *
+         * This interface field doesn't have a description.
+         */
+         length?: number
+        
+    }
+
+
+    /**
+     * **Array nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ArrayNullableWithAggregatesFilter<T> = std.ArrayNullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.ArrayNullableFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.ArrayNullableFilter<T>
+        
+    }
+
+
+    /**
+     * **Array with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ArrayWithAggregatesFilter<T> = std.ArrayFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.ArrayFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.ArrayFilter<T>
+        
+    }
+
+
+    /**
+     * **Bool filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type BoolFilter = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: boolean
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: boolean | std.BoolFilter
+        
+    }
+
+
+    /**
+     * **Bool nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type BoolNullableFilter = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: boolean | null
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: boolean | null | std.BoolNullableFilter
+        
+    }
+
+
+    /**
+     * **Bool nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type BoolNullableWithAggregatesFilter = std.BoolNullableFilter & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.BoolNullableFilter
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.BoolNullableFilter
+        
+    }
+
+
+    /**
+     * **Bool with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type BoolWithAggregatesFilter = std.BoolFilter & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.BoolFilter
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.BoolFilter
+        
+    }
+
+
+    /**
+     * **Data**
+     *
+     * This interface is common for action output
+     */
+    export type Data<T> = {
+        
+        /**
+         * **Data**
+         *
+         * This interface field doesn't have a description.
+         */
+         data: T
+        
+    }
+
+
+    /**
+     * **Data and Meta**
+     *
+     * This interface is common for action output with meta information
+     */
+    export type DataMeta<T, U> = {
+        
+        /**
+         * **Data**
+         *
+         * This interface field doesn't have a description.
+         */
+         data: T
+        
+        /**
+         * **Meta**
+         *
+         * This interface field doesn't have a description.
+         */
+         meta: U
+        
+    }
+
+
+    /**
+     * **Decimal nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type DecimalNullableWithAggregatesFilter<T> = std.NullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.NullableFilter<Decimal>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.NullableFilter<Decimal>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.NullableFilter<Decimal>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.NullableFilter<Decimal>
+        
+    }
+
+
+    /**
+     * **Decimal with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type DecimalWithAggregatesFilter = std.Filter<Decimal> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.Filter<Decimal>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.Filter<Decimal>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.Filter<Decimal>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.Filter<Decimal>
+        
+    }
+
+
+    /**
+     * **Empty**
+     *
+     * The empty interface
+     */
+    export type Empty = {
+        
+    }
+
+
+    /**
+     * **Enum filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type EnumFilter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: T[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: T[]
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: T | std.EnumFilter<T>
+        
+    }
+
+
+    /**
+     * **Enum nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type EnumNullableFilter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T | null
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: (T | null)[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: (T | null)[]
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: T | null | std.EnumNullableFilter<T>
+        
+    }
+
+
+    /**
+     * **Enum nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type EnumNullableWithAggregatesFilter<T> = std.EnumNullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.EnumNullableFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.EnumNullableFilter<T>
+        
+    }
+
+
+    /**
+     * **Enum with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type EnumWithAggregatesFilter<T> = std.EnumFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.EnumFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.EnumFilter<T>
+        
+    }
+
+
+    /**
+     * **Filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type Filter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: T[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: T[]
+        
+        /**
+         * **Lt**
+         *
+         * This interface field doesn't have a description.
+         */
+         lt?: T
+        
+        /**
+         * **Lte**
+         *
+         * This interface field doesn't have a description.
+         */
+         lte?: T
+        
+        /**
+         * **Gt**
+         *
+         * This interface field doesn't have a description.
+         */
+         gt?: T
+        
+        /**
+         * **Gte**
+         *
+         * This interface field doesn't have a description.
+         */
+         gte?: T
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: T | std.Filter<T>
+        
+    }
+
+
+    /**
+     * **Float number nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type FloatNumberNullableWithAggregatesFilter<T> = std.NullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.NullableFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.NullableFilter<T>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.NullableFilter<number>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.NullableFilter<number>
+        
+    }
+
+
+    /**
+     * **Float number with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type FloatNumberWithAggregatesFilter<T> = std.Filter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.Filter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.Filter<T>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.Filter<number>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.Filter<number>
+        
+    }
+
+
+    /**
+     * **Int number nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type IntNumberNullableWithAggregatesFilter<T> = std.NullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.NullableFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.NullableFilter<T>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.NullableFilter<number>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.NullableFilter<Int64>
+        
+    }
+
+
+    /**
+     * **Int number with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type IntNumberWithAggregatesFilter<T> = std.Filter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.Filter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.Filter<T>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.Filter<number>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.Filter<Int64>
+        
+    }
+
+
+    /**
+     * **Nullable aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type NullableAggregatesFilter<T> = std.NullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.NullableFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.NullableFilter<T>
+        
+    }
+
+
+    /**
+     * **Nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type NullableFilter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T | null
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: (T | null)[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: (T | null)[]
+        
+        /**
+         * **Lt**
+         *
+         * This interface field doesn't have a description.
+         */
+         lt?: T
+        
+        /**
+         * **Lte**
+         *
+         * This interface field doesn't have a description.
+         */
+         lte?: T
+        
+        /**
+         * **Gt**
+         *
+         * This interface field doesn't have a description.
+         */
+         gt?: T
+        
+        /**
+         * **Gte**
+         *
+         * This interface field doesn't have a description.
+         */
+         gte?: T
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: T | null | std.NullableFilter<T>
+        
+    }
+
+
+    /**
+     * **Number atomic update operation input**
+     *
+     * This interface doesn't have a description.
+     */
+    export type NumberAtomicUpdateOperationInput<T> = {
+        
+        /**
+         * **Increment**
+         *
+         * This interface field doesn't have a description.
+         */
+         increment?: T
+        
+        /**
+         * **Decrement**
+         *
+         * This interface field doesn't have a description.
+         */
+         decrement?: T
+        
+        /**
+         * **Multiply**
+         *
+         * This interface field doesn't have a description.
+         */
+         multiply?: T
+        
+        /**
+         * **Divide**
+         *
+         * This interface field doesn't have a description.
+         */
+         divide?: T
+        
+    }
+
+
+    /**
+     * **Paging info**
+     *
+     * This interface doesn't have a description.
+     */
+    export type PagingInfo = {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         count: Int64
+        
+        /**
+         * **Number of pages**
+         *
+         * This interface field doesn't have a description.
+         */
+         numberOfPages?: Int64
+        
+    }
+
+
+    /**
+     * **Response error**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ResponseError = {
+        
+        /**
+         * **Type**
+         *
+         * This interface field doesn't have a description.
+         */
+         type: string
+        
+        /**
+         * **Message**
+         *
+         * This interface field doesn't have a description.
+         */
+         message: string
+        
+        /**
+         * **Fields**
+         *
+         * This interface field doesn't have a description.
+         */
+         fields: {[key: string]: string} | null
+        
+    }
+
+
+    /**
+     * **String filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type StringFilter = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: string
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: string[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: string[]
+        
+        /**
+         * **Lt**
+         *
+         * This interface field doesn't have a description.
+         */
+         lt?: string
+        
+        /**
+         * **Lte**
+         *
+         * This interface field doesn't have a description.
+         */
+         lte?: string
+        
+        /**
+         * **Gt**
+         *
+         * This interface field doesn't have a description.
+         */
+         gt?: string
+        
+        /**
+         * **Gte**
+         *
+         * This interface field doesn't have a description.
+         */
+         gte?: string
+        
+        /**
+         * **Contains**
+         *
+         * This interface field doesn't have a description.
+         */
+         contains?: string
+        
+        /**
+         * **Starts with**
+         *
+         * This interface field doesn't have a description.
+         */
+         startsWith?: string
+        
+        /**
+         * **Ends with**
+         *
+         * This interface field doesn't have a description.
+         */
+         endsWith?: string
+        
+        /**
+         * **Matches**
+         *
+         * This interface field doesn't have a description.
+         */
+         matches?: string
+        
+        /**
+         * **Mode**
+         *
+         * This interface field doesn't have a description.
+         */
+         mode?: std.StringMatchMode
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: string | std.StringFilter
+        
+    }
+
+
+    /**
+     * **String nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type StringNullableFilter = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: string | null
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: (string | null)[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: (string | null)[]
+        
+        /**
+         * **Lt**
+         *
+         * This interface field doesn't have a description.
+         */
+         lt?: string
+        
+        /**
+         * **Lte**
+         *
+         * This interface field doesn't have a description.
+         */
+         lte?: string
+        
+        /**
+         * **Gt**
+         *
+         * This interface field doesn't have a description.
+         */
+         gt?: string
+        
+        /**
+         * **Gte**
+         *
+         * This interface field doesn't have a description.
+         */
+         gte?: string
+        
+        /**
+         * **Contains**
+         *
+         * This interface field doesn't have a description.
+         */
+         contains?: string
+        
+        /**
+         * **Starts with**
+         *
+         * This interface field doesn't have a description.
+         */
+         startsWith?: string
+        
+        /**
+         * **Ends with**
+         *
+         * This interface field doesn't have a description.
+         */
+         endsWith?: string
+        
+        /**
+         * **Matches**
+         *
+         * This interface field doesn't have a description.
+         */
+         matches?: string
+        
+        /**
+         * **Mode**
+         *
+         * This interface field doesn't have a description.
+         */
+         mode?: std.StringMatchMode
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: string | null | std.StringNullableFilter
+        
+    }
+
+
+    /**
+     * **String nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type StringNullableWithAggregatesFilter = std.StringNullableFilter & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.StringNullableFilter
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.StringNullableFilter
+        
+    }
+
+
+    /**
+     * **String with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type StringWithAggregatesFilter = std.StringFilter & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.StringFilter
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.StringFilter
+        
+    }
+
+
+
+    export namespace admin {
+
+
+
+
+
+
+
+        export interface AdminNamespaceDelegate {
+
+            
+
+            
+
+            
+
+            /**
+             * Get a new client altered with `headers`.
+             * @param {headers?} headers - The new headers.
+             */
+            $headers(headers?: {[key: string]: string} | undefined): Teo
+        }
+
+
+
+
+
+    }
+
+    export namespace bcrypt {
+
+
+
+
+
+
+
+        export interface BcryptNamespaceDelegate {
+
+            
+
+            
+
+            
+
+            /**
+             * Get a new client altered with `headers`.
+             * @param {headers?} headers - The new headers.
+             */
+            $headers(headers?: {[key: string]: string} | undefined): Teo
+        }
+
+
+
+
+
+    }
+
+    export namespace identity {
+
+
+
+        /**
+         * **Token info**
+         *
+         * This interface doesn't have a description.
+         */
+        export type TokenInfo = {
+            
+            /**
+             * **Token**
+             *
+             * This interface field doesn't have a description.
+             */
+             token: string
+            
+        }
+
+
+
+
+
+
+        export interface IdentityNamespaceDelegate {
+
+            
+
+            
+
+            
+
+            /**
+             * Get a new client altered with `headers`.
+             * @param {headers?} headers - The new headers.
+             */
+            $headers(headers?: {[key: string]: string} | undefined): Teo
+        }
+
+
+
+
+
+    }
+
+
+
+
+    export interface StdNamespaceDelegate {
+
+        
+
+        
+        admin: std.admin.AdminNamespaceDelegate
+        
+        bcrypt: std.bcrypt.BcryptNamespaceDelegate
+        
+        identity: std.identity.IdentityNamespaceDelegate
+        
+
+        
+
+        /**
+         * Get a new client altered with `headers`.
+         * @param {headers?} headers - The new headers.
+         */
+        $headers(headers?: {[key: string]: string} | undefined): Teo
+    }
+
+
+
+
+
+}
+
+
+
+
+export interface PostDelegate {
+
+    
+    /**
+     * Find a unique post.
+     *
+     * @example
+     * ```ts
+     * // Find a unique post
+     * const { data } = await teo.post.findUnique({
+     *     where: {}, // a unique filter
+     * })
+     * ```
+     */
+    findUnique<T extends PostFindUniqueArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Post, PostGetPayload<T>>>>
+    
+    /**
+     * Find the first post matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Find the first post matching a filter
+     * const { data } = await teo.post.findFirst({
+     *     where: {}, // a filter
+     *     orderBy: {}, // the order to find in
+     * })
+     * ```
+     */
+    findFirst<T extends PostFindFirstArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Post, PostGetPayload<T>>>>
+    
+    /**
+     * Find posts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Find posts matching a filter
+     * const { data } = await teo.post.findMany({
+     *     where: {}, // a filter
+     *     orderBy: {}, // the order to find in
+     *     skip: 0, // records to skip
+     *     take: 10, // records to take
+     * })
+     * ```
+     */
+    findMany<T extends PostFindManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, Post, PostGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Create a post.
+     *
+     * @example
+     * ```ts
+     * // Create a post
+     * const { data } = await teo.post.create({
+     *     create: {}, // the data to create
+     * })
+     * ```
+     */
+    create<T extends PostCreateArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Post, PostGetPayload<T>>>>
+    
+    /**
+     * Update a unique post.
+     *
+     * @example
+     * ```ts
+     * // Update a unique post
+     * const { data } = await teo.post.update({
+     *     where: {}, // a unique filter
+     *     update: {}, // the data to update
+     * })
+     * ```
+     */
+    update<T extends PostUpdateArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Post, PostGetPayload<T>>>>
+    
+    /**
+     * Update a unique post, or create it if it doesn't exist.
+     *
+     * @example
+     * ```ts
+     * // Update a unique post, or create it if it doesn't exist
+     * const { data } = await teo.post.upsert({
+     *     where: {}, // a unique filter
+     *     create: {}, // the data to create
+     *     update: {}, // the data to update
+     * })
+     * ```
+     */
+    upsert<T extends PostUpsertArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Post, PostGetPayload<T>>>>
+    
+    /**
+     * Copy a unique post.
+     *
+     * @example
+     * ```ts
+     * // Copy a unique post
+     * const { data } = await teo.post.copy({
+     *     where: {}, // a unique filter
+     *     copy: {}, // the data to change on the copy
+     * })
+     * ```
+     */
+    copy<T extends PostCopyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Post, PostGetPayload<T>>>>
+    
+    /**
+     * Delete a unique post.
+     *
+     * @example
+     * ```ts
+     * // Delete a unique post
+     * const { data } = await teo.post.delete({
+     *     where: {}, // a unique filter
+     * })
+     * ```
+     */
+    delete<T extends PostDeleteArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Post, PostGetPayload<T>>>>
+    
+    /**
+     * Create many posts.
+     *
+     * @example
+     * ```ts
+     * // Create many posts
+     * const { data } = await teo.post.createMany({
+     *     create: [], // the data to create
+     * })
+     * ```
+     */
+    createMany<T extends PostCreateManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, Post, PostGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Update posts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Update posts matching a filter
+     * const { data } = await teo.post.updateMany({
+     *     where: {}, // a filter
+     *     update: {}, // the data to update
+     * })
+     * ```
+     */
+    updateMany<T extends PostUpdateManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, Post, PostGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Copy posts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Copy posts matching a filter
+     * const { data } = await teo.post.copyMany({
+     *     where: {}, // a filter
+     *     copy: {}, // the data to change on the copies
+     * })
+     * ```
+     */
+    copyMany<T extends PostCopyManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, Post, PostGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Delete posts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Delete posts matching a filter
+     * const { data } = await teo.post.deleteMany({
+     *     where: {}, // a filter
+     * })
+     * ```
+     */
+    deleteMany<T extends PostDeleteManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, Post, PostGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Count posts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Count posts matching a filter
+     * const { data } = await teo.post.count({
+     *     where: {}, // a filter
+     * })
+     * ```
+     */
+    count<T extends PostCountArgs>(body: Subset<T, PostCountArgs>,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<T extends Record<'select', any>
+       ? T['select'] extends true
+         ? number
+         : GetScalarType<T['select'], PostCountAggregateResult>
+       : number>>
+    
+    /**
+     * Aggregate on posts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Aggregate on posts matching a filter
+     * const { data } = await teo.post.aggregate({
+     *     where: {}, // a filter
+     *     _count: { _all: true }, // aggregations like _avg, _sum, _min and _max
+     * })
+     * ```
+     */
+    aggregate<T extends PostAggregateArgs>(body: Subset<T, PostAggregateArgs>,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<GetPostAggregateType<T>>>
+    
+    /**
+     * Group posts by fields and aggregate on each group.
+     *
+     * @example
+     * ```ts
+     * // Group posts by fields and aggregate on each group
+     * const { data } = await teo.post.groupBy({
+     *     by: [], // the fields to group by
+     *     _count: { _all: true }, // aggregations of each group
+     * })
+     * ```
+     */
+    groupBy<T extends PostGroupByArgs,
+      HasSelectOrTake extends Or<
+        Extends<'skip', Keys<T>>,
+        Extends<'take', Keys<T>>
+      >,
+      OrderByArg extends True extends HasSelectOrTake
+        ? { orderBy: PostGroupByArgs['orderBy'] }
+        : { orderBy?: PostGroupByArgs['orderBy'] },
+      OrderFields extends ExcludeUnderscoreKeys<Keys<MaybeTupleToUnion<T['orderBy']>>>,
+      ByFields extends MaybeTupleToUnion<T['by']>,
+      ByValid extends Has<ByFields, OrderFields>,
+      HavingFields extends GetHavingFields<T['having']>,
+      HavingValid extends Has<ByFields, HavingFields>,
+      ByEmpty extends T['by'] extends never[] ? True : False,
+      InputErrors extends ByEmpty extends True
+      ? `Error: "by" must not be empty.`
+      : HavingValid extends False
+      ? {
+          [P in HavingFields]: P extends ByFields
+            ? never
+            : P extends string
+            ? `Error: Field "${P}" used in "having" needs to be provided in "by".`
+            : [
+                Error,
+                'Field ',
+                P,
+                ` in "having" needs to be provided in "by"`,
+              ]
+        }[HavingFields]
+      : 'take' extends Keys<T>
+      ? 'orderBy' extends Keys<T>
+        ? ByValid extends True
+          ? {}
+          : {
+              [P in OrderFields]: P extends ByFields
+                ? never
+                : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+            }[OrderFields]
+        : 'Error: If you provide "take", you also need to provide "orderBy"'
+      : 'skip' extends Keys<T>
+      ? 'orderBy' extends Keys<T>
+        ? ByValid extends True
+          ? {}
+          : {
+              [P in OrderFields]: P extends ByFields
+                ? never
+                : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+            }[OrderFields]
+        : 'Error: If you provide "skip", you also need to provide "orderBy"'
+      : ByValid extends True
+      ? {}
+      : {
+          [P in OrderFields]: P extends ByFields
+            ? never
+            : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+        }[OrderFields]>(body: SubsetIntersection<T, PostGroupByArgs, OrderByArg> & InputErrors,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<{} extends InputErrors ? GetPostGroupByPayload<T> : InputErrors>>
+    
+
+    
+
+    
+
+    /**
+     * Get a new client altered with `headers`.
+     * @param {headers?} headers - The new headers.
+     */
+    $headers(headers?: {[key: string]: string} | undefined): Teo
+}
+
+export interface UserDelegate {
+
+    
+    /**
+     * Find a unique user.
+     *
+     * @example
+     * ```ts
+     * // Find a unique user
+     * const { data } = await teo.user.findUnique({
+     *     where: {}, // a unique filter
+     * })
+     * ```
+     */
+    findUnique<T extends UserFindUniqueArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, User, UserGetPayload<T>>>>
+    
+    /**
+     * Find the first user matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Find the first user matching a filter
+     * const { data } = await teo.user.findFirst({
+     *     where: {}, // a filter
+     *     orderBy: {}, // the order to find in
+     * })
+     * ```
+     */
+    findFirst<T extends UserFindFirstArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, User, UserGetPayload<T>>>>
+    
+    /**
+     * Find users matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Find users matching a filter
+     * const { data } = await teo.user.findMany({
+     *     where: {}, // a filter
+     *     orderBy: {}, // the order to find in
+     *     skip: 0, // records to skip
+     *     take: 10, // records to take
+     * })
+     * ```
+     */
+    findMany<T extends UserFindManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, User, UserGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Create a user.
+     *
+     * @example
+     * ```ts
+     * // Create a user
+     * const { data } = await teo.user.create({
+     *     create: {}, // the data to create
+     * })
+     * ```
+     */
+    create<T extends UserCreateArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, User, UserGetPayload<T>>>>
+    
+    /**
+     * Update a unique user.
+     *
+     * @example
+     * ```ts
+     * // Update a unique user
+     * const { data } = await teo.user.update({
+     *     where: {}, // a unique filter
+     *     update: {}, // the data to update
+     * })
+     * ```
+     */
+    update<T extends UserUpdateArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, User, UserGetPayload<T>>>>
+    
+    /**
+     * Update a unique user, or create it if it doesn't exist.
+     *
+     * @example
+     * ```ts
+     * // Update a unique user, or create it if it doesn't exist
+     * const { data } = await teo.user.upsert({
+     *     where: {}, // a unique filter
+     *     create: {}, // the data to create
+     *     update: {}, // the data to update
+     * })
+     * ```
+     */
+    upsert<T extends UserUpsertArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, User, UserGetPayload<T>>>>
+    
+    /**
+     * Copy a unique user.
+     *
+     * @example
+     * ```ts
+     * // Copy a unique user
+     * const { data } = await teo.user.copy({
+     *     where: {}, // a unique filter
+     *     copy: {}, // the data to change on the copy
+     * })
+     * ```
+     */
+    copy<T extends UserCopyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, User, UserGetPayload<T>>>>
+    
+    /**
+     * Delete a unique user.
+     *
+     * @example
+     * ```ts
+     * // Delete a unique user
+     * const { data } = await teo.user.delete({
+     *     where: {}, // a unique filter
+     * })
+     * ```
+     */
+    delete<T extends UserDeleteArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, User, UserGetPayload<T>>>>
+    
+    /**
+     * Create many users.
+     *
+     * @example
+     * ```ts
+     * // Create many users
+     * const { data } = await teo.user.createMany({
+     *     create: [], // the data to create
+     * })
+     * ```
+     */
+    createMany<T extends UserCreateManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, User, UserGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Update users matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Update users matching a filter
+     * const { data } = await teo.user.updateMany({
+     *     where: {}, // a filter
+     *     update: {}, // the data to update
+     * })
+     * ```
+     */
+    updateMany<T extends UserUpdateManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, User, UserGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Copy users matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Copy users matching a filter
+     * const { data } = await teo.user.copyMany({
+     *     where: {}, // a filter
+     *     copy: {}, // the data to change on the copies
+     * })
+     * ```
+     */
+    copyMany<T extends UserCopyManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, User, UserGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Delete users matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Delete users matching a filter
+     * const { data } = await teo.user.deleteMany({
+     *     where: {}, // a filter
+     * })
+     * ```
+     */
+    deleteMany<T extends UserDeleteManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, User, UserGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Count users matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Count users matching a filter
+     * const { data } = await teo.user.count({
+     *     where: {}, // a filter
+     * })
+     * ```
+     */
+    count<T extends UserCountArgs>(body: Subset<T, UserCountArgs>,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<T extends Record<'select', any>
+       ? T['select'] extends true
+         ? number
+         : GetScalarType<T['select'], UserCountAggregateResult>
+       : number>>
+    
+    /**
+     * Aggregate on users matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Aggregate on users matching a filter
+     * const { data } = await teo.user.aggregate({
+     *     where: {}, // a filter
+     *     _count: { _all: true }, // aggregations like _avg, _sum, _min and _max
+     * })
+     * ```
+     */
+    aggregate<T extends UserAggregateArgs>(body: Subset<T, UserAggregateArgs>,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<GetUserAggregateType<T>>>
+    
+    /**
+     * Group users by fields and aggregate on each group.
+     *
+     * @example
+     * ```ts
+     * // Group users by fields and aggregate on each group
+     * const { data } = await teo.user.groupBy({
+     *     by: [], // the fields to group by
+     *     _count: { _all: true }, // aggregations of each group
+     * })
+     * ```
+     */
+    groupBy<T extends UserGroupByArgs,
+      HasSelectOrTake extends Or<
+        Extends<'skip', Keys<T>>,
+        Extends<'take', Keys<T>>
+      >,
+      OrderByArg extends True extends HasSelectOrTake
+        ? { orderBy: UserGroupByArgs['orderBy'] }
+        : { orderBy?: UserGroupByArgs['orderBy'] },
+      OrderFields extends ExcludeUnderscoreKeys<Keys<MaybeTupleToUnion<T['orderBy']>>>,
+      ByFields extends MaybeTupleToUnion<T['by']>,
+      ByValid extends Has<ByFields, OrderFields>,
+      HavingFields extends GetHavingFields<T['having']>,
+      HavingValid extends Has<ByFields, HavingFields>,
+      ByEmpty extends T['by'] extends never[] ? True : False,
+      InputErrors extends ByEmpty extends True
+      ? `Error: "by" must not be empty.`
+      : HavingValid extends False
+      ? {
+          [P in HavingFields]: P extends ByFields
+            ? never
+            : P extends string
+            ? `Error: Field "${P}" used in "having" needs to be provided in "by".`
+            : [
+                Error,
+                'Field ',
+                P,
+                ` in "having" needs to be provided in "by"`,
+              ]
+        }[HavingFields]
+      : 'take' extends Keys<T>
+      ? 'orderBy' extends Keys<T>
+        ? ByValid extends True
+          ? {}
+          : {
+              [P in OrderFields]: P extends ByFields
+                ? never
+                : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+            }[OrderFields]
+        : 'Error: If you provide "take", you also need to provide "orderBy"'
+      : 'skip' extends Keys<T>
+      ? 'orderBy' extends Keys<T>
+        ? ByValid extends True
+          ? {}
+          : {
+              [P in OrderFields]: P extends ByFields
+                ? never
+                : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+            }[OrderFields]
+        : 'Error: If you provide "skip", you also need to provide "orderBy"'
+      : ByValid extends True
+      ? {}
+      : {
+          [P in OrderFields]: P extends ByFields
+            ? never
+            : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+        }[OrderFields]>(body: SubsetIntersection<T, UserGroupByArgs, OrderByArg> & InputErrors,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<{} extends InputErrors ? GetUserGroupByPayload<T> : InputErrors>>
+    
+
+    
+
+    
+
+    /**
+     * Get a new client altered with `headers`.
+     * @param {headers?} headers - The new headers.
+     */
+    $headers(headers?: {[key: string]: string} | undefined): Teo
+}
+
+export interface Teo {
+
+    
+
+    
+    std: std.StdNamespaceDelegate
+    
+
+    
+    post: PostDelegate
+    
+    user: UserDelegate
+    
+
+    /**
+     * Get a new client altered with `headers`.
+     * @param {headers?} headers - The new headers.
+     */
+    $headers(headers?: {[key: string]: string} | undefined): Teo
+}
+
+
+
+/**
+ * ## Teo API Client
+ *
+ * Teo API client for TypeScript & javaScript. It supports both browser and
+ * node.js. It's generated by the fantastic Teo framework.
+ *
+ * @example
+ * ```ts
+ * // Fetch posts
+ * const { data } = await teo.post.findMany({})
+ * ```
+ *
+ */
+export const teo: Teo
+
+
@@ -0,0 +1,423 @@
+// @typescript-eslint/no-unused-vars
+
+import Decimal from 'decimal.js'
+
+let groupDelegateMap = [
+    "Post",
+    "User",
+    "std",
+    "std.DataSetRecord",
+    "std.DataSetRelation",
+    "std.admin",
+    "std.bcrypt",
+    "std.identity"
+]
+let customHandlerMap = {
+    "Search.post":{ method: "GET", path: "/posts/:id", pathArguments: true },
+    "Search.upload":{ method: "POST", path: "Search/upload", pathArguments: false },
+    "Search.users":{ method: "POST", path: "Search/users", pathArguments: false }
+}
+
+// Where the Int64 values are: 1 is an Int64, a string refers to an interface,
+// [shape] is an array, { "*": shape } a dictionary, { "|": [shapes] } a union
+// and other objects list fields
+let int64Shapes = {
+    "Post": { "author": "User", "views": 1 },
+    "PostAggregateArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1, "where": "PostWhereInput" },
+    "PostAggregateResult": { "_count": "PostCountAggregateResult", "_max": "PostMaxAggregateResult", "_min": "PostMinAggregateResult", "_sum": "PostSumAggregateResult" },
+    "PostArgs": { "include": "PostInclude" },
+    "PostConnectOrCreateInput": { "create": "PostCreateInput" },
+    "PostConnectOrCreateWithoutAuthorInput": { "create": "PostCreateInput" },
+    "PostCopyArgs": { "copy": "PostUpdateInput", "include": "PostInclude" },
+    "PostCopyManyArgs": { "copy": "PostUpdateInput", "include": "PostInclude", "where": "PostWhereInput" },
+    "PostCountAggregateResult": { "_all": 1, "authorId": 1, "content": 1, "id": 1, "tags": 1, "title": 1, "views": 1 },
+    "PostCountArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1, "where": "PostWhereInput" },
+    "PostCreateArgs": { "create": "PostCreateInput", "include": "PostInclude" },
+    "PostCreateInput": { "author": "UserCreateNestedOneWithoutPostsInput", "views": 1 },
+    "PostCreateManyArgs": { "create": { "|": ["PostCreateInput", ["PostCreateInput"]] }, "include": "PostInclude" },
+    "PostCreateNestedManyInput": { "connectOrCreate": { "|": ["PostConnectOrCreateInput", ["PostConnectOrCreateInput"]] }, "create": { "|": ["PostCreateInput", ["PostCreateInput"]] } },
+    "PostCreateNestedManyWithoutAuthorInput": { "connectOrCreate": { "|": ["PostConnectOrCreateWithoutAuthorInput", ["PostConnectOrCreateWithoutAuthorInput"]] }, "create": { "|": ["PostCreateInput", ["PostCreateInput"]] } },
+    "PostCreateNestedOneInput": { "connectOrCreate": "PostConnectOrCreateInput", "create": "PostCreateInput" },
+    "PostCreateNestedOneWithoutAuthorInput": { "connectOrCreate": "PostConnectOrCreateWithoutAuthorInput", "create": "PostCreateInput" },
+    "PostCreateWithoutAuthorInput": { "views": 1 },
+    "PostDeleteArgs": { "include": "PostInclude" },
+    "PostDeleteManyArgs": { "include": "PostInclude", "where": "PostWhereInput" },
+    "PostFindFirstArgs": { "include": "PostInclude", "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1, "where": "PostWhereInput" },
+    "PostFindManyArgs": { "include": "PostInclude", "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1, "where": "PostWhereInput" },
+    "PostFindUniqueArgs": { "include": "PostInclude" },
+    "PostGroupByArgs": { "having": "PostScalarWhereWithAggregatesInput", "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1, "where": "PostWhereInput" },
+    "PostGroupByResult": { "_count": "PostCountAggregateResult", "_max": "PostMaxAggregateResult", "_min": "PostMinAggregateResult", "_sum": "PostSumAggregateResult", "views": 1 },
+    "PostInclude": { "author": "UserArgs" },
+    "PostListRelationFilter": { "every": "PostWhereInput", "none": "PostWhereInput", "some": "PostWhereInput" },
+    "PostMaxAggregateResult": { "views": 1 },
+    "PostMinAggregateResult": { "views": 1 },
+    "PostRelationFilter": { "is": "PostWhereInput", "isNot": "PostWhereInput" },
+    "PostScalarUpdateInput": { "views": 1 },
+    "PostScalarWhereWithAggregatesInput": { "AND": ["PostWhereInput"], "NOT": "PostWhereInput", "OR": ["PostWhereInput"], "authorId": { "_count": 1, "_sum": { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": 1, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] } }, "content": "std.StringNullableWithAggregatesFilter", "id": { "_count": 1, "_sum": { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": 1, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] } }, "tags": { "_count": 1 }, "title": "std.StringWithAggregatesFilter", "views": { "|": [1, { "_count": 1, "_max": { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": 1, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }, "_min": { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": 1, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }, "_sum": { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": 1, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }, "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": 1, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] } },
+    "PostSignInInput": { "include": "PostInclude" },
+    "PostSumAggregateResult": { "authorId": 1, "id": 1, "views": 1 },
+    "PostUpdateArgs": { "include": "PostInclude", "update": "PostUpdateInput" },
+    "PostUpdateInput": { "author": "UserUpdateNestedOneWithoutPostsInput", "views": 1 },
+    "PostUpdateManyArgs": { "include": "PostInclude", "update": "PostUpdateInput", "where": "PostWhereInput" },
+    "PostUpdateManyWithWhereInput": { "update": "PostUpdateInput", "where": "PostWhereInput" },
+    "PostUpdateManyWithWhereWithoutAuthorInput": { "update": "PostUpdateInput", "where": "PostWhereInput" },
+    "PostUpdateNestedManyInput": { "connectOrCreate": { "|": ["PostConnectOrCreateInput", ["PostConnectOrCreateInput"]] }, "create": { "|": ["PostCreateInput", ["PostCreateInput"]] }, "deleteMany": { "|": ["PostWhereInput", ["PostWhereInput"]] }, "update": { "|": ["PostUpdateWithWhereUniqueInput", ["PostUpdateWithWhereUniqueInput"]] }, "updateMany": { "|": ["PostUpdateManyWithWhereInput", ["PostUpdateManyWithWhereInput"]] }, "upsert": { "|": ["PostUpsertWithWhereUniqueInput", ["PostUpsertWithWhereUniqueInput"]] } },
+    "PostUpdateNestedManyWithoutAuthorInput": { "connectOrCreate": { "|": ["PostConnectOrCreateWithoutAuthorInput", ["PostConnectOrCreateWithoutAuthorInput"]] }, "create": { "|": ["PostCreateInput", ["PostCreateInput"]] }, "deleteMany": { "|": ["PostWhereInput", ["PostWhereInput"]] }, "update": { "|": ["PostUpdateWithWhereUniqueWithoutAuthorInput", ["PostUpdateWithWhereUniqueWithoutAuthorInput"]] }, "updateMany": { "|": ["PostUpdateManyWithWhereWithoutAuthorInput", ["PostUpdateManyWithWhereWithoutAuthorInput"]] }, "upsert": { "|": ["PostUpsertWithWhereUniqueWithoutAuthorInput", ["PostUpsertWithWhereUniqueWithoutAuthorInput"]] } },
+    "PostUpdateNestedOneInput": { "connectOrCreate": "PostConnectOrCreateInput", "create": "PostCreateInput", "update": "PostUpdateInput", "upsert": "PostUpsertWithWhereUniqueInput" },
+    "PostUpdateNestedOneWithoutAuthorInput": { "connectOrCreate": "PostConnectOrCreateWithoutAuthorInput", "create": "PostCreateInput", "update": "PostUpdateInput", "upsert": "PostUpsertWithWhereUniqueWithoutAuthorInput" },
+    "PostUpdateWithWhereUniqueInput": { "update": "PostUpdateInput" },
+    "PostUpdateWithWhereUniqueWithoutAuthorInput": { "update": "PostUpdateInput" },
+    "PostUpdateWithoutAuthorInput": { "views": 1 },
+    "PostUpsertArgs": { "create": "PostCreateInput", "include": "PostInclude", "update": "PostUpdateInput" },
+    "PostUpsertWithWhereUniqueInput": { "create": "PostCreateInput", "update": "PostUpdateInput" },
+    "PostUpsertWithWhereUniqueWithoutAuthorInput": { "create": "PostCreateInput", "update": "PostUpdateInput" },
+    "PostWhereInput": { "AND": ["PostWhereInput"], "NOT": "PostWhereInput", "OR": ["PostWhereInput"], "author": "UserRelationFilter", "views": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": 1, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] } },
+    "User": { "posts": ["Post"] },
+    "UserAggregateArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1, "where": "UserWhereInput" },
+    "UserAggregateResult": { "_count": "UserCountAggregateResult", "_sum": "UserSumAggregateResult" },
+    "UserArgs": { "include": "UserInclude" },
+    "UserConnectOrCreateInput": { "create": "UserCreateInput" },
+    "UserConnectOrCreateWithoutPostsInput": { "create": "UserCreateInput" },
+    "UserCopyArgs": { "copy": "UserUpdateInput", "include": "UserInclude" },
+    "UserCopyManyArgs": { "copy": "UserUpdateInput", "include": "UserInclude", "where": "UserWhereInput" },
+    "UserCountAggregateResult": { "_all": 1, "balance": 1, "birthday": 1, "createdAt": 1, "email": 1, "id": 1, "name": 1, "sex": 1 },
+    "UserCountArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1, "where": "UserWhereInput" },
+    "UserCreateArgs": { "create": "UserCreateInput", "include": "UserInclude" },
+    "UserCreateInput": { "posts": "PostCreateNestedManyWithoutAuthorInput" },
+    "UserCreateManyArgs": { "create": { "|": ["UserCreateInput", ["UserCreateInput"]] }, "include": "UserInclude" },
+    "UserCreateNestedManyInput": { "connectOrCreate": { "|": ["UserConnectOrCreateInput", ["UserConnectOrCreateInput"]] }, "create": { "|": ["UserCreateInput", ["UserCreateInput"]] } },
+    "UserCreateNestedManyWithoutPostsInput": { "connectOrCreate": { "|": ["UserConnectOrCreateWithoutPostsInput", ["UserConnectOrCreateWithoutPostsInput"]] }, "create": { "|": ["UserCreateInput", ["UserCreateInput"]] } },
+    "UserCreateNestedOneInput": { "connectOrCreate": "UserConnectOrCreateInput", "create": "UserCreateInput" },
+    "UserCreateNestedOneWithoutPostsInput": { "connectOrCreate": "UserConnectOrCreateWithoutPostsInput", "create": "UserCreateInput" },
+    "UserDeleteArgs": { "include": "UserInclude" },
+    "UserDeleteManyArgs": { "include": "UserInclude", "where": "UserWhereInput" },
+    "UserFindFirstArgs": { "include": "UserInclude", "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1, "where": "UserWhereInput" },
+    "UserFindManyArgs": { "include": "UserInclude", "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1, "where": "UserWhereInput" },
+    "UserFindUniqueArgs": { "include": "UserInclude" },
+    "UserGroupByArgs": { "having": "UserScalarWhereWithAggregatesInput", "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1, "where": "UserWhereInput" },
+    "UserGroupByResult": { "_count": "UserCountAggregateResult", "_sum": "UserSumAggregateResult" },
+    "UserInclude": { "posts": "PostFindManyArgs" },
+    "UserListRelationFilter": { "every": "UserWhereInput", "none": "UserWhereInput", "some": "UserWhereInput" },
+    "UserRelationFilter": { "is": "UserWhereInput", "isNot": "UserWhereInput" },
+    "UserScalarWhereWithAggregatesInput": { "AND": ["UserWhereInput"], "NOT": "UserWhereInput", "OR": ["UserWhereInput"], "balance": { "_count": 1 }, "birthday": { "_count": 1 }, "createdAt": { "_count": 1 }, "email": "std.StringWithAggregatesFilter", "id": { "_count": 1, "_sum": { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": 1, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] } }, "name": "std.StringNullableWithAggregatesFilter", "sex": { "_count": 1 } },
+    "UserSignInInput": { "include": "UserInclude" },
+    "UserSumAggregateResult": { "id": 1 },
+    "UserUpdateArgs": { "include": "UserInclude", "update": "UserUpdateInput" },
+    "UserUpdateInput": { "posts": "PostUpdateNestedManyWithoutAuthorInput" },
+    "UserUpdateManyArgs": { "include": "UserInclude", "update": "UserUpdateInput", "where": "UserWhereInput" },
+    "UserUpdateManyWithWhereInput": { "update": "UserUpdateInput", "where": "UserWhereInput" },
+    "UserUpdateManyWithWhereWithoutPostsInput": { "update": "UserUpdateInput", "where": "UserWhereInput" },
+    "UserUpdateNestedManyInput": { "connectOrCreate": { "|": ["UserConnectOrCreateInput", ["UserConnectOrCreateInput"]] }, "create": { "|": ["UserCreateInput", ["UserCreateInput"]] }, "deleteMany": { "|": ["UserWhereInput", ["UserWhereInput"]] }, "update": { "|": ["UserUpdateWithWhereUniqueInput", ["UserUpdateWithWhereUniqueInput"]] }, "updateMany": { "|": ["UserUpdateManyWithWhereInput", ["UserUpdateManyWithWhereInput"]] }, "upsert": { "|": ["UserUpsertWithWhereUniqueInput", ["UserUpsertWithWhereUniqueInput"]] } },
+    "UserUpdateNestedManyWithoutPostsInput": { "connectOrCreate": { "|": ["UserConnectOrCreateWithoutPostsInput", ["UserConnectOrCreateWithoutPostsInput"]] }, "create": { "|": ["UserCreateInput", ["UserCreateInput"]] }, "deleteMany": { "|": ["UserWhereInput", ["UserWhereInput"]] }, "update": { "|": ["UserUpdateWithWhereUniqueWithoutPostsInput", ["UserUpdateWithWhereUniqueWithoutPostsInput"]] }, "updateMany": { "|": ["UserUpdateManyWithWhereWithoutPostsInput", ["UserUpdateManyWithWhereWithoutPostsInput"]] }, "upsert": { "|": ["UserUpsertWithWhereUniqueWithoutPostsInput", ["UserUpsertWithWhereUniqueWithoutPostsInput"]] } },
+    "UserUpdateNestedOneInput": { "connectOrCreate": "UserConnectOrCreateInput", "create": "UserCreateInput", "update": "UserUpdateInput", "upsert": "UserUpsertWithWhereUniqueInput" },
+    "UserUpdateNestedOneWithoutPostsInput": { "connectOrCreate": "UserConnectOrCreateWithoutPostsInput", "create": "UserCreateInput", "update": "UserUpdateInput", "upsert": "UserUpsertWithWhereUniqueWithoutPostsInput" },
+    "UserUpdateWithWhereUniqueInput": { "update": "UserUpdateInput" },
+    "UserUpdateWithWhereUniqueWithoutPostsInput": { "update": "UserUpdateInput" },
+    "UserUpsertArgs": { "create": "UserCreateInput", "include": "UserInclude", "update": "UserUpdateInput" },
+    "UserUpsertWithWhereUniqueInput": { "create": "UserCreateInput", "update": "UserUpdateInput" },
+    "UserUpsertWithWhereUniqueWithoutPostsInput": { "create": "UserCreateInput", "update": "UserUpdateInput" },
+    "UserWhereInput": { "AND": ["UserWhereInput"], "NOT": "UserWhereInput", "OR": ["UserWhereInput"], "posts": "PostListRelationFilter" },
+    "std.BoolNullableWithAggregatesFilter": { "_count": 1 },
+    "std.BoolWithAggregatesFilter": { "_count": 1 },
+    "std.DecimalWithAggregatesFilter": { "_count": 1 },
+    "std.PagingInfo": { "count": 1, "numberOfPages": 1 },
+    "std.StringNullableWithAggregatesFilter": { "_count": 1 },
+    "std.StringWithAggregatesFilter": { "_count": 1 }
+}
+let int64Handlers = {
+    "Post.aggregate": { input: "PostAggregateArgs", output: { "data": "PostAggregateResult" } },
+    "Post.copy": { input: "PostCopyArgs", output: { "data": "Post" } },
+    "Post.copyMany": { input: "PostCopyManyArgs", output: { "data": ["Post"], "meta": "std.PagingInfo" } },
+    "Post.count": { input: "PostCountArgs", output: { "data": 1 } },
+    "Post.create": { input: "PostCreateArgs", output: { "data": "Post" } },
+    "Post.createMany": { input: "PostCreateManyArgs", output: { "data": ["Post"], "meta": "std.PagingInfo" } },
+    "Post.delete": { input: "PostDeleteArgs", output: { "data": "Post" } },
+    "Post.deleteMany": { input: "PostDeleteManyArgs", output: { "data": ["Post"], "meta": "std.PagingInfo" } },
+    "Post.findFirst": { input: "PostFindFirstArgs", output: { "data": "Post" } },
+    "Post.findMany": { input: "PostFindManyArgs", output: { "data": ["Post"], "meta": "std.PagingInfo" } },
+    "Post.findUnique": { input: "PostFindUniqueArgs", output: { "data": "Post" } },
+    "Post.groupBy": { input: "PostGroupByArgs", output: { "data": "PostGroupByResult" } },
+    "Post.update": { input: "PostUpdateArgs", output: { "data": "Post" } },
+    "Post.updateMany": { input: "PostUpdateManyArgs", output: { "data": ["Post"], "meta": "std.PagingInfo" } },
+    "Post.upsert": { input: "PostUpsertArgs", output: { "data": "Post" } },
+    "User.aggregate": { input: "UserAggregateArgs", output: { "data": "UserAggregateResult" } },
+    "User.copy": { input: "UserCopyArgs", output: { "data": "User" } },
+    "User.copyMany": { input: "UserCopyManyArgs", output: { "data": ["User"], "meta": "std.PagingInfo" } },
+    "User.count": { input: "UserCountArgs", output: { "data": 1 } },
+    "User.create": { input: "UserCreateArgs", output: { "data": "User" } },
+    "User.createMany": { input: "UserCreateManyArgs", output: { "data": ["User"], "meta": "std.PagingInfo" } },
+    "User.delete": { input: "UserDeleteArgs", output: { "data": "User" } },
+    "User.deleteMany": { input: "UserDeleteManyArgs", output: { "data": ["User"], "meta": "std.PagingInfo" } },
+    "User.findFirst": { input: "UserFindFirstArgs", output: { "data": "User" } },
+    "User.findMany": { input: "UserFindManyArgs", output: { "data": ["User"], "meta": "std.PagingInfo" } },
+    "User.findUnique": { input: "UserFindUniqueArgs", output: { "data": "User" } },
+    "User.groupBy": { input: "UserGroupByArgs", output: { "data": "UserGroupByResult" } },
+    "User.update": { input: "UserUpdateArgs", output: { "data": "User" } },
+    "User.updateMany": { input: "UserUpdateManyArgs", output: { "data": ["User"], "meta": "std.PagingInfo" } },
+    "User.upsert": { input: "UserUpsertArgs", output: { "data": "User" } }
+}
+
+function resolveShape(shape) {
+    while (typeof shape === 'string') {
+        shape = int64Shapes[shape]
+    }
+    return shape
+}
+
+function unionShape(shapes) {
+    shapes = shapes.filter((shape) => shape !== undefined)
+    return shapes.length === 0 ? undefined : shapes.length === 1 ? shapes[0] : { "|": shapes }
+}
+
+function isInt64Shape(shape) {
+    shape = resolveShape(shape)
+    return shape === 1 || (shape !== undefined && shape["|"] !== undefined && shape["|"].some(isInt64Shape))
+}
+
+function elementShape(shape) {
+    shape = resolveShape(shape)
+    if (Array.isArray(shape)) {
+        return shape[0]
+    } else if (shape !== undefined && shape["|"] !== undefined) {
+        return unionShape(shape["|"].map(elementShape))
+    }
+}
+
+function fieldShape(shape, key) {
+    shape = resolveShape(shape)
+    if (shape === undefined || shape === 1 || Array.isArray(shape)) {
+        return undefined
+    } else if (shape["|"] !== undefined) {
+        return unionShape(shape["|"].map((shape) => fieldShape(shape, key)))
+    } else if (Object.prototype.hasOwnProperty.call(shape, key)) {
+        return shape[key]
+    } else {
+        return shape["*"]
+    }
+}
+
+// JSON with bigints, and integer strings where the schema has an Int64, written as integers
+function serializeBody(value, shape) {
+    if (typeof value === 'bigint') {
+        return value.toString()
+    } else if (typeof value === 'string' && /^-?\d+$/.test(value) && isInt64Shape(shape)) {
+        return value
+    } else if (Array.isArray(value)) {
+        const element = elementShape(shape)
+        return '[' + value.map((item) => {
+            const json = serializeBody(item, element)
+            return json === undefined ? 'null' : json
+        }).join(',') + ']'
+    } else if (typeof value === 'object' && value !== null && typeof value.toJSON !== 'function') {
+        const entries = Object.entries(value).map(([key, item]) => [key, serializeBody(item, fieldShape(shape, key))])
+        return '{' + entries.filter(([, item]) => item !== undefined).map(([key, item]) => JSON.stringify(key) + ':' + item).join(',') + '}'
+    } else {
+        return JSON.stringify(value)
+    }
+}
+
+// Integers beyond Number.MAX_SAFE_INTEGER are wrapped before JSON.parse rounds them
+function protectInt64(text) {
+    let result = ""
+    let start = 0
+    let inString = false
+    for (let i = 0; i < text.length; i++) {
+        const c = text[i]
+        if (inString) {
+            if (c === '\\') {
+                i++
+            } else if (c === '"') {
+                inString = false
+            }
+        } else if (c === '"') {
+            inString = true
+        } else if (c === '-' || (c >= '0' && c <= '9')) {
+            let end = i + 1
+            while (end < text.length && /[0-9.eE+\-]/.test(text[end])) {
+                end++
+            }
+            const literal = text.slice(i, end)
+            if (/^-?\d+$/.test(literal) && !Number.isSafeInteger(Number(literal))) {
+                result += text.slice(start, i) + '{"$int64":"' + literal + '"}'
+                start = end
+            }
+            i = end - 1
+        }
+    }
+    return result + text.slice(start)
+}
+
+// Every Int64 the schema has in a response as a bigint
+function decodeInt64(value, shape) {
+    if (shape === undefined || value === null || value === undefined) {
+        return value
+    } else if (typeof value === 'number' || typeof value === 'bigint') {
+        return isInt64Shape(shape) ? BigInt(value) : value
+    } else if (Array.isArray(value)) {
+        const element = elementShape(shape)
+        return element === undefined ? value : value.map((item) => decodeInt64(item, element))
+    } else if (typeof value === 'object' && value.constructor === Object) {
+        for (const key of Object.keys(value)) {
+            value[key] = decodeInt64(value[key], fieldShape(shape, key))
+        }
+        return value
+    } else {
+        return value
+    }
+}
+
+async function request(method, path, body = undefined, headers = undefined, queryString = undefined, shapes = {}) {
+    let url = "http://127.0.0.1:5050/" + path
+    if (queryString) {
+        url = url + "?" + queryString
+    }
+    
+    let response = await fetch(url, {
+        method: method,
+        headers: headers,
+        body: body === undefined ? undefined : (body instanceof FormData ? body : serializeBody(body, shapes.input))
+    })
+    let response_text = await response.text()
+    
+    let response_json = JSON.parse(protectInt64(response_text), (key, value) => {
+        if (typeof value === 'object' && value != null) {
+            if (value['$int64']) {
+                return BigInt(value['$int64'])
+            } else
+            if (value['$datetime']) {
+                return new Date(value['$datetime'])
+            } else if (value['$decimal']) {
+                return new Decimal(value['$decimal'])
+            } else if (value['$date']) {
+                return value['$date']
+            } else {
+                return value
+            }
+        } else {
+            return value
+        }
+    })
+    if (400 <= response.status) {
+        throw new TeoError(response_json.error)
+    }
+    return decodeInt64(response_json, shapes.output)
+}
+
+function replacePathArguments(path, args) {
+    let resultPath = path
+    for (const [key, value] of Object.entries(args)) {
+        resultPath = resultPath.replace(`{${key}}`, value)
+    }
+    return resultPath
+}
+
+function buildHeaders(currentHeaders, headers) {
+    let newHeaders = currentHeaders
+    if (headers && typeof headers === 'object') {
+        if (newHeaders) {
+            newHeaders = Object.assign({}, newHeaders, headers)
+        } else {
+            newHeaders = headers
+        }
+    }
+    return newHeaders
+}
+
+function buildHeadersAndQueryString(currentHeaders, headers, queryString) {
+    let newHeaders = buildHeaders(currentHeaders, headers)
+    let qs = undefined
+    if (typeof headers === 'string') {
+        qs = headers
+    } else if (typeof queryString === 'string') {
+        qs = queryString
+    }
+    return [newHeaders, qs]
+}
+
+class TeoError extends Error {
+    constructor(responseError) {
+        super(responseError.message)
+        this.type = responseError.type
+        this.errors = responseError.errors
+        Object.setPrototypeOf(this, TeoError.prototype)
+    }
+
+    get name() {
+        return "TeoError"
+    }
+}
+
+class GroupDelegate {
+    constructor(path = []) {
+        this._headers = undefined
+        this._path = path
+        let that = this
+        return new Proxy(this, {
+            get(target, name, receiver) {
+                if (name === '$headers') {
+                    return (headers) => {
+                        let retval = new Teo()
+                        retval._headers = headers
+                        retval._path = that._path
+                        return retval
+                    }
+                } else {
+                    let path = that._path.slice()
+                    path.push(name)
+                    if (groupDelegateMap.includes(path.join("."))) {
+                        return new GroupDelegate(path)
+                    } else {
+                        path.pop()
+                        path.push(name[0].toUpperCase() + name.slice(1))
+                        if (groupDelegateMap.includes(path.join("."))) {
+                            return new GroupDelegate(path)
+                        } else {
+                            path.pop()
+                            path.push(name)
+                            let settings = customHandlerMap[path.join(".")]
+                            let shapes = int64Handlers[path.join(".")]
+                            if (!settings) {
+                                return function(body, headers = undefined) {
+                                    let newHeaders = buildHeaders(this._headers, headers)
+                                    return request("POST", path.join("/"), body, newHeaders, undefined, shapes)
+                                }
+                            } else {
+                                if (settings.pathArguments) {
+                                    if (settings.method === 'GET' || settings.method === 'DELETE') {
+                                        return function(pathArguments, headers = undefined, queryString = undefined) {
+                                            let path = replacePathArguments(settings.path, pathArguments)
+                                            let [newHeaders, newQueryString] = buildHeadersAndQueryString(this._headers, headers, queryString)
+                                            return request(settings.method, path, undefined, newHeaders, newQueryString, shapes)
+                                        }
+                                    } else {
+                                        return function(pathArguments, body, headers = undefined, queryString = undefined) {
+                                            let path = replacePathArguments(settings.path, pathArguments)
+                                            let [newHeaders, newQueryString] = buildHeadersAndQueryString(this._headers, headers, queryString)
+                                            return request(settings.method, path, body, newHeaders, newQueryString, shapes)
+                                        }
+                                    }
+                                } else {
+                                    if (settings.method === 'GET' || settings.method === 'DELETE') {
+                                        return function(headers = undefined, queryString = undefined) {
+                                            let [newHeaders, newQueryString] = buildHeadersAndQueryString(this._headers, headers, queryString)
+                                            return request(settings.method, settings.path, undefined, newHeaders, newQueryString, shapes)
+                                        }
+                                    } else {
+                                        return function(body, headers = undefined, queryString = undefined) {
+                                            let [newHeaders, newQueryString] = buildHeadersAndQueryString(this._headers, headers, queryString)
+                                            return request(settings.method, settings.path, body, newHeaders, newQueryString, shapes)
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        })
+    }
+}
+
+const Teo = GroupDelegate
+const teo = new Teo()
+export { TeoError, Teo, teo }
\ No newline at end of file
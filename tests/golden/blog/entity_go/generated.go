@@ -0,0 +1,2684 @@
+// Code generated by Teo. DO NOT EDIT.
+
+package generated
+
+import (
+	"time"
+	teo "github.com/teodevgroup/teo-go"
+	stdlib "generated/stdlib"
+)
+
+// Sex Sex
+//
+// Sex The sex of a user.
+type Sex string
+
+const (
+	// SexMale Male
+	SexMale Sex = "male"
+	// SexFemale Female
+	SexFemale Sex = "female"
+)
+
+// PostDirectRelations Post direct relations
+//
+// This synthesized enum doesn't have a description.
+type PostDirectRelations string
+
+const (
+	// PostDirectRelationsAuthor Author
+	PostDirectRelationsAuthor PostDirectRelations = "author"
+)
+
+// PostIndirectRelations Post indirect relations
+//
+// This synthesized enum doesn't have a description.
+type PostIndirectRelations string
+
+const (
+)
+
+// PostRelations Post relations
+//
+// This synthesized enum doesn't have a description.
+type PostRelations string
+
+const (
+	// PostRelationsAuthor Author
+	PostRelationsAuthor PostRelations = "author"
+)
+
+// PostScalarFields Post scalar fields
+//
+// This synthesized enum doesn't have a description.
+type PostScalarFields string
+
+const (
+	// PostScalarFieldsAuthorId Author id
+	PostScalarFieldsAuthorId PostScalarFields = "authorId"
+	// PostScalarFieldsContent Content
+	PostScalarFieldsContent PostScalarFields = "content"
+	// PostScalarFieldsId Id
+	PostScalarFieldsId PostScalarFields = "id"
+	// PostScalarFieldsTags Tags
+	PostScalarFieldsTags PostScalarFields = "tags"
+	// PostScalarFieldsTitle Title
+	PostScalarFieldsTitle PostScalarFields = "title"
+	// PostScalarFieldsViews Views
+	PostScalarFieldsViews PostScalarFields = "views"
+)
+
+// PostSerializableScalarFields Post serializable scalar fields
+//
+// This synthesized enum doesn't have a description.
+type PostSerializableScalarFields string
+
+const (
+	// PostSerializableScalarFieldsAuthorId Author id
+	PostSerializableScalarFieldsAuthorId PostSerializableScalarFields = "authorId"
+	// PostSerializableScalarFieldsContent Content
+	PostSerializableScalarFieldsContent PostSerializableScalarFields = "content"
+	// PostSerializableScalarFieldsId Id
+	PostSerializableScalarFieldsId PostSerializableScalarFields = "id"
+	// PostSerializableScalarFieldsTags Tags
+	PostSerializableScalarFieldsTags PostSerializableScalarFields = "tags"
+	// PostSerializableScalarFieldsTitle Title
+	PostSerializableScalarFieldsTitle PostSerializableScalarFields = "title"
+	// PostSerializableScalarFieldsViews Views
+	PostSerializableScalarFieldsViews PostSerializableScalarFields = "views"
+)
+
+// UserDirectRelations User direct relations
+//
+// This synthesized enum doesn't have a description.
+type UserDirectRelations string
+
+const (
+	// UserDirectRelationsPosts Posts
+	UserDirectRelationsPosts UserDirectRelations = "posts"
+)
+
+// UserIndirectRelations User indirect relations
+//
+// This synthesized enum doesn't have a description.
+type UserIndirectRelations string
+
+const (
+)
+
+// UserRelations User relations
+//
+// This synthesized enum doesn't have a description.
+type UserRelations string
+
+const (
+	// UserRelationsPosts Posts
+	UserRelationsPosts UserRelations = "posts"
+)
+
+// UserScalarFields User scalar fields
+//
+// This synthesized enum doesn't have a description.
+type UserScalarFields string
+
+const (
+	// UserScalarFieldsBalance Balance
+	UserScalarFieldsBalance UserScalarFields = "balance"
+	// UserScalarFieldsBirthday Birthday
+	UserScalarFieldsBirthday UserScalarFields = "birthday"
+	// UserScalarFieldsCreatedAt Created at
+	UserScalarFieldsCreatedAt UserScalarFields = "createdAt"
+	// UserScalarFieldsEmail Email
+	UserScalarFieldsEmail UserScalarFields = "email"
+	// UserScalarFieldsId Id
+	UserScalarFieldsId UserScalarFields = "id"
+	// UserScalarFieldsName Name
+	UserScalarFieldsName UserScalarFields = "name"
+	// UserScalarFieldsSex Sex
+	UserScalarFieldsSex UserScalarFields = "sex"
+)
+
+// UserSerializableScalarFields User serializable scalar fields
+//
+// This synthesized enum doesn't have a description.
+type UserSerializableScalarFields string
+
+const (
+	// UserSerializableScalarFieldsBalance Balance
+	UserSerializableScalarFieldsBalance UserSerializableScalarFields = "balance"
+	// UserSerializableScalarFieldsBirthday Birthday
+	UserSerializableScalarFieldsBirthday UserSerializableScalarFields = "birthday"
+	// UserSerializableScalarFieldsCreatedAt Created at
+	UserSerializableScalarFieldsCreatedAt UserSerializableScalarFields = "createdAt"
+	// UserSerializableScalarFieldsEmail Email
+	UserSerializableScalarFieldsEmail UserSerializableScalarFields = "email"
+	// UserSerializableScalarFieldsId Id
+	UserSerializableScalarFieldsId UserSerializableScalarFields = "id"
+	// UserSerializableScalarFieldsName Name
+	UserSerializableScalarFieldsName UserSerializableScalarFields = "name"
+	// UserSerializableScalarFieldsSex Sex
+	UserSerializableScalarFieldsSex UserSerializableScalarFields = "sex"
+)
+
+// Keyword Keyword
+//
+// This interface doesn't have a description.
+type Keyword struct {
+	// Value Value
+	Value teo.Value `json:"value"`
+}
+
+// Page Page
+//
+// This interface doesn't have a description.
+type Page[T any] struct {
+	// Items Items
+	Items []T `json:"items"`
+	// Total Total
+	Total int64 `json:"total"`
+}
+
+// PostPathArguments Post path arguments
+//
+// This interface doesn't have a description.
+type PostPathArguments struct {
+	// Id Id
+	Id int32 `json:"id"`
+}
+
+// SearchInput Search input
+//
+// This interface doesn't have a description.
+type SearchInput struct {
+	// Keyword Keyword
+	Keyword string `json:"keyword"`
+	// Sex Sex
+	Sex *Sex `json:"sex,omitempty"`
+	// Tags Tags
+	Tags []string `json:"tags,omitempty"`
+}
+
+// UploadInput Upload input
+//
+// This interface doesn't have a description.
+type UploadInput struct {
+	// Title Title
+	Title string `json:"title"`
+	// Caption Caption
+	Caption *string `json:"caption,omitempty"`
+}
+
+// UploadOutput Upload output
+//
+// This interface doesn't have a description.
+type UploadOutput struct {
+	// Path Path
+	Path string `json:"path"`
+}
+
+// PostAggregateArgs Post aggregate args
+//
+// This synthesized interface doesn't have a description
+type PostAggregateArgs struct {
+	// Avg Avg
+	Avg *PostAvgAggregateInputType `json:"_avg,omitempty"`
+	// Count Count
+	Count *PostCountAggregateInputType `json:"_count,omitempty"`
+	// Max Max
+	Max *PostMaxAggregateInputType `json:"_max,omitempty"`
+	// Min Min
+	Min *PostMinAggregateInputType `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *PostSumAggregateInputType `json:"_sum,omitempty"`
+	// Cursor Cursor
+	Cursor *PostWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *PostSerializableScalarFields `json:"distinct,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *PostWhereInput `json:"where,omitempty"`
+}
+
+// PostAggregateResult Post aggregate result
+//
+// This synthesized interface doesn't have a description
+type PostAggregateResult struct {
+	// Avg Avg
+	Avg *PostAvgAggregateResult `json:"_avg,omitempty"`
+	// Count Count
+	Count *PostCountAggregateResult `json:"_count,omitempty"`
+	// Max Max
+	Max *PostMaxAggregateResult `json:"_max,omitempty"`
+	// Min Min
+	Min *PostMinAggregateResult `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *PostSumAggregateResult `json:"_sum,omitempty"`
+}
+
+// PostArgs Post args
+//
+// This synthesized interface doesn't have a description
+type PostArgs struct {
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+}
+
+// PostAvgAggregateInputType Post avg aggregate input type
+//
+// This synthesized interface doesn't have a description
+type PostAvgAggregateInputType struct {
+	// AuthorId Author Id
+	AuthorId *bool `json:"authorId,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Views Views
+	Views *bool `json:"views,omitempty"`
+}
+
+// PostAvgAggregateResult Post avg aggregate result
+//
+// This synthesized interface doesn't have a description
+type PostAvgAggregateResult struct {
+	// AuthorId Author Id
+	AuthorId *float64 `json:"authorId,omitempty"`
+	// Id Id
+	Id *float64 `json:"id,omitempty"`
+	// Views Views
+	Views *float64 `json:"views,omitempty"`
+}
+
+// PostConnectOrCreateInput Post connect or create input
+//
+// This synthesized interface doesn't have a description
+type PostConnectOrCreateInput struct {
+	// Create Create
+	Create PostCreateInput `json:"create"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostConnectOrCreateWithoutAuthorInput Post connect or create without author input
+//
+// This synthesized interface doesn't have a description
+type PostConnectOrCreateWithoutAuthorInput struct {
+	// Create Create
+	Create PostCreateInput `json:"create"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostCopyArgs Post copy args
+//
+// This synthesized interface doesn't have a description
+type PostCopyArgs struct {
+	// Copy Copy
+	Copy PostUpdateInput `json:"copy"`
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostCopyManyArgs Post copy many args
+//
+// This synthesized interface doesn't have a description
+type PostCopyManyArgs struct {
+	// Copy Copy
+	Copy PostUpdateInput `json:"copy"`
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+	// Where Where
+	Where PostWhereInput `json:"where"`
+}
+
+// PostCountAggregateInputType Post count aggregate input type
+//
+// This synthesized interface doesn't have a description
+type PostCountAggregateInputType struct {
+	// All All
+	All *bool `json:"_all,omitempty"`
+	// AuthorId Author Id
+	AuthorId *bool `json:"authorId,omitempty"`
+	// Content Content
+	Content *bool `json:"content,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Tags Tags
+	Tags *bool `json:"tags,omitempty"`
+	// Title Title
+	Title *bool `json:"title,omitempty"`
+	// Views Views
+	Views *bool `json:"views,omitempty"`
+}
+
+// PostCountAggregateResult Post count aggregate result
+//
+// This synthesized interface doesn't have a description
+type PostCountAggregateResult struct {
+	// All All
+	All *int64 `json:"_all,omitempty"`
+	// AuthorId Author Id
+	AuthorId *int64 `json:"authorId,omitempty"`
+	// Content Content
+	Content *int64 `json:"content,omitempty"`
+	// Id Id
+	Id *int64 `json:"id,omitempty"`
+	// Tags Tags
+	Tags *int64 `json:"tags,omitempty"`
+	// Title Title
+	Title *int64 `json:"title,omitempty"`
+	// Views Views
+	Views *int64 `json:"views,omitempty"`
+}
+
+// PostCountArgs Post count args
+//
+// This synthesized interface doesn't have a description
+type PostCountArgs struct {
+	// Cursor Cursor
+	Cursor *PostWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *PostSerializableScalarFields `json:"distinct,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *PostCountAggregateInputType `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *PostWhereInput `json:"where,omitempty"`
+}
+
+// PostCreateArgs Post create args
+//
+// This synthesized interface doesn't have a description
+type PostCreateArgs struct {
+	// Create Create
+	Create PostCreateInput `json:"create"`
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+}
+
+// PostCreateInput Post create input
+//
+// This synthesized interface doesn't have a description
+type PostCreateInput struct {
+	// Author Author
+	Author *UserCreateNestedOneWithoutPostsInput `json:"author,omitempty"`
+	// AuthorId Author Id
+	AuthorId *int32 `json:"authorId,omitempty"`
+	// Content Content
+	Content *string `json:"content,omitempty"`
+	// Tags Tags
+	Tags []string `json:"tags"`
+	// Title Title
+	Title string `json:"title"`
+	// Views Views
+	Views int64 `json:"views"`
+}
+
+// PostCreateManyArgs Post create many args
+//
+// This synthesized interface doesn't have a description
+type PostCreateManyArgs struct {
+	// Create Create
+	Create teo.Value `json:"create"`
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+}
+
+// PostCreateNestedManyInput Post create nested many input
+//
+// This synthesized interface doesn't have a description
+type PostCreateNestedManyInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+}
+
+// PostCreateNestedManyWithoutAuthorInput Post create nested many without author input
+//
+// This synthesized interface doesn't have a description
+type PostCreateNestedManyWithoutAuthorInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+}
+
+// PostCreateNestedOneInput Post create nested one input
+//
+// This synthesized interface doesn't have a description
+type PostCreateNestedOneInput struct {
+	// Connect Connect
+	Connect *PostWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *PostConnectOrCreateInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *PostCreateInput `json:"create,omitempty"`
+}
+
+// PostCreateNestedOneWithoutAuthorInput Post create nested one without author input
+//
+// This synthesized interface doesn't have a description
+type PostCreateNestedOneWithoutAuthorInput struct {
+	// Connect Connect
+	Connect *PostWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *PostConnectOrCreateWithoutAuthorInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *PostCreateInput `json:"create,omitempty"`
+}
+
+// PostCreateWithoutAuthorInput Post create without author input
+//
+// This synthesized interface doesn't have a description
+type PostCreateWithoutAuthorInput struct {
+	// AuthorId Author Id
+	AuthorId *int32 `json:"authorId,omitempty"`
+	// Content Content
+	Content *string `json:"content,omitempty"`
+	// Tags Tags
+	Tags []string `json:"tags"`
+	// Title Title
+	Title string `json:"title"`
+	// Views Views
+	Views int64 `json:"views"`
+}
+
+// PostDeleteArgs Post delete args
+//
+// This synthesized interface doesn't have a description
+type PostDeleteArgs struct {
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostDeleteManyArgs Post delete many args
+//
+// This synthesized interface doesn't have a description
+type PostDeleteManyArgs struct {
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+	// Where Where
+	Where PostWhereInput `json:"where"`
+}
+
+// PostFindFirstArgs Post find first args
+//
+// This synthesized interface doesn't have a description
+type PostFindFirstArgs struct {
+	// Cursor Cursor
+	Cursor *PostWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *PostSerializableScalarFields `json:"distinct,omitempty"`
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *PostWhereInput `json:"where,omitempty"`
+}
+
+// PostFindManyArgs Post find many args
+//
+// This synthesized interface doesn't have a description
+type PostFindManyArgs struct {
+	// Cursor Cursor
+	Cursor *PostWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *PostSerializableScalarFields `json:"distinct,omitempty"`
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *PostWhereInput `json:"where,omitempty"`
+}
+
+// PostFindUniqueArgs Post find unique args
+//
+// This synthesized interface doesn't have a description
+type PostFindUniqueArgs struct {
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostGroupByArgs Post group by args
+//
+// This synthesized interface doesn't have a description
+type PostGroupByArgs struct {
+	// Avg Avg
+	Avg *PostAvgAggregateInputType `json:"_avg,omitempty"`
+	// Count Count
+	Count *PostCountAggregateInputType `json:"_count,omitempty"`
+	// Max Max
+	Max *PostMaxAggregateInputType `json:"_max,omitempty"`
+	// Min Min
+	Min *PostMinAggregateInputType `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *PostSumAggregateInputType `json:"_sum,omitempty"`
+	// By By
+	By teo.Value `json:"by"`
+	// Cursor Cursor
+	Cursor *PostWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *PostSerializableScalarFields `json:"distinct,omitempty"`
+	// Having Having
+	Having *PostScalarWhereWithAggregatesInput `json:"having,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *PostWhereInput `json:"where,omitempty"`
+}
+
+// PostGroupByResult Post group by result
+//
+// This synthesized interface doesn't have a description
+type PostGroupByResult struct {
+	// Avg Avg
+	Avg *PostAvgAggregateResult `json:"_avg,omitempty"`
+	// Count Count
+	Count *PostCountAggregateResult `json:"_count,omitempty"`
+	// Max Max
+	Max *PostMaxAggregateResult `json:"_max,omitempty"`
+	// Min Min
+	Min *PostMinAggregateResult `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *PostSumAggregateResult `json:"_sum,omitempty"`
+	// AuthorId Author Id
+	AuthorId *int32 `json:"authorId,omitempty"`
+	// Content Content
+	Content *string `json:"content,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Tags Tags
+	Tags []string `json:"tags,omitempty"`
+	// Title Title
+	Title *string `json:"title,omitempty"`
+	// Views Views
+	Views *int64 `json:"views,omitempty"`
+}
+
+// PostInclude Post include
+//
+// This synthesized interface doesn't have a description
+type PostInclude struct {
+	// Author Author
+	Author teo.Value `json:"author,omitempty"`
+}
+
+// PostListRelationFilter Post list relation filter
+//
+// This synthesized interface doesn't have a description
+type PostListRelationFilter struct {
+	// Every Every
+	Every *PostWhereInput `json:"every,omitempty"`
+	// None None
+	None *PostWhereInput `json:"none,omitempty"`
+	// Some Some
+	Some *PostWhereInput `json:"some,omitempty"`
+}
+
+// PostMaxAggregateInputType Post max aggregate input type
+//
+// This synthesized interface doesn't have a description
+type PostMaxAggregateInputType struct {
+	// AuthorId Author Id
+	AuthorId *bool `json:"authorId,omitempty"`
+	// Content Content
+	Content *bool `json:"content,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Tags Tags
+	Tags *bool `json:"tags,omitempty"`
+	// Title Title
+	Title *bool `json:"title,omitempty"`
+	// Views Views
+	Views *bool `json:"views,omitempty"`
+}
+
+// PostMaxAggregateResult Post max aggregate result
+//
+// This synthesized interface doesn't have a description
+type PostMaxAggregateResult struct {
+	// AuthorId Author Id
+	AuthorId *int32 `json:"authorId,omitempty"`
+	// Content Content
+	Content *string `json:"content,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Tags Tags
+	Tags []string `json:"tags,omitempty"`
+	// Title Title
+	Title *string `json:"title,omitempty"`
+	// Views Views
+	Views *int64 `json:"views,omitempty"`
+}
+
+// PostMinAggregateInputType Post min aggregate input type
+//
+// This synthesized interface doesn't have a description
+type PostMinAggregateInputType struct {
+	// AuthorId Author Id
+	AuthorId *bool `json:"authorId,omitempty"`
+	// Content Content
+	Content *bool `json:"content,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Tags Tags
+	Tags *bool `json:"tags,omitempty"`
+	// Title Title
+	Title *bool `json:"title,omitempty"`
+	// Views Views
+	Views *bool `json:"views,omitempty"`
+}
+
+// PostMinAggregateResult Post min aggregate result
+//
+// This synthesized interface doesn't have a description
+type PostMinAggregateResult struct {
+	// AuthorId Author Id
+	AuthorId *int32 `json:"authorId,omitempty"`
+	// Content Content
+	Content *string `json:"content,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Tags Tags
+	Tags []string `json:"tags,omitempty"`
+	// Title Title
+	Title *string `json:"title,omitempty"`
+	// Views Views
+	Views *int64 `json:"views,omitempty"`
+}
+
+// PostOrderByInput Post order by input
+//
+// This synthesized interface doesn't have a description
+type PostOrderByInput struct {
+	// AuthorId Author Id
+	AuthorId *stdlib.Sort `json:"authorId,omitempty"`
+	// Content Content
+	Content *stdlib.Sort `json:"content,omitempty"`
+	// Id Id
+	Id *stdlib.Sort `json:"id,omitempty"`
+	// Tags Tags
+	Tags *stdlib.Sort `json:"tags,omitempty"`
+	// Title Title
+	Title *stdlib.Sort `json:"title,omitempty"`
+	// Views Views
+	Views *stdlib.Sort `json:"views,omitempty"`
+}
+
+// PostRelationFilter Post relation filter
+//
+// This synthesized interface doesn't have a description
+type PostRelationFilter struct {
+	// Is Is
+	Is *PostWhereInput `json:"is,omitempty"`
+	// IsNot Is Not
+	IsNot *PostWhereInput `json:"isNot,omitempty"`
+}
+
+// PostResult Post result
+//
+// This synthesized interface doesn't have a description
+type PostResult struct {
+	// Author Author
+	Author UserResult `json:"author"`
+	// AuthorId Author Id
+	AuthorId int32 `json:"authorId"`
+	// Content Content
+	Content *string `json:"content,omitempty"`
+	// Id Id
+	Id int32 `json:"id"`
+	// Tags Tags
+	Tags []string `json:"tags"`
+	// Title Title
+	Title string `json:"title"`
+	// Views Views
+	Views int64 `json:"views"`
+}
+
+// PostScalarUpdateInput Post scalar update input
+//
+// This synthesized interface doesn't have a description
+type PostScalarUpdateInput struct {
+	// AuthorId Author Id
+	AuthorId *int32 `json:"authorId,omitempty"`
+	// Content Content
+	Content *string `json:"content,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Tags Tags
+	Tags []string `json:"tags,omitempty"`
+	// Title Title
+	Title *string `json:"title,omitempty"`
+	// Views Views
+	Views *int64 `json:"views,omitempty"`
+}
+
+// PostScalarWhereWithAggregatesInput Post scalar where with aggregates input
+//
+// This synthesized interface doesn't have a description
+type PostScalarWhereWithAggregatesInput struct {
+	// And And
+	And []PostWhereInput `json:"AND,omitempty"`
+	// Not Not
+	Not *PostWhereInput `json:"NOT,omitempty"`
+	// Or Or
+	Or []PostWhereInput `json:"OR,omitempty"`
+	// AuthorId Author Id
+	AuthorId teo.Value `json:"authorId,omitempty"`
+	// Content Content
+	Content teo.Value `json:"content,omitempty"`
+	// Id Id
+	Id teo.Value `json:"id,omitempty"`
+	// Tags Tags
+	Tags teo.Value `json:"tags,omitempty"`
+	// Title Title
+	Title teo.Value `json:"title,omitempty"`
+	// Views Views
+	Views teo.Value `json:"views,omitempty"`
+}
+
+// PostSelect Post select
+//
+// This synthesized interface doesn't have a description
+type PostSelect struct {
+	// AuthorId Author Id
+	AuthorId *bool `json:"authorId,omitempty"`
+	// Content Content
+	Content *bool `json:"content,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Tags Tags
+	Tags *bool `json:"tags,omitempty"`
+	// Title Title
+	Title *bool `json:"title,omitempty"`
+	// Views Views
+	Views *bool `json:"views,omitempty"`
+}
+
+// PostSignInArgs Post sign in args
+//
+// This synthesized interface doesn't have a description
+type PostSignInArgs struct {
+}
+
+// PostSignInCheckerCompanions Post sign in checker companions
+//
+// This synthesized interface doesn't have a description
+type PostSignInCheckerCompanions struct {
+}
+
+// PostSignInCheckerIds Post sign in checker ids
+//
+// This synthesized interface doesn't have a description
+type PostSignInCheckerIds struct {
+}
+
+// PostSignInInput Post sign in input
+//
+// This synthesized interface doesn't have a description
+type PostSignInInput struct {
+	// Credentials Credentials
+	Credentials PostSignInArgs `json:"credentials"`
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+}
+
+// PostSumAggregateInputType Post sum aggregate input type
+//
+// This synthesized interface doesn't have a description
+type PostSumAggregateInputType struct {
+	// AuthorId Author Id
+	AuthorId *bool `json:"authorId,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Views Views
+	Views *bool `json:"views,omitempty"`
+}
+
+// PostSumAggregateResult Post sum aggregate result
+//
+// This synthesized interface doesn't have a description
+type PostSumAggregateResult struct {
+	// AuthorId Author Id
+	AuthorId *int64 `json:"authorId,omitempty"`
+	// Id Id
+	Id *int64 `json:"id,omitempty"`
+	// Views Views
+	Views *int64 `json:"views,omitempty"`
+}
+
+// PostUpdateArgs Post update args
+//
+// This synthesized interface doesn't have a description
+type PostUpdateArgs struct {
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+	// Update Update
+	Update PostUpdateInput `json:"update"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostUpdateInput Post update input
+//
+// This synthesized interface doesn't have a description
+type PostUpdateInput struct {
+	// Author Author
+	Author *UserUpdateNestedOneWithoutPostsInput `json:"author,omitempty"`
+	// AuthorId Author Id
+	AuthorId *int32 `json:"authorId,omitempty"`
+	// Content Content
+	Content *string `json:"content,omitempty"`
+	// Tags Tags
+	Tags []string `json:"tags,omitempty"`
+	// Title Title
+	Title *string `json:"title,omitempty"`
+	// Views Views
+	Views *int64 `json:"views,omitempty"`
+}
+
+// PostUpdateManyArgs Post update many args
+//
+// This synthesized interface doesn't have a description
+type PostUpdateManyArgs struct {
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+	// Update Update
+	Update PostUpdateInput `json:"update"`
+	// Where Where
+	Where PostWhereInput `json:"where"`
+}
+
+// PostUpdateManyWithWhereInput Post update many with where input
+//
+// This synthesized interface doesn't have a description
+type PostUpdateManyWithWhereInput struct {
+	// Update Update
+	Update PostUpdateInput `json:"update"`
+	// Where Where
+	Where PostWhereInput `json:"where"`
+}
+
+// PostUpdateManyWithWhereWithoutAuthorInput Post update many with where without author input
+//
+// This synthesized interface doesn't have a description
+type PostUpdateManyWithWhereWithoutAuthorInput struct {
+	// Update Update
+	Update PostUpdateInput `json:"update"`
+	// Where Where
+	Where PostWhereInput `json:"where"`
+}
+
+// PostUpdateNestedManyInput Post update nested many input
+//
+// This synthesized interface doesn't have a description
+type PostUpdateNestedManyInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+	// Delete Delete
+	Delete teo.Value `json:"delete,omitempty"`
+	// DeleteMany Delete Many
+	DeleteMany teo.Value `json:"deleteMany,omitempty"`
+	// Disconnect Disconnect
+	Disconnect teo.Value `json:"disconnect,omitempty"`
+	// Set Set
+	Set teo.Value `json:"set,omitempty"`
+	// Update Update
+	Update teo.Value `json:"update,omitempty"`
+	// UpdateMany Update Many
+	UpdateMany teo.Value `json:"updateMany,omitempty"`
+	// Upsert Upsert
+	Upsert teo.Value `json:"upsert,omitempty"`
+}
+
+// PostUpdateNestedManyWithoutAuthorInput Post update nested many without author input
+//
+// This synthesized interface doesn't have a description
+type PostUpdateNestedManyWithoutAuthorInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+	// Delete Delete
+	Delete teo.Value `json:"delete,omitempty"`
+	// DeleteMany Delete Many
+	DeleteMany teo.Value `json:"deleteMany,omitempty"`
+	// Disconnect Disconnect
+	Disconnect teo.Value `json:"disconnect,omitempty"`
+	// Set Set
+	Set teo.Value `json:"set,omitempty"`
+	// Update Update
+	Update teo.Value `json:"update,omitempty"`
+	// UpdateMany Update Many
+	UpdateMany teo.Value `json:"updateMany,omitempty"`
+	// Upsert Upsert
+	Upsert teo.Value `json:"upsert,omitempty"`
+}
+
+// PostUpdateNestedOneInput Post update nested one input
+//
+// This synthesized interface doesn't have a description
+type PostUpdateNestedOneInput struct {
+	// Connect Connect
+	Connect *PostWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *PostConnectOrCreateInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *PostCreateInput `json:"create,omitempty"`
+	// Delete Delete
+	Delete *bool `json:"delete,omitempty"`
+	// Disconnect Disconnect
+	Disconnect *bool `json:"disconnect,omitempty"`
+	// Set Set
+	Set *PostWhereUniqueInput `json:"set,omitempty"`
+	// Update Update
+	Update *PostUpdateInput `json:"update,omitempty"`
+	// Upsert Upsert
+	Upsert *PostUpsertWithWhereUniqueInput `json:"upsert,omitempty"`
+}
+
+// PostUpdateNestedOneWithoutAuthorInput Post update nested one without author input
+//
+// This synthesized interface doesn't have a description
+type PostUpdateNestedOneWithoutAuthorInput struct {
+	// Connect Connect
+	Connect *PostWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *PostConnectOrCreateWithoutAuthorInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *PostCreateInput `json:"create,omitempty"`
+	// Delete Delete
+	Delete *bool `json:"delete,omitempty"`
+	// Disconnect Disconnect
+	Disconnect *bool `json:"disconnect,omitempty"`
+	// Set Set
+	Set *PostWhereUniqueInput `json:"set,omitempty"`
+	// Update Update
+	Update *PostUpdateInput `json:"update,omitempty"`
+	// Upsert Upsert
+	Upsert *PostUpsertWithWhereUniqueWithoutAuthorInput `json:"upsert,omitempty"`
+}
+
+// PostUpdateWithWhereUniqueInput Post update with where unique input
+//
+// This synthesized interface doesn't have a description
+type PostUpdateWithWhereUniqueInput struct {
+	// Update Update
+	Update PostUpdateInput `json:"update"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostUpdateWithWhereUniqueWithoutAuthorInput Post update with where unique without author input
+//
+// This synthesized interface doesn't have a description
+type PostUpdateWithWhereUniqueWithoutAuthorInput struct {
+	// Update Update
+	Update PostUpdateInput `json:"update"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostUpdateWithoutAuthorInput Post update without author input
+//
+// This synthesized interface doesn't have a description
+type PostUpdateWithoutAuthorInput struct {
+	// AuthorId Author Id
+	AuthorId *int32 `json:"authorId,omitempty"`
+	// Content Content
+	Content *string `json:"content,omitempty"`
+	// Tags Tags
+	Tags []string `json:"tags,omitempty"`
+	// Title Title
+	Title *string `json:"title,omitempty"`
+	// Views Views
+	Views *int64 `json:"views,omitempty"`
+}
+
+// PostUpsertArgs Post upsert args
+//
+// This synthesized interface doesn't have a description
+type PostUpsertArgs struct {
+	// Create Create
+	Create PostCreateInput `json:"create"`
+	// Include Include
+	Include *PostInclude `json:"include,omitempty"`
+	// Select Select
+	Select *PostSelect `json:"select,omitempty"`
+	// Update Update
+	Update PostUpdateInput `json:"update"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostUpsertWithWhereUniqueInput Post upsert with where unique input
+//
+// This synthesized interface doesn't have a description
+type PostUpsertWithWhereUniqueInput struct {
+	// Create Create
+	Create PostCreateInput `json:"create"`
+	// Update Update
+	Update PostUpdateInput `json:"update"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostUpsertWithWhereUniqueWithoutAuthorInput Post upsert with where unique without author input
+//
+// This synthesized interface doesn't have a description
+type PostUpsertWithWhereUniqueWithoutAuthorInput struct {
+	// Create Create
+	Create PostCreateInput `json:"create"`
+	// Update Update
+	Update PostUpdateInput `json:"update"`
+	// Where Where
+	Where PostWhereUniqueInput `json:"where"`
+}
+
+// PostWhereInput Post where input
+//
+// This synthesized interface doesn't have a description
+type PostWhereInput struct {
+	// And And
+	And []PostWhereInput `json:"AND,omitempty"`
+	// Not Not
+	Not *PostWhereInput `json:"NOT,omitempty"`
+	// Or Or
+	Or []PostWhereInput `json:"OR,omitempty"`
+	// Author Author
+	Author *UserRelationFilter `json:"author,omitempty"`
+	// AuthorId Author Id
+	AuthorId teo.Value `json:"authorId,omitempty"`
+	// Content Content
+	Content teo.Value `json:"content,omitempty"`
+	// Id Id
+	Id teo.Value `json:"id,omitempty"`
+	// Tags Tags
+	Tags teo.Value `json:"tags,omitempty"`
+	// Title Title
+	Title teo.Value `json:"title,omitempty"`
+	// Views Views
+	Views teo.Value `json:"views,omitempty"`
+}
+
+// PostWhereUniqueInput Post where unique input
+//
+// This synthesized interface doesn't have a description
+type PostWhereUniqueInput struct {
+	// Id Id
+	Id int32 `json:"id"`
+}
+
+// UserAggregateArgs User aggregate args
+//
+// This synthesized interface doesn't have a description
+type UserAggregateArgs struct {
+	// Avg Avg
+	Avg *UserAvgAggregateInputType `json:"_avg,omitempty"`
+	// Count Count
+	Count *UserCountAggregateInputType `json:"_count,omitempty"`
+	// Max Max
+	Max *UserMaxAggregateInputType `json:"_max,omitempty"`
+	// Min Min
+	Min *UserMinAggregateInputType `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *UserSumAggregateInputType `json:"_sum,omitempty"`
+	// Cursor Cursor
+	Cursor *UserWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *UserSerializableScalarFields `json:"distinct,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *UserWhereInput `json:"where,omitempty"`
+}
+
+// UserAggregateResult User aggregate result
+//
+// This synthesized interface doesn't have a description
+type UserAggregateResult struct {
+	// Avg Avg
+	Avg *UserAvgAggregateResult `json:"_avg,omitempty"`
+	// Count Count
+	Count *UserCountAggregateResult `json:"_count,omitempty"`
+	// Max Max
+	Max *UserMaxAggregateResult `json:"_max,omitempty"`
+	// Min Min
+	Min *UserMinAggregateResult `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *UserSumAggregateResult `json:"_sum,omitempty"`
+}
+
+// UserArgs User args
+//
+// This synthesized interface doesn't have a description
+type UserArgs struct {
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+}
+
+// UserAvgAggregateInputType User avg aggregate input type
+//
+// This synthesized interface doesn't have a description
+type UserAvgAggregateInputType struct {
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+}
+
+// UserAvgAggregateResult User avg aggregate result
+//
+// This synthesized interface doesn't have a description
+type UserAvgAggregateResult struct {
+	// Id Id
+	Id *float64 `json:"id,omitempty"`
+}
+
+// UserConnectOrCreateInput User connect or create input
+//
+// This synthesized interface doesn't have a description
+type UserConnectOrCreateInput struct {
+	// Create Create
+	Create UserCreateInput `json:"create"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserConnectOrCreateWithoutPostsInput User connect or create without posts input
+//
+// This synthesized interface doesn't have a description
+type UserConnectOrCreateWithoutPostsInput struct {
+	// Create Create
+	Create UserCreateInput `json:"create"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserCopyArgs User copy args
+//
+// This synthesized interface doesn't have a description
+type UserCopyArgs struct {
+	// Copy Copy
+	Copy UserUpdateInput `json:"copy"`
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserCopyManyArgs User copy many args
+//
+// This synthesized interface doesn't have a description
+type UserCopyManyArgs struct {
+	// Copy Copy
+	Copy UserUpdateInput `json:"copy"`
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+	// Where Where
+	Where UserWhereInput `json:"where"`
+}
+
+// UserCountAggregateInputType User count aggregate input type
+//
+// This synthesized interface doesn't have a description
+type UserCountAggregateInputType struct {
+	// All All
+	All *bool `json:"_all,omitempty"`
+	// Balance Balance
+	Balance *bool `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *bool `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *bool `json:"createdAt,omitempty"`
+	// Email Email
+	Email *bool `json:"email,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Name Name
+	Name *bool `json:"name,omitempty"`
+	// Sex Sex
+	Sex *bool `json:"sex,omitempty"`
+}
+
+// UserCountAggregateResult User count aggregate result
+//
+// This synthesized interface doesn't have a description
+type UserCountAggregateResult struct {
+	// All All
+	All *int64 `json:"_all,omitempty"`
+	// Balance Balance
+	Balance *int64 `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *int64 `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *int64 `json:"createdAt,omitempty"`
+	// Email Email
+	Email *int64 `json:"email,omitempty"`
+	// Id Id
+	Id *int64 `json:"id,omitempty"`
+	// Name Name
+	Name *int64 `json:"name,omitempty"`
+	// Sex Sex
+	Sex *int64 `json:"sex,omitempty"`
+}
+
+// UserCountArgs User count args
+//
+// This synthesized interface doesn't have a description
+type UserCountArgs struct {
+	// Cursor Cursor
+	Cursor *UserWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *UserSerializableScalarFields `json:"distinct,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *UserCountAggregateInputType `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *UserWhereInput `json:"where,omitempty"`
+}
+
+// UserCreateArgs User create args
+//
+// This synthesized interface doesn't have a description
+type UserCreateArgs struct {
+	// Create Create
+	Create UserCreateInput `json:"create"`
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+}
+
+// UserCreateInput User create input
+//
+// This synthesized interface doesn't have a description
+type UserCreateInput struct {
+	// Balance Balance
+	Balance *teo.Decimal `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *teo.Date `json:"birthday,omitempty"`
+	// Email Email
+	Email string `json:"email"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Posts Posts
+	Posts *PostCreateNestedManyWithoutAuthorInput `json:"posts,omitempty"`
+	// Sex Sex
+	Sex *Sex `json:"sex,omitempty"`
+}
+
+// UserCreateManyArgs User create many args
+//
+// This synthesized interface doesn't have a description
+type UserCreateManyArgs struct {
+	// Create Create
+	Create teo.Value `json:"create"`
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+}
+
+// UserCreateNestedManyInput User create nested many input
+//
+// This synthesized interface doesn't have a description
+type UserCreateNestedManyInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+}
+
+// UserCreateNestedManyWithoutPostsInput User create nested many without posts input
+//
+// This synthesized interface doesn't have a description
+type UserCreateNestedManyWithoutPostsInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+}
+
+// UserCreateNestedOneInput User create nested one input
+//
+// This synthesized interface doesn't have a description
+type UserCreateNestedOneInput struct {
+	// Connect Connect
+	Connect *UserWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *UserConnectOrCreateInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *UserCreateInput `json:"create,omitempty"`
+}
+
+// UserCreateNestedOneWithoutPostsInput User create nested one without posts input
+//
+// This synthesized interface doesn't have a description
+type UserCreateNestedOneWithoutPostsInput struct {
+	// Connect Connect
+	Connect *UserWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *UserConnectOrCreateWithoutPostsInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *UserCreateInput `json:"create,omitempty"`
+}
+
+// UserCreateWithoutPostsInput User create without posts input
+//
+// This synthesized interface doesn't have a description
+type UserCreateWithoutPostsInput struct {
+	// Balance Balance
+	Balance *teo.Decimal `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *teo.Date `json:"birthday,omitempty"`
+	// Email Email
+	Email string `json:"email"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Sex Sex
+	Sex *Sex `json:"sex,omitempty"`
+}
+
+// UserDeleteArgs User delete args
+//
+// This synthesized interface doesn't have a description
+type UserDeleteArgs struct {
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserDeleteManyArgs User delete many args
+//
+// This synthesized interface doesn't have a description
+type UserDeleteManyArgs struct {
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+	// Where Where
+	Where UserWhereInput `json:"where"`
+}
+
+// UserFindFirstArgs User find first args
+//
+// This synthesized interface doesn't have a description
+type UserFindFirstArgs struct {
+	// Cursor Cursor
+	Cursor *UserWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *UserSerializableScalarFields `json:"distinct,omitempty"`
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *UserWhereInput `json:"where,omitempty"`
+}
+
+// UserFindManyArgs User find many args
+//
+// This synthesized interface doesn't have a description
+type UserFindManyArgs struct {
+	// Cursor Cursor
+	Cursor *UserWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *UserSerializableScalarFields `json:"distinct,omitempty"`
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *UserWhereInput `json:"where,omitempty"`
+}
+
+// UserFindUniqueArgs User find unique args
+//
+// This synthesized interface doesn't have a description
+type UserFindUniqueArgs struct {
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserGroupByArgs User group by args
+//
+// This synthesized interface doesn't have a description
+type UserGroupByArgs struct {
+	// Avg Avg
+	Avg *UserAvgAggregateInputType `json:"_avg,omitempty"`
+	// Count Count
+	Count *UserCountAggregateInputType `json:"_count,omitempty"`
+	// Max Max
+	Max *UserMaxAggregateInputType `json:"_max,omitempty"`
+	// Min Min
+	Min *UserMinAggregateInputType `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *UserSumAggregateInputType `json:"_sum,omitempty"`
+	// By By
+	By teo.Value `json:"by"`
+	// Cursor Cursor
+	Cursor *UserWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *UserSerializableScalarFields `json:"distinct,omitempty"`
+	// Having Having
+	Having *UserScalarWhereWithAggregatesInput `json:"having,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *UserWhereInput `json:"where,omitempty"`
+}
+
+// UserGroupByResult User group by result
+//
+// This synthesized interface doesn't have a description
+type UserGroupByResult struct {
+	// Avg Avg
+	Avg *UserAvgAggregateResult `json:"_avg,omitempty"`
+	// Count Count
+	Count *UserCountAggregateResult `json:"_count,omitempty"`
+	// Max Max
+	Max *UserMaxAggregateResult `json:"_max,omitempty"`
+	// Min Min
+	Min *UserMinAggregateResult `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *UserSumAggregateResult `json:"_sum,omitempty"`
+	// Balance Balance
+	Balance *teo.Decimal `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *teo.Date `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *time.Time `json:"createdAt,omitempty"`
+	// Email Email
+	Email *string `json:"email,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Sex Sex
+	Sex *Sex `json:"sex,omitempty"`
+}
+
+// UserInclude User include
+//
+// This synthesized interface doesn't have a description
+type UserInclude struct {
+	// Posts Posts
+	Posts teo.Value `json:"posts,omitempty"`
+}
+
+// UserListRelationFilter User list relation filter
+//
+// This synthesized interface doesn't have a description
+type UserListRelationFilter struct {
+	// Every Every
+	Every *UserWhereInput `json:"every,omitempty"`
+	// None None
+	None *UserWhereInput `json:"none,omitempty"`
+	// Some Some
+	Some *UserWhereInput `json:"some,omitempty"`
+}
+
+// UserMaxAggregateInputType User max aggregate input type
+//
+// This synthesized interface doesn't have a description
+type UserMaxAggregateInputType struct {
+	// Balance Balance
+	Balance *bool `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *bool `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *bool `json:"createdAt,omitempty"`
+	// Email Email
+	Email *bool `json:"email,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Name Name
+	Name *bool `json:"name,omitempty"`
+	// Sex Sex
+	Sex *bool `json:"sex,omitempty"`
+}
+
+// UserMaxAggregateResult User max aggregate result
+//
+// This synthesized interface doesn't have a description
+type UserMaxAggregateResult struct {
+	// Balance Balance
+	Balance *teo.Decimal `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *teo.Date `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *time.Time `json:"createdAt,omitempty"`
+	// Email Email
+	Email *string `json:"email,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Sex Sex
+	Sex *Sex `json:"sex,omitempty"`
+}
+
+// UserMinAggregateInputType User min aggregate input type
+//
+// This synthesized interface doesn't have a description
+type UserMinAggregateInputType struct {
+	// Balance Balance
+	Balance *bool `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *bool `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *bool `json:"createdAt,omitempty"`
+	// Email Email
+	Email *bool `json:"email,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Name Name
+	Name *bool `json:"name,omitempty"`
+	// Sex Sex
+	Sex *bool `json:"sex,omitempty"`
+}
+
+// UserMinAggregateResult User min aggregate result
+//
+// This synthesized interface doesn't have a description
+type UserMinAggregateResult struct {
+	// Balance Balance
+	Balance *teo.Decimal `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *teo.Date `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *time.Time `json:"createdAt,omitempty"`
+	// Email Email
+	Email *string `json:"email,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Sex Sex
+	Sex *Sex `json:"sex,omitempty"`
+}
+
+// UserOrderByInput User order by input
+//
+// This synthesized interface doesn't have a description
+type UserOrderByInput struct {
+	// Balance Balance
+	Balance *stdlib.Sort `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *stdlib.Sort `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *stdlib.Sort `json:"createdAt,omitempty"`
+	// Email Email
+	Email *stdlib.Sort `json:"email,omitempty"`
+	// Id Id
+	Id *stdlib.Sort `json:"id,omitempty"`
+	// Name Name
+	Name *stdlib.Sort `json:"name,omitempty"`
+	// Sex Sex
+	Sex *stdlib.Sort `json:"sex,omitempty"`
+}
+
+// UserRelationFilter User relation filter
+//
+// This synthesized interface doesn't have a description
+type UserRelationFilter struct {
+	// Is Is
+	Is *UserWhereInput `json:"is,omitempty"`
+	// IsNot Is Not
+	IsNot *UserWhereInput `json:"isNot,omitempty"`
+}
+
+// UserResult User result
+//
+// This synthesized interface doesn't have a description
+type UserResult struct {
+	// Balance Balance
+	Balance *teo.Decimal `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *teo.Date `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt time.Time `json:"createdAt"`
+	// DisplayName Display Name
+	DisplayName *string `json:"displayName,omitempty"`
+	// Email Email
+	Email string `json:"email"`
+	// Id Id
+	Id int32 `json:"id"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Posts Posts
+	Posts []PostResult `json:"posts"`
+	// Sex Sex
+	Sex *Sex `json:"sex,omitempty"`
+}
+
+// UserScalarUpdateInput User scalar update input
+//
+// This synthesized interface doesn't have a description
+type UserScalarUpdateInput struct {
+	// Balance Balance
+	Balance *teo.Decimal `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *teo.Date `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *time.Time `json:"createdAt,omitempty"`
+	// Email Email
+	Email *string `json:"email,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Sex Sex
+	Sex *Sex `json:"sex,omitempty"`
+}
+
+// UserScalarWhereWithAggregatesInput User scalar where with aggregates input
+//
+// This synthesized interface doesn't have a description
+type UserScalarWhereWithAggregatesInput struct {
+	// And And
+	And []UserWhereInput `json:"AND,omitempty"`
+	// Not Not
+	Not *UserWhereInput `json:"NOT,omitempty"`
+	// Or Or
+	Or []UserWhereInput `json:"OR,omitempty"`
+	// Balance Balance
+	Balance teo.Value `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday teo.Value `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt teo.Value `json:"createdAt,omitempty"`
+	// Email Email
+	Email teo.Value `json:"email,omitempty"`
+	// Id Id
+	Id teo.Value `json:"id,omitempty"`
+	// Name Name
+	Name teo.Value `json:"name,omitempty"`
+	// Sex Sex
+	Sex teo.Value `json:"sex,omitempty"`
+}
+
+// UserSelect User select
+//
+// This synthesized interface doesn't have a description
+type UserSelect struct {
+	// Balance Balance
+	Balance *bool `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *bool `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *bool `json:"createdAt,omitempty"`
+	// DisplayName Display Name
+	DisplayName *bool `json:"displayName,omitempty"`
+	// Email Email
+	Email *bool `json:"email,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Name Name
+	Name *bool `json:"name,omitempty"`
+	// Sex Sex
+	Sex *bool `json:"sex,omitempty"`
+}
+
+// UserSignInArgs User sign in args
+//
+// This synthesized interface doesn't have a description
+type UserSignInArgs struct {
+}
+
+// UserSignInCheckerCompanions User sign in checker companions
+//
+// This synthesized interface doesn't have a description
+type UserSignInCheckerCompanions struct {
+}
+
+// UserSignInCheckerIds User sign in checker ids
+//
+// This synthesized interface doesn't have a description
+type UserSignInCheckerIds struct {
+}
+
+// UserSignInInput User sign in input
+//
+// This synthesized interface doesn't have a description
+type UserSignInInput struct {
+	// Credentials Credentials
+	Credentials UserSignInArgs `json:"credentials"`
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+}
+
+// UserSumAggregateInputType User sum aggregate input type
+//
+// This synthesized interface doesn't have a description
+type UserSumAggregateInputType struct {
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+}
+
+// UserSumAggregateResult User sum aggregate result
+//
+// This synthesized interface doesn't have a description
+type UserSumAggregateResult struct {
+	// Id Id
+	Id *int64 `json:"id,omitempty"`
+}
+
+// UserUpdateArgs User update args
+//
+// This synthesized interface doesn't have a description
+type UserUpdateArgs struct {
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+	// Update Update
+	Update UserUpdateInput `json:"update"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserUpdateInput User update input
+//
+// This synthesized interface doesn't have a description
+type UserUpdateInput struct {
+	// Balance Balance
+	Balance *teo.Decimal `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *teo.Date `json:"birthday,omitempty"`
+	// Email Email
+	Email *string `json:"email,omitempty"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Posts Posts
+	Posts *PostUpdateNestedManyWithoutAuthorInput `json:"posts,omitempty"`
+	// Sex Sex
+	Sex *Sex `json:"sex,omitempty"`
+}
+
+// UserUpdateManyArgs User update many args
+//
+// This synthesized interface doesn't have a description
+type UserUpdateManyArgs struct {
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+	// Update Update
+	Update UserUpdateInput `json:"update"`
+	// Where Where
+	Where UserWhereInput `json:"where"`
+}
+
+// UserUpdateManyWithWhereInput User update many with where input
+//
+// This synthesized interface doesn't have a description
+type UserUpdateManyWithWhereInput struct {
+	// Update Update
+	Update UserUpdateInput `json:"update"`
+	// Where Where
+	Where UserWhereInput `json:"where"`
+}
+
+// UserUpdateManyWithWhereWithoutPostsInput User update many with where without posts input
+//
+// This synthesized interface doesn't have a description
+type UserUpdateManyWithWhereWithoutPostsInput struct {
+	// Update Update
+	Update UserUpdateInput `json:"update"`
+	// Where Where
+	Where UserWhereInput `json:"where"`
+}
+
+// UserUpdateNestedManyInput User update nested many input
+//
+// This synthesized interface doesn't have a description
+type UserUpdateNestedManyInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+	// Delete Delete
+	Delete teo.Value `json:"delete,omitempty"`
+	// DeleteMany Delete Many
+	DeleteMany teo.Value `json:"deleteMany,omitempty"`
+	// Disconnect Disconnect
+	Disconnect teo.Value `json:"disconnect,omitempty"`
+	// Set Set
+	Set teo.Value `json:"set,omitempty"`
+	// Update Update
+	Update teo.Value `json:"update,omitempty"`
+	// UpdateMany Update Many
+	UpdateMany teo.Value `json:"updateMany,omitempty"`
+	// Upsert Upsert
+	Upsert teo.Value `json:"upsert,omitempty"`
+}
+
+// UserUpdateNestedManyWithoutPostsInput User update nested many without posts input
+//
+// This synthesized interface doesn't have a description
+type UserUpdateNestedManyWithoutPostsInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+	// Delete Delete
+	Delete teo.Value `json:"delete,omitempty"`
+	// DeleteMany Delete Many
+	DeleteMany teo.Value `json:"deleteMany,omitempty"`
+	// Disconnect Disconnect
+	Disconnect teo.Value `json:"disconnect,omitempty"`
+	// Set Set
+	Set teo.Value `json:"set,omitempty"`
+	// Update Update
+	Update teo.Value `json:"update,omitempty"`
+	// UpdateMany Update Many
+	UpdateMany teo.Value `json:"updateMany,omitempty"`
+	// Upsert Upsert
+	Upsert teo.Value `json:"upsert,omitempty"`
+}
+
+// UserUpdateNestedOneInput User update nested one input
+//
+// This synthesized interface doesn't have a description
+type UserUpdateNestedOneInput struct {
+	// Connect Connect
+	Connect *UserWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *UserConnectOrCreateInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *UserCreateInput `json:"create,omitempty"`
+	// Delete Delete
+	Delete *bool `json:"delete,omitempty"`
+	// Disconnect Disconnect
+	Disconnect *bool `json:"disconnect,omitempty"`
+	// Set Set
+	Set *UserWhereUniqueInput `json:"set,omitempty"`
+	// Update Update
+	Update *UserUpdateInput `json:"update,omitempty"`
+	// Upsert Upsert
+	Upsert *UserUpsertWithWhereUniqueInput `json:"upsert,omitempty"`
+}
+
+// UserUpdateNestedOneWithoutPostsInput User update nested one without posts input
+//
+// This synthesized interface doesn't have a description
+type UserUpdateNestedOneWithoutPostsInput struct {
+	// Connect Connect
+	Connect *UserWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *UserConnectOrCreateWithoutPostsInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *UserCreateInput `json:"create,omitempty"`
+	// Delete Delete
+	Delete *bool `json:"delete,omitempty"`
+	// Disconnect Disconnect
+	Disconnect *bool `json:"disconnect,omitempty"`
+	// Set Set
+	Set *UserWhereUniqueInput `json:"set,omitempty"`
+	// Update Update
+	Update *UserUpdateInput `json:"update,omitempty"`
+	// Upsert Upsert
+	Upsert *UserUpsertWithWhereUniqueWithoutPostsInput `json:"upsert,omitempty"`
+}
+
+// UserUpdateWithWhereUniqueInput User update with where unique input
+//
+// This synthesized interface doesn't have a description
+type UserUpdateWithWhereUniqueInput struct {
+	// Update Update
+	Update UserUpdateInput `json:"update"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserUpdateWithWhereUniqueWithoutPostsInput User update with where unique without posts input
+//
+// This synthesized interface doesn't have a description
+type UserUpdateWithWhereUniqueWithoutPostsInput struct {
+	// Update Update
+	Update UserUpdateInput `json:"update"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserUpdateWithoutPostsInput User update without posts input
+//
+// This synthesized interface doesn't have a description
+type UserUpdateWithoutPostsInput struct {
+	// Balance Balance
+	Balance *teo.Decimal `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday *teo.Date `json:"birthday,omitempty"`
+	// Email Email
+	Email *string `json:"email,omitempty"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Sex Sex
+	Sex *Sex `json:"sex,omitempty"`
+}
+
+// UserUpsertArgs User upsert args
+//
+// This synthesized interface doesn't have a description
+type UserUpsertArgs struct {
+	// Create Create
+	Create UserCreateInput `json:"create"`
+	// Include Include
+	Include *UserInclude `json:"include,omitempty"`
+	// Select Select
+	Select *UserSelect `json:"select,omitempty"`
+	// Update Update
+	Update UserUpdateInput `json:"update"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserUpsertWithWhereUniqueInput User upsert with where unique input
+//
+// This synthesized interface doesn't have a description
+type UserUpsertWithWhereUniqueInput struct {
+	// Create Create
+	Create UserCreateInput `json:"create"`
+	// Update Update
+	Update UserUpdateInput `json:"update"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserUpsertWithWhereUniqueWithoutPostsInput User upsert with where unique without posts input
+//
+// This synthesized interface doesn't have a description
+type UserUpsertWithWhereUniqueWithoutPostsInput struct {
+	// Create Create
+	Create UserCreateInput `json:"create"`
+	// Update Update
+	Update UserUpdateInput `json:"update"`
+	// Where Where
+	Where UserWhereUniqueInput `json:"where"`
+}
+
+// UserWhereInput User where input
+//
+// This synthesized interface doesn't have a description
+type UserWhereInput struct {
+	// And And
+	And []UserWhereInput `json:"AND,omitempty"`
+	// Not Not
+	Not *UserWhereInput `json:"NOT,omitempty"`
+	// Or Or
+	Or []UserWhereInput `json:"OR,omitempty"`
+	// Balance Balance
+	Balance teo.Value `json:"balance,omitempty"`
+	// Birthday Birthday
+	Birthday teo.Value `json:"birthday,omitempty"`
+	// CreatedAt Created At
+	CreatedAt teo.Value `json:"createdAt,omitempty"`
+	// Email Email
+	Email teo.Value `json:"email,omitempty"`
+	// Id Id
+	Id teo.Value `json:"id,omitempty"`
+	// Name Name
+	Name teo.Value `json:"name,omitempty"`
+	// Posts Posts
+	Posts *PostListRelationFilter `json:"posts,omitempty"`
+	// Sex Sex
+	Sex teo.Value `json:"sex,omitempty"`
+}
+
+// UserWhereUniqueInput User where unique input
+//
+// This synthesized interface doesn't have a description
+type UserWhereUniqueInput struct {
+	// Email Email
+	Email *string `json:"email,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+}
+
+// PostModel Post
+//
+// Post A post written by a user.
+type PostModel struct {
+	ctx *teo.ModelCtx
+}
+
+// FindManyObjects finds many post objects.
+func (m *PostModel) FindManyObjects(query PostFindManyArgs) ([]*Post, error) {
+	objects, err := m.ctx.FindMany(query)
+	if err != nil {
+		return nil, err
+	}
+	result := make([]*Post, len(objects))
+	for i, object := range objects {
+		result[i] = WrapPost(object)
+	}
+	return result, nil
+}
+
+// FindUniqueObject finds a unique post object.
+func (m *PostModel) FindUniqueObject(query PostFindUniqueArgs) (*Post, error) {
+	object, err := m.ctx.FindUnique(query)
+	if err != nil {
+		return nil, err
+	}
+	return WrapPost(object), nil
+}
+
+// FindFirstObject finds a post object.
+func (m *PostModel) FindFirstObject(query PostFindFirstArgs) (*Post, error) {
+	object, err := m.ctx.FindFirst(query)
+	if err != nil {
+		return nil, err
+	}
+	return WrapPost(object), nil
+}
+
+// CreateObject creates a new post object.
+func (m *PostModel) CreateObject(values PostCreateInput) (*Post, error) {
+	object, err := m.ctx.Create(values)
+	if err != nil {
+		return nil, err
+	}
+	return WrapPost(object), nil
+}
+
+// CountObjects counts objects on post.
+func (m *PostModel) CountObjects(query PostCountArgs) (int, error) {
+	return m.ctx.Count(query)
+}
+
+// Aggregate aggregates on post.
+func (m *PostModel) Aggregate(query PostAggregateArgs) (teo.Value, error) {
+	return m.ctx.Aggregate(query)
+}
+
+// GroupBy groups by on post.
+func (m *PostModel) GroupBy(query PostGroupByArgs) ([]teo.Value, error) {
+	return m.ctx.GroupBy(query)
+}
+
+// SQL runs a custom SQL clause.
+func (m *PostModel) SQL(sql string) ([]teo.Value, error) {
+	return m.ctx.SQL(sql)
+}
+
+// Post Post
+//
+// Post A post written by a user.
+type Post struct {
+	inner *teo.Object
+}
+
+// WrapPost wraps a runtime object, returning nil for nil.
+func WrapPost(object *teo.Object) *Post {
+	if object == nil {
+		return nil
+	}
+	return &Post{inner: object}
+}
+
+// Inner returns the wrapped runtime object.
+func (o *Post) Inner() *teo.Object {
+	return o.inner
+}
+
+// IsNew reports whether this object is not saved yet.
+func (o *Post) IsNew() bool {
+	return o.inner.IsNew()
+}
+
+// IsModified reports whether this object has unsaved changes.
+func (o *Post) IsModified() bool {
+	return o.inner.IsModified()
+}
+
+// Set sets new values to this object.
+func (o *Post) Set(values PostUpdateInput) error {
+	return o.inner.Set(values)
+}
+
+// Update updates this object with scalar values.
+func (o *Post) Update(values PostScalarUpdateInput) error {
+	return o.inner.Update(values)
+}
+
+// Save saves this object.
+func (o *Post) Save() error {
+	return o.inner.Save()
+}
+
+// Delete deletes this object.
+func (o *Post) Delete() error {
+	return o.inner.Delete()
+}
+
+// ToTeon converts this object to a TEON value.
+func (o *Post) ToTeon() (teo.Value, error) {
+	return o.inner.ToTeon()
+}
+
+// Id Id
+//
+// This field doesn't have a description.
+func (o *Post) Id() (int32, error) {
+	return teo.Get[int32](o.inner, "id")
+}
+
+// SetId sets Id.
+func (o *Post) SetId(value int32) error {
+	return o.inner.SetValue("id", value)
+}
+
+// Title Title
+//
+// This field doesn't have a description.
+func (o *Post) Title() (string, error) {
+	return teo.Get[string](o.inner, "title")
+}
+
+// SetTitle sets Title.
+func (o *Post) SetTitle(value string) error {
+	return o.inner.SetValue("title", value)
+}
+
+// Content Content
+//
+// This field doesn't have a description.
+func (o *Post) Content() (*string, error) {
+	return teo.Get[*string](o.inner, "content")
+}
+
+// SetContent sets Content.
+func (o *Post) SetContent(value *string) error {
+	return o.inner.SetValue("content", value)
+}
+
+// Tags Tags
+//
+// This field doesn't have a description.
+func (o *Post) Tags() ([]string, error) {
+	return teo.Get[[]string](o.inner, "tags")
+}
+
+// SetTags sets Tags.
+func (o *Post) SetTags(value []string) error {
+	return o.inner.SetValue("tags", value)
+}
+
+// Views Views
+//
+// This field doesn't have a description.
+func (o *Post) Views() (int64, error) {
+	return teo.Get[int64](o.inner, "views")
+}
+
+// SetViews sets Views.
+func (o *Post) SetViews(value int64) error {
+	return o.inner.SetValue("views", value)
+}
+
+// AuthorId Author id
+//
+// This field doesn't have a description.
+func (o *Post) AuthorId() (int32, error) {
+	return teo.Get[int32](o.inner, "authorId")
+}
+
+// SetAuthorId sets Author id.
+func (o *Post) SetAuthorId(value int32) error {
+	return o.inner.SetValue("authorId", value)
+}
+
+// Author Author
+//
+// This relation doesn't have a description.
+func (o *Post) Author() (*User, error) {
+	object, err := o.inner.GetRelationObject("author")
+	if err != nil {
+		return nil, err
+	}
+	return WrapUser(object), nil
+}
+
+// SetAuthor sets Author.
+func (o *Post) SetAuthor(value *User) error {
+	if value == nil {
+		return o.inner.SetRelationObject("author", nil)
+	}
+	return o.inner.SetRelationObject("author", value.Inner())
+}
+
+// UserModel User
+//
+// User A user of the blog.
+type UserModel struct {
+	ctx *teo.ModelCtx
+}
+
+// FindManyObjects finds many user objects.
+func (m *UserModel) FindManyObjects(query UserFindManyArgs) ([]*User, error) {
+	objects, err := m.ctx.FindMany(query)
+	if err != nil {
+		return nil, err
+	}
+	result := make([]*User, len(objects))
+	for i, object := range objects {
+		result[i] = WrapUser(object)
+	}
+	return result, nil
+}
+
+// FindUniqueObject finds a unique user object.
+func (m *UserModel) FindUniqueObject(query UserFindUniqueArgs) (*User, error) {
+	object, err := m.ctx.FindUnique(query)
+	if err != nil {
+		return nil, err
+	}
+	return WrapUser(object), nil
+}
+
+// FindFirstObject finds a user object.
+func (m *UserModel) FindFirstObject(query UserFindFirstArgs) (*User, error) {
+	object, err := m.ctx.FindFirst(query)
+	if err != nil {
+		return nil, err
+	}
+	return WrapUser(object), nil
+}
+
+// CreateObject creates a new user object.
+func (m *UserModel) CreateObject(values UserCreateInput) (*User, error) {
+	object, err := m.ctx.Create(values)
+	if err != nil {
+		return nil, err
+	}
+	return WrapUser(object), nil
+}
+
+// CountObjects counts objects on user.
+func (m *UserModel) CountObjects(query UserCountArgs) (int, error) {
+	return m.ctx.Count(query)
+}
+
+// Aggregate aggregates on user.
+func (m *UserModel) Aggregate(query UserAggregateArgs) (teo.Value, error) {
+	return m.ctx.Aggregate(query)
+}
+
+// GroupBy groups by on user.
+func (m *UserModel) GroupBy(query UserGroupByArgs) ([]teo.Value, error) {
+	return m.ctx.GroupBy(query)
+}
+
+// SQL runs a custom SQL clause.
+func (m *UserModel) SQL(sql string) ([]teo.Value, error) {
+	return m.ctx.SQL(sql)
+}
+
+// User User
+//
+// User A user of the blog.
+type User struct {
+	inner *teo.Object
+}
+
+// WrapUser wraps a runtime object, returning nil for nil.
+func WrapUser(object *teo.Object) *User {
+	if object == nil {
+		return nil
+	}
+	return &User{inner: object}
+}
+
+// Inner returns the wrapped runtime object.
+func (o *User) Inner() *teo.Object {
+	return o.inner
+}
+
+// IsNew reports whether this object is not saved yet.
+func (o *User) IsNew() bool {
+	return o.inner.IsNew()
+}
+
+// IsModified reports whether this object has unsaved changes.
+func (o *User) IsModified() bool {
+	return o.inner.IsModified()
+}
+
+// Set sets new values to this object.
+func (o *User) Set(values UserUpdateInput) error {
+	return o.inner.Set(values)
+}
+
+// Update updates this object with scalar values.
+func (o *User) Update(values UserScalarUpdateInput) error {
+	return o.inner.Update(values)
+}
+
+// Save saves this object.
+func (o *User) Save() error {
+	return o.inner.Save()
+}
+
+// Delete deletes this object.
+func (o *User) Delete() error {
+	return o.inner.Delete()
+}
+
+// ToTeon converts this object to a TEON value.
+func (o *User) ToTeon() (teo.Value, error) {
+	return o.inner.ToTeon()
+}
+
+// Id Id
+//
+// This field doesn't have a description.
+func (o *User) Id() (int32, error) {
+	return teo.Get[int32](o.inner, "id")
+}
+
+// SetId sets Id.
+func (o *User) SetId(value int32) error {
+	return o.inner.SetValue("id", value)
+}
+
+// Email Email
+//
+// This field doesn't have a description.
+func (o *User) Email() (string, error) {
+	return teo.Get[string](o.inner, "email")
+}
+
+// SetEmail sets Email.
+func (o *User) SetEmail(value string) error {
+	return o.inner.SetValue("email", value)
+}
+
+// Name Name
+//
+// This field doesn't have a description.
+func (o *User) Name() (*string, error) {
+	return teo.Get[*string](o.inner, "name")
+}
+
+// SetName sets Name.
+func (o *User) SetName(value *string) error {
+	return o.inner.SetValue("name", value)
+}
+
+// Sex Sex
+//
+// This field doesn't have a description.
+func (o *User) Sex() (*Sex, error) {
+	return teo.Get[*Sex](o.inner, "sex")
+}
+
+// SetSex sets Sex.
+func (o *User) SetSex(value *Sex) error {
+	return o.inner.SetValue("sex", value)
+}
+
+// Birthday Birthday
+//
+// This field doesn't have a description.
+func (o *User) Birthday() (*teo.Date, error) {
+	return teo.Get[*teo.Date](o.inner, "birthday")
+}
+
+// SetBirthday sets Birthday.
+func (o *User) SetBirthday(value *teo.Date) error {
+	return o.inner.SetValue("birthday", value)
+}
+
+// Balance Balance
+//
+// This field doesn't have a description.
+func (o *User) Balance() (*teo.Decimal, error) {
+	return teo.Get[*teo.Decimal](o.inner, "balance")
+}
+
+// SetBalance sets Balance.
+func (o *User) SetBalance(value *teo.Decimal) error {
+	return o.inner.SetValue("balance", value)
+}
+
+// CreatedAt Created at
+//
+// This field doesn't have a description.
+func (o *User) CreatedAt() (time.Time, error) {
+	return teo.Get[time.Time](o.inner, "createdAt")
+}
+
+// SetCreatedAt sets Created at.
+func (o *User) SetCreatedAt(value time.Time) error {
+	return o.inner.SetValue("createdAt", value)
+}
+
+// Posts Posts
+//
+// This relation doesn't have a description.
+func (o *User) Posts(query any) ([]*Post, error) {
+	objects, err := o.inner.GetRelationObjects("posts", query)
+	if err != nil {
+		return nil, err
+	}
+	result := make([]*Post, len(objects))
+	for i, object := range objects {
+		result[i] = WrapPost(object)
+	}
+	return result, nil
+}
+
+// SetPosts sets Posts.
+func (o *User) SetPosts(values []*Post) error {
+	objects := make([]*teo.Object, len(values))
+	for i, value := range values {
+		objects[i] = value.Inner()
+	}
+	return o.inner.SetRelationObjects("posts", objects)
+}
+
+// AddToPosts adds objects to Posts.
+func (o *User) AddToPosts(values []*Post) error {
+	objects := make([]*teo.Object, len(values))
+	for i, value := range values {
+		objects[i] = value.Inner()
+	}
+	return o.inner.AddRelationObjects("posts", objects)
+}
+
+// RemoveFromPosts removes objects from Posts.
+func (o *User) RemoveFromPosts(values []*Post) error {
+	objects := make([]*teo.Object, len(values))
+	for i, value := range values {
+		objects[i] = value.Inner()
+	}
+	return o.inner.RemoveRelationObjects("posts", objects)
+}
+
+// DisplayName Display name
+//
+// This property doesn't have a description.
+func (o *User) DisplayName() (*string, error) {
+	return teo.GetProperty[*string](o.inner, "displayName")
+}
+
+// Teo gives typed access to the models and namespaces of the schema.
+type Teo struct {
+	ctx *teo.TransactionCtx
+}
+
+// New wraps a runtime transaction context.
+func New(ctx *teo.TransactionCtx) *Teo {
+	return &Teo{ctx: ctx}
+}
+
+// Transaction runs callback in a transaction.
+func (t *Teo) Transaction(callback func(*Teo) error) error {
+	return t.ctx.Transaction(func(ctx *teo.TransactionCtx) error {
+		return callback(New(ctx))
+	})
+}
+
+// Post returns the post model.
+func (t *Teo) Post() *PostModel {
+	return &PostModel{ctx: t.ctx.ModelCtx([]string{"Post"})}
+}
+
+// User returns the user model.
+func (t *Teo) User() *UserModel {
+	return &UserModel{ctx: t.ctx.ModelCtx([]string{"User"})}
+}
\ No newline at end of file
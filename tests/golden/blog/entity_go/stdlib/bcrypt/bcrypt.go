@@ -0,0 +1,17 @@
+// Code generated by Teo. DO NOT EDIT.
+
+package bcrypt
+
+import (
+	teo "github.com/teodevgroup/teo-go"
+)
+
+// Namespace gives typed access to the models and namespaces of `std.bcrypt`.
+type Namespace struct {
+	ctx *teo.TransactionCtx
+}
+
+// New wraps a runtime transaction context.
+func New(ctx *teo.TransactionCtx) *Namespace {
+	return &Namespace{ctx: ctx}
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+// Code generated by Teo. DO NOT EDIT.
+
+package identity
+
+import (
+	teo "github.com/teodevgroup/teo-go"
+)
+
+// TokenInfo Token info
+//
+// This interface doesn't have a description.
+type TokenInfo struct {
+	// Token Token
+	Token string `json:"token"`
+}
+
+// Namespace gives typed access to the models and namespaces of `std.identity`.
+type Namespace struct {
+	ctx *teo.TransactionCtx
+}
+
+// New wraps a runtime transaction context.
+func New(ctx *teo.TransactionCtx) *Namespace {
+	return &Namespace{ctx: ctx}
+}
\ No newline at end of file
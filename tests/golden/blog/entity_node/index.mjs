@@ -0,0 +1,251 @@
+
+const objectClasses = {}
+
+function wrapObject(path, object) {
+    if (object === null || object === undefined) {
+        return object
+    }
+    return new objectClasses[path](object)
+}
+
+function unwrapObject(object) {
+    if (object !== null && object !== undefined && object.__object !== undefined) {
+        return object.__object
+    }
+    return object
+}
+
+
+class PostModel {
+    constructor(ctx) {
+        this.__ctx = ctx
+    }
+    async findManyObjects(query) {
+        return (await this.__ctx.findManyObjects(query)).map((o) => wrapObject("Post", o))
+    }
+    async findUniqueObject(query) {
+        return wrapObject("Post", await this.__ctx.findUniqueObject(query))
+    }
+    async findFirstObject(query) {
+        return wrapObject("Post", await this.__ctx.findFirstObject(query))
+    }
+    async createObject(input) {
+        return wrapObject("Post", await this.__ctx.createObject(input))
+    }
+    count(input) {
+        return this.__ctx.count(input)
+    }
+    aggregate(input) {
+        return this.__ctx.aggregate(input)
+    }
+    groupBy(input) {
+        return this.__ctx.groupBy(input)
+    }
+    sql(sql) {
+        return this.__ctx.sql(sql)
+    }
+}
+
+class Post {
+    constructor(object) {
+        this.__object = object
+    }
+    get isNew() {
+        return this.__object.isNew
+    }
+    get isModified() {
+        return this.__object.isModified
+    }
+    set(input) {
+        return this.__object.set(input)
+    }
+    update(input) {
+        return this.__object.update(input)
+    }
+    save() {
+        return this.__object.save()
+    }
+    delete() {
+        return this.__object.delete()
+    }
+    toTeon() {
+        return this.__object.toTeon()
+    }
+    get id() {
+        return this.__object.id
+    }
+    set id(newValue) {
+        this.__object.id = newValue
+    }
+    get title() {
+        return this.__object.title
+    }
+    set title(newValue) {
+        this.__object.title = newValue
+    }
+    get content() {
+        return this.__object.content
+    }
+    set content(newValue) {
+        this.__object.content = newValue
+    }
+    get tags() {
+        return this.__object.tags
+    }
+    set tags(newValue) {
+        this.__object.tags = newValue
+    }
+    get views() {
+        return this.__object.views
+    }
+    set views(newValue) {
+        this.__object.views = newValue
+    }
+    get authorId() {
+        return this.__object.authorId
+    }
+    set authorId(newValue) {
+        this.__object.authorId = newValue
+    }
+    get author() {
+        return this.__object.author.then((o) => wrapObject("User", o))
+    }
+    setAuthor(newValue) {
+        return this.__object.setAuthor(unwrapObject(newValue))
+    }
+}
+
+objectClasses["Post"] = Post
+
+class UserModel {
+    constructor(ctx) {
+        this.__ctx = ctx
+    }
+    async findManyObjects(query) {
+        return (await this.__ctx.findManyObjects(query)).map((o) => wrapObject("User", o))
+    }
+    async findUniqueObject(query) {
+        return wrapObject("User", await this.__ctx.findUniqueObject(query))
+    }
+    async findFirstObject(query) {
+        return wrapObject("User", await this.__ctx.findFirstObject(query))
+    }
+    async createObject(input) {
+        return wrapObject("User", await this.__ctx.createObject(input))
+    }
+    count(input) {
+        return this.__ctx.count(input)
+    }
+    aggregate(input) {
+        return this.__ctx.aggregate(input)
+    }
+    groupBy(input) {
+        return this.__ctx.groupBy(input)
+    }
+    sql(sql) {
+        return this.__ctx.sql(sql)
+    }
+}
+
+class User {
+    constructor(object) {
+        this.__object = object
+    }
+    get isNew() {
+        return this.__object.isNew
+    }
+    get isModified() {
+        return this.__object.isModified
+    }
+    set(input) {
+        return this.__object.set(input)
+    }
+    update(input) {
+        return this.__object.update(input)
+    }
+    save() {
+        return this.__object.save()
+    }
+    delete() {
+        return this.__object.delete()
+    }
+    toTeon() {
+        return this.__object.toTeon()
+    }
+    get id() {
+        return this.__object.id
+    }
+    set id(newValue) {
+        this.__object.id = newValue
+    }
+    get email() {
+        return this.__object.email
+    }
+    set email(newValue) {
+        this.__object.email = newValue
+    }
+    get name() {
+        return this.__object.name
+    }
+    set name(newValue) {
+        this.__object.name = newValue
+    }
+    get sex() {
+        return this.__object.sex
+    }
+    set sex(newValue) {
+        this.__object.sex = newValue
+    }
+    get birthday() {
+        return this.__object.birthday
+    }
+    set birthday(newValue) {
+        this.__object.birthday = newValue
+    }
+    get balance() {
+        return this.__object.balance
+    }
+    set balance(newValue) {
+        this.__object.balance = newValue
+    }
+    get createdAt() {
+        return this.__object.createdAt
+    }
+    set createdAt(newValue) {
+        this.__object.createdAt = newValue
+    }
+    get posts() {
+        return this.__object.posts.then((objects) => objects.map((o) => wrapObject("Post", o)))
+    }
+    setPosts(newValue) {
+        return this.__object.setPosts(newValue.map(unwrapObject))
+    }
+    addToPosts(newValue) {
+        return this.__object.addToPosts(newValue.map(unwrapObject))
+    }
+    removeFromPosts(newValue) {
+        return this.__object.removeFromPosts(newValue.map(unwrapObject))
+    }
+    get displayName() {
+        return this.__object.displayName
+    }
+}
+
+objectClasses["User"] = User
+
+class Teo {
+    constructor(teo) {
+        this.__inner = teo
+    }
+    transaction(callback) {
+        return this.__inner.transaction((teo) => callback(new Teo(teo)))
+    }
+    get post() {
+        return new PostModel(this.__inner.post)
+    }
+    get user() {
+        return new UserModel(this.__inner.user)
+    }
+}
+
+export { PostModel, Post, UserModel, User, Teo }
\ No newline at end of file
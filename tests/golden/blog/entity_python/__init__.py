@@ -0,0 +1,4709 @@
+# type: ignore
+from __future__ import annotations
+from typing import Any, Optional, Literal, Generic, TypeVar, NotRequired, TypedDict, cast, TYPE_CHECKING
+from re import Pattern
+from datetime import date, datetime
+from decimal import Decimal
+from teo import ObjectId, Enumerable, File, Range, OptionVariant
+from teo.annotations import TeoAnnotationMark, ModelObjectAnnotationMark
+
+from . import std
+
+
+Sex = Literal["male", "female"]
+
+PostDirectRelations = Literal["author"]
+
+PostIndirectRelations = Literal[None]
+
+PostRelations = Literal["author"]
+
+PostScalarFields = Literal["authorId", "content", "id", "tags", "title", "views"]
+
+PostSerializableScalarFields = Literal["authorId", "content", "id", "tags", "title", "views"]
+
+UserDirectRelations = Literal["posts"]
+
+UserIndirectRelations = Literal[None]
+
+UserRelations = Literal["posts"]
+
+UserScalarFields = Literal["balance", "birthday", "createdAt", "email", "id", "name", "sex"]
+
+UserSerializableScalarFields = Literal["balance", "birthday", "createdAt", "email", "id", "name", "sex"]
+
+
+
+# **Keyword**
+#
+# This interface doesn't have a description.
+class Keyword(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Value**
+        #
+        # This interface field doesn't have a description.
+        "value": "str | int",
+        
+    },
+)):
+    pass
+
+# **Page**
+#
+# This interface doesn't have a description.
+class Page[T](TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Items**
+        #
+        # This interface field doesn't have a description.
+        "items": "list[T]",
+        
+        # **Total**
+        #
+        # This interface field doesn't have a description.
+        "total": "int",
+        
+    },
+)):
+    pass
+
+# **Post path arguments**
+#
+# This interface doesn't have a description.
+class PostPathArguments(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This interface field doesn't have a description.
+        "id": "int",
+        
+    },
+)):
+    pass
+
+# **Search input**
+#
+# This interface doesn't have a description.
+class SearchInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Keyword**
+        #
+        # This interface field doesn't have a description.
+        "keyword": "str",
+        
+        # **Sex**
+        #
+        # This interface field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex]]",
+        
+        # **Tags**
+        #
+        # This interface field doesn't have a description.
+        "tags": "NotRequired[Optional[list[str]]]",
+        
+    },
+)):
+    pass
+
+# **Upload input**
+#
+# This interface doesn't have a description.
+class UploadInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Title**
+        #
+        # This interface field doesn't have a description.
+        "title": "str",
+        
+        # **Caption**
+        #
+        # This interface field doesn't have a description.
+        "caption": "NotRequired[Optional[str]]",
+        
+    },
+)):
+    pass
+
+# **Upload output**
+#
+# This interface doesn't have a description.
+class UploadOutput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Path**
+        #
+        # This interface field doesn't have a description.
+        "path": "str",
+        
+    },
+)):
+    pass
+
+# **Post aggregate args**
+#
+# This synthesized interface doesn't have a description
+class PostAggregateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[PostAvgAggregateInputType]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[PostCountAggregateInputType]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[PostMaxAggregateInputType]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[PostMinAggregateInputType]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[PostSumAggregateInputType]]",
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[PostSerializableScalarFields]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[PostOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[PostWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Post aggregate result**
+#
+# This synthesized interface doesn't have a description
+class PostAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[PostAvgAggregateResult]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[PostCountAggregateResult]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[PostMaxAggregateResult]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[PostMinAggregateResult]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[PostSumAggregateResult]]",
+        
+    },
+)):
+    pass
+
+# **Post args**
+#
+# This synthesized interface doesn't have a description
+class PostArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+    },
+)):
+    pass
+
+# **Post avg aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class PostAvgAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Post avg aggregate result**
+#
+# This synthesized interface doesn't have a description
+class PostAvgAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[float]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[float]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[float]]",
+        
+    },
+)):
+    pass
+
+# **Post connect or create input**
+#
+# This synthesized interface doesn't have a description
+class PostConnectOrCreateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "PostCreateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post connect or create without author input**
+#
+# This synthesized interface doesn't have a description
+class PostConnectOrCreateWithoutAuthorInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "PostCreateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post copy args**
+#
+# This synthesized interface doesn't have a description
+class PostCopyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Copy**
+        #
+        # This synthesized field doesn't have a description.
+        "copy": "PostUpdateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post copy many args**
+#
+# This synthesized interface doesn't have a description
+class PostCopyManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Copy**
+        #
+        # This synthesized field doesn't have a description.
+        "copy": "PostUpdateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereInput",
+        
+    },
+)):
+    pass
+
+# **Post count aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class PostCountAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **All**
+        #
+        # This synthesized field doesn't have a description.
+        "_all": "NotRequired[Optional[bool]]",
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[bool]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[bool]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[bool]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Post count aggregate result**
+#
+# This synthesized interface doesn't have a description
+class PostCountAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **All**
+        #
+        # This synthesized field doesn't have a description.
+        "_all": "NotRequired[Optional[int]]",
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[int]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[int]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[int]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Post count args**
+#
+# This synthesized interface doesn't have a description
+class PostCountArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[PostSerializableScalarFields]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[PostOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostCountAggregateInputType]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[PostWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Post create args**
+#
+# This synthesized interface doesn't have a description
+class PostCreateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "PostCreateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+    },
+)):
+    pass
+
+# **Post create input**
+#
+# This synthesized interface doesn't have a description
+class PostCreateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author**
+        #
+        # This synthesized field doesn't have a description.
+        "author": "NotRequired[Optional[UserCreateNestedOneWithoutPostsInput]]",
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "list[str]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "str",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "int",
+        
+    },
+)):
+    pass
+
+# **Post create many args**
+#
+# This synthesized interface doesn't have a description
+class PostCreateManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "Enumerable[PostCreateInput]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+    },
+)):
+    pass
+
+# **Post create nested many input**
+#
+# This synthesized interface doesn't have a description
+class PostCreateNestedManyInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[PostWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[PostConnectOrCreateInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[PostCreateInput]]]",
+        
+    },
+)):
+    pass
+
+# **Post create nested many without author input**
+#
+# This synthesized interface doesn't have a description
+class PostCreateNestedManyWithoutAuthorInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[PostWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[PostConnectOrCreateWithoutAuthorInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[PostCreateInput]]]",
+        
+    },
+)):
+    pass
+
+# **Post create nested one input**
+#
+# This synthesized interface doesn't have a description
+class PostCreateNestedOneInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[PostConnectOrCreateInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[PostCreateInput]]",
+        
+    },
+)):
+    pass
+
+# **Post create nested one without author input**
+#
+# This synthesized interface doesn't have a description
+class PostCreateNestedOneWithoutAuthorInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[PostConnectOrCreateWithoutAuthorInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[PostCreateInput]]",
+        
+    },
+)):
+    pass
+
+# **Post create without author input**
+#
+# This synthesized interface doesn't have a description
+class PostCreateWithoutAuthorInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "list[str]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "str",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "int",
+        
+    },
+)):
+    pass
+
+# **Post delete args**
+#
+# This synthesized interface doesn't have a description
+class PostDeleteArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post delete many args**
+#
+# This synthesized interface doesn't have a description
+class PostDeleteManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereInput",
+        
+    },
+)):
+    pass
+
+# **Post find first args**
+#
+# This synthesized interface doesn't have a description
+class PostFindFirstArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[PostSerializableScalarFields]]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[PostOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[PostWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Post find many args**
+#
+# This synthesized interface doesn't have a description
+class PostFindManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[PostSerializableScalarFields]]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[PostOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[PostWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Post find unique args**
+#
+# This synthesized interface doesn't have a description
+class PostFindUniqueArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post group by args**
+#
+# This synthesized interface doesn't have a description
+class PostGroupByArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[PostAvgAggregateInputType]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[PostCountAggregateInputType]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[PostMaxAggregateInputType]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[PostMinAggregateInputType]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[PostSumAggregateInputType]]",
+        
+        # **By**
+        #
+        # This synthesized field doesn't have a description.
+        "by": "Enumerable[PostSerializableScalarFields]",
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[PostSerializableScalarFields]]",
+        
+        # **Having**
+        #
+        # This synthesized field doesn't have a description.
+        "having": "NotRequired[Optional[PostScalarWhereWithAggregatesInput]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[PostOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[PostWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Post group by result**
+#
+# This synthesized interface doesn't have a description
+class PostGroupByResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[PostAvgAggregateResult]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[PostCountAggregateResult]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[PostMaxAggregateResult]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[PostMinAggregateResult]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[PostSumAggregateResult]]",
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[list[str]]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[str]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Post include**
+#
+# This synthesized interface doesn't have a description
+class PostInclude(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author**
+        #
+        # This synthesized field doesn't have a description.
+        "author": "NotRequired[Optional[UserArgs | bool]]",
+        
+    },
+)):
+    pass
+
+# **Post list relation filter**
+#
+# This synthesized interface doesn't have a description
+class PostListRelationFilter(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Every**
+        #
+        # This synthesized field doesn't have a description.
+        "every": "NotRequired[Optional[PostWhereInput]]",
+        
+        # **None**
+        #
+        # This synthesized field doesn't have a description.
+        "none": "NotRequired[Optional[PostWhereInput]]",
+        
+        # **Some**
+        #
+        # This synthesized field doesn't have a description.
+        "some": "NotRequired[Optional[PostWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Post max aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class PostMaxAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[bool]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[bool]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[bool]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Post max aggregate result**
+#
+# This synthesized interface doesn't have a description
+class PostMaxAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[list[str]]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[str]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Post min aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class PostMinAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[bool]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[bool]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[bool]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Post min aggregate result**
+#
+# This synthesized interface doesn't have a description
+class PostMinAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[list[str]]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[str]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Post order by input**
+#
+# This synthesized interface doesn't have a description
+class PostOrderByInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[std.Sort]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[std.Sort]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[std.Sort]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[std.Sort]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[std.Sort]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[std.Sort]]",
+        
+    },
+)):
+    pass
+
+# **Post relation filter**
+#
+# This synthesized interface doesn't have a description
+class PostRelationFilter(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Is**
+        #
+        # This synthesized field doesn't have a description.
+        "is": "NotRequired[Optional[PostWhereInput]]",
+        
+        # **Is Not**
+        #
+        # This synthesized field doesn't have a description.
+        "isNot": "NotRequired[Optional[PostWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Post result**
+#
+# This synthesized interface doesn't have a description
+class PostResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author**
+        #
+        # This synthesized field doesn't have a description.
+        "author": "UserResult",
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "int",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "int",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "list[str]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "str",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "int",
+        
+    },
+)):
+    pass
+
+# **Post scalar update input**
+#
+# This synthesized interface doesn't have a description
+class PostScalarUpdateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[list[str]]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[str]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Post scalar where with aggregates input**
+#
+# This synthesized interface doesn't have a description
+class PostScalarWhereWithAggregatesInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **And**
+        #
+        # This synthesized field doesn't have a description.
+        "AND": "NotRequired[Optional[list[PostWhereInput]]]",
+        
+        # **Not**
+        #
+        # This synthesized field doesn't have a description.
+        "NOT": "NotRequired[Optional[PostWhereInput]]",
+        
+        # **Or**
+        #
+        # This synthesized field doesn't have a description.
+        "OR": "NotRequired[Optional[list[PostWhereInput]]]",
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int | std.IntNumberWithAggregatesFilter[int]]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str | None | std.StringNullableWithAggregatesFilter]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int | std.IntNumberWithAggregatesFilter[int]]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[list[str] | std.ArrayWithAggregatesFilter[str]]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[str | std.StringWithAggregatesFilter]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[int | std.IntNumberWithAggregatesFilter[int]]]",
+        
+    },
+)):
+    pass
+
+# **Post select**
+#
+# This synthesized interface doesn't have a description
+class PostSelect(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[bool]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[bool]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[bool]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Post sign in args**
+#
+# This synthesized interface doesn't have a description
+class PostSignInArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Post sign in checker companions**
+#
+# This synthesized interface doesn't have a description
+class PostSignInCheckerCompanions(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Post sign in checker ids**
+#
+# This synthesized interface doesn't have a description
+class PostSignInCheckerIds(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Post sign in input**
+#
+# This synthesized interface doesn't have a description
+class PostSignInInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Credentials**
+        #
+        # This synthesized field doesn't have a description.
+        "credentials": "PostSignInArgs",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+    },
+)):
+    pass
+
+# **Post sum aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class PostSumAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Post sum aggregate result**
+#
+# This synthesized interface doesn't have a description
+class PostSumAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Post update args**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "PostUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post update input**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author**
+        #
+        # This synthesized field doesn't have a description.
+        "author": "NotRequired[Optional[UserUpdateNestedOneWithoutPostsInput]]",
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[list[str]]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[str]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Post update many args**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "PostUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereInput",
+        
+    },
+)):
+    pass
+
+# **Post update many with where input**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateManyWithWhereInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "PostUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereInput",
+        
+    },
+)):
+    pass
+
+# **Post update many with where without author input**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateManyWithWhereWithoutAuthorInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "PostUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereInput",
+        
+    },
+)):
+    pass
+
+# **Post update nested many input**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateNestedManyInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[PostWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[PostConnectOrCreateInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[PostCreateInput]]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[Enumerable[PostWhereUniqueInput]]]",
+        
+        # **Delete Many**
+        #
+        # This synthesized field doesn't have a description.
+        "deleteMany": "NotRequired[Optional[Enumerable[PostWhereInput]]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[Enumerable[PostWhereUniqueInput]]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[Enumerable[PostWhereUniqueInput]]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[Enumerable[PostUpdateWithWhereUniqueInput]]]",
+        
+        # **Update Many**
+        #
+        # This synthesized field doesn't have a description.
+        "updateMany": "NotRequired[Optional[Enumerable[PostUpdateManyWithWhereInput]]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[Enumerable[PostUpsertWithWhereUniqueInput]]]",
+        
+    },
+)):
+    pass
+
+# **Post update nested many without author input**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateNestedManyWithoutAuthorInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[PostWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[PostConnectOrCreateWithoutAuthorInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[PostCreateInput]]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[Enumerable[PostWhereUniqueInput]]]",
+        
+        # **Delete Many**
+        #
+        # This synthesized field doesn't have a description.
+        "deleteMany": "NotRequired[Optional[Enumerable[PostWhereInput]]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[Enumerable[PostWhereUniqueInput]]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[Enumerable[PostWhereUniqueInput]]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[Enumerable[PostUpdateWithWhereUniqueWithoutAuthorInput]]]",
+        
+        # **Update Many**
+        #
+        # This synthesized field doesn't have a description.
+        "updateMany": "NotRequired[Optional[Enumerable[PostUpdateManyWithWhereWithoutAuthorInput]]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[Enumerable[PostUpsertWithWhereUniqueWithoutAuthorInput]]]",
+        
+    },
+)):
+    pass
+
+# **Post update nested one input**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateNestedOneInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[PostConnectOrCreateInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[PostCreateInput]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[bool]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[bool]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[PostUpdateInput]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[PostUpsertWithWhereUniqueInput]]",
+        
+    },
+)):
+    pass
+
+# **Post update nested one without author input**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateNestedOneWithoutAuthorInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[PostConnectOrCreateWithoutAuthorInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[PostCreateInput]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[bool]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[bool]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[PostWhereUniqueInput]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[PostUpdateInput]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[PostUpsertWithWhereUniqueWithoutAuthorInput]]",
+        
+    },
+)):
+    pass
+
+# **Post update with where unique input**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateWithWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "PostUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post update with where unique without author input**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateWithWhereUniqueWithoutAuthorInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "PostUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post update without author input**
+#
+# This synthesized interface doesn't have a description
+class PostUpdateWithoutAuthorInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[list[str]]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[str]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Post upsert args**
+#
+# This synthesized interface doesn't have a description
+class PostUpsertArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "PostCreateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[PostInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[PostSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "PostUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post upsert with where unique input**
+#
+# This synthesized interface doesn't have a description
+class PostUpsertWithWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "PostCreateInput",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "PostUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post upsert with where unique without author input**
+#
+# This synthesized interface doesn't have a description
+class PostUpsertWithWhereUniqueWithoutAuthorInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "PostCreateInput",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "PostUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "PostWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Post where input**
+#
+# This synthesized interface doesn't have a description
+class PostWhereInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **And**
+        #
+        # This synthesized field doesn't have a description.
+        "AND": "NotRequired[Optional[list[PostWhereInput]]]",
+        
+        # **Not**
+        #
+        # This synthesized field doesn't have a description.
+        "NOT": "NotRequired[Optional[PostWhereInput]]",
+        
+        # **Or**
+        #
+        # This synthesized field doesn't have a description.
+        "OR": "NotRequired[Optional[list[PostWhereInput]]]",
+        
+        # **Author**
+        #
+        # This synthesized field doesn't have a description.
+        "author": "NotRequired[Optional[UserRelationFilter]]",
+        
+        # **Author Id**
+        #
+        # This synthesized field doesn't have a description.
+        "authorId": "NotRequired[Optional[int | std.Filter[int]]]",
+        
+        # **Content**
+        #
+        # This synthesized field doesn't have a description.
+        "content": "NotRequired[Optional[str | None | std.StringNullableFilter]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int | std.Filter[int]]]",
+        
+        # **Tags**
+        #
+        # This synthesized field doesn't have a description.
+        "tags": "NotRequired[Optional[list[str] | std.ArrayFilter[str]]]",
+        
+        # **Title**
+        #
+        # This synthesized field doesn't have a description.
+        "title": "NotRequired[Optional[str | std.StringFilter]]",
+        
+        # **Views**
+        #
+        # This synthesized field doesn't have a description.
+        "views": "NotRequired[Optional[int | std.Filter[int]]]",
+        
+    },
+)):
+    pass
+
+# **Post where unique input**
+#
+# This synthesized interface doesn't have a description
+class PostWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "int",
+        
+    },
+)):
+    pass
+
+# **User aggregate args**
+#
+# This synthesized interface doesn't have a description
+class UserAggregateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[UserAvgAggregateInputType]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[UserCountAggregateInputType]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[UserMaxAggregateInputType]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[UserMinAggregateInputType]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[UserSumAggregateInputType]]",
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[UserSerializableScalarFields]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[UserOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[UserWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **User aggregate result**
+#
+# This synthesized interface doesn't have a description
+class UserAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[UserAvgAggregateResult]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[UserCountAggregateResult]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[UserMaxAggregateResult]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[UserMinAggregateResult]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[UserSumAggregateResult]]",
+        
+    },
+)):
+    pass
+
+# **User args**
+#
+# This synthesized interface doesn't have a description
+class UserArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+    },
+)):
+    pass
+
+# **User avg aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class UserAvgAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **User avg aggregate result**
+#
+# This synthesized interface doesn't have a description
+class UserAvgAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[float]]",
+        
+    },
+)):
+    pass
+
+# **User connect or create input**
+#
+# This synthesized interface doesn't have a description
+class UserConnectOrCreateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "UserCreateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User connect or create without posts input**
+#
+# This synthesized interface doesn't have a description
+class UserConnectOrCreateWithoutPostsInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "UserCreateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User copy args**
+#
+# This synthesized interface doesn't have a description
+class UserCopyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Copy**
+        #
+        # This synthesized field doesn't have a description.
+        "copy": "UserUpdateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User copy many args**
+#
+# This synthesized interface doesn't have a description
+class UserCopyManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Copy**
+        #
+        # This synthesized field doesn't have a description.
+        "copy": "UserUpdateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereInput",
+        
+    },
+)):
+    pass
+
+# **User count aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class UserCountAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **All**
+        #
+        # This synthesized field doesn't have a description.
+        "_all": "NotRequired[Optional[bool]]",
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[bool]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[bool]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[bool]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[bool]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **User count aggregate result**
+#
+# This synthesized interface doesn't have a description
+class UserCountAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **All**
+        #
+        # This synthesized field doesn't have a description.
+        "_all": "NotRequired[Optional[int]]",
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[int]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[int]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[int]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[int]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[int]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **User count args**
+#
+# This synthesized interface doesn't have a description
+class UserCountArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[UserSerializableScalarFields]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[UserOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserCountAggregateInputType]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[UserWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **User create args**
+#
+# This synthesized interface doesn't have a description
+class UserCreateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "UserCreateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+    },
+)):
+    pass
+
+# **User create input**
+#
+# This synthesized interface doesn't have a description
+class UserCreateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "str",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Posts**
+        #
+        # This synthesized field doesn't have a description.
+        "posts": "NotRequired[Optional[PostCreateNestedManyWithoutAuthorInput]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex]]",
+        
+    },
+)):
+    pass
+
+# **User create many args**
+#
+# This synthesized interface doesn't have a description
+class UserCreateManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "Enumerable[UserCreateInput]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+    },
+)):
+    pass
+
+# **User create nested many input**
+#
+# This synthesized interface doesn't have a description
+class UserCreateNestedManyInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[UserWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[UserConnectOrCreateInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[UserCreateInput]]]",
+        
+    },
+)):
+    pass
+
+# **User create nested many without posts input**
+#
+# This synthesized interface doesn't have a description
+class UserCreateNestedManyWithoutPostsInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[UserWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[UserConnectOrCreateWithoutPostsInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[UserCreateInput]]]",
+        
+    },
+)):
+    pass
+
+# **User create nested one input**
+#
+# This synthesized interface doesn't have a description
+class UserCreateNestedOneInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[UserConnectOrCreateInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[UserCreateInput]]",
+        
+    },
+)):
+    pass
+
+# **User create nested one without posts input**
+#
+# This synthesized interface doesn't have a description
+class UserCreateNestedOneWithoutPostsInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[UserConnectOrCreateWithoutPostsInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[UserCreateInput]]",
+        
+    },
+)):
+    pass
+
+# **User create without posts input**
+#
+# This synthesized interface doesn't have a description
+class UserCreateWithoutPostsInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "str",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex]]",
+        
+    },
+)):
+    pass
+
+# **User delete args**
+#
+# This synthesized interface doesn't have a description
+class UserDeleteArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User delete many args**
+#
+# This synthesized interface doesn't have a description
+class UserDeleteManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereInput",
+        
+    },
+)):
+    pass
+
+# **User find first args**
+#
+# This synthesized interface doesn't have a description
+class UserFindFirstArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[UserSerializableScalarFields]]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[UserOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[UserWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **User find many args**
+#
+# This synthesized interface doesn't have a description
+class UserFindManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[UserSerializableScalarFields]]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[UserOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[UserWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **User find unique args**
+#
+# This synthesized interface doesn't have a description
+class UserFindUniqueArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User group by args**
+#
+# This synthesized interface doesn't have a description
+class UserGroupByArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[UserAvgAggregateInputType]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[UserCountAggregateInputType]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[UserMaxAggregateInputType]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[UserMinAggregateInputType]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[UserSumAggregateInputType]]",
+        
+        # **By**
+        #
+        # This synthesized field doesn't have a description.
+        "by": "Enumerable[UserSerializableScalarFields]",
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[UserSerializableScalarFields]]",
+        
+        # **Having**
+        #
+        # This synthesized field doesn't have a description.
+        "having": "NotRequired[Optional[UserScalarWhereWithAggregatesInput]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[UserOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[UserWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **User group by result**
+#
+# This synthesized interface doesn't have a description
+class UserGroupByResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[UserAvgAggregateResult]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[UserCountAggregateResult]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[UserMaxAggregateResult]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[UserMinAggregateResult]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[UserSumAggregateResult]]",
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[str]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex]]",
+        
+    },
+)):
+    pass
+
+# **User include**
+#
+# This synthesized interface doesn't have a description
+class UserInclude(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Posts**
+        #
+        # This synthesized field doesn't have a description.
+        "posts": "NotRequired[Optional[PostFindManyArgs | bool]]",
+        
+    },
+)):
+    pass
+
+# **User list relation filter**
+#
+# This synthesized interface doesn't have a description
+class UserListRelationFilter(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Every**
+        #
+        # This synthesized field doesn't have a description.
+        "every": "NotRequired[Optional[UserWhereInput]]",
+        
+        # **None**
+        #
+        # This synthesized field doesn't have a description.
+        "none": "NotRequired[Optional[UserWhereInput]]",
+        
+        # **Some**
+        #
+        # This synthesized field doesn't have a description.
+        "some": "NotRequired[Optional[UserWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **User max aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class UserMaxAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[bool]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[bool]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[bool]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[bool]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **User max aggregate result**
+#
+# This synthesized interface doesn't have a description
+class UserMaxAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[str]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex]]",
+        
+    },
+)):
+    pass
+
+# **User min aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class UserMinAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[bool]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[bool]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[bool]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[bool]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **User min aggregate result**
+#
+# This synthesized interface doesn't have a description
+class UserMinAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[str]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex]]",
+        
+    },
+)):
+    pass
+
+# **User order by input**
+#
+# This synthesized interface doesn't have a description
+class UserOrderByInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[std.Sort]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[std.Sort]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[std.Sort]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[std.Sort]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[std.Sort]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[std.Sort]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[std.Sort]]",
+        
+    },
+)):
+    pass
+
+# **User relation filter**
+#
+# This synthesized interface doesn't have a description
+class UserRelationFilter(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Is**
+        #
+        # This synthesized field doesn't have a description.
+        "is": "NotRequired[Optional[UserWhereInput]]",
+        
+        # **Is Not**
+        #
+        # This synthesized field doesn't have a description.
+        "isNot": "NotRequired[Optional[UserWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **User result**
+#
+# This synthesized interface doesn't have a description
+class UserResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "datetime",
+        
+        # **Display Name**
+        #
+        # This synthesized field doesn't have a description.
+        "displayName": "NotRequired[Optional[str]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "str",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "int",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Posts**
+        #
+        # This synthesized field doesn't have a description.
+        "posts": "list[PostResult]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex]]",
+        
+    },
+)):
+    pass
+
+# **User scalar update input**
+#
+# This synthesized interface doesn't have a description
+class UserScalarUpdateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[str]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex]]",
+        
+    },
+)):
+    pass
+
+# **User scalar where with aggregates input**
+#
+# This synthesized interface doesn't have a description
+class UserScalarWhereWithAggregatesInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **And**
+        #
+        # This synthesized field doesn't have a description.
+        "AND": "NotRequired[Optional[list[UserWhereInput]]]",
+        
+        # **Not**
+        #
+        # This synthesized field doesn't have a description.
+        "NOT": "NotRequired[Optional[UserWhereInput]]",
+        
+        # **Or**
+        #
+        # This synthesized field doesn't have a description.
+        "OR": "NotRequired[Optional[list[UserWhereInput]]]",
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal | None | std.DecimalNullableWithAggregatesFilter[Decimal]]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date | None | std.NullableAggregatesFilter[date]]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime | std.AggregatesFilter[datetime]]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[str | std.StringWithAggregatesFilter]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int | std.IntNumberWithAggregatesFilter[int]]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str | None | std.StringNullableWithAggregatesFilter]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex | None | std.EnumNullableWithAggregatesFilter[Sex]]]",
+        
+    },
+)):
+    pass
+
+# **User select**
+#
+# This synthesized interface doesn't have a description
+class UserSelect(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[bool]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[bool]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[bool]]",
+        
+        # **Display Name**
+        #
+        # This synthesized field doesn't have a description.
+        "displayName": "NotRequired[Optional[bool]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[bool]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **User sign in args**
+#
+# This synthesized interface doesn't have a description
+class UserSignInArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **User sign in checker companions**
+#
+# This synthesized interface doesn't have a description
+class UserSignInCheckerCompanions(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **User sign in checker ids**
+#
+# This synthesized interface doesn't have a description
+class UserSignInCheckerIds(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **User sign in input**
+#
+# This synthesized interface doesn't have a description
+class UserSignInInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Credentials**
+        #
+        # This synthesized field doesn't have a description.
+        "credentials": "UserSignInArgs",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+    },
+)):
+    pass
+
+# **User sum aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class UserSumAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **User sum aggregate result**
+#
+# This synthesized interface doesn't have a description
+class UserSumAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **User update args**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "UserUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User update input**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[str]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Posts**
+        #
+        # This synthesized field doesn't have a description.
+        "posts": "NotRequired[Optional[PostUpdateNestedManyWithoutAuthorInput]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex]]",
+        
+    },
+)):
+    pass
+
+# **User update many args**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "UserUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereInput",
+        
+    },
+)):
+    pass
+
+# **User update many with where input**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateManyWithWhereInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "UserUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereInput",
+        
+    },
+)):
+    pass
+
+# **User update many with where without posts input**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateManyWithWhereWithoutPostsInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "UserUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereInput",
+        
+    },
+)):
+    pass
+
+# **User update nested many input**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateNestedManyInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[UserWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[UserConnectOrCreateInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[UserCreateInput]]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[Enumerable[UserWhereUniqueInput]]]",
+        
+        # **Delete Many**
+        #
+        # This synthesized field doesn't have a description.
+        "deleteMany": "NotRequired[Optional[Enumerable[UserWhereInput]]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[Enumerable[UserWhereUniqueInput]]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[Enumerable[UserWhereUniqueInput]]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[Enumerable[UserUpdateWithWhereUniqueInput]]]",
+        
+        # **Update Many**
+        #
+        # This synthesized field doesn't have a description.
+        "updateMany": "NotRequired[Optional[Enumerable[UserUpdateManyWithWhereInput]]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[Enumerable[UserUpsertWithWhereUniqueInput]]]",
+        
+    },
+)):
+    pass
+
+# **User update nested many without posts input**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateNestedManyWithoutPostsInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[UserWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[UserConnectOrCreateWithoutPostsInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[UserCreateInput]]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[Enumerable[UserWhereUniqueInput]]]",
+        
+        # **Delete Many**
+        #
+        # This synthesized field doesn't have a description.
+        "deleteMany": "NotRequired[Optional[Enumerable[UserWhereInput]]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[Enumerable[UserWhereUniqueInput]]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[Enumerable[UserWhereUniqueInput]]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[Enumerable[UserUpdateWithWhereUniqueWithoutPostsInput]]]",
+        
+        # **Update Many**
+        #
+        # This synthesized field doesn't have a description.
+        "updateMany": "NotRequired[Optional[Enumerable[UserUpdateManyWithWhereWithoutPostsInput]]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[Enumerable[UserUpsertWithWhereUniqueWithoutPostsInput]]]",
+        
+    },
+)):
+    pass
+
+# **User update nested one input**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateNestedOneInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[UserConnectOrCreateInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[UserCreateInput]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[bool]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[bool]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[UserUpdateInput]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[UserUpsertWithWhereUniqueInput]]",
+        
+    },
+)):
+    pass
+
+# **User update nested one without posts input**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateNestedOneWithoutPostsInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[UserConnectOrCreateWithoutPostsInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[UserCreateInput]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[bool]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[bool]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[UserWhereUniqueInput]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[UserUpdateInput]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[UserUpsertWithWhereUniqueWithoutPostsInput]]",
+        
+    },
+)):
+    pass
+
+# **User update with where unique input**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateWithWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "UserUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User update with where unique without posts input**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateWithWhereUniqueWithoutPostsInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "UserUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User update without posts input**
+#
+# This synthesized interface doesn't have a description
+class UserUpdateWithoutPostsInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[str]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex]]",
+        
+    },
+)):
+    pass
+
+# **User upsert args**
+#
+# This synthesized interface doesn't have a description
+class UserUpsertArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "UserCreateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[UserInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[UserSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "UserUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User upsert with where unique input**
+#
+# This synthesized interface doesn't have a description
+class UserUpsertWithWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "UserCreateInput",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "UserUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User upsert with where unique without posts input**
+#
+# This synthesized interface doesn't have a description
+class UserUpsertWithWhereUniqueWithoutPostsInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "UserCreateInput",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "UserUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "UserWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **User where input**
+#
+# This synthesized interface doesn't have a description
+class UserWhereInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **And**
+        #
+        # This synthesized field doesn't have a description.
+        "AND": "NotRequired[Optional[list[UserWhereInput]]]",
+        
+        # **Not**
+        #
+        # This synthesized field doesn't have a description.
+        "NOT": "NotRequired[Optional[UserWhereInput]]",
+        
+        # **Or**
+        #
+        # This synthesized field doesn't have a description.
+        "OR": "NotRequired[Optional[list[UserWhereInput]]]",
+        
+        # **Balance**
+        #
+        # This synthesized field doesn't have a description.
+        "balance": "NotRequired[Optional[Decimal | None | std.NullableFilter[Decimal]]]",
+        
+        # **Birthday**
+        #
+        # This synthesized field doesn't have a description.
+        "birthday": "NotRequired[Optional[date | None | std.NullableFilter[date]]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime | std.Filter[datetime]]]",
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[str | std.StringFilter]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int | std.Filter[int]]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str | None | std.StringNullableFilter]]",
+        
+        # **Posts**
+        #
+        # This synthesized field doesn't have a description.
+        "posts": "NotRequired[Optional[PostListRelationFilter]]",
+        
+        # **Sex**
+        #
+        # This synthesized field doesn't have a description.
+        "sex": "NotRequired[Optional[Sex | None | std.EnumNullableFilter[Sex]]]",
+        
+    },
+)):
+    pass
+
+# **User where unique input**
+#
+# This synthesized interface doesn't have a description
+class UserWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Email**
+        #
+        # This synthesized field doesn't have a description.
+        "email": "NotRequired[Optional[str]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+
+
+class PostModel:
+    async def find_many_objects(self, query: PostFindManyArgs, /) -> list[Post]:
+        return cast(Any, None)
+    async def find_unique_object(self, query: PostFindUniqueArgs, /) -> Optional[Post]:
+        return cast(Any, None)
+    async def find_first_object(self, query: PostFindFirstArgs, /) -> Optional[Post]:
+        return cast(Any, None)
+    async def create_object(self, input: PostCreateInput, /) -> Post:
+        return cast(Any, None)
+    async def count_objects(self, query: PostCountArgs, /) -> int:
+        return cast(Any, None)
+    async def count_fields(self, query: PostCountArgs, /) -> PostCountAggregateResult:
+        return cast(Any, None)
+    async def aggregate(self, query: PostAggregateArgs, /) -> PostAggregateResult:
+        return cast(Any, None)
+    async def group_by(self, query: PostGroupByArgs, /) -> list[PostAggregateResult]:
+        return cast(Any, None)
+    
+    async def sql(self, sql: str) -> list[Any]:
+        return cast(Any, None)
+    
+class Post(ModelObjectAnnotationMark):
+    def is_new(self) -> bool:
+        return cast(Any, None)
+    def is_modified(self) -> bool:
+        return cast(Any, None)
+    async def set(self, input: PostUpdateInput, /) -> None:
+        return cast(Any, None)
+    async def update(self, input: PostScalarUpdateInput, /) -> None:
+        return cast(Any, None)
+    async def save(self) -> None:
+        return cast(Any, None)
+    async def delete(self) -> None:
+        return cast(Any, None)
+    async def to_teon(self) -> PostResult:
+        return cast(Any, None)
+    id: int
+    title: str
+    content: Optional[str]
+    tags: list[str]
+    views: int
+    author_id: int
+    async def author(self) -> User:
+        return cast(Any, None)
+    async def set_author(self, new_value: User, /) -> None:
+        return cast(Any, None)
+
+class UserModel:
+    async def find_many_objects(self, query: UserFindManyArgs, /) -> list[User]:
+        return cast(Any, None)
+    async def find_unique_object(self, query: UserFindUniqueArgs, /) -> Optional[User]:
+        return cast(Any, None)
+    async def find_first_object(self, query: UserFindFirstArgs, /) -> Optional[User]:
+        return cast(Any, None)
+    async def create_object(self, input: UserCreateInput, /) -> User:
+        return cast(Any, None)
+    async def count_objects(self, query: UserCountArgs, /) -> int:
+        return cast(Any, None)
+    async def count_fields(self, query: UserCountArgs, /) -> UserCountAggregateResult:
+        return cast(Any, None)
+    async def aggregate(self, query: UserAggregateArgs, /) -> UserAggregateResult:
+        return cast(Any, None)
+    async def group_by(self, query: UserGroupByArgs, /) -> list[UserAggregateResult]:
+        return cast(Any, None)
+    
+    async def sql(self, sql: str) -> list[Any]:
+        return cast(Any, None)
+    
+class User(ModelObjectAnnotationMark):
+    def is_new(self) -> bool:
+        return cast(Any, None)
+    def is_modified(self) -> bool:
+        return cast(Any, None)
+    async def set(self, input: UserUpdateInput, /) -> None:
+        return cast(Any, None)
+    async def update(self, input: UserScalarUpdateInput, /) -> None:
+        return cast(Any, None)
+    async def save(self) -> None:
+        return cast(Any, None)
+    async def delete(self) -> None:
+        return cast(Any, None)
+    async def to_teon(self) -> UserResult:
+        return cast(Any, None)
+    id: int
+    email: str
+    name: Optional[str]
+    sex: Optional[Sex]
+    birthday: Optional[date]
+    balance: Optional[Decimal]
+    created_at: datetime
+    async def posts(self) -> list[Post]:
+        return cast(Any, None)
+    async def set_posts(self, new_value: list[Post], /) -> None:
+        return cast(Any, None)
+    async def add_to_posts(self, new_value: list[Post], /) -> None:
+        return cast(Any, None)
+    async def remove_from_posts(self, new_value: list[Post], /) -> None:
+        return cast(Any, None)
+    async def display_name(self) -> Optional[str]:
+        return cast(Any, None)
+
+
+
+class PostPathArguments(TypedDict(
+    "CapturesAnnotationMark",
+    {
+        
+            "id": str
+        
+    }
+)):
+    pass
+
+class Teo(TeoAnnotationMark):
+
+    
+    async def transaction[T](self, teo: Teo, /) -> T:
+        return cast(Any, None)
+    
+    post: PostModel
+    user: UserModel
\ No newline at end of file
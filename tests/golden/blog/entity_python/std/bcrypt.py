@@ -0,0 +1,27 @@
+# type: ignore
+from __future__ import annotations
+from typing import Any, Optional, Literal, Generic, TypeVar, NotRequired, TypedDict, cast, TYPE_CHECKING
+from re import Pattern
+from datetime import date, datetime
+from decimal import Decimal
+from teo import ObjectId, Enumerable, File, Range, OptionVariant
+from teo.annotations import TeoAnnotationMark, ModelObjectAnnotationMark
+
+
+if TYPE_CHECKING:
+    from .. import generated
+
+
+
+
+
+
+
+
+
+
+class BcryptNamespace:
+
+    
+    pass
+    
\ No newline at end of file
@@ -0,0 +1,121 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use std::marker::PhantomData;
+use indexmap::IndexMap;
+use teo::prelude::{Result, Value};
+use super::interface::AsInterface;
+
+/// ## Field filter
+///
+/// A typed filter on a single field of a where input. `W` is the where input
+/// which is built, `T` is the type of the field.
+pub struct FieldFilter<W, T> {
+    field: &'static str,
+    phantom_data: PhantomData<(W, T)>,
+}
+
+impl<W, T> FieldFilter<W, T> where W: AsInterface, T: Into<Value> {
+
+    pub fn new(field: &'static str) -> Self {
+        Self { field, phantom_data: PhantomData::default() }
+    }
+
+    fn build(self, operator: &str, value: Value) -> Result<W> {
+        let mut filter = IndexMap::new();
+        filter.insert(operator.to_owned(), value);
+        let mut input = IndexMap::new();
+        input.insert(self.field.to_owned(), Value::Dictionary(filter));
+        W::from_value(Value::Dictionary(input))
+    }
+
+    /// Field equals `value`.
+    pub fn equals(self, value: impl Into<T>) -> Result<W> {
+        self.build("equals", Into::<T>::into(value).into())
+    }
+
+    /// Field doesn't equal `value`.
+    pub fn not(self, value: impl Into<T>) -> Result<W> {
+        self.build("not", Into::<T>::into(value).into())
+    }
+
+    /// Field is one of `values`.
+    pub fn in_(self, values: impl IntoIterator<Item = T>) -> Result<W> {
+        self.build("in", Value::Array(values.into_iter().map(Into::into).collect()))
+    }
+
+    /// Field is none of `values`.
+    pub fn not_in(self, values: impl IntoIterator<Item = T>) -> Result<W> {
+        self.build("notIn", Value::Array(values.into_iter().map(Into::into).collect()))
+    }
+
+    /// Field is less than `value`.
+    pub fn lt(self, value: impl Into<T>) -> Result<W> {
+        self.build("lt", Into::<T>::into(value).into())
+    }
+
+    /// Field is less than or equals `value`.
+    pub fn lte(self, value: impl Into<T>) -> Result<W> {
+        self.build("lte", Into::<T>::into(value).into())
+    }
+
+    /// Field is greater than `value`.
+    pub fn gt(self, value: impl Into<T>) -> Result<W> {
+        self.build("gt", Into::<T>::into(value).into())
+    }
+
+    /// Field is greater than or equals `value`.
+    pub fn gte(self, value: impl Into<T>) -> Result<W> {
+        self.build("gte", Into::<T>::into(value).into())
+    }
+
+    /// Field is null.
+    pub fn is_null(self) -> Result<W> {
+        self.build("equals", Value::Null)
+    }
+
+    /// Field is not null.
+    pub fn is_not_null(self) -> Result<W> {
+        self.build("not", Value::Null)
+    }
+}
+
+impl<W> FieldFilter<W, String> where W: AsInterface {
+
+    /// Field contains `value`.
+    pub fn contains(self, value: impl Into<String>) -> Result<W> {
+        self.build("contains", Value::String(value.into()))
+    }
+
+    /// Field starts with `value`.
+    pub fn starts_with(self, value: impl Into<String>) -> Result<W> {
+        self.build("startsWith", Value::String(value.into()))
+    }
+
+    /// Field ends with `value`.
+    pub fn ends_with(self, value: impl Into<String>) -> Result<W> {
+        self.build("endsWith", Value::String(value.into()))
+    }
+
+    /// Field matches the regular expression `value`.
+    pub fn matches(self, value: impl Into<String>) -> Result<W> {
+        self.build("matches", Value::String(value.into()))
+    }
+}
+
+/// Combine where inputs with `AND`.
+pub fn and<W>(inputs: Vec<Value>) -> Result<W> where W: AsInterface {
+    combine("AND", inputs)
+}
+
+/// Combine where inputs with `OR`.
+pub fn or<W>(inputs: Vec<Value>) -> Result<W> where W: AsInterface {
+    combine("OR", inputs)
+}
+
+fn combine<W>(operator: &str, inputs: Vec<Value>) -> Result<W> where W: AsInterface {
+    let mut input = IndexMap::new();
+    input.insert(operator.to_owned(), Value::Array(inputs));
+    W::from_value(Value::Dictionary(input))
+}
@@ -0,0 +1,25466 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+pub mod helpers;
+pub mod stdlib;
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use chrono::NaiveDate;
+use chrono::{DateTime, Utc};
+use bigdecimal::BigDecimal;
+use teo::prelude::{
+    teon, model, Model, Value, Result, Error, transaction, Request, ExtractFromRequest, ExtractFromPipelineCtx, request, pipeline, ExtractFromTransactionCtx, File, Arguments,
+};
+use std::marker::PhantomData;
+use helpers::interface::{Interface, AsInterface, AsInterfaceRef, AsInterfaceVecRef};
+use helpers::query::{self, FieldFilter};
+use helpers::relations::{self, RelationCache};
+#[cfg(feature = "teo-serde")]
+use helpers::teon_serde::{serialize_teon, deserialize_teon};
+
+
+/// ## Sex
+///
+/// Sex The sex of a user.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct Sex {
+    inner: String,
+}
+
+impl Sex {
+    /// ### Is Male
+    ///
+    /// Returns true if value is male
+    pub fn is_male(&self) -> bool {
+        self.inner.as_str() == "male"
+    }
+    /// ### Male
+    ///
+    /// This enum member doesn't have a description.
+    pub fn male() -> Self {
+        Self { inner: "male".to_owned() }
+    }
+    /// ### Is Female
+    ///
+    /// Returns true if value is female
+    pub fn is_female(&self) -> bool {
+        self.inner.as_str() == "female"
+    }
+    /// ### Female
+    ///
+    /// This enum member doesn't have a description.
+    pub fn female() -> Self {
+        Self { inner: "female".to_owned() }
+    }
+}
+
+impl Debug for Sex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Sex(.{})", self.inner)
+    }
+}
+
+impl Display for Sex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<Sex> for Value {
+    fn from(value: Sex) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for Sex {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "male" => Sex::male(),
+                "female" => Sex::female(),
+                _ => Err(Error::new("cannot convert value to Sex"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to Sex"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for Sex {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "male" => Sex::male(),
+                "female" => Sex::female(),
+                _ => Err(Error::new("cannot convert value to Sex"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to Sex"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &Sex {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "male" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "female" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &Sex"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &Sex"))
+        }
+    }
+}
+
+impl AsInterface for Sex {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "male" => Sex::male(),
+                "female" => Sex::female(),
+                _ => Err(Error::new("cannot convert value to Sex"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to Sex"))
+        }
+    }
+}
+
+impl AsInterfaceRef for Sex {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "male" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "female" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &Sex"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &Sex"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for Sex {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for Sex {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Post direct relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct PostDirectRelations {
+    inner: String,
+}
+
+impl PostDirectRelations {
+    /// ### Is Author
+    ///
+    /// Returns true if value is author
+    pub fn is_author(&self) -> bool {
+        self.inner.as_str() == "author"
+    }
+    /// ### Author
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn author() -> Self {
+        Self { inner: "author".to_owned() }
+    }
+}
+
+impl Debug for PostDirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PostDirectRelations(.{})", self.inner)
+    }
+}
+
+impl Display for PostDirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<PostDirectRelations> for Value {
+    fn from(value: PostDirectRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for PostDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "author" => PostDirectRelations::author(),
+                _ => Err(Error::new("cannot convert value to PostDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostDirectRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for PostDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "author" => PostDirectRelations::author(),
+                _ => Err(Error::new("cannot convert value to PostDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostDirectRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &PostDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "author" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &PostDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &PostDirectRelations"))
+        }
+    }
+}
+
+impl AsInterface for PostDirectRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "author" => PostDirectRelations::author(),
+                _ => Err(Error::new("cannot convert value to PostDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostDirectRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for PostDirectRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "author" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &PostDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &PostDirectRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostDirectRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostDirectRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Post indirect relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct PostIndirectRelations {
+    inner: String,
+}
+
+impl PostIndirectRelations {
+}
+
+impl Debug for PostIndirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PostIndirectRelations(.{})", self.inner)
+    }
+}
+
+impl Display for PostIndirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<PostIndirectRelations> for Value {
+    fn from(value: PostIndirectRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for PostIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to PostIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostIndirectRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for PostIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to PostIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostIndirectRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &PostIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &PostIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &PostIndirectRelations"))
+        }
+    }
+}
+
+impl AsInterface for PostIndirectRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to PostIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostIndirectRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for PostIndirectRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &PostIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &PostIndirectRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostIndirectRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostIndirectRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Post relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct PostRelations {
+    inner: String,
+}
+
+impl PostRelations {
+    /// ### Is Author
+    ///
+    /// Returns true if value is author
+    pub fn is_author(&self) -> bool {
+        self.inner.as_str() == "author"
+    }
+    /// ### Author
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn author() -> Self {
+        Self { inner: "author".to_owned() }
+    }
+}
+
+impl Debug for PostRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PostRelations(.{})", self.inner)
+    }
+}
+
+impl Display for PostRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<PostRelations> for Value {
+    fn from(value: PostRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for PostRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "author" => PostRelations::author(),
+                _ => Err(Error::new("cannot convert value to PostRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for PostRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "author" => PostRelations::author(),
+                _ => Err(Error::new("cannot convert value to PostRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &PostRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "author" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &PostRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &PostRelations"))
+        }
+    }
+}
+
+impl AsInterface for PostRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "author" => PostRelations::author(),
+                _ => Err(Error::new("cannot convert value to PostRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for PostRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "author" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &PostRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &PostRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Post scalar fields
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct PostScalarFields {
+    inner: String,
+}
+
+impl PostScalarFields {
+    /// ### Is Author id
+    ///
+    /// Returns true if value is author id
+    pub fn is_author_id(&self) -> bool {
+        self.inner.as_str() == "authorId"
+    }
+    /// ### Author id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn author_id() -> Self {
+        Self { inner: "authorId".to_owned() }
+    }
+    /// ### Is Content
+    ///
+    /// Returns true if value is content
+    pub fn is_content(&self) -> bool {
+        self.inner.as_str() == "content"
+    }
+    /// ### Content
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn content() -> Self {
+        Self { inner: "content".to_owned() }
+    }
+    /// ### Is Id
+    ///
+    /// Returns true if value is id
+    pub fn is_id(&self) -> bool {
+        self.inner.as_str() == "id"
+    }
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn id() -> Self {
+        Self { inner: "id".to_owned() }
+    }
+    /// ### Is Tags
+    ///
+    /// Returns true if value is tags
+    pub fn is_tags(&self) -> bool {
+        self.inner.as_str() == "tags"
+    }
+    /// ### Tags
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn tags() -> Self {
+        Self { inner: "tags".to_owned() }
+    }
+    /// ### Is Title
+    ///
+    /// Returns true if value is title
+    pub fn is_title(&self) -> bool {
+        self.inner.as_str() == "title"
+    }
+    /// ### Title
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn title() -> Self {
+        Self { inner: "title".to_owned() }
+    }
+    /// ### Is Views
+    ///
+    /// Returns true if value is views
+    pub fn is_views(&self) -> bool {
+        self.inner.as_str() == "views"
+    }
+    /// ### Views
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn views() -> Self {
+        Self { inner: "views".to_owned() }
+    }
+}
+
+impl Debug for PostScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PostScalarFields(.{})", self.inner)
+    }
+}
+
+impl Display for PostScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<PostScalarFields> for Value {
+    fn from(value: PostScalarFields) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for PostScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "authorId" => PostScalarFields::author_id(),
+                "content" => PostScalarFields::content(),
+                "id" => PostScalarFields::id(),
+                "tags" => PostScalarFields::tags(),
+                "title" => PostScalarFields::title(),
+                "views" => PostScalarFields::views(),
+                _ => Err(Error::new("cannot convert value to PostScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostScalarFields"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for PostScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "authorId" => PostScalarFields::author_id(),
+                "content" => PostScalarFields::content(),
+                "id" => PostScalarFields::id(),
+                "tags" => PostScalarFields::tags(),
+                "title" => PostScalarFields::title(),
+                "views" => PostScalarFields::views(),
+                _ => Err(Error::new("cannot convert value to PostScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostScalarFields"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &PostScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "authorId" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "content" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "tags" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "title" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "views" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &PostScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &PostScalarFields"))
+        }
+    }
+}
+
+impl AsInterface for PostScalarFields {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "authorId" => PostScalarFields::author_id(),
+                "content" => PostScalarFields::content(),
+                "id" => PostScalarFields::id(),
+                "tags" => PostScalarFields::tags(),
+                "title" => PostScalarFields::title(),
+                "views" => PostScalarFields::views(),
+                _ => Err(Error::new("cannot convert value to PostScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostScalarFields"))
+        }
+    }
+}
+
+impl AsInterfaceRef for PostScalarFields {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "authorId" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "content" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "tags" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "title" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "views" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &PostScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &PostScalarFields"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostScalarFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostScalarFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Post serializable scalar fields
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct PostSerializableScalarFields {
+    inner: String,
+}
+
+impl PostSerializableScalarFields {
+    /// ### Is Author id
+    ///
+    /// Returns true if value is author id
+    pub fn is_author_id(&self) -> bool {
+        self.inner.as_str() == "authorId"
+    }
+    /// ### Author id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn author_id() -> Self {
+        Self { inner: "authorId".to_owned() }
+    }
+    /// ### Is Content
+    ///
+    /// Returns true if value is content
+    pub fn is_content(&self) -> bool {
+        self.inner.as_str() == "content"
+    }
+    /// ### Content
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn content() -> Self {
+        Self { inner: "content".to_owned() }
+    }
+    /// ### Is Id
+    ///
+    /// Returns true if value is id
+    pub fn is_id(&self) -> bool {
+        self.inner.as_str() == "id"
+    }
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn id() -> Self {
+        Self { inner: "id".to_owned() }
+    }
+    /// ### Is Tags
+    ///
+    /// Returns true if value is tags
+    pub fn is_tags(&self) -> bool {
+        self.inner.as_str() == "tags"
+    }
+    /// ### Tags
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn tags() -> Self {
+        Self { inner: "tags".to_owned() }
+    }
+    /// ### Is Title
+    ///
+    /// Returns true if value is title
+    pub fn is_title(&self) -> bool {
+        self.inner.as_str() == "title"
+    }
+    /// ### Title
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn title() -> Self {
+        Self { inner: "title".to_owned() }
+    }
+    /// ### Is Views
+    ///
+    /// Returns true if value is views
+    pub fn is_views(&self) -> bool {
+        self.inner.as_str() == "views"
+    }
+    /// ### Views
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn views() -> Self {
+        Self { inner: "views".to_owned() }
+    }
+}
+
+impl Debug for PostSerializableScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PostSerializableScalarFields(.{})", self.inner)
+    }
+}
+
+impl Display for PostSerializableScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<PostSerializableScalarFields> for Value {
+    fn from(value: PostSerializableScalarFields) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for PostSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "authorId" => PostSerializableScalarFields::author_id(),
+                "content" => PostSerializableScalarFields::content(),
+                "id" => PostSerializableScalarFields::id(),
+                "tags" => PostSerializableScalarFields::tags(),
+                "title" => PostSerializableScalarFields::title(),
+                "views" => PostSerializableScalarFields::views(),
+                _ => Err(Error::new("cannot convert value to PostSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostSerializableScalarFields"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for PostSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "authorId" => PostSerializableScalarFields::author_id(),
+                "content" => PostSerializableScalarFields::content(),
+                "id" => PostSerializableScalarFields::id(),
+                "tags" => PostSerializableScalarFields::tags(),
+                "title" => PostSerializableScalarFields::title(),
+                "views" => PostSerializableScalarFields::views(),
+                _ => Err(Error::new("cannot convert value to PostSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostSerializableScalarFields"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &PostSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "authorId" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "content" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "tags" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "title" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "views" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &PostSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &PostSerializableScalarFields"))
+        }
+    }
+}
+
+impl AsInterface for PostSerializableScalarFields {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "authorId" => PostSerializableScalarFields::author_id(),
+                "content" => PostSerializableScalarFields::content(),
+                "id" => PostSerializableScalarFields::id(),
+                "tags" => PostSerializableScalarFields::tags(),
+                "title" => PostSerializableScalarFields::title(),
+                "views" => PostSerializableScalarFields::views(),
+                _ => Err(Error::new("cannot convert value to PostSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to PostSerializableScalarFields"))
+        }
+    }
+}
+
+impl AsInterfaceRef for PostSerializableScalarFields {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "authorId" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "content" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "tags" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "title" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "views" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &PostSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &PostSerializableScalarFields"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostSerializableScalarFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostSerializableScalarFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## User direct relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct UserDirectRelations {
+    inner: String,
+}
+
+impl UserDirectRelations {
+    /// ### Is Posts
+    ///
+    /// Returns true if value is posts
+    pub fn is_posts(&self) -> bool {
+        self.inner.as_str() == "posts"
+    }
+    /// ### Posts
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn posts() -> Self {
+        Self { inner: "posts".to_owned() }
+    }
+}
+
+impl Debug for UserDirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UserDirectRelations(.{})", self.inner)
+    }
+}
+
+impl Display for UserDirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<UserDirectRelations> for Value {
+    fn from(value: UserDirectRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for UserDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "posts" => UserDirectRelations::posts(),
+                _ => Err(Error::new("cannot convert value to UserDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserDirectRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for UserDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "posts" => UserDirectRelations::posts(),
+                _ => Err(Error::new("cannot convert value to UserDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserDirectRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &UserDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "posts" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &UserDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &UserDirectRelations"))
+        }
+    }
+}
+
+impl AsInterface for UserDirectRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "posts" => UserDirectRelations::posts(),
+                _ => Err(Error::new("cannot convert value to UserDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserDirectRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for UserDirectRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "posts" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &UserDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &UserDirectRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserDirectRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserDirectRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## User indirect relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct UserIndirectRelations {
+    inner: String,
+}
+
+impl UserIndirectRelations {
+}
+
+impl Debug for UserIndirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UserIndirectRelations(.{})", self.inner)
+    }
+}
+
+impl Display for UserIndirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<UserIndirectRelations> for Value {
+    fn from(value: UserIndirectRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for UserIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to UserIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserIndirectRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for UserIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to UserIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserIndirectRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &UserIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &UserIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &UserIndirectRelations"))
+        }
+    }
+}
+
+impl AsInterface for UserIndirectRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to UserIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserIndirectRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for UserIndirectRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &UserIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &UserIndirectRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserIndirectRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserIndirectRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## User relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct UserRelations {
+    inner: String,
+}
+
+impl UserRelations {
+    /// ### Is Posts
+    ///
+    /// Returns true if value is posts
+    pub fn is_posts(&self) -> bool {
+        self.inner.as_str() == "posts"
+    }
+    /// ### Posts
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn posts() -> Self {
+        Self { inner: "posts".to_owned() }
+    }
+}
+
+impl Debug for UserRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UserRelations(.{})", self.inner)
+    }
+}
+
+impl Display for UserRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<UserRelations> for Value {
+    fn from(value: UserRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for UserRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "posts" => UserRelations::posts(),
+                _ => Err(Error::new("cannot convert value to UserRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for UserRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "posts" => UserRelations::posts(),
+                _ => Err(Error::new("cannot convert value to UserRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &UserRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "posts" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &UserRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &UserRelations"))
+        }
+    }
+}
+
+impl AsInterface for UserRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "posts" => UserRelations::posts(),
+                _ => Err(Error::new("cannot convert value to UserRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for UserRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "posts" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &UserRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &UserRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## User scalar fields
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct UserScalarFields {
+    inner: String,
+}
+
+impl UserScalarFields {
+    /// ### Is Balance
+    ///
+    /// Returns true if value is balance
+    pub fn is_balance(&self) -> bool {
+        self.inner.as_str() == "balance"
+    }
+    /// ### Balance
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn balance() -> Self {
+        Self { inner: "balance".to_owned() }
+    }
+    /// ### Is Birthday
+    ///
+    /// Returns true if value is birthday
+    pub fn is_birthday(&self) -> bool {
+        self.inner.as_str() == "birthday"
+    }
+    /// ### Birthday
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn birthday() -> Self {
+        Self { inner: "birthday".to_owned() }
+    }
+    /// ### Is Created at
+    ///
+    /// Returns true if value is created at
+    pub fn is_created_at(&self) -> bool {
+        self.inner.as_str() == "createdAt"
+    }
+    /// ### Created at
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn created_at() -> Self {
+        Self { inner: "createdAt".to_owned() }
+    }
+    /// ### Is Email
+    ///
+    /// Returns true if value is email
+    pub fn is_email(&self) -> bool {
+        self.inner.as_str() == "email"
+    }
+    /// ### Email
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn email() -> Self {
+        Self { inner: "email".to_owned() }
+    }
+    /// ### Is Id
+    ///
+    /// Returns true if value is id
+    pub fn is_id(&self) -> bool {
+        self.inner.as_str() == "id"
+    }
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn id() -> Self {
+        Self { inner: "id".to_owned() }
+    }
+    /// ### Is Name
+    ///
+    /// Returns true if value is name
+    pub fn is_name(&self) -> bool {
+        self.inner.as_str() == "name"
+    }
+    /// ### Name
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn name() -> Self {
+        Self { inner: "name".to_owned() }
+    }
+    /// ### Is Sex
+    ///
+    /// Returns true if value is sex
+    pub fn is_sex(&self) -> bool {
+        self.inner.as_str() == "sex"
+    }
+    /// ### Sex
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn sex() -> Self {
+        Self { inner: "sex".to_owned() }
+    }
+}
+
+impl Debug for UserScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UserScalarFields(.{})", self.inner)
+    }
+}
+
+impl Display for UserScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<UserScalarFields> for Value {
+    fn from(value: UserScalarFields) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for UserScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "balance" => UserScalarFields::balance(),
+                "birthday" => UserScalarFields::birthday(),
+                "createdAt" => UserScalarFields::created_at(),
+                "email" => UserScalarFields::email(),
+                "id" => UserScalarFields::id(),
+                "name" => UserScalarFields::name(),
+                "sex" => UserScalarFields::sex(),
+                _ => Err(Error::new("cannot convert value to UserScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserScalarFields"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for UserScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "balance" => UserScalarFields::balance(),
+                "birthday" => UserScalarFields::birthday(),
+                "createdAt" => UserScalarFields::created_at(),
+                "email" => UserScalarFields::email(),
+                "id" => UserScalarFields::id(),
+                "name" => UserScalarFields::name(),
+                "sex" => UserScalarFields::sex(),
+                _ => Err(Error::new("cannot convert value to UserScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserScalarFields"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &UserScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "balance" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "birthday" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "createdAt" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "email" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "name" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "sex" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &UserScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &UserScalarFields"))
+        }
+    }
+}
+
+impl AsInterface for UserScalarFields {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "balance" => UserScalarFields::balance(),
+                "birthday" => UserScalarFields::birthday(),
+                "createdAt" => UserScalarFields::created_at(),
+                "email" => UserScalarFields::email(),
+                "id" => UserScalarFields::id(),
+                "name" => UserScalarFields::name(),
+                "sex" => UserScalarFields::sex(),
+                _ => Err(Error::new("cannot convert value to UserScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserScalarFields"))
+        }
+    }
+}
+
+impl AsInterfaceRef for UserScalarFields {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "balance" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "birthday" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "createdAt" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "email" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "name" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "sex" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &UserScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &UserScalarFields"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserScalarFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserScalarFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## User serializable scalar fields
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct UserSerializableScalarFields {
+    inner: String,
+}
+
+impl UserSerializableScalarFields {
+    /// ### Is Balance
+    ///
+    /// Returns true if value is balance
+    pub fn is_balance(&self) -> bool {
+        self.inner.as_str() == "balance"
+    }
+    /// ### Balance
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn balance() -> Self {
+        Self { inner: "balance".to_owned() }
+    }
+    /// ### Is Birthday
+    ///
+    /// Returns true if value is birthday
+    pub fn is_birthday(&self) -> bool {
+        self.inner.as_str() == "birthday"
+    }
+    /// ### Birthday
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn birthday() -> Self {
+        Self { inner: "birthday".to_owned() }
+    }
+    /// ### Is Created at
+    ///
+    /// Returns true if value is created at
+    pub fn is_created_at(&self) -> bool {
+        self.inner.as_str() == "createdAt"
+    }
+    /// ### Created at
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn created_at() -> Self {
+        Self { inner: "createdAt".to_owned() }
+    }
+    /// ### Is Email
+    ///
+    /// Returns true if value is email
+    pub fn is_email(&self) -> bool {
+        self.inner.as_str() == "email"
+    }
+    /// ### Email
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn email() -> Self {
+        Self { inner: "email".to_owned() }
+    }
+    /// ### Is Id
+    ///
+    /// Returns true if value is id
+    pub fn is_id(&self) -> bool {
+        self.inner.as_str() == "id"
+    }
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn id() -> Self {
+        Self { inner: "id".to_owned() }
+    }
+    /// ### Is Name
+    ///
+    /// Returns true if value is name
+    pub fn is_name(&self) -> bool {
+        self.inner.as_str() == "name"
+    }
+    /// ### Name
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn name() -> Self {
+        Self { inner: "name".to_owned() }
+    }
+    /// ### Is Sex
+    ///
+    /// Returns true if value is sex
+    pub fn is_sex(&self) -> bool {
+        self.inner.as_str() == "sex"
+    }
+    /// ### Sex
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn sex() -> Self {
+        Self { inner: "sex".to_owned() }
+    }
+}
+
+impl Debug for UserSerializableScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UserSerializableScalarFields(.{})", self.inner)
+    }
+}
+
+impl Display for UserSerializableScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<UserSerializableScalarFields> for Value {
+    fn from(value: UserSerializableScalarFields) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for UserSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "balance" => UserSerializableScalarFields::balance(),
+                "birthday" => UserSerializableScalarFields::birthday(),
+                "createdAt" => UserSerializableScalarFields::created_at(),
+                "email" => UserSerializableScalarFields::email(),
+                "id" => UserSerializableScalarFields::id(),
+                "name" => UserSerializableScalarFields::name(),
+                "sex" => UserSerializableScalarFields::sex(),
+                _ => Err(Error::new("cannot convert value to UserSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserSerializableScalarFields"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for UserSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "balance" => UserSerializableScalarFields::balance(),
+                "birthday" => UserSerializableScalarFields::birthday(),
+                "createdAt" => UserSerializableScalarFields::created_at(),
+                "email" => UserSerializableScalarFields::email(),
+                "id" => UserSerializableScalarFields::id(),
+                "name" => UserSerializableScalarFields::name(),
+                "sex" => UserSerializableScalarFields::sex(),
+                _ => Err(Error::new("cannot convert value to UserSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserSerializableScalarFields"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &UserSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "balance" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "birthday" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "createdAt" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "email" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "name" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "sex" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &UserSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &UserSerializableScalarFields"))
+        }
+    }
+}
+
+impl AsInterface for UserSerializableScalarFields {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "balance" => UserSerializableScalarFields::balance(),
+                "birthday" => UserSerializableScalarFields::birthday(),
+                "createdAt" => UserSerializableScalarFields::created_at(),
+                "email" => UserSerializableScalarFields::email(),
+                "id" => UserSerializableScalarFields::id(),
+                "name" => UserSerializableScalarFields::name(),
+                "sex" => UserSerializableScalarFields::sex(),
+                _ => Err(Error::new("cannot convert value to UserSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to UserSerializableScalarFields"))
+        }
+    }
+}
+
+impl AsInterfaceRef for UserSerializableScalarFields {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "balance" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "birthday" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "createdAt" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "email" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "name" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "sex" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &UserSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &UserSerializableScalarFields"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserSerializableScalarFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserSerializableScalarFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+
+/// ## Post
+///
+/// Post A post written by a user.
+pub struct PostModel {
+    ctx: model::Ctx,
+}
+
+impl PostModel {
+    /// Find many post objects.
+    pub async fn find_many_objects(&self, query: impl Borrow<Value>) -> Result<Vec<Post>> {
+        Ok(self.ctx.find_many(query.borrow()).await?)
+    }
+
+    /// Find a unique post object.
+    pub async fn find_unique_object(&self, query: impl Borrow<Value>) -> Result<Option<Post>> {
+        Ok(self.ctx.find_unique(query.borrow()).await?)
+    }
+
+    /// Find a post object.
+    pub async fn find_first_object(&self, query: impl Borrow<Value>) -> Result<Option<Post>> {
+        Ok(self.ctx.find_first(query.borrow()).await?)
+    }
+
+    /// Create a new post object.
+    pub async fn create_object(&self, values: impl Borrow<Value>) -> Result<Post> {
+        Ok(self.ctx.create_object::<Post>(values.borrow()).await?)
+    }
+
+    /// Create an empty post object.
+    pub async fn create_default_object(&self) -> Result<Post> {
+        Ok(self.ctx.create_object::<Post>(teon!({}).borrow()).await?)
+    }
+
+    /// Create and save a post object from the `create` input of `args`.
+    pub async fn create_object_from_args(&self, args: impl Borrow<Value>) -> Result<Post> {
+        let values = args.borrow().as_dictionary().and_then(|args| args.get("create")).cloned().unwrap_or_else(|| teon!({}));
+        let object = self.create_object(values).await?;
+        object.save().await?;
+        Ok(object)
+    }
+
+    /// Count objects on post.
+    pub async fn count_objects(&self, query: impl Borrow<Value>) -> Result<usize> {
+        Ok(self.ctx.count_objects(query.borrow()).await?)
+    }
+
+    /// Count fields on post.
+    pub async fn count_fields(&self, query: impl Borrow<Value>) -> Result<PostCountAggregateResult> {
+        Ok(PostCountAggregateResult::from_value(self.ctx.count_fields(query.borrow()).await?)?)
+    }
+
+    /// Aggregate on post.
+    pub async fn aggregate(&self, query: impl Borrow<Value>) -> Result<PostAggregateResult> {
+        Ok(PostAggregateResult::from_value(self.ctx.aggregate(query.borrow()).await?)?)
+    }
+
+    /// Group by on post.
+    pub async fn group_by(&self, query: impl Borrow<Value>) -> Result<Vec<PostAggregateResult>> {
+        let values: Vec<Value> = self.ctx.group_by(query.borrow()).await?;
+        let mut result = vec![];
+        for value in values.into_iter() {
+            result.push(PostAggregateResult::from_value(value)?);
+        }
+        Ok(result)
+    }
+
+    
+    /// Run a custom SQL clause.
+    pub async fn sql<T, E>(&self, sql: &str) -> Result<Vec<T>> where T: TryFrom<Value, Error=E>, Error: From<E> {
+        self.ctx.sql(sql).await
+    }
+    
+
+    /// Load `author` of every object in `objects` at once. Later calls to
+    /// `author` on these objects read from the loaded results.
+    pub async fn load_author_for(&self, objects: &[Post]) -> Result<()> {
+        let owners = objects.iter().map(|o| (&o.relations, &o.inner)).collect();
+        let related = self.ctx.transaction_ctx().model_ctx_for_model_at_path(&vec!["User".to_owned()]).unwrap();
+        relations::batch_load(owners, "author", &related, &["authorId", ], &["id", ]).await
+    }
+}
+
+#[derive(Clone)]
+pub struct Post {
+    inner: model::Object,
+    relations: RelationCache,
+}
+
+impl Post {
+
+    /// Whether this post is new.
+    pub fn is_new(&self) -> bool {
+        self.inner.is_new()
+    }
+
+    /// Whether this post is modified.
+    pub fn is_modified(&self) -> bool {
+        self.inner.is_modified()
+    }
+
+    /// Set new values to a post. Validations and transformations are
+    /// triggered.
+    pub async fn set(&self, values: impl AsRef<Value>) -> Result<()> {
+        self.inner.set_teon(values.as_ref()).await
+    }
+
+    /// Update with new values to a post. Validations and transformations are
+    /// not triggered.
+    pub async fn update(&self, values: impl AsRef<Value>) -> Result<()> {
+        self.inner.update_teon(values.as_ref()).await
+    }
+
+    /// Save this post.
+    pub async fn save(&self) -> Result<()> {
+        self.inner.save().await
+    }
+
+    /// Delete this post.
+    pub async fn delete(&self) -> Result<()> {
+        self.inner.delete().await
+    }
+
+    /// Convert this post object to teon.
+    pub async fn to_teon(&self) -> Result<Value> {
+        self.inner.to_teon().await
+    }
+    /// ## Id
+    ///
+    /// This field doesn't have a description.
+    pub fn id(&self) -> Result<i32> {
+        self.inner.get("id")
+    }
+
+    /// ## Id
+    ///
+    /// This field doesn't have a description.
+    pub fn set_id(&self, new_value: i32) -> Result<()> {
+        self.inner.set("id", new_value)
+    }
+    /// ## Title
+    ///
+    /// This field doesn't have a description.
+    pub fn title(&self) -> Result<String> {
+        self.inner.get("title")
+    }
+
+    /// ## Title
+    ///
+    /// This field doesn't have a description.
+    pub fn set_title(&self, new_value: String) -> Result<()> {
+        self.inner.set("title", new_value)
+    }
+    /// ## Content
+    ///
+    /// This field doesn't have a description.
+    pub fn content(&self) -> Result<Option<String>> {
+        self.inner.get("content")
+    }
+
+    /// ## Content
+    ///
+    /// This field doesn't have a description.
+    pub fn set_content(&self, new_value: Option<String>) -> Result<()> {
+        self.inner.set("content", new_value)
+    }
+    /// ## Tags
+    ///
+    /// This field doesn't have a description.
+    pub fn tags(&self) -> Result<Vec<String>> {
+        self.inner.get("tags")
+    }
+
+    /// ## Tags
+    ///
+    /// This field doesn't have a description.
+    pub fn set_tags(&self, new_value: Vec<String>) -> Result<()> {
+        self.inner.set("tags", new_value)
+    }
+    /// ## Views
+    ///
+    /// This field doesn't have a description.
+    pub fn views(&self) -> Result<i64> {
+        self.inner.get("views")
+    }
+
+    /// ## Views
+    ///
+    /// This field doesn't have a description.
+    pub fn set_views(&self, new_value: i64) -> Result<()> {
+        self.inner.set("views", new_value)
+    }
+    /// ## Author id
+    ///
+    /// This field doesn't have a description.
+    pub fn author_id(&self) -> Result<i32> {
+        self.inner.get("authorId")
+    }
+
+    /// ## Author id
+    ///
+    /// This field doesn't have a description.
+    pub fn set_author_id(&self, new_value: i32) -> Result<()> {
+        self.inner.set("authorId", new_value)
+    }
+    /// ## Author
+    ///
+    /// This relation doesn't have a description.
+    pub async fn author(&self) -> Result<User> {
+        let object = match self.relations.get("author") {
+            Some(objects) => objects.into_iter().next(),
+            None => self.inner.force_get_relation_object("author").await?,
+        };
+        
+        Ok(User::from(object.unwrap()))
+        
+    }
+
+    /// ## Author
+    ///
+    /// This relation doesn't have a description.
+    pub async fn set_author(&self, author: User) {
+        self.relations.remove("author");
+        self.inner.force_set_relation_object("author", Some(author.inner.clone())).await
+    }
+}
+
+impl PostModel {
+    /// Build a typed find many query on post.
+    pub fn find_many(&self) -> PostFindManyQuery<'_> {
+        PostFindManyQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## Post find many query
+///
+/// A typed builder of `PostFindManyArgs`.
+pub struct PostFindManyQuery<'a> {
+    model: &'a PostModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> PostFindManyQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: PostWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: PostSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: PostInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: PostSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: PostWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Load `author` of the results in one batch.
+    pub fn with_author(mut self) -> Self {
+        self.preloads.push("author");
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Vec<Post>> {
+        let result = self.model.find_many_objects(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                "author" => self.model.load_author_for(result.as_slice()).await?,
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl PostModel {
+    /// Build a typed find unique query on post.
+    pub fn find_unique(&self) -> PostFindUniqueQuery<'_> {
+        PostFindUniqueQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## Post find unique query
+///
+/// A typed builder of `PostFindUniqueArgs`.
+pub struct PostFindUniqueQuery<'a> {
+    model: &'a PostModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> PostFindUniqueQuery<'a> {
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: PostInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: PostSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: PostWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Load `author` of the results in one batch.
+    pub fn with_author(mut self) -> Self {
+        self.preloads.push("author");
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Option<Post>> {
+        let result = self.model.find_unique_object(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                "author" => self.model.load_author_for(result.as_slice()).await?,
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl PostModel {
+    /// Build a typed find first query on post.
+    pub fn find_first(&self) -> PostFindFirstQuery<'_> {
+        PostFindFirstQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## Post find first query
+///
+/// A typed builder of `PostFindFirstArgs`.
+pub struct PostFindFirstQuery<'a> {
+    model: &'a PostModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> PostFindFirstQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: PostWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: PostSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: PostInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: PostSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: PostWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Load `author` of the results in one batch.
+    pub fn with_author(mut self) -> Self {
+        self.preloads.push("author");
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Option<Post>> {
+        let result = self.model.find_first_object(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                "author" => self.model.load_author_for(result.as_slice()).await?,
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl PostModel {
+    /// Build a typed count query on post.
+    pub fn count(&self) -> PostCountQuery<'_> {
+        PostCountQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Post count query
+///
+/// A typed builder of `PostCountArgs`.
+pub struct PostCountQuery<'a> {
+    model: &'a PostModel,
+    args: Value,
+}
+
+impl<'a> PostCountQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: PostWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: PostSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: PostCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: PostWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<usize> {
+        self.model.count_objects(self.args).await
+    }
+}
+
+impl PostModel {
+    /// Build a typed aggregate query on post.
+    pub fn aggregate_query(&self) -> PostAggregateQuery<'_> {
+        PostAggregateQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Post aggregate query
+///
+/// A typed builder of `PostAggregateArgs`.
+pub struct PostAggregateQuery<'a> {
+    model: &'a PostModel,
+    args: Value,
+}
+
+impl<'a> PostAggregateQuery<'a> {
+
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn avg(mut self, value: PostAvgAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_avg".to_owned(), value.into());
+        self
+    }
+
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn count(mut self, value: PostCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_count".to_owned(), value.into());
+        self
+    }
+
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn max(mut self, value: PostMaxAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_max".to_owned(), value.into());
+        self
+    }
+
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn min(mut self, value: PostMinAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_min".to_owned(), value.into());
+        self
+    }
+
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn sum(mut self, value: PostSumAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_sum".to_owned(), value.into());
+        self
+    }
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: PostWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: PostSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: PostWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<PostAggregateResult> {
+        self.model.aggregate(self.args).await
+    }
+}
+
+impl PostModel {
+    /// Build a typed group by query on post.
+    pub fn group_by_query(&self) -> PostGroupByQuery<'_> {
+        PostGroupByQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Post group by query
+///
+/// A typed builder of `PostGroupByArgs`.
+pub struct PostGroupByQuery<'a> {
+    model: &'a PostModel,
+    args: Value,
+}
+
+impl<'a> PostGroupByQuery<'a> {
+
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn avg(mut self, value: PostAvgAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_avg".to_owned(), value.into());
+        self
+    }
+
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn count(mut self, value: PostCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_count".to_owned(), value.into());
+        self
+    }
+
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn max(mut self, value: PostMaxAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_max".to_owned(), value.into());
+        self
+    }
+
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn min(mut self, value: PostMinAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_min".to_owned(), value.into());
+        self
+    }
+
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn sum(mut self, value: PostSumAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_sum".to_owned(), value.into());
+        self
+    }
+
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("by".to_owned(), value.into());
+        self
+    }
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: PostWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: PostSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn having(mut self, value: PostScalarWhereWithAggregatesInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("having".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: PostWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Vec<PostAggregateResult>> {
+        self.model.group_by(self.args).await
+    }
+}
+
+impl PostModel {
+    /// Build a typed create query on post.
+    pub fn create(&self) -> PostCreateQuery<'_> {
+        PostCreateQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Post create query
+///
+/// A typed builder of `PostCreateArgs`.
+pub struct PostCreateQuery<'a> {
+    model: &'a PostModel,
+    args: Value,
+}
+
+impl<'a> PostCreateQuery<'a> {
+
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn create(mut self, value: PostCreateInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("create".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: PostInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: PostSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Post> {
+        self.model.create_object_from_args(self.args).await
+    }
+}
+
+impl PostWhereInput {
+
+    /// Filter on id.
+    pub fn id() -> FieldFilter<Self, i32> {
+        FieldFilter::new("id")
+    }
+
+    /// Filter on title.
+    pub fn title() -> FieldFilter<Self, String> {
+        FieldFilter::new("title")
+    }
+
+    /// Filter on content.
+    pub fn content() -> FieldFilter<Self, String> {
+        FieldFilter::new("content")
+    }
+
+    /// Filter on tags.
+    pub fn tags() -> FieldFilter<Self, Vec<String>> {
+        FieldFilter::new("tags")
+    }
+
+    /// Filter on views.
+    pub fn views() -> FieldFilter<Self, i64> {
+        FieldFilter::new("views")
+    }
+
+    /// Filter on author id.
+    pub fn author_id() -> FieldFilter<Self, i32> {
+        FieldFilter::new("authorId")
+    }
+
+    /// Match both this and `other`.
+    pub fn and(self, other: Self) -> Result<Self> {
+        query::and(vec![self.into(), other.into()])
+    }
+
+    /// Match either this or `other`.
+    pub fn or(self, other: Self) -> Result<Self> {
+        query::or(vec![self.into(), other.into()])
+    }
+}
+
+impl From<Post> for model::Object {
+    fn from(value: Post) -> Self {
+        value.inner.clone()
+    }
+}
+
+impl From<model::Object> for Post {
+    fn from(value: model::Object) -> Self {
+        Self { inner: value, relations: RelationCache::default() }
+    }
+}
+
+impl From<Post> for Value {
+    fn from(value: Post) -> Self {
+        Value::ModelObject(value.inner.clone())
+    }
+}
+
+impl AsInterface for Post {
+    fn from_value(value: Value) -> Result<Self> {
+        let model_object: model::Object = value.try_into()?;
+        Ok(Self::from(model_object))
+    }
+}
+
+impl TryFrom<Value> for Post {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        let model_object: model::Object = value.try_into()?;
+        Ok(Self::from(model_object))
+    }
+}
+
+impl Debug for Post {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
+}
+
+impl Display for Post {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+
+impl ExtractFromPipelineCtx for Post {
+    fn extract(ctx: &pipeline::Ctx) -> Self {
+        Post::from(ctx.object().clone())
+    }
+}
+
+/// Serializes the loaded fields of this post. Model objects are bound to a
+/// connection, so deserialize into `PostResult` instead.
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for Post {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut map = indexmap::IndexMap::new();
+        map.insert("id".to_owned(), self.inner.get::<Value, _>("id").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("title".to_owned(), self.inner.get::<Value, _>("title").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("content".to_owned(), self.inner.get::<Value, _>("content").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("tags".to_owned(), self.inner.get::<Value, _>("tags").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("views".to_owned(), self.inner.get::<Value, _>("views").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("authorId".to_owned(), self.inner.get::<Value, _>("authorId").map_err(<S::Error as serde::ser::Error>::custom)?);
+        serialize_teon(&Value::Dictionary(map), serializer)
+    }
+}/// ## User
+///
+/// User A user of the blog.
+pub struct UserModel {
+    ctx: model::Ctx,
+}
+
+impl UserModel {
+    /// Find many user objects.
+    pub async fn find_many_objects(&self, query: impl Borrow<Value>) -> Result<Vec<User>> {
+        Ok(self.ctx.find_many(query.borrow()).await?)
+    }
+
+    /// Find a unique user object.
+    pub async fn find_unique_object(&self, query: impl Borrow<Value>) -> Result<Option<User>> {
+        Ok(self.ctx.find_unique(query.borrow()).await?)
+    }
+
+    /// Find a user object.
+    pub async fn find_first_object(&self, query: impl Borrow<Value>) -> Result<Option<User>> {
+        Ok(self.ctx.find_first(query.borrow()).await?)
+    }
+
+    /// Create a new user object.
+    pub async fn create_object(&self, values: impl Borrow<Value>) -> Result<User> {
+        Ok(self.ctx.create_object::<User>(values.borrow()).await?)
+    }
+
+    /// Create an empty user object.
+    pub async fn create_default_object(&self) -> Result<User> {
+        Ok(self.ctx.create_object::<User>(teon!({}).borrow()).await?)
+    }
+
+    /// Create and save a user object from the `create` input of `args`.
+    pub async fn create_object_from_args(&self, args: impl Borrow<Value>) -> Result<User> {
+        let values = args.borrow().as_dictionary().and_then(|args| args.get("create")).cloned().unwrap_or_else(|| teon!({}));
+        let object = self.create_object(values).await?;
+        object.save().await?;
+        Ok(object)
+    }
+
+    /// Count objects on user.
+    pub async fn count_objects(&self, query: impl Borrow<Value>) -> Result<usize> {
+        Ok(self.ctx.count_objects(query.borrow()).await?)
+    }
+
+    /// Count fields on user.
+    pub async fn count_fields(&self, query: impl Borrow<Value>) -> Result<UserCountAggregateResult> {
+        Ok(UserCountAggregateResult::from_value(self.ctx.count_fields(query.borrow()).await?)?)
+    }
+
+    /// Aggregate on user.
+    pub async fn aggregate(&self, query: impl Borrow<Value>) -> Result<UserAggregateResult> {
+        Ok(UserAggregateResult::from_value(self.ctx.aggregate(query.borrow()).await?)?)
+    }
+
+    /// Group by on user.
+    pub async fn group_by(&self, query: impl Borrow<Value>) -> Result<Vec<UserAggregateResult>> {
+        let values: Vec<Value> = self.ctx.group_by(query.borrow()).await?;
+        let mut result = vec![];
+        for value in values.into_iter() {
+            result.push(UserAggregateResult::from_value(value)?);
+        }
+        Ok(result)
+    }
+
+    
+    /// Run a custom SQL clause.
+    pub async fn sql<T, E>(&self, sql: &str) -> Result<Vec<T>> where T: TryFrom<Value, Error=E>, Error: From<E> {
+        self.ctx.sql(sql).await
+    }
+    
+
+    /// Load `posts` of every object in `objects` at once. Later calls to
+    /// `posts` on these objects read from the loaded results.
+    pub async fn load_posts_for(&self, objects: &[User]) -> Result<()> {
+        let owners = objects.iter().map(|o| (&o.relations, &o.inner)).collect();
+        let related = self.ctx.transaction_ctx().model_ctx_for_model_at_path(&vec!["Post".to_owned()]).unwrap();
+        relations::batch_load(owners, "posts", &related, &["id", ], &["authorId", ]).await
+    }
+}
+
+#[derive(Clone)]
+pub struct User {
+    inner: model::Object,
+    relations: RelationCache,
+}
+
+impl User {
+
+    /// Whether this user is new.
+    pub fn is_new(&self) -> bool {
+        self.inner.is_new()
+    }
+
+    /// Whether this user is modified.
+    pub fn is_modified(&self) -> bool {
+        self.inner.is_modified()
+    }
+
+    /// Set new values to a user. Validations and transformations are
+    /// triggered.
+    pub async fn set(&self, values: impl AsRef<Value>) -> Result<()> {
+        self.inner.set_teon(values.as_ref()).await
+    }
+
+    /// Update with new values to a user. Validations and transformations are
+    /// not triggered.
+    pub async fn update(&self, values: impl AsRef<Value>) -> Result<()> {
+        self.inner.update_teon(values.as_ref()).await
+    }
+
+    /// Save this user.
+    pub async fn save(&self) -> Result<()> {
+        self.inner.save().await
+    }
+
+    /// Delete this user.
+    pub async fn delete(&self) -> Result<()> {
+        self.inner.delete().await
+    }
+
+    /// Convert this user object to teon.
+    pub async fn to_teon(&self) -> Result<Value> {
+        self.inner.to_teon().await
+    }
+    /// ## Id
+    ///
+    /// This field doesn't have a description.
+    pub fn id(&self) -> Result<i32> {
+        self.inner.get("id")
+    }
+
+    /// ## Id
+    ///
+    /// This field doesn't have a description.
+    pub fn set_id(&self, new_value: i32) -> Result<()> {
+        self.inner.set("id", new_value)
+    }
+    /// ## Email
+    ///
+    /// This field doesn't have a description.
+    pub fn email(&self) -> Result<String> {
+        self.inner.get("email")
+    }
+
+    /// ## Email
+    ///
+    /// This field doesn't have a description.
+    pub fn set_email(&self, new_value: String) -> Result<()> {
+        self.inner.set("email", new_value)
+    }
+    /// ## Name
+    ///
+    /// This field doesn't have a description.
+    pub fn name(&self) -> Result<Option<String>> {
+        self.inner.get("name")
+    }
+
+    /// ## Name
+    ///
+    /// This field doesn't have a description.
+    pub fn set_name(&self, new_value: Option<String>) -> Result<()> {
+        self.inner.set("name", new_value)
+    }
+    /// ## Sex
+    ///
+    /// This field doesn't have a description.
+    pub fn sex(&self) -> Result<Option<Sex>> {
+        let value: Value = self.inner.get("sex").unwrap();
+        Ok(match value {
+            Value::Null => None,
+            _ => Some(value.try_into()?),
+        })
+    }
+
+    /// ## Sex
+    ///
+    /// This field doesn't have a description.
+    pub fn set_sex(&self, new_value: Option<Sex>) -> Result<()> {
+        self.inner.set("sex", match new_value {
+            None => Value::Null,
+            Some(new_value) => Value::from(new_value),
+        })
+    }
+    /// ## Birthday
+    ///
+    /// This field doesn't have a description.
+    pub fn birthday(&self) -> Result<Option<NaiveDate>> {
+        self.inner.get("birthday")
+    }
+
+    /// ## Birthday
+    ///
+    /// This field doesn't have a description.
+    pub fn set_birthday(&self, new_value: Option<NaiveDate>) -> Result<()> {
+        self.inner.set("birthday", new_value)
+    }
+    /// ## Balance
+    ///
+    /// This field doesn't have a description.
+    pub fn balance(&self) -> Result<Option<BigDecimal>> {
+        self.inner.get("balance")
+    }
+
+    /// ## Balance
+    ///
+    /// This field doesn't have a description.
+    pub fn set_balance(&self, new_value: Option<BigDecimal>) -> Result<()> {
+        self.inner.set("balance", new_value)
+    }
+    /// ## Created at
+    ///
+    /// This field doesn't have a description.
+    pub fn created_at(&self) -> Result<DateTime<Utc>> {
+        self.inner.get("createdAt")
+    }
+
+    /// ## Created at
+    ///
+    /// This field doesn't have a description.
+    pub fn set_created_at(&self, new_value: DateTime<Utc>) -> Result<()> {
+        self.inner.set("createdAt", new_value)
+    }
+    /// ## Posts
+    ///
+    /// This relation doesn't have a description.
+    pub async fn posts(&self, find_many_input: impl AsRef<Value>) -> Result<Vec<Post>> {
+        let is_plain = find_many_input.as_ref().as_dictionary().map_or(true, |d| d.is_empty());
+        let objects = match self.relations.get("posts") {
+            Some(objects) if is_plain => objects,
+            _ => self.inner.force_get_relation_objects("posts", find_many_input.as_ref()).await?,
+        };
+        Ok(objects.iter().map(|o| Post::from(o.clone())).collect())
+    }
+
+    /// ## Posts
+    ///
+    /// This relation doesn't have a description.
+    pub async fn set_posts(&self, posts: Vec<Post>) {
+        let objects = posts.iter().map(|o| o.inner.clone()).collect();
+        self.relations.remove("posts");
+        self.inner.force_set_relation_objects("posts", objects).await
+    }
+
+    /// ## Posts
+    ///
+    /// This relation doesn't have a description.
+    pub async fn add_to_posts(&self, posts: Vec<Post>) {
+        let objects = posts.iter().map(|o| o.inner.clone()).collect();
+        self.relations.remove("posts");
+        self.inner.force_add_relation_objects("posts", objects).await
+    }
+
+    /// ## Posts
+    ///
+    /// This relation doesn't have a description.
+    pub async fn remove_from_posts(&self, posts: Vec<Post>) {
+        let objects = posts.iter().map(|o| o.inner.clone()).collect();
+        self.relations.remove("posts");
+        self.inner.force_remove_relation_objects("posts", objects).await
+    }
+    /// ## Display name
+    ///
+    /// This property doesn't have a description.
+    pub async fn display_name(&self) -> Result<Option<String>> {
+        self.inner.get_property("displayName").await
+    }
+}
+
+impl UserModel {
+    /// Build a typed find many query on user.
+    pub fn find_many(&self) -> UserFindManyQuery<'_> {
+        UserFindManyQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## User find many query
+///
+/// A typed builder of `UserFindManyArgs`.
+pub struct UserFindManyQuery<'a> {
+    model: &'a UserModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> UserFindManyQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: UserWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: UserSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: UserInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: UserSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: UserWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Load `posts` of the results in one batch.
+    pub fn with_posts(mut self) -> Self {
+        self.preloads.push("posts");
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Vec<User>> {
+        let result = self.model.find_many_objects(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                "posts" => self.model.load_posts_for(result.as_slice()).await?,
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl UserModel {
+    /// Build a typed find unique query on user.
+    pub fn find_unique(&self) -> UserFindUniqueQuery<'_> {
+        UserFindUniqueQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## User find unique query
+///
+/// A typed builder of `UserFindUniqueArgs`.
+pub struct UserFindUniqueQuery<'a> {
+    model: &'a UserModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> UserFindUniqueQuery<'a> {
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: UserInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: UserSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: UserWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Load `posts` of the results in one batch.
+    pub fn with_posts(mut self) -> Self {
+        self.preloads.push("posts");
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Option<User>> {
+        let result = self.model.find_unique_object(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                "posts" => self.model.load_posts_for(result.as_slice()).await?,
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl UserModel {
+    /// Build a typed find first query on user.
+    pub fn find_first(&self) -> UserFindFirstQuery<'_> {
+        UserFindFirstQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## User find first query
+///
+/// A typed builder of `UserFindFirstArgs`.
+pub struct UserFindFirstQuery<'a> {
+    model: &'a UserModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> UserFindFirstQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: UserWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: UserSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: UserInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: UserSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: UserWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Load `posts` of the results in one batch.
+    pub fn with_posts(mut self) -> Self {
+        self.preloads.push("posts");
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Option<User>> {
+        let result = self.model.find_first_object(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                "posts" => self.model.load_posts_for(result.as_slice()).await?,
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl UserModel {
+    /// Build a typed count query on user.
+    pub fn count(&self) -> UserCountQuery<'_> {
+        UserCountQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## User count query
+///
+/// A typed builder of `UserCountArgs`.
+pub struct UserCountQuery<'a> {
+    model: &'a UserModel,
+    args: Value,
+}
+
+impl<'a> UserCountQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: UserWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: UserSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: UserCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: UserWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<usize> {
+        self.model.count_objects(self.args).await
+    }
+}
+
+impl UserModel {
+    /// Build a typed aggregate query on user.
+    pub fn aggregate_query(&self) -> UserAggregateQuery<'_> {
+        UserAggregateQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## User aggregate query
+///
+/// A typed builder of `UserAggregateArgs`.
+pub struct UserAggregateQuery<'a> {
+    model: &'a UserModel,
+    args: Value,
+}
+
+impl<'a> UserAggregateQuery<'a> {
+
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn avg(mut self, value: UserAvgAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_avg".to_owned(), value.into());
+        self
+    }
+
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn count(mut self, value: UserCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_count".to_owned(), value.into());
+        self
+    }
+
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn max(mut self, value: UserMaxAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_max".to_owned(), value.into());
+        self
+    }
+
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn min(mut self, value: UserMinAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_min".to_owned(), value.into());
+        self
+    }
+
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn sum(mut self, value: UserSumAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_sum".to_owned(), value.into());
+        self
+    }
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: UserWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: UserSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: UserWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<UserAggregateResult> {
+        self.model.aggregate(self.args).await
+    }
+}
+
+impl UserModel {
+    /// Build a typed group by query on user.
+    pub fn group_by_query(&self) -> UserGroupByQuery<'_> {
+        UserGroupByQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## User group by query
+///
+/// A typed builder of `UserGroupByArgs`.
+pub struct UserGroupByQuery<'a> {
+    model: &'a UserModel,
+    args: Value,
+}
+
+impl<'a> UserGroupByQuery<'a> {
+
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn avg(mut self, value: UserAvgAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_avg".to_owned(), value.into());
+        self
+    }
+
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn count(mut self, value: UserCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_count".to_owned(), value.into());
+        self
+    }
+
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn max(mut self, value: UserMaxAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_max".to_owned(), value.into());
+        self
+    }
+
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn min(mut self, value: UserMinAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_min".to_owned(), value.into());
+        self
+    }
+
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn sum(mut self, value: UserSumAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_sum".to_owned(), value.into());
+        self
+    }
+
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("by".to_owned(), value.into());
+        self
+    }
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: UserWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: UserSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn having(mut self, value: UserScalarWhereWithAggregatesInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("having".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: UserWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Vec<UserAggregateResult>> {
+        self.model.group_by(self.args).await
+    }
+}
+
+impl UserModel {
+    /// Build a typed create query on user.
+    pub fn create(&self) -> UserCreateQuery<'_> {
+        UserCreateQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## User create query
+///
+/// A typed builder of `UserCreateArgs`.
+pub struct UserCreateQuery<'a> {
+    model: &'a UserModel,
+    args: Value,
+}
+
+impl<'a> UserCreateQuery<'a> {
+
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn create(mut self, value: UserCreateInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("create".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: UserInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: UserSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<User> {
+        self.model.create_object_from_args(self.args).await
+    }
+}
+
+impl UserWhereInput {
+
+    /// Filter on id.
+    pub fn id() -> FieldFilter<Self, i32> {
+        FieldFilter::new("id")
+    }
+
+    /// Filter on email.
+    pub fn email() -> FieldFilter<Self, String> {
+        FieldFilter::new("email")
+    }
+
+    /// Filter on name.
+    pub fn name() -> FieldFilter<Self, String> {
+        FieldFilter::new("name")
+    }
+
+    /// Filter on sex.
+    pub fn sex() -> FieldFilter<Self, Sex> {
+        FieldFilter::new("sex")
+    }
+
+    /// Filter on birthday.
+    pub fn birthday() -> FieldFilter<Self, NaiveDate> {
+        FieldFilter::new("birthday")
+    }
+
+    /// Filter on balance.
+    pub fn balance() -> FieldFilter<Self, BigDecimal> {
+        FieldFilter::new("balance")
+    }
+
+    /// Filter on created at.
+    pub fn created_at() -> FieldFilter<Self, DateTime<Utc>> {
+        FieldFilter::new("createdAt")
+    }
+
+    /// Match both this and `other`.
+    pub fn and(self, other: Self) -> Result<Self> {
+        query::and(vec![self.into(), other.into()])
+    }
+
+    /// Match either this or `other`.
+    pub fn or(self, other: Self) -> Result<Self> {
+        query::or(vec![self.into(), other.into()])
+    }
+}
+
+impl From<User> for model::Object {
+    fn from(value: User) -> Self {
+        value.inner.clone()
+    }
+}
+
+impl From<model::Object> for User {
+    fn from(value: model::Object) -> Self {
+        Self { inner: value, relations: RelationCache::default() }
+    }
+}
+
+impl From<User> for Value {
+    fn from(value: User) -> Self {
+        Value::ModelObject(value.inner.clone())
+    }
+}
+
+impl AsInterface for User {
+    fn from_value(value: Value) -> Result<Self> {
+        let model_object: model::Object = value.try_into()?;
+        Ok(Self::from(model_object))
+    }
+}
+
+impl TryFrom<Value> for User {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        let model_object: model::Object = value.try_into()?;
+        Ok(Self::from(model_object))
+    }
+}
+
+impl Debug for User {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
+}
+
+impl Display for User {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+
+impl ExtractFromPipelineCtx for User {
+    fn extract(ctx: &pipeline::Ctx) -> Self {
+        User::from(ctx.object().clone())
+    }
+}
+
+/// Serializes the loaded fields of this user. Model objects are bound to a
+/// connection, so deserialize into `UserResult` instead.
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for User {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut map = indexmap::IndexMap::new();
+        map.insert("id".to_owned(), self.inner.get::<Value, _>("id").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("email".to_owned(), self.inner.get::<Value, _>("email").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("name".to_owned(), self.inner.get::<Value, _>("name").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("sex".to_owned(), self.inner.get::<Value, _>("sex").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("birthday".to_owned(), self.inner.get::<Value, _>("birthday").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("balance".to_owned(), self.inner.get::<Value, _>("balance").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("createdAt".to_owned(), self.inner.get::<Value, _>("createdAt").map_err(<S::Error as serde::ser::Error>::custom)?);
+        serialize_teon(&Value::Dictionary(map), serializer)
+    }
+}
+
+pub trait KeywordTrait: Interface {
+    /// ## Value
+    ///
+    /// This interface field doesn't have a description.
+    fn value(&self) -> &Value {
+        Value::from_value_ref(self.inner().get("value").unwrap()).unwrap()
+    }
+    /// ## Value
+    ///
+    /// This interface field doesn't have a description.
+    fn set_value(&mut self, new_value: Value) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("value".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct Keyword {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for Keyword {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &Keyword {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for Keyword {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl KeywordTrait for Keyword { }
+
+impl AsInterface for Keyword {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<Keyword> for Value {
+    fn from(value: Keyword) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for Keyword {
+
+    fn from_value_ref(value: &Value) -> Result<&Keyword> {
+        Ok(unsafe {
+            &*(value as *const Value as *const Keyword)
+        })
+    }
+}
+
+impl ExtractFromRequest for Keyword {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for Keyword {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for Keyword {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PageTrait<T>: Interface where T: Into<Value> + AsInterface + AsInterfaceRef {
+    /// ## Items
+    ///
+    /// This interface field doesn't have a description.
+    fn items(&self) -> Vec<&T> {
+        Vec::<&T>::from_value_ref_vec(self.inner().get("items").unwrap()).unwrap()
+    }
+    /// ## Items
+    ///
+    /// This interface field doesn't have a description.
+    fn set_items(&mut self, new_value: Vec<T>) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("items".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Total
+    ///
+    /// This interface field doesn't have a description.
+    fn total(&self) -> &i64 {
+        i64::from_value_ref(self.inner().get("total").unwrap()).unwrap()
+    }
+    /// ## Total
+    ///
+    /// This interface field doesn't have a description.
+    fn set_total(&mut self, new_value: i64) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("total".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct Page<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    inner: Value,
+    phantom_data: PhantomData<T>,
+}
+
+impl<T> Borrow<Value> for Page<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl<T> Borrow<Value> for &Page<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl<T> Interface for Page<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl<T> PageTrait<T> for Page<T> where T: Into<Value> + AsInterface + AsInterfaceRef { }
+
+impl<T> AsInterface for Page<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl<T> From<Page<T>> for Value where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn from(value: Page<T>) -> Self {
+        value.inner
+    }
+}
+
+impl<T> AsInterfaceRef for Page<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+
+    fn from_value_ref(value: &Value) -> Result<&Page<T>> {
+        Ok(unsafe {
+            &*(value as *const Value as *const Page<T>)
+        })
+    }
+}
+
+impl<T> ExtractFromRequest for Page<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<T> serde::Serialize for Page<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de, T> serde::Deserialize<'de> for Page<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostPathArgumentsTrait: Interface {
+    /// ## Id
+    ///
+    /// This interface field doesn't have a description.
+    fn id(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("id").unwrap()).unwrap()
+    }
+    /// ## Id
+    ///
+    /// This interface field doesn't have a description.
+    fn set_id(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostPathArguments {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostPathArguments {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostPathArguments {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostPathArguments {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostPathArgumentsTrait for PostPathArguments { }
+
+impl AsInterface for PostPathArguments {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostPathArguments> for Value {
+    fn from(value: PostPathArguments) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostPathArguments {
+
+    fn from_value_ref(value: &Value) -> Result<&PostPathArguments> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostPathArguments)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostPathArguments {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostPathArguments {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostPathArguments {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait SearchInputTrait: Interface {
+    /// ## Keyword
+    ///
+    /// This interface field doesn't have a description.
+    fn keyword(&self) -> &String {
+        String::from_value_ref(self.inner().get("keyword").unwrap()).unwrap()
+    }
+    /// ## Keyword
+    ///
+    /// This interface field doesn't have a description.
+    fn set_keyword(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("keyword".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Sex
+    ///
+    /// This interface field doesn't have a description.
+    fn sex(&self) -> Option<&Sex> {
+        Some(Sex::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This interface field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Sex>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This interface field doesn't have a description.
+    fn tags(&self) -> Option<Vec<&String>> {
+        Some(Vec::<&String>::from_value_ref_vec(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This interface field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<Vec<String>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct SearchInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for SearchInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &SearchInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for SearchInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl SearchInputTrait for SearchInput { }
+
+impl AsInterface for SearchInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<SearchInput> for Value {
+    fn from(value: SearchInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for SearchInput {
+
+    fn from_value_ref(value: &Value) -> Result<&SearchInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const SearchInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for SearchInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for SearchInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for SearchInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UploadInputTrait: Interface {
+    /// ## Title
+    ///
+    /// This interface field doesn't have a description.
+    fn title(&self) -> &String {
+        String::from_value_ref(self.inner().get("title").unwrap()).unwrap()
+    }
+    /// ## Title
+    ///
+    /// This interface field doesn't have a description.
+    fn set_title(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Caption
+    ///
+    /// This interface field doesn't have a description.
+    fn caption(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("caption")?).unwrap())
+    }
+    /// ## Caption
+    ///
+    /// This interface field doesn't have a description.
+    fn set_caption(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("caption".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("caption");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UploadInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UploadInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UploadInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UploadInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UploadInputTrait for UploadInput { }
+
+impl AsInterface for UploadInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UploadInput> for Value {
+    fn from(value: UploadInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UploadInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UploadInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UploadInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UploadInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UploadInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UploadInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UploadOutputTrait: Interface {
+    /// ## Path
+    ///
+    /// This interface field doesn't have a description.
+    fn path(&self) -> &String {
+        String::from_value_ref(self.inner().get("path").unwrap()).unwrap()
+    }
+    /// ## Path
+    ///
+    /// This interface field doesn't have a description.
+    fn set_path(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("path".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UploadOutput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UploadOutput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UploadOutput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UploadOutput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UploadOutputTrait for UploadOutput { }
+
+impl AsInterface for UploadOutput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UploadOutput> for Value {
+    fn from(value: UploadOutput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UploadOutput {
+
+    fn from_value_ref(value: &Value) -> Result<&UploadOutput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UploadOutput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UploadOutput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UploadOutput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UploadOutput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostAggregateArgsTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&PostAvgAggregateInputType> {
+        Some(PostAvgAggregateInputType::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<PostAvgAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&PostCountAggregateInputType> {
+        Some(PostCountAggregateInputType::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<PostCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&PostMaxAggregateInputType> {
+        Some(PostMaxAggregateInputType::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<PostMaxAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&PostMinAggregateInputType> {
+        Some(PostMinAggregateInputType::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<PostMinAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&PostSumAggregateInputType> {
+        Some(PostSumAggregateInputType::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<PostSumAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&PostSerializableScalarFields> {
+        Some(PostSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<PostSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostAggregateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostAggregateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostAggregateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostAggregateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostAggregateArgsTrait for PostAggregateArgs { }
+
+impl AsInterface for PostAggregateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostAggregateArgs> for Value {
+    fn from(value: PostAggregateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostAggregateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostAggregateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostAggregateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostAggregateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostAggregateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostAggregateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostAggregateResultTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&PostAvgAggregateResult> {
+        Some(PostAvgAggregateResult::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<PostAvgAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&PostCountAggregateResult> {
+        Some(PostCountAggregateResult::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<PostCountAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&PostMaxAggregateResult> {
+        Some(PostMaxAggregateResult::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<PostMaxAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&PostMinAggregateResult> {
+        Some(PostMinAggregateResult::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<PostMinAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&PostSumAggregateResult> {
+        Some(PostSumAggregateResult::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<PostSumAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostAggregateResultTrait for PostAggregateResult { }
+
+impl AsInterface for PostAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostAggregateResult> for Value {
+    fn from(value: PostAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&PostAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostArgsTrait for PostArgs { }
+
+impl AsInterface for PostArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostArgs> for Value {
+    fn from(value: PostArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostAvgAggregateInputTypeTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostAvgAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostAvgAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostAvgAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostAvgAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostAvgAggregateInputTypeTrait for PostAvgAggregateInputType { }
+
+impl AsInterface for PostAvgAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostAvgAggregateInputType> for Value {
+    fn from(value: PostAvgAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostAvgAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&PostAvgAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostAvgAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostAvgAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostAvgAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostAvgAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostAvgAggregateResultTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&f64> {
+        Some(f64::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<f64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&f64> {
+        Some(f64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<f64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&f64> {
+        Some(f64::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<f64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostAvgAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostAvgAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostAvgAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostAvgAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostAvgAggregateResultTrait for PostAvgAggregateResult { }
+
+impl AsInterface for PostAvgAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostAvgAggregateResult> for Value {
+    fn from(value: PostAvgAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostAvgAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&PostAvgAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostAvgAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostAvgAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostAvgAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostAvgAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostConnectOrCreateInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &PostCreateInput {
+        PostCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: PostCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostConnectOrCreateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostConnectOrCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostConnectOrCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostConnectOrCreateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostConnectOrCreateInputTrait for PostConnectOrCreateInput { }
+
+impl AsInterface for PostConnectOrCreateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostConnectOrCreateInput> for Value {
+    fn from(value: PostConnectOrCreateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostConnectOrCreateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostConnectOrCreateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostConnectOrCreateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostConnectOrCreateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostConnectOrCreateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostConnectOrCreateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostConnectOrCreateWithoutAuthorInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &PostCreateInput {
+        PostCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: PostCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostConnectOrCreateWithoutAuthorInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostConnectOrCreateWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostConnectOrCreateWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostConnectOrCreateWithoutAuthorInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostConnectOrCreateWithoutAuthorInputTrait for PostConnectOrCreateWithoutAuthorInput { }
+
+impl AsInterface for PostConnectOrCreateWithoutAuthorInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostConnectOrCreateWithoutAuthorInput> for Value {
+    fn from(value: PostConnectOrCreateWithoutAuthorInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostConnectOrCreateWithoutAuthorInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostConnectOrCreateWithoutAuthorInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostConnectOrCreateWithoutAuthorInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostConnectOrCreateWithoutAuthorInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostConnectOrCreateWithoutAuthorInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostConnectOrCreateWithoutAuthorInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCopyArgsTrait: Interface {
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn copy(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("copy").unwrap()).unwrap()
+    }
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_copy(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("copy".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCopyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCopyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCopyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCopyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCopyArgsTrait for PostCopyArgs { }
+
+impl AsInterface for PostCopyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCopyArgs> for Value {
+    fn from(value: PostCopyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCopyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCopyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCopyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCopyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCopyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCopyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCopyManyArgsTrait: Interface {
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn copy(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("copy").unwrap()).unwrap()
+    }
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_copy(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("copy".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereInput {
+        PostWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCopyManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCopyManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCopyManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCopyManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCopyManyArgsTrait for PostCopyManyArgs { }
+
+impl AsInterface for PostCopyManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCopyManyArgs> for Value {
+    fn from(value: PostCopyManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCopyManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCopyManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCopyManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCopyManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCopyManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCopyManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCountAggregateInputTypeTrait: Interface {
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn all(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("_all")?).unwrap())
+    }
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_all(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_all".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_all");
+            },
+        }
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCountAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCountAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCountAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCountAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCountAggregateInputTypeTrait for PostCountAggregateInputType { }
+
+impl AsInterface for PostCountAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCountAggregateInputType> for Value {
+    fn from(value: PostCountAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCountAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCountAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCountAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCountAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCountAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCountAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCountAggregateResultTrait: Interface {
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn all(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("_all")?).unwrap())
+    }
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_all(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_all".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_all");
+            },
+        }
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCountAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCountAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCountAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCountAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCountAggregateResultTrait for PostCountAggregateResult { }
+
+impl AsInterface for PostCountAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCountAggregateResult> for Value {
+    fn from(value: PostCountAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCountAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCountAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCountAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCountAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCountAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCountAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCountArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&PostSerializableScalarFields> {
+        Some(PostSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<PostSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostCountAggregateInputType> {
+        Some(PostCountAggregateInputType::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCountArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCountArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCountArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCountArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCountArgsTrait for PostCountArgs { }
+
+impl AsInterface for PostCountArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCountArgs> for Value {
+    fn from(value: PostCountArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCountArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCountArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCountArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCountArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCountArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCountArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCreateArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &PostCreateInput {
+        PostCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: PostCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCreateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCreateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCreateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCreateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCreateArgsTrait for PostCreateArgs { }
+
+impl AsInterface for PostCreateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCreateArgs> for Value {
+    fn from(value: PostCreateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCreateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCreateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCreateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCreateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCreateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCreateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCreateInputTrait: Interface {
+    /// ## Author
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author(&self) -> Option<&UserCreateNestedOneWithoutPostsInput> {
+        Some(UserCreateNestedOneWithoutPostsInput::from_value_ref(self.inner().get("author")?).unwrap())
+    }
+    /// ## Author
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author(&mut self, new_value: Option<UserCreateNestedOneWithoutPostsInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("author".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("author");
+            },
+        }
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Vec<&String> {
+        Vec::<&String>::from_value_ref_vec(self.inner().get("tags").unwrap()).unwrap()
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Vec<String>) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> &String {
+        String::from_value_ref(self.inner().get("title").unwrap()).unwrap()
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> &i64 {
+        i64::from_value_ref(self.inner().get("views").unwrap()).unwrap()
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: i64) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCreateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCreateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCreateInputTrait for PostCreateInput { }
+
+impl AsInterface for PostCreateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCreateInput> for Value {
+    fn from(value: PostCreateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCreateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCreateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCreateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCreateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCreateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCreateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCreateManyArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &Value {
+        Value::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Value) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCreateManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCreateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCreateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCreateManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCreateManyArgsTrait for PostCreateManyArgs { }
+
+impl AsInterface for PostCreateManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCreateManyArgs> for Value {
+    fn from(value: PostCreateManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCreateManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCreateManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCreateManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCreateManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCreateManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCreateManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCreateNestedManyInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCreateNestedManyInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCreateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCreateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCreateNestedManyInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCreateNestedManyInputTrait for PostCreateNestedManyInput { }
+
+impl AsInterface for PostCreateNestedManyInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCreateNestedManyInput> for Value {
+    fn from(value: PostCreateNestedManyInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCreateNestedManyInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCreateNestedManyInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCreateNestedManyInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCreateNestedManyInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCreateNestedManyInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCreateNestedManyInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCreateNestedManyWithoutAuthorInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCreateNestedManyWithoutAuthorInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCreateNestedManyWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCreateNestedManyWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCreateNestedManyWithoutAuthorInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCreateNestedManyWithoutAuthorInputTrait for PostCreateNestedManyWithoutAuthorInput { }
+
+impl AsInterface for PostCreateNestedManyWithoutAuthorInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCreateNestedManyWithoutAuthorInput> for Value {
+    fn from(value: PostCreateNestedManyWithoutAuthorInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCreateNestedManyWithoutAuthorInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCreateNestedManyWithoutAuthorInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCreateNestedManyWithoutAuthorInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCreateNestedManyWithoutAuthorInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCreateNestedManyWithoutAuthorInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCreateNestedManyWithoutAuthorInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCreateNestedOneInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&PostConnectOrCreateInput> {
+        Some(PostConnectOrCreateInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<PostConnectOrCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&PostCreateInput> {
+        Some(PostCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<PostCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCreateNestedOneInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCreateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCreateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCreateNestedOneInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCreateNestedOneInputTrait for PostCreateNestedOneInput { }
+
+impl AsInterface for PostCreateNestedOneInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCreateNestedOneInput> for Value {
+    fn from(value: PostCreateNestedOneInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCreateNestedOneInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCreateNestedOneInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCreateNestedOneInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCreateNestedOneInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCreateNestedOneInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCreateNestedOneInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCreateNestedOneWithoutAuthorInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&PostConnectOrCreateWithoutAuthorInput> {
+        Some(PostConnectOrCreateWithoutAuthorInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<PostConnectOrCreateWithoutAuthorInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&PostCreateInput> {
+        Some(PostCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<PostCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCreateNestedOneWithoutAuthorInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCreateNestedOneWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCreateNestedOneWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCreateNestedOneWithoutAuthorInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCreateNestedOneWithoutAuthorInputTrait for PostCreateNestedOneWithoutAuthorInput { }
+
+impl AsInterface for PostCreateNestedOneWithoutAuthorInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCreateNestedOneWithoutAuthorInput> for Value {
+    fn from(value: PostCreateNestedOneWithoutAuthorInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCreateNestedOneWithoutAuthorInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCreateNestedOneWithoutAuthorInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCreateNestedOneWithoutAuthorInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCreateNestedOneWithoutAuthorInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCreateNestedOneWithoutAuthorInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCreateNestedOneWithoutAuthorInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostCreateWithoutAuthorInputTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Vec<&String> {
+        Vec::<&String>::from_value_ref_vec(self.inner().get("tags").unwrap()).unwrap()
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Vec<String>) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> &String {
+        String::from_value_ref(self.inner().get("title").unwrap()).unwrap()
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> &i64 {
+        i64::from_value_ref(self.inner().get("views").unwrap()).unwrap()
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: i64) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostCreateWithoutAuthorInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostCreateWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostCreateWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostCreateWithoutAuthorInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostCreateWithoutAuthorInputTrait for PostCreateWithoutAuthorInput { }
+
+impl AsInterface for PostCreateWithoutAuthorInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostCreateWithoutAuthorInput> for Value {
+    fn from(value: PostCreateWithoutAuthorInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostCreateWithoutAuthorInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostCreateWithoutAuthorInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostCreateWithoutAuthorInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostCreateWithoutAuthorInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostCreateWithoutAuthorInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostCreateWithoutAuthorInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostDeleteArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostDeleteArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostDeleteArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostDeleteArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostDeleteArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostDeleteArgsTrait for PostDeleteArgs { }
+
+impl AsInterface for PostDeleteArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostDeleteArgs> for Value {
+    fn from(value: PostDeleteArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostDeleteArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostDeleteArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostDeleteArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostDeleteArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostDeleteArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostDeleteArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostDeleteManyArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereInput {
+        PostWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostDeleteManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostDeleteManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostDeleteManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostDeleteManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostDeleteManyArgsTrait for PostDeleteManyArgs { }
+
+impl AsInterface for PostDeleteManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostDeleteManyArgs> for Value {
+    fn from(value: PostDeleteManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostDeleteManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostDeleteManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostDeleteManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostDeleteManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostDeleteManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostDeleteManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostFindFirstArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&PostSerializableScalarFields> {
+        Some(PostSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<PostSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostFindFirstArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostFindFirstArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostFindFirstArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostFindFirstArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostFindFirstArgsTrait for PostFindFirstArgs { }
+
+impl AsInterface for PostFindFirstArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostFindFirstArgs> for Value {
+    fn from(value: PostFindFirstArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostFindFirstArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostFindFirstArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostFindFirstArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostFindFirstArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostFindFirstArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostFindFirstArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostFindManyArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&PostSerializableScalarFields> {
+        Some(PostSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<PostSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostFindManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostFindManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostFindManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostFindManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostFindManyArgsTrait for PostFindManyArgs { }
+
+impl AsInterface for PostFindManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostFindManyArgs> for Value {
+    fn from(value: PostFindManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostFindManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostFindManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostFindManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostFindManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostFindManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostFindManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostFindUniqueArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostFindUniqueArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostFindUniqueArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostFindUniqueArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostFindUniqueArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostFindUniqueArgsTrait for PostFindUniqueArgs { }
+
+impl AsInterface for PostFindUniqueArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostFindUniqueArgs> for Value {
+    fn from(value: PostFindUniqueArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostFindUniqueArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostFindUniqueArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostFindUniqueArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostFindUniqueArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostFindUniqueArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostFindUniqueArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostGroupByArgsTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&PostAvgAggregateInputType> {
+        Some(PostAvgAggregateInputType::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<PostAvgAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&PostCountAggregateInputType> {
+        Some(PostCountAggregateInputType::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<PostCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&PostMaxAggregateInputType> {
+        Some(PostMaxAggregateInputType::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<PostMaxAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&PostMinAggregateInputType> {
+        Some(PostMinAggregateInputType::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<PostMinAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&PostSumAggregateInputType> {
+        Some(PostSumAggregateInputType::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<PostSumAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn by(&self) -> &Value {
+        Value::from_value_ref(self.inner().get("by").unwrap()).unwrap()
+    }
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_by(&mut self, new_value: Value) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("by".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&PostSerializableScalarFields> {
+        Some(PostSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<PostSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    fn having(&self) -> Option<&PostScalarWhereWithAggregatesInput> {
+        Some(PostScalarWhereWithAggregatesInput::from_value_ref(self.inner().get("having")?).unwrap())
+    }
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_having(&mut self, new_value: Option<PostScalarWhereWithAggregatesInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("having".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("having");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostGroupByArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostGroupByArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostGroupByArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostGroupByArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostGroupByArgsTrait for PostGroupByArgs { }
+
+impl AsInterface for PostGroupByArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostGroupByArgs> for Value {
+    fn from(value: PostGroupByArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostGroupByArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostGroupByArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostGroupByArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostGroupByArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostGroupByArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostGroupByArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostGroupByResultTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&PostAvgAggregateResult> {
+        Some(PostAvgAggregateResult::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<PostAvgAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&PostCountAggregateResult> {
+        Some(PostCountAggregateResult::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<PostCountAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&PostMaxAggregateResult> {
+        Some(PostMaxAggregateResult::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<PostMaxAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&PostMinAggregateResult> {
+        Some(PostMinAggregateResult::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<PostMinAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&PostSumAggregateResult> {
+        Some(PostSumAggregateResult::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<PostSumAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<Vec<&String>> {
+        Some(Vec::<&String>::from_value_ref_vec(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<Vec<String>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostGroupByResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostGroupByResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostGroupByResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostGroupByResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostGroupByResultTrait for PostGroupByResult { }
+
+impl AsInterface for PostGroupByResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostGroupByResult> for Value {
+    fn from(value: PostGroupByResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostGroupByResult {
+
+    fn from_value_ref(value: &Value) -> Result<&PostGroupByResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostGroupByResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostGroupByResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostGroupByResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostGroupByResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostIncludeTrait: Interface {
+    /// ## Author
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("author")?).unwrap())
+    }
+    /// ## Author
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("author".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("author");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostInclude {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostInclude {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostInclude {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostInclude {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostIncludeTrait for PostInclude { }
+
+impl AsInterface for PostInclude {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostInclude> for Value {
+    fn from(value: PostInclude) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostInclude {
+
+    fn from_value_ref(value: &Value) -> Result<&PostInclude> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostInclude)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostInclude {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostInclude {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostInclude {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostListRelationFilterTrait: Interface {
+    /// ## Every
+    ///
+    /// This synthesized field doesn't have a description.
+    fn every(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("every")?).unwrap())
+    }
+    /// ## Every
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_every(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("every".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("every");
+            },
+        }
+    }
+    /// ## None
+    ///
+    /// This synthesized field doesn't have a description.
+    fn none(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("none")?).unwrap())
+    }
+    /// ## None
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_none(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("none".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("none");
+            },
+        }
+    }
+    /// ## Some
+    ///
+    /// This synthesized field doesn't have a description.
+    fn some(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("some")?).unwrap())
+    }
+    /// ## Some
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_some(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("some".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("some");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostListRelationFilter {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostListRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostListRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostListRelationFilter {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostListRelationFilterTrait for PostListRelationFilter { }
+
+impl AsInterface for PostListRelationFilter {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostListRelationFilter> for Value {
+    fn from(value: PostListRelationFilter) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostListRelationFilter {
+
+    fn from_value_ref(value: &Value) -> Result<&PostListRelationFilter> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostListRelationFilter)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostListRelationFilter {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostListRelationFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostListRelationFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostMaxAggregateInputTypeTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostMaxAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostMaxAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostMaxAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostMaxAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostMaxAggregateInputTypeTrait for PostMaxAggregateInputType { }
+
+impl AsInterface for PostMaxAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostMaxAggregateInputType> for Value {
+    fn from(value: PostMaxAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostMaxAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&PostMaxAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostMaxAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostMaxAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostMaxAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostMaxAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostMaxAggregateResultTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<Vec<&String>> {
+        Some(Vec::<&String>::from_value_ref_vec(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<Vec<String>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostMaxAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostMaxAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostMaxAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostMaxAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostMaxAggregateResultTrait for PostMaxAggregateResult { }
+
+impl AsInterface for PostMaxAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostMaxAggregateResult> for Value {
+    fn from(value: PostMaxAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostMaxAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&PostMaxAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostMaxAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostMaxAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostMaxAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostMaxAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostMinAggregateInputTypeTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostMinAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostMinAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostMinAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostMinAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostMinAggregateInputTypeTrait for PostMinAggregateInputType { }
+
+impl AsInterface for PostMinAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostMinAggregateInputType> for Value {
+    fn from(value: PostMinAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostMinAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&PostMinAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostMinAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostMinAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostMinAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostMinAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostMinAggregateResultTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<Vec<&String>> {
+        Some(Vec::<&String>::from_value_ref_vec(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<Vec<String>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostMinAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostMinAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostMinAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostMinAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostMinAggregateResultTrait for PostMinAggregateResult { }
+
+impl AsInterface for PostMinAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostMinAggregateResult> for Value {
+    fn from(value: PostMinAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostMinAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&PostMinAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostMinAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostMinAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostMinAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostMinAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostOrderByInputTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostOrderByInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostOrderByInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostOrderByInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostOrderByInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostOrderByInputTrait for PostOrderByInput { }
+
+impl AsInterface for PostOrderByInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostOrderByInput> for Value {
+    fn from(value: PostOrderByInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostOrderByInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostOrderByInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostOrderByInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostOrderByInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostOrderByInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostOrderByInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostRelationFilterTrait: Interface {
+    /// ## Is
+    ///
+    /// This synthesized field doesn't have a description.
+    fn is(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("is")?).unwrap())
+    }
+    /// ## Is
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_is(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("is".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("is");
+            },
+        }
+    }
+    /// ## Is Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn is_not(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("isNot")?).unwrap())
+    }
+    /// ## Is Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_is_not(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("isNot".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("isNot");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostRelationFilter {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostRelationFilter {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostRelationFilterTrait for PostRelationFilter { }
+
+impl AsInterface for PostRelationFilter {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostRelationFilter> for Value {
+    fn from(value: PostRelationFilter) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostRelationFilter {
+
+    fn from_value_ref(value: &Value) -> Result<&PostRelationFilter> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostRelationFilter)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostRelationFilter {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostRelationFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostRelationFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostResultTrait: Interface {
+    /// ## Author
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author(&self) -> &UserResult {
+        UserResult::from_value_ref(self.inner().get("author").unwrap()).unwrap()
+    }
+    /// ## Author
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author(&mut self, new_value: UserResult) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("author".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("authorId").unwrap()).unwrap()
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("id").unwrap()).unwrap()
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Vec<&String> {
+        Vec::<&String>::from_value_ref_vec(self.inner().get("tags").unwrap()).unwrap()
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Vec<String>) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> &String {
+        String::from_value_ref(self.inner().get("title").unwrap()).unwrap()
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> &i64 {
+        i64::from_value_ref(self.inner().get("views").unwrap()).unwrap()
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: i64) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostResultTrait for PostResult { }
+
+impl AsInterface for PostResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostResult> for Value {
+    fn from(value: PostResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostResult {
+
+    fn from_value_ref(value: &Value) -> Result<&PostResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostScalarUpdateInputTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<Vec<&String>> {
+        Some(Vec::<&String>::from_value_ref_vec(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<Vec<String>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostScalarUpdateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostScalarUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostScalarUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostScalarUpdateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostScalarUpdateInputTrait for PostScalarUpdateInput { }
+
+impl AsInterface for PostScalarUpdateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostScalarUpdateInput> for Value {
+    fn from(value: PostScalarUpdateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostScalarUpdateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostScalarUpdateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostScalarUpdateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostScalarUpdateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostScalarUpdateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostScalarUpdateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostScalarWhereWithAggregatesInputTrait: Interface {
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn and(&self) -> Option<Vec<&PostWhereInput>> {
+        Some(Vec::<&PostWhereInput>::from_value_ref_vec(self.inner().get("AND")?).unwrap())
+    }
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_and(&mut self, new_value: Option<Vec<PostWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("AND".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("AND");
+            },
+        }
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn not(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("NOT")?).unwrap())
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_not(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("NOT".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("NOT");
+            },
+        }
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn or(&self) -> Option<Vec<&PostWhereInput>> {
+        Some(Vec::<&PostWhereInput>::from_value_ref_vec(self.inner().get("OR")?).unwrap())
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_or(&mut self, new_value: Option<Vec<PostWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("OR".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("OR");
+            },
+        }
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostScalarWhereWithAggregatesInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostScalarWhereWithAggregatesInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostScalarWhereWithAggregatesInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostScalarWhereWithAggregatesInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostScalarWhereWithAggregatesInputTrait for PostScalarWhereWithAggregatesInput { }
+
+impl AsInterface for PostScalarWhereWithAggregatesInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostScalarWhereWithAggregatesInput> for Value {
+    fn from(value: PostScalarWhereWithAggregatesInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostScalarWhereWithAggregatesInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostScalarWhereWithAggregatesInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostScalarWhereWithAggregatesInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostScalarWhereWithAggregatesInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostScalarWhereWithAggregatesInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostScalarWhereWithAggregatesInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostSelectTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostSelect {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostSelect {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostSelect {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostSelect {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostSelectTrait for PostSelect { }
+
+impl AsInterface for PostSelect {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostSelect> for Value {
+    fn from(value: PostSelect) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostSelect {
+
+    fn from_value_ref(value: &Value) -> Result<&PostSelect> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostSelect)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostSelect {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostSelect {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostSelect {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostSignInArgsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct PostSignInArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostSignInArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostSignInArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostSignInArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostSignInArgsTrait for PostSignInArgs { }
+
+impl AsInterface for PostSignInArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostSignInArgs> for Value {
+    fn from(value: PostSignInArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostSignInArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostSignInArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostSignInArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostSignInArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostSignInArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostSignInArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostSignInCheckerCompanionsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct PostSignInCheckerCompanions {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostSignInCheckerCompanions {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostSignInCheckerCompanions {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostSignInCheckerCompanions {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostSignInCheckerCompanionsTrait for PostSignInCheckerCompanions { }
+
+impl AsInterface for PostSignInCheckerCompanions {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostSignInCheckerCompanions> for Value {
+    fn from(value: PostSignInCheckerCompanions) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostSignInCheckerCompanions {
+
+    fn from_value_ref(value: &Value) -> Result<&PostSignInCheckerCompanions> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostSignInCheckerCompanions)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostSignInCheckerCompanions {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostSignInCheckerCompanions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostSignInCheckerCompanions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostSignInCheckerIdsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct PostSignInCheckerIds {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostSignInCheckerIds {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostSignInCheckerIds {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostSignInCheckerIds {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostSignInCheckerIdsTrait for PostSignInCheckerIds { }
+
+impl AsInterface for PostSignInCheckerIds {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostSignInCheckerIds> for Value {
+    fn from(value: PostSignInCheckerIds) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostSignInCheckerIds {
+
+    fn from_value_ref(value: &Value) -> Result<&PostSignInCheckerIds> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostSignInCheckerIds)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostSignInCheckerIds {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostSignInCheckerIds {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostSignInCheckerIds {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostSignInInputTrait: Interface {
+    /// ## Credentials
+    ///
+    /// This synthesized field doesn't have a description.
+    fn credentials(&self) -> &PostSignInArgs {
+        PostSignInArgs::from_value_ref(self.inner().get("credentials").unwrap()).unwrap()
+    }
+    /// ## Credentials
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_credentials(&mut self, new_value: PostSignInArgs) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("credentials".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostSignInInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostSignInInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostSignInInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostSignInInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostSignInInputTrait for PostSignInInput { }
+
+impl AsInterface for PostSignInInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostSignInInput> for Value {
+    fn from(value: PostSignInInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostSignInInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostSignInInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostSignInInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostSignInInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostSignInInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostSignInInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostSumAggregateInputTypeTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostSumAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostSumAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostSumAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostSumAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostSumAggregateInputTypeTrait for PostSumAggregateInputType { }
+
+impl AsInterface for PostSumAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostSumAggregateInputType> for Value {
+    fn from(value: PostSumAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostSumAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&PostSumAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostSumAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostSumAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostSumAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostSumAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostSumAggregateResultTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostSumAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostSumAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostSumAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostSumAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostSumAggregateResultTrait for PostSumAggregateResult { }
+
+impl AsInterface for PostSumAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostSumAggregateResult> for Value {
+    fn from(value: PostSumAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostSumAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&PostSumAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostSumAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostSumAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostSumAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostSumAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateArgsTrait for PostUpdateArgs { }
+
+impl AsInterface for PostUpdateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateArgs> for Value {
+    fn from(value: PostUpdateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateInputTrait: Interface {
+    /// ## Author
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author(&self) -> Option<&UserUpdateNestedOneWithoutPostsInput> {
+        Some(UserUpdateNestedOneWithoutPostsInput::from_value_ref(self.inner().get("author")?).unwrap())
+    }
+    /// ## Author
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author(&mut self, new_value: Option<UserUpdateNestedOneWithoutPostsInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("author".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("author");
+            },
+        }
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<Vec<&String>> {
+        Some(Vec::<&String>::from_value_ref_vec(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<Vec<String>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateInputTrait for PostUpdateInput { }
+
+impl AsInterface for PostUpdateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateInput> for Value {
+    fn from(value: PostUpdateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateManyArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereInput {
+        PostWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateManyArgsTrait for PostUpdateManyArgs { }
+
+impl AsInterface for PostUpdateManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateManyArgs> for Value {
+    fn from(value: PostUpdateManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateManyWithWhereInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereInput {
+        PostWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateManyWithWhereInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateManyWithWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateManyWithWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateManyWithWhereInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateManyWithWhereInputTrait for PostUpdateManyWithWhereInput { }
+
+impl AsInterface for PostUpdateManyWithWhereInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateManyWithWhereInput> for Value {
+    fn from(value: PostUpdateManyWithWhereInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateManyWithWhereInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateManyWithWhereInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateManyWithWhereInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateManyWithWhereInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateManyWithWhereInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateManyWithWhereInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateManyWithWhereWithoutAuthorInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereInput {
+        PostWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateManyWithWhereWithoutAuthorInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateManyWithWhereWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateManyWithWhereWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateManyWithWhereWithoutAuthorInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateManyWithWhereWithoutAuthorInputTrait for PostUpdateManyWithWhereWithoutAuthorInput { }
+
+impl AsInterface for PostUpdateManyWithWhereWithoutAuthorInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateManyWithWhereWithoutAuthorInput> for Value {
+    fn from(value: PostUpdateManyWithWhereWithoutAuthorInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateManyWithWhereWithoutAuthorInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateManyWithWhereWithoutAuthorInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateManyWithWhereWithoutAuthorInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateManyWithWhereWithoutAuthorInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateManyWithWhereWithoutAuthorInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateManyWithWhereWithoutAuthorInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateNestedManyInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("deleteMany")?).unwrap())
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("deleteMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("deleteMany");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("updateMany")?).unwrap())
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("updateMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("updateMany");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateNestedManyInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateNestedManyInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateNestedManyInputTrait for PostUpdateNestedManyInput { }
+
+impl AsInterface for PostUpdateNestedManyInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateNestedManyInput> for Value {
+    fn from(value: PostUpdateNestedManyInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateNestedManyInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateNestedManyInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateNestedManyInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateNestedManyInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateNestedManyInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateNestedManyInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateNestedManyWithoutAuthorInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("deleteMany")?).unwrap())
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("deleteMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("deleteMany");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("updateMany")?).unwrap())
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("updateMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("updateMany");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateNestedManyWithoutAuthorInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateNestedManyWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateNestedManyWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateNestedManyWithoutAuthorInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateNestedManyWithoutAuthorInputTrait for PostUpdateNestedManyWithoutAuthorInput { }
+
+impl AsInterface for PostUpdateNestedManyWithoutAuthorInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateNestedManyWithoutAuthorInput> for Value {
+    fn from(value: PostUpdateNestedManyWithoutAuthorInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateNestedManyWithoutAuthorInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateNestedManyWithoutAuthorInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateNestedManyWithoutAuthorInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateNestedManyWithoutAuthorInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateNestedManyWithoutAuthorInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateNestedManyWithoutAuthorInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateNestedOneInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&PostConnectOrCreateInput> {
+        Some(PostConnectOrCreateInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<PostConnectOrCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&PostCreateInput> {
+        Some(PostCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<PostCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&PostUpdateInput> {
+        Some(PostUpdateInput::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<PostUpdateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&PostUpsertWithWhereUniqueInput> {
+        Some(PostUpsertWithWhereUniqueInput::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<PostUpsertWithWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateNestedOneInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateNestedOneInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateNestedOneInputTrait for PostUpdateNestedOneInput { }
+
+impl AsInterface for PostUpdateNestedOneInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateNestedOneInput> for Value {
+    fn from(value: PostUpdateNestedOneInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateNestedOneInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateNestedOneInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateNestedOneInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateNestedOneInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateNestedOneInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateNestedOneInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateNestedOneWithoutAuthorInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&PostConnectOrCreateWithoutAuthorInput> {
+        Some(PostConnectOrCreateWithoutAuthorInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<PostConnectOrCreateWithoutAuthorInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&PostCreateInput> {
+        Some(PostCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<PostCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&PostWhereUniqueInput> {
+        Some(PostWhereUniqueInput::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<PostWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&PostUpdateInput> {
+        Some(PostUpdateInput::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<PostUpdateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&PostUpsertWithWhereUniqueWithoutAuthorInput> {
+        Some(PostUpsertWithWhereUniqueWithoutAuthorInput::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<PostUpsertWithWhereUniqueWithoutAuthorInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateNestedOneWithoutAuthorInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateNestedOneWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateNestedOneWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateNestedOneWithoutAuthorInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateNestedOneWithoutAuthorInputTrait for PostUpdateNestedOneWithoutAuthorInput { }
+
+impl AsInterface for PostUpdateNestedOneWithoutAuthorInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateNestedOneWithoutAuthorInput> for Value {
+    fn from(value: PostUpdateNestedOneWithoutAuthorInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateNestedOneWithoutAuthorInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateNestedOneWithoutAuthorInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateNestedOneWithoutAuthorInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateNestedOneWithoutAuthorInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateNestedOneWithoutAuthorInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateNestedOneWithoutAuthorInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateWithWhereUniqueInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateWithWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateWithWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateWithWhereUniqueInputTrait for PostUpdateWithWhereUniqueInput { }
+
+impl AsInterface for PostUpdateWithWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateWithWhereUniqueInput> for Value {
+    fn from(value: PostUpdateWithWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateWithWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateWithWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateWithWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateWithWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateWithWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateWithWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateWithWhereUniqueWithoutAuthorInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateWithWhereUniqueWithoutAuthorInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateWithWhereUniqueWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateWithWhereUniqueWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateWithWhereUniqueWithoutAuthorInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateWithWhereUniqueWithoutAuthorInputTrait for PostUpdateWithWhereUniqueWithoutAuthorInput { }
+
+impl AsInterface for PostUpdateWithWhereUniqueWithoutAuthorInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateWithWhereUniqueWithoutAuthorInput> for Value {
+    fn from(value: PostUpdateWithWhereUniqueWithoutAuthorInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateWithWhereUniqueWithoutAuthorInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateWithWhereUniqueWithoutAuthorInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateWithWhereUniqueWithoutAuthorInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateWithWhereUniqueWithoutAuthorInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateWithWhereUniqueWithoutAuthorInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateWithWhereUniqueWithoutAuthorInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpdateWithoutAuthorInputTrait: Interface {
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<Vec<&String>> {
+        Some(Vec::<&String>::from_value_ref_vec(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<Vec<String>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpdateWithoutAuthorInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpdateWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpdateWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpdateWithoutAuthorInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpdateWithoutAuthorInputTrait for PostUpdateWithoutAuthorInput { }
+
+impl AsInterface for PostUpdateWithoutAuthorInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpdateWithoutAuthorInput> for Value {
+    fn from(value: PostUpdateWithoutAuthorInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpdateWithoutAuthorInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpdateWithoutAuthorInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpdateWithoutAuthorInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpdateWithoutAuthorInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpdateWithoutAuthorInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpdateWithoutAuthorInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpsertArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &PostCreateInput {
+        PostCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: PostCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&PostInclude> {
+        Some(PostInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<PostInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&PostSelect> {
+        Some(PostSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<PostSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpsertArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpsertArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpsertArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpsertArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpsertArgsTrait for PostUpsertArgs { }
+
+impl AsInterface for PostUpsertArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpsertArgs> for Value {
+    fn from(value: PostUpsertArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpsertArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpsertArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpsertArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpsertArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpsertArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpsertArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpsertWithWhereUniqueInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &PostCreateInput {
+        PostCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: PostCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpsertWithWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpsertWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpsertWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpsertWithWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpsertWithWhereUniqueInputTrait for PostUpsertWithWhereUniqueInput { }
+
+impl AsInterface for PostUpsertWithWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpsertWithWhereUniqueInput> for Value {
+    fn from(value: PostUpsertWithWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpsertWithWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpsertWithWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpsertWithWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpsertWithWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpsertWithWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpsertWithWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostUpsertWithWhereUniqueWithoutAuthorInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &PostCreateInput {
+        PostCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: PostCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &PostUpdateInput {
+        PostUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: PostUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &PostWhereUniqueInput {
+        PostWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: PostWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostUpsertWithWhereUniqueWithoutAuthorInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostUpsertWithWhereUniqueWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostUpsertWithWhereUniqueWithoutAuthorInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostUpsertWithWhereUniqueWithoutAuthorInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostUpsertWithWhereUniqueWithoutAuthorInputTrait for PostUpsertWithWhereUniqueWithoutAuthorInput { }
+
+impl AsInterface for PostUpsertWithWhereUniqueWithoutAuthorInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostUpsertWithWhereUniqueWithoutAuthorInput> for Value {
+    fn from(value: PostUpsertWithWhereUniqueWithoutAuthorInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostUpsertWithWhereUniqueWithoutAuthorInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostUpsertWithWhereUniqueWithoutAuthorInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostUpsertWithWhereUniqueWithoutAuthorInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostUpsertWithWhereUniqueWithoutAuthorInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostUpsertWithWhereUniqueWithoutAuthorInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostUpsertWithWhereUniqueWithoutAuthorInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostWhereInputTrait: Interface {
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn and(&self) -> Option<Vec<&PostWhereInput>> {
+        Some(Vec::<&PostWhereInput>::from_value_ref_vec(self.inner().get("AND")?).unwrap())
+    }
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_and(&mut self, new_value: Option<Vec<PostWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("AND".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("AND");
+            },
+        }
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn not(&self) -> Option<&PostWhereInput> {
+        Some(PostWhereInput::from_value_ref(self.inner().get("NOT")?).unwrap())
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_not(&mut self, new_value: Option<PostWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("NOT".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("NOT");
+            },
+        }
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn or(&self) -> Option<Vec<&PostWhereInput>> {
+        Some(Vec::<&PostWhereInput>::from_value_ref_vec(self.inner().get("OR")?).unwrap())
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_or(&mut self, new_value: Option<Vec<PostWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("OR".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("OR");
+            },
+        }
+    }
+    /// ## Author
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author(&self) -> Option<&UserRelationFilter> {
+        Some(UserRelationFilter::from_value_ref(self.inner().get("author")?).unwrap())
+    }
+    /// ## Author
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author(&mut self, new_value: Option<UserRelationFilter>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("author".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("author");
+            },
+        }
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn author_id(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("authorId")?).unwrap())
+    }
+    /// ## Author Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_author_id(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("authorId".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("authorId");
+            },
+        }
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn content(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("content")?).unwrap())
+    }
+    /// ## Content
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_content(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("content".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("content");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn tags(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("tags")?).unwrap())
+    }
+    /// ## Tags
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_tags(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("tags".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("tags");
+            },
+        }
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn title(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("title")?).unwrap())
+    }
+    /// ## Title
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_title(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("title".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("title");
+            },
+        }
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn views(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("views")?).unwrap())
+    }
+    /// ## Views
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_views(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("views".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("views");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct PostWhereInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostWhereInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostWhereInputTrait for PostWhereInput { }
+
+impl AsInterface for PostWhereInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostWhereInput> for Value {
+    fn from(value: PostWhereInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostWhereInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostWhereInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostWhereInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostWhereInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostWhereInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostWhereInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait PostWhereUniqueInputTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("id").unwrap()).unwrap()
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct PostWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for PostWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &PostWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for PostWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl PostWhereUniqueInputTrait for PostWhereUniqueInput { }
+
+impl AsInterface for PostWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<PostWhereUniqueInput> for Value {
+    fn from(value: PostWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for PostWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&PostWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const PostWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for PostWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for PostWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for PostWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserAggregateArgsTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&UserAvgAggregateInputType> {
+        Some(UserAvgAggregateInputType::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<UserAvgAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&UserCountAggregateInputType> {
+        Some(UserCountAggregateInputType::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<UserCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&UserMaxAggregateInputType> {
+        Some(UserMaxAggregateInputType::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<UserMaxAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&UserMinAggregateInputType> {
+        Some(UserMinAggregateInputType::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<UserMinAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&UserSumAggregateInputType> {
+        Some(UserSumAggregateInputType::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<UserSumAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&UserSerializableScalarFields> {
+        Some(UserSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<UserSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserAggregateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserAggregateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserAggregateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserAggregateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserAggregateArgsTrait for UserAggregateArgs { }
+
+impl AsInterface for UserAggregateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserAggregateArgs> for Value {
+    fn from(value: UserAggregateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserAggregateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserAggregateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserAggregateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserAggregateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserAggregateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserAggregateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserAggregateResultTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&UserAvgAggregateResult> {
+        Some(UserAvgAggregateResult::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<UserAvgAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&UserCountAggregateResult> {
+        Some(UserCountAggregateResult::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<UserCountAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&UserMaxAggregateResult> {
+        Some(UserMaxAggregateResult::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<UserMaxAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&UserMinAggregateResult> {
+        Some(UserMinAggregateResult::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<UserMinAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&UserSumAggregateResult> {
+        Some(UserSumAggregateResult::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<UserSumAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserAggregateResultTrait for UserAggregateResult { }
+
+impl AsInterface for UserAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserAggregateResult> for Value {
+    fn from(value: UserAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&UserAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserArgsTrait for UserArgs { }
+
+impl AsInterface for UserArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserArgs> for Value {
+    fn from(value: UserArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserAvgAggregateInputTypeTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserAvgAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserAvgAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserAvgAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserAvgAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserAvgAggregateInputTypeTrait for UserAvgAggregateInputType { }
+
+impl AsInterface for UserAvgAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserAvgAggregateInputType> for Value {
+    fn from(value: UserAvgAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserAvgAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&UserAvgAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserAvgAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserAvgAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserAvgAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserAvgAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserAvgAggregateResultTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&f64> {
+        Some(f64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<f64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserAvgAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserAvgAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserAvgAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserAvgAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserAvgAggregateResultTrait for UserAvgAggregateResult { }
+
+impl AsInterface for UserAvgAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserAvgAggregateResult> for Value {
+    fn from(value: UserAvgAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserAvgAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&UserAvgAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserAvgAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserAvgAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserAvgAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserAvgAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserConnectOrCreateInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &UserCreateInput {
+        UserCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: UserCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserConnectOrCreateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserConnectOrCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserConnectOrCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserConnectOrCreateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserConnectOrCreateInputTrait for UserConnectOrCreateInput { }
+
+impl AsInterface for UserConnectOrCreateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserConnectOrCreateInput> for Value {
+    fn from(value: UserConnectOrCreateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserConnectOrCreateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserConnectOrCreateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserConnectOrCreateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserConnectOrCreateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserConnectOrCreateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserConnectOrCreateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserConnectOrCreateWithoutPostsInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &UserCreateInput {
+        UserCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: UserCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserConnectOrCreateWithoutPostsInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserConnectOrCreateWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserConnectOrCreateWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserConnectOrCreateWithoutPostsInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserConnectOrCreateWithoutPostsInputTrait for UserConnectOrCreateWithoutPostsInput { }
+
+impl AsInterface for UserConnectOrCreateWithoutPostsInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserConnectOrCreateWithoutPostsInput> for Value {
+    fn from(value: UserConnectOrCreateWithoutPostsInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserConnectOrCreateWithoutPostsInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserConnectOrCreateWithoutPostsInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserConnectOrCreateWithoutPostsInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserConnectOrCreateWithoutPostsInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserConnectOrCreateWithoutPostsInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserConnectOrCreateWithoutPostsInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCopyArgsTrait: Interface {
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn copy(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("copy").unwrap()).unwrap()
+    }
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_copy(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("copy".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCopyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCopyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCopyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCopyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCopyArgsTrait for UserCopyArgs { }
+
+impl AsInterface for UserCopyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCopyArgs> for Value {
+    fn from(value: UserCopyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCopyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCopyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCopyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCopyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCopyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCopyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCopyManyArgsTrait: Interface {
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn copy(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("copy").unwrap()).unwrap()
+    }
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_copy(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("copy".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereInput {
+        UserWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCopyManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCopyManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCopyManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCopyManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCopyManyArgsTrait for UserCopyManyArgs { }
+
+impl AsInterface for UserCopyManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCopyManyArgs> for Value {
+    fn from(value: UserCopyManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCopyManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCopyManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCopyManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCopyManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCopyManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCopyManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCountAggregateInputTypeTrait: Interface {
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn all(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("_all")?).unwrap())
+    }
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_all(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_all".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_all");
+            },
+        }
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCountAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCountAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCountAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCountAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCountAggregateInputTypeTrait for UserCountAggregateInputType { }
+
+impl AsInterface for UserCountAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCountAggregateInputType> for Value {
+    fn from(value: UserCountAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCountAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCountAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCountAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCountAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCountAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCountAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCountAggregateResultTrait: Interface {
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn all(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("_all")?).unwrap())
+    }
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_all(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_all".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_all");
+            },
+        }
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCountAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCountAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCountAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCountAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCountAggregateResultTrait for UserCountAggregateResult { }
+
+impl AsInterface for UserCountAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCountAggregateResult> for Value {
+    fn from(value: UserCountAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCountAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCountAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCountAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCountAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCountAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCountAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCountArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&UserSerializableScalarFields> {
+        Some(UserSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<UserSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserCountAggregateInputType> {
+        Some(UserCountAggregateInputType::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCountArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCountArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCountArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCountArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCountArgsTrait for UserCountArgs { }
+
+impl AsInterface for UserCountArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCountArgs> for Value {
+    fn from(value: UserCountArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCountArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCountArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCountArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCountArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCountArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCountArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCreateArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &UserCreateInput {
+        UserCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: UserCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCreateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCreateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCreateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCreateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCreateArgsTrait for UserCreateArgs { }
+
+impl AsInterface for UserCreateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCreateArgs> for Value {
+    fn from(value: UserCreateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCreateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCreateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCreateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCreateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCreateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCreateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCreateInputTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&BigDecimal> {
+        Some(BigDecimal::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<BigDecimal>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&NaiveDate> {
+        Some(NaiveDate::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<NaiveDate>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> &String {
+        String::from_value_ref(self.inner().get("email").unwrap()).unwrap()
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Posts
+    ///
+    /// This synthesized field doesn't have a description.
+    fn posts(&self) -> Option<&PostCreateNestedManyWithoutAuthorInput> {
+        Some(PostCreateNestedManyWithoutAuthorInput::from_value_ref(self.inner().get("posts")?).unwrap())
+    }
+    /// ## Posts
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_posts(&mut self, new_value: Option<PostCreateNestedManyWithoutAuthorInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("posts".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("posts");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Sex> {
+        Some(Sex::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Sex>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCreateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCreateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCreateInputTrait for UserCreateInput { }
+
+impl AsInterface for UserCreateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCreateInput> for Value {
+    fn from(value: UserCreateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCreateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCreateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCreateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCreateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCreateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCreateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCreateManyArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &Value {
+        Value::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Value) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCreateManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCreateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCreateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCreateManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCreateManyArgsTrait for UserCreateManyArgs { }
+
+impl AsInterface for UserCreateManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCreateManyArgs> for Value {
+    fn from(value: UserCreateManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCreateManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCreateManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCreateManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCreateManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCreateManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCreateManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCreateNestedManyInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCreateNestedManyInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCreateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCreateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCreateNestedManyInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCreateNestedManyInputTrait for UserCreateNestedManyInput { }
+
+impl AsInterface for UserCreateNestedManyInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCreateNestedManyInput> for Value {
+    fn from(value: UserCreateNestedManyInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCreateNestedManyInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCreateNestedManyInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCreateNestedManyInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCreateNestedManyInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCreateNestedManyInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCreateNestedManyInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCreateNestedManyWithoutPostsInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCreateNestedManyWithoutPostsInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCreateNestedManyWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCreateNestedManyWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCreateNestedManyWithoutPostsInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCreateNestedManyWithoutPostsInputTrait for UserCreateNestedManyWithoutPostsInput { }
+
+impl AsInterface for UserCreateNestedManyWithoutPostsInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCreateNestedManyWithoutPostsInput> for Value {
+    fn from(value: UserCreateNestedManyWithoutPostsInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCreateNestedManyWithoutPostsInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCreateNestedManyWithoutPostsInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCreateNestedManyWithoutPostsInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCreateNestedManyWithoutPostsInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCreateNestedManyWithoutPostsInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCreateNestedManyWithoutPostsInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCreateNestedOneInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&UserConnectOrCreateInput> {
+        Some(UserConnectOrCreateInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<UserConnectOrCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&UserCreateInput> {
+        Some(UserCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<UserCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCreateNestedOneInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCreateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCreateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCreateNestedOneInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCreateNestedOneInputTrait for UserCreateNestedOneInput { }
+
+impl AsInterface for UserCreateNestedOneInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCreateNestedOneInput> for Value {
+    fn from(value: UserCreateNestedOneInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCreateNestedOneInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCreateNestedOneInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCreateNestedOneInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCreateNestedOneInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCreateNestedOneInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCreateNestedOneInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCreateNestedOneWithoutPostsInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&UserConnectOrCreateWithoutPostsInput> {
+        Some(UserConnectOrCreateWithoutPostsInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<UserConnectOrCreateWithoutPostsInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&UserCreateInput> {
+        Some(UserCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<UserCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCreateNestedOneWithoutPostsInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCreateNestedOneWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCreateNestedOneWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCreateNestedOneWithoutPostsInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCreateNestedOneWithoutPostsInputTrait for UserCreateNestedOneWithoutPostsInput { }
+
+impl AsInterface for UserCreateNestedOneWithoutPostsInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCreateNestedOneWithoutPostsInput> for Value {
+    fn from(value: UserCreateNestedOneWithoutPostsInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCreateNestedOneWithoutPostsInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCreateNestedOneWithoutPostsInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCreateNestedOneWithoutPostsInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCreateNestedOneWithoutPostsInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCreateNestedOneWithoutPostsInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCreateNestedOneWithoutPostsInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserCreateWithoutPostsInputTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&BigDecimal> {
+        Some(BigDecimal::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<BigDecimal>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&NaiveDate> {
+        Some(NaiveDate::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<NaiveDate>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> &String {
+        String::from_value_ref(self.inner().get("email").unwrap()).unwrap()
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Sex> {
+        Some(Sex::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Sex>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserCreateWithoutPostsInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserCreateWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserCreateWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserCreateWithoutPostsInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserCreateWithoutPostsInputTrait for UserCreateWithoutPostsInput { }
+
+impl AsInterface for UserCreateWithoutPostsInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserCreateWithoutPostsInput> for Value {
+    fn from(value: UserCreateWithoutPostsInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserCreateWithoutPostsInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserCreateWithoutPostsInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserCreateWithoutPostsInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserCreateWithoutPostsInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserCreateWithoutPostsInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserCreateWithoutPostsInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserDeleteArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserDeleteArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserDeleteArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserDeleteArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserDeleteArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserDeleteArgsTrait for UserDeleteArgs { }
+
+impl AsInterface for UserDeleteArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserDeleteArgs> for Value {
+    fn from(value: UserDeleteArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserDeleteArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserDeleteArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserDeleteArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserDeleteArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserDeleteArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserDeleteArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserDeleteManyArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereInput {
+        UserWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserDeleteManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserDeleteManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserDeleteManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserDeleteManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserDeleteManyArgsTrait for UserDeleteManyArgs { }
+
+impl AsInterface for UserDeleteManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserDeleteManyArgs> for Value {
+    fn from(value: UserDeleteManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserDeleteManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserDeleteManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserDeleteManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserDeleteManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserDeleteManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserDeleteManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserFindFirstArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&UserSerializableScalarFields> {
+        Some(UserSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<UserSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserFindFirstArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserFindFirstArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserFindFirstArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserFindFirstArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserFindFirstArgsTrait for UserFindFirstArgs { }
+
+impl AsInterface for UserFindFirstArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserFindFirstArgs> for Value {
+    fn from(value: UserFindFirstArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserFindFirstArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserFindFirstArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserFindFirstArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserFindFirstArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserFindFirstArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserFindFirstArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserFindManyArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&UserSerializableScalarFields> {
+        Some(UserSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<UserSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserFindManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserFindManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserFindManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserFindManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserFindManyArgsTrait for UserFindManyArgs { }
+
+impl AsInterface for UserFindManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserFindManyArgs> for Value {
+    fn from(value: UserFindManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserFindManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserFindManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserFindManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserFindManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserFindManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserFindManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserFindUniqueArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserFindUniqueArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserFindUniqueArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserFindUniqueArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserFindUniqueArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserFindUniqueArgsTrait for UserFindUniqueArgs { }
+
+impl AsInterface for UserFindUniqueArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserFindUniqueArgs> for Value {
+    fn from(value: UserFindUniqueArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserFindUniqueArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserFindUniqueArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserFindUniqueArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserFindUniqueArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserFindUniqueArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserFindUniqueArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserGroupByArgsTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&UserAvgAggregateInputType> {
+        Some(UserAvgAggregateInputType::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<UserAvgAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&UserCountAggregateInputType> {
+        Some(UserCountAggregateInputType::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<UserCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&UserMaxAggregateInputType> {
+        Some(UserMaxAggregateInputType::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<UserMaxAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&UserMinAggregateInputType> {
+        Some(UserMinAggregateInputType::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<UserMinAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&UserSumAggregateInputType> {
+        Some(UserSumAggregateInputType::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<UserSumAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn by(&self) -> &Value {
+        Value::from_value_ref(self.inner().get("by").unwrap()).unwrap()
+    }
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_by(&mut self, new_value: Value) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("by".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&UserSerializableScalarFields> {
+        Some(UserSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<UserSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    fn having(&self) -> Option<&UserScalarWhereWithAggregatesInput> {
+        Some(UserScalarWhereWithAggregatesInput::from_value_ref(self.inner().get("having")?).unwrap())
+    }
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_having(&mut self, new_value: Option<UserScalarWhereWithAggregatesInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("having".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("having");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserGroupByArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserGroupByArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserGroupByArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserGroupByArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserGroupByArgsTrait for UserGroupByArgs { }
+
+impl AsInterface for UserGroupByArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserGroupByArgs> for Value {
+    fn from(value: UserGroupByArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserGroupByArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserGroupByArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserGroupByArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserGroupByArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserGroupByArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserGroupByArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserGroupByResultTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&UserAvgAggregateResult> {
+        Some(UserAvgAggregateResult::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<UserAvgAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&UserCountAggregateResult> {
+        Some(UserCountAggregateResult::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<UserCountAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&UserMaxAggregateResult> {
+        Some(UserMaxAggregateResult::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<UserMaxAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&UserMinAggregateResult> {
+        Some(UserMinAggregateResult::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<UserMinAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&UserSumAggregateResult> {
+        Some(UserSumAggregateResult::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<UserSumAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&BigDecimal> {
+        Some(BigDecimal::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<BigDecimal>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&NaiveDate> {
+        Some(NaiveDate::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<NaiveDate>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&DateTime<Utc>> {
+        Some(DateTime::<Utc>::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<DateTime<Utc>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Sex> {
+        Some(Sex::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Sex>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserGroupByResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserGroupByResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserGroupByResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserGroupByResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserGroupByResultTrait for UserGroupByResult { }
+
+impl AsInterface for UserGroupByResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserGroupByResult> for Value {
+    fn from(value: UserGroupByResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserGroupByResult {
+
+    fn from_value_ref(value: &Value) -> Result<&UserGroupByResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserGroupByResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserGroupByResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserGroupByResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserGroupByResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserIncludeTrait: Interface {
+    /// ## Posts
+    ///
+    /// This synthesized field doesn't have a description.
+    fn posts(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("posts")?).unwrap())
+    }
+    /// ## Posts
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_posts(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("posts".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("posts");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserInclude {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserInclude {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserInclude {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserInclude {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserIncludeTrait for UserInclude { }
+
+impl AsInterface for UserInclude {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserInclude> for Value {
+    fn from(value: UserInclude) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserInclude {
+
+    fn from_value_ref(value: &Value) -> Result<&UserInclude> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserInclude)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserInclude {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserInclude {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserInclude {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserListRelationFilterTrait: Interface {
+    /// ## Every
+    ///
+    /// This synthesized field doesn't have a description.
+    fn every(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("every")?).unwrap())
+    }
+    /// ## Every
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_every(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("every".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("every");
+            },
+        }
+    }
+    /// ## None
+    ///
+    /// This synthesized field doesn't have a description.
+    fn none(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("none")?).unwrap())
+    }
+    /// ## None
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_none(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("none".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("none");
+            },
+        }
+    }
+    /// ## Some
+    ///
+    /// This synthesized field doesn't have a description.
+    fn some(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("some")?).unwrap())
+    }
+    /// ## Some
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_some(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("some".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("some");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserListRelationFilter {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserListRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserListRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserListRelationFilter {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserListRelationFilterTrait for UserListRelationFilter { }
+
+impl AsInterface for UserListRelationFilter {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserListRelationFilter> for Value {
+    fn from(value: UserListRelationFilter) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserListRelationFilter {
+
+    fn from_value_ref(value: &Value) -> Result<&UserListRelationFilter> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserListRelationFilter)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserListRelationFilter {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserListRelationFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserListRelationFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserMaxAggregateInputTypeTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserMaxAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserMaxAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserMaxAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserMaxAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserMaxAggregateInputTypeTrait for UserMaxAggregateInputType { }
+
+impl AsInterface for UserMaxAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserMaxAggregateInputType> for Value {
+    fn from(value: UserMaxAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserMaxAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&UserMaxAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserMaxAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserMaxAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserMaxAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserMaxAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserMaxAggregateResultTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&BigDecimal> {
+        Some(BigDecimal::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<BigDecimal>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&NaiveDate> {
+        Some(NaiveDate::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<NaiveDate>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&DateTime<Utc>> {
+        Some(DateTime::<Utc>::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<DateTime<Utc>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Sex> {
+        Some(Sex::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Sex>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserMaxAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserMaxAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserMaxAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserMaxAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserMaxAggregateResultTrait for UserMaxAggregateResult { }
+
+impl AsInterface for UserMaxAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserMaxAggregateResult> for Value {
+    fn from(value: UserMaxAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserMaxAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&UserMaxAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserMaxAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserMaxAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserMaxAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserMaxAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserMinAggregateInputTypeTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserMinAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserMinAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserMinAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserMinAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserMinAggregateInputTypeTrait for UserMinAggregateInputType { }
+
+impl AsInterface for UserMinAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserMinAggregateInputType> for Value {
+    fn from(value: UserMinAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserMinAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&UserMinAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserMinAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserMinAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserMinAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserMinAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserMinAggregateResultTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&BigDecimal> {
+        Some(BigDecimal::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<BigDecimal>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&NaiveDate> {
+        Some(NaiveDate::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<NaiveDate>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&DateTime<Utc>> {
+        Some(DateTime::<Utc>::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<DateTime<Utc>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Sex> {
+        Some(Sex::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Sex>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserMinAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserMinAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserMinAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserMinAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserMinAggregateResultTrait for UserMinAggregateResult { }
+
+impl AsInterface for UserMinAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserMinAggregateResult> for Value {
+    fn from(value: UserMinAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserMinAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&UserMinAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserMinAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserMinAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserMinAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserMinAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserOrderByInputTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserOrderByInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserOrderByInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserOrderByInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserOrderByInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserOrderByInputTrait for UserOrderByInput { }
+
+impl AsInterface for UserOrderByInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserOrderByInput> for Value {
+    fn from(value: UserOrderByInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserOrderByInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserOrderByInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserOrderByInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserOrderByInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserOrderByInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserOrderByInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserRelationFilterTrait: Interface {
+    /// ## Is
+    ///
+    /// This synthesized field doesn't have a description.
+    fn is(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("is")?).unwrap())
+    }
+    /// ## Is
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_is(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("is".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("is");
+            },
+        }
+    }
+    /// ## Is Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn is_not(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("isNot")?).unwrap())
+    }
+    /// ## Is Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_is_not(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("isNot".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("isNot");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserRelationFilter {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserRelationFilter {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserRelationFilterTrait for UserRelationFilter { }
+
+impl AsInterface for UserRelationFilter {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserRelationFilter> for Value {
+    fn from(value: UserRelationFilter) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserRelationFilter {
+
+    fn from_value_ref(value: &Value) -> Result<&UserRelationFilter> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserRelationFilter)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserRelationFilter {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserRelationFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserRelationFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserResultTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&BigDecimal> {
+        Some(BigDecimal::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<BigDecimal>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&NaiveDate> {
+        Some(NaiveDate::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<NaiveDate>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> &DateTime<Utc> {
+        DateTime::<Utc>::from_value_ref(self.inner().get("createdAt").unwrap()).unwrap()
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: DateTime<Utc>) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Display Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn display_name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("displayName")?).unwrap())
+    }
+    /// ## Display Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_display_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("displayName".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("displayName");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> &String {
+        String::from_value_ref(self.inner().get("email").unwrap()).unwrap()
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("id").unwrap()).unwrap()
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Posts
+    ///
+    /// This synthesized field doesn't have a description.
+    fn posts(&self) -> Vec<&PostResult> {
+        Vec::<&PostResult>::from_value_ref_vec(self.inner().get("posts").unwrap()).unwrap()
+    }
+    /// ## Posts
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_posts(&mut self, new_value: Vec<PostResult>) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("posts".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Sex> {
+        Some(Sex::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Sex>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserResultTrait for UserResult { }
+
+impl AsInterface for UserResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserResult> for Value {
+    fn from(value: UserResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserResult {
+
+    fn from_value_ref(value: &Value) -> Result<&UserResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserScalarUpdateInputTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&BigDecimal> {
+        Some(BigDecimal::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<BigDecimal>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&NaiveDate> {
+        Some(NaiveDate::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<NaiveDate>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&DateTime<Utc>> {
+        Some(DateTime::<Utc>::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<DateTime<Utc>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Sex> {
+        Some(Sex::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Sex>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserScalarUpdateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserScalarUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserScalarUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserScalarUpdateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserScalarUpdateInputTrait for UserScalarUpdateInput { }
+
+impl AsInterface for UserScalarUpdateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserScalarUpdateInput> for Value {
+    fn from(value: UserScalarUpdateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserScalarUpdateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserScalarUpdateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserScalarUpdateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserScalarUpdateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserScalarUpdateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserScalarUpdateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserScalarWhereWithAggregatesInputTrait: Interface {
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn and(&self) -> Option<Vec<&UserWhereInput>> {
+        Some(Vec::<&UserWhereInput>::from_value_ref_vec(self.inner().get("AND")?).unwrap())
+    }
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_and(&mut self, new_value: Option<Vec<UserWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("AND".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("AND");
+            },
+        }
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn not(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("NOT")?).unwrap())
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_not(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("NOT".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("NOT");
+            },
+        }
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn or(&self) -> Option<Vec<&UserWhereInput>> {
+        Some(Vec::<&UserWhereInput>::from_value_ref_vec(self.inner().get("OR")?).unwrap())
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_or(&mut self, new_value: Option<Vec<UserWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("OR".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("OR");
+            },
+        }
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserScalarWhereWithAggregatesInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserScalarWhereWithAggregatesInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserScalarWhereWithAggregatesInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserScalarWhereWithAggregatesInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserScalarWhereWithAggregatesInputTrait for UserScalarWhereWithAggregatesInput { }
+
+impl AsInterface for UserScalarWhereWithAggregatesInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserScalarWhereWithAggregatesInput> for Value {
+    fn from(value: UserScalarWhereWithAggregatesInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserScalarWhereWithAggregatesInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserScalarWhereWithAggregatesInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserScalarWhereWithAggregatesInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserScalarWhereWithAggregatesInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserScalarWhereWithAggregatesInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserScalarWhereWithAggregatesInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserSelectTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Display Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn display_name(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("displayName")?).unwrap())
+    }
+    /// ## Display Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_display_name(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("displayName".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("displayName");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserSelect {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserSelect {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserSelect {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserSelect {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserSelectTrait for UserSelect { }
+
+impl AsInterface for UserSelect {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserSelect> for Value {
+    fn from(value: UserSelect) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserSelect {
+
+    fn from_value_ref(value: &Value) -> Result<&UserSelect> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserSelect)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserSelect {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserSelect {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserSelect {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserSignInArgsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct UserSignInArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserSignInArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserSignInArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserSignInArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserSignInArgsTrait for UserSignInArgs { }
+
+impl AsInterface for UserSignInArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserSignInArgs> for Value {
+    fn from(value: UserSignInArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserSignInArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserSignInArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserSignInArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserSignInArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserSignInArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserSignInArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserSignInCheckerCompanionsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct UserSignInCheckerCompanions {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserSignInCheckerCompanions {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserSignInCheckerCompanions {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserSignInCheckerCompanions {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserSignInCheckerCompanionsTrait for UserSignInCheckerCompanions { }
+
+impl AsInterface for UserSignInCheckerCompanions {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserSignInCheckerCompanions> for Value {
+    fn from(value: UserSignInCheckerCompanions) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserSignInCheckerCompanions {
+
+    fn from_value_ref(value: &Value) -> Result<&UserSignInCheckerCompanions> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserSignInCheckerCompanions)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserSignInCheckerCompanions {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserSignInCheckerCompanions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserSignInCheckerCompanions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserSignInCheckerIdsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct UserSignInCheckerIds {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserSignInCheckerIds {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserSignInCheckerIds {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserSignInCheckerIds {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserSignInCheckerIdsTrait for UserSignInCheckerIds { }
+
+impl AsInterface for UserSignInCheckerIds {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserSignInCheckerIds> for Value {
+    fn from(value: UserSignInCheckerIds) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserSignInCheckerIds {
+
+    fn from_value_ref(value: &Value) -> Result<&UserSignInCheckerIds> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserSignInCheckerIds)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserSignInCheckerIds {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserSignInCheckerIds {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserSignInCheckerIds {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserSignInInputTrait: Interface {
+    /// ## Credentials
+    ///
+    /// This synthesized field doesn't have a description.
+    fn credentials(&self) -> &UserSignInArgs {
+        UserSignInArgs::from_value_ref(self.inner().get("credentials").unwrap()).unwrap()
+    }
+    /// ## Credentials
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_credentials(&mut self, new_value: UserSignInArgs) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("credentials".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserSignInInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserSignInInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserSignInInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserSignInInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserSignInInputTrait for UserSignInInput { }
+
+impl AsInterface for UserSignInInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserSignInInput> for Value {
+    fn from(value: UserSignInInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserSignInInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserSignInInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserSignInInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserSignInInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserSignInInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserSignInInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserSumAggregateInputTypeTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserSumAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserSumAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserSumAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserSumAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserSumAggregateInputTypeTrait for UserSumAggregateInputType { }
+
+impl AsInterface for UserSumAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserSumAggregateInputType> for Value {
+    fn from(value: UserSumAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserSumAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&UserSumAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserSumAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserSumAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserSumAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserSumAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserSumAggregateResultTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserSumAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserSumAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserSumAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserSumAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserSumAggregateResultTrait for UserSumAggregateResult { }
+
+impl AsInterface for UserSumAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserSumAggregateResult> for Value {
+    fn from(value: UserSumAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserSumAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&UserSumAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserSumAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserSumAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserSumAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserSumAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateArgsTrait for UserUpdateArgs { }
+
+impl AsInterface for UserUpdateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateArgs> for Value {
+    fn from(value: UserUpdateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateInputTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&BigDecimal> {
+        Some(BigDecimal::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<BigDecimal>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&NaiveDate> {
+        Some(NaiveDate::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<NaiveDate>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Posts
+    ///
+    /// This synthesized field doesn't have a description.
+    fn posts(&self) -> Option<&PostUpdateNestedManyWithoutAuthorInput> {
+        Some(PostUpdateNestedManyWithoutAuthorInput::from_value_ref(self.inner().get("posts")?).unwrap())
+    }
+    /// ## Posts
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_posts(&mut self, new_value: Option<PostUpdateNestedManyWithoutAuthorInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("posts".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("posts");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Sex> {
+        Some(Sex::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Sex>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateInputTrait for UserUpdateInput { }
+
+impl AsInterface for UserUpdateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateInput> for Value {
+    fn from(value: UserUpdateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateManyArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereInput {
+        UserWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateManyArgsTrait for UserUpdateManyArgs { }
+
+impl AsInterface for UserUpdateManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateManyArgs> for Value {
+    fn from(value: UserUpdateManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateManyWithWhereInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereInput {
+        UserWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateManyWithWhereInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateManyWithWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateManyWithWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateManyWithWhereInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateManyWithWhereInputTrait for UserUpdateManyWithWhereInput { }
+
+impl AsInterface for UserUpdateManyWithWhereInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateManyWithWhereInput> for Value {
+    fn from(value: UserUpdateManyWithWhereInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateManyWithWhereInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateManyWithWhereInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateManyWithWhereInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateManyWithWhereInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateManyWithWhereInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateManyWithWhereInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateManyWithWhereWithoutPostsInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereInput {
+        UserWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateManyWithWhereWithoutPostsInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateManyWithWhereWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateManyWithWhereWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateManyWithWhereWithoutPostsInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateManyWithWhereWithoutPostsInputTrait for UserUpdateManyWithWhereWithoutPostsInput { }
+
+impl AsInterface for UserUpdateManyWithWhereWithoutPostsInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateManyWithWhereWithoutPostsInput> for Value {
+    fn from(value: UserUpdateManyWithWhereWithoutPostsInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateManyWithWhereWithoutPostsInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateManyWithWhereWithoutPostsInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateManyWithWhereWithoutPostsInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateManyWithWhereWithoutPostsInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateManyWithWhereWithoutPostsInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateManyWithWhereWithoutPostsInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateNestedManyInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("deleteMany")?).unwrap())
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("deleteMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("deleteMany");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("updateMany")?).unwrap())
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("updateMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("updateMany");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateNestedManyInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateNestedManyInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateNestedManyInputTrait for UserUpdateNestedManyInput { }
+
+impl AsInterface for UserUpdateNestedManyInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateNestedManyInput> for Value {
+    fn from(value: UserUpdateNestedManyInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateNestedManyInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateNestedManyInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateNestedManyInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateNestedManyInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateNestedManyInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateNestedManyInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateNestedManyWithoutPostsInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("deleteMany")?).unwrap())
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("deleteMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("deleteMany");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("updateMany")?).unwrap())
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("updateMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("updateMany");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateNestedManyWithoutPostsInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateNestedManyWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateNestedManyWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateNestedManyWithoutPostsInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateNestedManyWithoutPostsInputTrait for UserUpdateNestedManyWithoutPostsInput { }
+
+impl AsInterface for UserUpdateNestedManyWithoutPostsInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateNestedManyWithoutPostsInput> for Value {
+    fn from(value: UserUpdateNestedManyWithoutPostsInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateNestedManyWithoutPostsInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateNestedManyWithoutPostsInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateNestedManyWithoutPostsInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateNestedManyWithoutPostsInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateNestedManyWithoutPostsInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateNestedManyWithoutPostsInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateNestedOneInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&UserConnectOrCreateInput> {
+        Some(UserConnectOrCreateInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<UserConnectOrCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&UserCreateInput> {
+        Some(UserCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<UserCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&UserUpdateInput> {
+        Some(UserUpdateInput::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<UserUpdateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&UserUpsertWithWhereUniqueInput> {
+        Some(UserUpsertWithWhereUniqueInput::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<UserUpsertWithWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateNestedOneInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateNestedOneInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateNestedOneInputTrait for UserUpdateNestedOneInput { }
+
+impl AsInterface for UserUpdateNestedOneInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateNestedOneInput> for Value {
+    fn from(value: UserUpdateNestedOneInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateNestedOneInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateNestedOneInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateNestedOneInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateNestedOneInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateNestedOneInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateNestedOneInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateNestedOneWithoutPostsInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&UserConnectOrCreateWithoutPostsInput> {
+        Some(UserConnectOrCreateWithoutPostsInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<UserConnectOrCreateWithoutPostsInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&UserCreateInput> {
+        Some(UserCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<UserCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&UserWhereUniqueInput> {
+        Some(UserWhereUniqueInput::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<UserWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&UserUpdateInput> {
+        Some(UserUpdateInput::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<UserUpdateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&UserUpsertWithWhereUniqueWithoutPostsInput> {
+        Some(UserUpsertWithWhereUniqueWithoutPostsInput::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<UserUpsertWithWhereUniqueWithoutPostsInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateNestedOneWithoutPostsInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateNestedOneWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateNestedOneWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateNestedOneWithoutPostsInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateNestedOneWithoutPostsInputTrait for UserUpdateNestedOneWithoutPostsInput { }
+
+impl AsInterface for UserUpdateNestedOneWithoutPostsInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateNestedOneWithoutPostsInput> for Value {
+    fn from(value: UserUpdateNestedOneWithoutPostsInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateNestedOneWithoutPostsInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateNestedOneWithoutPostsInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateNestedOneWithoutPostsInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateNestedOneWithoutPostsInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateNestedOneWithoutPostsInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateNestedOneWithoutPostsInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateWithWhereUniqueInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateWithWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateWithWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateWithWhereUniqueInputTrait for UserUpdateWithWhereUniqueInput { }
+
+impl AsInterface for UserUpdateWithWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateWithWhereUniqueInput> for Value {
+    fn from(value: UserUpdateWithWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateWithWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateWithWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateWithWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateWithWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateWithWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateWithWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateWithWhereUniqueWithoutPostsInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateWithWhereUniqueWithoutPostsInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateWithWhereUniqueWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateWithWhereUniqueWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateWithWhereUniqueWithoutPostsInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateWithWhereUniqueWithoutPostsInputTrait for UserUpdateWithWhereUniqueWithoutPostsInput { }
+
+impl AsInterface for UserUpdateWithWhereUniqueWithoutPostsInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateWithWhereUniqueWithoutPostsInput> for Value {
+    fn from(value: UserUpdateWithWhereUniqueWithoutPostsInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateWithWhereUniqueWithoutPostsInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateWithWhereUniqueWithoutPostsInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateWithWhereUniqueWithoutPostsInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateWithWhereUniqueWithoutPostsInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateWithWhereUniqueWithoutPostsInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateWithWhereUniqueWithoutPostsInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpdateWithoutPostsInputTrait: Interface {
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&BigDecimal> {
+        Some(BigDecimal::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<BigDecimal>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&NaiveDate> {
+        Some(NaiveDate::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<NaiveDate>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Sex> {
+        Some(Sex::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Sex>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpdateWithoutPostsInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpdateWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpdateWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpdateWithoutPostsInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpdateWithoutPostsInputTrait for UserUpdateWithoutPostsInput { }
+
+impl AsInterface for UserUpdateWithoutPostsInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpdateWithoutPostsInput> for Value {
+    fn from(value: UserUpdateWithoutPostsInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpdateWithoutPostsInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpdateWithoutPostsInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpdateWithoutPostsInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpdateWithoutPostsInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpdateWithoutPostsInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpdateWithoutPostsInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpsertArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &UserCreateInput {
+        UserCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: UserCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&UserInclude> {
+        Some(UserInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<UserInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&UserSelect> {
+        Some(UserSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<UserSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpsertArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpsertArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpsertArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpsertArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpsertArgsTrait for UserUpsertArgs { }
+
+impl AsInterface for UserUpsertArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpsertArgs> for Value {
+    fn from(value: UserUpsertArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpsertArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpsertArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpsertArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpsertArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpsertArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpsertArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpsertWithWhereUniqueInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &UserCreateInput {
+        UserCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: UserCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpsertWithWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpsertWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpsertWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpsertWithWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpsertWithWhereUniqueInputTrait for UserUpsertWithWhereUniqueInput { }
+
+impl AsInterface for UserUpsertWithWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpsertWithWhereUniqueInput> for Value {
+    fn from(value: UserUpsertWithWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpsertWithWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpsertWithWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpsertWithWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpsertWithWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpsertWithWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpsertWithWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserUpsertWithWhereUniqueWithoutPostsInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &UserCreateInput {
+        UserCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: UserCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &UserUpdateInput {
+        UserUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: UserUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &UserWhereUniqueInput {
+        UserWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: UserWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct UserUpsertWithWhereUniqueWithoutPostsInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserUpsertWithWhereUniqueWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserUpsertWithWhereUniqueWithoutPostsInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserUpsertWithWhereUniqueWithoutPostsInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserUpsertWithWhereUniqueWithoutPostsInputTrait for UserUpsertWithWhereUniqueWithoutPostsInput { }
+
+impl AsInterface for UserUpsertWithWhereUniqueWithoutPostsInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserUpsertWithWhereUniqueWithoutPostsInput> for Value {
+    fn from(value: UserUpsertWithWhereUniqueWithoutPostsInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserUpsertWithWhereUniqueWithoutPostsInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserUpsertWithWhereUniqueWithoutPostsInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserUpsertWithWhereUniqueWithoutPostsInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserUpsertWithWhereUniqueWithoutPostsInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserUpsertWithWhereUniqueWithoutPostsInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserUpsertWithWhereUniqueWithoutPostsInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserWhereInputTrait: Interface {
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn and(&self) -> Option<Vec<&UserWhereInput>> {
+        Some(Vec::<&UserWhereInput>::from_value_ref_vec(self.inner().get("AND")?).unwrap())
+    }
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_and(&mut self, new_value: Option<Vec<UserWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("AND".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("AND");
+            },
+        }
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn not(&self) -> Option<&UserWhereInput> {
+        Some(UserWhereInput::from_value_ref(self.inner().get("NOT")?).unwrap())
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_not(&mut self, new_value: Option<UserWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("NOT".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("NOT");
+            },
+        }
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn or(&self) -> Option<Vec<&UserWhereInput>> {
+        Some(Vec::<&UserWhereInput>::from_value_ref_vec(self.inner().get("OR")?).unwrap())
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_or(&mut self, new_value: Option<Vec<UserWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("OR".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("OR");
+            },
+        }
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn balance(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("balance")?).unwrap())
+    }
+    /// ## Balance
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_balance(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("balance".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("balance");
+            },
+        }
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn birthday(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("birthday")?).unwrap())
+    }
+    /// ## Birthday
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_birthday(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("birthday".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("birthday");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Posts
+    ///
+    /// This synthesized field doesn't have a description.
+    fn posts(&self) -> Option<&PostListRelationFilter> {
+        Some(PostListRelationFilter::from_value_ref(self.inner().get("posts")?).unwrap())
+    }
+    /// ## Posts
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_posts(&mut self, new_value: Option<PostListRelationFilter>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("posts".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("posts");
+            },
+        }
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sex(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("sex")?).unwrap())
+    }
+    /// ## Sex
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sex(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("sex".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("sex");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserWhereInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserWhereInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserWhereInputTrait for UserWhereInput { }
+
+impl AsInterface for UserWhereInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserWhereInput> for Value {
+    fn from(value: UserWhereInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserWhereInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserWhereInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserWhereInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserWhereInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserWhereInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserWhereInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait UserWhereUniqueInputTrait: Interface {
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn email(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("email")?).unwrap())
+    }
+    /// ## Email
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_email(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("email".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("email");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct UserWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for UserWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &UserWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for UserWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl UserWhereUniqueInputTrait for UserWhereUniqueInput { }
+
+impl AsInterface for UserWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<UserWhereUniqueInput> for Value {
+    fn from(value: UserWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for UserWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&UserWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const UserWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for UserWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for UserWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for UserWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+#[repr(transparent)]
+pub struct PostPathArguments {
+    request: Request,
+}
+
+impl PostPathArguments {
+
+    pub fn id(&self) -> Result<&str> {
+        Ok(self.request.captures()?.get("id").unwrap().as_str())
+    }
+}
+
+impl ExtractFromRequest for PostPathArguments {
+    fn extract(request: &Request) -> Self {
+        PostPathArguments {
+            request: request.clone(),
+        }
+    }
+}
+
+pub struct Teo {
+    pub(crate) ctx: transaction::Ctx,
+}
+
+impl From<transaction::Ctx> for Teo {
+    fn from(value: transaction::Ctx) -> Self {
+        Self { ctx: value }
+    }
+}
+
+impl Teo {
+
+    pub async fn transaction<F, C, Fut, R>(&self, f: F) -> Result<R> where
+        F: Fn(C) -> Fut,
+        C: for <'a> From<&'a transaction::Ctx>,
+        Fut: Future<Output = Result<R>> {
+        Ok(self.ctx.run_transaction(f).await?)
+    }
+    
+    pub fn post(&self) -> PostModel {
+        PostModel { ctx: self.ctx.model_ctx_for_model_at_path(&vec!["Post".to_owned()]).unwrap() }
+    }
+    pub fn user(&self) -> UserModel {
+        UserModel { ctx: self.ctx.model_ctx_for_model_at_path(&vec!["User".to_owned()]).unwrap() }
+    }
+}
+
+
+impl ExtractFromTransactionCtx for Teo {
+    fn extract(ctx: &transaction::Ctx) -> Self {
+        Teo {
+            ctx: ctx.clone(),
+        }
+    }
+}
+
+impl ExtractFromRequest for Teo {
+    fn extract(request: &Request) -> Self {
+        Teo {
+            ctx: request.transaction_ctx().clone(),
+        }
+    }
+}
+
+impl ExtractFromPipelineCtx for Teo {
+    fn extract(ctx: &pipeline::Ctx) -> Self {
+        Teo {
+            ctx: ctx.transaction_ctx().clone(),
+        }
+    }
+}
@@ -0,0 +1,4511 @@
+import Foundation
+
+public protocol Reflectable : CustomStringConvertible { }
+
+extension Reflectable {
+
+    public var description: String {
+        let mirror = Mirror(reflecting: self)
+        var str = "\(mirror.subjectType)("
+        var first = true
+        for (label, value) in mirror.children {
+            if let label = label {
+                if first {
+                    first = false
+                } else {
+                    str += ", "
+                }
+                str += label
+                str += ": "
+                str += "\(value)"
+            }
+        }
+        str += ")"
+        return str
+    }
+}
+
+public struct TeoError: Error, LocalizedError, Decodable {
+    public let type: String
+    public let message: String
+    public let errors: Dictionary<String, String>?
+}
+
+public struct AnyCodable: Codable {
+    public init(from: any Decoder) throws {
+        fatalError()
+    }
+    public init(value: any Codable) {
+        self.value = value
+    }
+    static func fromValue(_ value: Codable?) -> Self? {
+        if let value {
+            return Self(value: value)
+        } else {
+            return nil
+        }
+    }
+    let value: any Codable
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(value)
+    }
+}
+
+public struct Null: Codable {
+    fileprivate init() { }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encodeNil()
+    }
+}
+
+public let null = Null()
+
+/// A range of values, encoded as `{"start": start, "end": end, "closed": closed}`.
+public struct TeoRange<T: Codable>: Codable {
+    public let start: T
+    public let end: T
+    public let closed: Bool
+    public init(start: T, end: T, closed: Bool = false) {
+        self.start = start
+        self.end = end
+        self.closed = closed
+    }
+}
+
+/// A tuple of 1 item, encoded as a JSON array.
+public struct TeoTuple1<T0: Codable>: Codable {
+    public let item0: T0
+    public init(_ item0: T0) {
+        self.item0 = item0
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+    }
+}
+
+/// A tuple of 2 items, encoded as a JSON array.
+public struct TeoTuple2<T0: Codable, T1: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public init(_ item0: T0, _ item1: T1) {
+        self.item0 = item0
+        self.item1 = item1
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+    }
+}
+
+/// A tuple of 3 items, encoded as a JSON array.
+public struct TeoTuple3<T0: Codable, T1: Codable, T2: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public init(_ item0: T0, _ item1: T1, _ item2: T2) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+    }
+}
+
+/// A tuple of 4 items, encoded as a JSON array.
+public struct TeoTuple4<T0: Codable, T1: Codable, T2: Codable, T3: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public let item3: T3
+    public init(_ item0: T0, _ item1: T1, _ item2: T2, _ item3: T3) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+        self.item3 = item3
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+        self.item3 = try container.decode(T3.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+        try container.encode(item3)
+    }
+}
+
+/// A tuple of 5 items, encoded as a JSON array.
+public struct TeoTuple5<T0: Codable, T1: Codable, T2: Codable, T3: Codable, T4: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public let item3: T3
+    public let item4: T4
+    public init(_ item0: T0, _ item1: T1, _ item2: T2, _ item3: T3, _ item4: T4) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+        self.item3 = item3
+        self.item4 = item4
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+        self.item3 = try container.decode(T3.self)
+        self.item4 = try container.decode(T4.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+        try container.encode(item3)
+        try container.encode(item4)
+    }
+}
+
+/// A tuple of 6 items, encoded as a JSON array.
+public struct TeoTuple6<T0: Codable, T1: Codable, T2: Codable, T3: Codable, T4: Codable, T5: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public let item3: T3
+    public let item4: T4
+    public let item5: T5
+    public init(_ item0: T0, _ item1: T1, _ item2: T2, _ item3: T3, _ item4: T4, _ item5: T5) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+        self.item3 = item3
+        self.item4 = item4
+        self.item5 = item5
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+        self.item3 = try container.decode(T3.self)
+        self.item4 = try container.decode(T4.self)
+        self.item5 = try container.decode(T5.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+        try container.encode(item3)
+        try container.encode(item4)
+        try container.encode(item5)
+    }
+}
+
+/// A tuple of 7 items, encoded as a JSON array.
+public struct TeoTuple7<T0: Codable, T1: Codable, T2: Codable, T3: Codable, T4: Codable, T5: Codable, T6: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public let item3: T3
+    public let item4: T4
+    public let item5: T5
+    public let item6: T6
+    public init(_ item0: T0, _ item1: T1, _ item2: T2, _ item3: T3, _ item4: T4, _ item5: T5, _ item6: T6) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+        self.item3 = item3
+        self.item4 = item4
+        self.item5 = item5
+        self.item6 = item6
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+        self.item3 = try container.decode(T3.self)
+        self.item4 = try container.decode(T4.self)
+        self.item5 = try container.decode(T5.self)
+        self.item6 = try container.decode(T6.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+        try container.encode(item3)
+        try container.encode(item4)
+        try container.encode(item5)
+        try container.encode(item6)
+    }
+}
+
+/// A tuple of 8 items, encoded as a JSON array.
+public struct TeoTuple8<T0: Codable, T1: Codable, T2: Codable, T3: Codable, T4: Codable, T5: Codable, T6: Codable, T7: Codable>: Codable {
+    public let item0: T0
+    public let item1: T1
+    public let item2: T2
+    public let item3: T3
+    public let item4: T4
+    public let item5: T5
+    public let item6: T6
+    public let item7: T7
+    public init(_ item0: T0, _ item1: T1, _ item2: T2, _ item3: T3, _ item4: T4, _ item5: T5, _ item6: T6, _ item7: T7) {
+        self.item0 = item0
+        self.item1 = item1
+        self.item2 = item2
+        self.item3 = item3
+        self.item4 = item4
+        self.item5 = item5
+        self.item6 = item6
+        self.item7 = item7
+    }
+    public init(from decoder: any Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.item0 = try container.decode(T0.self)
+        self.item1 = try container.decode(T1.self)
+        self.item2 = try container.decode(T2.self)
+        self.item3 = try container.decode(T3.self)
+        self.item4 = try container.decode(T4.self)
+        self.item5 = try container.decode(T5.self)
+        self.item6 = try container.decode(T6.self)
+        self.item7 = try container.decode(T7.self)
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(item0)
+        try container.encode(item1)
+        try container.encode(item2)
+        try container.encode(item3)
+        try container.encode(item4)
+        try container.encode(item5)
+        try container.encode(item6)
+        try container.encode(item7)
+    }
+}
+
+struct FormRequestBuilder {
+    private let boundary: String = UUID().uuidString
+    var httpBody = Data()
+
+    mutating func addTextField(named name: String, value: String) {
+        httpBody.append(textFormField(named: name, value: value).data(using: .utf8)!)
+    }
+
+    private func textFormField(named name: String, value: String) -> String {
+        var fieldString = "--\(boundary)\r\n"
+        fieldString += "Content-Disposition: form-data; name=\"\(name)\"\r\n"
+        fieldString += "Content-Type: text/plain; charset=utf8\r\n"
+        fieldString += "Content-Transfer-Encoding: 8bit\r\n"
+        fieldString += "\r\n"
+        fieldString += "\(value)\r\n"
+
+        return fieldString
+    }
+
+    mutating func addDataField(named name: String, filename: String, data: Data, mimeType: String? = nil) {
+        httpBody.append(dataFormField(named: name, filename: filename, data: data, mimeType: mimeType))
+    }
+
+    private func dataFormField(named name: String,
+                               filename: String,
+                               data: Data,
+                               mimeType: String? = nil) -> Data {
+        var fieldData = Data()
+
+        fieldData.append("--\(boundary)\r\n".data(using: .utf8)!)
+        fieldData.append("Content-Disposition: form-data; name=\"\(name)\"; filename=\"\(filename)\"\r\n".data(using: .utf8)!)
+        if let mimeType {
+            fieldData.append("Content-Type: \(mimeType)\r\n".data(using: .utf8)!)
+        }
+        fieldData.append("\r\n".data(using: .utf8)!)
+        fieldData.append(data)
+        fieldData.append("\r\n".data(using: .utf8)!)
+
+        return fieldData as Data
+    }
+}
+
+public struct File {
+    public let url: URL
+}
+
+private func formRequest<O: Decodable>(_ method: String, _ path: String, _ input: Dictionary<String, Any>, _ headers: Dictionary<String, String>? = nil, _ query: String? = nil) async throws -> O {
+    let url = URL(string: "http://127.0.0.1:5050/" + path)!
+    var request = URLRequest(url: url)
+    if let headers {
+        headers.forEach { (key, value) in
+            request.setValue(value, forHTTPHeaderField: key)
+        }
+    }
+    request.httpMethod = method
+    var builder = FormRequestBuilder()
+    try input.forEach { (key, value) in
+        if let value = value as? String {
+            builder.addTextField(named: key, value: value)
+        } else if let value = value as? File {
+            builder.addDataField(named: key, filename: value.url.lastPathComponent, data: try Data(contentsOf: value.url), mimeType: nil)
+        }
+    }
+    request.httpBody = builder.httpBody
+    let (data, response) = try await URLSession.shared.data(for: request)
+    guard response is HTTPURLResponse else { fatalError("response format is unexpected") }
+    return try JSONDecoder().decode(O.self, from: data)
+}
+
+private func jsonRequest<I: Encodable, O: Decodable>(_ method: String, _ path: String, _ input: I, _ headers: Dictionary<String, String>? = nil, _ query: String? = nil) async throws -> O {
+    let url = URL(string: "http://127.0.0.1:5050/" + path)!
+    var request = URLRequest(url: url)
+    request.setValue("application/json", forHTTPHeaderField: "content-type")
+    if let headers {
+        headers.forEach { (key, value) in
+            request.setValue(value, forHTTPHeaderField: key)
+        }
+    }
+    request.httpMethod = method
+    request.httpBody = try JSONEncoder().encode(input)
+    let (data, response) = try await URLSession.shared.data(for: request)
+    guard response is HTTPURLResponse else { fatalError("response format is unexpected") }
+    return try JSONDecoder().decode(O.self, from: data)
+}
+
+
+/// ## Account scalar fields
+///
+/// This synthesized enum doesn't have a description.
+public enum AccountScalarFields: String, Codable {
+
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case id = "id"
+
+    /// ### Name
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case name = "name"
+
+    /// ### Role
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case role = "role"
+}
+/// ## Account serializable scalar fields
+///
+/// This synthesized enum doesn't have a description.
+public enum AccountSerializableScalarFields: String, Codable {
+
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case id = "id"
+
+    /// ### Name
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case name = "name"
+
+    /// ### Role
+    ///
+    /// This synthesized enum member doesn't have a description.
+    case role = "role"
+}
+/**
+ * **Account**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class Account : Codable, Reflectable {
+    public let role: admin.Role?
+    public let name: String?
+    public let id: Int32?
+    public init(
+        role: admin.Role? = nil,
+        name: String? = nil,
+        id: Int32? = nil
+    ) {
+        self.role = role
+        self.name = name
+        self.id = id
+    }
+}
+/**
+ * **Account aggregate args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountAggregateArgs : Codable, Reflectable {
+    public let _avg: AccountAvgAggregateInputType?
+    public let _count: AccountCountAggregateInputType?
+    public let _max: AccountMaxAggregateInputType?
+    public let _min: AccountMinAggregateInputType?
+    public let _sum: AccountSumAggregateInputType?
+    public let cursor: AccountWhereUniqueInput?
+    public let distinct: AccountSerializableScalarFields?
+    public let orderBy: Array<AccountOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: AccountWhereInput?
+    public init(
+        _avg: AccountAvgAggregateInputType? = nil,
+        _count: AccountCountAggregateInputType? = nil,
+        _max: AccountMaxAggregateInputType? = nil,
+        _min: AccountMinAggregateInputType? = nil,
+        _sum: AccountSumAggregateInputType? = nil,
+        cursor: AccountWhereUniqueInput? = nil,
+        distinct: AccountSerializableScalarFields? = nil,
+        orderBy: Array<AccountOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: AccountWhereInput? = nil
+    ) {
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+        self.cursor = cursor
+        self.distinct = distinct
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **Account aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountAggregateResult : Codable, Reflectable {
+    public let _avg: AccountAvgAggregateResult?
+    public let _count: AccountCountAggregateResult?
+    public let _max: AccountMaxAggregateResult?
+    public let _min: AccountMinAggregateResult?
+    public let _sum: AccountSumAggregateResult?
+    public init(
+        _avg: AccountAvgAggregateResult? = nil,
+        _count: AccountCountAggregateResult? = nil,
+        _max: AccountMaxAggregateResult? = nil,
+        _min: AccountMinAggregateResult? = nil,
+        _sum: AccountSumAggregateResult? = nil
+    ) {
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+    }
+}
+/**
+ * **Account args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountArgs : Codable, Reflectable {
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account avg aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountAvgAggregateInputType : Codable, Reflectable {
+    public let id: Bool?
+    public init(
+        id: Bool? = nil
+    ) {
+        self.id = id
+    }
+}
+/**
+ * **Account avg aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountAvgAggregateResult : Codable, Reflectable {
+    public let id: Double?
+    public init(
+        id: Double? = nil
+    ) {
+        self.id = id
+    }
+}
+/**
+ * **Account connect or create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountConnectOrCreateInput : Codable, Reflectable {
+    public let `where`: AccountWhereUniqueInput
+    public let create: AccountCreateInput
+    public init(
+        `where`: AccountWhereUniqueInput,
+        create: AccountCreateInput
+    ) {
+        self.where = `where`
+        self.create = create
+    }
+}
+/**
+ * **Account copy args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountCopyArgs : Codable, Reflectable {
+    public let `where`: AccountWhereUniqueInput
+    public let copy: AccountUpdateInput
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        `where`: AccountWhereUniqueInput,
+        copy: AccountUpdateInput,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.where = `where`
+        self.copy = copy
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account copy many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountCopyManyArgs : Codable, Reflectable {
+    public let `where`: AccountWhereInput
+    public let copy: AccountUpdateInput
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        `where`: AccountWhereInput,
+        copy: AccountUpdateInput,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.where = `where`
+        self.copy = copy
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account count aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountCountAggregateInputType : Codable, Reflectable {
+    public let _all: Bool?
+    public let id: Bool?
+    public let name: Bool?
+    public let role: Bool?
+    public init(
+        _all: Bool? = nil,
+        id: Bool? = nil,
+        name: Bool? = nil,
+        role: Bool? = nil
+    ) {
+        self._all = _all
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account count aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountCountAggregateResult : Codable, Reflectable {
+    public let _all: Int64?
+    public let id: Int64?
+    public let name: Int64?
+    public let role: Int64?
+    public init(
+        _all: Int64? = nil,
+        id: Int64? = nil,
+        name: Int64? = nil,
+        role: Int64? = nil
+    ) {
+        self._all = _all
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account count args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountCountArgs : Codable, Reflectable {
+    public let cursor: AccountWhereUniqueInput?
+    public let distinct: AccountSerializableScalarFields?
+    public let orderBy: Array<AccountOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let select: AccountCountAggregateInputType?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: AccountWhereInput?
+    public init(
+        cursor: AccountWhereUniqueInput? = nil,
+        distinct: AccountSerializableScalarFields? = nil,
+        orderBy: Array<AccountOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        select: AccountCountAggregateInputType? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: AccountWhereInput? = nil
+    ) {
+        self.cursor = cursor
+        self.distinct = distinct
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.select = select
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **Account create args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountCreateArgs : Codable, Reflectable {
+    public let create: AccountCreateInput
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        create: AccountCreateInput,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.create = create
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountCreateInput : Codable, Reflectable {
+    public let role: admin.Role
+    public let name: String
+    public init(
+        role: admin.Role,
+        name: String
+    ) {
+        self.role = role
+        self.name = name
+    }
+}
+/**
+ * **Account create many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountCreateManyArgs : Codable, Reflectable {
+    public let create: Array<AccountCreateInput>
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        create: Array<AccountCreateInput>,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.create = create
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account create nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountCreateNestedManyInput : Codable, Reflectable {
+    public let connect: Array<AccountWhereUniqueInput>?
+    public let connectOrCreate: Array<AccountConnectOrCreateInput>?
+    public let create: Array<AccountCreateInput>?
+    public init(
+        connect: Array<AccountWhereUniqueInput>? = nil,
+        connectOrCreate: Array<AccountConnectOrCreateInput>? = nil,
+        create: Array<AccountCreateInput>? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+    }
+}
+/**
+ * **Account create nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountCreateNestedOneInput : Codable, Reflectable {
+    public let connect: AccountWhereUniqueInput?
+    public let connectOrCreate: AccountConnectOrCreateInput?
+    public let create: AccountCreateInput?
+    public init(
+        connect: AccountWhereUniqueInput? = nil,
+        connectOrCreate: AccountConnectOrCreateInput? = nil,
+        create: AccountCreateInput? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+    }
+}
+/**
+ * **Account delete args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountDeleteArgs : Codable, Reflectable {
+    public let `where`: AccountWhereUniqueInput
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        `where`: AccountWhereUniqueInput,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.where = `where`
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account delete many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountDeleteManyArgs : Codable, Reflectable {
+    public let `where`: AccountWhereInput
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        `where`: AccountWhereInput,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.where = `where`
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account find first args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountFindFirstArgs : Codable, Reflectable {
+    public let cursor: AccountWhereUniqueInput?
+    public let distinct: AccountSerializableScalarFields?
+    public let include: AccountInclude?
+    public let orderBy: Array<AccountOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let select: AccountSelect?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: AccountWhereInput?
+    public init(
+        cursor: AccountWhereUniqueInput? = nil,
+        distinct: AccountSerializableScalarFields? = nil,
+        include: AccountInclude? = nil,
+        orderBy: Array<AccountOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        select: AccountSelect? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: AccountWhereInput? = nil
+    ) {
+        self.cursor = cursor
+        self.distinct = distinct
+        self.include = include
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.select = select
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **Account find many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountFindManyArgs : Codable, Reflectable {
+    public let cursor: AccountWhereUniqueInput?
+    public let distinct: AccountSerializableScalarFields?
+    public let include: AccountInclude?
+    public let orderBy: Array<AccountOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let select: AccountSelect?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: AccountWhereInput?
+    public init(
+        cursor: AccountWhereUniqueInput? = nil,
+        distinct: AccountSerializableScalarFields? = nil,
+        include: AccountInclude? = nil,
+        orderBy: Array<AccountOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        select: AccountSelect? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: AccountWhereInput? = nil
+    ) {
+        self.cursor = cursor
+        self.distinct = distinct
+        self.include = include
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.select = select
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **Account find unique args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountFindUniqueArgs : Codable, Reflectable {
+    public let `where`: AccountWhereUniqueInput
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        `where`: AccountWhereUniqueInput,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.where = `where`
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account group by args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountGroupByArgs : Codable, Reflectable {
+    public let by: Array<AccountSerializableScalarFields>
+    public let _avg: AccountAvgAggregateInputType?
+    public let _count: AccountCountAggregateInputType?
+    public let _max: AccountMaxAggregateInputType?
+    public let _min: AccountMinAggregateInputType?
+    public let _sum: AccountSumAggregateInputType?
+    public let cursor: AccountWhereUniqueInput?
+    public let distinct: AccountSerializableScalarFields?
+    public let having: AccountScalarWhereWithAggregatesInput?
+    public let orderBy: Array<AccountOrderByInput>?
+    public let pageNumber: Int64?
+    public let pageSize: Int64?
+    public let skip: Int64?
+    public let take: Int64?
+    public let `where`: AccountWhereInput?
+    public init(
+        by: Array<AccountSerializableScalarFields>,
+        _avg: AccountAvgAggregateInputType? = nil,
+        _count: AccountCountAggregateInputType? = nil,
+        _max: AccountMaxAggregateInputType? = nil,
+        _min: AccountMinAggregateInputType? = nil,
+        _sum: AccountSumAggregateInputType? = nil,
+        cursor: AccountWhereUniqueInput? = nil,
+        distinct: AccountSerializableScalarFields? = nil,
+        having: AccountScalarWhereWithAggregatesInput? = nil,
+        orderBy: Array<AccountOrderByInput>? = nil,
+        pageNumber: Int64? = nil,
+        pageSize: Int64? = nil,
+        skip: Int64? = nil,
+        take: Int64? = nil,
+        `where`: AccountWhereInput? = nil
+    ) {
+        self.by = by
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+        self.cursor = cursor
+        self.distinct = distinct
+        self.having = having
+        self.orderBy = orderBy
+        self.pageNumber = pageNumber
+        self.pageSize = pageSize
+        self.skip = skip
+        self.take = take
+        self.where = `where`
+    }
+}
+/**
+ * **Account group by result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountGroupByResult : Codable, Reflectable {
+    public let _avg: AccountAvgAggregateResult?
+    public let _count: AccountCountAggregateResult?
+    public let _max: AccountMaxAggregateResult?
+    public let _min: AccountMinAggregateResult?
+    public let _sum: AccountSumAggregateResult?
+    public let id: Int32?
+    public let name: String?
+    public let role: admin.Role?
+    public init(
+        _avg: AccountAvgAggregateResult? = nil,
+        _count: AccountCountAggregateResult? = nil,
+        _max: AccountMaxAggregateResult? = nil,
+        _min: AccountMinAggregateResult? = nil,
+        _sum: AccountSumAggregateResult? = nil,
+        id: Int32? = nil,
+        name: String? = nil,
+        role: admin.Role? = nil
+    ) {
+        self._avg = _avg
+        self._count = _count
+        self._max = _max
+        self._min = _min
+        self._sum = _sum
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account include**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountInclude : Codable, Reflectable {
+    public init(
+    ) {
+    }
+}
+/**
+ * **Account list relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountListRelationFilter : Codable, Reflectable {
+    public let every: AccountWhereInput?
+    public let none: AccountWhereInput?
+    public let some: AccountWhereInput?
+    public init(
+        every: AccountWhereInput? = nil,
+        none: AccountWhereInput? = nil,
+        some: AccountWhereInput? = nil
+    ) {
+        self.every = every
+        self.none = none
+        self.some = some
+    }
+}
+/**
+ * **Account max aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountMaxAggregateInputType : Codable, Reflectable {
+    public let id: Bool?
+    public let name: Bool?
+    public let role: Bool?
+    public init(
+        id: Bool? = nil,
+        name: Bool? = nil,
+        role: Bool? = nil
+    ) {
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account max aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountMaxAggregateResult : Codable, Reflectable {
+    public let id: Int32?
+    public let name: String?
+    public let role: admin.Role?
+    public init(
+        id: Int32? = nil,
+        name: String? = nil,
+        role: admin.Role? = nil
+    ) {
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account min aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountMinAggregateInputType : Codable, Reflectable {
+    public let id: Bool?
+    public let name: Bool?
+    public let role: Bool?
+    public init(
+        id: Bool? = nil,
+        name: Bool? = nil,
+        role: Bool? = nil
+    ) {
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account min aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountMinAggregateResult : Codable, Reflectable {
+    public let id: Int32?
+    public let name: String?
+    public let role: admin.Role?
+    public init(
+        id: Int32? = nil,
+        name: String? = nil,
+        role: admin.Role? = nil
+    ) {
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+public enum AccountOrderByInput: Codable {
+    case id(std.Sort)
+    case name(std.Sort)
+    case role(std.Sort)
+    enum CodingKeys: String, CodingKey {
+        case id = "id"
+        case name = "name"
+        case role = "role"
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .id(let a0):
+            try container.encode(a0, forKey: .id)
+        case .name(let a0):
+            try container.encode(a0, forKey: .name)
+        case .role(let a0):
+            try container.encode(a0, forKey: .role)
+        }
+    }
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        for key in container.allKeys {
+            switch key {
+            case .id:
+                self = Self.id(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .name:
+                self = Self.name(try container.decode(std.Sort.self, forKey: key))
+                return
+            case .role:
+                self = Self.role(try container.decode(std.Sort.self, forKey: key))
+                return
+            }
+        }
+        fatalError("invalid input for enum value decoder")
+    }
+}
+/**
+ * **Account relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountRelationFilter : Codable, Reflectable {
+    public let `is`: AccountWhereInput?
+    public let isNot: AccountWhereInput?
+    public init(
+        `is`: AccountWhereInput? = nil,
+        isNot: AccountWhereInput? = nil
+    ) {
+        self.is = `is`
+        self.isNot = isNot
+    }
+}
+/**
+ * **Account scalar update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountScalarUpdateInput : Codable, Reflectable {
+    public let id: Int32?
+    public let name: String?
+    public let role: admin.Role?
+    public init(
+        id: Int32? = nil,
+        name: String? = nil,
+        role: admin.Role? = nil
+    ) {
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account scalar where with aggregates input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountScalarWhereWithAggregatesInput : Codable, Reflectable {
+    public let AND: Array<AccountWhereInput>?
+    public let NOT: AccountWhereInput?
+    public let OR: Array<AccountWhereInput>?
+    public let id: IntOrStdIntNumberWithAggregatesFilterInt?
+    public let name: StringOrStdStringWithAggregatesFilter?
+    public let role: AdminRoleOrStdEnumWithAggregatesFilterAdminRole?
+    public init(
+        AND: Array<AccountWhereInput>? = nil,
+        NOT: AccountWhereInput? = nil,
+        OR: Array<AccountWhereInput>? = nil,
+        id: IntOrStdIntNumberWithAggregatesFilterInt? = nil,
+        name: StringOrStdStringWithAggregatesFilter? = nil,
+        role: AdminRoleOrStdEnumWithAggregatesFilterAdminRole? = nil
+    ) {
+        self.AND = AND
+        self.NOT = NOT
+        self.OR = OR
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account select**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountSelect : Codable, Reflectable {
+    public let id: Bool?
+    public let name: Bool?
+    public let role: Bool?
+    public init(
+        id: Bool? = nil,
+        name: Bool? = nil,
+        role: Bool? = nil
+    ) {
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account sign in args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountSignInArgs : Codable, Reflectable {
+    public init(
+    ) {
+    }
+}
+/**
+ * **Account sign in checker companions**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountSignInCheckerCompanions : Codable, Reflectable {
+    public init(
+    ) {
+    }
+}
+/**
+ * **Account sign in checker ids**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountSignInCheckerIds : Codable, Reflectable {
+    public init(
+    ) {
+    }
+}
+/**
+ * **Account sign in input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountSignInInput : Codable, Reflectable {
+    public let credentials: AccountSignInArgs
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        credentials: AccountSignInArgs,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.credentials = credentials
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account sum aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountSumAggregateInputType : Codable, Reflectable {
+    public let id: Bool?
+    public init(
+        id: Bool? = nil
+    ) {
+        self.id = id
+    }
+}
+/**
+ * **Account sum aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountSumAggregateResult : Codable, Reflectable {
+    public let id: Int64?
+    public init(
+        id: Int64? = nil
+    ) {
+        self.id = id
+    }
+}
+/**
+ * **Account update args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountUpdateArgs : Codable, Reflectable {
+    public let `where`: AccountWhereUniqueInput
+    public let update: AccountUpdateInput
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        `where`: AccountWhereUniqueInput,
+        update: AccountUpdateInput,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.where = `where`
+        self.update = update
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountUpdateInput : Codable, Reflectable {
+    public let name: String?
+    public let role: admin.Role?
+    public init(
+        name: String? = nil,
+        role: admin.Role? = nil
+    ) {
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account update many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountUpdateManyArgs : Codable, Reflectable {
+    public let `where`: AccountWhereInput
+    public let update: AccountUpdateInput
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        `where`: AccountWhereInput,
+        update: AccountUpdateInput,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.where = `where`
+        self.update = update
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account update many with where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountUpdateManyWithWhereInput : Codable, Reflectable {
+    public let `where`: AccountWhereInput
+    public let update: AccountUpdateInput
+    public init(
+        `where`: AccountWhereInput,
+        update: AccountUpdateInput
+    ) {
+        self.where = `where`
+        self.update = update
+    }
+}
+/**
+ * **Account update nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountUpdateNestedManyInput : Codable, Reflectable {
+    public let connect: Array<AccountWhereUniqueInput>?
+    public let connectOrCreate: Array<AccountConnectOrCreateInput>?
+    public let create: Array<AccountCreateInput>?
+    public let delete: Array<AccountWhereUniqueInput>?
+    public let deleteMany: Array<AccountWhereInput>?
+    public let disconnect: Array<AccountWhereUniqueInput>?
+    public let set: Array<AccountWhereUniqueInput>?
+    public let update: Array<AccountUpdateWithWhereUniqueInput>?
+    public let updateMany: Array<AccountUpdateManyWithWhereInput>?
+    public let upsert: Array<AccountUpsertWithWhereUniqueInput>?
+    public init(
+        connect: Array<AccountWhereUniqueInput>? = nil,
+        connectOrCreate: Array<AccountConnectOrCreateInput>? = nil,
+        create: Array<AccountCreateInput>? = nil,
+        delete: Array<AccountWhereUniqueInput>? = nil,
+        deleteMany: Array<AccountWhereInput>? = nil,
+        disconnect: Array<AccountWhereUniqueInput>? = nil,
+        set: Array<AccountWhereUniqueInput>? = nil,
+        update: Array<AccountUpdateWithWhereUniqueInput>? = nil,
+        updateMany: Array<AccountUpdateManyWithWhereInput>? = nil,
+        upsert: Array<AccountUpsertWithWhereUniqueInput>? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+        self.delete = delete
+        self.deleteMany = deleteMany
+        self.disconnect = disconnect
+        self.set = set
+        self.update = update
+        self.updateMany = updateMany
+        self.upsert = upsert
+    }
+}
+/**
+ * **Account update nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountUpdateNestedOneInput : Codable, Reflectable {
+    public let connect: AccountWhereUniqueInput?
+    public let connectOrCreate: AccountConnectOrCreateInput?
+    public let create: AccountCreateInput?
+    public let delete: Bool?
+    public let disconnect: Bool?
+    public let set: AccountWhereUniqueInput?
+    public let update: AccountUpdateInput?
+    public let upsert: AccountUpsertWithWhereUniqueInput?
+    public init(
+        connect: AccountWhereUniqueInput? = nil,
+        connectOrCreate: AccountConnectOrCreateInput? = nil,
+        create: AccountCreateInput? = nil,
+        delete: Bool? = nil,
+        disconnect: Bool? = nil,
+        set: AccountWhereUniqueInput? = nil,
+        update: AccountUpdateInput? = nil,
+        upsert: AccountUpsertWithWhereUniqueInput? = nil
+    ) {
+        self.connect = connect
+        self.connectOrCreate = connectOrCreate
+        self.create = create
+        self.delete = delete
+        self.disconnect = disconnect
+        self.set = set
+        self.update = update
+        self.upsert = upsert
+    }
+}
+/**
+ * **Account update with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountUpdateWithWhereUniqueInput : Codable, Reflectable {
+    public let `where`: AccountWhereUniqueInput
+    public let update: AccountUpdateInput
+    public init(
+        `where`: AccountWhereUniqueInput,
+        update: AccountUpdateInput
+    ) {
+        self.where = `where`
+        self.update = update
+    }
+}
+/**
+ * **Account upsert args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountUpsertArgs : Codable, Reflectable {
+    public let `where`: AccountWhereUniqueInput
+    public let update: AccountUpdateInput
+    public let create: AccountCreateInput
+    public let include: AccountInclude?
+    public let select: AccountSelect?
+    public init(
+        `where`: AccountWhereUniqueInput,
+        update: AccountUpdateInput,
+        create: AccountCreateInput,
+        include: AccountInclude? = nil,
+        select: AccountSelect? = nil
+    ) {
+        self.where = `where`
+        self.update = update
+        self.create = create
+        self.include = include
+        self.select = select
+    }
+}
+/**
+ * **Account upsert with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountUpsertWithWhereUniqueInput : Codable, Reflectable {
+    public let `where`: AccountWhereUniqueInput
+    public let update: AccountUpdateInput
+    public let create: AccountCreateInput
+    public init(
+        `where`: AccountWhereUniqueInput,
+        update: AccountUpdateInput,
+        create: AccountCreateInput
+    ) {
+        self.where = `where`
+        self.update = update
+        self.create = create
+    }
+}
+/**
+ * **Account where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountWhereInput : Codable, Reflectable {
+    public let AND: Array<AccountWhereInput>?
+    public let NOT: AccountWhereInput?
+    public let OR: Array<AccountWhereInput>?
+    public let id: IntOrStdFilterInt?
+    public let name: StringOrStdStringFilter?
+    public let role: AdminRoleOrStdEnumFilterAdminRole?
+    public init(
+        AND: Array<AccountWhereInput>? = nil,
+        NOT: AccountWhereInput? = nil,
+        OR: Array<AccountWhereInput>? = nil,
+        id: IntOrStdFilterInt? = nil,
+        name: StringOrStdStringFilter? = nil,
+        role: AdminRoleOrStdEnumFilterAdminRole? = nil
+    ) {
+        self.AND = AND
+        self.NOT = NOT
+        self.OR = OR
+        self.id = id
+        self.name = name
+        self.role = role
+    }
+}
+/**
+ * **Account where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+public class AccountWhereUniqueInput : Codable, Reflectable {
+    public let id: Int32
+    public init(
+        id: Int32
+    ) {
+        self.id = id
+    }
+}
+/// ## IntOrStdIntNumberWithAggregatesFilterInt
+///
+/// One of `Int32`, `std.IntNumberWithAggregatesFilter<Int32>`.
+public enum IntOrStdIntNumberWithAggregatesFilterInt: Codable {
+    case int(Int32)
+    case stdIntNumberWithAggregatesFilterInt(std.IntNumberWithAggregatesFilter<Int32>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Int32.self) {
+            self = .int(value)
+            return
+        }
+        if let value = try? container.decode(std.IntNumberWithAggregatesFilter<Int32>.self) {
+            self = .stdIntNumberWithAggregatesFilterInt(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of IntOrStdIntNumberWithAggregatesFilterInt")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .int(let value):
+            try container.encode(value)
+        case .stdIntNumberWithAggregatesFilterInt(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## StringOrStdStringWithAggregatesFilter
+///
+/// One of `String`, `std.StringWithAggregatesFilter`.
+public enum StringOrStdStringWithAggregatesFilter: Codable {
+    case string(String)
+    case stdStringWithAggregatesFilter(std.StringWithAggregatesFilter)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(String.self) {
+            self = .string(value)
+            return
+        }
+        if let value = try? container.decode(std.StringWithAggregatesFilter.self) {
+            self = .stdStringWithAggregatesFilter(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringWithAggregatesFilter")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .string(let value):
+            try container.encode(value)
+        case .stdStringWithAggregatesFilter(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## AdminRoleOrStdEnumWithAggregatesFilterAdminRole
+///
+/// One of `admin.Role`, `std.EnumWithAggregatesFilter<admin.Role>`.
+public enum AdminRoleOrStdEnumWithAggregatesFilterAdminRole: Codable {
+    case adminRole(admin.Role)
+    case stdEnumWithAggregatesFilterAdminRole(std.EnumWithAggregatesFilter<admin.Role>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(admin.Role.self) {
+            self = .adminRole(value)
+            return
+        }
+        if let value = try? container.decode(std.EnumWithAggregatesFilter<admin.Role>.self) {
+            self = .stdEnumWithAggregatesFilterAdminRole(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of AdminRoleOrStdEnumWithAggregatesFilterAdminRole")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .adminRole(let value):
+            try container.encode(value)
+        case .stdEnumWithAggregatesFilterAdminRole(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## IntOrStdFilterInt
+///
+/// One of `Int32`, `std.Filter<Int32>`.
+public enum IntOrStdFilterInt: Codable {
+    case int(Int32)
+    case stdFilterInt(std.Filter<Int32>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Int32.self) {
+            self = .int(value)
+            return
+        }
+        if let value = try? container.decode(std.Filter<Int32>.self) {
+            self = .stdFilterInt(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of IntOrStdFilterInt")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .int(let value):
+            try container.encode(value)
+        case .stdFilterInt(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## StringOrStdStringFilter
+///
+/// One of `String`, `std.StringFilter`.
+public enum StringOrStdStringFilter: Codable {
+    case string(String)
+    case stdStringFilter(std.StringFilter)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(String.self) {
+            self = .string(value)
+            return
+        }
+        if let value = try? container.decode(std.StringFilter.self) {
+            self = .stdStringFilter(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringFilter")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .string(let value):
+            try container.encode(value)
+        case .stdStringFilter(let value):
+            try container.encode(value)
+        }
+    }
+}
+/// ## AdminRoleOrStdEnumFilterAdminRole
+///
+/// One of `admin.Role`, `std.EnumFilter<admin.Role>`.
+public enum AdminRoleOrStdEnumFilterAdminRole: Codable {
+    case adminRole(admin.Role)
+    case stdEnumFilterAdminRole(std.EnumFilter<admin.Role>)
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(admin.Role.self) {
+            self = .adminRole(value)
+            return
+        }
+        if let value = try? container.decode(std.EnumFilter<admin.Role>.self) {
+            self = .stdEnumFilterAdminRole(value)
+            return
+        }
+        throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of AdminRoleOrStdEnumFilterAdminRole")
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .adminRole(let value):
+            try container.encode(value)
+        case .stdEnumFilterAdminRole(let value):
+            try container.encode(value)
+        }
+    }
+}
+public class AccountDelegate {
+    public let _headers: Dictionary<String, String>?
+    public init(_ headers: Dictionary<String, String>? = nil) {
+        self._headers = headers
+    }
+    public func findUnique(input: AccountFindUniqueArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Account> {
+      return try await jsonRequest("POST", "Account/findUnique", input, headers ?? _headers, nil)
+    }
+    public func findFirst(input: AccountFindFirstArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Account> {
+      return try await jsonRequest("POST", "Account/findFirst", input, headers ?? _headers, nil)
+    }
+    public func findMany(input: AccountFindManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<Account>, std.PagingInfo> {
+      return try await jsonRequest("POST", "Account/findMany", input, headers ?? _headers, nil)
+    }
+    public func create(input: AccountCreateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Account> {
+      return try await jsonRequest("POST", "Account/create", input, headers ?? _headers, nil)
+    }
+    public func update(input: AccountUpdateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Account> {
+      return try await jsonRequest("POST", "Account/update", input, headers ?? _headers, nil)
+    }
+    public func upsert(input: AccountUpsertArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Account> {
+      return try await jsonRequest("POST", "Account/upsert", input, headers ?? _headers, nil)
+    }
+    public func copy(input: AccountCopyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Account> {
+      return try await jsonRequest("POST", "Account/copy", input, headers ?? _headers, nil)
+    }
+    public func delete(input: AccountDeleteArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Account> {
+      return try await jsonRequest("POST", "Account/delete", input, headers ?? _headers, nil)
+    }
+    public func createMany(input: AccountCreateManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<Account>, std.PagingInfo> {
+      return try await jsonRequest("POST", "Account/createMany", input, headers ?? _headers, nil)
+    }
+    public func updateMany(input: AccountUpdateManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<Account>, std.PagingInfo> {
+      return try await jsonRequest("POST", "Account/updateMany", input, headers ?? _headers, nil)
+    }
+    public func copyMany(input: AccountCopyManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<Account>, std.PagingInfo> {
+      return try await jsonRequest("POST", "Account/copyMany", input, headers ?? _headers, nil)
+    }
+    public func deleteMany(input: AccountDeleteManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<Account>, std.PagingInfo> {
+      return try await jsonRequest("POST", "Account/deleteMany", input, headers ?? _headers, nil)
+    }
+    public func count(input: AccountCountArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Int64> {
+      return try await jsonRequest("POST", "Account/count", input, headers ?? _headers, nil)
+    }
+    public func aggregate(input: AccountAggregateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<AccountAggregateResult> {
+      return try await jsonRequest("POST", "Account/aggregate", input, headers ?? _headers, nil)
+    }
+    public func groupBy(input: AccountGroupByArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<AccountGroupByResult> {
+      return try await jsonRequest("POST", "Account/groupBy", input, headers ?? _headers, nil)
+    }
+}
+
+public class Teo {
+    public let _headers: Dictionary<String, String>?
+    lazy var admin: admin.AdminNamespaceDelegate = { admin.AdminNamespaceDelegate(_headers) }();
+    lazy var account: AccountDelegate = { AccountDelegate(_headers) }();
+    public init(_ headers: Dictionary<String, String>? = nil) {
+        self._headers = headers
+    }
+}
+
+public struct admin {
+
+    /// ## Role
+    ///
+    /// This enum doesn't have a description.
+    public enum Role: String, Codable {
+
+        /// ### Owner
+        ///
+        /// This enum member doesn't have a description.
+        case owner = "owner"
+
+        /// ### Editor
+        ///
+        /// This enum member doesn't have a description.
+        case editor = "editor"
+
+        /// ### Viewer
+        ///
+        /// This enum member doesn't have a description.
+        case viewer = "viewer"
+    }
+    /// ## Audit scalar fields
+    ///
+    /// This synthesized enum doesn't have a description.
+    public enum AuditScalarFields: String, Codable {
+
+        /// ### Action
+        ///
+        /// This synthesized enum member doesn't have a description.
+        case action = "action"
+
+        /// ### Created at
+        ///
+        /// This synthesized enum member doesn't have a description.
+        case createdAt = "createdAt"
+
+        /// ### Id
+        ///
+        /// This synthesized enum member doesn't have a description.
+        case id = "id"
+
+        /// ### Role
+        ///
+        /// This synthesized enum member doesn't have a description.
+        case role = "role"
+    }
+    /// ## Audit serializable scalar fields
+    ///
+    /// This synthesized enum doesn't have a description.
+    public enum AuditSerializableScalarFields: String, Codable {
+
+        /// ### Action
+        ///
+        /// This synthesized enum member doesn't have a description.
+        case action = "action"
+
+        /// ### Created at
+        ///
+        /// This synthesized enum member doesn't have a description.
+        case createdAt = "createdAt"
+
+        /// ### Id
+        ///
+        /// This synthesized enum member doesn't have a description.
+        case id = "id"
+
+        /// ### Role
+        ///
+        /// This synthesized enum member doesn't have a description.
+        case role = "role"
+    }
+    /**
+     * **Audit**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class Audit : Codable, Reflectable {
+        public let role: admin.Role?
+        public let id: Int32?
+        public let createdAt: Date?
+        public let action: String?
+        public init(
+            role: admin.Role? = nil,
+            id: Int32? = nil,
+            createdAt: Date? = nil,
+            action: String? = nil
+        ) {
+            self.role = role
+            self.id = id
+            self.createdAt = createdAt
+            self.action = action
+        }
+    }
+    /**
+     * **Audit aggregate args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditAggregateArgs : Codable, Reflectable {
+        public let _avg: admin.AuditAvgAggregateInputType?
+        public let _count: admin.AuditCountAggregateInputType?
+        public let _max: admin.AuditMaxAggregateInputType?
+        public let _min: admin.AuditMinAggregateInputType?
+        public let _sum: admin.AuditSumAggregateInputType?
+        public let cursor: admin.AuditWhereUniqueInput?
+        public let distinct: admin.AuditSerializableScalarFields?
+        public let orderBy: Array<admin.AuditOrderByInput>?
+        public let pageNumber: Int64?
+        public let pageSize: Int64?
+        public let skip: Int64?
+        public let take: Int64?
+        public let `where`: admin.AuditWhereInput?
+        public init(
+            _avg: admin.AuditAvgAggregateInputType? = nil,
+            _count: admin.AuditCountAggregateInputType? = nil,
+            _max: admin.AuditMaxAggregateInputType? = nil,
+            _min: admin.AuditMinAggregateInputType? = nil,
+            _sum: admin.AuditSumAggregateInputType? = nil,
+            cursor: admin.AuditWhereUniqueInput? = nil,
+            distinct: admin.AuditSerializableScalarFields? = nil,
+            orderBy: Array<admin.AuditOrderByInput>? = nil,
+            pageNumber: Int64? = nil,
+            pageSize: Int64? = nil,
+            skip: Int64? = nil,
+            take: Int64? = nil,
+            `where`: admin.AuditWhereInput? = nil
+        ) {
+            self._avg = _avg
+            self._count = _count
+            self._max = _max
+            self._min = _min
+            self._sum = _sum
+            self.cursor = cursor
+            self.distinct = distinct
+            self.orderBy = orderBy
+            self.pageNumber = pageNumber
+            self.pageSize = pageSize
+            self.skip = skip
+            self.take = take
+            self.where = `where`
+        }
+    }
+    /**
+     * **Audit aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditAggregateResult : Codable, Reflectable {
+        public let _avg: admin.AuditAvgAggregateResult?
+        public let _count: admin.AuditCountAggregateResult?
+        public let _max: admin.AuditMaxAggregateResult?
+        public let _min: admin.AuditMinAggregateResult?
+        public let _sum: admin.AuditSumAggregateResult?
+        public init(
+            _avg: admin.AuditAvgAggregateResult? = nil,
+            _count: admin.AuditCountAggregateResult? = nil,
+            _max: admin.AuditMaxAggregateResult? = nil,
+            _min: admin.AuditMinAggregateResult? = nil,
+            _sum: admin.AuditSumAggregateResult? = nil
+        ) {
+            self._avg = _avg
+            self._count = _count
+            self._max = _max
+            self._min = _min
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Audit args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditArgs : Codable, Reflectable {
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit avg aggregate input type**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditAvgAggregateInputType : Codable, Reflectable {
+        public let id: Bool?
+        public init(
+            id: Bool? = nil
+        ) {
+            self.id = id
+        }
+    }
+    /**
+     * **Audit avg aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditAvgAggregateResult : Codable, Reflectable {
+        public let id: Double?
+        public init(
+            id: Double? = nil
+        ) {
+            self.id = id
+        }
+    }
+    /**
+     * **Audit connect or create input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditConnectOrCreateInput : Codable, Reflectable {
+        public let `where`: admin.AuditWhereUniqueInput
+        public let create: admin.AuditCreateInput
+        public init(
+            `where`: admin.AuditWhereUniqueInput,
+            create: admin.AuditCreateInput
+        ) {
+            self.where = `where`
+            self.create = create
+        }
+    }
+    /**
+     * **Audit copy args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditCopyArgs : Codable, Reflectable {
+        public let `where`: admin.AuditWhereUniqueInput
+        public let copy: admin.AuditUpdateInput
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            `where`: admin.AuditWhereUniqueInput,
+            copy: admin.AuditUpdateInput,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.where = `where`
+            self.copy = copy
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit copy many args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditCopyManyArgs : Codable, Reflectable {
+        public let `where`: admin.AuditWhereInput
+        public let copy: admin.AuditUpdateInput
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            `where`: admin.AuditWhereInput,
+            copy: admin.AuditUpdateInput,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.where = `where`
+            self.copy = copy
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit count aggregate input type**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditCountAggregateInputType : Codable, Reflectable {
+        public let _all: Bool?
+        public let action: Bool?
+        public let createdAt: Bool?
+        public let id: Bool?
+        public let role: Bool?
+        public init(
+            _all: Bool? = nil,
+            action: Bool? = nil,
+            createdAt: Bool? = nil,
+            id: Bool? = nil,
+            role: Bool? = nil
+        ) {
+            self._all = _all
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    /**
+     * **Audit count aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditCountAggregateResult : Codable, Reflectable {
+        public let _all: Int64?
+        public let action: Int64?
+        public let createdAt: Int64?
+        public let id: Int64?
+        public let role: Int64?
+        public init(
+            _all: Int64? = nil,
+            action: Int64? = nil,
+            createdAt: Int64? = nil,
+            id: Int64? = nil,
+            role: Int64? = nil
+        ) {
+            self._all = _all
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    /**
+     * **Audit count args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditCountArgs : Codable, Reflectable {
+        public let cursor: admin.AuditWhereUniqueInput?
+        public let distinct: admin.AuditSerializableScalarFields?
+        public let orderBy: Array<admin.AuditOrderByInput>?
+        public let pageNumber: Int64?
+        public let pageSize: Int64?
+        public let select: admin.AuditCountAggregateInputType?
+        public let skip: Int64?
+        public let take: Int64?
+        public let `where`: admin.AuditWhereInput?
+        public init(
+            cursor: admin.AuditWhereUniqueInput? = nil,
+            distinct: admin.AuditSerializableScalarFields? = nil,
+            orderBy: Array<admin.AuditOrderByInput>? = nil,
+            pageNumber: Int64? = nil,
+            pageSize: Int64? = nil,
+            select: admin.AuditCountAggregateInputType? = nil,
+            skip: Int64? = nil,
+            take: Int64? = nil,
+            `where`: admin.AuditWhereInput? = nil
+        ) {
+            self.cursor = cursor
+            self.distinct = distinct
+            self.orderBy = orderBy
+            self.pageNumber = pageNumber
+            self.pageSize = pageSize
+            self.select = select
+            self.skip = skip
+            self.take = take
+            self.where = `where`
+        }
+    }
+    /**
+     * **Audit create args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditCreateArgs : Codable, Reflectable {
+        public let create: admin.AuditCreateInput
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            create: admin.AuditCreateInput,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.create = create
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit create input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditCreateInput : Codable, Reflectable {
+        public let role: admin.Role
+        public let action: String
+        public init(
+            role: admin.Role,
+            action: String
+        ) {
+            self.role = role
+            self.action = action
+        }
+    }
+    /**
+     * **Audit create many args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditCreateManyArgs : Codable, Reflectable {
+        public let create: Array<admin.AuditCreateInput>
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            create: Array<admin.AuditCreateInput>,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.create = create
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit create nested many input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditCreateNestedManyInput : Codable, Reflectable {
+        public let connect: Array<admin.AuditWhereUniqueInput>?
+        public let connectOrCreate: Array<admin.AuditConnectOrCreateInput>?
+        public let create: Array<admin.AuditCreateInput>?
+        public init(
+            connect: Array<admin.AuditWhereUniqueInput>? = nil,
+            connectOrCreate: Array<admin.AuditConnectOrCreateInput>? = nil,
+            create: Array<admin.AuditCreateInput>? = nil
+        ) {
+            self.connect = connect
+            self.connectOrCreate = connectOrCreate
+            self.create = create
+        }
+    }
+    /**
+     * **Audit create nested one input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditCreateNestedOneInput : Codable, Reflectable {
+        public let connect: admin.AuditWhereUniqueInput?
+        public let connectOrCreate: admin.AuditConnectOrCreateInput?
+        public let create: admin.AuditCreateInput?
+        public init(
+            connect: admin.AuditWhereUniqueInput? = nil,
+            connectOrCreate: admin.AuditConnectOrCreateInput? = nil,
+            create: admin.AuditCreateInput? = nil
+        ) {
+            self.connect = connect
+            self.connectOrCreate = connectOrCreate
+            self.create = create
+        }
+    }
+    /**
+     * **Audit delete args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditDeleteArgs : Codable, Reflectable {
+        public let `where`: admin.AuditWhereUniqueInput
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            `where`: admin.AuditWhereUniqueInput,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.where = `where`
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit delete many args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditDeleteManyArgs : Codable, Reflectable {
+        public let `where`: admin.AuditWhereInput
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            `where`: admin.AuditWhereInput,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.where = `where`
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit find first args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditFindFirstArgs : Codable, Reflectable {
+        public let cursor: admin.AuditWhereUniqueInput?
+        public let distinct: admin.AuditSerializableScalarFields?
+        public let include: admin.AuditInclude?
+        public let orderBy: Array<admin.AuditOrderByInput>?
+        public let pageNumber: Int64?
+        public let pageSize: Int64?
+        public let select: admin.AuditSelect?
+        public let skip: Int64?
+        public let take: Int64?
+        public let `where`: admin.AuditWhereInput?
+        public init(
+            cursor: admin.AuditWhereUniqueInput? = nil,
+            distinct: admin.AuditSerializableScalarFields? = nil,
+            include: admin.AuditInclude? = nil,
+            orderBy: Array<admin.AuditOrderByInput>? = nil,
+            pageNumber: Int64? = nil,
+            pageSize: Int64? = nil,
+            select: admin.AuditSelect? = nil,
+            skip: Int64? = nil,
+            take: Int64? = nil,
+            `where`: admin.AuditWhereInput? = nil
+        ) {
+            self.cursor = cursor
+            self.distinct = distinct
+            self.include = include
+            self.orderBy = orderBy
+            self.pageNumber = pageNumber
+            self.pageSize = pageSize
+            self.select = select
+            self.skip = skip
+            self.take = take
+            self.where = `where`
+        }
+    }
+    /**
+     * **Audit find many args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditFindManyArgs : Codable, Reflectable {
+        public let cursor: admin.AuditWhereUniqueInput?
+        public let distinct: admin.AuditSerializableScalarFields?
+        public let include: admin.AuditInclude?
+        public let orderBy: Array<admin.AuditOrderByInput>?
+        public let pageNumber: Int64?
+        public let pageSize: Int64?
+        public let select: admin.AuditSelect?
+        public let skip: Int64?
+        public let take: Int64?
+        public let `where`: admin.AuditWhereInput?
+        public init(
+            cursor: admin.AuditWhereUniqueInput? = nil,
+            distinct: admin.AuditSerializableScalarFields? = nil,
+            include: admin.AuditInclude? = nil,
+            orderBy: Array<admin.AuditOrderByInput>? = nil,
+            pageNumber: Int64? = nil,
+            pageSize: Int64? = nil,
+            select: admin.AuditSelect? = nil,
+            skip: Int64? = nil,
+            take: Int64? = nil,
+            `where`: admin.AuditWhereInput? = nil
+        ) {
+            self.cursor = cursor
+            self.distinct = distinct
+            self.include = include
+            self.orderBy = orderBy
+            self.pageNumber = pageNumber
+            self.pageSize = pageSize
+            self.select = select
+            self.skip = skip
+            self.take = take
+            self.where = `where`
+        }
+    }
+    /**
+     * **Audit find unique args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditFindUniqueArgs : Codable, Reflectable {
+        public let `where`: admin.AuditWhereUniqueInput
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            `where`: admin.AuditWhereUniqueInput,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.where = `where`
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit group by args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditGroupByArgs : Codable, Reflectable {
+        public let by: Array<admin.AuditSerializableScalarFields>
+        public let _avg: admin.AuditAvgAggregateInputType?
+        public let _count: admin.AuditCountAggregateInputType?
+        public let _max: admin.AuditMaxAggregateInputType?
+        public let _min: admin.AuditMinAggregateInputType?
+        public let _sum: admin.AuditSumAggregateInputType?
+        public let cursor: admin.AuditWhereUniqueInput?
+        public let distinct: admin.AuditSerializableScalarFields?
+        public let having: admin.AuditScalarWhereWithAggregatesInput?
+        public let orderBy: Array<admin.AuditOrderByInput>?
+        public let pageNumber: Int64?
+        public let pageSize: Int64?
+        public let skip: Int64?
+        public let take: Int64?
+        public let `where`: admin.AuditWhereInput?
+        public init(
+            by: Array<admin.AuditSerializableScalarFields>,
+            _avg: admin.AuditAvgAggregateInputType? = nil,
+            _count: admin.AuditCountAggregateInputType? = nil,
+            _max: admin.AuditMaxAggregateInputType? = nil,
+            _min: admin.AuditMinAggregateInputType? = nil,
+            _sum: admin.AuditSumAggregateInputType? = nil,
+            cursor: admin.AuditWhereUniqueInput? = nil,
+            distinct: admin.AuditSerializableScalarFields? = nil,
+            having: admin.AuditScalarWhereWithAggregatesInput? = nil,
+            orderBy: Array<admin.AuditOrderByInput>? = nil,
+            pageNumber: Int64? = nil,
+            pageSize: Int64? = nil,
+            skip: Int64? = nil,
+            take: Int64? = nil,
+            `where`: admin.AuditWhereInput? = nil
+        ) {
+            self.by = by
+            self._avg = _avg
+            self._count = _count
+            self._max = _max
+            self._min = _min
+            self._sum = _sum
+            self.cursor = cursor
+            self.distinct = distinct
+            self.having = having
+            self.orderBy = orderBy
+            self.pageNumber = pageNumber
+            self.pageSize = pageSize
+            self.skip = skip
+            self.take = take
+            self.where = `where`
+        }
+    }
+    /**
+     * **Audit group by result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditGroupByResult : Codable, Reflectable {
+        public let _avg: admin.AuditAvgAggregateResult?
+        public let _count: admin.AuditCountAggregateResult?
+        public let _max: admin.AuditMaxAggregateResult?
+        public let _min: admin.AuditMinAggregateResult?
+        public let _sum: admin.AuditSumAggregateResult?
+        public let action: String?
+        public let createdAt: Date?
+        public let id: Int32?
+        public let role: admin.Role?
+        public init(
+            _avg: admin.AuditAvgAggregateResult? = nil,
+            _count: admin.AuditCountAggregateResult? = nil,
+            _max: admin.AuditMaxAggregateResult? = nil,
+            _min: admin.AuditMinAggregateResult? = nil,
+            _sum: admin.AuditSumAggregateResult? = nil,
+            action: String? = nil,
+            createdAt: Date? = nil,
+            id: Int32? = nil,
+            role: admin.Role? = nil
+        ) {
+            self._avg = _avg
+            self._count = _count
+            self._max = _max
+            self._min = _min
+            self._sum = _sum
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    /**
+     * **Audit include**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditInclude : Codable, Reflectable {
+        public init(
+        ) {
+        }
+    }
+    /**
+     * **Audit list relation filter**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditListRelationFilter : Codable, Reflectable {
+        public let every: admin.AuditWhereInput?
+        public let none: admin.AuditWhereInput?
+        public let some: admin.AuditWhereInput?
+        public init(
+            every: admin.AuditWhereInput? = nil,
+            none: admin.AuditWhereInput? = nil,
+            some: admin.AuditWhereInput? = nil
+        ) {
+            self.every = every
+            self.none = none
+            self.some = some
+        }
+    }
+    /**
+     * **Audit max aggregate input type**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditMaxAggregateInputType : Codable, Reflectable {
+        public let action: Bool?
+        public let createdAt: Bool?
+        public let id: Bool?
+        public let role: Bool?
+        public init(
+            action: Bool? = nil,
+            createdAt: Bool? = nil,
+            id: Bool? = nil,
+            role: Bool? = nil
+        ) {
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    /**
+     * **Audit max aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditMaxAggregateResult : Codable, Reflectable {
+        public let action: String?
+        public let createdAt: Date?
+        public let id: Int32?
+        public let role: admin.Role?
+        public init(
+            action: String? = nil,
+            createdAt: Date? = nil,
+            id: Int32? = nil,
+            role: admin.Role? = nil
+        ) {
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    /**
+     * **Audit min aggregate input type**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditMinAggregateInputType : Codable, Reflectable {
+        public let action: Bool?
+        public let createdAt: Bool?
+        public let id: Bool?
+        public let role: Bool?
+        public init(
+            action: Bool? = nil,
+            createdAt: Bool? = nil,
+            id: Bool? = nil,
+            role: Bool? = nil
+        ) {
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    /**
+     * **Audit min aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditMinAggregateResult : Codable, Reflectable {
+        public let action: String?
+        public let createdAt: Date?
+        public let id: Int32?
+        public let role: admin.Role?
+        public init(
+            action: String? = nil,
+            createdAt: Date? = nil,
+            id: Int32? = nil,
+            role: admin.Role? = nil
+        ) {
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    public enum AuditOrderByInput: Codable {
+        case action(std.Sort)
+        case createdAt(std.Sort)
+        case id(std.Sort)
+        case role(std.Sort)
+        enum CodingKeys: String, CodingKey {
+            case action = "action"
+            case createdAt = "createdAt"
+            case id = "id"
+            case role = "role"
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            switch self {
+            case .action(let a0):
+                try container.encode(a0, forKey: .action)
+            case .createdAt(let a0):
+                try container.encode(a0, forKey: .createdAt)
+            case .id(let a0):
+                try container.encode(a0, forKey: .id)
+            case .role(let a0):
+                try container.encode(a0, forKey: .role)
+            }
+        }
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.container(keyedBy: CodingKeys.self)
+            for key in container.allKeys {
+                switch key {
+                case .action:
+                    self = Self.action(try container.decode(std.Sort.self, forKey: key))
+                    return
+                case .createdAt:
+                    self = Self.createdAt(try container.decode(std.Sort.self, forKey: key))
+                    return
+                case .id:
+                    self = Self.id(try container.decode(std.Sort.self, forKey: key))
+                    return
+                case .role:
+                    self = Self.role(try container.decode(std.Sort.self, forKey: key))
+                    return
+                }
+            }
+            fatalError("invalid input for enum value decoder")
+        }
+    }
+    /**
+     * **Audit relation filter**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditRelationFilter : Codable, Reflectable {
+        public let `is`: admin.AuditWhereInput?
+        public let isNot: admin.AuditWhereInput?
+        public init(
+            `is`: admin.AuditWhereInput? = nil,
+            isNot: admin.AuditWhereInput? = nil
+        ) {
+            self.is = `is`
+            self.isNot = isNot
+        }
+    }
+    /**
+     * **Audit scalar update input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditScalarUpdateInput : Codable, Reflectable {
+        public let action: String?
+        public let createdAt: Date?
+        public let id: Int32?
+        public let role: admin.Role?
+        public init(
+            action: String? = nil,
+            createdAt: Date? = nil,
+            id: Int32? = nil,
+            role: admin.Role? = nil
+        ) {
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    /**
+     * **Audit scalar where with aggregates input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditScalarWhereWithAggregatesInput : Codable, Reflectable {
+        public let AND: Array<admin.AuditWhereInput>?
+        public let NOT: admin.AuditWhereInput?
+        public let OR: Array<admin.AuditWhereInput>?
+        public let action: StringOrStdStringWithAggregatesFilter?
+        public let createdAt: DateTimeOrStdAggregatesFilterDateTime?
+        public let id: IntOrStdIntNumberWithAggregatesFilterInt?
+        public let role: AdminRoleOrStdEnumWithAggregatesFilterAdminRole?
+        public init(
+            AND: Array<admin.AuditWhereInput>? = nil,
+            NOT: admin.AuditWhereInput? = nil,
+            OR: Array<admin.AuditWhereInput>? = nil,
+            action: StringOrStdStringWithAggregatesFilter? = nil,
+            createdAt: DateTimeOrStdAggregatesFilterDateTime? = nil,
+            id: IntOrStdIntNumberWithAggregatesFilterInt? = nil,
+            role: AdminRoleOrStdEnumWithAggregatesFilterAdminRole? = nil
+        ) {
+            self.AND = AND
+            self.NOT = NOT
+            self.OR = OR
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    /**
+     * **Audit select**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditSelect : Codable, Reflectable {
+        public let action: Bool?
+        public let createdAt: Bool?
+        public let id: Bool?
+        public let role: Bool?
+        public init(
+            action: Bool? = nil,
+            createdAt: Bool? = nil,
+            id: Bool? = nil,
+            role: Bool? = nil
+        ) {
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    /**
+     * **Audit sign in args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditSignInArgs : Codable, Reflectable {
+        public init(
+        ) {
+        }
+    }
+    /**
+     * **Audit sign in checker companions**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditSignInCheckerCompanions : Codable, Reflectable {
+        public init(
+        ) {
+        }
+    }
+    /**
+     * **Audit sign in checker ids**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditSignInCheckerIds : Codable, Reflectable {
+        public init(
+        ) {
+        }
+    }
+    /**
+     * **Audit sign in input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditSignInInput : Codable, Reflectable {
+        public let credentials: admin.AuditSignInArgs
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            credentials: admin.AuditSignInArgs,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.credentials = credentials
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit sum aggregate input type**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditSumAggregateInputType : Codable, Reflectable {
+        public let id: Bool?
+        public init(
+            id: Bool? = nil
+        ) {
+            self.id = id
+        }
+    }
+    /**
+     * **Audit sum aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditSumAggregateResult : Codable, Reflectable {
+        public let id: Int64?
+        public init(
+            id: Int64? = nil
+        ) {
+            self.id = id
+        }
+    }
+    /**
+     * **Audit update args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditUpdateArgs : Codable, Reflectable {
+        public let `where`: admin.AuditWhereUniqueInput
+        public let update: admin.AuditUpdateInput
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            `where`: admin.AuditWhereUniqueInput,
+            update: admin.AuditUpdateInput,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.where = `where`
+            self.update = update
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit update input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditUpdateInput : Codable, Reflectable {
+        public let action: String?
+        public let role: admin.Role?
+        public init(
+            action: String? = nil,
+            role: admin.Role? = nil
+        ) {
+            self.action = action
+            self.role = role
+        }
+    }
+    /**
+     * **Audit update many args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditUpdateManyArgs : Codable, Reflectable {
+        public let `where`: admin.AuditWhereInput
+        public let update: admin.AuditUpdateInput
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            `where`: admin.AuditWhereInput,
+            update: admin.AuditUpdateInput,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.where = `where`
+            self.update = update
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit update many with where input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditUpdateManyWithWhereInput : Codable, Reflectable {
+        public let `where`: admin.AuditWhereInput
+        public let update: admin.AuditUpdateInput
+        public init(
+            `where`: admin.AuditWhereInput,
+            update: admin.AuditUpdateInput
+        ) {
+            self.where = `where`
+            self.update = update
+        }
+    }
+    /**
+     * **Audit update nested many input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditUpdateNestedManyInput : Codable, Reflectable {
+        public let connect: Array<admin.AuditWhereUniqueInput>?
+        public let connectOrCreate: Array<admin.AuditConnectOrCreateInput>?
+        public let create: Array<admin.AuditCreateInput>?
+        public let delete: Array<admin.AuditWhereUniqueInput>?
+        public let deleteMany: Array<admin.AuditWhereInput>?
+        public let disconnect: Array<admin.AuditWhereUniqueInput>?
+        public let set: Array<admin.AuditWhereUniqueInput>?
+        public let update: Array<admin.AuditUpdateWithWhereUniqueInput>?
+        public let updateMany: Array<admin.AuditUpdateManyWithWhereInput>?
+        public let upsert: Array<admin.AuditUpsertWithWhereUniqueInput>?
+        public init(
+            connect: Array<admin.AuditWhereUniqueInput>? = nil,
+            connectOrCreate: Array<admin.AuditConnectOrCreateInput>? = nil,
+            create: Array<admin.AuditCreateInput>? = nil,
+            delete: Array<admin.AuditWhereUniqueInput>? = nil,
+            deleteMany: Array<admin.AuditWhereInput>? = nil,
+            disconnect: Array<admin.AuditWhereUniqueInput>? = nil,
+            set: Array<admin.AuditWhereUniqueInput>? = nil,
+            update: Array<admin.AuditUpdateWithWhereUniqueInput>? = nil,
+            updateMany: Array<admin.AuditUpdateManyWithWhereInput>? = nil,
+            upsert: Array<admin.AuditUpsertWithWhereUniqueInput>? = nil
+        ) {
+            self.connect = connect
+            self.connectOrCreate = connectOrCreate
+            self.create = create
+            self.delete = delete
+            self.deleteMany = deleteMany
+            self.disconnect = disconnect
+            self.set = set
+            self.update = update
+            self.updateMany = updateMany
+            self.upsert = upsert
+        }
+    }
+    /**
+     * **Audit update nested one input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditUpdateNestedOneInput : Codable, Reflectable {
+        public let connect: admin.AuditWhereUniqueInput?
+        public let connectOrCreate: admin.AuditConnectOrCreateInput?
+        public let create: admin.AuditCreateInput?
+        public let delete: Bool?
+        public let disconnect: Bool?
+        public let set: admin.AuditWhereUniqueInput?
+        public let update: admin.AuditUpdateInput?
+        public let upsert: admin.AuditUpsertWithWhereUniqueInput?
+        public init(
+            connect: admin.AuditWhereUniqueInput? = nil,
+            connectOrCreate: admin.AuditConnectOrCreateInput? = nil,
+            create: admin.AuditCreateInput? = nil,
+            delete: Bool? = nil,
+            disconnect: Bool? = nil,
+            set: admin.AuditWhereUniqueInput? = nil,
+            update: admin.AuditUpdateInput? = nil,
+            upsert: admin.AuditUpsertWithWhereUniqueInput? = nil
+        ) {
+            self.connect = connect
+            self.connectOrCreate = connectOrCreate
+            self.create = create
+            self.delete = delete
+            self.disconnect = disconnect
+            self.set = set
+            self.update = update
+            self.upsert = upsert
+        }
+    }
+    /**
+     * **Audit update with where unique input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditUpdateWithWhereUniqueInput : Codable, Reflectable {
+        public let `where`: admin.AuditWhereUniqueInput
+        public let update: admin.AuditUpdateInput
+        public init(
+            `where`: admin.AuditWhereUniqueInput,
+            update: admin.AuditUpdateInput
+        ) {
+            self.where = `where`
+            self.update = update
+        }
+    }
+    /**
+     * **Audit upsert args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditUpsertArgs : Codable, Reflectable {
+        public let `where`: admin.AuditWhereUniqueInput
+        public let update: admin.AuditUpdateInput
+        public let create: admin.AuditCreateInput
+        public let include: admin.AuditInclude?
+        public let select: admin.AuditSelect?
+        public init(
+            `where`: admin.AuditWhereUniqueInput,
+            update: admin.AuditUpdateInput,
+            create: admin.AuditCreateInput,
+            include: admin.AuditInclude? = nil,
+            select: admin.AuditSelect? = nil
+        ) {
+            self.where = `where`
+            self.update = update
+            self.create = create
+            self.include = include
+            self.select = select
+        }
+    }
+    /**
+     * **Audit upsert with where unique input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditUpsertWithWhereUniqueInput : Codable, Reflectable {
+        public let `where`: admin.AuditWhereUniqueInput
+        public let update: admin.AuditUpdateInput
+        public let create: admin.AuditCreateInput
+        public init(
+            `where`: admin.AuditWhereUniqueInput,
+            update: admin.AuditUpdateInput,
+            create: admin.AuditCreateInput
+        ) {
+            self.where = `where`
+            self.update = update
+            self.create = create
+        }
+    }
+    /**
+     * **Audit where input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditWhereInput : Codable, Reflectable {
+        public let AND: Array<admin.AuditWhereInput>?
+        public let NOT: admin.AuditWhereInput?
+        public let OR: Array<admin.AuditWhereInput>?
+        public let action: StringOrStdStringFilter?
+        public let createdAt: DateTimeOrStdFilterDateTime?
+        public let id: IntOrStdFilterInt?
+        public let role: AdminRoleOrStdEnumFilterAdminRole?
+        public init(
+            AND: Array<admin.AuditWhereInput>? = nil,
+            NOT: admin.AuditWhereInput? = nil,
+            OR: Array<admin.AuditWhereInput>? = nil,
+            action: StringOrStdStringFilter? = nil,
+            createdAt: DateTimeOrStdFilterDateTime? = nil,
+            id: IntOrStdFilterInt? = nil,
+            role: AdminRoleOrStdEnumFilterAdminRole? = nil
+        ) {
+            self.AND = AND
+            self.NOT = NOT
+            self.OR = OR
+            self.action = action
+            self.createdAt = createdAt
+            self.id = id
+            self.role = role
+        }
+    }
+    /**
+     * **Audit where unique input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    public class AuditWhereUniqueInput : Codable, Reflectable {
+        public let id: Int32
+        public init(
+            id: Int32
+        ) {
+            self.id = id
+        }
+    }
+    /// ## StringOrStdStringWithAggregatesFilter
+    ///
+    /// One of `String`, `std.StringWithAggregatesFilter`.
+    public enum StringOrStdStringWithAggregatesFilter: Codable {
+        case string(String)
+        case stdStringWithAggregatesFilter(std.StringWithAggregatesFilter)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(String.self) {
+                self = .string(value)
+                return
+            }
+            if let value = try? container.decode(std.StringWithAggregatesFilter.self) {
+                self = .stdStringWithAggregatesFilter(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringWithAggregatesFilter")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .string(let value):
+                try container.encode(value)
+            case .stdStringWithAggregatesFilter(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## DateTimeOrStdAggregatesFilterDateTime
+    ///
+    /// One of `Date`, `std.AggregatesFilter<Date>`.
+    public enum DateTimeOrStdAggregatesFilterDateTime: Codable {
+        case dateTime(Date)
+        case stdAggregatesFilterDateTime(std.AggregatesFilter<Date>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(Date.self) {
+                self = .dateTime(value)
+                return
+            }
+            if let value = try? container.decode(std.AggregatesFilter<Date>.self) {
+                self = .stdAggregatesFilterDateTime(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of DateTimeOrStdAggregatesFilterDateTime")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .dateTime(let value):
+                try container.encode(value)
+            case .stdAggregatesFilterDateTime(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## IntOrStdIntNumberWithAggregatesFilterInt
+    ///
+    /// One of `Int32`, `std.IntNumberWithAggregatesFilter<Int32>`.
+    public enum IntOrStdIntNumberWithAggregatesFilterInt: Codable {
+        case int(Int32)
+        case stdIntNumberWithAggregatesFilterInt(std.IntNumberWithAggregatesFilter<Int32>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(Int32.self) {
+                self = .int(value)
+                return
+            }
+            if let value = try? container.decode(std.IntNumberWithAggregatesFilter<Int32>.self) {
+                self = .stdIntNumberWithAggregatesFilterInt(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of IntOrStdIntNumberWithAggregatesFilterInt")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .int(let value):
+                try container.encode(value)
+            case .stdIntNumberWithAggregatesFilterInt(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## AdminRoleOrStdEnumWithAggregatesFilterAdminRole
+    ///
+    /// One of `admin.Role`, `std.EnumWithAggregatesFilter<admin.Role>`.
+    public enum AdminRoleOrStdEnumWithAggregatesFilterAdminRole: Codable {
+        case adminRole(admin.Role)
+        case stdEnumWithAggregatesFilterAdminRole(std.EnumWithAggregatesFilter<admin.Role>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(admin.Role.self) {
+                self = .adminRole(value)
+                return
+            }
+            if let value = try? container.decode(std.EnumWithAggregatesFilter<admin.Role>.self) {
+                self = .stdEnumWithAggregatesFilterAdminRole(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of AdminRoleOrStdEnumWithAggregatesFilterAdminRole")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .adminRole(let value):
+                try container.encode(value)
+            case .stdEnumWithAggregatesFilterAdminRole(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## StringOrStdStringFilter
+    ///
+    /// One of `String`, `std.StringFilter`.
+    public enum StringOrStdStringFilter: Codable {
+        case string(String)
+        case stdStringFilter(std.StringFilter)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(String.self) {
+                self = .string(value)
+                return
+            }
+            if let value = try? container.decode(std.StringFilter.self) {
+                self = .stdStringFilter(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringFilter")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .string(let value):
+                try container.encode(value)
+            case .stdStringFilter(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## DateTimeOrStdFilterDateTime
+    ///
+    /// One of `Date`, `std.Filter<Date>`.
+    public enum DateTimeOrStdFilterDateTime: Codable {
+        case dateTime(Date)
+        case stdFilterDateTime(std.Filter<Date>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(Date.self) {
+                self = .dateTime(value)
+                return
+            }
+            if let value = try? container.decode(std.Filter<Date>.self) {
+                self = .stdFilterDateTime(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of DateTimeOrStdFilterDateTime")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .dateTime(let value):
+                try container.encode(value)
+            case .stdFilterDateTime(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## IntOrStdFilterInt
+    ///
+    /// One of `Int32`, `std.Filter<Int32>`.
+    public enum IntOrStdFilterInt: Codable {
+        case int(Int32)
+        case stdFilterInt(std.Filter<Int32>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(Int32.self) {
+                self = .int(value)
+                return
+            }
+            if let value = try? container.decode(std.Filter<Int32>.self) {
+                self = .stdFilterInt(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of IntOrStdFilterInt")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .int(let value):
+                try container.encode(value)
+            case .stdFilterInt(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## AdminRoleOrStdEnumFilterAdminRole
+    ///
+    /// One of `admin.Role`, `std.EnumFilter<admin.Role>`.
+    public enum AdminRoleOrStdEnumFilterAdminRole: Codable {
+        case adminRole(admin.Role)
+        case stdEnumFilterAdminRole(std.EnumFilter<admin.Role>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(admin.Role.self) {
+                self = .adminRole(value)
+                return
+            }
+            if let value = try? container.decode(std.EnumFilter<admin.Role>.self) {
+                self = .stdEnumFilterAdminRole(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of AdminRoleOrStdEnumFilterAdminRole")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .adminRole(let value):
+                try container.encode(value)
+            case .stdEnumFilterAdminRole(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    public class AuditDelegate {
+        public let _headers: Dictionary<String, String>?
+        public init(_ headers: Dictionary<String, String>? = nil) {
+            self._headers = headers
+        }
+        public func findUnique(input: admin.AuditFindUniqueArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<admin.Audit> {
+          return try await jsonRequest("POST", "admin/Audit/findUnique", input, headers ?? _headers, nil)
+        }
+        public func findFirst(input: admin.AuditFindFirstArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<admin.Audit> {
+          return try await jsonRequest("POST", "admin/Audit/findFirst", input, headers ?? _headers, nil)
+        }
+        public func findMany(input: admin.AuditFindManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<admin.Audit>, std.PagingInfo> {
+          return try await jsonRequest("POST", "admin/Audit/findMany", input, headers ?? _headers, nil)
+        }
+        public func create(input: admin.AuditCreateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<admin.Audit> {
+          return try await jsonRequest("POST", "admin/Audit/create", input, headers ?? _headers, nil)
+        }
+        public func update(input: admin.AuditUpdateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<admin.Audit> {
+          return try await jsonRequest("POST", "admin/Audit/update", input, headers ?? _headers, nil)
+        }
+        public func upsert(input: admin.AuditUpsertArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<admin.Audit> {
+          return try await jsonRequest("POST", "admin/Audit/upsert", input, headers ?? _headers, nil)
+        }
+        public func copy(input: admin.AuditCopyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<admin.Audit> {
+          return try await jsonRequest("POST", "admin/Audit/copy", input, headers ?? _headers, nil)
+        }
+        public func delete(input: admin.AuditDeleteArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<admin.Audit> {
+          return try await jsonRequest("POST", "admin/Audit/delete", input, headers ?? _headers, nil)
+        }
+        public func createMany(input: admin.AuditCreateManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<admin.Audit>, std.PagingInfo> {
+          return try await jsonRequest("POST", "admin/Audit/createMany", input, headers ?? _headers, nil)
+        }
+        public func updateMany(input: admin.AuditUpdateManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<admin.Audit>, std.PagingInfo> {
+          return try await jsonRequest("POST", "admin/Audit/updateMany", input, headers ?? _headers, nil)
+        }
+        public func copyMany(input: admin.AuditCopyManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<admin.Audit>, std.PagingInfo> {
+          return try await jsonRequest("POST", "admin/Audit/copyMany", input, headers ?? _headers, nil)
+        }
+        public func deleteMany(input: admin.AuditDeleteManyArgs, headers: Dictionary<String, String>? = nil) async throws -> std.DataMeta<Array<admin.Audit>, std.PagingInfo> {
+          return try await jsonRequest("POST", "admin/Audit/deleteMany", input, headers ?? _headers, nil)
+        }
+        public func count(input: admin.AuditCountArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<Int64> {
+          return try await jsonRequest("POST", "admin/Audit/count", input, headers ?? _headers, nil)
+        }
+        public func aggregate(input: admin.AuditAggregateArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<admin.AuditAggregateResult> {
+          return try await jsonRequest("POST", "admin/Audit/aggregate", input, headers ?? _headers, nil)
+        }
+        public func groupBy(input: admin.AuditGroupByArgs, headers: Dictionary<String, String>? = nil) async throws -> std.Data<admin.AuditGroupByResult> {
+          return try await jsonRequest("POST", "admin/Audit/groupBy", input, headers ?? _headers, nil)
+        }
+    }
+
+    public class AdminNamespaceDelegate {
+        public let _headers: Dictionary<String, String>?
+        lazy var reports: admin.reports.ReportsNamespaceDelegate = { admin.reports.ReportsNamespaceDelegate(_headers) }();
+        lazy var audit: admin.AuditDelegate = { admin.AuditDelegate(_headers) }();
+        public init(_ headers: Dictionary<String, String>? = nil) {
+            self._headers = headers
+        }
+    }
+
+    public struct reports {
+
+        public class ReportPathArguments: Codable, Reflectable {
+            public let year: String
+            public let month: String
+            public init(
+                year: String,
+                month: String
+            ) {
+                self.year = year,
+                self.month = month,
+            }
+        }
+        /**
+         * **Report path arguments**
+         *
+         * This interface doesn't have a description.
+         */
+        public class ReportPathArguments : Codable, Reflectable {
+            public let month: Int32
+            public let year: Int32
+            public init(
+                month: Int32,
+                year: Int32
+            ) {
+                self.month = month
+                self.year = year
+            }
+        }
+        /**
+         * **Summary**
+         *
+         * This interface doesn't have a description.
+         */
+        public class Summary<T> : Codable, Reflectable where T: Codable {
+            public let byRole: Array<admin.Role>
+            public let total: Int32
+            public let latest: T?
+            public init(
+                byRole: Array<admin.Role>,
+                total: Int32,
+                latest: T? = nil
+            ) {
+                self.byRole = byRole
+                self.total = total
+                self.latest = latest
+            }
+        }
+        public class ReportsNamespaceDelegate {
+            public let _headers: Dictionary<String, String>?
+            public init(_ headers: Dictionary<String, String>? = nil) {
+                self._headers = headers
+            }
+        }
+
+    }
+}
+public struct std {
+
+    /// ## Sort Order
+    ///
+    /// Represents the sort order
+    public enum Sort: String, Codable {
+
+        /// ### Asc
+        ///
+        /// This enum member doesn't have a description.
+        case asc = "asc"
+
+        /// ### Desc
+        ///
+        /// This enum member doesn't have a description.
+        case desc = "desc"
+    }
+    /// ## String Match Mode
+    ///
+    /// Whether the string query is case sensitive or not
+    public enum StringMatchMode: String, Codable {
+
+        /// ### Default
+        ///
+        /// This enum member doesn't have a description.
+        case `default` = "default"
+
+        /// ### Case insensitive
+        ///
+        /// This enum member doesn't have a description.
+        case caseInsensitive = "caseInsensitive"
+    }
+    /**
+     * **Aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class AggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.Filter<T>?
+        public let _max: std.Filter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.Filter<T>? = nil,
+            _max: std.Filter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    public enum ArrayAtomicUpdateOperationInput<T> : Codable where T: Codable {
+        case push(T)
+        enum CodingKeys: String, CodingKey {
+            case push = "push"
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.container(keyedBy: ArrayAtomicUpdateOperationInput<T>.CodingKeys.self)
+            switch self {
+            case .push(let a0):
+                try container.encode(a0, forKey: .push)
+            }
+        }
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.container(keyedBy: CodingKeys.self)
+            for key in container.allKeys {
+                switch key {
+                case .push:
+                    self = Self.push(try container.decode(T.self, forKey: key))
+                    return
+                }
+            }
+            fatalError("invalid input for enum value decoder")
+        }
+    }
+    /**
+     * **Array filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class ArrayFilter<T> : Codable, Reflectable where T: Codable {
+        public let equals: Array<T>?
+        public let has: T?
+        public let hasSome: Array<T>?
+        public let hasEvery: Array<T>?
+        public let isEmpty: Bool?
+        public let length: Int32?
+        public init(
+            equals: Array<T>? = nil,
+            has: T? = nil,
+            hasSome: Array<T>? = nil,
+            hasEvery: Array<T>? = nil,
+            isEmpty: Bool? = nil,
+            length: Int32? = nil
+        ) {
+            self.equals = equals
+            self.has = has
+            self.hasSome = hasSome
+            self.hasEvery = hasEvery
+            self.isEmpty = isEmpty
+            self.length = length
+        }
+    }
+    /**
+     * **Array nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class ArrayNullableFilter<T> : Codable, Reflectable where T: Codable {
+        public let equals: Array<T>??
+        public let has: T?
+        public let hasSome: Array<T>?
+        public let hasEvery: Array<T>?
+        public let isEmpty: Bool?
+        public let length: Int32?
+        public init(
+            equals: Array<T>?? = nil,
+            has: T? = nil,
+            hasSome: Array<T>? = nil,
+            hasEvery: Array<T>? = nil,
+            isEmpty: Bool? = nil,
+            length: Int32? = nil
+        ) {
+            self.equals = equals
+            self.has = has
+            self.hasSome = hasSome
+            self.hasEvery = hasEvery
+            self.isEmpty = isEmpty
+            self.length = length
+        }
+    }
+    /**
+     * **Array nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class ArrayNullableWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.ArrayNullableFilter<T>?
+        public let _max: std.ArrayNullableFilter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.ArrayNullableFilter<T>? = nil,
+            _max: std.ArrayNullableFilter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Array with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class ArrayWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.ArrayFilter<T>?
+        public let _max: std.ArrayFilter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.ArrayFilter<T>? = nil,
+            _max: std.ArrayFilter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Bool filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class BoolFilter : Codable, Reflectable {
+        public let equals: Bool?
+        public let not: BoolOrStdBoolFilter?
+        public init(
+            equals: Bool? = nil,
+            not: BoolOrStdBoolFilter? = nil
+        ) {
+            self.equals = equals
+            self.not = not
+        }
+    }
+    /**
+     * **Bool nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class BoolNullableFilter : Codable, Reflectable {
+        public let equals: Bool??
+        public let not: BoolOrStdBoolNullableFilter?
+        public init(
+            equals: Bool?? = nil,
+            not: BoolOrStdBoolNullableFilter? = nil
+        ) {
+            self.equals = equals
+            self.not = not
+        }
+    }
+    /**
+     * **Bool nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class BoolNullableWithAggregatesFilter : Codable, Reflectable {
+        public let _count: Int64?
+        public let _min: std.BoolNullableFilter?
+        public let _max: std.BoolNullableFilter?
+        public init(
+            _count: Int64? = nil,
+            _min: std.BoolNullableFilter? = nil,
+            _max: std.BoolNullableFilter? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Bool with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class BoolWithAggregatesFilter : Codable, Reflectable {
+        public let _count: Int64?
+        public let _min: std.BoolFilter?
+        public let _max: std.BoolFilter?
+        public init(
+            _count: Int64? = nil,
+            _min: std.BoolFilter? = nil,
+            _max: std.BoolFilter? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Data**
+     *
+     * This interface is common for action output
+     */
+    public class Data<T> : Codable, Reflectable where T: Codable {
+        public let data: T
+        public init(
+            data: T
+        ) {
+            self.data = data
+        }
+    }
+    /**
+     * **Data and Meta**
+     *
+     * This interface is common for action output with meta information
+     */
+    public class DataMeta<T, U> : Codable, Reflectable where T: Codable, U: Codable {
+        public let meta: U
+        public let data: T
+        public init(
+            meta: U,
+            data: T
+        ) {
+            self.meta = meta
+            self.data = data
+        }
+    }
+    /**
+     * **Decimal nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class DecimalNullableWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.NullableFilter<Decimal>?
+        public let _max: std.NullableFilter<Decimal>?
+        public let _avg: std.NullableFilter<Decimal>?
+        public let _sum: std.NullableFilter<Decimal>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.NullableFilter<Decimal>? = nil,
+            _max: std.NullableFilter<Decimal>? = nil,
+            _avg: std.NullableFilter<Decimal>? = nil,
+            _sum: std.NullableFilter<Decimal>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Decimal with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class DecimalWithAggregatesFilter : Codable, Reflectable {
+        public let _count: Int64?
+        public let _min: std.Filter<Decimal>?
+        public let _max: std.Filter<Decimal>?
+        public let _avg: std.Filter<Decimal>?
+        public let _sum: std.Filter<Decimal>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.Filter<Decimal>? = nil,
+            _max: std.Filter<Decimal>? = nil,
+            _avg: std.Filter<Decimal>? = nil,
+            _sum: std.Filter<Decimal>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Empty**
+     *
+     * The empty interface
+     */
+    public class Empty : Codable, Reflectable {
+        public init(
+        ) {
+        }
+    }
+    /**
+     * **Enum filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class EnumFilter<T> : Codable, Reflectable where T: Codable {
+        public let equals: T?
+        public let `in`: Array<T>?
+        public let notIn: Array<T>?
+        public let not: TOrStdEnumFilterT?
+        public init(
+            equals: T? = nil,
+            `in`: Array<T>? = nil,
+            notIn: Array<T>? = nil,
+            not: TOrStdEnumFilterT? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.not = not
+        }
+    }
+    /**
+     * **Enum nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class EnumNullableFilter<T> : Codable, Reflectable where T: Codable {
+        public let equals: T??
+        public let `in`: Array<T?>?
+        public let notIn: Array<T?>?
+        public let not: TOrStdEnumNullableFilterT?
+        public init(
+            equals: T?? = nil,
+            `in`: Array<T?>? = nil,
+            notIn: Array<T?>? = nil,
+            not: TOrStdEnumNullableFilterT? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.not = not
+        }
+    }
+    /**
+     * **Enum nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class EnumNullableWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.EnumNullableFilter<T>?
+        public let _max: std.EnumNullableFilter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.EnumNullableFilter<T>? = nil,
+            _max: std.EnumNullableFilter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Enum with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class EnumWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.EnumFilter<T>?
+        public let _max: std.EnumFilter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.EnumFilter<T>? = nil,
+            _max: std.EnumFilter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class Filter<T> : Codable, Reflectable where T: Codable {
+        public let equals: T?
+        public let `in`: Array<T>?
+        public let notIn: Array<T>?
+        public let lt: T?
+        public let lte: T?
+        public let gt: T?
+        public let gte: T?
+        public let not: TOrStdFilterT?
+        public init(
+            equals: T? = nil,
+            `in`: Array<T>? = nil,
+            notIn: Array<T>? = nil,
+            lt: T? = nil,
+            lte: T? = nil,
+            gt: T? = nil,
+            gte: T? = nil,
+            not: TOrStdFilterT? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.lt = lt
+            self.lte = lte
+            self.gt = gt
+            self.gte = gte
+            self.not = not
+        }
+    }
+    /**
+     * **Float number nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class FloatNumberNullableWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.NullableFilter<T>?
+        public let _max: std.NullableFilter<T>?
+        public let _avg: std.NullableFilter<Double>?
+        public let _sum: std.NullableFilter<Double>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.NullableFilter<T>? = nil,
+            _max: std.NullableFilter<T>? = nil,
+            _avg: std.NullableFilter<Double>? = nil,
+            _sum: std.NullableFilter<Double>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Float number with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class FloatNumberWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.Filter<T>?
+        public let _max: std.Filter<T>?
+        public let _avg: std.Filter<Double>?
+        public let _sum: std.Filter<Double>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.Filter<T>? = nil,
+            _max: std.Filter<T>? = nil,
+            _avg: std.Filter<Double>? = nil,
+            _sum: std.Filter<Double>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Int number nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class IntNumberNullableWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.NullableFilter<T>?
+        public let _max: std.NullableFilter<T>?
+        public let _avg: std.NullableFilter<Double>?
+        public let _sum: std.NullableFilter<Int64>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.NullableFilter<T>? = nil,
+            _max: std.NullableFilter<T>? = nil,
+            _avg: std.NullableFilter<Double>? = nil,
+            _sum: std.NullableFilter<Int64>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Int number with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class IntNumberWithAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.Filter<T>?
+        public let _max: std.Filter<T>?
+        public let _avg: std.Filter<Double>?
+        public let _sum: std.Filter<Int64>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.Filter<T>? = nil,
+            _max: std.Filter<T>? = nil,
+            _avg: std.Filter<Double>? = nil,
+            _sum: std.Filter<Int64>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+            self._avg = _avg
+            self._sum = _sum
+        }
+    }
+    /**
+     * **Nullable aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class NullableAggregatesFilter<T> : Codable, Reflectable where T: Codable {
+        public let _count: Int64?
+        public let _min: std.NullableFilter<T>?
+        public let _max: std.NullableFilter<T>?
+        public init(
+            _count: Int64? = nil,
+            _min: std.NullableFilter<T>? = nil,
+            _max: std.NullableFilter<T>? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **Nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class NullableFilter<T> : Codable, Reflectable where T: Codable {
+        public let equals: T??
+        public let `in`: Array<T?>?
+        public let notIn: Array<T?>?
+        public let lt: T?
+        public let lte: T?
+        public let gt: T?
+        public let gte: T?
+        public let not: TOrStdNullableFilterT?
+        public init(
+            equals: T?? = nil,
+            `in`: Array<T?>? = nil,
+            notIn: Array<T?>? = nil,
+            lt: T? = nil,
+            lte: T? = nil,
+            gt: T? = nil,
+            gte: T? = nil,
+            not: TOrStdNullableFilterT? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.lt = lt
+            self.lte = lte
+            self.gt = gt
+            self.gte = gte
+            self.not = not
+        }
+    }
+    public enum NumberAtomicUpdateOperationInput<T> : Codable where T: Codable {
+        case increment(T)
+        case decrement(T)
+        case multiply(T)
+        case divide(T)
+        enum CodingKeys: String, CodingKey {
+            case increment = "increment"
+            case decrement = "decrement"
+            case multiply = "multiply"
+            case divide = "divide"
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.container(keyedBy: NumberAtomicUpdateOperationInput<T>.CodingKeys.self)
+            switch self {
+            case .increment(let a0):
+                try container.encode(a0, forKey: .increment)
+            case .decrement(let a0):
+                try container.encode(a0, forKey: .decrement)
+            case .multiply(let a0):
+                try container.encode(a0, forKey: .multiply)
+            case .divide(let a0):
+                try container.encode(a0, forKey: .divide)
+            }
+        }
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.container(keyedBy: CodingKeys.self)
+            for key in container.allKeys {
+                switch key {
+                case .increment:
+                    self = Self.increment(try container.decode(T.self, forKey: key))
+                    return
+                case .decrement:
+                    self = Self.decrement(try container.decode(T.self, forKey: key))
+                    return
+                case .multiply:
+                    self = Self.multiply(try container.decode(T.self, forKey: key))
+                    return
+                case .divide:
+                    self = Self.divide(try container.decode(T.self, forKey: key))
+                    return
+                }
+            }
+            fatalError("invalid input for enum value decoder")
+        }
+    }
+    /**
+     * **Paging info**
+     *
+     * This interface doesn't have a description.
+     */
+    public class PagingInfo : Codable, Reflectable {
+        public let count: Int64
+        public let numberOfPages: Int64?
+        public init(
+            count: Int64,
+            numberOfPages: Int64? = nil
+        ) {
+            self.count = count
+            self.numberOfPages = numberOfPages
+        }
+    }
+    /**
+     * **Response error**
+     *
+     * This interface doesn't have a description.
+     */
+    public class ResponseError : Codable, Reflectable {
+        public let fields: Dictionary<String, String>?
+        public let message: String
+        public let type: String
+        public init(
+            fields: Dictionary<String, String>?,
+            message: String,
+            type: String
+        ) {
+            self.fields = fields
+            self.message = message
+            self.type = type
+        }
+    }
+    /**
+     * **String filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class StringFilter : Codable, Reflectable {
+        public let equals: String?
+        public let `in`: Array<String>?
+        public let notIn: Array<String>?
+        public let lt: String?
+        public let lte: String?
+        public let gt: String?
+        public let gte: String?
+        public let contains: String?
+        public let startsWith: String?
+        public let endsWith: String?
+        public let matches: String?
+        public let mode: std.StringMatchMode?
+        public let not: StringOrStdStringFilter?
+        public init(
+            equals: String? = nil,
+            `in`: Array<String>? = nil,
+            notIn: Array<String>? = nil,
+            lt: String? = nil,
+            lte: String? = nil,
+            gt: String? = nil,
+            gte: String? = nil,
+            contains: String? = nil,
+            startsWith: String? = nil,
+            endsWith: String? = nil,
+            matches: String? = nil,
+            mode: std.StringMatchMode? = nil,
+            not: StringOrStdStringFilter? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.lt = lt
+            self.lte = lte
+            self.gt = gt
+            self.gte = gte
+            self.contains = contains
+            self.startsWith = startsWith
+            self.endsWith = endsWith
+            self.matches = matches
+            self.mode = mode
+            self.not = not
+        }
+    }
+    /**
+     * **String nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class StringNullableFilter : Codable, Reflectable {
+        public let equals: String??
+        public let `in`: Array<String?>?
+        public let notIn: Array<String?>?
+        public let lt: String?
+        public let lte: String?
+        public let gt: String?
+        public let gte: String?
+        public let contains: String?
+        public let startsWith: String?
+        public let endsWith: String?
+        public let matches: String?
+        public let mode: std.StringMatchMode?
+        public let not: StringOrStdStringNullableFilter?
+        public init(
+            equals: String?? = nil,
+            `in`: Array<String?>? = nil,
+            notIn: Array<String?>? = nil,
+            lt: String? = nil,
+            lte: String? = nil,
+            gt: String? = nil,
+            gte: String? = nil,
+            contains: String? = nil,
+            startsWith: String? = nil,
+            endsWith: String? = nil,
+            matches: String? = nil,
+            mode: std.StringMatchMode? = nil,
+            not: StringOrStdStringNullableFilter? = nil
+        ) {
+            self.equals = equals
+            self.in = `in`
+            self.notIn = notIn
+            self.lt = lt
+            self.lte = lte
+            self.gt = gt
+            self.gte = gte
+            self.contains = contains
+            self.startsWith = startsWith
+            self.endsWith = endsWith
+            self.matches = matches
+            self.mode = mode
+            self.not = not
+        }
+    }
+    /**
+     * **String nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class StringNullableWithAggregatesFilter : Codable, Reflectable {
+        public let _count: Int64?
+        public let _min: std.StringNullableFilter?
+        public let _max: std.StringNullableFilter?
+        public init(
+            _count: Int64? = nil,
+            _min: std.StringNullableFilter? = nil,
+            _max: std.StringNullableFilter? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /**
+     * **String with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    public class StringWithAggregatesFilter : Codable, Reflectable {
+        public let _count: Int64?
+        public let _min: std.StringFilter?
+        public let _max: std.StringFilter?
+        public init(
+            _count: Int64? = nil,
+            _min: std.StringFilter? = nil,
+            _max: std.StringFilter? = nil
+        ) {
+            self._count = _count
+            self._min = _min
+            self._max = _max
+        }
+    }
+    /// ## BoolOrStdBoolFilter
+    ///
+    /// One of `Bool`, `std.BoolFilter`.
+    public enum BoolOrStdBoolFilter: Codable {
+        case bool(Bool)
+        case stdBoolFilter(std.BoolFilter)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(Bool.self) {
+                self = .bool(value)
+                return
+            }
+            if let value = try? container.decode(std.BoolFilter.self) {
+                self = .stdBoolFilter(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of BoolOrStdBoolFilter")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .bool(let value):
+                try container.encode(value)
+            case .stdBoolFilter(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## BoolOrStdBoolNullableFilter
+    ///
+    /// One of `Bool?`, `std.BoolNullableFilter`.
+    public enum BoolOrStdBoolNullableFilter: Codable {
+        case bool(Bool?)
+        case stdBoolNullableFilter(std.BoolNullableFilter)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.BoolNullableFilter.self) {
+                self = .stdBoolNullableFilter(value)
+                return
+            }
+            if let value = try? container.decode(Bool?.self) {
+                self = .bool(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of BoolOrStdBoolNullableFilter")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .bool(let value):
+                try container.encode(value)
+            case .stdBoolNullableFilter(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## TOrStdEnumFilterT
+    ///
+    /// One of `T`, `std.EnumFilter<T>`.
+    public enum TOrStdEnumFilterT: Codable {
+        case t(T)
+        case stdEnumFilterT(std.EnumFilter<T>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.EnumFilter<T>.self) {
+                self = .stdEnumFilterT(value)
+                return
+            }
+            if let value = try? container.decode(T.self) {
+                self = .t(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of TOrStdEnumFilterT")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .t(let value):
+                try container.encode(value)
+            case .stdEnumFilterT(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## TOrStdEnumNullableFilterT
+    ///
+    /// One of `T?`, `std.EnumNullableFilter<T>`.
+    public enum TOrStdEnumNullableFilterT: Codable {
+        case t(T?)
+        case stdEnumNullableFilterT(std.EnumNullableFilter<T>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.EnumNullableFilter<T>.self) {
+                self = .stdEnumNullableFilterT(value)
+                return
+            }
+            if let value = try? container.decode(T?.self) {
+                self = .t(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of TOrStdEnumNullableFilterT")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .t(let value):
+                try container.encode(value)
+            case .stdEnumNullableFilterT(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## TOrStdFilterT
+    ///
+    /// One of `T`, `std.Filter<T>`.
+    public enum TOrStdFilterT: Codable {
+        case t(T)
+        case stdFilterT(std.Filter<T>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.Filter<T>.self) {
+                self = .stdFilterT(value)
+                return
+            }
+            if let value = try? container.decode(T.self) {
+                self = .t(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of TOrStdFilterT")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .t(let value):
+                try container.encode(value)
+            case .stdFilterT(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## TOrStdNullableFilterT
+    ///
+    /// One of `T?`, `std.NullableFilter<T>`.
+    public enum TOrStdNullableFilterT: Codable {
+        case t(T?)
+        case stdNullableFilterT(std.NullableFilter<T>)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.NullableFilter<T>.self) {
+                self = .stdNullableFilterT(value)
+                return
+            }
+            if let value = try? container.decode(T?.self) {
+                self = .t(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of TOrStdNullableFilterT")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .t(let value):
+                try container.encode(value)
+            case .stdNullableFilterT(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## StringOrStdStringFilter
+    ///
+    /// One of `String`, `std.StringFilter`.
+    public enum StringOrStdStringFilter: Codable {
+        case string(String)
+        case stdStringFilter(std.StringFilter)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(String.self) {
+                self = .string(value)
+                return
+            }
+            if let value = try? container.decode(std.StringFilter.self) {
+                self = .stdStringFilter(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringFilter")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .string(let value):
+                try container.encode(value)
+            case .stdStringFilter(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    /// ## StringOrStdStringNullableFilter
+    ///
+    /// One of `String?`, `std.StringNullableFilter`.
+    public enum StringOrStdStringNullableFilter: Codable {
+        case string(String?)
+        case stdStringNullableFilter(std.StringNullableFilter)
+        public init(from decoder: any Decoder) throws {
+            let container = try decoder.singleValueContainer()
+            if let value = try? container.decode(std.StringNullableFilter.self) {
+                self = .stdStringNullableFilter(value)
+                return
+            }
+            if let value = try? container.decode(String?.self) {
+                self = .string(value)
+                return
+            }
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "value doesn't match any member of StringOrStdStringNullableFilter")
+        }
+        public func encode(to encoder: Encoder) throws {
+            var container = encoder.singleValueContainer()
+            switch self {
+            case .string(let value):
+                try container.encode(value)
+            case .stdStringNullableFilter(let value):
+                try container.encode(value)
+            }
+        }
+    }
+    public class StdNamespaceDelegate {
+        public let _headers: Dictionary<String, String>?
+        lazy var admin: std.admin.AdminNamespaceDelegate = { std.admin.AdminNamespaceDelegate(_headers) }();
+        lazy var bcrypt: std.bcrypt.BcryptNamespaceDelegate = { std.bcrypt.BcryptNamespaceDelegate(_headers) }();
+        lazy var identity: std.identity.IdentityNamespaceDelegate = { std.identity.IdentityNamespaceDelegate(_headers) }();
+        public init(_ headers: Dictionary<String, String>? = nil) {
+            self._headers = headers
+        }
+    }
+
+    public struct admin {
+
+        public class AdminNamespaceDelegate {
+            public let _headers: Dictionary<String, String>?
+            public init(_ headers: Dictionary<String, String>? = nil) {
+                self._headers = headers
+            }
+        }
+
+    }
+    public struct bcrypt {
+
+        public class BcryptNamespaceDelegate {
+            public let _headers: Dictionary<String, String>?
+            public init(_ headers: Dictionary<String, String>? = nil) {
+                self._headers = headers
+            }
+        }
+
+    }
+    public struct identity {
+
+        /**
+         * **Token info**
+         *
+         * This interface doesn't have a description.
+         */
+        public class TokenInfo : Codable, Reflectable {
+            public let token: String
+            public init(
+                token: String
+            ) {
+                self.token = token
+            }
+        }
+        public class IdentityNamespaceDelegate {
+            public let _headers: Dictionary<String, String>?
+            public init(_ headers: Dictionary<String, String>? = nil) {
+                self._headers = headers
+            }
+        }
+
+    }
+}
+
+public let teo = Teo()
\ No newline at end of file
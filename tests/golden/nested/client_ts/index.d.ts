@@ -0,0 +1,6722 @@
+// @typescript-eslint/no-unused-vars
+
+import Decimal from "decimal.js"
+
+export type ExistKeys<T> = {
+    [key in keyof T]: T[key] extends false | undefined | null ? never : key
+}[keyof T]
+
+type HasSelect = {
+    select: any
+}
+
+type HasInclude = {
+    include: any
+}
+
+export type CheckSelectInclude<T, S, U> = T extends HasSelect
+    ? U
+    : T extends HasInclude
+    ? U
+    : S
+
+export type SelectSubset<T, U> = U extends HasSelect
+    ? {
+        [K in ExistKeys<U['select']>]: K extends keyof T ? T[K] : never
+    }
+    : T
+
+export type Enumerable<T> = T | Array<T>
+
+export type Subset<T, U> = {
+    [key in keyof T]: key extends keyof U ? T[key] : never
+}
+
+export type GetScalarType<T, O> = O extends object ? {
+    [P in keyof T]: P extends keyof O
+      ? O[P]
+      : never
+} : never
+
+type Teo__Pick<T, K extends keyof T> = {
+    [P in K]: T[P];
+}
+
+type PickEnumerable<T, K extends Enumerable<keyof T> | keyof T> = Teo__Pick<T, MaybeTupleToUnion<K>>
+
+export type Boolean = True | False
+
+export type True = 1
+
+export type False = 0
+
+export type Not<B extends Boolean> = {
+  0: 1
+  1: 0
+}[B]
+
+type NoExpand<T> = T extends unknown ? T : never;
+
+type Key = string | number | symbol;
+type AtBasic<O extends object, K extends Key> = K extends keyof O ? O[K] : never;
+type AtStrict<O extends object, K extends Key> = O[K & keyof O];
+type AtLoose<O extends object, K extends Key> = O extends unknown ? AtStrict<O, K> : never;
+export type At<O extends object, K extends Key, strict extends Boolean = 1> = {
+    1: AtStrict<O, K>;
+    0: AtLoose<O, K>;
+}[strict];
+
+export type IntersectOf<U extends Union> = (
+  U extends unknown ? (k: U) => void : never
+) extends (k: infer I) => void
+  ? I
+  : never
+
+export type Overwrite<O extends object, O1 extends object> = {
+    [K in keyof O]: K extends keyof O1 ? O1[K] : O[K];
+} & {};
+
+type _Merge<U extends object> = IntersectOf<Overwrite<U, {
+    [K in keyof U]-?: At<U, K>;
+}>>;
+
+export type ComputeRaw<A extends any> = A extends Function ? A : {
+  [K in keyof A]: A[K];
+} & {};
+
+export type OptionalFlat<O> = {
+  [K in keyof O]?: O[K];
+} & {};
+
+type _Record<K extends keyof any, T> = {
+  [P in K]: T;
+};
+
+type AtLeast<O extends object, K extends string> = NoExpand<
+  O extends unknown
+  ? | (K extends keyof O ? { [P in K]: O[P] } & O : O)
+    | {[P in keyof O as P extends K ? K : never]-?: O[P]} & O
+  : never>;
+
+type _Strict<U, _U = U> = U extends unknown ? U & OptionalFlat<_Record<Exclude<Keys<_U>, keyof U>, never>> : never;
+
+export type Strict<U extends object> = ComputeRaw<_Strict<U>>;
+
+export type Merge<U extends object> = ComputeRaw<_Merge<Strict<U>>>;
+
+type ExcludeUnderscoreKeys<T extends string> = T extends `_${string}` ? never : T
+
+export type Union = any
+
+export type Extends<A1 extends any, A2 extends any> = [A1] extends [never]
+  ? 0 // anything `never` is false
+  : A1 extends A2
+  ? 1
+  : 0
+
+export type Has<U extends Union, U1 extends Union> = Not<
+  Extends<Exclude<U1, U>, U1>
+>
+
+export type Or<B1 extends Boolean, B2 extends Boolean> = {
+  0: {
+    0: 0
+    1: 1
+  }
+  1: {
+    0: 1
+    1: 1
+  }
+}[B1][B2]
+
+export type Keys<U extends Union> = U extends unknown ? keyof U : never
+
+type Cast<A, B> = A extends B ? A : B;
+
+type IsObject<T extends any> = T extends Array<any>
+? False
+: T extends Date
+? False
+: T extends Uint8Array
+? False
+: T extends object
+? True
+: False
+
+type FieldPaths<
+  T,
+  U = Omit<T, '_avg' | '_sum' | '_count' | '_min' | '_max'>
+> = IsObject<T> extends True ? U : T
+
+type GetHavingFields<T> = {
+  [K in keyof T]: Or<
+    Or<Extends<'OR', K>, Extends<'AND', K>>,
+    Extends<'NOT', K>
+  > extends True
+    ? // infer is only needed to not hit TS limit
+      // based on the brilliant idea of Pierre-Antoine Mills
+      // https://github.com/microsoft/TypeScript/issues/30188#issuecomment-478938437
+      T[K] extends infer TK
+      ? GetHavingFields<UnEnumerate<TK> extends object ? Merge<UnEnumerate<TK>> : never>
+      : never
+    : {} extends FieldPaths<T[K]>
+    ? never
+    : K
+}[keyof T]
+
+export type UnEnumerate<T extends unknown> = T extends Array<infer U> ? U : T
+
+export type SubsetIntersection<T, U, K> = {
+  [key in keyof T]: key extends keyof U ? T[key] : never
+} & K
+
+type _TupleToUnion<T> = T extends (infer E)[] ? E : never
+type TupleToUnion<K extends readonly any[]> = _TupleToUnion<K>
+type MaybeTupleToUnion<T> = T extends any[] ? TupleToUnion<T> : T
+
+export type Int64 = number
+
+export type ObjectId = string
+
+export type DateOnly = string
+
+export declare class TeoError extends Error {
+    type: string
+
+    errors: {[key: string]: string} | null
+
+    constructor(responseError: std.ResponseError)
+
+    get name(): string
+}
+
+/**
+ * **Account direct relations**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type AccountDirectRelations = undefined
+
+/**
+ * **Account indirect relations**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type AccountIndirectRelations = undefined
+
+/**
+ * **Account relations**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type AccountRelations = undefined
+
+/**
+ * **Account scalar fields**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type AccountScalarFields = "id" | "name" | "role"
+
+/**
+ * **Account serializable scalar fields**
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export type AccountSerializableScalarFields = "id" | "name" | "role"
+
+/**
+ * ## Account direct relations
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum AccountDirectRelationsEnumType {
+}
+
+/**
+ * ## Account indirect relations
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum AccountIndirectRelationsEnumType {
+}
+
+/**
+ * ## Account relations
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum AccountRelationsEnumType {
+}
+
+/**
+ * ## Account scalar fields
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum AccountScalarFieldsEnumType {
+
+    /**
+     * ### Id
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    id = "id",
+
+    /**
+     * ### Name
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    name = "name",
+
+    /**
+     * ### Role
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    role = "role",
+}
+
+/**
+ * ## Account serializable scalar fields
+ *
+ * This synthesized enum doesn't have a description.
+ */
+export const enum AccountSerializableScalarFieldsEnumType {
+
+    /**
+     * ### Id
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    id = "id",
+
+    /**
+     * ### Name
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    name = "name",
+
+    /**
+     * ### Role
+     *
+     * This synthesized enum member doesn't have a description.
+     */
+    role = "role",
+}
+
+
+/**
+ * **Account**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type Account = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id: number
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name: string
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role: admin.Role
+    
+}
+export type AccountGetPayload<S extends boolean | null | undefined | AccountArgs, U = keyof S> = S extends true
+    ? Account
+    : S extends undefined
+        ? never
+        : S extends AccountArgs | AccountFindManyArgs
+            ? 'include' extends U
+                ? SelectSubset<Account, S> & {
+                    [P in ExistKeys<S['include']>]:
+                    never
+                }
+                : SelectSubset<Account, S>
+            : Account
+
+export type GetAccountAggregateType<T extends AccountAggregateArgs> = {
+    [P in keyof T & keyof AccountAggregateResult]: P extends '_count' | 'count'
+  ? T[P] extends true
+    ? number
+    : GetScalarType<T[P], AccountAggregateResult[P]>
+  : GetScalarType<T[P], AccountAggregateResult[P]>
+}
+
+export type GetAccountGroupByPayload<T extends AccountGroupByArgs> =
+  Array<
+    PickEnumerable<AccountGroupByResult, T['by']> &
+      {
+        [P in ((keyof T) & (keyof AccountGroupByResult))]: P extends '_count'
+          ? T[P] extends boolean
+            ? number
+            : GetScalarType<T[P], AccountGroupByResult[P]>
+          : GetScalarType<T[P], AccountGroupByResult[P]>
+      }
+    >
+
+
+/**
+ * **Account aggregate args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountAggregateArgs = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: AccountAvgAggregateInputType
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: AccountCountAggregateInputType
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: AccountMaxAggregateInputType
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: AccountMinAggregateInputType
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: AccountSumAggregateInputType
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: AccountWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: AccountSerializableScalarFields
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<AccountOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountAggregateResult = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: AccountAvgAggregateResult
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: AccountCountAggregateResult
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: AccountMaxAggregateResult
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: AccountMinAggregateResult
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: AccountSumAggregateResult
+    
+}
+
+
+/**
+ * **Account args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+}
+
+
+/**
+ * **Account avg aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountAvgAggregateInputType = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+}
+
+
+/**
+ * **Account avg aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountAvgAggregateResult = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+}
+
+
+/**
+ * **Account connect or create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountConnectOrCreateInput = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: AccountCreateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereUniqueInput
+    
+}
+
+
+/**
+ * **Account copy args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountCopyArgs = {
+    
+    /**
+     * **Copy**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     copy: AccountUpdateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereUniqueInput
+    
+}
+
+
+/**
+ * **Account copy many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountCopyManyArgs = {
+    
+    /**
+     * **Copy**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     copy: AccountUpdateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account count aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountCountAggregateInputType = {
+    
+    /**
+     * **All**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _all?: boolean
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: boolean
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: boolean
+    
+}
+
+
+/**
+ * **Account count aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountCountAggregateResult = {
+    
+    /**
+     * **All**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _all?: Int64
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: Int64
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: Int64
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: Int64
+    
+}
+
+
+/**
+ * **Account count args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountCountArgs = {
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: AccountWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: AccountSerializableScalarFields
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<AccountOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountCountAggregateInputType
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account create args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountCreateArgs = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: AccountCreateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+}
+
+
+/**
+ * **Account create input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountCreateInput = {
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name: string
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role: admin.Role
+    
+}
+
+
+/**
+ * **Account create many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountCreateManyArgs = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: Enumerable<AccountCreateInput>
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+}
+
+
+/**
+ * **Account create nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountCreateNestedManyInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: Enumerable<AccountWhereUniqueInput>
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: Enumerable<AccountConnectOrCreateInput>
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: Enumerable<AccountCreateInput>
+    
+}
+
+
+/**
+ * **Account create nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountCreateNestedOneInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: AccountWhereUniqueInput
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: AccountConnectOrCreateInput
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: AccountCreateInput
+    
+}
+
+
+/**
+ * **Account delete args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountDeleteArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereUniqueInput
+    
+}
+
+
+/**
+ * **Account delete many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountDeleteManyArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account find first args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountFindFirstArgs = {
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: AccountWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: AccountSerializableScalarFields
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<AccountOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account find many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountFindManyArgs = {
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: AccountWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: AccountSerializableScalarFields
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<AccountOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account find unique args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountFindUniqueArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereUniqueInput
+    
+}
+
+
+/**
+ * **Account group by args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountGroupByArgs = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: AccountAvgAggregateInputType
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: AccountCountAggregateInputType
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: AccountMaxAggregateInputType
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: AccountMinAggregateInputType
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: AccountSumAggregateInputType
+    
+    /**
+     * **By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     by: Enumerable<AccountSerializableScalarFields>
+    
+    /**
+     * **Cursor**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     cursor?: AccountWhereUniqueInput
+    
+    /**
+     * **Distinct**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     distinct?: AccountSerializableScalarFields
+    
+    /**
+     * **Having**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     having?: AccountScalarWhereWithAggregatesInput
+    
+    /**
+     * **Order By**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     orderBy?: Enumerable<AccountOrderByInput>
+    
+    /**
+     * **Page Number**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageNumber?: Int64
+    
+    /**
+     * **Page Size**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     pageSize?: Int64
+    
+    /**
+     * **Skip**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     skip?: Int64
+    
+    /**
+     * **Take**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     take?: Int64
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where?: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account group by result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountGroupByResult = {
+    
+    /**
+     * **Avg**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _avg?: AccountAvgAggregateResult
+    
+    /**
+     * **Count**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _count?: AccountCountAggregateResult
+    
+    /**
+     * **Max**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _max?: AccountMaxAggregateResult
+    
+    /**
+     * **Min**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _min?: AccountMinAggregateResult
+    
+    /**
+     * **Sum**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     _sum?: AccountSumAggregateResult
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: admin.Role
+    
+}
+
+
+/**
+ * **Account include**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountInclude = {
+    
+}
+
+
+/**
+ * **Account list relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountListRelationFilter = {
+    
+    /**
+     * **Every**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     every?: AccountWhereInput
+    
+    /**
+     * **None**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     none?: AccountWhereInput
+    
+    /**
+     * **Some**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     some?: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account max aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountMaxAggregateInputType = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: boolean
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: boolean
+    
+}
+
+
+/**
+ * **Account max aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountMaxAggregateResult = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: admin.Role
+    
+}
+
+
+/**
+ * **Account min aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountMinAggregateInputType = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: boolean
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: boolean
+    
+}
+
+
+/**
+ * **Account min aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountMinAggregateResult = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: admin.Role
+    
+}
+
+
+/**
+ * **Account order by input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountOrderByInput = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: std.Sort
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: std.Sort
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: std.Sort
+    
+}
+
+
+/**
+ * **Account relation filter**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountRelationFilter = {
+    
+    /**
+     * **Is**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     is?: AccountWhereInput
+    
+    /**
+     * **Is Not**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     isNot?: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account scalar update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountScalarUpdateInput = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: admin.Role
+    
+}
+
+
+/**
+ * **Account scalar where with aggregates input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountScalarWhereWithAggregatesInput = {
+    
+    /**
+     * **And**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     AND?: AccountWhereInput[]
+    
+    /**
+     * **Not**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     NOT?: AccountWhereInput
+    
+    /**
+     * **Or**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     OR?: AccountWhereInput[]
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number | std.IntNumberWithAggregatesFilter<number>
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string | std.StringWithAggregatesFilter
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: admin.Role | std.EnumWithAggregatesFilter<admin.Role>
+    
+}
+
+
+/**
+ * **Account select**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountSelect = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: boolean
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: boolean
+    
+}
+
+
+/**
+ * **Account sign in args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountSignInArgs = {
+    
+}
+
+
+/**
+ * **Account sign in checker companions**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountSignInCheckerCompanions = {
+    
+}
+
+
+/**
+ * **Account sign in checker ids**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountSignInCheckerIds = {
+    
+}
+
+
+/**
+ * **Account sign in input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountSignInInput = {
+    
+    /**
+     * **Credentials**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     credentials: AccountSignInArgs
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+}
+
+
+/**
+ * **Account sum aggregate input type**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountSumAggregateInputType = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: boolean
+    
+}
+
+
+/**
+ * **Account sum aggregate result**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountSumAggregateResult = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: Int64
+    
+}
+
+
+/**
+ * **Account update args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountUpdateArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: AccountUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereUniqueInput
+    
+}
+
+
+/**
+ * **Account update input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountUpdateInput = {
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: admin.Role
+    
+}
+
+
+/**
+ * **Account update many args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountUpdateManyArgs = {
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: AccountUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account update many with where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountUpdateManyWithWhereInput = {
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: AccountUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereInput
+    
+}
+
+
+/**
+ * **Account update nested many input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountUpdateNestedManyInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: Enumerable<AccountWhereUniqueInput>
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: Enumerable<AccountConnectOrCreateInput>
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: Enumerable<AccountCreateInput>
+    
+    /**
+     * **Delete**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     delete?: Enumerable<AccountWhereUniqueInput>
+    
+    /**
+     * **Delete Many**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     deleteMany?: Enumerable<AccountWhereInput>
+    
+    /**
+     * **Disconnect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     disconnect?: Enumerable<AccountWhereUniqueInput>
+    
+    /**
+     * **Set**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     set?: Enumerable<AccountWhereUniqueInput>
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update?: Enumerable<AccountUpdateWithWhereUniqueInput>
+    
+    /**
+     * **Update Many**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     updateMany?: Enumerable<AccountUpdateManyWithWhereInput>
+    
+    /**
+     * **Upsert**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     upsert?: Enumerable<AccountUpsertWithWhereUniqueInput>
+    
+}
+
+
+/**
+ * **Account update nested one input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountUpdateNestedOneInput = {
+    
+    /**
+     * **Connect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connect?: AccountWhereUniqueInput
+    
+    /**
+     * **Connect Or Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     connectOrCreate?: AccountConnectOrCreateInput
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create?: AccountCreateInput
+    
+    /**
+     * **Delete**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     delete?: boolean
+    
+    /**
+     * **Disconnect**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     disconnect?: boolean
+    
+    /**
+     * **Set**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     set?: AccountWhereUniqueInput
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update?: AccountUpdateInput
+    
+    /**
+     * **Upsert**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     upsert?: AccountUpsertWithWhereUniqueInput
+    
+}
+
+
+/**
+ * **Account update with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountUpdateWithWhereUniqueInput = {
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: AccountUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereUniqueInput
+    
+}
+
+
+/**
+ * **Account upsert args**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountUpsertArgs = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: AccountCreateInput
+    
+    /**
+     * **Include**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     include?: AccountInclude
+    
+    /**
+     * **Select**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     select?: AccountSelect
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: AccountUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereUniqueInput
+    
+}
+
+
+/**
+ * **Account upsert with where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountUpsertWithWhereUniqueInput = {
+    
+    /**
+     * **Create**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     create: AccountCreateInput
+    
+    /**
+     * **Update**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     update: AccountUpdateInput
+    
+    /**
+     * **Where**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     where: AccountWhereUniqueInput
+    
+}
+
+
+/**
+ * **Account where input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountWhereInput = {
+    
+    /**
+     * **And**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     AND?: AccountWhereInput[]
+    
+    /**
+     * **Not**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     NOT?: AccountWhereInput
+    
+    /**
+     * **Or**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     OR?: AccountWhereInput[]
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id?: number | std.Filter<number>
+    
+    /**
+     * **Name**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     name?: string | std.StringFilter
+    
+    /**
+     * **Role**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     role?: admin.Role | std.EnumFilter<admin.Role>
+    
+}
+
+
+/**
+ * **Account where unique input**
+ *
+ * This synthesized interface doesn't have a description
+ */
+export type AccountWhereUniqueInput = {
+    
+    /**
+     * **Id**
+     *
+     * This synthesized field doesn't have a description.
+     */
+     id: number
+    
+}
+
+
+
+export namespace admin {
+
+
+    /**
+     * **Role**
+     *
+     * This enum doesn't have a description.
+     */
+    export type Role = "owner" | "editor" | "viewer"
+
+    /**
+     * **Audit direct relations**
+     *
+     * This synthesized enum doesn't have a description.
+     */
+    export type AuditDirectRelations = undefined
+
+    /**
+     * **Audit indirect relations**
+     *
+     * This synthesized enum doesn't have a description.
+     */
+    export type AuditIndirectRelations = undefined
+
+    /**
+     * **Audit relations**
+     *
+     * This synthesized enum doesn't have a description.
+     */
+    export type AuditRelations = undefined
+
+    /**
+     * **Audit scalar fields**
+     *
+     * This synthesized enum doesn't have a description.
+     */
+    export type AuditScalarFields = "action" | "createdAt" | "id" | "role"
+
+    /**
+     * **Audit serializable scalar fields**
+     *
+     * This synthesized enum doesn't have a description.
+     */
+    export type AuditSerializableScalarFields = "action" | "createdAt" | "id" | "role"
+
+    /**
+     * ## Role
+     *
+     * This enum doesn't have a description.
+     */
+    export const enum RoleEnumType {
+
+        /**
+         * ### Owner
+         *
+         * This enum member doesn't have a description.
+         */
+        owner = "owner",
+
+        /**
+         * ### Editor
+         *
+         * This enum member doesn't have a description.
+         */
+        editor = "editor",
+
+        /**
+         * ### Viewer
+         *
+         * This enum member doesn't have a description.
+         */
+        viewer = "viewer",
+    }
+
+    /**
+     * ## Audit direct relations
+     *
+     * This synthesized enum doesn't have a description.
+     */
+    export const enum AuditDirectRelationsEnumType {
+    }
+
+    /**
+     * ## Audit indirect relations
+     *
+     * This synthesized enum doesn't have a description.
+     */
+    export const enum AuditIndirectRelationsEnumType {
+    }
+
+    /**
+     * ## Audit relations
+     *
+     * This synthesized enum doesn't have a description.
+     */
+    export const enum AuditRelationsEnumType {
+    }
+
+    /**
+     * ## Audit scalar fields
+     *
+     * This synthesized enum doesn't have a description.
+     */
+    export const enum AuditScalarFieldsEnumType {
+
+        /**
+         * ### Action
+         *
+         * This synthesized enum member doesn't have a description.
+         */
+        action = "action",
+
+        /**
+         * ### Created at
+         *
+         * This synthesized enum member doesn't have a description.
+         */
+        createdAt = "createdAt",
+
+        /**
+         * ### Id
+         *
+         * This synthesized enum member doesn't have a description.
+         */
+        id = "id",
+
+        /**
+         * ### Role
+         *
+         * This synthesized enum member doesn't have a description.
+         */
+        role = "role",
+    }
+
+    /**
+     * ## Audit serializable scalar fields
+     *
+     * This synthesized enum doesn't have a description.
+     */
+    export const enum AuditSerializableScalarFieldsEnumType {
+
+        /**
+         * ### Action
+         *
+         * This synthesized enum member doesn't have a description.
+         */
+        action = "action",
+
+        /**
+         * ### Created at
+         *
+         * This synthesized enum member doesn't have a description.
+         */
+        createdAt = "createdAt",
+
+        /**
+         * ### Id
+         *
+         * This synthesized enum member doesn't have a description.
+         */
+        id = "id",
+
+        /**
+         * ### Role
+         *
+         * This synthesized enum member doesn't have a description.
+         */
+        role = "role",
+    }
+
+
+    /**
+     * **Audit**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type Audit = {
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action: string
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt: Date
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id: number
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role: admin.Role
+        
+    }
+    export type AuditGetPayload<S extends boolean | null | undefined | AuditArgs, U = keyof S> = S extends true
+        ? Audit
+        : S extends undefined
+            ? never
+            : S extends AuditArgs | AuditFindManyArgs
+                ? 'include' extends U
+                    ? SelectSubset<Audit, S> & {
+                        [P in ExistKeys<S['include']>]:
+                        never
+                    }
+                    : SelectSubset<Audit, S>
+                : Audit
+
+    export type GetAuditAggregateType<T extends AuditAggregateArgs> = {
+        [P in keyof T & keyof AuditAggregateResult]: P extends '_count' | 'count'
+      ? T[P] extends true
+        ? number
+        : GetScalarType<T[P], AuditAggregateResult[P]>
+      : GetScalarType<T[P], AuditAggregateResult[P]>
+    }
+
+    export type GetAuditGroupByPayload<T extends AuditGroupByArgs> =
+      Array<
+        PickEnumerable<AuditGroupByResult, T['by']> &
+          {
+            [P in ((keyof T) & (keyof AuditGroupByResult))]: P extends '_count'
+              ? T[P] extends boolean
+                ? number
+                : GetScalarType<T[P], AuditGroupByResult[P]>
+              : GetScalarType<T[P], AuditGroupByResult[P]>
+          }
+        >
+
+
+    /**
+     * **Audit aggregate args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditAggregateArgs = {
+        
+        /**
+         * **Avg**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _avg?: admin.AuditAvgAggregateInputType
+        
+        /**
+         * **Count**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _count?: admin.AuditCountAggregateInputType
+        
+        /**
+         * **Max**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _max?: admin.AuditMaxAggregateInputType
+        
+        /**
+         * **Min**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _min?: admin.AuditMinAggregateInputType
+        
+        /**
+         * **Sum**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _sum?: admin.AuditSumAggregateInputType
+        
+        /**
+         * **Cursor**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         cursor?: admin.AuditWhereUniqueInput
+        
+        /**
+         * **Distinct**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         distinct?: admin.AuditSerializableScalarFields
+        
+        /**
+         * **Order By**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         orderBy?: Enumerable<admin.AuditOrderByInput>
+        
+        /**
+         * **Page Number**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         pageNumber?: Int64
+        
+        /**
+         * **Page Size**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         pageSize?: Int64
+        
+        /**
+         * **Skip**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         skip?: Int64
+        
+        /**
+         * **Take**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         take?: Int64
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where?: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditAggregateResult = {
+        
+        /**
+         * **Avg**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _avg?: admin.AuditAvgAggregateResult
+        
+        /**
+         * **Count**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _count?: admin.AuditCountAggregateResult
+        
+        /**
+         * **Max**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _max?: admin.AuditMaxAggregateResult
+        
+        /**
+         * **Min**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _min?: admin.AuditMinAggregateResult
+        
+        /**
+         * **Sum**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _sum?: admin.AuditSumAggregateResult
+        
+    }
+
+
+    /**
+     * **Audit args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditArgs = {
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+    }
+
+
+    /**
+     * **Audit avg aggregate input type**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditAvgAggregateInputType = {
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: boolean
+        
+    }
+
+
+    /**
+     * **Audit avg aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditAvgAggregateResult = {
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: number
+        
+    }
+
+
+    /**
+     * **Audit connect or create input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditConnectOrCreateInput = {
+        
+        /**
+         * **Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         create: admin.AuditCreateInput
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereUniqueInput
+        
+    }
+
+
+    /**
+     * **Audit copy args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditCopyArgs = {
+        
+        /**
+         * **Copy**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         copy: admin.AuditUpdateInput
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereUniqueInput
+        
+    }
+
+
+    /**
+     * **Audit copy many args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditCopyManyArgs = {
+        
+        /**
+         * **Copy**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         copy: admin.AuditUpdateInput
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit count aggregate input type**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditCountAggregateInputType = {
+        
+        /**
+         * **All**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _all?: boolean
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: boolean
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: boolean
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: boolean
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: boolean
+        
+    }
+
+
+    /**
+     * **Audit count aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditCountAggregateResult = {
+        
+        /**
+         * **All**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _all?: Int64
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: Int64
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: Int64
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: Int64
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: Int64
+        
+    }
+
+
+    /**
+     * **Audit count args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditCountArgs = {
+        
+        /**
+         * **Cursor**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         cursor?: admin.AuditWhereUniqueInput
+        
+        /**
+         * **Distinct**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         distinct?: admin.AuditSerializableScalarFields
+        
+        /**
+         * **Order By**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         orderBy?: Enumerable<admin.AuditOrderByInput>
+        
+        /**
+         * **Page Number**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         pageNumber?: Int64
+        
+        /**
+         * **Page Size**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         pageSize?: Int64
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditCountAggregateInputType
+        
+        /**
+         * **Skip**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         skip?: Int64
+        
+        /**
+         * **Take**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         take?: Int64
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where?: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit create args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditCreateArgs = {
+        
+        /**
+         * **Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         create: admin.AuditCreateInput
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+    }
+
+
+    /**
+     * **Audit create input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditCreateInput = {
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action: string
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role: admin.Role
+        
+    }
+
+
+    /**
+     * **Audit create many args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditCreateManyArgs = {
+        
+        /**
+         * **Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         create: Enumerable<admin.AuditCreateInput>
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+    }
+
+
+    /**
+     * **Audit create nested many input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditCreateNestedManyInput = {
+        
+        /**
+         * **Connect**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         connect?: Enumerable<admin.AuditWhereUniqueInput>
+        
+        /**
+         * **Connect Or Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         connectOrCreate?: Enumerable<admin.AuditConnectOrCreateInput>
+        
+        /**
+         * **Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         create?: Enumerable<admin.AuditCreateInput>
+        
+    }
+
+
+    /**
+     * **Audit create nested one input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditCreateNestedOneInput = {
+        
+        /**
+         * **Connect**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         connect?: admin.AuditWhereUniqueInput
+        
+        /**
+         * **Connect Or Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         connectOrCreate?: admin.AuditConnectOrCreateInput
+        
+        /**
+         * **Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         create?: admin.AuditCreateInput
+        
+    }
+
+
+    /**
+     * **Audit delete args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditDeleteArgs = {
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereUniqueInput
+        
+    }
+
+
+    /**
+     * **Audit delete many args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditDeleteManyArgs = {
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit find first args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditFindFirstArgs = {
+        
+        /**
+         * **Cursor**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         cursor?: admin.AuditWhereUniqueInput
+        
+        /**
+         * **Distinct**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         distinct?: admin.AuditSerializableScalarFields
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Order By**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         orderBy?: Enumerable<admin.AuditOrderByInput>
+        
+        /**
+         * **Page Number**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         pageNumber?: Int64
+        
+        /**
+         * **Page Size**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         pageSize?: Int64
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+        /**
+         * **Skip**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         skip?: Int64
+        
+        /**
+         * **Take**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         take?: Int64
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where?: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit find many args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditFindManyArgs = {
+        
+        /**
+         * **Cursor**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         cursor?: admin.AuditWhereUniqueInput
+        
+        /**
+         * **Distinct**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         distinct?: admin.AuditSerializableScalarFields
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Order By**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         orderBy?: Enumerable<admin.AuditOrderByInput>
+        
+        /**
+         * **Page Number**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         pageNumber?: Int64
+        
+        /**
+         * **Page Size**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         pageSize?: Int64
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+        /**
+         * **Skip**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         skip?: Int64
+        
+        /**
+         * **Take**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         take?: Int64
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where?: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit find unique args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditFindUniqueArgs = {
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereUniqueInput
+        
+    }
+
+
+    /**
+     * **Audit group by args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditGroupByArgs = {
+        
+        /**
+         * **Avg**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _avg?: admin.AuditAvgAggregateInputType
+        
+        /**
+         * **Count**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _count?: admin.AuditCountAggregateInputType
+        
+        /**
+         * **Max**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _max?: admin.AuditMaxAggregateInputType
+        
+        /**
+         * **Min**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _min?: admin.AuditMinAggregateInputType
+        
+        /**
+         * **Sum**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _sum?: admin.AuditSumAggregateInputType
+        
+        /**
+         * **By**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         by: Enumerable<admin.AuditSerializableScalarFields>
+        
+        /**
+         * **Cursor**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         cursor?: admin.AuditWhereUniqueInput
+        
+        /**
+         * **Distinct**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         distinct?: admin.AuditSerializableScalarFields
+        
+        /**
+         * **Having**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         having?: admin.AuditScalarWhereWithAggregatesInput
+        
+        /**
+         * **Order By**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         orderBy?: Enumerable<admin.AuditOrderByInput>
+        
+        /**
+         * **Page Number**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         pageNumber?: Int64
+        
+        /**
+         * **Page Size**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         pageSize?: Int64
+        
+        /**
+         * **Skip**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         skip?: Int64
+        
+        /**
+         * **Take**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         take?: Int64
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where?: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit group by result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditGroupByResult = {
+        
+        /**
+         * **Avg**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _avg?: admin.AuditAvgAggregateResult
+        
+        /**
+         * **Count**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _count?: admin.AuditCountAggregateResult
+        
+        /**
+         * **Max**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _max?: admin.AuditMaxAggregateResult
+        
+        /**
+         * **Min**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _min?: admin.AuditMinAggregateResult
+        
+        /**
+         * **Sum**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         _sum?: admin.AuditSumAggregateResult
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: string
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: Date
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: number
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: admin.Role
+        
+    }
+
+
+    /**
+     * **Audit include**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditInclude = {
+        
+    }
+
+
+    /**
+     * **Audit list relation filter**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditListRelationFilter = {
+        
+        /**
+         * **Every**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         every?: admin.AuditWhereInput
+        
+        /**
+         * **None**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         none?: admin.AuditWhereInput
+        
+        /**
+         * **Some**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         some?: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit max aggregate input type**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditMaxAggregateInputType = {
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: boolean
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: boolean
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: boolean
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: boolean
+        
+    }
+
+
+    /**
+     * **Audit max aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditMaxAggregateResult = {
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: string
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: Date
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: number
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: admin.Role
+        
+    }
+
+
+    /**
+     * **Audit min aggregate input type**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditMinAggregateInputType = {
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: boolean
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: boolean
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: boolean
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: boolean
+        
+    }
+
+
+    /**
+     * **Audit min aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditMinAggregateResult = {
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: string
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: Date
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: number
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: admin.Role
+        
+    }
+
+
+    /**
+     * **Audit order by input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditOrderByInput = {
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: std.Sort
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: std.Sort
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: std.Sort
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: std.Sort
+        
+    }
+
+
+    /**
+     * **Audit relation filter**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditRelationFilter = {
+        
+        /**
+         * **Is**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         is?: admin.AuditWhereInput
+        
+        /**
+         * **Is Not**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         isNot?: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit scalar update input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditScalarUpdateInput = {
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: string
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: Date
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: number
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: admin.Role
+        
+    }
+
+
+    /**
+     * **Audit scalar where with aggregates input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditScalarWhereWithAggregatesInput = {
+        
+        /**
+         * **And**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         AND?: admin.AuditWhereInput[]
+        
+        /**
+         * **Not**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         NOT?: admin.AuditWhereInput
+        
+        /**
+         * **Or**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         OR?: admin.AuditWhereInput[]
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: string | std.StringWithAggregatesFilter
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: Date | std.AggregatesFilter<Date>
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: number | std.IntNumberWithAggregatesFilter<number>
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: admin.Role | std.EnumWithAggregatesFilter<admin.Role>
+        
+    }
+
+
+    /**
+     * **Audit select**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditSelect = {
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: boolean
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: boolean
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: boolean
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: boolean
+        
+    }
+
+
+    /**
+     * **Audit sign in args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditSignInArgs = {
+        
+    }
+
+
+    /**
+     * **Audit sign in checker companions**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditSignInCheckerCompanions = {
+        
+    }
+
+
+    /**
+     * **Audit sign in checker ids**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditSignInCheckerIds = {
+        
+    }
+
+
+    /**
+     * **Audit sign in input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditSignInInput = {
+        
+        /**
+         * **Credentials**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         credentials: admin.AuditSignInArgs
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+    }
+
+
+    /**
+     * **Audit sum aggregate input type**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditSumAggregateInputType = {
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: boolean
+        
+    }
+
+
+    /**
+     * **Audit sum aggregate result**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditSumAggregateResult = {
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: Int64
+        
+    }
+
+
+    /**
+     * **Audit update args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditUpdateArgs = {
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+        /**
+         * **Update**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         update: admin.AuditUpdateInput
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereUniqueInput
+        
+    }
+
+
+    /**
+     * **Audit update input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditUpdateInput = {
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: string
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: admin.Role
+        
+    }
+
+
+    /**
+     * **Audit update many args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditUpdateManyArgs = {
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+        /**
+         * **Update**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         update: admin.AuditUpdateInput
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit update many with where input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditUpdateManyWithWhereInput = {
+        
+        /**
+         * **Update**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         update: admin.AuditUpdateInput
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereInput
+        
+    }
+
+
+    /**
+     * **Audit update nested many input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditUpdateNestedManyInput = {
+        
+        /**
+         * **Connect**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         connect?: Enumerable<admin.AuditWhereUniqueInput>
+        
+        /**
+         * **Connect Or Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         connectOrCreate?: Enumerable<admin.AuditConnectOrCreateInput>
+        
+        /**
+         * **Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         create?: Enumerable<admin.AuditCreateInput>
+        
+        /**
+         * **Delete**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         delete?: Enumerable<admin.AuditWhereUniqueInput>
+        
+        /**
+         * **Delete Many**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         deleteMany?: Enumerable<admin.AuditWhereInput>
+        
+        /**
+         * **Disconnect**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         disconnect?: Enumerable<admin.AuditWhereUniqueInput>
+        
+        /**
+         * **Set**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         set?: Enumerable<admin.AuditWhereUniqueInput>
+        
+        /**
+         * **Update**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         update?: Enumerable<admin.AuditUpdateWithWhereUniqueInput>
+        
+        /**
+         * **Update Many**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         updateMany?: Enumerable<admin.AuditUpdateManyWithWhereInput>
+        
+        /**
+         * **Upsert**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         upsert?: Enumerable<admin.AuditUpsertWithWhereUniqueInput>
+        
+    }
+
+
+    /**
+     * **Audit update nested one input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditUpdateNestedOneInput = {
+        
+        /**
+         * **Connect**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         connect?: admin.AuditWhereUniqueInput
+        
+        /**
+         * **Connect Or Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         connectOrCreate?: admin.AuditConnectOrCreateInput
+        
+        /**
+         * **Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         create?: admin.AuditCreateInput
+        
+        /**
+         * **Delete**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         delete?: boolean
+        
+        /**
+         * **Disconnect**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         disconnect?: boolean
+        
+        /**
+         * **Set**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         set?: admin.AuditWhereUniqueInput
+        
+        /**
+         * **Update**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         update?: admin.AuditUpdateInput
+        
+        /**
+         * **Upsert**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         upsert?: admin.AuditUpsertWithWhereUniqueInput
+        
+    }
+
+
+    /**
+     * **Audit update with where unique input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditUpdateWithWhereUniqueInput = {
+        
+        /**
+         * **Update**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         update: admin.AuditUpdateInput
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereUniqueInput
+        
+    }
+
+
+    /**
+     * **Audit upsert args**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditUpsertArgs = {
+        
+        /**
+         * **Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         create: admin.AuditCreateInput
+        
+        /**
+         * **Include**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         include?: admin.AuditInclude
+        
+        /**
+         * **Select**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         select?: admin.AuditSelect
+        
+        /**
+         * **Update**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         update: admin.AuditUpdateInput
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereUniqueInput
+        
+    }
+
+
+    /**
+     * **Audit upsert with where unique input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditUpsertWithWhereUniqueInput = {
+        
+        /**
+         * **Create**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         create: admin.AuditCreateInput
+        
+        /**
+         * **Update**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         update: admin.AuditUpdateInput
+        
+        /**
+         * **Where**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         where: admin.AuditWhereUniqueInput
+        
+    }
+
+
+    /**
+     * **Audit where input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditWhereInput = {
+        
+        /**
+         * **And**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         AND?: admin.AuditWhereInput[]
+        
+        /**
+         * **Not**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         NOT?: admin.AuditWhereInput
+        
+        /**
+         * **Or**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         OR?: admin.AuditWhereInput[]
+        
+        /**
+         * **Action**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         action?: string | std.StringFilter
+        
+        /**
+         * **Created At**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         createdAt?: Date | std.Filter<Date>
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id?: number | std.Filter<number>
+        
+        /**
+         * **Role**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         role?: admin.Role | std.EnumFilter<admin.Role>
+        
+    }
+
+
+    /**
+     * **Audit where unique input**
+     *
+     * This synthesized interface doesn't have a description
+     */
+    export type AuditWhereUniqueInput = {
+        
+        /**
+         * **Id**
+         *
+         * This synthesized field doesn't have a description.
+         */
+         id: number
+        
+    }
+
+
+
+    export namespace reports {
+
+
+
+        /**
+         * **Report path arguments**
+         *
+         * This interface doesn't have a description.
+         */
+        export type ReportPathArguments = {
+            
+            /**
+             * **Year**
+             *
+             * This interface field doesn't have a description.
+             */
+             year: number
+            
+            /**
+             * **Month**
+             *
+             * This interface field doesn't have a description.
+             */
+             month: number
+            
+        }
+
+
+        /**
+         * **Summary**
+         *
+         * This interface doesn't have a description.
+         */
+        export type Summary<T> = {
+            
+            /**
+             * **Total**
+             *
+             * This interface field doesn't have a description.
+             */
+             total: number
+            
+            /**
+             * **Latest**
+             *
+             * This interface field doesn't have a description.
+             */
+             latest?: T
+            
+            /**
+             * **By role**
+             *
+             * This interface field doesn't have a description.
+             */
+             byRole: admin.Role[]
+            
+        }
+
+
+
+
+
+
+        export interface ReportsNamespaceDelegate {
+
+            
+
+            
+
+            
+
+            /**
+             * Get a new client altered with `headers`.
+             * @param {headers?} headers - The new headers.
+             */
+            $headers(headers?: {[key: string]: string} | undefined): Teo
+        }
+
+
+
+
+
+    }
+
+
+
+
+    export interface AuditDelegate {
+
+        
+        /**
+         * Find a unique audit.
+         *
+         * @example
+         * ```ts
+         * // Find a unique audit
+         * const { data } = await teo.admin.audit.findUnique({
+         *     where: {}, // a unique filter
+         * })
+         * ```
+         */
+        findUnique<T extends admin.AuditFindUniqueArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>>>
+        
+        /**
+         * Find the first audit matching a filter.
+         *
+         * @example
+         * ```ts
+         * // Find the first audit matching a filter
+         * const { data } = await teo.admin.audit.findFirst({
+         *     where: {}, // a filter
+         *     orderBy: {}, // the order to find in
+         * })
+         * ```
+         */
+        findFirst<T extends admin.AuditFindFirstArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>>>
+        
+        /**
+         * Find audits matching a filter.
+         *
+         * @example
+         * ```ts
+         * // Find audits matching a filter
+         * const { data } = await teo.admin.audit.findMany({
+         *     where: {}, // a filter
+         *     orderBy: {}, // the order to find in
+         *     skip: 0, // records to skip
+         *     take: 10, // records to take
+         * })
+         * ```
+         */
+        findMany<T extends admin.AuditFindManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>[], std.PagingInfo>>
+        
+        /**
+         * Create an audit.
+         *
+         * @example
+         * ```ts
+         * // Create an audit
+         * const { data } = await teo.admin.audit.create({
+         *     create: {}, // the data to create
+         * })
+         * ```
+         */
+        create<T extends admin.AuditCreateArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>>>
+        
+        /**
+         * Update a unique audit.
+         *
+         * @example
+         * ```ts
+         * // Update a unique audit
+         * const { data } = await teo.admin.audit.update({
+         *     where: {}, // a unique filter
+         *     update: {}, // the data to update
+         * })
+         * ```
+         */
+        update<T extends admin.AuditUpdateArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>>>
+        
+        /**
+         * Update a unique audit, or create it if it doesn't exist.
+         *
+         * @example
+         * ```ts
+         * // Update a unique audit, or create it if it doesn't exist
+         * const { data } = await teo.admin.audit.upsert({
+         *     where: {}, // a unique filter
+         *     create: {}, // the data to create
+         *     update: {}, // the data to update
+         * })
+         * ```
+         */
+        upsert<T extends admin.AuditUpsertArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>>>
+        
+        /**
+         * Copy a unique audit.
+         *
+         * @example
+         * ```ts
+         * // Copy a unique audit
+         * const { data } = await teo.admin.audit.copy({
+         *     where: {}, // a unique filter
+         *     copy: {}, // the data to change on the copy
+         * })
+         * ```
+         */
+        copy<T extends admin.AuditCopyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>>>
+        
+        /**
+         * Delete a unique audit.
+         *
+         * @example
+         * ```ts
+         * // Delete a unique audit
+         * const { data } = await teo.admin.audit.delete({
+         *     where: {}, // a unique filter
+         * })
+         * ```
+         */
+        delete<T extends admin.AuditDeleteArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>>>
+        
+        /**
+         * Create many audits.
+         *
+         * @example
+         * ```ts
+         * // Create many audits
+         * const { data } = await teo.admin.audit.createMany({
+         *     create: [], // the data to create
+         * })
+         * ```
+         */
+        createMany<T extends admin.AuditCreateManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>[], std.PagingInfo>>
+        
+        /**
+         * Update audits matching a filter.
+         *
+         * @example
+         * ```ts
+         * // Update audits matching a filter
+         * const { data } = await teo.admin.audit.updateMany({
+         *     where: {}, // a filter
+         *     update: {}, // the data to update
+         * })
+         * ```
+         */
+        updateMany<T extends admin.AuditUpdateManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>[], std.PagingInfo>>
+        
+        /**
+         * Copy audits matching a filter.
+         *
+         * @example
+         * ```ts
+         * // Copy audits matching a filter
+         * const { data } = await teo.admin.audit.copyMany({
+         *     where: {}, // a filter
+         *     copy: {}, // the data to change on the copies
+         * })
+         * ```
+         */
+        copyMany<T extends admin.AuditCopyManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>[], std.PagingInfo>>
+        
+        /**
+         * Delete audits matching a filter.
+         *
+         * @example
+         * ```ts
+         * // Delete audits matching a filter
+         * const { data } = await teo.admin.audit.deleteMany({
+         *     where: {}, // a filter
+         * })
+         * ```
+         */
+        deleteMany<T extends admin.AuditDeleteManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, admin.Audit, admin.AuditGetPayload<T>>[], std.PagingInfo>>
+        
+        /**
+         * Count audits matching a filter.
+         *
+         * @example
+         * ```ts
+         * // Count audits matching a filter
+         * const { data } = await teo.admin.audit.count({
+         *     where: {}, // a filter
+         * })
+         * ```
+         */
+        count<T extends admin.AuditCountArgs>(body: Subset<T, admin.AuditCountArgs>,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<T extends Record<'select', any>
+           ? T['select'] extends true
+             ? number
+             : GetScalarType<T['select'], AuditCountAggregateResult>
+           : number>>
+        
+        /**
+         * Aggregate on audits matching a filter.
+         *
+         * @example
+         * ```ts
+         * // Aggregate on audits matching a filter
+         * const { data } = await teo.admin.audit.aggregate({
+         *     where: {}, // a filter
+         *     _count: { _all: true }, // aggregations like _avg, _sum, _min and _max
+         * })
+         * ```
+         */
+        aggregate<T extends admin.AuditAggregateArgs>(body: Subset<T, admin.AuditAggregateArgs>,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<Getadmin.AuditAggregateType<T>>>
+        
+        /**
+         * Group audits by fields and aggregate on each group.
+         *
+         * @example
+         * ```ts
+         * // Group audits by fields and aggregate on each group
+         * const { data } = await teo.admin.audit.groupBy({
+         *     by: [], // the fields to group by
+         *     _count: { _all: true }, // aggregations of each group
+         * })
+         * ```
+         */
+        groupBy<T extends admin.AuditGroupByArgs,
+          HasSelectOrTake extends Or<
+            Extends<'skip', Keys<T>>,
+            Extends<'take', Keys<T>>
+          >,
+          OrderByArg extends True extends HasSelectOrTake
+            ? { orderBy: admin.AuditGroupByArgs['orderBy'] }
+            : { orderBy?: admin.AuditGroupByArgs['orderBy'] },
+          OrderFields extends ExcludeUnderscoreKeys<Keys<MaybeTupleToUnion<T['orderBy']>>>,
+          ByFields extends MaybeTupleToUnion<T['by']>,
+          ByValid extends Has<ByFields, OrderFields>,
+          HavingFields extends GetHavingFields<T['having']>,
+          HavingValid extends Has<ByFields, HavingFields>,
+          ByEmpty extends T['by'] extends never[] ? True : False,
+          InputErrors extends ByEmpty extends True
+          ? `Error: "by" must not be empty.`
+          : HavingValid extends False
+          ? {
+              [P in HavingFields]: P extends ByFields
+                ? never
+                : P extends string
+                ? `Error: Field "${P}" used in "having" needs to be provided in "by".`
+                : [
+                    Error,
+                    'Field ',
+                    P,
+                    ` in "having" needs to be provided in "by"`,
+                  ]
+            }[HavingFields]
+          : 'take' extends Keys<T>
+          ? 'orderBy' extends Keys<T>
+            ? ByValid extends True
+              ? {}
+              : {
+                  [P in OrderFields]: P extends ByFields
+                    ? never
+                    : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+                }[OrderFields]
+            : 'Error: If you provide "take", you also need to provide "orderBy"'
+          : 'skip' extends Keys<T>
+          ? 'orderBy' extends Keys<T>
+            ? ByValid extends True
+              ? {}
+              : {
+                  [P in OrderFields]: P extends ByFields
+                    ? never
+                    : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+                }[OrderFields]
+            : 'Error: If you provide "skip", you also need to provide "orderBy"'
+          : ByValid extends True
+          ? {}
+          : {
+              [P in OrderFields]: P extends ByFields
+                ? never
+                : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+            }[OrderFields]>(body: SubsetIntersection<T, admin.AuditGroupByArgs, OrderByArg> & InputErrors,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<{} extends InputErrors ? Getadmin.AuditGroupByPayload<T> : InputErrors>>
+        
+
+        
+
+        
+
+        /**
+         * Get a new client altered with `headers`.
+         * @param {headers?} headers - The new headers.
+         */
+        $headers(headers?: {[key: string]: string} | undefined): Teo
+    }
+
+    export interface AdminNamespaceDelegate {
+
+        
+
+        
+        reports: admin.reports.ReportsNamespaceDelegate
+        
+
+        
+        audit: admin.AuditDelegate
+        
+
+        /**
+         * Get a new client altered with `headers`.
+         * @param {headers?} headers - The new headers.
+         */
+        $headers(headers?: {[key: string]: string} | undefined): Teo
+    }
+
+
+
+
+
+}
+
+export namespace std {
+
+
+    /**
+     * **Sort Order**
+     *
+     * Represents the sort order
+     */
+    export type Sort = "asc" | "desc"
+
+    /**
+     * **String Match Mode**
+     *
+     * Whether the string query is case sensitive or not
+     */
+    export type StringMatchMode = "default" | "caseInsensitive"
+
+    /**
+     * ## Sort Order
+     *
+     * Represents the sort order
+     */
+    export const enum SortEnumType {
+
+        /**
+         * ### Asc
+         *
+         * This enum member doesn't have a description.
+         */
+        asc = "asc",
+
+        /**
+         * ### Desc
+         *
+         * This enum member doesn't have a description.
+         */
+        desc = "desc",
+    }
+
+    /**
+     * ## String Match Mode
+     *
+     * Whether the string query is case sensitive or not
+     */
+    export const enum StringMatchModeEnumType {
+
+        /**
+         * ### Default
+         *
+         * This enum member doesn't have a description.
+         */
+        default = "default",
+
+        /**
+         * ### Case insensitive
+         *
+         * This enum member doesn't have a description.
+         */
+        caseInsensitive = "caseInsensitive",
+    }
+
+
+    /**
+     * **Aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type AggregatesFilter<T> = std.Filter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.Filter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.Filter<T>
+        
+    }
+
+
+    /**
+     * **Array atomic update operation input**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ArrayAtomicUpdateOperationInput<T> = {
+        
+        /**
+         * **Push**
+         *
+         * This interface field doesn't have a description.
+         */
+         push?: T
+        
+    }
+
+
+    /**
+     * **Array filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ArrayFilter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T[]
+        
+        /**
+         * **Has**
+         *
+         * This interface field doesn't have a description.
+         */
+         has?: T
+        
+        /**
+         * **Has some**
+         *
+         * This interface field doesn't have a description.
+         */
+         hasSome?: T[]
+        
+        /**
+         * **Has every**
+         *
+         * This interface field doesn't have a description.
+         */
+         hasEvery?: T[]
+        
+        /**
+         * **Is empty**
+         *
+         * This interface field doesn't have a description.
+         */
+         isEmpty?: boolean
+        
+        /**
+         * **Length**
+         *
+         * This interface field doesn't have a description.
+         */
+         length?: number
+        
+    }
+
+
+    /**
+     * **Array nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ArrayNullableFilter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T[] | null
+        
+        /**
+         * **Has**
+         *
+         * This interface field doesn't have a description.
+         */
+         has?: T
+        
+        /**
+         * **Has some**
+         *
+         * This interface field doesn't have a description.
+         */
+         hasSome?: T[]
+        
+        /**
+         * **Has every**
+         *
+         * This interface field doesn't have a description.
+         */
+         hasEvery?: T[]
+        
+        /**
+         * **Is empty**
+         *
+         * This interface field doesn't have a description.
+         */
+         isEmpty?: boolean
+        
+        /**
+         * **Length**
+         *
+         * This interface field doesn't have a description.
+         */
+         length?: number
+        
+    }
+
+
+    /**
+     * **Array nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ArrayNullableWithAggregatesFilter<T> = std.ArrayNullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.ArrayNullableFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.ArrayNullableFilter<T>
+        
+    }
+
+
+    /**
+     * **Array with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ArrayWithAggregatesFilter<T> = std.ArrayFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.ArrayFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.ArrayFilter<T>
+        
+    }
+
+
+    /**
+     * **Bool filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type BoolFilter = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: boolean
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: boolean | std.BoolFilter
+        
+    }
+
+
+    /**
+     * **Bool nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type BoolNullableFilter = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: boolean | null
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: boolean | null | std.BoolNullableFilter
+        
+    }
+
+
+    /**
+     * **Bool nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type BoolNullableWithAggregatesFilter = std.BoolNullableFilter & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.BoolNullableFilter
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.BoolNullableFilter
+        
+    }
+
+
+    /**
+     * **Bool with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type BoolWithAggregatesFilter = std.BoolFilter & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.BoolFilter
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.BoolFilter
+        
+    }
+
+
+    /**
+     * **Data**
+     *
+     * This interface is common for action output
+     */
+    export type Data<T> = {
+        
+        /**
+         * **Data**
+         *
+         * This interface field doesn't have a description.
+         */
+         data: T
+        
+    }
+
+
+    /**
+     * **Data and Meta**
+     *
+     * This interface is common for action output with meta information
+     */
+    export type DataMeta<T, U> = {
+        
+        /**
+         * **Data**
+         *
+         * This interface field doesn't have a description.
+         */
+         data: T
+        
+        /**
+         * **Meta**
+         *
+         * This interface field doesn't have a description.
+         */
+         meta: U
+        
+    }
+
+
+    /**
+     * **Decimal nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type DecimalNullableWithAggregatesFilter<T> = std.NullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.NullableFilter<Decimal>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.NullableFilter<Decimal>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.NullableFilter<Decimal>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.NullableFilter<Decimal>
+        
+    }
+
+
+    /**
+     * **Decimal with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type DecimalWithAggregatesFilter = std.Filter<Decimal> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.Filter<Decimal>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.Filter<Decimal>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.Filter<Decimal>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.Filter<Decimal>
+        
+    }
+
+
+    /**
+     * **Empty**
+     *
+     * The empty interface
+     */
+    export type Empty = {
+        
+    }
+
+
+    /**
+     * **Enum filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type EnumFilter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: T[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: T[]
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: T | std.EnumFilter<T>
+        
+    }
+
+
+    /**
+     * **Enum nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type EnumNullableFilter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T | null
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: (T | null)[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: (T | null)[]
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: T | null | std.EnumNullableFilter<T>
+        
+    }
+
+
+    /**
+     * **Enum nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type EnumNullableWithAggregatesFilter<T> = std.EnumNullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.EnumNullableFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.EnumNullableFilter<T>
+        
+    }
+
+
+    /**
+     * **Enum with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type EnumWithAggregatesFilter<T> = std.EnumFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.EnumFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.EnumFilter<T>
+        
+    }
+
+
+    /**
+     * **Filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type Filter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: T[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: T[]
+        
+        /**
+         * **Lt**
+         *
+         * This interface field doesn't have a description.
+         */
+         lt?: T
+        
+        /**
+         * **Lte**
+         *
+         * This interface field doesn't have a description.
+         */
+         lte?: T
+        
+        /**
+         * **Gt**
+         *
+         * This interface field doesn't have a description.
+         */
+         gt?: T
+        
+        /**
+         * **Gte**
+         *
+         * This interface field doesn't have a description.
+         */
+         gte?: T
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: T | std.Filter<T>
+        
+    }
+
+
+    /**
+     * **Float number nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type FloatNumberNullableWithAggregatesFilter<T> = std.NullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.NullableFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.NullableFilter<T>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.NullableFilter<number>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.NullableFilter<number>
+        
+    }
+
+
+    /**
+     * **Float number with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type FloatNumberWithAggregatesFilter<T> = std.Filter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.Filter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.Filter<T>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.Filter<number>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.Filter<number>
+        
+    }
+
+
+    /**
+     * **Int number nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type IntNumberNullableWithAggregatesFilter<T> = std.NullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.NullableFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.NullableFilter<T>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.NullableFilter<number>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.NullableFilter<Int64>
+        
+    }
+
+
+    /**
+     * **Int number with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type IntNumberWithAggregatesFilter<T> = std.Filter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.Filter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.Filter<T>
+        
+        /**
+         * **Avg**
+         *
+         * This interface field doesn't have a description.
+         */
+         _avg?: std.Filter<number>
+        
+        /**
+         * **Sum**
+         *
+         * This interface field doesn't have a description.
+         */
+         _sum?: std.Filter<Int64>
+        
+    }
+
+
+    /**
+     * **Nullable aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type NullableAggregatesFilter<T> = std.NullableFilter<T> & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.NullableFilter<T>
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.NullableFilter<T>
+        
+    }
+
+
+    /**
+     * **Nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type NullableFilter<T> = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: T | null
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: (T | null)[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: (T | null)[]
+        
+        /**
+         * **Lt**
+         *
+         * This interface field doesn't have a description.
+         */
+         lt?: T
+        
+        /**
+         * **Lte**
+         *
+         * This interface field doesn't have a description.
+         */
+         lte?: T
+        
+        /**
+         * **Gt**
+         *
+         * This interface field doesn't have a description.
+         */
+         gt?: T
+        
+        /**
+         * **Gte**
+         *
+         * This interface field doesn't have a description.
+         */
+         gte?: T
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: T | null | std.NullableFilter<T>
+        
+    }
+
+
+    /**
+     * **Number atomic update operation input**
+     *
+     * This interface doesn't have a description.
+     */
+    export type NumberAtomicUpdateOperationInput<T> = {
+        
+        /**
+         * **Increment**
+         *
+         * This interface field doesn't have a description.
+         */
+         increment?: T
+        
+        /**
+         * **Decrement**
+         *
+         * This interface field doesn't have a description.
+         */
+         decrement?: T
+        
+        /**
+         * **Multiply**
+         *
+         * This interface field doesn't have a description.
+         */
+         multiply?: T
+        
+        /**
+         * **Divide**
+         *
+         * This interface field doesn't have a description.
+         */
+         divide?: T
+        
+    }
+
+
+    /**
+     * **Paging info**
+     *
+     * This interface doesn't have a description.
+     */
+    export type PagingInfo = {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         count: Int64
+        
+        /**
+         * **Number of pages**
+         *
+         * This interface field doesn't have a description.
+         */
+         numberOfPages?: Int64
+        
+    }
+
+
+    /**
+     * **Response error**
+     *
+     * This interface doesn't have a description.
+     */
+    export type ResponseError = {
+        
+        /**
+         * **Type**
+         *
+         * This interface field doesn't have a description.
+         */
+         type: string
+        
+        /**
+         * **Message**
+         *
+         * This interface field doesn't have a description.
+         */
+         message: string
+        
+        /**
+         * **Fields**
+         *
+         * This interface field doesn't have a description.
+         */
+         fields: {[key: string]: string} | null
+        
+    }
+
+
+    /**
+     * **String filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type StringFilter = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: string
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: string[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: string[]
+        
+        /**
+         * **Lt**
+         *
+         * This interface field doesn't have a description.
+         */
+         lt?: string
+        
+        /**
+         * **Lte**
+         *
+         * This interface field doesn't have a description.
+         */
+         lte?: string
+        
+        /**
+         * **Gt**
+         *
+         * This interface field doesn't have a description.
+         */
+         gt?: string
+        
+        /**
+         * **Gte**
+         *
+         * This interface field doesn't have a description.
+         */
+         gte?: string
+        
+        /**
+         * **Contains**
+         *
+         * This interface field doesn't have a description.
+         */
+         contains?: string
+        
+        /**
+         * **Starts with**
+         *
+         * This interface field doesn't have a description.
+         */
+         startsWith?: string
+        
+        /**
+         * **Ends with**
+         *
+         * This interface field doesn't have a description.
+         */
+         endsWith?: string
+        
+        /**
+         * **Matches**
+         *
+         * This interface field doesn't have a description.
+         */
+         matches?: string
+        
+        /**
+         * **Mode**
+         *
+         * This interface field doesn't have a description.
+         */
+         mode?: std.StringMatchMode
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: string | std.StringFilter
+        
+    }
+
+
+    /**
+     * **String nullable filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type StringNullableFilter = {
+        
+        /**
+         * **Equals**
+         *
+         * This interface field doesn't have a description.
+         */
+         equals?: string | null
+        
+        /**
+         * **In**
+         *
+         * This interface field doesn't have a description.
+         */
+         in?: (string | null)[]
+        
+        /**
+         * **Not in**
+         *
+         * This interface field doesn't have a description.
+         */
+         notIn?: (string | null)[]
+        
+        /**
+         * **Lt**
+         *
+         * This interface field doesn't have a description.
+         */
+         lt?: string
+        
+        /**
+         * **Lte**
+         *
+         * This interface field doesn't have a description.
+         */
+         lte?: string
+        
+        /**
+         * **Gt**
+         *
+         * This interface field doesn't have a description.
+         */
+         gt?: string
+        
+        /**
+         * **Gte**
+         *
+         * This interface field doesn't have a description.
+         */
+         gte?: string
+        
+        /**
+         * **Contains**
+         *
+         * This interface field doesn't have a description.
+         */
+         contains?: string
+        
+        /**
+         * **Starts with**
+         *
+         * This interface field doesn't have a description.
+         */
+         startsWith?: string
+        
+        /**
+         * **Ends with**
+         *
+         * This interface field doesn't have a description.
+         */
+         endsWith?: string
+        
+        /**
+         * **Matches**
+         *
+         * This interface field doesn't have a description.
+         */
+         matches?: string
+        
+        /**
+         * **Mode**
+         *
+         * This interface field doesn't have a description.
+         */
+         mode?: std.StringMatchMode
+        
+        /**
+         * **Not**
+         *
+         * This interface field doesn't have a description.
+         */
+         not?: string | null | std.StringNullableFilter
+        
+    }
+
+
+    /**
+     * **String nullable with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type StringNullableWithAggregatesFilter = std.StringNullableFilter & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.StringNullableFilter
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.StringNullableFilter
+        
+    }
+
+
+    /**
+     * **String with aggregates filter**
+     *
+     * This interface doesn't have a description.
+     */
+    export type StringWithAggregatesFilter = std.StringFilter & {
+        
+        /**
+         * **Count**
+         *
+         * This interface field doesn't have a description.
+         */
+         _count?: Int64
+        
+        /**
+         * **Min**
+         *
+         * This interface field doesn't have a description.
+         */
+         _min?: std.StringFilter
+        
+        /**
+         * **Max**
+         *
+         * This interface field doesn't have a description.
+         */
+         _max?: std.StringFilter
+        
+    }
+
+
+
+    export namespace admin {
+
+
+
+
+
+
+
+        export interface AdminNamespaceDelegate {
+
+            
+
+            
+
+            
+
+            /**
+             * Get a new client altered with `headers`.
+             * @param {headers?} headers - The new headers.
+             */
+            $headers(headers?: {[key: string]: string} | undefined): Teo
+        }
+
+
+
+
+
+    }
+
+    export namespace bcrypt {
+
+
+
+
+
+
+
+        export interface BcryptNamespaceDelegate {
+
+            
+
+            
+
+            
+
+            /**
+             * Get a new client altered with `headers`.
+             * @param {headers?} headers - The new headers.
+             */
+            $headers(headers?: {[key: string]: string} | undefined): Teo
+        }
+
+
+
+
+
+    }
+
+    export namespace identity {
+
+
+
+        /**
+         * **Token info**
+         *
+         * This interface doesn't have a description.
+         */
+        export type TokenInfo = {
+            
+            /**
+             * **Token**
+             *
+             * This interface field doesn't have a description.
+             */
+             token: string
+            
+        }
+
+
+
+
+
+
+        export interface IdentityNamespaceDelegate {
+
+            
+
+            
+
+            
+
+            /**
+             * Get a new client altered with `headers`.
+             * @param {headers?} headers - The new headers.
+             */
+            $headers(headers?: {[key: string]: string} | undefined): Teo
+        }
+
+
+
+
+
+    }
+
+
+
+
+    export interface StdNamespaceDelegate {
+
+        
+
+        
+        admin: std.admin.AdminNamespaceDelegate
+        
+        bcrypt: std.bcrypt.BcryptNamespaceDelegate
+        
+        identity: std.identity.IdentityNamespaceDelegate
+        
+
+        
+
+        /**
+         * Get a new client altered with `headers`.
+         * @param {headers?} headers - The new headers.
+         */
+        $headers(headers?: {[key: string]: string} | undefined): Teo
+    }
+
+
+
+
+
+}
+
+
+
+
+export interface AccountDelegate {
+
+    
+    /**
+     * Find a unique account.
+     *
+     * @example
+     * ```ts
+     * // Find a unique account
+     * const { data } = await teo.account.findUnique({
+     *     where: {}, // a unique filter
+     * })
+     * ```
+     */
+    findUnique<T extends AccountFindUniqueArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Account, AccountGetPayload<T>>>>
+    
+    /**
+     * Find the first account matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Find the first account matching a filter
+     * const { data } = await teo.account.findFirst({
+     *     where: {}, // a filter
+     *     orderBy: {}, // the order to find in
+     * })
+     * ```
+     */
+    findFirst<T extends AccountFindFirstArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Account, AccountGetPayload<T>>>>
+    
+    /**
+     * Find accounts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Find accounts matching a filter
+     * const { data } = await teo.account.findMany({
+     *     where: {}, // a filter
+     *     orderBy: {}, // the order to find in
+     *     skip: 0, // records to skip
+     *     take: 10, // records to take
+     * })
+     * ```
+     */
+    findMany<T extends AccountFindManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, Account, AccountGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Create an account.
+     *
+     * @example
+     * ```ts
+     * // Create an account
+     * const { data } = await teo.account.create({
+     *     create: {}, // the data to create
+     * })
+     * ```
+     */
+    create<T extends AccountCreateArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Account, AccountGetPayload<T>>>>
+    
+    /**
+     * Update a unique account.
+     *
+     * @example
+     * ```ts
+     * // Update a unique account
+     * const { data } = await teo.account.update({
+     *     where: {}, // a unique filter
+     *     update: {}, // the data to update
+     * })
+     * ```
+     */
+    update<T extends AccountUpdateArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Account, AccountGetPayload<T>>>>
+    
+    /**
+     * Update a unique account, or create it if it doesn't exist.
+     *
+     * @example
+     * ```ts
+     * // Update a unique account, or create it if it doesn't exist
+     * const { data } = await teo.account.upsert({
+     *     where: {}, // a unique filter
+     *     create: {}, // the data to create
+     *     update: {}, // the data to update
+     * })
+     * ```
+     */
+    upsert<T extends AccountUpsertArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Account, AccountGetPayload<T>>>>
+    
+    /**
+     * Copy a unique account.
+     *
+     * @example
+     * ```ts
+     * // Copy a unique account
+     * const { data } = await teo.account.copy({
+     *     where: {}, // a unique filter
+     *     copy: {}, // the data to change on the copy
+     * })
+     * ```
+     */
+    copy<T extends AccountCopyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Account, AccountGetPayload<T>>>>
+    
+    /**
+     * Delete a unique account.
+     *
+     * @example
+     * ```ts
+     * // Delete a unique account
+     * const { data } = await teo.account.delete({
+     *     where: {}, // a unique filter
+     * })
+     * ```
+     */
+    delete<T extends AccountDeleteArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<CheckSelectInclude<T, Account, AccountGetPayload<T>>>>
+    
+    /**
+     * Create many accounts.
+     *
+     * @example
+     * ```ts
+     * // Create many accounts
+     * const { data } = await teo.account.createMany({
+     *     create: [], // the data to create
+     * })
+     * ```
+     */
+    createMany<T extends AccountCreateManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, Account, AccountGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Update accounts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Update accounts matching a filter
+     * const { data } = await teo.account.updateMany({
+     *     where: {}, // a filter
+     *     update: {}, // the data to update
+     * })
+     * ```
+     */
+    updateMany<T extends AccountUpdateManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, Account, AccountGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Copy accounts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Copy accounts matching a filter
+     * const { data } = await teo.account.copyMany({
+     *     where: {}, // a filter
+     *     copy: {}, // the data to change on the copies
+     * })
+     * ```
+     */
+    copyMany<T extends AccountCopyManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, Account, AccountGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Delete accounts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Delete accounts matching a filter
+     * const { data } = await teo.account.deleteMany({
+     *     where: {}, // a filter
+     * })
+     * ```
+     */
+    deleteMany<T extends AccountDeleteManyArgs>(body: T,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.DataMeta<CheckSelectInclude<T, Account, AccountGetPayload<T>>[], std.PagingInfo>>
+    
+    /**
+     * Count accounts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Count accounts matching a filter
+     * const { data } = await teo.account.count({
+     *     where: {}, // a filter
+     * })
+     * ```
+     */
+    count<T extends AccountCountArgs>(body: Subset<T, AccountCountArgs>,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<T extends Record<'select', any>
+       ? T['select'] extends true
+         ? number
+         : GetScalarType<T['select'], AccountCountAggregateResult>
+       : number>>
+    
+    /**
+     * Aggregate on accounts matching a filter.
+     *
+     * @example
+     * ```ts
+     * // Aggregate on accounts matching a filter
+     * const { data } = await teo.account.aggregate({
+     *     where: {}, // a filter
+     *     _count: { _all: true }, // aggregations like _avg, _sum, _min and _max
+     * })
+     * ```
+     */
+    aggregate<T extends AccountAggregateArgs>(body: Subset<T, AccountAggregateArgs>,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<GetAccountAggregateType<T>>>
+    
+    /**
+     * Group accounts by fields and aggregate on each group.
+     *
+     * @example
+     * ```ts
+     * // Group accounts by fields and aggregate on each group
+     * const { data } = await teo.account.groupBy({
+     *     by: [], // the fields to group by
+     *     _count: { _all: true }, // aggregations of each group
+     * })
+     * ```
+     */
+    groupBy<T extends AccountGroupByArgs,
+      HasSelectOrTake extends Or<
+        Extends<'skip', Keys<T>>,
+        Extends<'take', Keys<T>>
+      >,
+      OrderByArg extends True extends HasSelectOrTake
+        ? { orderBy: AccountGroupByArgs['orderBy'] }
+        : { orderBy?: AccountGroupByArgs['orderBy'] },
+      OrderFields extends ExcludeUnderscoreKeys<Keys<MaybeTupleToUnion<T['orderBy']>>>,
+      ByFields extends MaybeTupleToUnion<T['by']>,
+      ByValid extends Has<ByFields, OrderFields>,
+      HavingFields extends GetHavingFields<T['having']>,
+      HavingValid extends Has<ByFields, HavingFields>,
+      ByEmpty extends T['by'] extends never[] ? True : False,
+      InputErrors extends ByEmpty extends True
+      ? `Error: "by" must not be empty.`
+      : HavingValid extends False
+      ? {
+          [P in HavingFields]: P extends ByFields
+            ? never
+            : P extends string
+            ? `Error: Field "${P}" used in "having" needs to be provided in "by".`
+            : [
+                Error,
+                'Field ',
+                P,
+                ` in "having" needs to be provided in "by"`,
+              ]
+        }[HavingFields]
+      : 'take' extends Keys<T>
+      ? 'orderBy' extends Keys<T>
+        ? ByValid extends True
+          ? {}
+          : {
+              [P in OrderFields]: P extends ByFields
+                ? never
+                : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+            }[OrderFields]
+        : 'Error: If you provide "take", you also need to provide "orderBy"'
+      : 'skip' extends Keys<T>
+      ? 'orderBy' extends Keys<T>
+        ? ByValid extends True
+          ? {}
+          : {
+              [P in OrderFields]: P extends ByFields
+                ? never
+                : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+            }[OrderFields]
+        : 'Error: If you provide "skip", you also need to provide "orderBy"'
+      : ByValid extends True
+      ? {}
+      : {
+          [P in OrderFields]: P extends ByFields
+            ? never
+            : `Error: Field "${P}" in "orderBy" needs to be provided in "by"`
+        }[OrderFields]>(body: SubsetIntersection<T, AccountGroupByArgs, OrderByArg> & InputErrors,headers?: {[key: string]: string} | undefined, queryString?: string | undefined): Promise<std.Data<{} extends InputErrors ? GetAccountGroupByPayload<T> : InputErrors>>
+    
+
+    
+
+    
+
+    /**
+     * Get a new client altered with `headers`.
+     * @param {headers?} headers - The new headers.
+     */
+    $headers(headers?: {[key: string]: string} | undefined): Teo
+}
+
+export interface Teo {
+
+    
+
+    
+    admin: admin.AdminNamespaceDelegate
+    
+    std: std.StdNamespaceDelegate
+    
+
+    
+    account: AccountDelegate
+    
+
+    /**
+     * Get a new client altered with `headers`.
+     * @param {headers?} headers - The new headers.
+     */
+    $headers(headers?: {[key: string]: string} | undefined): Teo
+}
+
+
+
+/**
+ * ## Teo API Client
+ *
+ * Teo API client for TypeScript & javaScript. It supports both browser and
+ * node.js. It's generated by the fantastic Teo framework.
+ *
+ * @example
+ * ```ts
+ * // Fetch accounts
+ * const { data } = await teo.account.findMany({})
+ * ```
+ *
+ */
+export const teo: Teo
+
+
@@ -0,0 +1,337 @@
+// @typescript-eslint/no-unused-vars
+
+import Decimal from 'decimal.js'
+
+let groupDelegateMap = [
+    "Account",
+    "admin",
+    "admin.Audit",
+    "admin.reports",
+    "std",
+    "std.DataSetRecord",
+    "std.DataSetRelation",
+    "std.admin",
+    "std.bcrypt",
+    "std.identity"
+]
+let customHandlerMap = {
+    "admin.reports.Reports.monthly":{ method: "GET", path: "/reports/:year/:month", pathArguments: true },
+    "admin.reports.Reports.summary":{ method: "POST", path: "admin/reports/Reports/summary", pathArguments: false }
+}
+
+// Where the Int64 values are: 1 is an Int64, a string refers to an interface,
+// [shape] is an array, { "*": shape } a dictionary, { "|": [shapes] } a union
+// and other objects list fields
+let int64Shapes = {
+    "AccountAggregateArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1 },
+    "AccountAggregateResult": { "_count": "AccountCountAggregateResult", "_sum": "AccountSumAggregateResult" },
+    "AccountCountAggregateResult": { "_all": 1, "id": 1, "name": 1, "role": 1 },
+    "AccountCountArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1 },
+    "AccountFindFirstArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1 },
+    "AccountFindManyArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1 },
+    "AccountGroupByArgs": { "having": "AccountScalarWhereWithAggregatesInput", "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1 },
+    "AccountGroupByResult": { "_count": "AccountCountAggregateResult", "_sum": "AccountSumAggregateResult" },
+    "AccountScalarWhereWithAggregatesInput": { "id": { "_count": 1, "_sum": { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": 1, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] } }, "name": "std.StringWithAggregatesFilter", "role": { "_count": 1 } },
+    "AccountSumAggregateResult": { "id": 1 },
+    "admin.AuditAggregateArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1 },
+    "admin.AuditAggregateResult": { "_count": "admin.AuditCountAggregateResult", "_sum": "admin.AuditSumAggregateResult" },
+    "admin.AuditCountAggregateResult": { "_all": 1, "action": 1, "createdAt": 1, "id": 1, "role": 1 },
+    "admin.AuditCountArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1 },
+    "admin.AuditFindFirstArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1 },
+    "admin.AuditFindManyArgs": { "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1 },
+    "admin.AuditGroupByArgs": { "having": "admin.AuditScalarWhereWithAggregatesInput", "pageNumber": 1, "pageSize": 1, "skip": 1, "take": 1 },
+    "admin.AuditGroupByResult": { "_count": "admin.AuditCountAggregateResult", "_sum": "admin.AuditSumAggregateResult" },
+    "admin.AuditScalarWhereWithAggregatesInput": { "action": "std.StringWithAggregatesFilter", "createdAt": { "_count": 1 }, "id": { "_count": 1, "_sum": { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": { "|": [1, { "equals": 1, "gt": 1, "gte": 1, "in": [1], "lt": 1, "lte": 1, "not": 1, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] }] }, "notIn": [1] } }, "role": { "_count": 1 } },
+    "admin.AuditSumAggregateResult": { "id": 1 },
+    "std.BoolNullableWithAggregatesFilter": { "_count": 1 },
+    "std.BoolWithAggregatesFilter": { "_count": 1 },
+    "std.DecimalWithAggregatesFilter": { "_count": 1 },
+    "std.PagingInfo": { "count": 1, "numberOfPages": 1 },
+    "std.StringNullableWithAggregatesFilter": { "_count": 1 },
+    "std.StringWithAggregatesFilter": { "_count": 1 }
+}
+let int64Handlers = {
+    "Account.aggregate": { input: "AccountAggregateArgs", output: { "data": "AccountAggregateResult" } },
+    "Account.copyMany": { output: { "meta": "std.PagingInfo" } },
+    "Account.count": { input: "AccountCountArgs", output: { "data": 1 } },
+    "Account.createMany": { output: { "meta": "std.PagingInfo" } },
+    "Account.deleteMany": { output: { "meta": "std.PagingInfo" } },
+    "Account.findFirst": { input: "AccountFindFirstArgs" },
+    "Account.findMany": { input: "AccountFindManyArgs", output: { "meta": "std.PagingInfo" } },
+    "Account.groupBy": { input: "AccountGroupByArgs", output: { "data": "AccountGroupByResult" } },
+    "Account.updateMany": { output: { "meta": "std.PagingInfo" } },
+    "admin.Audit.aggregate": { input: "admin.AuditAggregateArgs", output: { "data": "admin.AuditAggregateResult" } },
+    "admin.Audit.copyMany": { output: { "meta": "std.PagingInfo" } },
+    "admin.Audit.count": { input: "admin.AuditCountArgs", output: { "data": 1 } },
+    "admin.Audit.createMany": { output: { "meta": "std.PagingInfo" } },
+    "admin.Audit.deleteMany": { output: { "meta": "std.PagingInfo" } },
+    "admin.Audit.findFirst": { input: "admin.AuditFindFirstArgs" },
+    "admin.Audit.findMany": { input: "admin.AuditFindManyArgs", output: { "meta": "std.PagingInfo" } },
+    "admin.Audit.groupBy": { input: "admin.AuditGroupByArgs", output: { "data": "admin.AuditGroupByResult" } },
+    "admin.Audit.updateMany": { output: { "meta": "std.PagingInfo" } }
+}
+
+function resolveShape(shape) {
+    while (typeof shape === 'string') {
+        shape = int64Shapes[shape]
+    }
+    return shape
+}
+
+function unionShape(shapes) {
+    shapes = shapes.filter((shape) => shape !== undefined)
+    return shapes.length === 0 ? undefined : shapes.length === 1 ? shapes[0] : { "|": shapes }
+}
+
+function isInt64Shape(shape) {
+    shape = resolveShape(shape)
+    return shape === 1 || (shape !== undefined && shape["|"] !== undefined && shape["|"].some(isInt64Shape))
+}
+
+function elementShape(shape) {
+    shape = resolveShape(shape)
+    if (Array.isArray(shape)) {
+        return shape[0]
+    } else if (shape !== undefined && shape["|"] !== undefined) {
+        return unionShape(shape["|"].map(elementShape))
+    }
+}
+
+function fieldShape(shape, key) {
+    shape = resolveShape(shape)
+    if (shape === undefined || shape === 1 || Array.isArray(shape)) {
+        return undefined
+    } else if (shape["|"] !== undefined) {
+        return unionShape(shape["|"].map((shape) => fieldShape(shape, key)))
+    } else if (Object.prototype.hasOwnProperty.call(shape, key)) {
+        return shape[key]
+    } else {
+        return shape["*"]
+    }
+}
+
+// JSON with bigints, and integer strings where the schema has an Int64, written as integers
+function serializeBody(value, shape) {
+    if (typeof value === 'bigint') {
+        return value.toString()
+    } else if (typeof value === 'string' && /^-?\d+$/.test(value) && isInt64Shape(shape)) {
+        return value
+    } else if (Array.isArray(value)) {
+        const element = elementShape(shape)
+        return '[' + value.map((item) => {
+            const json = serializeBody(item, element)
+            return json === undefined ? 'null' : json
+        }).join(',') + ']'
+    } else if (typeof value === 'object' && value !== null && typeof value.toJSON !== 'function') {
+        const entries = Object.entries(value).map(([key, item]) => [key, serializeBody(item, fieldShape(shape, key))])
+        return '{' + entries.filter(([, item]) => item !== undefined).map(([key, item]) => JSON.stringify(key) + ':' + item).join(',') + '}'
+    } else {
+        return JSON.stringify(value)
+    }
+}
+
+// Integers beyond Number.MAX_SAFE_INTEGER are wrapped before JSON.parse rounds them
+function protectInt64(text) {
+    let result = ""
+    let start = 0
+    let inString = false
+    for (let i = 0; i < text.length; i++) {
+        const c = text[i]
+        if (inString) {
+            if (c === '\\') {
+                i++
+            } else if (c === '"') {
+                inString = false
+            }
+        } else if (c === '"') {
+            inString = true
+        } else if (c === '-' || (c >= '0' && c <= '9')) {
+            let end = i + 1
+            while (end < text.length && /[0-9.eE+\-]/.test(text[end])) {
+                end++
+            }
+            const literal = text.slice(i, end)
+            if (/^-?\d+$/.test(literal) && !Number.isSafeInteger(Number(literal))) {
+                result += text.slice(start, i) + '{"$int64":"' + literal + '"}'
+                start = end
+            }
+            i = end - 1
+        }
+    }
+    return result + text.slice(start)
+}
+
+// Every Int64 the schema has in a response as a bigint
+function decodeInt64(value, shape) {
+    if (shape === undefined || value === null || value === undefined) {
+        return value
+    } else if (typeof value === 'number' || typeof value === 'bigint') {
+        return isInt64Shape(shape) ? BigInt(value) : value
+    } else if (Array.isArray(value)) {
+        const element = elementShape(shape)
+        return element === undefined ? value : value.map((item) => decodeInt64(item, element))
+    } else if (typeof value === 'object' && value.constructor === Object) {
+        for (const key of Object.keys(value)) {
+            value[key] = decodeInt64(value[key], fieldShape(shape, key))
+        }
+        return value
+    } else {
+        return value
+    }
+}
+
+async function request(method, path, body = undefined, headers = undefined, queryString = undefined, shapes = {}) {
+    let url = "http://127.0.0.1:5050/" + path
+    if (queryString) {
+        url = url + "?" + queryString
+    }
+    
+    let response = await fetch(url, {
+        method: method,
+        headers: headers,
+        body: body === undefined ? undefined : (body instanceof FormData ? body : serializeBody(body, shapes.input))
+    })
+    let response_text = await response.text()
+    
+    let response_json = JSON.parse(protectInt64(response_text), (key, value) => {
+        if (typeof value === 'object' && value != null) {
+            if (value['$int64']) {
+                return BigInt(value['$int64'])
+            } else
+            if (value['$datetime']) {
+                return new Date(value['$datetime'])
+            } else if (value['$decimal']) {
+                return new Decimal(value['$decimal'])
+            } else if (value['$date']) {
+                return value['$date']
+            } else {
+                return value
+            }
+        } else {
+            return value
+        }
+    })
+    if (400 <= response.status) {
+        throw new TeoError(response_json.error)
+    }
+    return decodeInt64(response_json, shapes.output)
+}
+
+function replacePathArguments(path, args) {
+    let resultPath = path
+    for (const [key, value] of Object.entries(args)) {
+        resultPath = resultPath.replace(`{${key}}`, value)
+    }
+    return resultPath
+}
+
+function buildHeaders(currentHeaders, headers) {
+    let newHeaders = currentHeaders
+    if (headers && typeof headers === 'object') {
+        if (newHeaders) {
+            newHeaders = Object.assign({}, newHeaders, headers)
+        } else {
+            newHeaders = headers
+        }
+    }
+    return newHeaders
+}
+
+function buildHeadersAndQueryString(currentHeaders, headers, queryString) {
+    let newHeaders = buildHeaders(currentHeaders, headers)
+    let qs = undefined
+    if (typeof headers === 'string') {
+        qs = headers
+    } else if (typeof queryString === 'string') {
+        qs = queryString
+    }
+    return [newHeaders, qs]
+}
+
+class TeoError extends Error {
+    constructor(responseError) {
+        super(responseError.message)
+        this.type = responseError.type
+        this.errors = responseError.errors
+        Object.setPrototypeOf(this, TeoError.prototype)
+    }
+
+    get name() {
+        return "TeoError"
+    }
+}
+
+class GroupDelegate {
+    constructor(path = []) {
+        this._headers = undefined
+        this._path = path
+        let that = this
+        return new Proxy(this, {
+            get(target, name, receiver) {
+                if (name === '$headers') {
+                    return (headers) => {
+                        let retval = new Teo()
+                        retval._headers = headers
+                        retval._path = that._path
+                        return retval
+                    }
+                } else {
+                    let path = that._path.slice()
+                    path.push(name)
+                    if (groupDelegateMap.includes(path.join("."))) {
+                        return new GroupDelegate(path)
+                    } else {
+                        path.pop()
+                        path.push(name[0].toUpperCase() + name.slice(1))
+                        if (groupDelegateMap.includes(path.join("."))) {
+                            return new GroupDelegate(path)
+                        } else {
+                            path.pop()
+                            path.push(name)
+                            let settings = customHandlerMap[path.join(".")]
+                            let shapes = int64Handlers[path.join(".")]
+                            if (!settings) {
+                                return function(body, headers = undefined) {
+                                    let newHeaders = buildHeaders(this._headers, headers)
+                                    return request("POST", path.join("/"), body, newHeaders, undefined, shapes)
+                                }
+                            } else {
+                                if (settings.pathArguments) {
+                                    if (settings.method === 'GET' || settings.method === 'DELETE') {
+                                        return function(pathArguments, headers = undefined, queryString = undefined) {
+                                            let path = replacePathArguments(settings.path, pathArguments)
+                                            let [newHeaders, newQueryString] = buildHeadersAndQueryString(this._headers, headers, queryString)
+                                            return request(settings.method, path, undefined, newHeaders, newQueryString, shapes)
+                                        }
+                                    } else {
+                                        return function(pathArguments, body, headers = undefined, queryString = undefined) {
+                                            let path = replacePathArguments(settings.path, pathArguments)
+                                            let [newHeaders, newQueryString] = buildHeadersAndQueryString(this._headers, headers, queryString)
+                                            return request(settings.method, path, body, newHeaders, newQueryString, shapes)
+                                        }
+                                    }
+                                } else {
+                                    if (settings.method === 'GET' || settings.method === 'DELETE') {
+                                        return function(headers = undefined, queryString = undefined) {
+                                            let [newHeaders, newQueryString] = buildHeadersAndQueryString(this._headers, headers, queryString)
+                                            return request(settings.method, settings.path, undefined, newHeaders, newQueryString, shapes)
+                                        }
+                                    } else {
+                                        return function(body, headers = undefined, queryString = undefined) {
+                                            let [newHeaders, newQueryString] = buildHeadersAndQueryString(this._headers, headers, queryString)
+                                            return request(settings.method, settings.path, body, newHeaders, newQueryString, shapes)
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        })
+    }
+}
+
+const Teo = GroupDelegate
+const teo = new Teo()
+export { TeoError, Teo, teo }
\ No newline at end of file
@@ -0,0 +1,1006 @@
+// Code generated by Teo. DO NOT EDIT.
+
+package admin
+
+import (
+	"time"
+	teo "github.com/teodevgroup/teo-go"
+	stdlib "generated/stdlib"
+)
+
+// Role Role
+//
+// This enum doesn't have a description.
+type Role string
+
+const (
+	// RoleOwner Owner
+	RoleOwner Role = "owner"
+	// RoleEditor Editor
+	RoleEditor Role = "editor"
+	// RoleViewer Viewer
+	RoleViewer Role = "viewer"
+)
+
+// AuditDirectRelations Audit direct relations
+//
+// This synthesized enum doesn't have a description.
+type AuditDirectRelations string
+
+const (
+)
+
+// AuditIndirectRelations Audit indirect relations
+//
+// This synthesized enum doesn't have a description.
+type AuditIndirectRelations string
+
+const (
+)
+
+// AuditRelations Audit relations
+//
+// This synthesized enum doesn't have a description.
+type AuditRelations string
+
+const (
+)
+
+// AuditScalarFields Audit scalar fields
+//
+// This synthesized enum doesn't have a description.
+type AuditScalarFields string
+
+const (
+	// AuditScalarFieldsAction Action
+	AuditScalarFieldsAction AuditScalarFields = "action"
+	// AuditScalarFieldsCreatedAt Created at
+	AuditScalarFieldsCreatedAt AuditScalarFields = "createdAt"
+	// AuditScalarFieldsId Id
+	AuditScalarFieldsId AuditScalarFields = "id"
+	// AuditScalarFieldsRole Role
+	AuditScalarFieldsRole AuditScalarFields = "role"
+)
+
+// AuditSerializableScalarFields Audit serializable scalar fields
+//
+// This synthesized enum doesn't have a description.
+type AuditSerializableScalarFields string
+
+const (
+	// AuditSerializableScalarFieldsAction Action
+	AuditSerializableScalarFieldsAction AuditSerializableScalarFields = "action"
+	// AuditSerializableScalarFieldsCreatedAt Created at
+	AuditSerializableScalarFieldsCreatedAt AuditSerializableScalarFields = "createdAt"
+	// AuditSerializableScalarFieldsId Id
+	AuditSerializableScalarFieldsId AuditSerializableScalarFields = "id"
+	// AuditSerializableScalarFieldsRole Role
+	AuditSerializableScalarFieldsRole AuditSerializableScalarFields = "role"
+)
+
+// AuditAggregateArgs Audit aggregate args
+//
+// This synthesized interface doesn't have a description
+type AuditAggregateArgs struct {
+	// Avg Avg
+	Avg *AuditAvgAggregateInputType `json:"_avg,omitempty"`
+	// Count Count
+	Count *AuditCountAggregateInputType `json:"_count,omitempty"`
+	// Max Max
+	Max *AuditMaxAggregateInputType `json:"_max,omitempty"`
+	// Min Min
+	Min *AuditMinAggregateInputType `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *AuditSumAggregateInputType `json:"_sum,omitempty"`
+	// Cursor Cursor
+	Cursor *AuditWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *AuditSerializableScalarFields `json:"distinct,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *AuditWhereInput `json:"where,omitempty"`
+}
+
+// AuditAggregateResult Audit aggregate result
+//
+// This synthesized interface doesn't have a description
+type AuditAggregateResult struct {
+	// Avg Avg
+	Avg *AuditAvgAggregateResult `json:"_avg,omitempty"`
+	// Count Count
+	Count *AuditCountAggregateResult `json:"_count,omitempty"`
+	// Max Max
+	Max *AuditMaxAggregateResult `json:"_max,omitempty"`
+	// Min Min
+	Min *AuditMinAggregateResult `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *AuditSumAggregateResult `json:"_sum,omitempty"`
+}
+
+// AuditArgs Audit args
+//
+// This synthesized interface doesn't have a description
+type AuditArgs struct {
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+}
+
+// AuditAvgAggregateInputType Audit avg aggregate input type
+//
+// This synthesized interface doesn't have a description
+type AuditAvgAggregateInputType struct {
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+}
+
+// AuditAvgAggregateResult Audit avg aggregate result
+//
+// This synthesized interface doesn't have a description
+type AuditAvgAggregateResult struct {
+	// Id Id
+	Id *float64 `json:"id,omitempty"`
+}
+
+// AuditConnectOrCreateInput Audit connect or create input
+//
+// This synthesized interface doesn't have a description
+type AuditConnectOrCreateInput struct {
+	// Create Create
+	Create AuditCreateInput `json:"create"`
+	// Where Where
+	Where AuditWhereUniqueInput `json:"where"`
+}
+
+// AuditCopyArgs Audit copy args
+//
+// This synthesized interface doesn't have a description
+type AuditCopyArgs struct {
+	// Copy Copy
+	Copy AuditUpdateInput `json:"copy"`
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+	// Where Where
+	Where AuditWhereUniqueInput `json:"where"`
+}
+
+// AuditCopyManyArgs Audit copy many args
+//
+// This synthesized interface doesn't have a description
+type AuditCopyManyArgs struct {
+	// Copy Copy
+	Copy AuditUpdateInput `json:"copy"`
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+	// Where Where
+	Where AuditWhereInput `json:"where"`
+}
+
+// AuditCountAggregateInputType Audit count aggregate input type
+//
+// This synthesized interface doesn't have a description
+type AuditCountAggregateInputType struct {
+	// All All
+	All *bool `json:"_all,omitempty"`
+	// Action Action
+	Action *bool `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *bool `json:"createdAt,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Role Role
+	Role *bool `json:"role,omitempty"`
+}
+
+// AuditCountAggregateResult Audit count aggregate result
+//
+// This synthesized interface doesn't have a description
+type AuditCountAggregateResult struct {
+	// All All
+	All *int64 `json:"_all,omitempty"`
+	// Action Action
+	Action *int64 `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *int64 `json:"createdAt,omitempty"`
+	// Id Id
+	Id *int64 `json:"id,omitempty"`
+	// Role Role
+	Role *int64 `json:"role,omitempty"`
+}
+
+// AuditCountArgs Audit count args
+//
+// This synthesized interface doesn't have a description
+type AuditCountArgs struct {
+	// Cursor Cursor
+	Cursor *AuditWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *AuditSerializableScalarFields `json:"distinct,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *AuditCountAggregateInputType `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *AuditWhereInput `json:"where,omitempty"`
+}
+
+// AuditCreateArgs Audit create args
+//
+// This synthesized interface doesn't have a description
+type AuditCreateArgs struct {
+	// Create Create
+	Create AuditCreateInput `json:"create"`
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+}
+
+// AuditCreateInput Audit create input
+//
+// This synthesized interface doesn't have a description
+type AuditCreateInput struct {
+	// Action Action
+	Action string `json:"action"`
+	// Role Role
+	Role Role `json:"role"`
+}
+
+// AuditCreateManyArgs Audit create many args
+//
+// This synthesized interface doesn't have a description
+type AuditCreateManyArgs struct {
+	// Create Create
+	Create teo.Value `json:"create"`
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+}
+
+// AuditCreateNestedManyInput Audit create nested many input
+//
+// This synthesized interface doesn't have a description
+type AuditCreateNestedManyInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+}
+
+// AuditCreateNestedOneInput Audit create nested one input
+//
+// This synthesized interface doesn't have a description
+type AuditCreateNestedOneInput struct {
+	// Connect Connect
+	Connect *AuditWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *AuditConnectOrCreateInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *AuditCreateInput `json:"create,omitempty"`
+}
+
+// AuditDeleteArgs Audit delete args
+//
+// This synthesized interface doesn't have a description
+type AuditDeleteArgs struct {
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+	// Where Where
+	Where AuditWhereUniqueInput `json:"where"`
+}
+
+// AuditDeleteManyArgs Audit delete many args
+//
+// This synthesized interface doesn't have a description
+type AuditDeleteManyArgs struct {
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+	// Where Where
+	Where AuditWhereInput `json:"where"`
+}
+
+// AuditFindFirstArgs Audit find first args
+//
+// This synthesized interface doesn't have a description
+type AuditFindFirstArgs struct {
+	// Cursor Cursor
+	Cursor *AuditWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *AuditSerializableScalarFields `json:"distinct,omitempty"`
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *AuditWhereInput `json:"where,omitempty"`
+}
+
+// AuditFindManyArgs Audit find many args
+//
+// This synthesized interface doesn't have a description
+type AuditFindManyArgs struct {
+	// Cursor Cursor
+	Cursor *AuditWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *AuditSerializableScalarFields `json:"distinct,omitempty"`
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *AuditWhereInput `json:"where,omitempty"`
+}
+
+// AuditFindUniqueArgs Audit find unique args
+//
+// This synthesized interface doesn't have a description
+type AuditFindUniqueArgs struct {
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+	// Where Where
+	Where AuditWhereUniqueInput `json:"where"`
+}
+
+// AuditGroupByArgs Audit group by args
+//
+// This synthesized interface doesn't have a description
+type AuditGroupByArgs struct {
+	// Avg Avg
+	Avg *AuditAvgAggregateInputType `json:"_avg,omitempty"`
+	// Count Count
+	Count *AuditCountAggregateInputType `json:"_count,omitempty"`
+	// Max Max
+	Max *AuditMaxAggregateInputType `json:"_max,omitempty"`
+	// Min Min
+	Min *AuditMinAggregateInputType `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *AuditSumAggregateInputType `json:"_sum,omitempty"`
+	// By By
+	By teo.Value `json:"by"`
+	// Cursor Cursor
+	Cursor *AuditWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *AuditSerializableScalarFields `json:"distinct,omitempty"`
+	// Having Having
+	Having *AuditScalarWhereWithAggregatesInput `json:"having,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *AuditWhereInput `json:"where,omitempty"`
+}
+
+// AuditGroupByResult Audit group by result
+//
+// This synthesized interface doesn't have a description
+type AuditGroupByResult struct {
+	// Avg Avg
+	Avg *AuditAvgAggregateResult `json:"_avg,omitempty"`
+	// Count Count
+	Count *AuditCountAggregateResult `json:"_count,omitempty"`
+	// Max Max
+	Max *AuditMaxAggregateResult `json:"_max,omitempty"`
+	// Min Min
+	Min *AuditMinAggregateResult `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *AuditSumAggregateResult `json:"_sum,omitempty"`
+	// Action Action
+	Action *string `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *time.Time `json:"createdAt,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Role Role
+	Role *Role `json:"role,omitempty"`
+}
+
+// AuditInclude Audit include
+//
+// This synthesized interface doesn't have a description
+type AuditInclude struct {
+}
+
+// AuditListRelationFilter Audit list relation filter
+//
+// This synthesized interface doesn't have a description
+type AuditListRelationFilter struct {
+	// Every Every
+	Every *AuditWhereInput `json:"every,omitempty"`
+	// None None
+	None *AuditWhereInput `json:"none,omitempty"`
+	// Some Some
+	Some *AuditWhereInput `json:"some,omitempty"`
+}
+
+// AuditMaxAggregateInputType Audit max aggregate input type
+//
+// This synthesized interface doesn't have a description
+type AuditMaxAggregateInputType struct {
+	// Action Action
+	Action *bool `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *bool `json:"createdAt,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Role Role
+	Role *bool `json:"role,omitempty"`
+}
+
+// AuditMaxAggregateResult Audit max aggregate result
+//
+// This synthesized interface doesn't have a description
+type AuditMaxAggregateResult struct {
+	// Action Action
+	Action *string `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *time.Time `json:"createdAt,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Role Role
+	Role *Role `json:"role,omitempty"`
+}
+
+// AuditMinAggregateInputType Audit min aggregate input type
+//
+// This synthesized interface doesn't have a description
+type AuditMinAggregateInputType struct {
+	// Action Action
+	Action *bool `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *bool `json:"createdAt,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Role Role
+	Role *bool `json:"role,omitempty"`
+}
+
+// AuditMinAggregateResult Audit min aggregate result
+//
+// This synthesized interface doesn't have a description
+type AuditMinAggregateResult struct {
+	// Action Action
+	Action *string `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *time.Time `json:"createdAt,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Role Role
+	Role *Role `json:"role,omitempty"`
+}
+
+// AuditOrderByInput Audit order by input
+//
+// This synthesized interface doesn't have a description
+type AuditOrderByInput struct {
+	// Action Action
+	Action *stdlib.Sort `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *stdlib.Sort `json:"createdAt,omitempty"`
+	// Id Id
+	Id *stdlib.Sort `json:"id,omitempty"`
+	// Role Role
+	Role *stdlib.Sort `json:"role,omitempty"`
+}
+
+// AuditRelationFilter Audit relation filter
+//
+// This synthesized interface doesn't have a description
+type AuditRelationFilter struct {
+	// Is Is
+	Is *AuditWhereInput `json:"is,omitempty"`
+	// IsNot Is Not
+	IsNot *AuditWhereInput `json:"isNot,omitempty"`
+}
+
+// AuditResult Audit result
+//
+// This synthesized interface doesn't have a description
+type AuditResult struct {
+	// Action Action
+	Action string `json:"action"`
+	// CreatedAt Created At
+	CreatedAt time.Time `json:"createdAt"`
+	// Id Id
+	Id int32 `json:"id"`
+	// Role Role
+	Role Role `json:"role"`
+}
+
+// AuditScalarUpdateInput Audit scalar update input
+//
+// This synthesized interface doesn't have a description
+type AuditScalarUpdateInput struct {
+	// Action Action
+	Action *string `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *time.Time `json:"createdAt,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Role Role
+	Role *Role `json:"role,omitempty"`
+}
+
+// AuditScalarWhereWithAggregatesInput Audit scalar where with aggregates input
+//
+// This synthesized interface doesn't have a description
+type AuditScalarWhereWithAggregatesInput struct {
+	// And And
+	And []AuditWhereInput `json:"AND,omitempty"`
+	// Not Not
+	Not *AuditWhereInput `json:"NOT,omitempty"`
+	// Or Or
+	Or []AuditWhereInput `json:"OR,omitempty"`
+	// Action Action
+	Action teo.Value `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt teo.Value `json:"createdAt,omitempty"`
+	// Id Id
+	Id teo.Value `json:"id,omitempty"`
+	// Role Role
+	Role teo.Value `json:"role,omitempty"`
+}
+
+// AuditSelect Audit select
+//
+// This synthesized interface doesn't have a description
+type AuditSelect struct {
+	// Action Action
+	Action *bool `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt *bool `json:"createdAt,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Role Role
+	Role *bool `json:"role,omitempty"`
+}
+
+// AuditSignInArgs Audit sign in args
+//
+// This synthesized interface doesn't have a description
+type AuditSignInArgs struct {
+}
+
+// AuditSignInCheckerCompanions Audit sign in checker companions
+//
+// This synthesized interface doesn't have a description
+type AuditSignInCheckerCompanions struct {
+}
+
+// AuditSignInCheckerIds Audit sign in checker ids
+//
+// This synthesized interface doesn't have a description
+type AuditSignInCheckerIds struct {
+}
+
+// AuditSignInInput Audit sign in input
+//
+// This synthesized interface doesn't have a description
+type AuditSignInInput struct {
+	// Credentials Credentials
+	Credentials AuditSignInArgs `json:"credentials"`
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+}
+
+// AuditSumAggregateInputType Audit sum aggregate input type
+//
+// This synthesized interface doesn't have a description
+type AuditSumAggregateInputType struct {
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+}
+
+// AuditSumAggregateResult Audit sum aggregate result
+//
+// This synthesized interface doesn't have a description
+type AuditSumAggregateResult struct {
+	// Id Id
+	Id *int64 `json:"id,omitempty"`
+}
+
+// AuditUpdateArgs Audit update args
+//
+// This synthesized interface doesn't have a description
+type AuditUpdateArgs struct {
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+	// Update Update
+	Update AuditUpdateInput `json:"update"`
+	// Where Where
+	Where AuditWhereUniqueInput `json:"where"`
+}
+
+// AuditUpdateInput Audit update input
+//
+// This synthesized interface doesn't have a description
+type AuditUpdateInput struct {
+	// Action Action
+	Action *string `json:"action,omitempty"`
+	// Role Role
+	Role *Role `json:"role,omitempty"`
+}
+
+// AuditUpdateManyArgs Audit update many args
+//
+// This synthesized interface doesn't have a description
+type AuditUpdateManyArgs struct {
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+	// Update Update
+	Update AuditUpdateInput `json:"update"`
+	// Where Where
+	Where AuditWhereInput `json:"where"`
+}
+
+// AuditUpdateManyWithWhereInput Audit update many with where input
+//
+// This synthesized interface doesn't have a description
+type AuditUpdateManyWithWhereInput struct {
+	// Update Update
+	Update AuditUpdateInput `json:"update"`
+	// Where Where
+	Where AuditWhereInput `json:"where"`
+}
+
+// AuditUpdateNestedManyInput Audit update nested many input
+//
+// This synthesized interface doesn't have a description
+type AuditUpdateNestedManyInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+	// Delete Delete
+	Delete teo.Value `json:"delete,omitempty"`
+	// DeleteMany Delete Many
+	DeleteMany teo.Value `json:"deleteMany,omitempty"`
+	// Disconnect Disconnect
+	Disconnect teo.Value `json:"disconnect,omitempty"`
+	// Set Set
+	Set teo.Value `json:"set,omitempty"`
+	// Update Update
+	Update teo.Value `json:"update,omitempty"`
+	// UpdateMany Update Many
+	UpdateMany teo.Value `json:"updateMany,omitempty"`
+	// Upsert Upsert
+	Upsert teo.Value `json:"upsert,omitempty"`
+}
+
+// AuditUpdateNestedOneInput Audit update nested one input
+//
+// This synthesized interface doesn't have a description
+type AuditUpdateNestedOneInput struct {
+	// Connect Connect
+	Connect *AuditWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *AuditConnectOrCreateInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *AuditCreateInput `json:"create,omitempty"`
+	// Delete Delete
+	Delete *bool `json:"delete,omitempty"`
+	// Disconnect Disconnect
+	Disconnect *bool `json:"disconnect,omitempty"`
+	// Set Set
+	Set *AuditWhereUniqueInput `json:"set,omitempty"`
+	// Update Update
+	Update *AuditUpdateInput `json:"update,omitempty"`
+	// Upsert Upsert
+	Upsert *AuditUpsertWithWhereUniqueInput `json:"upsert,omitempty"`
+}
+
+// AuditUpdateWithWhereUniqueInput Audit update with where unique input
+//
+// This synthesized interface doesn't have a description
+type AuditUpdateWithWhereUniqueInput struct {
+	// Update Update
+	Update AuditUpdateInput `json:"update"`
+	// Where Where
+	Where AuditWhereUniqueInput `json:"where"`
+}
+
+// AuditUpsertArgs Audit upsert args
+//
+// This synthesized interface doesn't have a description
+type AuditUpsertArgs struct {
+	// Create Create
+	Create AuditCreateInput `json:"create"`
+	// Include Include
+	Include *AuditInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AuditSelect `json:"select,omitempty"`
+	// Update Update
+	Update AuditUpdateInput `json:"update"`
+	// Where Where
+	Where AuditWhereUniqueInput `json:"where"`
+}
+
+// AuditUpsertWithWhereUniqueInput Audit upsert with where unique input
+//
+// This synthesized interface doesn't have a description
+type AuditUpsertWithWhereUniqueInput struct {
+	// Create Create
+	Create AuditCreateInput `json:"create"`
+	// Update Update
+	Update AuditUpdateInput `json:"update"`
+	// Where Where
+	Where AuditWhereUniqueInput `json:"where"`
+}
+
+// AuditWhereInput Audit where input
+//
+// This synthesized interface doesn't have a description
+type AuditWhereInput struct {
+	// And And
+	And []AuditWhereInput `json:"AND,omitempty"`
+	// Not Not
+	Not *AuditWhereInput `json:"NOT,omitempty"`
+	// Or Or
+	Or []AuditWhereInput `json:"OR,omitempty"`
+	// Action Action
+	Action teo.Value `json:"action,omitempty"`
+	// CreatedAt Created At
+	CreatedAt teo.Value `json:"createdAt,omitempty"`
+	// Id Id
+	Id teo.Value `json:"id,omitempty"`
+	// Role Role
+	Role teo.Value `json:"role,omitempty"`
+}
+
+// AuditWhereUniqueInput Audit where unique input
+//
+// This synthesized interface doesn't have a description
+type AuditWhereUniqueInput struct {
+	// Id Id
+	Id int32 `json:"id"`
+}
+
+// AuditModel Audit
+//
+// This model doesn't have a description.
+type AuditModel struct {
+	ctx *teo.ModelCtx
+}
+
+// FindManyObjects finds many audit objects.
+func (m *AuditModel) FindManyObjects(query AuditFindManyArgs) ([]*Audit, error) {
+	objects, err := m.ctx.FindMany(query)
+	if err != nil {
+		return nil, err
+	}
+	result := make([]*Audit, len(objects))
+	for i, object := range objects {
+		result[i] = WrapAudit(object)
+	}
+	return result, nil
+}
+
+// FindUniqueObject finds a unique audit object.
+func (m *AuditModel) FindUniqueObject(query AuditFindUniqueArgs) (*Audit, error) {
+	object, err := m.ctx.FindUnique(query)
+	if err != nil {
+		return nil, err
+	}
+	return WrapAudit(object), nil
+}
+
+// FindFirstObject finds an audit object.
+func (m *AuditModel) FindFirstObject(query AuditFindFirstArgs) (*Audit, error) {
+	object, err := m.ctx.FindFirst(query)
+	if err != nil {
+		return nil, err
+	}
+	return WrapAudit(object), nil
+}
+
+// CreateObject creates a new audit object.
+func (m *AuditModel) CreateObject(values AuditCreateInput) (*Audit, error) {
+	object, err := m.ctx.Create(values)
+	if err != nil {
+		return nil, err
+	}
+	return WrapAudit(object), nil
+}
+
+// CountObjects counts objects on audit.
+func (m *AuditModel) CountObjects(query AuditCountArgs) (int, error) {
+	return m.ctx.Count(query)
+}
+
+// Aggregate aggregates on audit.
+func (m *AuditModel) Aggregate(query AuditAggregateArgs) (teo.Value, error) {
+	return m.ctx.Aggregate(query)
+}
+
+// GroupBy groups by on audit.
+func (m *AuditModel) GroupBy(query AuditGroupByArgs) ([]teo.Value, error) {
+	return m.ctx.GroupBy(query)
+}
+
+// SQL runs a custom SQL clause.
+func (m *AuditModel) SQL(sql string) ([]teo.Value, error) {
+	return m.ctx.SQL(sql)
+}
+
+// Audit Audit
+//
+// This model doesn't have a description.
+type Audit struct {
+	inner *teo.Object
+}
+
+// WrapAudit wraps a runtime object, returning nil for nil.
+func WrapAudit(object *teo.Object) *Audit {
+	if object == nil {
+		return nil
+	}
+	return &Audit{inner: object}
+}
+
+// Inner returns the wrapped runtime object.
+func (o *Audit) Inner() *teo.Object {
+	return o.inner
+}
+
+// IsNew reports whether this object is not saved yet.
+func (o *Audit) IsNew() bool {
+	return o.inner.IsNew()
+}
+
+// IsModified reports whether this object has unsaved changes.
+func (o *Audit) IsModified() bool {
+	return o.inner.IsModified()
+}
+
+// Set sets new values to this object.
+func (o *Audit) Set(values AuditUpdateInput) error {
+	return o.inner.Set(values)
+}
+
+// Update updates this object with scalar values.
+func (o *Audit) Update(values AuditScalarUpdateInput) error {
+	return o.inner.Update(values)
+}
+
+// Save saves this object.
+func (o *Audit) Save() error {
+	return o.inner.Save()
+}
+
+// Delete deletes this object.
+func (o *Audit) Delete() error {
+	return o.inner.Delete()
+}
+
+// ToTeon converts this object to a TEON value.
+func (o *Audit) ToTeon() (teo.Value, error) {
+	return o.inner.ToTeon()
+}
+
+// Id Id
+//
+// This field doesn't have a description.
+func (o *Audit) Id() (int32, error) {
+	return teo.Get[int32](o.inner, "id")
+}
+
+// SetId sets Id.
+func (o *Audit) SetId(value int32) error {
+	return o.inner.SetValue("id", value)
+}
+
+// Action Action
+//
+// This field doesn't have a description.
+func (o *Audit) Action() (string, error) {
+	return teo.Get[string](o.inner, "action")
+}
+
+// SetAction sets Action.
+func (o *Audit) SetAction(value string) error {
+	return o.inner.SetValue("action", value)
+}
+
+// Role Role
+//
+// This field doesn't have a description.
+func (o *Audit) Role() (Role, error) {
+	return teo.Get[Role](o.inner, "role")
+}
+
+// SetRole sets Role.
+func (o *Audit) SetRole(value Role) error {
+	return o.inner.SetValue("role", value)
+}
+
+// CreatedAt Created at
+//
+// This field doesn't have a description.
+func (o *Audit) CreatedAt() (time.Time, error) {
+	return teo.Get[time.Time](o.inner, "createdAt")
+}
+
+// SetCreatedAt sets Created at.
+func (o *Audit) SetCreatedAt(value time.Time) error {
+	return o.inner.SetValue("createdAt", value)
+}
+
+// Namespace gives typed access to the models and namespaces of `admin`.
+type Namespace struct {
+	ctx *teo.TransactionCtx
+}
+
+// New wraps a runtime transaction context.
+func New(ctx *teo.TransactionCtx) *Namespace {
+	return &Namespace{ctx: ctx}
+}
+
+// Audit returns the audit model.
+func (t *Namespace) Audit() *AuditModel {
+	return &AuditModel{ctx: t.ctx.ModelCtx([]string{"admin", "Audit"})}
+}
\ No newline at end of file
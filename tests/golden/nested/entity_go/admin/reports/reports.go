@@ -0,0 +1,40 @@
+// Code generated by Teo. DO NOT EDIT.
+
+package reports
+
+import (
+	teo "github.com/teodevgroup/teo-go"
+	admin "generated/admin"
+)
+
+// ReportPathArguments Report path arguments
+//
+// This interface doesn't have a description.
+type ReportPathArguments struct {
+	// Year Year
+	Year int32 `json:"year"`
+	// Month Month
+	Month int32 `json:"month"`
+}
+
+// Summary Summary
+//
+// This interface doesn't have a description.
+type Summary[T any] struct {
+	// Total Total
+	Total int32 `json:"total"`
+	// Latest Latest
+	Latest *T `json:"latest,omitempty"`
+	// ByRole By role
+	ByRole []admin.Role `json:"byRole"`
+}
+
+// Namespace gives typed access to the models and namespaces of `admin.reports`.
+type Namespace struct {
+	ctx *teo.TransactionCtx
+}
+
+// New wraps a runtime transaction context.
+func New(ctx *teo.TransactionCtx) *Namespace {
+	return &Namespace{ctx: ctx}
+}
\ No newline at end of file
@@ -0,0 +1,962 @@
+// Code generated by Teo. DO NOT EDIT.
+
+package generated
+
+import (
+	teo "github.com/teodevgroup/teo-go"
+	admin "generated/admin"
+	stdlib "generated/stdlib"
+)
+
+// AccountDirectRelations Account direct relations
+//
+// This synthesized enum doesn't have a description.
+type AccountDirectRelations string
+
+const (
+)
+
+// AccountIndirectRelations Account indirect relations
+//
+// This synthesized enum doesn't have a description.
+type AccountIndirectRelations string
+
+const (
+)
+
+// AccountRelations Account relations
+//
+// This synthesized enum doesn't have a description.
+type AccountRelations string
+
+const (
+)
+
+// AccountScalarFields Account scalar fields
+//
+// This synthesized enum doesn't have a description.
+type AccountScalarFields string
+
+const (
+	// AccountScalarFieldsId Id
+	AccountScalarFieldsId AccountScalarFields = "id"
+	// AccountScalarFieldsName Name
+	AccountScalarFieldsName AccountScalarFields = "name"
+	// AccountScalarFieldsRole Role
+	AccountScalarFieldsRole AccountScalarFields = "role"
+)
+
+// AccountSerializableScalarFields Account serializable scalar fields
+//
+// This synthesized enum doesn't have a description.
+type AccountSerializableScalarFields string
+
+const (
+	// AccountSerializableScalarFieldsId Id
+	AccountSerializableScalarFieldsId AccountSerializableScalarFields = "id"
+	// AccountSerializableScalarFieldsName Name
+	AccountSerializableScalarFieldsName AccountSerializableScalarFields = "name"
+	// AccountSerializableScalarFieldsRole Role
+	AccountSerializableScalarFieldsRole AccountSerializableScalarFields = "role"
+)
+
+// AccountAggregateArgs Account aggregate args
+//
+// This synthesized interface doesn't have a description
+type AccountAggregateArgs struct {
+	// Avg Avg
+	Avg *AccountAvgAggregateInputType `json:"_avg,omitempty"`
+	// Count Count
+	Count *AccountCountAggregateInputType `json:"_count,omitempty"`
+	// Max Max
+	Max *AccountMaxAggregateInputType `json:"_max,omitempty"`
+	// Min Min
+	Min *AccountMinAggregateInputType `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *AccountSumAggregateInputType `json:"_sum,omitempty"`
+	// Cursor Cursor
+	Cursor *AccountWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *AccountSerializableScalarFields `json:"distinct,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *AccountWhereInput `json:"where,omitempty"`
+}
+
+// AccountAggregateResult Account aggregate result
+//
+// This synthesized interface doesn't have a description
+type AccountAggregateResult struct {
+	// Avg Avg
+	Avg *AccountAvgAggregateResult `json:"_avg,omitempty"`
+	// Count Count
+	Count *AccountCountAggregateResult `json:"_count,omitempty"`
+	// Max Max
+	Max *AccountMaxAggregateResult `json:"_max,omitempty"`
+	// Min Min
+	Min *AccountMinAggregateResult `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *AccountSumAggregateResult `json:"_sum,omitempty"`
+}
+
+// AccountArgs Account args
+//
+// This synthesized interface doesn't have a description
+type AccountArgs struct {
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+}
+
+// AccountAvgAggregateInputType Account avg aggregate input type
+//
+// This synthesized interface doesn't have a description
+type AccountAvgAggregateInputType struct {
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+}
+
+// AccountAvgAggregateResult Account avg aggregate result
+//
+// This synthesized interface doesn't have a description
+type AccountAvgAggregateResult struct {
+	// Id Id
+	Id *float64 `json:"id,omitempty"`
+}
+
+// AccountConnectOrCreateInput Account connect or create input
+//
+// This synthesized interface doesn't have a description
+type AccountConnectOrCreateInput struct {
+	// Create Create
+	Create AccountCreateInput `json:"create"`
+	// Where Where
+	Where AccountWhereUniqueInput `json:"where"`
+}
+
+// AccountCopyArgs Account copy args
+//
+// This synthesized interface doesn't have a description
+type AccountCopyArgs struct {
+	// Copy Copy
+	Copy AccountUpdateInput `json:"copy"`
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+	// Where Where
+	Where AccountWhereUniqueInput `json:"where"`
+}
+
+// AccountCopyManyArgs Account copy many args
+//
+// This synthesized interface doesn't have a description
+type AccountCopyManyArgs struct {
+	// Copy Copy
+	Copy AccountUpdateInput `json:"copy"`
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+	// Where Where
+	Where AccountWhereInput `json:"where"`
+}
+
+// AccountCountAggregateInputType Account count aggregate input type
+//
+// This synthesized interface doesn't have a description
+type AccountCountAggregateInputType struct {
+	// All All
+	All *bool `json:"_all,omitempty"`
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Name Name
+	Name *bool `json:"name,omitempty"`
+	// Role Role
+	Role *bool `json:"role,omitempty"`
+}
+
+// AccountCountAggregateResult Account count aggregate result
+//
+// This synthesized interface doesn't have a description
+type AccountCountAggregateResult struct {
+	// All All
+	All *int64 `json:"_all,omitempty"`
+	// Id Id
+	Id *int64 `json:"id,omitempty"`
+	// Name Name
+	Name *int64 `json:"name,omitempty"`
+	// Role Role
+	Role *int64 `json:"role,omitempty"`
+}
+
+// AccountCountArgs Account count args
+//
+// This synthesized interface doesn't have a description
+type AccountCountArgs struct {
+	// Cursor Cursor
+	Cursor *AccountWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *AccountSerializableScalarFields `json:"distinct,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *AccountCountAggregateInputType `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *AccountWhereInput `json:"where,omitempty"`
+}
+
+// AccountCreateArgs Account create args
+//
+// This synthesized interface doesn't have a description
+type AccountCreateArgs struct {
+	// Create Create
+	Create AccountCreateInput `json:"create"`
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+}
+
+// AccountCreateInput Account create input
+//
+// This synthesized interface doesn't have a description
+type AccountCreateInput struct {
+	// Name Name
+	Name string `json:"name"`
+	// Role Role
+	Role admin.Role `json:"role"`
+}
+
+// AccountCreateManyArgs Account create many args
+//
+// This synthesized interface doesn't have a description
+type AccountCreateManyArgs struct {
+	// Create Create
+	Create teo.Value `json:"create"`
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+}
+
+// AccountCreateNestedManyInput Account create nested many input
+//
+// This synthesized interface doesn't have a description
+type AccountCreateNestedManyInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+}
+
+// AccountCreateNestedOneInput Account create nested one input
+//
+// This synthesized interface doesn't have a description
+type AccountCreateNestedOneInput struct {
+	// Connect Connect
+	Connect *AccountWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *AccountConnectOrCreateInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *AccountCreateInput `json:"create,omitempty"`
+}
+
+// AccountDeleteArgs Account delete args
+//
+// This synthesized interface doesn't have a description
+type AccountDeleteArgs struct {
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+	// Where Where
+	Where AccountWhereUniqueInput `json:"where"`
+}
+
+// AccountDeleteManyArgs Account delete many args
+//
+// This synthesized interface doesn't have a description
+type AccountDeleteManyArgs struct {
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+	// Where Where
+	Where AccountWhereInput `json:"where"`
+}
+
+// AccountFindFirstArgs Account find first args
+//
+// This synthesized interface doesn't have a description
+type AccountFindFirstArgs struct {
+	// Cursor Cursor
+	Cursor *AccountWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *AccountSerializableScalarFields `json:"distinct,omitempty"`
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *AccountWhereInput `json:"where,omitempty"`
+}
+
+// AccountFindManyArgs Account find many args
+//
+// This synthesized interface doesn't have a description
+type AccountFindManyArgs struct {
+	// Cursor Cursor
+	Cursor *AccountWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *AccountSerializableScalarFields `json:"distinct,omitempty"`
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *AccountWhereInput `json:"where,omitempty"`
+}
+
+// AccountFindUniqueArgs Account find unique args
+//
+// This synthesized interface doesn't have a description
+type AccountFindUniqueArgs struct {
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+	// Where Where
+	Where AccountWhereUniqueInput `json:"where"`
+}
+
+// AccountGroupByArgs Account group by args
+//
+// This synthesized interface doesn't have a description
+type AccountGroupByArgs struct {
+	// Avg Avg
+	Avg *AccountAvgAggregateInputType `json:"_avg,omitempty"`
+	// Count Count
+	Count *AccountCountAggregateInputType `json:"_count,omitempty"`
+	// Max Max
+	Max *AccountMaxAggregateInputType `json:"_max,omitempty"`
+	// Min Min
+	Min *AccountMinAggregateInputType `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *AccountSumAggregateInputType `json:"_sum,omitempty"`
+	// By By
+	By teo.Value `json:"by"`
+	// Cursor Cursor
+	Cursor *AccountWhereUniqueInput `json:"cursor,omitempty"`
+	// Distinct Distinct
+	Distinct *AccountSerializableScalarFields `json:"distinct,omitempty"`
+	// Having Having
+	Having *AccountScalarWhereWithAggregatesInput `json:"having,omitempty"`
+	// OrderBy Order By
+	OrderBy teo.Value `json:"orderBy,omitempty"`
+	// PageNumber Page Number
+	PageNumber *int64 `json:"pageNumber,omitempty"`
+	// PageSize Page Size
+	PageSize *int64 `json:"pageSize,omitempty"`
+	// Skip Skip
+	Skip *int64 `json:"skip,omitempty"`
+	// Take Take
+	Take *int64 `json:"take,omitempty"`
+	// Where Where
+	Where *AccountWhereInput `json:"where,omitempty"`
+}
+
+// AccountGroupByResult Account group by result
+//
+// This synthesized interface doesn't have a description
+type AccountGroupByResult struct {
+	// Avg Avg
+	Avg *AccountAvgAggregateResult `json:"_avg,omitempty"`
+	// Count Count
+	Count *AccountCountAggregateResult `json:"_count,omitempty"`
+	// Max Max
+	Max *AccountMaxAggregateResult `json:"_max,omitempty"`
+	// Min Min
+	Min *AccountMinAggregateResult `json:"_min,omitempty"`
+	// Sum Sum
+	Sum *AccountSumAggregateResult `json:"_sum,omitempty"`
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Role Role
+	Role *admin.Role `json:"role,omitempty"`
+}
+
+// AccountInclude Account include
+//
+// This synthesized interface doesn't have a description
+type AccountInclude struct {
+}
+
+// AccountListRelationFilter Account list relation filter
+//
+// This synthesized interface doesn't have a description
+type AccountListRelationFilter struct {
+	// Every Every
+	Every *AccountWhereInput `json:"every,omitempty"`
+	// None None
+	None *AccountWhereInput `json:"none,omitempty"`
+	// Some Some
+	Some *AccountWhereInput `json:"some,omitempty"`
+}
+
+// AccountMaxAggregateInputType Account max aggregate input type
+//
+// This synthesized interface doesn't have a description
+type AccountMaxAggregateInputType struct {
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Name Name
+	Name *bool `json:"name,omitempty"`
+	// Role Role
+	Role *bool `json:"role,omitempty"`
+}
+
+// AccountMaxAggregateResult Account max aggregate result
+//
+// This synthesized interface doesn't have a description
+type AccountMaxAggregateResult struct {
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Role Role
+	Role *admin.Role `json:"role,omitempty"`
+}
+
+// AccountMinAggregateInputType Account min aggregate input type
+//
+// This synthesized interface doesn't have a description
+type AccountMinAggregateInputType struct {
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Name Name
+	Name *bool `json:"name,omitempty"`
+	// Role Role
+	Role *bool `json:"role,omitempty"`
+}
+
+// AccountMinAggregateResult Account min aggregate result
+//
+// This synthesized interface doesn't have a description
+type AccountMinAggregateResult struct {
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Role Role
+	Role *admin.Role `json:"role,omitempty"`
+}
+
+// AccountOrderByInput Account order by input
+//
+// This synthesized interface doesn't have a description
+type AccountOrderByInput struct {
+	// Id Id
+	Id *stdlib.Sort `json:"id,omitempty"`
+	// Name Name
+	Name *stdlib.Sort `json:"name,omitempty"`
+	// Role Role
+	Role *stdlib.Sort `json:"role,omitempty"`
+}
+
+// AccountRelationFilter Account relation filter
+//
+// This synthesized interface doesn't have a description
+type AccountRelationFilter struct {
+	// Is Is
+	Is *AccountWhereInput `json:"is,omitempty"`
+	// IsNot Is Not
+	IsNot *AccountWhereInput `json:"isNot,omitempty"`
+}
+
+// AccountResult Account result
+//
+// This synthesized interface doesn't have a description
+type AccountResult struct {
+	// Id Id
+	Id int32 `json:"id"`
+	// Name Name
+	Name string `json:"name"`
+	// Role Role
+	Role admin.Role `json:"role"`
+}
+
+// AccountScalarUpdateInput Account scalar update input
+//
+// This synthesized interface doesn't have a description
+type AccountScalarUpdateInput struct {
+	// Id Id
+	Id *int32 `json:"id,omitempty"`
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Role Role
+	Role *admin.Role `json:"role,omitempty"`
+}
+
+// AccountScalarWhereWithAggregatesInput Account scalar where with aggregates input
+//
+// This synthesized interface doesn't have a description
+type AccountScalarWhereWithAggregatesInput struct {
+	// And And
+	And []AccountWhereInput `json:"AND,omitempty"`
+	// Not Not
+	Not *AccountWhereInput `json:"NOT,omitempty"`
+	// Or Or
+	Or []AccountWhereInput `json:"OR,omitempty"`
+	// Id Id
+	Id teo.Value `json:"id,omitempty"`
+	// Name Name
+	Name teo.Value `json:"name,omitempty"`
+	// Role Role
+	Role teo.Value `json:"role,omitempty"`
+}
+
+// AccountSelect Account select
+//
+// This synthesized interface doesn't have a description
+type AccountSelect struct {
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+	// Name Name
+	Name *bool `json:"name,omitempty"`
+	// Role Role
+	Role *bool `json:"role,omitempty"`
+}
+
+// AccountSignInArgs Account sign in args
+//
+// This synthesized interface doesn't have a description
+type AccountSignInArgs struct {
+}
+
+// AccountSignInCheckerCompanions Account sign in checker companions
+//
+// This synthesized interface doesn't have a description
+type AccountSignInCheckerCompanions struct {
+}
+
+// AccountSignInCheckerIds Account sign in checker ids
+//
+// This synthesized interface doesn't have a description
+type AccountSignInCheckerIds struct {
+}
+
+// AccountSignInInput Account sign in input
+//
+// This synthesized interface doesn't have a description
+type AccountSignInInput struct {
+	// Credentials Credentials
+	Credentials AccountSignInArgs `json:"credentials"`
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+}
+
+// AccountSumAggregateInputType Account sum aggregate input type
+//
+// This synthesized interface doesn't have a description
+type AccountSumAggregateInputType struct {
+	// Id Id
+	Id *bool `json:"id,omitempty"`
+}
+
+// AccountSumAggregateResult Account sum aggregate result
+//
+// This synthesized interface doesn't have a description
+type AccountSumAggregateResult struct {
+	// Id Id
+	Id *int64 `json:"id,omitempty"`
+}
+
+// AccountUpdateArgs Account update args
+//
+// This synthesized interface doesn't have a description
+type AccountUpdateArgs struct {
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+	// Update Update
+	Update AccountUpdateInput `json:"update"`
+	// Where Where
+	Where AccountWhereUniqueInput `json:"where"`
+}
+
+// AccountUpdateInput Account update input
+//
+// This synthesized interface doesn't have a description
+type AccountUpdateInput struct {
+	// Name Name
+	Name *string `json:"name,omitempty"`
+	// Role Role
+	Role *admin.Role `json:"role,omitempty"`
+}
+
+// AccountUpdateManyArgs Account update many args
+//
+// This synthesized interface doesn't have a description
+type AccountUpdateManyArgs struct {
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+	// Update Update
+	Update AccountUpdateInput `json:"update"`
+	// Where Where
+	Where AccountWhereInput `json:"where"`
+}
+
+// AccountUpdateManyWithWhereInput Account update many with where input
+//
+// This synthesized interface doesn't have a description
+type AccountUpdateManyWithWhereInput struct {
+	// Update Update
+	Update AccountUpdateInput `json:"update"`
+	// Where Where
+	Where AccountWhereInput `json:"where"`
+}
+
+// AccountUpdateNestedManyInput Account update nested many input
+//
+// This synthesized interface doesn't have a description
+type AccountUpdateNestedManyInput struct {
+	// Connect Connect
+	Connect teo.Value `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate teo.Value `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create teo.Value `json:"create,omitempty"`
+	// Delete Delete
+	Delete teo.Value `json:"delete,omitempty"`
+	// DeleteMany Delete Many
+	DeleteMany teo.Value `json:"deleteMany,omitempty"`
+	// Disconnect Disconnect
+	Disconnect teo.Value `json:"disconnect,omitempty"`
+	// Set Set
+	Set teo.Value `json:"set,omitempty"`
+	// Update Update
+	Update teo.Value `json:"update,omitempty"`
+	// UpdateMany Update Many
+	UpdateMany teo.Value `json:"updateMany,omitempty"`
+	// Upsert Upsert
+	Upsert teo.Value `json:"upsert,omitempty"`
+}
+
+// AccountUpdateNestedOneInput Account update nested one input
+//
+// This synthesized interface doesn't have a description
+type AccountUpdateNestedOneInput struct {
+	// Connect Connect
+	Connect *AccountWhereUniqueInput `json:"connect,omitempty"`
+	// ConnectOrCreate Connect Or Create
+	ConnectOrCreate *AccountConnectOrCreateInput `json:"connectOrCreate,omitempty"`
+	// Create Create
+	Create *AccountCreateInput `json:"create,omitempty"`
+	// Delete Delete
+	Delete *bool `json:"delete,omitempty"`
+	// Disconnect Disconnect
+	Disconnect *bool `json:"disconnect,omitempty"`
+	// Set Set
+	Set *AccountWhereUniqueInput `json:"set,omitempty"`
+	// Update Update
+	Update *AccountUpdateInput `json:"update,omitempty"`
+	// Upsert Upsert
+	Upsert *AccountUpsertWithWhereUniqueInput `json:"upsert,omitempty"`
+}
+
+// AccountUpdateWithWhereUniqueInput Account update with where unique input
+//
+// This synthesized interface doesn't have a description
+type AccountUpdateWithWhereUniqueInput struct {
+	// Update Update
+	Update AccountUpdateInput `json:"update"`
+	// Where Where
+	Where AccountWhereUniqueInput `json:"where"`
+}
+
+// AccountUpsertArgs Account upsert args
+//
+// This synthesized interface doesn't have a description
+type AccountUpsertArgs struct {
+	// Create Create
+	Create AccountCreateInput `json:"create"`
+	// Include Include
+	Include *AccountInclude `json:"include,omitempty"`
+	// Select Select
+	Select *AccountSelect `json:"select,omitempty"`
+	// Update Update
+	Update AccountUpdateInput `json:"update"`
+	// Where Where
+	Where AccountWhereUniqueInput `json:"where"`
+}
+
+// AccountUpsertWithWhereUniqueInput Account upsert with where unique input
+//
+// This synthesized interface doesn't have a description
+type AccountUpsertWithWhereUniqueInput struct {
+	// Create Create
+	Create AccountCreateInput `json:"create"`
+	// Update Update
+	Update AccountUpdateInput `json:"update"`
+	// Where Where
+	Where AccountWhereUniqueInput `json:"where"`
+}
+
+// AccountWhereInput Account where input
+//
+// This synthesized interface doesn't have a description
+type AccountWhereInput struct {
+	// And And
+	And []AccountWhereInput `json:"AND,omitempty"`
+	// Not Not
+	Not *AccountWhereInput `json:"NOT,omitempty"`
+	// Or Or
+	Or []AccountWhereInput `json:"OR,omitempty"`
+	// Id Id
+	Id teo.Value `json:"id,omitempty"`
+	// Name Name
+	Name teo.Value `json:"name,omitempty"`
+	// Role Role
+	Role teo.Value `json:"role,omitempty"`
+}
+
+// AccountWhereUniqueInput Account where unique input
+//
+// This synthesized interface doesn't have a description
+type AccountWhereUniqueInput struct {
+	// Id Id
+	Id int32 `json:"id"`
+}
+
+// AccountModel Account
+//
+// This model doesn't have a description.
+type AccountModel struct {
+	ctx *teo.ModelCtx
+}
+
+// FindManyObjects finds many account objects.
+func (m *AccountModel) FindManyObjects(query AccountFindManyArgs) ([]*Account, error) {
+	objects, err := m.ctx.FindMany(query)
+	if err != nil {
+		return nil, err
+	}
+	result := make([]*Account, len(objects))
+	for i, object := range objects {
+		result[i] = WrapAccount(object)
+	}
+	return result, nil
+}
+
+// FindUniqueObject finds a unique account object.
+func (m *AccountModel) FindUniqueObject(query AccountFindUniqueArgs) (*Account, error) {
+	object, err := m.ctx.FindUnique(query)
+	if err != nil {
+		return nil, err
+	}
+	return WrapAccount(object), nil
+}
+
+// FindFirstObject finds an account object.
+func (m *AccountModel) FindFirstObject(query AccountFindFirstArgs) (*Account, error) {
+	object, err := m.ctx.FindFirst(query)
+	if err != nil {
+		return nil, err
+	}
+	return WrapAccount(object), nil
+}
+
+// CreateObject creates a new account object.
+func (m *AccountModel) CreateObject(values AccountCreateInput) (*Account, error) {
+	object, err := m.ctx.Create(values)
+	if err != nil {
+		return nil, err
+	}
+	return WrapAccount(object), nil
+}
+
+// CountObjects counts objects on account.
+func (m *AccountModel) CountObjects(query AccountCountArgs) (int, error) {
+	return m.ctx.Count(query)
+}
+
+// Aggregate aggregates on account.
+func (m *AccountModel) Aggregate(query AccountAggregateArgs) (teo.Value, error) {
+	return m.ctx.Aggregate(query)
+}
+
+// GroupBy groups by on account.
+func (m *AccountModel) GroupBy(query AccountGroupByArgs) ([]teo.Value, error) {
+	return m.ctx.GroupBy(query)
+}
+
+// SQL runs a custom SQL clause.
+func (m *AccountModel) SQL(sql string) ([]teo.Value, error) {
+	return m.ctx.SQL(sql)
+}
+
+// Account Account
+//
+// This model doesn't have a description.
+type Account struct {
+	inner *teo.Object
+}
+
+// WrapAccount wraps a runtime object, returning nil for nil.
+func WrapAccount(object *teo.Object) *Account {
+	if object == nil {
+		return nil
+	}
+	return &Account{inner: object}
+}
+
+// Inner returns the wrapped runtime object.
+func (o *Account) Inner() *teo.Object {
+	return o.inner
+}
+
+// IsNew reports whether this object is not saved yet.
+func (o *Account) IsNew() bool {
+	return o.inner.IsNew()
+}
+
+// IsModified reports whether this object has unsaved changes.
+func (o *Account) IsModified() bool {
+	return o.inner.IsModified()
+}
+
+// Set sets new values to this object.
+func (o *Account) Set(values AccountUpdateInput) error {
+	return o.inner.Set(values)
+}
+
+// Update updates this object with scalar values.
+func (o *Account) Update(values AccountScalarUpdateInput) error {
+	return o.inner.Update(values)
+}
+
+// Save saves this object.
+func (o *Account) Save() error {
+	return o.inner.Save()
+}
+
+// Delete deletes this object.
+func (o *Account) Delete() error {
+	return o.inner.Delete()
+}
+
+// ToTeon converts this object to a TEON value.
+func (o *Account) ToTeon() (teo.Value, error) {
+	return o.inner.ToTeon()
+}
+
+// Id Id
+//
+// This field doesn't have a description.
+func (o *Account) Id() (int32, error) {
+	return teo.Get[int32](o.inner, "id")
+}
+
+// SetId sets Id.
+func (o *Account) SetId(value int32) error {
+	return o.inner.SetValue("id", value)
+}
+
+// Name Name
+//
+// This field doesn't have a description.
+func (o *Account) Name() (string, error) {
+	return teo.Get[string](o.inner, "name")
+}
+
+// SetName sets Name.
+func (o *Account) SetName(value string) error {
+	return o.inner.SetValue("name", value)
+}
+
+// Role Role
+//
+// This field doesn't have a description.
+func (o *Account) Role() (admin.Role, error) {
+	return teo.Get[admin.Role](o.inner, "role")
+}
+
+// SetRole sets Role.
+func (o *Account) SetRole(value admin.Role) error {
+	return o.inner.SetValue("role", value)
+}
+
+// Teo gives typed access to the models and namespaces of the schema.
+type Teo struct {
+	ctx *teo.TransactionCtx
+}
+
+// New wraps a runtime transaction context.
+func New(ctx *teo.TransactionCtx) *Teo {
+	return &Teo{ctx: ctx}
+}
+
+// Transaction runs callback in a transaction.
+func (t *Teo) Transaction(callback func(*Teo) error) error {
+	return t.ctx.Transaction(func(ctx *teo.TransactionCtx) error {
+		return callback(New(ctx))
+	})
+}
+
+// Account returns the account model.
+func (t *Teo) Account() *AccountModel {
+	return &AccountModel{ctx: t.ctx.ModelCtx([]string{"Account"})}
+}
+
+// Admin returns the `admin` namespace.
+func (t *Teo) Admin() *admin.Namespace {
+	return admin.New(t.ctx)
+}
\ No newline at end of file
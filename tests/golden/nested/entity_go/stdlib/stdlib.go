@@ -0,0 +1,523 @@
+// Code generated by Teo. DO NOT EDIT.
+
+package stdlib
+
+import (
+	teo "github.com/teodevgroup/teo-go"
+)
+
+// Sort Sort Order
+//
+// Represents the sort order
+type Sort string
+
+const (
+	// SortAsc Asc
+	SortAsc Sort = "asc"
+	// SortDesc Desc
+	SortDesc Sort = "desc"
+)
+
+// StringMatchMode String Match Mode
+//
+// Whether the string query is case sensitive or not
+type StringMatchMode string
+
+const (
+	// StringMatchModeDefault Default
+	StringMatchModeDefault StringMatchMode = "default"
+	// StringMatchModeCaseInsensitive Case insensitive
+	StringMatchModeCaseInsensitive StringMatchMode = "caseInsensitive"
+)
+
+// AggregatesFilter Aggregates filter
+//
+// This interface doesn't have a description.
+type AggregatesFilter[T any] struct {
+	Filter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *Filter[T] `json:"_min,omitempty"`
+	// Max Max
+	Max *Filter[T] `json:"_max,omitempty"`
+}
+
+// ArrayAtomicUpdateOperationInput Array atomic update operation input
+//
+// This interface doesn't have a description.
+type ArrayAtomicUpdateOperationInput[T any] struct {
+	// Push Push
+	Push *T `json:"push,omitempty"`
+}
+
+// ArrayFilter Array filter
+//
+// This interface doesn't have a description.
+type ArrayFilter[T any] struct {
+	// Equals Equals
+	Equals []T `json:"equals,omitempty"`
+	// Has Has
+	Has *T `json:"has,omitempty"`
+	// HasSome Has some
+	HasSome []T `json:"hasSome,omitempty"`
+	// HasEvery Has every
+	HasEvery []T `json:"hasEvery,omitempty"`
+	// IsEmpty Is empty
+	IsEmpty *bool `json:"isEmpty,omitempty"`
+	// Length Length
+	Length *int32 `json:"length,omitempty"`
+}
+
+// ArrayNullableFilter Array nullable filter
+//
+// This interface doesn't have a description.
+type ArrayNullableFilter[T any] struct {
+	// Equals Equals
+	Equals teo.Value `json:"equals,omitempty"`
+	// Has Has
+	Has *T `json:"has,omitempty"`
+	// HasSome Has some
+	HasSome []T `json:"hasSome,omitempty"`
+	// HasEvery Has every
+	HasEvery []T `json:"hasEvery,omitempty"`
+	// IsEmpty Is empty
+	IsEmpty *bool `json:"isEmpty,omitempty"`
+	// Length Length
+	Length *int32 `json:"length,omitempty"`
+}
+
+// ArrayNullableWithAggregatesFilter Array nullable with aggregates filter
+//
+// This interface doesn't have a description.
+type ArrayNullableWithAggregatesFilter[T any] struct {
+	ArrayNullableFilter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *ArrayNullableFilter[T] `json:"_min,omitempty"`
+	// Max Max
+	Max *ArrayNullableFilter[T] `json:"_max,omitempty"`
+}
+
+// ArrayWithAggregatesFilter Array with aggregates filter
+//
+// This interface doesn't have a description.
+type ArrayWithAggregatesFilter[T any] struct {
+	ArrayFilter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *ArrayFilter[T] `json:"_min,omitempty"`
+	// Max Max
+	Max *ArrayFilter[T] `json:"_max,omitempty"`
+}
+
+// BoolFilter Bool filter
+//
+// This interface doesn't have a description.
+type BoolFilter struct {
+	// Equals Equals
+	Equals *bool `json:"equals,omitempty"`
+	// Not Not
+	Not teo.Value `json:"not,omitempty"`
+}
+
+// BoolNullableFilter Bool nullable filter
+//
+// This interface doesn't have a description.
+type BoolNullableFilter struct {
+	// Equals Equals
+	Equals teo.Value `json:"equals,omitempty"`
+	// Not Not
+	Not teo.Value `json:"not,omitempty"`
+}
+
+// BoolNullableWithAggregatesFilter Bool nullable with aggregates filter
+//
+// This interface doesn't have a description.
+type BoolNullableWithAggregatesFilter struct {
+	BoolNullableFilter
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *BoolNullableFilter `json:"_min,omitempty"`
+	// Max Max
+	Max *BoolNullableFilter `json:"_max,omitempty"`
+}
+
+// BoolWithAggregatesFilter Bool with aggregates filter
+//
+// This interface doesn't have a description.
+type BoolWithAggregatesFilter struct {
+	BoolFilter
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *BoolFilter `json:"_min,omitempty"`
+	// Max Max
+	Max *BoolFilter `json:"_max,omitempty"`
+}
+
+// Data Data
+//
+// This interface is common for action output
+type Data[T any] struct {
+	// Data Data
+	Data T `json:"data"`
+}
+
+// DataMeta Data and Meta
+//
+// This interface is common for action output with meta information
+type DataMeta[T any, U any] struct {
+	// Data Data
+	Data T `json:"data"`
+	// Meta Meta
+	Meta U `json:"meta"`
+}
+
+// DecimalNullableWithAggregatesFilter Decimal nullable with aggregates filter
+//
+// This interface doesn't have a description.
+type DecimalNullableWithAggregatesFilter[T any] struct {
+	NullableFilter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *NullableFilter[teo.Decimal] `json:"_min,omitempty"`
+	// Max Max
+	Max *NullableFilter[teo.Decimal] `json:"_max,omitempty"`
+	// Avg Avg
+	Avg *NullableFilter[teo.Decimal] `json:"_avg,omitempty"`
+	// Sum Sum
+	Sum *NullableFilter[teo.Decimal] `json:"_sum,omitempty"`
+}
+
+// DecimalWithAggregatesFilter Decimal with aggregates filter
+//
+// This interface doesn't have a description.
+type DecimalWithAggregatesFilter struct {
+	Filter[teo.Decimal]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *Filter[teo.Decimal] `json:"_min,omitempty"`
+	// Max Max
+	Max *Filter[teo.Decimal] `json:"_max,omitempty"`
+	// Avg Avg
+	Avg *Filter[teo.Decimal] `json:"_avg,omitempty"`
+	// Sum Sum
+	Sum *Filter[teo.Decimal] `json:"_sum,omitempty"`
+}
+
+// Empty Empty
+//
+// The empty interface
+type Empty struct {
+}
+
+// EnumFilter Enum filter
+//
+// This interface doesn't have a description.
+type EnumFilter[T any] struct {
+	// Equals Equals
+	Equals *T `json:"equals,omitempty"`
+	// In In
+	In []T `json:"in,omitempty"`
+	// NotIn Not in
+	NotIn []T `json:"notIn,omitempty"`
+	// Not Not
+	Not teo.Value `json:"not,omitempty"`
+}
+
+// EnumNullableFilter Enum nullable filter
+//
+// This interface doesn't have a description.
+type EnumNullableFilter[T any] struct {
+	// Equals Equals
+	Equals teo.Value `json:"equals,omitempty"`
+	// In In
+	In []teo.Value `json:"in,omitempty"`
+	// NotIn Not in
+	NotIn []teo.Value `json:"notIn,omitempty"`
+	// Not Not
+	Not teo.Value `json:"not,omitempty"`
+}
+
+// EnumNullableWithAggregatesFilter Enum nullable with aggregates filter
+//
+// This interface doesn't have a description.
+type EnumNullableWithAggregatesFilter[T any] struct {
+	EnumNullableFilter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *EnumNullableFilter[T] `json:"_min,omitempty"`
+	// Max Max
+	Max *EnumNullableFilter[T] `json:"_max,omitempty"`
+}
+
+// EnumWithAggregatesFilter Enum with aggregates filter
+//
+// This interface doesn't have a description.
+type EnumWithAggregatesFilter[T any] struct {
+	EnumFilter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *EnumFilter[T] `json:"_min,omitempty"`
+	// Max Max
+	Max *EnumFilter[T] `json:"_max,omitempty"`
+}
+
+// Filter Filter
+//
+// This interface doesn't have a description.
+type Filter[T any] struct {
+	// Equals Equals
+	Equals *T `json:"equals,omitempty"`
+	// In In
+	In []T `json:"in,omitempty"`
+	// NotIn Not in
+	NotIn []T `json:"notIn,omitempty"`
+	// Lt Lt
+	Lt *T `json:"lt,omitempty"`
+	// Lte Lte
+	Lte *T `json:"lte,omitempty"`
+	// Gt Gt
+	Gt *T `json:"gt,omitempty"`
+	// Gte Gte
+	Gte *T `json:"gte,omitempty"`
+	// Not Not
+	Not teo.Value `json:"not,omitempty"`
+}
+
+// FloatNumberNullableWithAggregatesFilter Float number nullable with aggregates filter
+//
+// This interface doesn't have a description.
+type FloatNumberNullableWithAggregatesFilter[T any] struct {
+	NullableFilter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *NullableFilter[T] `json:"_min,omitempty"`
+	// Max Max
+	Max *NullableFilter[T] `json:"_max,omitempty"`
+	// Avg Avg
+	Avg *NullableFilter[float64] `json:"_avg,omitempty"`
+	// Sum Sum
+	Sum *NullableFilter[float64] `json:"_sum,omitempty"`
+}
+
+// FloatNumberWithAggregatesFilter Float number with aggregates filter
+//
+// This interface doesn't have a description.
+type FloatNumberWithAggregatesFilter[T any] struct {
+	Filter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *Filter[T] `json:"_min,omitempty"`
+	// Max Max
+	Max *Filter[T] `json:"_max,omitempty"`
+	// Avg Avg
+	Avg *Filter[float64] `json:"_avg,omitempty"`
+	// Sum Sum
+	Sum *Filter[float64] `json:"_sum,omitempty"`
+}
+
+// IntNumberNullableWithAggregatesFilter Int number nullable with aggregates filter
+//
+// This interface doesn't have a description.
+type IntNumberNullableWithAggregatesFilter[T any] struct {
+	NullableFilter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *NullableFilter[T] `json:"_min,omitempty"`
+	// Max Max
+	Max *NullableFilter[T] `json:"_max,omitempty"`
+	// Avg Avg
+	Avg *NullableFilter[float64] `json:"_avg,omitempty"`
+	// Sum Sum
+	Sum *NullableFilter[int64] `json:"_sum,omitempty"`
+}
+
+// IntNumberWithAggregatesFilter Int number with aggregates filter
+//
+// This interface doesn't have a description.
+type IntNumberWithAggregatesFilter[T any] struct {
+	Filter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *Filter[T] `json:"_min,omitempty"`
+	// Max Max
+	Max *Filter[T] `json:"_max,omitempty"`
+	// Avg Avg
+	Avg *Filter[float64] `json:"_avg,omitempty"`
+	// Sum Sum
+	Sum *Filter[int64] `json:"_sum,omitempty"`
+}
+
+// NullableAggregatesFilter Nullable aggregates filter
+//
+// This interface doesn't have a description.
+type NullableAggregatesFilter[T any] struct {
+	NullableFilter[T]
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *NullableFilter[T] `json:"_min,omitempty"`
+	// Max Max
+	Max *NullableFilter[T] `json:"_max,omitempty"`
+}
+
+// NullableFilter Nullable filter
+//
+// This interface doesn't have a description.
+type NullableFilter[T any] struct {
+	// Equals Equals
+	Equals teo.Value `json:"equals,omitempty"`
+	// In In
+	In []teo.Value `json:"in,omitempty"`
+	// NotIn Not in
+	NotIn []teo.Value `json:"notIn,omitempty"`
+	// Lt Lt
+	Lt *T `json:"lt,omitempty"`
+	// Lte Lte
+	Lte *T `json:"lte,omitempty"`
+	// Gt Gt
+	Gt *T `json:"gt,omitempty"`
+	// Gte Gte
+	Gte *T `json:"gte,omitempty"`
+	// Not Not
+	Not teo.Value `json:"not,omitempty"`
+}
+
+// NumberAtomicUpdateOperationInput Number atomic update operation input
+//
+// This interface doesn't have a description.
+type NumberAtomicUpdateOperationInput[T any] struct {
+	// Increment Increment
+	Increment *T `json:"increment,omitempty"`
+	// Decrement Decrement
+	Decrement *T `json:"decrement,omitempty"`
+	// Multiply Multiply
+	Multiply *T `json:"multiply,omitempty"`
+	// Divide Divide
+	Divide *T `json:"divide,omitempty"`
+}
+
+// PagingInfo Paging info
+//
+// This interface doesn't have a description.
+type PagingInfo struct {
+	// Count Count
+	Count int64 `json:"count"`
+	// NumberOfPages Number of pages
+	NumberOfPages *int64 `json:"numberOfPages,omitempty"`
+}
+
+// ResponseError Response error
+//
+// This interface doesn't have a description.
+type ResponseError struct {
+	// Type Type
+	Type string `json:"type"`
+	// Message Message
+	Message string `json:"message"`
+	// Fields Fields
+	Fields teo.Value `json:"fields"`
+}
+
+// StringFilter String filter
+//
+// This interface doesn't have a description.
+type StringFilter struct {
+	// Equals Equals
+	Equals *string `json:"equals,omitempty"`
+	// In In
+	In []string `json:"in,omitempty"`
+	// NotIn Not in
+	NotIn []string `json:"notIn,omitempty"`
+	// Lt Lt
+	Lt *string `json:"lt,omitempty"`
+	// Lte Lte
+	Lte *string `json:"lte,omitempty"`
+	// Gt Gt
+	Gt *string `json:"gt,omitempty"`
+	// Gte Gte
+	Gte *string `json:"gte,omitempty"`
+	// Contains Contains
+	Contains *string `json:"contains,omitempty"`
+	// StartsWith Starts with
+	StartsWith *string `json:"startsWith,omitempty"`
+	// EndsWith Ends with
+	EndsWith *string `json:"endsWith,omitempty"`
+	// Matches Matches
+	Matches *string `json:"matches,omitempty"`
+	// Mode Mode
+	Mode *StringMatchMode `json:"mode,omitempty"`
+	// Not Not
+	Not teo.Value `json:"not,omitempty"`
+}
+
+// StringNullableFilter String nullable filter
+//
+// This interface doesn't have a description.
+type StringNullableFilter struct {
+	// Equals Equals
+	Equals teo.Value `json:"equals,omitempty"`
+	// In In
+	In []teo.Value `json:"in,omitempty"`
+	// NotIn Not in
+	NotIn []teo.Value `json:"notIn,omitempty"`
+	// Lt Lt
+	Lt *string `json:"lt,omitempty"`
+	// Lte Lte
+	Lte *string `json:"lte,omitempty"`
+	// Gt Gt
+	Gt *string `json:"gt,omitempty"`
+	// Gte Gte
+	Gte *string `json:"gte,omitempty"`
+	// Contains Contains
+	Contains *string `json:"contains,omitempty"`
+	// StartsWith Starts with
+	StartsWith *string `json:"startsWith,omitempty"`
+	// EndsWith Ends with
+	EndsWith *string `json:"endsWith,omitempty"`
+	// Matches Matches
+	Matches *string `json:"matches,omitempty"`
+	// Mode Mode
+	Mode *StringMatchMode `json:"mode,omitempty"`
+	// Not Not
+	Not teo.Value `json:"not,omitempty"`
+}
+
+// StringNullableWithAggregatesFilter String nullable with aggregates filter
+//
+// This interface doesn't have a description.
+type StringNullableWithAggregatesFilter struct {
+	StringNullableFilter
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *StringNullableFilter `json:"_min,omitempty"`
+	// Max Max
+	Max *StringNullableFilter `json:"_max,omitempty"`
+}
+
+// StringWithAggregatesFilter String with aggregates filter
+//
+// This interface doesn't have a description.
+type StringWithAggregatesFilter struct {
+	StringFilter
+	// Count Count
+	Count *int64 `json:"_count,omitempty"`
+	// Min Min
+	Min *StringFilter `json:"_min,omitempty"`
+	// Max Max
+	Max *StringFilter `json:"_max,omitempty"`
+}
\ No newline at end of file
@@ -0,0 +1,226 @@
+
+"use strict"
+
+const objectClasses = {}
+
+function wrapObject(path, object) {
+    if (object === null || object === undefined) {
+        return object
+    }
+    return new objectClasses[path](object)
+}
+
+function unwrapObject(object) {
+    if (object !== null && object !== undefined && object.__object !== undefined) {
+        return object.__object
+    }
+    return object
+}
+
+
+class AccountModel {
+    constructor(ctx) {
+        this.__ctx = ctx
+    }
+    async findManyObjects(query) {
+        return (await this.__ctx.findManyObjects(query)).map((o) => wrapObject("Account", o))
+    }
+    async findUniqueObject(query) {
+        return wrapObject("Account", await this.__ctx.findUniqueObject(query))
+    }
+    async findFirstObject(query) {
+        return wrapObject("Account", await this.__ctx.findFirstObject(query))
+    }
+    async createObject(input) {
+        return wrapObject("Account", await this.__ctx.createObject(input))
+    }
+    count(input) {
+        return this.__ctx.count(input)
+    }
+    aggregate(input) {
+        return this.__ctx.aggregate(input)
+    }
+    groupBy(input) {
+        return this.__ctx.groupBy(input)
+    }
+    sql(sql) {
+        return this.__ctx.sql(sql)
+    }
+}
+
+class Account {
+    constructor(object) {
+        this.__object = object
+    }
+    get isNew() {
+        return this.__object.isNew
+    }
+    get isModified() {
+        return this.__object.isModified
+    }
+    set(input) {
+        return this.__object.set(input)
+    }
+    update(input) {
+        return this.__object.update(input)
+    }
+    save() {
+        return this.__object.save()
+    }
+    delete() {
+        return this.__object.delete()
+    }
+    toTeon() {
+        return this.__object.toTeon()
+    }
+    get id() {
+        return this.__object.id
+    }
+    set id(newValue) {
+        this.__object.id = newValue
+    }
+    get name() {
+        return this.__object.name
+    }
+    set name(newValue) {
+        this.__object.name = newValue
+    }
+    get role() {
+        return this.__object.role
+    }
+    set role(newValue) {
+        this.__object.role = newValue
+    }
+}
+
+objectClasses["Account"] = Account
+
+const admin = (() => {
+
+
+    class AuditModel {
+        constructor(ctx) {
+            this.__ctx = ctx
+        }
+        async findManyObjects(query) {
+            return (await this.__ctx.findManyObjects(query)).map((o) => wrapObject("admin.Audit", o))
+        }
+        async findUniqueObject(query) {
+            return wrapObject("admin.Audit", await this.__ctx.findUniqueObject(query))
+        }
+        async findFirstObject(query) {
+            return wrapObject("admin.Audit", await this.__ctx.findFirstObject(query))
+        }
+        async createObject(input) {
+            return wrapObject("admin.Audit", await this.__ctx.createObject(input))
+        }
+        count(input) {
+            return this.__ctx.count(input)
+        }
+        aggregate(input) {
+            return this.__ctx.aggregate(input)
+        }
+        groupBy(input) {
+            return this.__ctx.groupBy(input)
+        }
+        sql(sql) {
+            return this.__ctx.sql(sql)
+        }
+    }
+
+    class Audit {
+        constructor(object) {
+            this.__object = object
+        }
+        get isNew() {
+            return this.__object.isNew
+        }
+        get isModified() {
+            return this.__object.isModified
+        }
+        set(input) {
+            return this.__object.set(input)
+        }
+        update(input) {
+            return this.__object.update(input)
+        }
+        save() {
+            return this.__object.save()
+        }
+        delete() {
+            return this.__object.delete()
+        }
+        toTeon() {
+            return this.__object.toTeon()
+        }
+        get id() {
+            return this.__object.id
+        }
+        set id(newValue) {
+            this.__object.id = newValue
+        }
+        get action() {
+            return this.__object.action
+        }
+        set action(newValue) {
+            this.__object.action = newValue
+        }
+        get role() {
+            return this.__object.role
+        }
+        set role(newValue) {
+            this.__object.role = newValue
+        }
+        get createdAt() {
+            return this.__object.createdAt
+        }
+        set createdAt(newValue) {
+            this.__object.createdAt = newValue
+        }
+    }
+
+    objectClasses["admin.Audit"] = Audit
+
+    const reports = (() => {
+
+
+        class ReportsNamespace {
+            constructor(namespace) {
+                this.__inner = namespace
+            }
+        }
+
+        return { ReportsNamespace }
+    })()
+
+    class AdminNamespace {
+        constructor(namespace) {
+            this.__inner = namespace
+        }
+        get audit() {
+            return new AuditModel(this.__inner.audit)
+        }
+        get reports() {
+            return new reports.ReportsNamespace(this.__inner.reports)
+        }
+    }
+
+    return { AuditModel, Audit, reports, AdminNamespace }
+})()
+
+class Teo {
+    constructor(teo) {
+        this.__inner = teo
+    }
+    transaction(callback) {
+        return this.__inner.transaction((teo) => callback(new Teo(teo)))
+    }
+    get account() {
+        return new AccountModel(this.__inner.account)
+    }
+    get admin() {
+        return new admin.AdminNamespace(this.__inner.admin)
+    }
+}
+
+module.exports = { AccountModel, Account, admin, Teo }
\ No newline at end of file
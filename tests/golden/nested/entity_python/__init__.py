@@ -0,0 +1,1661 @@
+# type: ignore
+from __future__ import annotations
+from typing import Any, Optional, Literal, Generic, TypeVar, NotRequired, TypedDict, cast, TYPE_CHECKING
+from re import Pattern
+from datetime import date, datetime
+from decimal import Decimal
+from teo import ObjectId, Enumerable, File, Range, OptionVariant
+from teo.annotations import TeoAnnotationMark, ModelObjectAnnotationMark
+
+from . import admin
+from .admin import AdminNamespace
+
+from . import std
+
+
+AccountDirectRelations = Literal[None]
+
+AccountIndirectRelations = Literal[None]
+
+AccountRelations = Literal[None]
+
+AccountScalarFields = Literal["id", "name", "role"]
+
+AccountSerializableScalarFields = Literal["id", "name", "role"]
+
+
+
+# **Account aggregate args**
+#
+# This synthesized interface doesn't have a description
+class AccountAggregateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[AccountAvgAggregateInputType]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[AccountCountAggregateInputType]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[AccountMaxAggregateInputType]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[AccountMinAggregateInputType]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[AccountSumAggregateInputType]]",
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[AccountWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[AccountSerializableScalarFields]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[AccountOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[AccountWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Account aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AccountAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[AccountAvgAggregateResult]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[AccountCountAggregateResult]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[AccountMaxAggregateResult]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[AccountMinAggregateResult]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[AccountSumAggregateResult]]",
+        
+    },
+)):
+    pass
+
+# **Account args**
+#
+# This synthesized interface doesn't have a description
+class AccountArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+    },
+)):
+    pass
+
+# **Account avg aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class AccountAvgAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Account avg aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AccountAvgAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[float]]",
+        
+    },
+)):
+    pass
+
+# **Account connect or create input**
+#
+# This synthesized interface doesn't have a description
+class AccountConnectOrCreateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "AccountCreateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Account copy args**
+#
+# This synthesized interface doesn't have a description
+class AccountCopyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Copy**
+        #
+        # This synthesized field doesn't have a description.
+        "copy": "AccountUpdateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Account copy many args**
+#
+# This synthesized interface doesn't have a description
+class AccountCopyManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Copy**
+        #
+        # This synthesized field doesn't have a description.
+        "copy": "AccountUpdateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereInput",
+        
+    },
+)):
+    pass
+
+# **Account count aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class AccountCountAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **All**
+        #
+        # This synthesized field doesn't have a description.
+        "_all": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[bool]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Account count aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AccountCountAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **All**
+        #
+        # This synthesized field doesn't have a description.
+        "_all": "NotRequired[Optional[int]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[int]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Account count args**
+#
+# This synthesized interface doesn't have a description
+class AccountCountArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[AccountWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[AccountSerializableScalarFields]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[AccountOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountCountAggregateInputType]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[AccountWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Account create args**
+#
+# This synthesized interface doesn't have a description
+class AccountCreateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "AccountCreateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+    },
+)):
+    pass
+
+# **Account create input**
+#
+# This synthesized interface doesn't have a description
+class AccountCreateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "str",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "admin.Role",
+        
+    },
+)):
+    pass
+
+# **Account create many args**
+#
+# This synthesized interface doesn't have a description
+class AccountCreateManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "Enumerable[AccountCreateInput]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+    },
+)):
+    pass
+
+# **Account create nested many input**
+#
+# This synthesized interface doesn't have a description
+class AccountCreateNestedManyInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[AccountWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[AccountConnectOrCreateInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[AccountCreateInput]]]",
+        
+    },
+)):
+    pass
+
+# **Account create nested one input**
+#
+# This synthesized interface doesn't have a description
+class AccountCreateNestedOneInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[AccountWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[AccountConnectOrCreateInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[AccountCreateInput]]",
+        
+    },
+)):
+    pass
+
+# **Account delete args**
+#
+# This synthesized interface doesn't have a description
+class AccountDeleteArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Account delete many args**
+#
+# This synthesized interface doesn't have a description
+class AccountDeleteManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereInput",
+        
+    },
+)):
+    pass
+
+# **Account find first args**
+#
+# This synthesized interface doesn't have a description
+class AccountFindFirstArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[AccountWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[AccountSerializableScalarFields]]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[AccountOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[AccountWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Account find many args**
+#
+# This synthesized interface doesn't have a description
+class AccountFindManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[AccountWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[AccountSerializableScalarFields]]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[AccountOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[AccountWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Account find unique args**
+#
+# This synthesized interface doesn't have a description
+class AccountFindUniqueArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Account group by args**
+#
+# This synthesized interface doesn't have a description
+class AccountGroupByArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[AccountAvgAggregateInputType]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[AccountCountAggregateInputType]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[AccountMaxAggregateInputType]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[AccountMinAggregateInputType]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[AccountSumAggregateInputType]]",
+        
+        # **By**
+        #
+        # This synthesized field doesn't have a description.
+        "by": "Enumerable[AccountSerializableScalarFields]",
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[AccountWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[AccountSerializableScalarFields]]",
+        
+        # **Having**
+        #
+        # This synthesized field doesn't have a description.
+        "having": "NotRequired[Optional[AccountScalarWhereWithAggregatesInput]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[AccountOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[AccountWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Account group by result**
+#
+# This synthesized interface doesn't have a description
+class AccountGroupByResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[AccountAvgAggregateResult]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[AccountCountAggregateResult]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[AccountMaxAggregateResult]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[AccountMinAggregateResult]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[AccountSumAggregateResult]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[admin.Role]]",
+        
+    },
+)):
+    pass
+
+# **Account include**
+#
+# This synthesized interface doesn't have a description
+class AccountInclude(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Account list relation filter**
+#
+# This synthesized interface doesn't have a description
+class AccountListRelationFilter(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Every**
+        #
+        # This synthesized field doesn't have a description.
+        "every": "NotRequired[Optional[AccountWhereInput]]",
+        
+        # **None**
+        #
+        # This synthesized field doesn't have a description.
+        "none": "NotRequired[Optional[AccountWhereInput]]",
+        
+        # **Some**
+        #
+        # This synthesized field doesn't have a description.
+        "some": "NotRequired[Optional[AccountWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Account max aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class AccountMaxAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[bool]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Account max aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AccountMaxAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[admin.Role]]",
+        
+    },
+)):
+    pass
+
+# **Account min aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class AccountMinAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[bool]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Account min aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AccountMinAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[admin.Role]]",
+        
+    },
+)):
+    pass
+
+# **Account order by input**
+#
+# This synthesized interface doesn't have a description
+class AccountOrderByInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[std.Sort]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[std.Sort]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[std.Sort]]",
+        
+    },
+)):
+    pass
+
+# **Account relation filter**
+#
+# This synthesized interface doesn't have a description
+class AccountRelationFilter(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Is**
+        #
+        # This synthesized field doesn't have a description.
+        "is": "NotRequired[Optional[AccountWhereInput]]",
+        
+        # **Is Not**
+        #
+        # This synthesized field doesn't have a description.
+        "isNot": "NotRequired[Optional[AccountWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Account result**
+#
+# This synthesized interface doesn't have a description
+class AccountResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "int",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "str",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "admin.Role",
+        
+    },
+)):
+    pass
+
+# **Account scalar update input**
+#
+# This synthesized interface doesn't have a description
+class AccountScalarUpdateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[admin.Role]]",
+        
+    },
+)):
+    pass
+
+# **Account scalar where with aggregates input**
+#
+# This synthesized interface doesn't have a description
+class AccountScalarWhereWithAggregatesInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **And**
+        #
+        # This synthesized field doesn't have a description.
+        "AND": "NotRequired[Optional[list[AccountWhereInput]]]",
+        
+        # **Not**
+        #
+        # This synthesized field doesn't have a description.
+        "NOT": "NotRequired[Optional[AccountWhereInput]]",
+        
+        # **Or**
+        #
+        # This synthesized field doesn't have a description.
+        "OR": "NotRequired[Optional[list[AccountWhereInput]]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int | std.IntNumberWithAggregatesFilter[int]]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str | std.StringWithAggregatesFilter]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[admin.Role | std.EnumWithAggregatesFilter[admin.Role]]]",
+        
+    },
+)):
+    pass
+
+# **Account select**
+#
+# This synthesized interface doesn't have a description
+class AccountSelect(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[bool]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Account sign in args**
+#
+# This synthesized interface doesn't have a description
+class AccountSignInArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Account sign in checker companions**
+#
+# This synthesized interface doesn't have a description
+class AccountSignInCheckerCompanions(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Account sign in checker ids**
+#
+# This synthesized interface doesn't have a description
+class AccountSignInCheckerIds(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Account sign in input**
+#
+# This synthesized interface doesn't have a description
+class AccountSignInInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Credentials**
+        #
+        # This synthesized field doesn't have a description.
+        "credentials": "AccountSignInArgs",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+    },
+)):
+    pass
+
+# **Account sum aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class AccountSumAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Account sum aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AccountSumAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Account update args**
+#
+# This synthesized interface doesn't have a description
+class AccountUpdateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AccountUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Account update input**
+#
+# This synthesized interface doesn't have a description
+class AccountUpdateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[admin.Role]]",
+        
+    },
+)):
+    pass
+
+# **Account update many args**
+#
+# This synthesized interface doesn't have a description
+class AccountUpdateManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AccountUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereInput",
+        
+    },
+)):
+    pass
+
+# **Account update many with where input**
+#
+# This synthesized interface doesn't have a description
+class AccountUpdateManyWithWhereInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AccountUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereInput",
+        
+    },
+)):
+    pass
+
+# **Account update nested many input**
+#
+# This synthesized interface doesn't have a description
+class AccountUpdateNestedManyInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[AccountWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[AccountConnectOrCreateInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[AccountCreateInput]]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[Enumerable[AccountWhereUniqueInput]]]",
+        
+        # **Delete Many**
+        #
+        # This synthesized field doesn't have a description.
+        "deleteMany": "NotRequired[Optional[Enumerable[AccountWhereInput]]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[Enumerable[AccountWhereUniqueInput]]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[Enumerable[AccountWhereUniqueInput]]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[Enumerable[AccountUpdateWithWhereUniqueInput]]]",
+        
+        # **Update Many**
+        #
+        # This synthesized field doesn't have a description.
+        "updateMany": "NotRequired[Optional[Enumerable[AccountUpdateManyWithWhereInput]]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[Enumerable[AccountUpsertWithWhereUniqueInput]]]",
+        
+    },
+)):
+    pass
+
+# **Account update nested one input**
+#
+# This synthesized interface doesn't have a description
+class AccountUpdateNestedOneInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[AccountWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[AccountConnectOrCreateInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[AccountCreateInput]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[bool]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[bool]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[AccountWhereUniqueInput]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[AccountUpdateInput]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[AccountUpsertWithWhereUniqueInput]]",
+        
+    },
+)):
+    pass
+
+# **Account update with where unique input**
+#
+# This synthesized interface doesn't have a description
+class AccountUpdateWithWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AccountUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Account upsert args**
+#
+# This synthesized interface doesn't have a description
+class AccountUpsertArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "AccountCreateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AccountInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AccountSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AccountUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Account upsert with where unique input**
+#
+# This synthesized interface doesn't have a description
+class AccountUpsertWithWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "AccountCreateInput",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AccountUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AccountWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Account where input**
+#
+# This synthesized interface doesn't have a description
+class AccountWhereInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **And**
+        #
+        # This synthesized field doesn't have a description.
+        "AND": "NotRequired[Optional[list[AccountWhereInput]]]",
+        
+        # **Not**
+        #
+        # This synthesized field doesn't have a description.
+        "NOT": "NotRequired[Optional[AccountWhereInput]]",
+        
+        # **Or**
+        #
+        # This synthesized field doesn't have a description.
+        "OR": "NotRequired[Optional[list[AccountWhereInput]]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int | std.Filter[int]]]",
+        
+        # **Name**
+        #
+        # This synthesized field doesn't have a description.
+        "name": "NotRequired[Optional[str | std.StringFilter]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[admin.Role | std.EnumFilter[admin.Role]]]",
+        
+    },
+)):
+    pass
+
+# **Account where unique input**
+#
+# This synthesized interface doesn't have a description
+class AccountWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "int",
+        
+    },
+)):
+    pass
+
+
+
+class AccountModel:
+    async def find_many_objects(self, query: AccountFindManyArgs, /) -> list[Account]:
+        return cast(Any, None)
+    async def find_unique_object(self, query: AccountFindUniqueArgs, /) -> Optional[Account]:
+        return cast(Any, None)
+    async def find_first_object(self, query: AccountFindFirstArgs, /) -> Optional[Account]:
+        return cast(Any, None)
+    async def create_object(self, input: AccountCreateInput, /) -> Account:
+        return cast(Any, None)
+    async def count_objects(self, query: AccountCountArgs, /) -> int:
+        return cast(Any, None)
+    async def count_fields(self, query: AccountCountArgs, /) -> AccountCountAggregateResult:
+        return cast(Any, None)
+    async def aggregate(self, query: AccountAggregateArgs, /) -> AccountAggregateResult:
+        return cast(Any, None)
+    async def group_by(self, query: AccountGroupByArgs, /) -> list[AccountAggregateResult]:
+        return cast(Any, None)
+    
+    async def sql(self, sql: str) -> list[Any]:
+        return cast(Any, None)
+    
+class Account(ModelObjectAnnotationMark):
+    def is_new(self) -> bool:
+        return cast(Any, None)
+    def is_modified(self) -> bool:
+        return cast(Any, None)
+    async def set(self, input: AccountUpdateInput, /) -> None:
+        return cast(Any, None)
+    async def update(self, input: AccountScalarUpdateInput, /) -> None:
+        return cast(Any, None)
+    async def save(self) -> None:
+        return cast(Any, None)
+    async def delete(self) -> None:
+        return cast(Any, None)
+    async def to_teon(self) -> AccountResult:
+        return cast(Any, None)
+    id: int
+    name: str
+    role: admin.Role
+
+
+
+class Teo(TeoAnnotationMark):
+
+    
+    async def transaction[T](self, teo: Teo, /) -> T:
+        return cast(Any, None)
+    
+    account: AccountModel
+    admin: AdminNamespace
\ No newline at end of file
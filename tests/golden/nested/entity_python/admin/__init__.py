@@ -0,0 +1,1730 @@
+# type: ignore
+from __future__ import annotations
+from typing import Any, Optional, Literal, Generic, TypeVar, NotRequired, TypedDict, cast, TYPE_CHECKING
+from re import Pattern
+from datetime import date, datetime
+from decimal import Decimal
+from teo import ObjectId, Enumerable, File, Range, OptionVariant
+from teo.annotations import TeoAnnotationMark, ModelObjectAnnotationMark
+
+
+if TYPE_CHECKING:
+    from . import generated
+
+
+from . import reports
+from .reports import ReportsNamespace
+
+
+
+Role = Literal["owner", "editor", "viewer"]
+
+AuditDirectRelations = Literal[None]
+
+AuditIndirectRelations = Literal[None]
+
+AuditRelations = Literal[None]
+
+AuditScalarFields = Literal["action", "createdAt", "id", "role"]
+
+AuditSerializableScalarFields = Literal["action", "createdAt", "id", "role"]
+
+
+
+# **Audit aggregate args**
+#
+# This synthesized interface doesn't have a description
+class AuditAggregateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[AuditAvgAggregateInputType]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[AuditCountAggregateInputType]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[AuditMaxAggregateInputType]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[AuditMinAggregateInputType]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[AuditSumAggregateInputType]]",
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[AuditWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[AuditSerializableScalarFields]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[AuditOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[AuditWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Audit aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AuditAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[AuditAvgAggregateResult]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[AuditCountAggregateResult]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[AuditMaxAggregateResult]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[AuditMinAggregateResult]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[AuditSumAggregateResult]]",
+        
+    },
+)):
+    pass
+
+# **Audit args**
+#
+# This synthesized interface doesn't have a description
+class AuditArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+    },
+)):
+    pass
+
+# **Audit avg aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class AuditAvgAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Audit avg aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AuditAvgAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[float]]",
+        
+    },
+)):
+    pass
+
+# **Audit connect or create input**
+#
+# This synthesized interface doesn't have a description
+class AuditConnectOrCreateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "AuditCreateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Audit copy args**
+#
+# This synthesized interface doesn't have a description
+class AuditCopyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Copy**
+        #
+        # This synthesized field doesn't have a description.
+        "copy": "AuditUpdateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Audit copy many args**
+#
+# This synthesized interface doesn't have a description
+class AuditCopyManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Copy**
+        #
+        # This synthesized field doesn't have a description.
+        "copy": "AuditUpdateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereInput",
+        
+    },
+)):
+    pass
+
+# **Audit count aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class AuditCountAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **All**
+        #
+        # This synthesized field doesn't have a description.
+        "_all": "NotRequired[Optional[bool]]",
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[bool]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Audit count aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AuditCountAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **All**
+        #
+        # This synthesized field doesn't have a description.
+        "_all": "NotRequired[Optional[int]]",
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[int]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[int]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Audit count args**
+#
+# This synthesized interface doesn't have a description
+class AuditCountArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[AuditWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[AuditSerializableScalarFields]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[AuditOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditCountAggregateInputType]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[AuditWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Audit create args**
+#
+# This synthesized interface doesn't have a description
+class AuditCreateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "AuditCreateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+    },
+)):
+    pass
+
+# **Audit create input**
+#
+# This synthesized interface doesn't have a description
+class AuditCreateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "str",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "Role",
+        
+    },
+)):
+    pass
+
+# **Audit create many args**
+#
+# This synthesized interface doesn't have a description
+class AuditCreateManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "Enumerable[AuditCreateInput]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+    },
+)):
+    pass
+
+# **Audit create nested many input**
+#
+# This synthesized interface doesn't have a description
+class AuditCreateNestedManyInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[AuditWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[AuditConnectOrCreateInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[AuditCreateInput]]]",
+        
+    },
+)):
+    pass
+
+# **Audit create nested one input**
+#
+# This synthesized interface doesn't have a description
+class AuditCreateNestedOneInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[AuditWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[AuditConnectOrCreateInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[AuditCreateInput]]",
+        
+    },
+)):
+    pass
+
+# **Audit delete args**
+#
+# This synthesized interface doesn't have a description
+class AuditDeleteArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Audit delete many args**
+#
+# This synthesized interface doesn't have a description
+class AuditDeleteManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereInput",
+        
+    },
+)):
+    pass
+
+# **Audit find first args**
+#
+# This synthesized interface doesn't have a description
+class AuditFindFirstArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[AuditWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[AuditSerializableScalarFields]]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[AuditOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[AuditWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Audit find many args**
+#
+# This synthesized interface doesn't have a description
+class AuditFindManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[AuditWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[AuditSerializableScalarFields]]",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[AuditOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[AuditWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Audit find unique args**
+#
+# This synthesized interface doesn't have a description
+class AuditFindUniqueArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Audit group by args**
+#
+# This synthesized interface doesn't have a description
+class AuditGroupByArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[AuditAvgAggregateInputType]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[AuditCountAggregateInputType]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[AuditMaxAggregateInputType]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[AuditMinAggregateInputType]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[AuditSumAggregateInputType]]",
+        
+        # **By**
+        #
+        # This synthesized field doesn't have a description.
+        "by": "Enumerable[AuditSerializableScalarFields]",
+        
+        # **Cursor**
+        #
+        # This synthesized field doesn't have a description.
+        "cursor": "NotRequired[Optional[AuditWhereUniqueInput]]",
+        
+        # **Distinct**
+        #
+        # This synthesized field doesn't have a description.
+        "distinct": "NotRequired[Optional[AuditSerializableScalarFields]]",
+        
+        # **Having**
+        #
+        # This synthesized field doesn't have a description.
+        "having": "NotRequired[Optional[AuditScalarWhereWithAggregatesInput]]",
+        
+        # **Order By**
+        #
+        # This synthesized field doesn't have a description.
+        "orderBy": "NotRequired[Optional[Enumerable[AuditOrderByInput]]]",
+        
+        # **Page Number**
+        #
+        # This synthesized field doesn't have a description.
+        "pageNumber": "NotRequired[Optional[int]]",
+        
+        # **Page Size**
+        #
+        # This synthesized field doesn't have a description.
+        "pageSize": "NotRequired[Optional[int]]",
+        
+        # **Skip**
+        #
+        # This synthesized field doesn't have a description.
+        "skip": "NotRequired[Optional[int]]",
+        
+        # **Take**
+        #
+        # This synthesized field doesn't have a description.
+        "take": "NotRequired[Optional[int]]",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "NotRequired[Optional[AuditWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Audit group by result**
+#
+# This synthesized interface doesn't have a description
+class AuditGroupByResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Avg**
+        #
+        # This synthesized field doesn't have a description.
+        "_avg": "NotRequired[Optional[AuditAvgAggregateResult]]",
+        
+        # **Count**
+        #
+        # This synthesized field doesn't have a description.
+        "_count": "NotRequired[Optional[AuditCountAggregateResult]]",
+        
+        # **Max**
+        #
+        # This synthesized field doesn't have a description.
+        "_max": "NotRequired[Optional[AuditMaxAggregateResult]]",
+        
+        # **Min**
+        #
+        # This synthesized field doesn't have a description.
+        "_min": "NotRequired[Optional[AuditMinAggregateResult]]",
+        
+        # **Sum**
+        #
+        # This synthesized field doesn't have a description.
+        "_sum": "NotRequired[Optional[AuditSumAggregateResult]]",
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[str]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[Role]]",
+        
+    },
+)):
+    pass
+
+# **Audit include**
+#
+# This synthesized interface doesn't have a description
+class AuditInclude(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Audit list relation filter**
+#
+# This synthesized interface doesn't have a description
+class AuditListRelationFilter(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Every**
+        #
+        # This synthesized field doesn't have a description.
+        "every": "NotRequired[Optional[AuditWhereInput]]",
+        
+        # **None**
+        #
+        # This synthesized field doesn't have a description.
+        "none": "NotRequired[Optional[AuditWhereInput]]",
+        
+        # **Some**
+        #
+        # This synthesized field doesn't have a description.
+        "some": "NotRequired[Optional[AuditWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Audit max aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class AuditMaxAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[bool]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Audit max aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AuditMaxAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[str]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[Role]]",
+        
+    },
+)):
+    pass
+
+# **Audit min aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class AuditMinAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[bool]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Audit min aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AuditMinAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[str]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[Role]]",
+        
+    },
+)):
+    pass
+
+# **Audit order by input**
+#
+# This synthesized interface doesn't have a description
+class AuditOrderByInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[generated.std.Sort]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[generated.std.Sort]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[generated.std.Sort]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[generated.std.Sort]]",
+        
+    },
+)):
+    pass
+
+# **Audit relation filter**
+#
+# This synthesized interface doesn't have a description
+class AuditRelationFilter(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Is**
+        #
+        # This synthesized field doesn't have a description.
+        "is": "NotRequired[Optional[AuditWhereInput]]",
+        
+        # **Is Not**
+        #
+        # This synthesized field doesn't have a description.
+        "isNot": "NotRequired[Optional[AuditWhereInput]]",
+        
+    },
+)):
+    pass
+
+# **Audit result**
+#
+# This synthesized interface doesn't have a description
+class AuditResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "str",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "datetime",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "int",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "Role",
+        
+    },
+)):
+    pass
+
+# **Audit scalar update input**
+#
+# This synthesized interface doesn't have a description
+class AuditScalarUpdateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[str]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[Role]]",
+        
+    },
+)):
+    pass
+
+# **Audit scalar where with aggregates input**
+#
+# This synthesized interface doesn't have a description
+class AuditScalarWhereWithAggregatesInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **And**
+        #
+        # This synthesized field doesn't have a description.
+        "AND": "NotRequired[Optional[list[AuditWhereInput]]]",
+        
+        # **Not**
+        #
+        # This synthesized field doesn't have a description.
+        "NOT": "NotRequired[Optional[AuditWhereInput]]",
+        
+        # **Or**
+        #
+        # This synthesized field doesn't have a description.
+        "OR": "NotRequired[Optional[list[AuditWhereInput]]]",
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[str | generated.std.StringWithAggregatesFilter]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime | generated.std.AggregatesFilter[datetime]]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int | generated.std.IntNumberWithAggregatesFilter[int]]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[Role | generated.std.EnumWithAggregatesFilter[Role]]]",
+        
+    },
+)):
+    pass
+
+# **Audit select**
+#
+# This synthesized interface doesn't have a description
+class AuditSelect(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[bool]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[bool]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Audit sign in args**
+#
+# This synthesized interface doesn't have a description
+class AuditSignInArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Audit sign in checker companions**
+#
+# This synthesized interface doesn't have a description
+class AuditSignInCheckerCompanions(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Audit sign in checker ids**
+#
+# This synthesized interface doesn't have a description
+class AuditSignInCheckerIds(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+    },
+)):
+    pass
+
+# **Audit sign in input**
+#
+# This synthesized interface doesn't have a description
+class AuditSignInInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Credentials**
+        #
+        # This synthesized field doesn't have a description.
+        "credentials": "AuditSignInArgs",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+    },
+)):
+    pass
+
+# **Audit sum aggregate input type**
+#
+# This synthesized interface doesn't have a description
+class AuditSumAggregateInputType(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[bool]]",
+        
+    },
+)):
+    pass
+
+# **Audit sum aggregate result**
+#
+# This synthesized interface doesn't have a description
+class AuditSumAggregateResult(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int]]",
+        
+    },
+)):
+    pass
+
+# **Audit update args**
+#
+# This synthesized interface doesn't have a description
+class AuditUpdateArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AuditUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Audit update input**
+#
+# This synthesized interface doesn't have a description
+class AuditUpdateInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[str]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[Role]]",
+        
+    },
+)):
+    pass
+
+# **Audit update many args**
+#
+# This synthesized interface doesn't have a description
+class AuditUpdateManyArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AuditUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereInput",
+        
+    },
+)):
+    pass
+
+# **Audit update many with where input**
+#
+# This synthesized interface doesn't have a description
+class AuditUpdateManyWithWhereInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AuditUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereInput",
+        
+    },
+)):
+    pass
+
+# **Audit update nested many input**
+#
+# This synthesized interface doesn't have a description
+class AuditUpdateNestedManyInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[Enumerable[AuditWhereUniqueInput]]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[Enumerable[AuditConnectOrCreateInput]]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[Enumerable[AuditCreateInput]]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[Enumerable[AuditWhereUniqueInput]]]",
+        
+        # **Delete Many**
+        #
+        # This synthesized field doesn't have a description.
+        "deleteMany": "NotRequired[Optional[Enumerable[AuditWhereInput]]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[Enumerable[AuditWhereUniqueInput]]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[Enumerable[AuditWhereUniqueInput]]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[Enumerable[AuditUpdateWithWhereUniqueInput]]]",
+        
+        # **Update Many**
+        #
+        # This synthesized field doesn't have a description.
+        "updateMany": "NotRequired[Optional[Enumerable[AuditUpdateManyWithWhereInput]]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[Enumerable[AuditUpsertWithWhereUniqueInput]]]",
+        
+    },
+)):
+    pass
+
+# **Audit update nested one input**
+#
+# This synthesized interface doesn't have a description
+class AuditUpdateNestedOneInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Connect**
+        #
+        # This synthesized field doesn't have a description.
+        "connect": "NotRequired[Optional[AuditWhereUniqueInput]]",
+        
+        # **Connect Or Create**
+        #
+        # This synthesized field doesn't have a description.
+        "connectOrCreate": "NotRequired[Optional[AuditConnectOrCreateInput]]",
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "NotRequired[Optional[AuditCreateInput]]",
+        
+        # **Delete**
+        #
+        # This synthesized field doesn't have a description.
+        "delete": "NotRequired[Optional[bool]]",
+        
+        # **Disconnect**
+        #
+        # This synthesized field doesn't have a description.
+        "disconnect": "NotRequired[Optional[bool]]",
+        
+        # **Set**
+        #
+        # This synthesized field doesn't have a description.
+        "set": "NotRequired[Optional[AuditWhereUniqueInput]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "NotRequired[Optional[AuditUpdateInput]]",
+        
+        # **Upsert**
+        #
+        # This synthesized field doesn't have a description.
+        "upsert": "NotRequired[Optional[AuditUpsertWithWhereUniqueInput]]",
+        
+    },
+)):
+    pass
+
+# **Audit update with where unique input**
+#
+# This synthesized interface doesn't have a description
+class AuditUpdateWithWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AuditUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Audit upsert args**
+#
+# This synthesized interface doesn't have a description
+class AuditUpsertArgs(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "AuditCreateInput",
+        
+        # **Include**
+        #
+        # This synthesized field doesn't have a description.
+        "include": "NotRequired[Optional[AuditInclude]]",
+        
+        # **Select**
+        #
+        # This synthesized field doesn't have a description.
+        "select": "NotRequired[Optional[AuditSelect]]",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AuditUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Audit upsert with where unique input**
+#
+# This synthesized interface doesn't have a description
+class AuditUpsertWithWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Create**
+        #
+        # This synthesized field doesn't have a description.
+        "create": "AuditCreateInput",
+        
+        # **Update**
+        #
+        # This synthesized field doesn't have a description.
+        "update": "AuditUpdateInput",
+        
+        # **Where**
+        #
+        # This synthesized field doesn't have a description.
+        "where": "AuditWhereUniqueInput",
+        
+    },
+)):
+    pass
+
+# **Audit where input**
+#
+# This synthesized interface doesn't have a description
+class AuditWhereInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **And**
+        #
+        # This synthesized field doesn't have a description.
+        "AND": "NotRequired[Optional[list[AuditWhereInput]]]",
+        
+        # **Not**
+        #
+        # This synthesized field doesn't have a description.
+        "NOT": "NotRequired[Optional[AuditWhereInput]]",
+        
+        # **Or**
+        #
+        # This synthesized field doesn't have a description.
+        "OR": "NotRequired[Optional[list[AuditWhereInput]]]",
+        
+        # **Action**
+        #
+        # This synthesized field doesn't have a description.
+        "action": "NotRequired[Optional[str | generated.std.StringFilter]]",
+        
+        # **Created At**
+        #
+        # This synthesized field doesn't have a description.
+        "createdAt": "NotRequired[Optional[datetime | generated.std.Filter[datetime]]]",
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "NotRequired[Optional[int | generated.std.Filter[int]]]",
+        
+        # **Role**
+        #
+        # This synthesized field doesn't have a description.
+        "role": "NotRequired[Optional[Role | generated.std.EnumFilter[Role]]]",
+        
+    },
+)):
+    pass
+
+# **Audit where unique input**
+#
+# This synthesized interface doesn't have a description
+class AuditWhereUniqueInput(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Id**
+        #
+        # This synthesized field doesn't have a description.
+        "id": "int",
+        
+    },
+)):
+    pass
+
+
+
+class AuditModel:
+    async def find_many_objects(self, query: AuditFindManyArgs, /) -> list[Audit]:
+        return cast(Any, None)
+    async def find_unique_object(self, query: AuditFindUniqueArgs, /) -> Optional[Audit]:
+        return cast(Any, None)
+    async def find_first_object(self, query: AuditFindFirstArgs, /) -> Optional[Audit]:
+        return cast(Any, None)
+    async def create_object(self, input: AuditCreateInput, /) -> Audit:
+        return cast(Any, None)
+    async def count_objects(self, query: AuditCountArgs, /) -> int:
+        return cast(Any, None)
+    async def count_fields(self, query: AuditCountArgs, /) -> AuditCountAggregateResult:
+        return cast(Any, None)
+    async def aggregate(self, query: AuditAggregateArgs, /) -> AuditAggregateResult:
+        return cast(Any, None)
+    async def group_by(self, query: AuditGroupByArgs, /) -> list[AuditAggregateResult]:
+        return cast(Any, None)
+    
+    async def sql(self, sql: str) -> list[Any]:
+        return cast(Any, None)
+    
+class Audit(ModelObjectAnnotationMark):
+    def is_new(self) -> bool:
+        return cast(Any, None)
+    def is_modified(self) -> bool:
+        return cast(Any, None)
+    async def set(self, input: AuditUpdateInput, /) -> None:
+        return cast(Any, None)
+    async def update(self, input: AuditScalarUpdateInput, /) -> None:
+        return cast(Any, None)
+    async def save(self) -> None:
+        return cast(Any, None)
+    async def delete(self) -> None:
+        return cast(Any, None)
+    async def to_teon(self) -> AuditResult:
+        return cast(Any, None)
+    id: int
+    action: str
+    role: Role
+    created_at: datetime
+
+
+
+class AdminNamespace:
+
+    
+    audit: AuditModel
+    reports: ReportsNamespace
\ No newline at end of file
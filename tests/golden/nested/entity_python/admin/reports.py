@@ -0,0 +1,86 @@
+# type: ignore
+from __future__ import annotations
+from typing import Any, Optional, Literal, Generic, TypeVar, NotRequired, TypedDict, cast, TYPE_CHECKING
+from re import Pattern
+from datetime import date, datetime
+from decimal import Decimal
+from teo import ObjectId, Enumerable, File, Range, OptionVariant
+from teo.annotations import TeoAnnotationMark, ModelObjectAnnotationMark
+
+
+if TYPE_CHECKING:
+    from .. import generated
+
+
+
+
+
+
+# **Report path arguments**
+#
+# This interface doesn't have a description.
+class ReportPathArguments(TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Year**
+        #
+        # This interface field doesn't have a description.
+        "year": "int",
+        
+        # **Month**
+        #
+        # This interface field doesn't have a description.
+        "month": "int",
+        
+    },
+)):
+    pass
+
+# **Summary**
+#
+# This interface doesn't have a description.
+class Summary[T](TypedDict(
+    "RequestBodyObjectAnnotationMark",
+    {
+        
+        # **Total**
+        #
+        # This interface field doesn't have a description.
+        "total": "int",
+        
+        # **Latest**
+        #
+        # This interface field doesn't have a description.
+        "latest": "NotRequired[Optional[T]]",
+        
+        # **By role**
+        #
+        # This interface field doesn't have a description.
+        "byRole": "list[generated.admin.Role]",
+        
+    },
+)):
+    pass
+
+
+
+
+
+class ReportPathArguments(TypedDict(
+    "CapturesAnnotationMark",
+    {
+        
+            "year": str
+        
+            "month": str
+        
+    }
+)):
+    pass
+
+class ReportsNamespace:
+
+    
+    pass
+    
\ No newline at end of file
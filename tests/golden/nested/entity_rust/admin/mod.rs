@@ -0,0 +1,9796 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+pub mod reports;
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use chrono::{DateTime, Utc};
+use teo::prelude::{
+    teon, model, Model, Value, Result, Error, transaction, Request, ExtractFromRequest, ExtractFromPipelineCtx, request, pipeline, ExtractFromTransactionCtx, File, Arguments,
+};
+use std::marker::PhantomData;
+use super::helpers::interface::{Interface, AsInterface, AsInterfaceRef, AsInterfaceVecRef};
+use super::helpers::query::{self, FieldFilter};
+use super::helpers::relations::{self, RelationCache};
+#[cfg(feature = "teo-serde")]
+use super::helpers::teon_serde::{serialize_teon, deserialize_teon};
+pub use reports::ReportsNamespace;
+
+
+
+/// ## Role
+///
+/// This enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct Role {
+    inner: String,
+}
+
+impl Role {
+    /// ### Is Owner
+    ///
+    /// Returns true if value is owner
+    pub fn is_owner(&self) -> bool {
+        self.inner.as_str() == "owner"
+    }
+    /// ### Owner
+    ///
+    /// This enum member doesn't have a description.
+    pub fn owner() -> Self {
+        Self { inner: "owner".to_owned() }
+    }
+    /// ### Is Editor
+    ///
+    /// Returns true if value is editor
+    pub fn is_editor(&self) -> bool {
+        self.inner.as_str() == "editor"
+    }
+    /// ### Editor
+    ///
+    /// This enum member doesn't have a description.
+    pub fn editor() -> Self {
+        Self { inner: "editor".to_owned() }
+    }
+    /// ### Is Viewer
+    ///
+    /// Returns true if value is viewer
+    pub fn is_viewer(&self) -> bool {
+        self.inner.as_str() == "viewer"
+    }
+    /// ### Viewer
+    ///
+    /// This enum member doesn't have a description.
+    pub fn viewer() -> Self {
+        Self { inner: "viewer".to_owned() }
+    }
+}
+
+impl Debug for Role {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Role(.{})", self.inner)
+    }
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<Role> for Value {
+    fn from(value: Role) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for Role {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "owner" => Role::owner(),
+                "editor" => Role::editor(),
+                "viewer" => Role::viewer(),
+                _ => Err(Error::new("cannot convert value to Role"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to Role"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for Role {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "owner" => Role::owner(),
+                "editor" => Role::editor(),
+                "viewer" => Role::viewer(),
+                _ => Err(Error::new("cannot convert value to Role"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to Role"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &Role {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "owner" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "editor" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "viewer" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &Role"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &Role"))
+        }
+    }
+}
+
+impl AsInterface for Role {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "owner" => Role::owner(),
+                "editor" => Role::editor(),
+                "viewer" => Role::viewer(),
+                _ => Err(Error::new("cannot convert value to Role"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to Role"))
+        }
+    }
+}
+
+impl AsInterfaceRef for Role {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "owner" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "editor" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "viewer" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &Role"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &Role"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for Role {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for Role {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Audit direct relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct AuditDirectRelations {
+    inner: String,
+}
+
+impl AuditDirectRelations {
+}
+
+impl Debug for AuditDirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AuditDirectRelations(.{})", self.inner)
+    }
+}
+
+impl Display for AuditDirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<AuditDirectRelations> for Value {
+    fn from(value: AuditDirectRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for AuditDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AuditDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditDirectRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for AuditDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AuditDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditDirectRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &AuditDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AuditDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AuditDirectRelations"))
+        }
+    }
+}
+
+impl AsInterface for AuditDirectRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AuditDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditDirectRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for AuditDirectRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AuditDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AuditDirectRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditDirectRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditDirectRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Audit indirect relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct AuditIndirectRelations {
+    inner: String,
+}
+
+impl AuditIndirectRelations {
+}
+
+impl Debug for AuditIndirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AuditIndirectRelations(.{})", self.inner)
+    }
+}
+
+impl Display for AuditIndirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<AuditIndirectRelations> for Value {
+    fn from(value: AuditIndirectRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for AuditIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AuditIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditIndirectRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for AuditIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AuditIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditIndirectRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &AuditIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AuditIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AuditIndirectRelations"))
+        }
+    }
+}
+
+impl AsInterface for AuditIndirectRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AuditIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditIndirectRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for AuditIndirectRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AuditIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AuditIndirectRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditIndirectRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditIndirectRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Audit relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct AuditRelations {
+    inner: String,
+}
+
+impl AuditRelations {
+}
+
+impl Debug for AuditRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AuditRelations(.{})", self.inner)
+    }
+}
+
+impl Display for AuditRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<AuditRelations> for Value {
+    fn from(value: AuditRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for AuditRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AuditRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for AuditRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AuditRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &AuditRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AuditRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AuditRelations"))
+        }
+    }
+}
+
+impl AsInterface for AuditRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AuditRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for AuditRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AuditRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AuditRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Audit scalar fields
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct AuditScalarFields {
+    inner: String,
+}
+
+impl AuditScalarFields {
+    /// ### Is Action
+    ///
+    /// Returns true if value is action
+    pub fn is_action(&self) -> bool {
+        self.inner.as_str() == "action"
+    }
+    /// ### Action
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn action() -> Self {
+        Self { inner: "action".to_owned() }
+    }
+    /// ### Is Created at
+    ///
+    /// Returns true if value is created at
+    pub fn is_created_at(&self) -> bool {
+        self.inner.as_str() == "createdAt"
+    }
+    /// ### Created at
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn created_at() -> Self {
+        Self { inner: "createdAt".to_owned() }
+    }
+    /// ### Is Id
+    ///
+    /// Returns true if value is id
+    pub fn is_id(&self) -> bool {
+        self.inner.as_str() == "id"
+    }
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn id() -> Self {
+        Self { inner: "id".to_owned() }
+    }
+    /// ### Is Role
+    ///
+    /// Returns true if value is role
+    pub fn is_role(&self) -> bool {
+        self.inner.as_str() == "role"
+    }
+    /// ### Role
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn role() -> Self {
+        Self { inner: "role".to_owned() }
+    }
+}
+
+impl Debug for AuditScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AuditScalarFields(.{})", self.inner)
+    }
+}
+
+impl Display for AuditScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<AuditScalarFields> for Value {
+    fn from(value: AuditScalarFields) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for AuditScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "action" => AuditScalarFields::action(),
+                "createdAt" => AuditScalarFields::created_at(),
+                "id" => AuditScalarFields::id(),
+                "role" => AuditScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AuditScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditScalarFields"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for AuditScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "action" => AuditScalarFields::action(),
+                "createdAt" => AuditScalarFields::created_at(),
+                "id" => AuditScalarFields::id(),
+                "role" => AuditScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AuditScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditScalarFields"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &AuditScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "action" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "createdAt" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "role" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &AuditScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AuditScalarFields"))
+        }
+    }
+}
+
+impl AsInterface for AuditScalarFields {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "action" => AuditScalarFields::action(),
+                "createdAt" => AuditScalarFields::created_at(),
+                "id" => AuditScalarFields::id(),
+                "role" => AuditScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AuditScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditScalarFields"))
+        }
+    }
+}
+
+impl AsInterfaceRef for AuditScalarFields {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "action" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "createdAt" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "role" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &AuditScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AuditScalarFields"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditScalarFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditScalarFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Audit serializable scalar fields
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct AuditSerializableScalarFields {
+    inner: String,
+}
+
+impl AuditSerializableScalarFields {
+    /// ### Is Action
+    ///
+    /// Returns true if value is action
+    pub fn is_action(&self) -> bool {
+        self.inner.as_str() == "action"
+    }
+    /// ### Action
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn action() -> Self {
+        Self { inner: "action".to_owned() }
+    }
+    /// ### Is Created at
+    ///
+    /// Returns true if value is created at
+    pub fn is_created_at(&self) -> bool {
+        self.inner.as_str() == "createdAt"
+    }
+    /// ### Created at
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn created_at() -> Self {
+        Self { inner: "createdAt".to_owned() }
+    }
+    /// ### Is Id
+    ///
+    /// Returns true if value is id
+    pub fn is_id(&self) -> bool {
+        self.inner.as_str() == "id"
+    }
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn id() -> Self {
+        Self { inner: "id".to_owned() }
+    }
+    /// ### Is Role
+    ///
+    /// Returns true if value is role
+    pub fn is_role(&self) -> bool {
+        self.inner.as_str() == "role"
+    }
+    /// ### Role
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn role() -> Self {
+        Self { inner: "role".to_owned() }
+    }
+}
+
+impl Debug for AuditSerializableScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AuditSerializableScalarFields(.{})", self.inner)
+    }
+}
+
+impl Display for AuditSerializableScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<AuditSerializableScalarFields> for Value {
+    fn from(value: AuditSerializableScalarFields) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for AuditSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "action" => AuditSerializableScalarFields::action(),
+                "createdAt" => AuditSerializableScalarFields::created_at(),
+                "id" => AuditSerializableScalarFields::id(),
+                "role" => AuditSerializableScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AuditSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditSerializableScalarFields"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for AuditSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "action" => AuditSerializableScalarFields::action(),
+                "createdAt" => AuditSerializableScalarFields::created_at(),
+                "id" => AuditSerializableScalarFields::id(),
+                "role" => AuditSerializableScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AuditSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditSerializableScalarFields"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &AuditSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "action" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "createdAt" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "role" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &AuditSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AuditSerializableScalarFields"))
+        }
+    }
+}
+
+impl AsInterface for AuditSerializableScalarFields {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "action" => AuditSerializableScalarFields::action(),
+                "createdAt" => AuditSerializableScalarFields::created_at(),
+                "id" => AuditSerializableScalarFields::id(),
+                "role" => AuditSerializableScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AuditSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AuditSerializableScalarFields"))
+        }
+    }
+}
+
+impl AsInterfaceRef for AuditSerializableScalarFields {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "action" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "createdAt" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "role" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &AuditSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AuditSerializableScalarFields"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditSerializableScalarFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditSerializableScalarFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+
+/// ## Audit
+///
+/// This model doesn't have a description.
+pub struct AuditModel {
+    ctx: model::Ctx,
+}
+
+impl AuditModel {
+    /// Find many audit objects.
+    pub async fn find_many_objects(&self, query: impl Borrow<Value>) -> Result<Vec<Audit>> {
+        Ok(self.ctx.find_many(query.borrow()).await?)
+    }
+
+    /// Find a unique audit object.
+    pub async fn find_unique_object(&self, query: impl Borrow<Value>) -> Result<Option<Audit>> {
+        Ok(self.ctx.find_unique(query.borrow()).await?)
+    }
+
+    /// Find an audit object.
+    pub async fn find_first_object(&self, query: impl Borrow<Value>) -> Result<Option<Audit>> {
+        Ok(self.ctx.find_first(query.borrow()).await?)
+    }
+
+    /// Create a new audit object.
+    pub async fn create_object(&self, values: impl Borrow<Value>) -> Result<Audit> {
+        Ok(self.ctx.create_object::<Audit>(values.borrow()).await?)
+    }
+
+    /// Create an empty audit object.
+    pub async fn create_default_object(&self) -> Result<Audit> {
+        Ok(self.ctx.create_object::<Audit>(teon!({}).borrow()).await?)
+    }
+
+    /// Create and save a audit object from the `create` input of `args`.
+    pub async fn create_object_from_args(&self, args: impl Borrow<Value>) -> Result<Audit> {
+        let values = args.borrow().as_dictionary().and_then(|args| args.get("create")).cloned().unwrap_or_else(|| teon!({}));
+        let object = self.create_object(values).await?;
+        object.save().await?;
+        Ok(object)
+    }
+
+    /// Count objects on audit.
+    pub async fn count_objects(&self, query: impl Borrow<Value>) -> Result<usize> {
+        Ok(self.ctx.count_objects(query.borrow()).await?)
+    }
+
+    /// Count fields on audit.
+    pub async fn count_fields(&self, query: impl Borrow<Value>) -> Result<AuditCountAggregateResult> {
+        Ok(AuditCountAggregateResult::from_value(self.ctx.count_fields(query.borrow()).await?)?)
+    }
+
+    /// Aggregate on audit.
+    pub async fn aggregate(&self, query: impl Borrow<Value>) -> Result<AuditAggregateResult> {
+        Ok(AuditAggregateResult::from_value(self.ctx.aggregate(query.borrow()).await?)?)
+    }
+
+    /// Group by on audit.
+    pub async fn group_by(&self, query: impl Borrow<Value>) -> Result<Vec<AuditAggregateResult>> {
+        let values: Vec<Value> = self.ctx.group_by(query.borrow()).await?;
+        let mut result = vec![];
+        for value in values.into_iter() {
+            result.push(AuditAggregateResult::from_value(value)?);
+        }
+        Ok(result)
+    }
+
+    
+    /// Run a custom SQL clause.
+    pub async fn sql<T, E>(&self, sql: &str) -> Result<Vec<T>> where T: TryFrom<Value, Error=E>, Error: From<E> {
+        self.ctx.sql(sql).await
+    }
+    
+}
+
+#[derive(Clone)]
+pub struct Audit {
+    inner: model::Object,
+    relations: RelationCache,
+}
+
+impl Audit {
+
+    /// Whether this audit is new.
+    pub fn is_new(&self) -> bool {
+        self.inner.is_new()
+    }
+
+    /// Whether this audit is modified.
+    pub fn is_modified(&self) -> bool {
+        self.inner.is_modified()
+    }
+
+    /// Set new values to a audit. Validations and transformations are
+    /// triggered.
+    pub async fn set(&self, values: impl AsRef<Value>) -> Result<()> {
+        self.inner.set_teon(values.as_ref()).await
+    }
+
+    /// Update with new values to a audit. Validations and transformations are
+    /// not triggered.
+    pub async fn update(&self, values: impl AsRef<Value>) -> Result<()> {
+        self.inner.update_teon(values.as_ref()).await
+    }
+
+    /// Save this audit.
+    pub async fn save(&self) -> Result<()> {
+        self.inner.save().await
+    }
+
+    /// Delete this audit.
+    pub async fn delete(&self) -> Result<()> {
+        self.inner.delete().await
+    }
+
+    /// Convert this audit object to teon.
+    pub async fn to_teon(&self) -> Result<Value> {
+        self.inner.to_teon().await
+    }
+    /// ## Id
+    ///
+    /// This field doesn't have a description.
+    pub fn id(&self) -> Result<i32> {
+        self.inner.get("id")
+    }
+
+    /// ## Id
+    ///
+    /// This field doesn't have a description.
+    pub fn set_id(&self, new_value: i32) -> Result<()> {
+        self.inner.set("id", new_value)
+    }
+    /// ## Action
+    ///
+    /// This field doesn't have a description.
+    pub fn action(&self) -> Result<String> {
+        self.inner.get("action")
+    }
+
+    /// ## Action
+    ///
+    /// This field doesn't have a description.
+    pub fn set_action(&self, new_value: String) -> Result<()> {
+        self.inner.set("action", new_value)
+    }
+    /// ## Role
+    ///
+    /// This field doesn't have a description.
+    pub fn role(&self) -> Result<admin::Role> {
+        self.inner.get("role")
+    }
+
+    /// ## Role
+    ///
+    /// This field doesn't have a description.
+    pub fn set_role(&self, new_value: admin::Role) -> Result<()> {
+        self.inner.set("role", new_value)
+    }
+    /// ## Created at
+    ///
+    /// This field doesn't have a description.
+    pub fn created_at(&self) -> Result<DateTime<Utc>> {
+        self.inner.get("createdAt")
+    }
+
+    /// ## Created at
+    ///
+    /// This field doesn't have a description.
+    pub fn set_created_at(&self, new_value: DateTime<Utc>) -> Result<()> {
+        self.inner.set("createdAt", new_value)
+    }
+}
+
+impl AuditModel {
+    /// Build a typed find many query on audit.
+    pub fn find_many(&self) -> AuditFindManyQuery<'_> {
+        AuditFindManyQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## Audit find many query
+///
+/// A typed builder of `AuditFindManyArgs`.
+pub struct AuditFindManyQuery<'a> {
+    model: &'a AuditModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> AuditFindManyQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: AuditWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: AuditSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: AuditInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: AuditSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AuditWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Vec<Audit>> {
+        let result = self.model.find_many_objects(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl AuditModel {
+    /// Build a typed find unique query on audit.
+    pub fn find_unique(&self) -> AuditFindUniqueQuery<'_> {
+        AuditFindUniqueQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## Audit find unique query
+///
+/// A typed builder of `AuditFindUniqueArgs`.
+pub struct AuditFindUniqueQuery<'a> {
+    model: &'a AuditModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> AuditFindUniqueQuery<'a> {
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: AuditInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: AuditSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AuditWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Option<Audit>> {
+        let result = self.model.find_unique_object(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl AuditModel {
+    /// Build a typed find first query on audit.
+    pub fn find_first(&self) -> AuditFindFirstQuery<'_> {
+        AuditFindFirstQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## Audit find first query
+///
+/// A typed builder of `AuditFindFirstArgs`.
+pub struct AuditFindFirstQuery<'a> {
+    model: &'a AuditModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> AuditFindFirstQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: AuditWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: AuditSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: AuditInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: AuditSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AuditWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Option<Audit>> {
+        let result = self.model.find_first_object(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl AuditModel {
+    /// Build a typed count query on audit.
+    pub fn count(&self) -> AuditCountQuery<'_> {
+        AuditCountQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Audit count query
+///
+/// A typed builder of `AuditCountArgs`.
+pub struct AuditCountQuery<'a> {
+    model: &'a AuditModel,
+    args: Value,
+}
+
+impl<'a> AuditCountQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: AuditWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: AuditSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: AuditCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AuditWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<usize> {
+        self.model.count_objects(self.args).await
+    }
+}
+
+impl AuditModel {
+    /// Build a typed aggregate query on audit.
+    pub fn aggregate_query(&self) -> AuditAggregateQuery<'_> {
+        AuditAggregateQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Audit aggregate query
+///
+/// A typed builder of `AuditAggregateArgs`.
+pub struct AuditAggregateQuery<'a> {
+    model: &'a AuditModel,
+    args: Value,
+}
+
+impl<'a> AuditAggregateQuery<'a> {
+
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn avg(mut self, value: AuditAvgAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_avg".to_owned(), value.into());
+        self
+    }
+
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn count(mut self, value: AuditCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_count".to_owned(), value.into());
+        self
+    }
+
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn max(mut self, value: AuditMaxAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_max".to_owned(), value.into());
+        self
+    }
+
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn min(mut self, value: AuditMinAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_min".to_owned(), value.into());
+        self
+    }
+
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn sum(mut self, value: AuditSumAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_sum".to_owned(), value.into());
+        self
+    }
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: AuditWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: AuditSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AuditWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<AuditAggregateResult> {
+        self.model.aggregate(self.args).await
+    }
+}
+
+impl AuditModel {
+    /// Build a typed group by query on audit.
+    pub fn group_by_query(&self) -> AuditGroupByQuery<'_> {
+        AuditGroupByQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Audit group by query
+///
+/// A typed builder of `AuditGroupByArgs`.
+pub struct AuditGroupByQuery<'a> {
+    model: &'a AuditModel,
+    args: Value,
+}
+
+impl<'a> AuditGroupByQuery<'a> {
+
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn avg(mut self, value: AuditAvgAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_avg".to_owned(), value.into());
+        self
+    }
+
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn count(mut self, value: AuditCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_count".to_owned(), value.into());
+        self
+    }
+
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn max(mut self, value: AuditMaxAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_max".to_owned(), value.into());
+        self
+    }
+
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn min(mut self, value: AuditMinAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_min".to_owned(), value.into());
+        self
+    }
+
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn sum(mut self, value: AuditSumAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_sum".to_owned(), value.into());
+        self
+    }
+
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("by".to_owned(), value.into());
+        self
+    }
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: AuditWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: AuditSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn having(mut self, value: AuditScalarWhereWithAggregatesInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("having".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AuditWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Vec<AuditAggregateResult>> {
+        self.model.group_by(self.args).await
+    }
+}
+
+impl AuditModel {
+    /// Build a typed create query on audit.
+    pub fn create(&self) -> AuditCreateQuery<'_> {
+        AuditCreateQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Audit create query
+///
+/// A typed builder of `AuditCreateArgs`.
+pub struct AuditCreateQuery<'a> {
+    model: &'a AuditModel,
+    args: Value,
+}
+
+impl<'a> AuditCreateQuery<'a> {
+
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn create(mut self, value: AuditCreateInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("create".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: AuditInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: AuditSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Audit> {
+        self.model.create_object_from_args(self.args).await
+    }
+}
+
+impl AuditWhereInput {
+
+    /// Filter on id.
+    pub fn id() -> FieldFilter<Self, i32> {
+        FieldFilter::new("id")
+    }
+
+    /// Filter on action.
+    pub fn action() -> FieldFilter<Self, String> {
+        FieldFilter::new("action")
+    }
+
+    /// Filter on role.
+    pub fn role() -> FieldFilter<Self, admin::Role> {
+        FieldFilter::new("role")
+    }
+
+    /// Filter on created at.
+    pub fn created_at() -> FieldFilter<Self, DateTime<Utc>> {
+        FieldFilter::new("createdAt")
+    }
+
+    /// Match both this and `other`.
+    pub fn and(self, other: Self) -> Result<Self> {
+        query::and(vec![self.into(), other.into()])
+    }
+
+    /// Match either this or `other`.
+    pub fn or(self, other: Self) -> Result<Self> {
+        query::or(vec![self.into(), other.into()])
+    }
+}
+
+impl From<Audit> for model::Object {
+    fn from(value: Audit) -> Self {
+        value.inner.clone()
+    }
+}
+
+impl From<model::Object> for Audit {
+    fn from(value: model::Object) -> Self {
+        Self { inner: value, relations: RelationCache::default() }
+    }
+}
+
+impl From<Audit> for Value {
+    fn from(value: Audit) -> Self {
+        Value::ModelObject(value.inner.clone())
+    }
+}
+
+impl AsInterface for Audit {
+    fn from_value(value: Value) -> Result<Self> {
+        let model_object: model::Object = value.try_into()?;
+        Ok(Self::from(model_object))
+    }
+}
+
+impl TryFrom<Value> for Audit {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        let model_object: model::Object = value.try_into()?;
+        Ok(Self::from(model_object))
+    }
+}
+
+impl Debug for Audit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
+}
+
+impl Display for Audit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+
+impl ExtractFromPipelineCtx for Audit {
+    fn extract(ctx: &pipeline::Ctx) -> Self {
+        Audit::from(ctx.object().clone())
+    }
+}
+
+/// Serializes the loaded fields of this audit. Model objects are bound to a
+/// connection, so deserialize into `AuditResult` instead.
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for Audit {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut map = indexmap::IndexMap::new();
+        map.insert("id".to_owned(), self.inner.get::<Value, _>("id").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("action".to_owned(), self.inner.get::<Value, _>("action").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("role".to_owned(), self.inner.get::<Value, _>("role").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("createdAt".to_owned(), self.inner.get::<Value, _>("createdAt").map_err(<S::Error as serde::ser::Error>::custom)?);
+        serialize_teon(&Value::Dictionary(map), serializer)
+    }
+}
+
+pub trait AuditAggregateArgsTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&AuditAvgAggregateInputType> {
+        Some(AuditAvgAggregateInputType::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<AuditAvgAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&AuditCountAggregateInputType> {
+        Some(AuditCountAggregateInputType::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<AuditCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&AuditMaxAggregateInputType> {
+        Some(AuditMaxAggregateInputType::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<AuditMaxAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&AuditMinAggregateInputType> {
+        Some(AuditMinAggregateInputType::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<AuditMinAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&AuditSumAggregateInputType> {
+        Some(AuditSumAggregateInputType::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<AuditSumAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&AuditWhereUniqueInput> {
+        Some(AuditWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<AuditWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&AuditSerializableScalarFields> {
+        Some(AuditSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<AuditSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditAggregateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditAggregateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditAggregateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditAggregateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditAggregateArgsTrait for AuditAggregateArgs { }
+
+impl AsInterface for AuditAggregateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditAggregateArgs> for Value {
+    fn from(value: AuditAggregateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditAggregateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditAggregateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditAggregateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditAggregateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditAggregateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditAggregateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditAggregateResultTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&AuditAvgAggregateResult> {
+        Some(AuditAvgAggregateResult::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<AuditAvgAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&AuditCountAggregateResult> {
+        Some(AuditCountAggregateResult::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<AuditCountAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&AuditMaxAggregateResult> {
+        Some(AuditMaxAggregateResult::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<AuditMaxAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&AuditMinAggregateResult> {
+        Some(AuditMinAggregateResult::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<AuditMinAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&AuditSumAggregateResult> {
+        Some(AuditSumAggregateResult::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<AuditSumAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditAggregateResultTrait for AuditAggregateResult { }
+
+impl AsInterface for AuditAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditAggregateResult> for Value {
+    fn from(value: AuditAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditArgsTrait for AuditArgs { }
+
+impl AsInterface for AuditArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditArgs> for Value {
+    fn from(value: AuditArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditAvgAggregateInputTypeTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditAvgAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditAvgAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditAvgAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditAvgAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditAvgAggregateInputTypeTrait for AuditAvgAggregateInputType { }
+
+impl AsInterface for AuditAvgAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditAvgAggregateInputType> for Value {
+    fn from(value: AuditAvgAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditAvgAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditAvgAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditAvgAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditAvgAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditAvgAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditAvgAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditAvgAggregateResultTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&f64> {
+        Some(f64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<f64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditAvgAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditAvgAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditAvgAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditAvgAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditAvgAggregateResultTrait for AuditAvgAggregateResult { }
+
+impl AsInterface for AuditAvgAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditAvgAggregateResult> for Value {
+    fn from(value: AuditAvgAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditAvgAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditAvgAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditAvgAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditAvgAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditAvgAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditAvgAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditConnectOrCreateInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &AuditCreateInput {
+        AuditCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: AuditCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereUniqueInput {
+        AuditWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditConnectOrCreateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditConnectOrCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditConnectOrCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditConnectOrCreateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditConnectOrCreateInputTrait for AuditConnectOrCreateInput { }
+
+impl AsInterface for AuditConnectOrCreateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditConnectOrCreateInput> for Value {
+    fn from(value: AuditConnectOrCreateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditConnectOrCreateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditConnectOrCreateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditConnectOrCreateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditConnectOrCreateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditConnectOrCreateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditConnectOrCreateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditCopyArgsTrait: Interface {
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn copy(&self) -> &AuditUpdateInput {
+        AuditUpdateInput::from_value_ref(self.inner().get("copy").unwrap()).unwrap()
+    }
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_copy(&mut self, new_value: AuditUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("copy".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereUniqueInput {
+        AuditWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditCopyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditCopyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditCopyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditCopyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditCopyArgsTrait for AuditCopyArgs { }
+
+impl AsInterface for AuditCopyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditCopyArgs> for Value {
+    fn from(value: AuditCopyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditCopyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditCopyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditCopyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditCopyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditCopyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditCopyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditCopyManyArgsTrait: Interface {
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn copy(&self) -> &AuditUpdateInput {
+        AuditUpdateInput::from_value_ref(self.inner().get("copy").unwrap()).unwrap()
+    }
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_copy(&mut self, new_value: AuditUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("copy".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereInput {
+        AuditWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditCopyManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditCopyManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditCopyManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditCopyManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditCopyManyArgsTrait for AuditCopyManyArgs { }
+
+impl AsInterface for AuditCopyManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditCopyManyArgs> for Value {
+    fn from(value: AuditCopyManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditCopyManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditCopyManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditCopyManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditCopyManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditCopyManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditCopyManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditCountAggregateInputTypeTrait: Interface {
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn all(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("_all")?).unwrap())
+    }
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_all(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_all".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_all");
+            },
+        }
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditCountAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditCountAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditCountAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditCountAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditCountAggregateInputTypeTrait for AuditCountAggregateInputType { }
+
+impl AsInterface for AuditCountAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditCountAggregateInputType> for Value {
+    fn from(value: AuditCountAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditCountAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditCountAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditCountAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditCountAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditCountAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditCountAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditCountAggregateResultTrait: Interface {
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn all(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("_all")?).unwrap())
+    }
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_all(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_all".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_all");
+            },
+        }
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditCountAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditCountAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditCountAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditCountAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditCountAggregateResultTrait for AuditCountAggregateResult { }
+
+impl AsInterface for AuditCountAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditCountAggregateResult> for Value {
+    fn from(value: AuditCountAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditCountAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditCountAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditCountAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditCountAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditCountAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditCountAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditCountArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&AuditWhereUniqueInput> {
+        Some(AuditWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<AuditWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&AuditSerializableScalarFields> {
+        Some(AuditSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<AuditSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditCountAggregateInputType> {
+        Some(AuditCountAggregateInputType::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditCountArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditCountArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditCountArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditCountArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditCountArgsTrait for AuditCountArgs { }
+
+impl AsInterface for AuditCountArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditCountArgs> for Value {
+    fn from(value: AuditCountArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditCountArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditCountArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditCountArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditCountArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditCountArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditCountArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditCreateArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &AuditCreateInput {
+        AuditCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: AuditCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditCreateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditCreateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditCreateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditCreateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditCreateArgsTrait for AuditCreateArgs { }
+
+impl AsInterface for AuditCreateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditCreateArgs> for Value {
+    fn from(value: AuditCreateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditCreateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditCreateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditCreateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditCreateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditCreateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditCreateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditCreateInputTrait: Interface {
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> &String {
+        String::from_value_ref(self.inner().get("action").unwrap()).unwrap()
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> &Role {
+        Role::from_value_ref(self.inner().get("role").unwrap()).unwrap()
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Role) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditCreateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditCreateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditCreateInputTrait for AuditCreateInput { }
+
+impl AsInterface for AuditCreateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditCreateInput> for Value {
+    fn from(value: AuditCreateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditCreateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditCreateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditCreateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditCreateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditCreateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditCreateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditCreateManyArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &Value {
+        Value::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Value) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditCreateManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditCreateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditCreateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditCreateManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditCreateManyArgsTrait for AuditCreateManyArgs { }
+
+impl AsInterface for AuditCreateManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditCreateManyArgs> for Value {
+    fn from(value: AuditCreateManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditCreateManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditCreateManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditCreateManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditCreateManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditCreateManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditCreateManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditCreateNestedManyInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditCreateNestedManyInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditCreateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditCreateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditCreateNestedManyInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditCreateNestedManyInputTrait for AuditCreateNestedManyInput { }
+
+impl AsInterface for AuditCreateNestedManyInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditCreateNestedManyInput> for Value {
+    fn from(value: AuditCreateNestedManyInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditCreateNestedManyInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditCreateNestedManyInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditCreateNestedManyInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditCreateNestedManyInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditCreateNestedManyInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditCreateNestedManyInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditCreateNestedOneInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&AuditWhereUniqueInput> {
+        Some(AuditWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<AuditWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&AuditConnectOrCreateInput> {
+        Some(AuditConnectOrCreateInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<AuditConnectOrCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&AuditCreateInput> {
+        Some(AuditCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<AuditCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditCreateNestedOneInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditCreateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditCreateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditCreateNestedOneInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditCreateNestedOneInputTrait for AuditCreateNestedOneInput { }
+
+impl AsInterface for AuditCreateNestedOneInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditCreateNestedOneInput> for Value {
+    fn from(value: AuditCreateNestedOneInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditCreateNestedOneInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditCreateNestedOneInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditCreateNestedOneInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditCreateNestedOneInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditCreateNestedOneInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditCreateNestedOneInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditDeleteArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereUniqueInput {
+        AuditWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditDeleteArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditDeleteArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditDeleteArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditDeleteArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditDeleteArgsTrait for AuditDeleteArgs { }
+
+impl AsInterface for AuditDeleteArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditDeleteArgs> for Value {
+    fn from(value: AuditDeleteArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditDeleteArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditDeleteArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditDeleteArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditDeleteArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditDeleteArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditDeleteArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditDeleteManyArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereInput {
+        AuditWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditDeleteManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditDeleteManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditDeleteManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditDeleteManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditDeleteManyArgsTrait for AuditDeleteManyArgs { }
+
+impl AsInterface for AuditDeleteManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditDeleteManyArgs> for Value {
+    fn from(value: AuditDeleteManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditDeleteManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditDeleteManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditDeleteManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditDeleteManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditDeleteManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditDeleteManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditFindFirstArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&AuditWhereUniqueInput> {
+        Some(AuditWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<AuditWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&AuditSerializableScalarFields> {
+        Some(AuditSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<AuditSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditFindFirstArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditFindFirstArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditFindFirstArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditFindFirstArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditFindFirstArgsTrait for AuditFindFirstArgs { }
+
+impl AsInterface for AuditFindFirstArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditFindFirstArgs> for Value {
+    fn from(value: AuditFindFirstArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditFindFirstArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditFindFirstArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditFindFirstArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditFindFirstArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditFindFirstArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditFindFirstArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditFindManyArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&AuditWhereUniqueInput> {
+        Some(AuditWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<AuditWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&AuditSerializableScalarFields> {
+        Some(AuditSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<AuditSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditFindManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditFindManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditFindManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditFindManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditFindManyArgsTrait for AuditFindManyArgs { }
+
+impl AsInterface for AuditFindManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditFindManyArgs> for Value {
+    fn from(value: AuditFindManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditFindManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditFindManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditFindManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditFindManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditFindManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditFindManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditFindUniqueArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereUniqueInput {
+        AuditWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditFindUniqueArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditFindUniqueArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditFindUniqueArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditFindUniqueArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditFindUniqueArgsTrait for AuditFindUniqueArgs { }
+
+impl AsInterface for AuditFindUniqueArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditFindUniqueArgs> for Value {
+    fn from(value: AuditFindUniqueArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditFindUniqueArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditFindUniqueArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditFindUniqueArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditFindUniqueArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditFindUniqueArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditFindUniqueArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditGroupByArgsTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&AuditAvgAggregateInputType> {
+        Some(AuditAvgAggregateInputType::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<AuditAvgAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&AuditCountAggregateInputType> {
+        Some(AuditCountAggregateInputType::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<AuditCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&AuditMaxAggregateInputType> {
+        Some(AuditMaxAggregateInputType::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<AuditMaxAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&AuditMinAggregateInputType> {
+        Some(AuditMinAggregateInputType::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<AuditMinAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&AuditSumAggregateInputType> {
+        Some(AuditSumAggregateInputType::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<AuditSumAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn by(&self) -> &Value {
+        Value::from_value_ref(self.inner().get("by").unwrap()).unwrap()
+    }
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_by(&mut self, new_value: Value) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("by".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&AuditWhereUniqueInput> {
+        Some(AuditWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<AuditWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&AuditSerializableScalarFields> {
+        Some(AuditSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<AuditSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    fn having(&self) -> Option<&AuditScalarWhereWithAggregatesInput> {
+        Some(AuditScalarWhereWithAggregatesInput::from_value_ref(self.inner().get("having")?).unwrap())
+    }
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_having(&mut self, new_value: Option<AuditScalarWhereWithAggregatesInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("having".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("having");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditGroupByArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditGroupByArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditGroupByArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditGroupByArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditGroupByArgsTrait for AuditGroupByArgs { }
+
+impl AsInterface for AuditGroupByArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditGroupByArgs> for Value {
+    fn from(value: AuditGroupByArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditGroupByArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditGroupByArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditGroupByArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditGroupByArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditGroupByArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditGroupByArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditGroupByResultTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&AuditAvgAggregateResult> {
+        Some(AuditAvgAggregateResult::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<AuditAvgAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&AuditCountAggregateResult> {
+        Some(AuditCountAggregateResult::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<AuditCountAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&AuditMaxAggregateResult> {
+        Some(AuditMaxAggregateResult::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<AuditMaxAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&AuditMinAggregateResult> {
+        Some(AuditMinAggregateResult::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<AuditMinAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&AuditSumAggregateResult> {
+        Some(AuditSumAggregateResult::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<AuditSumAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&DateTime<Utc>> {
+        Some(DateTime::<Utc>::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<DateTime<Utc>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&Role> {
+        Some(Role::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<Role>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditGroupByResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditGroupByResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditGroupByResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditGroupByResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditGroupByResultTrait for AuditGroupByResult { }
+
+impl AsInterface for AuditGroupByResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditGroupByResult> for Value {
+    fn from(value: AuditGroupByResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditGroupByResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditGroupByResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditGroupByResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditGroupByResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditGroupByResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditGroupByResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditIncludeTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct AuditInclude {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditInclude {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditInclude {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditInclude {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditIncludeTrait for AuditInclude { }
+
+impl AsInterface for AuditInclude {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditInclude> for Value {
+    fn from(value: AuditInclude) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditInclude {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditInclude> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditInclude)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditInclude {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditInclude {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditInclude {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditListRelationFilterTrait: Interface {
+    /// ## Every
+    ///
+    /// This synthesized field doesn't have a description.
+    fn every(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("every")?).unwrap())
+    }
+    /// ## Every
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_every(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("every".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("every");
+            },
+        }
+    }
+    /// ## None
+    ///
+    /// This synthesized field doesn't have a description.
+    fn none(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("none")?).unwrap())
+    }
+    /// ## None
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_none(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("none".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("none");
+            },
+        }
+    }
+    /// ## Some
+    ///
+    /// This synthesized field doesn't have a description.
+    fn some(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("some")?).unwrap())
+    }
+    /// ## Some
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_some(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("some".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("some");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditListRelationFilter {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditListRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditListRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditListRelationFilter {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditListRelationFilterTrait for AuditListRelationFilter { }
+
+impl AsInterface for AuditListRelationFilter {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditListRelationFilter> for Value {
+    fn from(value: AuditListRelationFilter) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditListRelationFilter {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditListRelationFilter> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditListRelationFilter)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditListRelationFilter {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditListRelationFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditListRelationFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditMaxAggregateInputTypeTrait: Interface {
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditMaxAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditMaxAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditMaxAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditMaxAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditMaxAggregateInputTypeTrait for AuditMaxAggregateInputType { }
+
+impl AsInterface for AuditMaxAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditMaxAggregateInputType> for Value {
+    fn from(value: AuditMaxAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditMaxAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditMaxAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditMaxAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditMaxAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditMaxAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditMaxAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditMaxAggregateResultTrait: Interface {
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&DateTime<Utc>> {
+        Some(DateTime::<Utc>::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<DateTime<Utc>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&Role> {
+        Some(Role::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<Role>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditMaxAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditMaxAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditMaxAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditMaxAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditMaxAggregateResultTrait for AuditMaxAggregateResult { }
+
+impl AsInterface for AuditMaxAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditMaxAggregateResult> for Value {
+    fn from(value: AuditMaxAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditMaxAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditMaxAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditMaxAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditMaxAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditMaxAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditMaxAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditMinAggregateInputTypeTrait: Interface {
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditMinAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditMinAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditMinAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditMinAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditMinAggregateInputTypeTrait for AuditMinAggregateInputType { }
+
+impl AsInterface for AuditMinAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditMinAggregateInputType> for Value {
+    fn from(value: AuditMinAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditMinAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditMinAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditMinAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditMinAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditMinAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditMinAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditMinAggregateResultTrait: Interface {
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&DateTime<Utc>> {
+        Some(DateTime::<Utc>::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<DateTime<Utc>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&Role> {
+        Some(Role::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<Role>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditMinAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditMinAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditMinAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditMinAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditMinAggregateResultTrait for AuditMinAggregateResult { }
+
+impl AsInterface for AuditMinAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditMinAggregateResult> for Value {
+    fn from(value: AuditMinAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditMinAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditMinAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditMinAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditMinAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditMinAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditMinAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditOrderByInputTrait: Interface {
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&super::stdlib::Sort> {
+        Some(super::stdlib::Sort::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<super::stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&super::stdlib::Sort> {
+        Some(super::stdlib::Sort::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<super::stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&super::stdlib::Sort> {
+        Some(super::stdlib::Sort::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<super::stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&super::stdlib::Sort> {
+        Some(super::stdlib::Sort::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<super::stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditOrderByInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditOrderByInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditOrderByInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditOrderByInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditOrderByInputTrait for AuditOrderByInput { }
+
+impl AsInterface for AuditOrderByInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditOrderByInput> for Value {
+    fn from(value: AuditOrderByInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditOrderByInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditOrderByInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditOrderByInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditOrderByInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditOrderByInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditOrderByInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditRelationFilterTrait: Interface {
+    /// ## Is
+    ///
+    /// This synthesized field doesn't have a description.
+    fn is(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("is")?).unwrap())
+    }
+    /// ## Is
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_is(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("is".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("is");
+            },
+        }
+    }
+    /// ## Is Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn is_not(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("isNot")?).unwrap())
+    }
+    /// ## Is Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_is_not(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("isNot".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("isNot");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditRelationFilter {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditRelationFilter {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditRelationFilterTrait for AuditRelationFilter { }
+
+impl AsInterface for AuditRelationFilter {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditRelationFilter> for Value {
+    fn from(value: AuditRelationFilter) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditRelationFilter {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditRelationFilter> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditRelationFilter)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditRelationFilter {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditRelationFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditRelationFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditResultTrait: Interface {
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> &String {
+        String::from_value_ref(self.inner().get("action").unwrap()).unwrap()
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> &DateTime<Utc> {
+        DateTime::<Utc>::from_value_ref(self.inner().get("createdAt").unwrap()).unwrap()
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: DateTime<Utc>) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("id").unwrap()).unwrap()
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> &Role {
+        Role::from_value_ref(self.inner().get("role").unwrap()).unwrap()
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Role) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditResultTrait for AuditResult { }
+
+impl AsInterface for AuditResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditResult> for Value {
+    fn from(value: AuditResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditScalarUpdateInputTrait: Interface {
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&DateTime<Utc>> {
+        Some(DateTime::<Utc>::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<DateTime<Utc>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&Role> {
+        Some(Role::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<Role>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditScalarUpdateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditScalarUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditScalarUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditScalarUpdateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditScalarUpdateInputTrait for AuditScalarUpdateInput { }
+
+impl AsInterface for AuditScalarUpdateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditScalarUpdateInput> for Value {
+    fn from(value: AuditScalarUpdateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditScalarUpdateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditScalarUpdateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditScalarUpdateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditScalarUpdateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditScalarUpdateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditScalarUpdateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditScalarWhereWithAggregatesInputTrait: Interface {
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn and(&self) -> Option<Vec<&AuditWhereInput>> {
+        Some(Vec::<&AuditWhereInput>::from_value_ref_vec(self.inner().get("AND")?).unwrap())
+    }
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_and(&mut self, new_value: Option<Vec<AuditWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("AND".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("AND");
+            },
+        }
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn not(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("NOT")?).unwrap())
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_not(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("NOT".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("NOT");
+            },
+        }
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn or(&self) -> Option<Vec<&AuditWhereInput>> {
+        Some(Vec::<&AuditWhereInput>::from_value_ref_vec(self.inner().get("OR")?).unwrap())
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_or(&mut self, new_value: Option<Vec<AuditWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("OR".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("OR");
+            },
+        }
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditScalarWhereWithAggregatesInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditScalarWhereWithAggregatesInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditScalarWhereWithAggregatesInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditScalarWhereWithAggregatesInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditScalarWhereWithAggregatesInputTrait for AuditScalarWhereWithAggregatesInput { }
+
+impl AsInterface for AuditScalarWhereWithAggregatesInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditScalarWhereWithAggregatesInput> for Value {
+    fn from(value: AuditScalarWhereWithAggregatesInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditScalarWhereWithAggregatesInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditScalarWhereWithAggregatesInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditScalarWhereWithAggregatesInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditScalarWhereWithAggregatesInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditScalarWhereWithAggregatesInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditScalarWhereWithAggregatesInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditSelectTrait: Interface {
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditSelect {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditSelect {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditSelect {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditSelect {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditSelectTrait for AuditSelect { }
+
+impl AsInterface for AuditSelect {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditSelect> for Value {
+    fn from(value: AuditSelect) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditSelect {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditSelect> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditSelect)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditSelect {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditSelect {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditSelect {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditSignInArgsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct AuditSignInArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditSignInArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditSignInArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditSignInArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditSignInArgsTrait for AuditSignInArgs { }
+
+impl AsInterface for AuditSignInArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditSignInArgs> for Value {
+    fn from(value: AuditSignInArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditSignInArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditSignInArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditSignInArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditSignInArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditSignInArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditSignInArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditSignInCheckerCompanionsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct AuditSignInCheckerCompanions {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditSignInCheckerCompanions {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditSignInCheckerCompanions {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditSignInCheckerCompanions {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditSignInCheckerCompanionsTrait for AuditSignInCheckerCompanions { }
+
+impl AsInterface for AuditSignInCheckerCompanions {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditSignInCheckerCompanions> for Value {
+    fn from(value: AuditSignInCheckerCompanions) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditSignInCheckerCompanions {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditSignInCheckerCompanions> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditSignInCheckerCompanions)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditSignInCheckerCompanions {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditSignInCheckerCompanions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditSignInCheckerCompanions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditSignInCheckerIdsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct AuditSignInCheckerIds {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditSignInCheckerIds {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditSignInCheckerIds {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditSignInCheckerIds {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditSignInCheckerIdsTrait for AuditSignInCheckerIds { }
+
+impl AsInterface for AuditSignInCheckerIds {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditSignInCheckerIds> for Value {
+    fn from(value: AuditSignInCheckerIds) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditSignInCheckerIds {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditSignInCheckerIds> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditSignInCheckerIds)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditSignInCheckerIds {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditSignInCheckerIds {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditSignInCheckerIds {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditSignInInputTrait: Interface {
+    /// ## Credentials
+    ///
+    /// This synthesized field doesn't have a description.
+    fn credentials(&self) -> &AuditSignInArgs {
+        AuditSignInArgs::from_value_ref(self.inner().get("credentials").unwrap()).unwrap()
+    }
+    /// ## Credentials
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_credentials(&mut self, new_value: AuditSignInArgs) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("credentials".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditSignInInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditSignInInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditSignInInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditSignInInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditSignInInputTrait for AuditSignInInput { }
+
+impl AsInterface for AuditSignInInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditSignInInput> for Value {
+    fn from(value: AuditSignInInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditSignInInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditSignInInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditSignInInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditSignInInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditSignInInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditSignInInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditSumAggregateInputTypeTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditSumAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditSumAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditSumAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditSumAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditSumAggregateInputTypeTrait for AuditSumAggregateInputType { }
+
+impl AsInterface for AuditSumAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditSumAggregateInputType> for Value {
+    fn from(value: AuditSumAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditSumAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditSumAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditSumAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditSumAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditSumAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditSumAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditSumAggregateResultTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditSumAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditSumAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditSumAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditSumAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditSumAggregateResultTrait for AuditSumAggregateResult { }
+
+impl AsInterface for AuditSumAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditSumAggregateResult> for Value {
+    fn from(value: AuditSumAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditSumAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditSumAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditSumAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditSumAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditSumAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditSumAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditUpdateArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AuditUpdateInput {
+        AuditUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AuditUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereUniqueInput {
+        AuditWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditUpdateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditUpdateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditUpdateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditUpdateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditUpdateArgsTrait for AuditUpdateArgs { }
+
+impl AsInterface for AuditUpdateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditUpdateArgs> for Value {
+    fn from(value: AuditUpdateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditUpdateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditUpdateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditUpdateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditUpdateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditUpdateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditUpdateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditUpdateInputTrait: Interface {
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&Role> {
+        Some(Role::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<Role>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditUpdateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditUpdateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditUpdateInputTrait for AuditUpdateInput { }
+
+impl AsInterface for AuditUpdateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditUpdateInput> for Value {
+    fn from(value: AuditUpdateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditUpdateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditUpdateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditUpdateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditUpdateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditUpdateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditUpdateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditUpdateManyArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AuditUpdateInput {
+        AuditUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AuditUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereInput {
+        AuditWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditUpdateManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditUpdateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditUpdateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditUpdateManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditUpdateManyArgsTrait for AuditUpdateManyArgs { }
+
+impl AsInterface for AuditUpdateManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditUpdateManyArgs> for Value {
+    fn from(value: AuditUpdateManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditUpdateManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditUpdateManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditUpdateManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditUpdateManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditUpdateManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditUpdateManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditUpdateManyWithWhereInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AuditUpdateInput {
+        AuditUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AuditUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereInput {
+        AuditWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditUpdateManyWithWhereInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditUpdateManyWithWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditUpdateManyWithWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditUpdateManyWithWhereInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditUpdateManyWithWhereInputTrait for AuditUpdateManyWithWhereInput { }
+
+impl AsInterface for AuditUpdateManyWithWhereInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditUpdateManyWithWhereInput> for Value {
+    fn from(value: AuditUpdateManyWithWhereInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditUpdateManyWithWhereInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditUpdateManyWithWhereInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditUpdateManyWithWhereInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditUpdateManyWithWhereInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditUpdateManyWithWhereInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditUpdateManyWithWhereInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditUpdateNestedManyInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("deleteMany")?).unwrap())
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("deleteMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("deleteMany");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("updateMany")?).unwrap())
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("updateMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("updateMany");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditUpdateNestedManyInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditUpdateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditUpdateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditUpdateNestedManyInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditUpdateNestedManyInputTrait for AuditUpdateNestedManyInput { }
+
+impl AsInterface for AuditUpdateNestedManyInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditUpdateNestedManyInput> for Value {
+    fn from(value: AuditUpdateNestedManyInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditUpdateNestedManyInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditUpdateNestedManyInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditUpdateNestedManyInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditUpdateNestedManyInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditUpdateNestedManyInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditUpdateNestedManyInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditUpdateNestedOneInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&AuditWhereUniqueInput> {
+        Some(AuditWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<AuditWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&AuditConnectOrCreateInput> {
+        Some(AuditConnectOrCreateInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<AuditConnectOrCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&AuditCreateInput> {
+        Some(AuditCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<AuditCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&AuditWhereUniqueInput> {
+        Some(AuditWhereUniqueInput::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<AuditWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&AuditUpdateInput> {
+        Some(AuditUpdateInput::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<AuditUpdateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&AuditUpsertWithWhereUniqueInput> {
+        Some(AuditUpsertWithWhereUniqueInput::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<AuditUpsertWithWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditUpdateNestedOneInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditUpdateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditUpdateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditUpdateNestedOneInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditUpdateNestedOneInputTrait for AuditUpdateNestedOneInput { }
+
+impl AsInterface for AuditUpdateNestedOneInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditUpdateNestedOneInput> for Value {
+    fn from(value: AuditUpdateNestedOneInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditUpdateNestedOneInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditUpdateNestedOneInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditUpdateNestedOneInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditUpdateNestedOneInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditUpdateNestedOneInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditUpdateNestedOneInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditUpdateWithWhereUniqueInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AuditUpdateInput {
+        AuditUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AuditUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereUniqueInput {
+        AuditWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditUpdateWithWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditUpdateWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditUpdateWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditUpdateWithWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditUpdateWithWhereUniqueInputTrait for AuditUpdateWithWhereUniqueInput { }
+
+impl AsInterface for AuditUpdateWithWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditUpdateWithWhereUniqueInput> for Value {
+    fn from(value: AuditUpdateWithWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditUpdateWithWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditUpdateWithWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditUpdateWithWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditUpdateWithWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditUpdateWithWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditUpdateWithWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditUpsertArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &AuditCreateInput {
+        AuditCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: AuditCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AuditInclude> {
+        Some(AuditInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AuditInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AuditSelect> {
+        Some(AuditSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AuditSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AuditUpdateInput {
+        AuditUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AuditUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereUniqueInput {
+        AuditWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditUpsertArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditUpsertArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditUpsertArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditUpsertArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditUpsertArgsTrait for AuditUpsertArgs { }
+
+impl AsInterface for AuditUpsertArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditUpsertArgs> for Value {
+    fn from(value: AuditUpsertArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditUpsertArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditUpsertArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditUpsertArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditUpsertArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditUpsertArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditUpsertArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditUpsertWithWhereUniqueInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &AuditCreateInput {
+        AuditCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: AuditCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AuditUpdateInput {
+        AuditUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AuditUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AuditWhereUniqueInput {
+        AuditWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AuditWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditUpsertWithWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditUpsertWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditUpsertWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditUpsertWithWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditUpsertWithWhereUniqueInputTrait for AuditUpsertWithWhereUniqueInput { }
+
+impl AsInterface for AuditUpsertWithWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditUpsertWithWhereUniqueInput> for Value {
+    fn from(value: AuditUpsertWithWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditUpsertWithWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditUpsertWithWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditUpsertWithWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditUpsertWithWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditUpsertWithWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditUpsertWithWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditWhereInputTrait: Interface {
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn and(&self) -> Option<Vec<&AuditWhereInput>> {
+        Some(Vec::<&AuditWhereInput>::from_value_ref_vec(self.inner().get("AND")?).unwrap())
+    }
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_and(&mut self, new_value: Option<Vec<AuditWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("AND".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("AND");
+            },
+        }
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn not(&self) -> Option<&AuditWhereInput> {
+        Some(AuditWhereInput::from_value_ref(self.inner().get("NOT")?).unwrap())
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_not(&mut self, new_value: Option<AuditWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("NOT".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("NOT");
+            },
+        }
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn or(&self) -> Option<Vec<&AuditWhereInput>> {
+        Some(Vec::<&AuditWhereInput>::from_value_ref_vec(self.inner().get("OR")?).unwrap())
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_or(&mut self, new_value: Option<Vec<AuditWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("OR".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("OR");
+            },
+        }
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn action(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("action")?).unwrap())
+    }
+    /// ## Action
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_action(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("action".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("action");
+            },
+        }
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn created_at(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("createdAt")?).unwrap())
+    }
+    /// ## Created At
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_created_at(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("createdAt".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("createdAt");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditWhereInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditWhereInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditWhereInputTrait for AuditWhereInput { }
+
+impl AsInterface for AuditWhereInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditWhereInput> for Value {
+    fn from(value: AuditWhereInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditWhereInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditWhereInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditWhereInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditWhereInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditWhereInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditWhereInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AuditWhereUniqueInputTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("id").unwrap()).unwrap()
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AuditWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AuditWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AuditWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AuditWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AuditWhereUniqueInputTrait for AuditWhereUniqueInput { }
+
+impl AsInterface for AuditWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AuditWhereUniqueInput> for Value {
+    fn from(value: AuditWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AuditWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AuditWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AuditWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AuditWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AuditWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AuditWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub struct AdminNamespace {
+    pub(crate) ctx: transaction::Ctx,
+}
+
+impl From<transaction::Ctx> for AdminNamespace {
+    fn from(value: transaction::Ctx) -> Self {
+        Self { ctx: value }
+    }
+}
+
+impl AdminNamespace {
+
+    
+    pub fn audit(&self) -> AuditModel {
+        AuditModel { ctx: self.ctx.model_ctx_for_model_at_path(&vec!["admin".to_owned(), "Audit".to_owned()]).unwrap() }
+    }
+    pub fn reports(&self) -> ReportsNamespace {
+        ReportsNamespace { ctx: self.ctx.clone() }
+    }
+}
+
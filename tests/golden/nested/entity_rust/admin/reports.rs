@@ -0,0 +1,276 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use teo::prelude::{
+    teon, model, Model, Value, Result, Error, transaction, Request, ExtractFromRequest, ExtractFromPipelineCtx, request, pipeline, ExtractFromTransactionCtx, File, Arguments,
+};
+use std::marker::PhantomData;
+use super::super::helpers::interface::{Interface, AsInterface, AsInterfaceRef, AsInterfaceVecRef};
+use super::super::helpers::query::{self, FieldFilter};
+use super::super::helpers::relations::{self, RelationCache};
+#[cfg(feature = "teo-serde")]
+use super::super::helpers::teon_serde::{serialize_teon, deserialize_teon};
+
+
+
+
+
+pub trait ReportPathArgumentsTrait: Interface {
+    /// ## Year
+    ///
+    /// This interface field doesn't have a description.
+    fn year(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("year").unwrap()).unwrap()
+    }
+    /// ## Year
+    ///
+    /// This interface field doesn't have a description.
+    fn set_year(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("year".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Month
+    ///
+    /// This interface field doesn't have a description.
+    fn month(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("month").unwrap()).unwrap()
+    }
+    /// ## Month
+    ///
+    /// This interface field doesn't have a description.
+    fn set_month(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("month".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct ReportPathArguments {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for ReportPathArguments {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &ReportPathArguments {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for ReportPathArguments {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl ReportPathArgumentsTrait for ReportPathArguments { }
+
+impl AsInterface for ReportPathArguments {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<ReportPathArguments> for Value {
+    fn from(value: ReportPathArguments) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for ReportPathArguments {
+
+    fn from_value_ref(value: &Value) -> Result<&ReportPathArguments> {
+        Ok(unsafe {
+            &*(value as *const Value as *const ReportPathArguments)
+        })
+    }
+}
+
+impl ExtractFromRequest for ReportPathArguments {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for ReportPathArguments {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for ReportPathArguments {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait SummaryTrait<T>: Interface where T: Into<Value> + AsInterface + AsInterfaceRef {
+    /// ## Total
+    ///
+    /// This interface field doesn't have a description.
+    fn total(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("total").unwrap()).unwrap()
+    }
+    /// ## Total
+    ///
+    /// This interface field doesn't have a description.
+    fn set_total(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("total".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Latest
+    ///
+    /// This interface field doesn't have a description.
+    fn latest(&self) -> Option<&T> {
+        Some(T::from_value_ref(self.inner().get("latest")?).unwrap())
+    }
+    /// ## Latest
+    ///
+    /// This interface field doesn't have a description.
+    fn set_latest(&mut self, new_value: Option<T>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("latest".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("latest");
+            },
+        }
+    }
+    /// ## By role
+    ///
+    /// This interface field doesn't have a description.
+    fn by_role(&self) -> Vec<&super::Role> {
+        Vec::<&super::Role>::from_value_ref_vec(self.inner().get("byRole").unwrap()).unwrap()
+    }
+    /// ## By role
+    ///
+    /// This interface field doesn't have a description.
+    fn set_by_role(&mut self, new_value: Vec<super::Role>) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("byRole".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct Summary<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    inner: Value,
+    phantom_data: PhantomData<T>,
+}
+
+impl<T> Borrow<Value> for Summary<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl<T> Borrow<Value> for &Summary<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl<T> Interface for Summary<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl<T> SummaryTrait<T> for Summary<T> where T: Into<Value> + AsInterface + AsInterfaceRef { }
+
+impl<T> AsInterface for Summary<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl<T> From<Summary<T>> for Value where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn from(value: Summary<T>) -> Self {
+        value.inner
+    }
+}
+
+impl<T> AsInterfaceRef for Summary<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+
+    fn from_value_ref(value: &Value) -> Result<&Summary<T>> {
+        Ok(unsafe {
+            &*(value as *const Value as *const Summary<T>)
+        })
+    }
+}
+
+impl<T> ExtractFromRequest for Summary<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<T> serde::Serialize for Summary<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de, T> serde::Deserialize<'de> for Summary<T> where T: Into<Value> + AsInterface + AsInterfaceRef {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+#[repr(transparent)]
+pub struct ReportPathArguments {
+    request: Request,
+}
+
+impl ReportPathArguments {
+
+    pub fn year(&self) -> Result<&str> {
+        Ok(self.request.captures()?.get("year").unwrap().as_str())
+    }pub fn month(&self) -> Result<&str> {
+        Ok(self.request.captures()?.get("month").unwrap().as_str())
+    }
+}
+
+impl ExtractFromRequest for ReportPathArguments {
+    fn extract(request: &Request) -> Self {
+        ReportPathArguments {
+            request: request.clone(),
+        }
+    }
+}
+
+pub struct ReportsNamespace {
+    pub(crate) ctx: transaction::Ctx,
+}
+
+impl From<transaction::Ctx> for ReportsNamespace {
+    fn from(value: transaction::Ctx) -> Self {
+        Self { ctx: value }
+    }
+}
+
+impl ReportsNamespace {
+
+    
+}
+
@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+pub mod interface;
+pub mod query;
+pub mod relations;
+#[cfg(feature = "teo-serde")]
+pub mod teon_serde;
\ No newline at end of file
@@ -0,0 +1,131 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use indexmap::IndexMap;
+use teo::prelude::{model, Value, Result};
+
+/// ## Relation cache
+///
+/// Relation objects loaded ahead of time by batch loaders. Relation accessors
+/// read from here before querying the database.
+#[derive(Clone, Default)]
+pub struct RelationCache {
+    inner: Arc<Mutex<BTreeMap<&'static str, Vec<model::Object>>>>,
+}
+
+impl RelationCache {
+
+    pub fn get(&self, name: &str) -> Option<Vec<model::Object>> {
+        self.inner.lock().unwrap().get(name).cloned()
+    }
+
+    pub fn set(&self, name: &'static str, objects: Vec<model::Object>) {
+        self.inner.lock().unwrap().insert(name, objects);
+    }
+
+    pub fn remove(&self, name: &str) {
+        self.inner.lock().unwrap().remove(name);
+    }
+}
+
+/// Load the relation `name` of every owner with a single query on the related
+/// model, matching owner `fields` against related `references`.
+pub async fn batch_load(owners: Vec<(&RelationCache, &model::Object)>, name: &'static str, related: &model::Ctx, fields: &[&str], references: &[&str]) -> Result<()> {
+    if owners.is_empty() {
+        return Ok(());
+    }
+    let keys = keys(owners.iter().map(|(_, object)| *object), fields)?;
+    let related_objects = find_matching(related, references, &keys).await?;
+    for ((cache, _), key) in owners.iter().zip(keys.iter()) {
+        let mut matched = vec![];
+        for related_object in &related_objects {
+            if matches(related_object, references, key)? {
+                matched.push(related_object.clone());
+            }
+        }
+        cache.set(name, matched);
+    }
+    Ok(())
+}
+
+/// Load the relation `name` which goes through a join model with two queries,
+/// one on the join model and one on the related model. Join objects refer to
+/// owner `local_fields` with `local_references`, and to related
+/// `foreign_references` with `foreign_fields`.
+pub async fn batch_load_through(
+    owners: Vec<(&RelationCache, &model::Object)>,
+    name: &'static str,
+    join: &model::Ctx,
+    local_fields: &[&str],
+    local_references: &[&str],
+    related: &model::Ctx,
+    foreign_fields: &[&str],
+    foreign_references: &[&str],
+) -> Result<()> {
+    if owners.is_empty() {
+        return Ok(());
+    }
+    let owner_keys = keys(owners.iter().map(|(_, object)| *object), local_fields)?;
+    let join_objects = find_matching(join, local_references, &owner_keys).await?;
+    let join_keys = keys(join_objects.iter(), foreign_fields)?;
+    let related_objects = if join_keys.is_empty() {
+        vec![]
+    } else {
+        find_matching(related, foreign_references, &join_keys).await?
+    };
+    for ((cache, _), owner_key) in owners.iter().zip(owner_keys.iter()) {
+        let mut matched = vec![];
+        for (join_object, join_key) in join_objects.iter().zip(join_keys.iter()) {
+            if !matches(join_object, local_references, owner_key)? {
+                continue;
+            }
+            for related_object in &related_objects {
+                if matches(related_object, foreign_references, join_key)? {
+                    matched.push(related_object.clone());
+                }
+            }
+        }
+        cache.set(name, matched);
+    }
+    Ok(())
+}
+
+fn keys<'a>(objects: impl Iterator<Item = &'a model::Object>, fields: &[&str]) -> Result<Vec<Vec<Value>>> {
+    let mut keys = vec![];
+    for object in objects {
+        let mut key = vec![];
+        for field in fields {
+            let value: Value = object.get(*field)?;
+            key.push(value);
+        }
+        keys.push(key);
+    }
+    Ok(keys)
+}
+
+/// Objects of `model` whose `references` equal one of `keys`.
+async fn find_matching(model: &model::Ctx, references: &[&str], keys: &Vec<Vec<Value>>) -> Result<Vec<model::Object>> {
+    let r#where = if references.len() == 1 {
+        dictionary(vec![(references[0], dictionary(vec![("in", Value::Array(keys.iter().map(|key| key[0].clone()).collect()))]))])
+    } else {
+        dictionary(vec![("OR", Value::Array(keys.iter().map(|key| dictionary(references.iter().map(|r| *r).zip(key.iter().cloned()).collect())).collect()))])
+    };
+    model.find_many(&dictionary(vec![("where", r#where)])).await
+}
+
+fn matches(object: &model::Object, references: &[&str], key: &Vec<Value>) -> Result<bool> {
+    for (reference, value) in references.iter().zip(key.iter()) {
+        let object_value: Value = object.get(*reference)?;
+        if &object_value != value {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn dictionary(entries: Vec<(&str, Value)>) -> Value {
+    Value::Dictionary(entries.into_iter().map(|(k, v)| (k.to_owned(), v)).collect::<IndexMap<String, Value>>())
+}
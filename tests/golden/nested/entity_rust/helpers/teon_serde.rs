@@ -0,0 +1,88 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use std::str::FromStr;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, SecondsFormat, Utc};
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error as DeError;
+use serde::ser::Error as SerError;
+use serde_json::{Map, Number, Value as JsonValue};
+use teo::prelude::Value;
+
+/// Convert a teon value into its JSON representation. Dates, datetimes and
+/// decimals are tagged the same way Teo encodes them in responses. Only the
+/// types the schema uses are converted, the others need crates it doesn't add.
+pub fn teon_to_json(value: &Value) -> std::result::Result<JsonValue, String> {
+    Ok(match value {
+        Value::Null => JsonValue::Null,
+        Value::Bool(b) => JsonValue::Bool(*b),
+        Value::Int(i) => JsonValue::Number(Number::from(*i)),
+        Value::Int64(i) => JsonValue::Number(Number::from(*i)),
+        Value::Float32(f) => Number::from_f64(*f as f64).map(JsonValue::Number).ok_or("float is not finite")?,
+        Value::Float(f) => Number::from_f64(*f).map(JsonValue::Number).ok_or("float is not finite")?,
+        Value::String(s) => JsonValue::String(s.clone()),
+        Value::ObjectId(o) => JsonValue::String(o.to_hex()),
+        Value::Decimal(d) => tagged("$decimal", d.to_string()),
+        Value::DateTime(d) => tagged("$datetime", d.to_rfc3339_opts(SecondsFormat::Millis, true)),
+        Value::Array(a) => JsonValue::Array(a.iter().map(teon_to_json).collect::<std::result::Result<Vec<JsonValue>, String>>()?),
+        Value::Dictionary(d) => {
+            let mut map = Map::new();
+            for (k, v) in d {
+                map.insert(k.clone(), teon_to_json(v)?);
+            }
+            JsonValue::Object(map)
+        },
+        _ => Err("value cannot be serialized")?,
+    })
+}
+
+/// Convert a JSON value back into teon, decoding tagged dates, datetimes and
+/// decimals.
+pub fn json_to_teon(json: JsonValue) -> std::result::Result<Value, String> {
+    Ok(match json {
+        JsonValue::Null => Value::Null,
+        JsonValue::Bool(b) => Value::Bool(b),
+        JsonValue::Number(n) => if let Some(i) = n.as_i64() {
+            match i32::try_from(i) {
+                Ok(i) => Value::Int(i),
+                Err(_) => Value::Int64(i),
+            }
+        } else {
+            Value::Float(n.as_f64().ok_or("number is out of range")?)
+        },
+        JsonValue::String(s) => Value::String(s),
+        JsonValue::Array(a) => Value::Array(a.into_iter().map(json_to_teon).collect::<std::result::Result<Vec<Value>, String>>()?),
+        JsonValue::Object(map) => {
+            if map.len() == 1 {
+                if let Some(JsonValue::String(s)) = map.get("$decimal") {
+                    return Ok(Value::Decimal(BigDecimal::from_str(s).map_err(|e| e.to_string())?));
+                }
+                if let Some(JsonValue::String(s)) = map.get("$datetime") {
+                    return Ok(Value::DateTime(DateTime::parse_from_rfc3339(s).map_err(|e| e.to_string())?.with_timezone(&Utc)));
+                }
+            }
+            let mut dictionary = IndexMap::new();
+            for (k, v) in map {
+                dictionary.insert(k, json_to_teon(v)?);
+            }
+            Value::Dictionary(dictionary)
+        },
+    })
+}
+
+pub fn serialize_teon<S>(value: &Value, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: Serializer {
+    teon_to_json(value).map_err(S::Error::custom)?.serialize(serializer)
+}
+
+pub fn deserialize_teon<'de, D>(deserializer: D) -> std::result::Result<Value, D::Error> where D: Deserializer<'de> {
+    json_to_teon(JsonValue::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+fn tagged(tag: &str, value: String) -> JsonValue {
+    let mut map = Map::new();
+    map.insert(tag.to_owned(), JsonValue::String(value));
+    JsonValue::Object(map)
+}
\ No newline at end of file
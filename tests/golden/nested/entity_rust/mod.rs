@@ -0,0 +1,9358 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+pub mod helpers;
+pub mod admin;
+
+pub mod stdlib;
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use teo::prelude::{
+    teon, model, Model, Value, Result, Error, transaction, Request, ExtractFromRequest, ExtractFromPipelineCtx, request, pipeline, ExtractFromTransactionCtx, File, Arguments,
+};
+use std::marker::PhantomData;
+use helpers::interface::{Interface, AsInterface, AsInterfaceRef, AsInterfaceVecRef};
+use helpers::query::{self, FieldFilter};
+use helpers::relations::{self, RelationCache};
+#[cfg(feature = "teo-serde")]
+use helpers::teon_serde::{serialize_teon, deserialize_teon};
+pub use admin::AdminNamespace;
+
+
+
+/// ## Account direct relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct AccountDirectRelations {
+    inner: String,
+}
+
+impl AccountDirectRelations {
+}
+
+impl Debug for AccountDirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AccountDirectRelations(.{})", self.inner)
+    }
+}
+
+impl Display for AccountDirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<AccountDirectRelations> for Value {
+    fn from(value: AccountDirectRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for AccountDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AccountDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountDirectRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for AccountDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AccountDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountDirectRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &AccountDirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AccountDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AccountDirectRelations"))
+        }
+    }
+}
+
+impl AsInterface for AccountDirectRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AccountDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountDirectRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for AccountDirectRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AccountDirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AccountDirectRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountDirectRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountDirectRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Account indirect relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct AccountIndirectRelations {
+    inner: String,
+}
+
+impl AccountIndirectRelations {
+}
+
+impl Debug for AccountIndirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AccountIndirectRelations(.{})", self.inner)
+    }
+}
+
+impl Display for AccountIndirectRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<AccountIndirectRelations> for Value {
+    fn from(value: AccountIndirectRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for AccountIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AccountIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountIndirectRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for AccountIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AccountIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountIndirectRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &AccountIndirectRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AccountIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AccountIndirectRelations"))
+        }
+    }
+}
+
+impl AsInterface for AccountIndirectRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AccountIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountIndirectRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for AccountIndirectRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AccountIndirectRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AccountIndirectRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountIndirectRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountIndirectRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Account relations
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct AccountRelations {
+    inner: String,
+}
+
+impl AccountRelations {
+}
+
+impl Debug for AccountRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AccountRelations(.{})", self.inner)
+    }
+}
+
+impl Display for AccountRelations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<AccountRelations> for Value {
+    fn from(value: AccountRelations) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for AccountRelations {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AccountRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountRelations"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for AccountRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AccountRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountRelations"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &AccountRelations {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AccountRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AccountRelations"))
+        }
+    }
+}
+
+impl AsInterface for AccountRelations {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert value to AccountRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountRelations"))
+        }
+    }
+}
+
+impl AsInterfaceRef for AccountRelations {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                _ => Err(Error::new("cannot convert &Value to &AccountRelations"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AccountRelations"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountRelations {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountRelations {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Account scalar fields
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct AccountScalarFields {
+    inner: String,
+}
+
+impl AccountScalarFields {
+    /// ### Is Id
+    ///
+    /// Returns true if value is id
+    pub fn is_id(&self) -> bool {
+        self.inner.as_str() == "id"
+    }
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn id() -> Self {
+        Self { inner: "id".to_owned() }
+    }
+    /// ### Is Name
+    ///
+    /// Returns true if value is name
+    pub fn is_name(&self) -> bool {
+        self.inner.as_str() == "name"
+    }
+    /// ### Name
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn name() -> Self {
+        Self { inner: "name".to_owned() }
+    }
+    /// ### Is Role
+    ///
+    /// Returns true if value is role
+    pub fn is_role(&self) -> bool {
+        self.inner.as_str() == "role"
+    }
+    /// ### Role
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn role() -> Self {
+        Self { inner: "role".to_owned() }
+    }
+}
+
+impl Debug for AccountScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AccountScalarFields(.{})", self.inner)
+    }
+}
+
+impl Display for AccountScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<AccountScalarFields> for Value {
+    fn from(value: AccountScalarFields) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for AccountScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "id" => AccountScalarFields::id(),
+                "name" => AccountScalarFields::name(),
+                "role" => AccountScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AccountScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountScalarFields"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for AccountScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "id" => AccountScalarFields::id(),
+                "name" => AccountScalarFields::name(),
+                "role" => AccountScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AccountScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountScalarFields"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &AccountScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "name" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "role" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &AccountScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AccountScalarFields"))
+        }
+    }
+}
+
+impl AsInterface for AccountScalarFields {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "id" => AccountScalarFields::id(),
+                "name" => AccountScalarFields::name(),
+                "role" => AccountScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AccountScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountScalarFields"))
+        }
+    }
+}
+
+impl AsInterfaceRef for AccountScalarFields {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "name" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "role" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &AccountScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AccountScalarFields"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountScalarFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountScalarFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+/// ## Account serializable scalar fields
+///
+/// This synthesized enum doesn't have a description.
+#[repr(transparent)]
+#[derive(PartialEq, Clone)]
+pub struct AccountSerializableScalarFields {
+    inner: String,
+}
+
+impl AccountSerializableScalarFields {
+    /// ### Is Id
+    ///
+    /// Returns true if value is id
+    pub fn is_id(&self) -> bool {
+        self.inner.as_str() == "id"
+    }
+    /// ### Id
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn id() -> Self {
+        Self { inner: "id".to_owned() }
+    }
+    /// ### Is Name
+    ///
+    /// Returns true if value is name
+    pub fn is_name(&self) -> bool {
+        self.inner.as_str() == "name"
+    }
+    /// ### Name
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn name() -> Self {
+        Self { inner: "name".to_owned() }
+    }
+    /// ### Is Role
+    ///
+    /// Returns true if value is role
+    pub fn is_role(&self) -> bool {
+        self.inner.as_str() == "role"
+    }
+    /// ### Role
+    ///
+    /// This synthesized enum member doesn't have a description.
+    pub fn role() -> Self {
+        Self { inner: "role".to_owned() }
+    }
+}
+
+impl Debug for AccountSerializableScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AccountSerializableScalarFields(.{})", self.inner)
+    }
+}
+
+impl Display for AccountSerializableScalarFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<AccountSerializableScalarFields> for Value {
+    fn from(value: AccountSerializableScalarFields) -> Value {
+        Value::String(value.inner.clone())
+    }
+}
+
+impl TryFrom<Value> for AccountSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "id" => AccountSerializableScalarFields::id(),
+                "name" => AccountSerializableScalarFields::name(),
+                "role" => AccountSerializableScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AccountSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountSerializableScalarFields"))
+        }
+    }
+}
+
+impl TryFrom<&Value> for AccountSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "id" => AccountSerializableScalarFields::id(),
+                "name" => AccountSerializableScalarFields::name(),
+                "role" => AccountSerializableScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AccountSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountSerializableScalarFields"))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Value> for &AccountSerializableScalarFields {
+
+    type Error = Error;
+
+    fn try_from(value: &Value) -> std::result::Result<Self, Self::Error> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "name" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "role" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &AccountSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AccountSerializableScalarFields"))
+        }
+    }
+}
+
+impl AsInterface for AccountSerializableScalarFields {
+    fn from_value(value: Value) -> Result<Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "id" => AccountSerializableScalarFields::id(),
+                "name" => AccountSerializableScalarFields::name(),
+                "role" => AccountSerializableScalarFields::role(),
+                _ => Err(Error::new("cannot convert value to AccountSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert value to AccountSerializableScalarFields"))
+        }
+    }
+}
+
+impl AsInterfaceRef for AccountSerializableScalarFields {
+    fn from_value_ref(value: &Value) -> Result<&Self> {
+        if let Some(enum_variant) = value.as_str() {
+            Ok(match enum_variant {
+                "id" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "name" => unsafe { &*(enum_variant as *const str as *const Self) },
+                "role" => unsafe { &*(enum_variant as *const str as *const Self) },
+                _ => Err(Error::new("cannot convert &Value to &AccountSerializableScalarFields"))?
+            })
+        } else {
+            Err(Error::new("cannot convert &Value to &AccountSerializableScalarFields"))
+        }
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountSerializableScalarFields {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountSerializableScalarFields {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(Value::String(value)).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+
+/// ## Account
+///
+/// This model doesn't have a description.
+pub struct AccountModel {
+    ctx: model::Ctx,
+}
+
+impl AccountModel {
+    /// Find many account objects.
+    pub async fn find_many_objects(&self, query: impl Borrow<Value>) -> Result<Vec<Account>> {
+        Ok(self.ctx.find_many(query.borrow()).await?)
+    }
+
+    /// Find a unique account object.
+    pub async fn find_unique_object(&self, query: impl Borrow<Value>) -> Result<Option<Account>> {
+        Ok(self.ctx.find_unique(query.borrow()).await?)
+    }
+
+    /// Find an account object.
+    pub async fn find_first_object(&self, query: impl Borrow<Value>) -> Result<Option<Account>> {
+        Ok(self.ctx.find_first(query.borrow()).await?)
+    }
+
+    /// Create a new account object.
+    pub async fn create_object(&self, values: impl Borrow<Value>) -> Result<Account> {
+        Ok(self.ctx.create_object::<Account>(values.borrow()).await?)
+    }
+
+    /// Create an empty account object.
+    pub async fn create_default_object(&self) -> Result<Account> {
+        Ok(self.ctx.create_object::<Account>(teon!({}).borrow()).await?)
+    }
+
+    /// Create and save a account object from the `create` input of `args`.
+    pub async fn create_object_from_args(&self, args: impl Borrow<Value>) -> Result<Account> {
+        let values = args.borrow().as_dictionary().and_then(|args| args.get("create")).cloned().unwrap_or_else(|| teon!({}));
+        let object = self.create_object(values).await?;
+        object.save().await?;
+        Ok(object)
+    }
+
+    /// Count objects on account.
+    pub async fn count_objects(&self, query: impl Borrow<Value>) -> Result<usize> {
+        Ok(self.ctx.count_objects(query.borrow()).await?)
+    }
+
+    /// Count fields on account.
+    pub async fn count_fields(&self, query: impl Borrow<Value>) -> Result<AccountCountAggregateResult> {
+        Ok(AccountCountAggregateResult::from_value(self.ctx.count_fields(query.borrow()).await?)?)
+    }
+
+    /// Aggregate on account.
+    pub async fn aggregate(&self, query: impl Borrow<Value>) -> Result<AccountAggregateResult> {
+        Ok(AccountAggregateResult::from_value(self.ctx.aggregate(query.borrow()).await?)?)
+    }
+
+    /// Group by on account.
+    pub async fn group_by(&self, query: impl Borrow<Value>) -> Result<Vec<AccountAggregateResult>> {
+        let values: Vec<Value> = self.ctx.group_by(query.borrow()).await?;
+        let mut result = vec![];
+        for value in values.into_iter() {
+            result.push(AccountAggregateResult::from_value(value)?);
+        }
+        Ok(result)
+    }
+
+    
+    /// Run a custom SQL clause.
+    pub async fn sql<T, E>(&self, sql: &str) -> Result<Vec<T>> where T: TryFrom<Value, Error=E>, Error: From<E> {
+        self.ctx.sql(sql).await
+    }
+    
+}
+
+#[derive(Clone)]
+pub struct Account {
+    inner: model::Object,
+    relations: RelationCache,
+}
+
+impl Account {
+
+    /// Whether this account is new.
+    pub fn is_new(&self) -> bool {
+        self.inner.is_new()
+    }
+
+    /// Whether this account is modified.
+    pub fn is_modified(&self) -> bool {
+        self.inner.is_modified()
+    }
+
+    /// Set new values to a account. Validations and transformations are
+    /// triggered.
+    pub async fn set(&self, values: impl AsRef<Value>) -> Result<()> {
+        self.inner.set_teon(values.as_ref()).await
+    }
+
+    /// Update with new values to a account. Validations and transformations are
+    /// not triggered.
+    pub async fn update(&self, values: impl AsRef<Value>) -> Result<()> {
+        self.inner.update_teon(values.as_ref()).await
+    }
+
+    /// Save this account.
+    pub async fn save(&self) -> Result<()> {
+        self.inner.save().await
+    }
+
+    /// Delete this account.
+    pub async fn delete(&self) -> Result<()> {
+        self.inner.delete().await
+    }
+
+    /// Convert this account object to teon.
+    pub async fn to_teon(&self) -> Result<Value> {
+        self.inner.to_teon().await
+    }
+    /// ## Id
+    ///
+    /// This field doesn't have a description.
+    pub fn id(&self) -> Result<i32> {
+        self.inner.get("id")
+    }
+
+    /// ## Id
+    ///
+    /// This field doesn't have a description.
+    pub fn set_id(&self, new_value: i32) -> Result<()> {
+        self.inner.set("id", new_value)
+    }
+    /// ## Name
+    ///
+    /// This field doesn't have a description.
+    pub fn name(&self) -> Result<String> {
+        self.inner.get("name")
+    }
+
+    /// ## Name
+    ///
+    /// This field doesn't have a description.
+    pub fn set_name(&self, new_value: String) -> Result<()> {
+        self.inner.set("name", new_value)
+    }
+    /// ## Role
+    ///
+    /// This field doesn't have a description.
+    pub fn role(&self) -> Result<admin::Role> {
+        self.inner.get("role")
+    }
+
+    /// ## Role
+    ///
+    /// This field doesn't have a description.
+    pub fn set_role(&self, new_value: admin::Role) -> Result<()> {
+        self.inner.set("role", new_value)
+    }
+}
+
+impl AccountModel {
+    /// Build a typed find many query on account.
+    pub fn find_many(&self) -> AccountFindManyQuery<'_> {
+        AccountFindManyQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## Account find many query
+///
+/// A typed builder of `AccountFindManyArgs`.
+pub struct AccountFindManyQuery<'a> {
+    model: &'a AccountModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> AccountFindManyQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: AccountWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: AccountSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: AccountInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: AccountSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AccountWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Vec<Account>> {
+        let result = self.model.find_many_objects(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl AccountModel {
+    /// Build a typed find unique query on account.
+    pub fn find_unique(&self) -> AccountFindUniqueQuery<'_> {
+        AccountFindUniqueQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## Account find unique query
+///
+/// A typed builder of `AccountFindUniqueArgs`.
+pub struct AccountFindUniqueQuery<'a> {
+    model: &'a AccountModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> AccountFindUniqueQuery<'a> {
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: AccountInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: AccountSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AccountWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Option<Account>> {
+        let result = self.model.find_unique_object(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl AccountModel {
+    /// Build a typed find first query on account.
+    pub fn find_first(&self) -> AccountFindFirstQuery<'_> {
+        AccountFindFirstQuery { model: self, args: teon!({}), preloads: vec![] }
+    }
+}
+
+/// ## Account find first query
+///
+/// A typed builder of `AccountFindFirstArgs`.
+pub struct AccountFindFirstQuery<'a> {
+    model: &'a AccountModel,
+    args: Value,
+    preloads: Vec<&'static str>,
+}
+
+impl<'a> AccountFindFirstQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: AccountWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: AccountSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: AccountInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: AccountSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AccountWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Option<Account>> {
+        let result = self.model.find_first_object(self.args).await?;
+        for preload in self.preloads {
+            match preload {
+                _ => (),
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl AccountModel {
+    /// Build a typed count query on account.
+    pub fn count(&self) -> AccountCountQuery<'_> {
+        AccountCountQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Account count query
+///
+/// A typed builder of `AccountCountArgs`.
+pub struct AccountCountQuery<'a> {
+    model: &'a AccountModel,
+    args: Value,
+}
+
+impl<'a> AccountCountQuery<'a> {
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: AccountWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: AccountSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: AccountCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AccountWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<usize> {
+        self.model.count_objects(self.args).await
+    }
+}
+
+impl AccountModel {
+    /// Build a typed aggregate query on account.
+    pub fn aggregate_query(&self) -> AccountAggregateQuery<'_> {
+        AccountAggregateQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Account aggregate query
+///
+/// A typed builder of `AccountAggregateArgs`.
+pub struct AccountAggregateQuery<'a> {
+    model: &'a AccountModel,
+    args: Value,
+}
+
+impl<'a> AccountAggregateQuery<'a> {
+
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn avg(mut self, value: AccountAvgAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_avg".to_owned(), value.into());
+        self
+    }
+
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn count(mut self, value: AccountCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_count".to_owned(), value.into());
+        self
+    }
+
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn max(mut self, value: AccountMaxAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_max".to_owned(), value.into());
+        self
+    }
+
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn min(mut self, value: AccountMinAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_min".to_owned(), value.into());
+        self
+    }
+
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn sum(mut self, value: AccountSumAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_sum".to_owned(), value.into());
+        self
+    }
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: AccountWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: AccountSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AccountWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<AccountAggregateResult> {
+        self.model.aggregate(self.args).await
+    }
+}
+
+impl AccountModel {
+    /// Build a typed group by query on account.
+    pub fn group_by_query(&self) -> AccountGroupByQuery<'_> {
+        AccountGroupByQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Account group by query
+///
+/// A typed builder of `AccountGroupByArgs`.
+pub struct AccountGroupByQuery<'a> {
+    model: &'a AccountModel,
+    args: Value,
+}
+
+impl<'a> AccountGroupByQuery<'a> {
+
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn avg(mut self, value: AccountAvgAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_avg".to_owned(), value.into());
+        self
+    }
+
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn count(mut self, value: AccountCountAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_count".to_owned(), value.into());
+        self
+    }
+
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn max(mut self, value: AccountMaxAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_max".to_owned(), value.into());
+        self
+    }
+
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn min(mut self, value: AccountMinAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_min".to_owned(), value.into());
+        self
+    }
+
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn sum(mut self, value: AccountSumAggregateInputType) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("_sum".to_owned(), value.into());
+        self
+    }
+
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("by".to_owned(), value.into());
+        self
+    }
+
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn cursor(mut self, value: AccountWhereUniqueInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("cursor".to_owned(), value.into());
+        self
+    }
+
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn distinct(mut self, value: AccountSerializableScalarFields) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("distinct".to_owned(), value.into());
+        self
+    }
+
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn having(mut self, value: AccountScalarWhereWithAggregatesInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("having".to_owned(), value.into());
+        self
+    }
+
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn order_by(mut self, value: Value) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("orderBy".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_number(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), value.into());
+        self
+    }
+
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("pageSize".to_owned(), value.into());
+        self
+    }
+
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn skip(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("skip".to_owned(), value.into());
+        self
+    }
+
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn take(mut self, value: i64) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("take".to_owned(), value.into());
+        self
+    }
+
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn where_(mut self, value: AccountWhereInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("where".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Vec<AccountAggregateResult>> {
+        self.model.group_by(self.args).await
+    }
+}
+
+impl AccountModel {
+    /// Build a typed create query on account.
+    pub fn create(&self) -> AccountCreateQuery<'_> {
+        AccountCreateQuery { model: self, args: teon!({}) }
+    }
+}
+
+/// ## Account create query
+///
+/// A typed builder of `AccountCreateArgs`.
+pub struct AccountCreateQuery<'a> {
+    model: &'a AccountModel,
+    args: Value,
+}
+
+impl<'a> AccountCreateQuery<'a> {
+
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn create(mut self, value: AccountCreateInput) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("create".to_owned(), value.into());
+        self
+    }
+
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn include(mut self, value: AccountInclude) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("include".to_owned(), value.into());
+        self
+    }
+
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    pub fn select(mut self, value: AccountSelect) -> Self {
+        self.args.as_dictionary_mut().unwrap().insert("select".to_owned(), value.into());
+        self
+    }
+
+    /// Run this query.
+    pub async fn exec(self) -> Result<Account> {
+        self.model.create_object_from_args(self.args).await
+    }
+}
+
+impl AccountWhereInput {
+
+    /// Filter on id.
+    pub fn id() -> FieldFilter<Self, i32> {
+        FieldFilter::new("id")
+    }
+
+    /// Filter on name.
+    pub fn name() -> FieldFilter<Self, String> {
+        FieldFilter::new("name")
+    }
+
+    /// Filter on role.
+    pub fn role() -> FieldFilter<Self, admin::Role> {
+        FieldFilter::new("role")
+    }
+
+    /// Match both this and `other`.
+    pub fn and(self, other: Self) -> Result<Self> {
+        query::and(vec![self.into(), other.into()])
+    }
+
+    /// Match either this or `other`.
+    pub fn or(self, other: Self) -> Result<Self> {
+        query::or(vec![self.into(), other.into()])
+    }
+}
+
+impl From<Account> for model::Object {
+    fn from(value: Account) -> Self {
+        value.inner.clone()
+    }
+}
+
+impl From<model::Object> for Account {
+    fn from(value: model::Object) -> Self {
+        Self { inner: value, relations: RelationCache::default() }
+    }
+}
+
+impl From<Account> for Value {
+    fn from(value: Account) -> Self {
+        Value::ModelObject(value.inner.clone())
+    }
+}
+
+impl AsInterface for Account {
+    fn from_value(value: Value) -> Result<Self> {
+        let model_object: model::Object = value.try_into()?;
+        Ok(Self::from(model_object))
+    }
+}
+
+impl TryFrom<Value> for Account {
+
+    type Error = Error;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        let model_object: model::Object = value.try_into()?;
+        Ok(Self::from(model_object))
+    }
+}
+
+impl Debug for Account {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
+}
+
+impl Display for Account {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+
+impl ExtractFromPipelineCtx for Account {
+    fn extract(ctx: &pipeline::Ctx) -> Self {
+        Account::from(ctx.object().clone())
+    }
+}
+
+/// Serializes the loaded fields of this account. Model objects are bound to a
+/// connection, so deserialize into `AccountResult` instead.
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for Account {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut map = indexmap::IndexMap::new();
+        map.insert("id".to_owned(), self.inner.get::<Value, _>("id").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("name".to_owned(), self.inner.get::<Value, _>("name").map_err(<S::Error as serde::ser::Error>::custom)?);
+        map.insert("role".to_owned(), self.inner.get::<Value, _>("role").map_err(<S::Error as serde::ser::Error>::custom)?);
+        serialize_teon(&Value::Dictionary(map), serializer)
+    }
+}
+
+pub trait AccountAggregateArgsTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&AccountAvgAggregateInputType> {
+        Some(AccountAvgAggregateInputType::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<AccountAvgAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&AccountCountAggregateInputType> {
+        Some(AccountCountAggregateInputType::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<AccountCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&AccountMaxAggregateInputType> {
+        Some(AccountMaxAggregateInputType::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<AccountMaxAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&AccountMinAggregateInputType> {
+        Some(AccountMinAggregateInputType::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<AccountMinAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&AccountSumAggregateInputType> {
+        Some(AccountSumAggregateInputType::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<AccountSumAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&AccountWhereUniqueInput> {
+        Some(AccountWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<AccountWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&AccountSerializableScalarFields> {
+        Some(AccountSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<AccountSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountAggregateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountAggregateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountAggregateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountAggregateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountAggregateArgsTrait for AccountAggregateArgs { }
+
+impl AsInterface for AccountAggregateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountAggregateArgs> for Value {
+    fn from(value: AccountAggregateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountAggregateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountAggregateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountAggregateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountAggregateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountAggregateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountAggregateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountAggregateResultTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&AccountAvgAggregateResult> {
+        Some(AccountAvgAggregateResult::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<AccountAvgAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&AccountCountAggregateResult> {
+        Some(AccountCountAggregateResult::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<AccountCountAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&AccountMaxAggregateResult> {
+        Some(AccountMaxAggregateResult::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<AccountMaxAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&AccountMinAggregateResult> {
+        Some(AccountMinAggregateResult::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<AccountMinAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&AccountSumAggregateResult> {
+        Some(AccountSumAggregateResult::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<AccountSumAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountAggregateResultTrait for AccountAggregateResult { }
+
+impl AsInterface for AccountAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountAggregateResult> for Value {
+    fn from(value: AccountAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountArgsTrait for AccountArgs { }
+
+impl AsInterface for AccountArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountArgs> for Value {
+    fn from(value: AccountArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountAvgAggregateInputTypeTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountAvgAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountAvgAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountAvgAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountAvgAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountAvgAggregateInputTypeTrait for AccountAvgAggregateInputType { }
+
+impl AsInterface for AccountAvgAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountAvgAggregateInputType> for Value {
+    fn from(value: AccountAvgAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountAvgAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountAvgAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountAvgAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountAvgAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountAvgAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountAvgAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountAvgAggregateResultTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&f64> {
+        Some(f64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<f64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountAvgAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountAvgAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountAvgAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountAvgAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountAvgAggregateResultTrait for AccountAvgAggregateResult { }
+
+impl AsInterface for AccountAvgAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountAvgAggregateResult> for Value {
+    fn from(value: AccountAvgAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountAvgAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountAvgAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountAvgAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountAvgAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountAvgAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountAvgAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountConnectOrCreateInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &AccountCreateInput {
+        AccountCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: AccountCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereUniqueInput {
+        AccountWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountConnectOrCreateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountConnectOrCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountConnectOrCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountConnectOrCreateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountConnectOrCreateInputTrait for AccountConnectOrCreateInput { }
+
+impl AsInterface for AccountConnectOrCreateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountConnectOrCreateInput> for Value {
+    fn from(value: AccountConnectOrCreateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountConnectOrCreateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountConnectOrCreateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountConnectOrCreateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountConnectOrCreateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountConnectOrCreateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountConnectOrCreateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountCopyArgsTrait: Interface {
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn copy(&self) -> &AccountUpdateInput {
+        AccountUpdateInput::from_value_ref(self.inner().get("copy").unwrap()).unwrap()
+    }
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_copy(&mut self, new_value: AccountUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("copy".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereUniqueInput {
+        AccountWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountCopyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountCopyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountCopyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountCopyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountCopyArgsTrait for AccountCopyArgs { }
+
+impl AsInterface for AccountCopyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountCopyArgs> for Value {
+    fn from(value: AccountCopyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountCopyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountCopyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountCopyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountCopyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountCopyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountCopyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountCopyManyArgsTrait: Interface {
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn copy(&self) -> &AccountUpdateInput {
+        AccountUpdateInput::from_value_ref(self.inner().get("copy").unwrap()).unwrap()
+    }
+    /// ## Copy
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_copy(&mut self, new_value: AccountUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("copy".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereInput {
+        AccountWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountCopyManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountCopyManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountCopyManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountCopyManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountCopyManyArgsTrait for AccountCopyManyArgs { }
+
+impl AsInterface for AccountCopyManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountCopyManyArgs> for Value {
+    fn from(value: AccountCopyManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountCopyManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountCopyManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountCopyManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountCopyManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountCopyManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountCopyManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountCountAggregateInputTypeTrait: Interface {
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn all(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("_all")?).unwrap())
+    }
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_all(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_all".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_all");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountCountAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountCountAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountCountAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountCountAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountCountAggregateInputTypeTrait for AccountCountAggregateInputType { }
+
+impl AsInterface for AccountCountAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountCountAggregateInputType> for Value {
+    fn from(value: AccountCountAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountCountAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountCountAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountCountAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountCountAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountCountAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountCountAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountCountAggregateResultTrait: Interface {
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn all(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("_all")?).unwrap())
+    }
+    /// ## All
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_all(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_all".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_all");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountCountAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountCountAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountCountAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountCountAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountCountAggregateResultTrait for AccountCountAggregateResult { }
+
+impl AsInterface for AccountCountAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountCountAggregateResult> for Value {
+    fn from(value: AccountCountAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountCountAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountCountAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountCountAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountCountAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountCountAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountCountAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountCountArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&AccountWhereUniqueInput> {
+        Some(AccountWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<AccountWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&AccountSerializableScalarFields> {
+        Some(AccountSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<AccountSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountCountAggregateInputType> {
+        Some(AccountCountAggregateInputType::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountCountArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountCountArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountCountArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountCountArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountCountArgsTrait for AccountCountArgs { }
+
+impl AsInterface for AccountCountArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountCountArgs> for Value {
+    fn from(value: AccountCountArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountCountArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountCountArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountCountArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountCountArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountCountArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountCountArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountCreateArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &AccountCreateInput {
+        AccountCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: AccountCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountCreateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountCreateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountCreateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountCreateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountCreateArgsTrait for AccountCreateArgs { }
+
+impl AsInterface for AccountCreateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountCreateArgs> for Value {
+    fn from(value: AccountCreateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountCreateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountCreateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountCreateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountCreateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountCreateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountCreateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountCreateInputTrait: Interface {
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> &String {
+        String::from_value_ref(self.inner().get("name").unwrap()).unwrap()
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> &admin::Role {
+        admin::Role::from_value_ref(self.inner().get("role").unwrap()).unwrap()
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: admin::Role) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountCreateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountCreateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountCreateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountCreateInputTrait for AccountCreateInput { }
+
+impl AsInterface for AccountCreateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountCreateInput> for Value {
+    fn from(value: AccountCreateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountCreateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountCreateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountCreateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountCreateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountCreateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountCreateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountCreateManyArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &Value {
+        Value::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Value) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountCreateManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountCreateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountCreateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountCreateManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountCreateManyArgsTrait for AccountCreateManyArgs { }
+
+impl AsInterface for AccountCreateManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountCreateManyArgs> for Value {
+    fn from(value: AccountCreateManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountCreateManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountCreateManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountCreateManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountCreateManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountCreateManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountCreateManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountCreateNestedManyInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountCreateNestedManyInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountCreateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountCreateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountCreateNestedManyInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountCreateNestedManyInputTrait for AccountCreateNestedManyInput { }
+
+impl AsInterface for AccountCreateNestedManyInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountCreateNestedManyInput> for Value {
+    fn from(value: AccountCreateNestedManyInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountCreateNestedManyInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountCreateNestedManyInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountCreateNestedManyInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountCreateNestedManyInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountCreateNestedManyInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountCreateNestedManyInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountCreateNestedOneInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&AccountWhereUniqueInput> {
+        Some(AccountWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<AccountWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&AccountConnectOrCreateInput> {
+        Some(AccountConnectOrCreateInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<AccountConnectOrCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&AccountCreateInput> {
+        Some(AccountCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<AccountCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountCreateNestedOneInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountCreateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountCreateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountCreateNestedOneInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountCreateNestedOneInputTrait for AccountCreateNestedOneInput { }
+
+impl AsInterface for AccountCreateNestedOneInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountCreateNestedOneInput> for Value {
+    fn from(value: AccountCreateNestedOneInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountCreateNestedOneInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountCreateNestedOneInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountCreateNestedOneInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountCreateNestedOneInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountCreateNestedOneInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountCreateNestedOneInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountDeleteArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereUniqueInput {
+        AccountWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountDeleteArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountDeleteArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountDeleteArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountDeleteArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountDeleteArgsTrait for AccountDeleteArgs { }
+
+impl AsInterface for AccountDeleteArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountDeleteArgs> for Value {
+    fn from(value: AccountDeleteArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountDeleteArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountDeleteArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountDeleteArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountDeleteArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountDeleteArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountDeleteArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountDeleteManyArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereInput {
+        AccountWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountDeleteManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountDeleteManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountDeleteManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountDeleteManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountDeleteManyArgsTrait for AccountDeleteManyArgs { }
+
+impl AsInterface for AccountDeleteManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountDeleteManyArgs> for Value {
+    fn from(value: AccountDeleteManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountDeleteManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountDeleteManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountDeleteManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountDeleteManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountDeleteManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountDeleteManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountFindFirstArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&AccountWhereUniqueInput> {
+        Some(AccountWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<AccountWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&AccountSerializableScalarFields> {
+        Some(AccountSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<AccountSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountFindFirstArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountFindFirstArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountFindFirstArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountFindFirstArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountFindFirstArgsTrait for AccountFindFirstArgs { }
+
+impl AsInterface for AccountFindFirstArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountFindFirstArgs> for Value {
+    fn from(value: AccountFindFirstArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountFindFirstArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountFindFirstArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountFindFirstArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountFindFirstArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountFindFirstArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountFindFirstArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountFindManyArgsTrait: Interface {
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&AccountWhereUniqueInput> {
+        Some(AccountWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<AccountWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&AccountSerializableScalarFields> {
+        Some(AccountSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<AccountSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountFindManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountFindManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountFindManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountFindManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountFindManyArgsTrait for AccountFindManyArgs { }
+
+impl AsInterface for AccountFindManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountFindManyArgs> for Value {
+    fn from(value: AccountFindManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountFindManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountFindManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountFindManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountFindManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountFindManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountFindManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountFindUniqueArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereUniqueInput {
+        AccountWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountFindUniqueArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountFindUniqueArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountFindUniqueArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountFindUniqueArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountFindUniqueArgsTrait for AccountFindUniqueArgs { }
+
+impl AsInterface for AccountFindUniqueArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountFindUniqueArgs> for Value {
+    fn from(value: AccountFindUniqueArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountFindUniqueArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountFindUniqueArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountFindUniqueArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountFindUniqueArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountFindUniqueArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountFindUniqueArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountGroupByArgsTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&AccountAvgAggregateInputType> {
+        Some(AccountAvgAggregateInputType::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<AccountAvgAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&AccountCountAggregateInputType> {
+        Some(AccountCountAggregateInputType::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<AccountCountAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&AccountMaxAggregateInputType> {
+        Some(AccountMaxAggregateInputType::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<AccountMaxAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&AccountMinAggregateInputType> {
+        Some(AccountMinAggregateInputType::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<AccountMinAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&AccountSumAggregateInputType> {
+        Some(AccountSumAggregateInputType::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<AccountSumAggregateInputType>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn by(&self) -> &Value {
+        Value::from_value_ref(self.inner().get("by").unwrap()).unwrap()
+    }
+    /// ## By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_by(&mut self, new_value: Value) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("by".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn cursor(&self) -> Option<&AccountWhereUniqueInput> {
+        Some(AccountWhereUniqueInput::from_value_ref(self.inner().get("cursor")?).unwrap())
+    }
+    /// ## Cursor
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_cursor(&mut self, new_value: Option<AccountWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("cursor".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("cursor");
+            },
+        }
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn distinct(&self) -> Option<&AccountSerializableScalarFields> {
+        Some(AccountSerializableScalarFields::from_value_ref(self.inner().get("distinct")?).unwrap())
+    }
+    /// ## Distinct
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_distinct(&mut self, new_value: Option<AccountSerializableScalarFields>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("distinct".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("distinct");
+            },
+        }
+    }
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    fn having(&self) -> Option<&AccountScalarWhereWithAggregatesInput> {
+        Some(AccountScalarWhereWithAggregatesInput::from_value_ref(self.inner().get("having")?).unwrap())
+    }
+    /// ## Having
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_having(&mut self, new_value: Option<AccountScalarWhereWithAggregatesInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("having".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("having");
+            },
+        }
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn order_by(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("orderBy")?).unwrap())
+    }
+    /// ## Order By
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_order_by(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("orderBy".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("orderBy");
+            },
+        }
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_number(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageNumber")?).unwrap())
+    }
+    /// ## Page Number
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_number(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageNumber".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageNumber");
+            },
+        }
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn page_size(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("pageSize")?).unwrap())
+    }
+    /// ## Page Size
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_page_size(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("pageSize".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("pageSize");
+            },
+        }
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn skip(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("skip")?).unwrap())
+    }
+    /// ## Skip
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_skip(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("skip".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("skip");
+            },
+        }
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn take(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("take")?).unwrap())
+    }
+    /// ## Take
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_take(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("take".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("take");
+            },
+        }
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("where")?).unwrap())
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("where");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountGroupByArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountGroupByArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountGroupByArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountGroupByArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountGroupByArgsTrait for AccountGroupByArgs { }
+
+impl AsInterface for AccountGroupByArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountGroupByArgs> for Value {
+    fn from(value: AccountGroupByArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountGroupByArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountGroupByArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountGroupByArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountGroupByArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountGroupByArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountGroupByArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountGroupByResultTrait: Interface {
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn avg(&self) -> Option<&AccountAvgAggregateResult> {
+        Some(AccountAvgAggregateResult::from_value_ref(self.inner().get("_avg")?).unwrap())
+    }
+    /// ## Avg
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_avg(&mut self, new_value: Option<AccountAvgAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_avg".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_avg");
+            },
+        }
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn count(&self) -> Option<&AccountCountAggregateResult> {
+        Some(AccountCountAggregateResult::from_value_ref(self.inner().get("_count")?).unwrap())
+    }
+    /// ## Count
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_count(&mut self, new_value: Option<AccountCountAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_count".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_count");
+            },
+        }
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn max(&self) -> Option<&AccountMaxAggregateResult> {
+        Some(AccountMaxAggregateResult::from_value_ref(self.inner().get("_max")?).unwrap())
+    }
+    /// ## Max
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_max(&mut self, new_value: Option<AccountMaxAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_max".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_max");
+            },
+        }
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn min(&self) -> Option<&AccountMinAggregateResult> {
+        Some(AccountMinAggregateResult::from_value_ref(self.inner().get("_min")?).unwrap())
+    }
+    /// ## Min
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_min(&mut self, new_value: Option<AccountMinAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_min".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_min");
+            },
+        }
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn sum(&self) -> Option<&AccountSumAggregateResult> {
+        Some(AccountSumAggregateResult::from_value_ref(self.inner().get("_sum")?).unwrap())
+    }
+    /// ## Sum
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_sum(&mut self, new_value: Option<AccountSumAggregateResult>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("_sum".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("_sum");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&admin::Role> {
+        Some(admin::Role::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<admin::Role>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountGroupByResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountGroupByResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountGroupByResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountGroupByResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountGroupByResultTrait for AccountGroupByResult { }
+
+impl AsInterface for AccountGroupByResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountGroupByResult> for Value {
+    fn from(value: AccountGroupByResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountGroupByResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountGroupByResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountGroupByResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountGroupByResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountGroupByResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountGroupByResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountIncludeTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct AccountInclude {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountInclude {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountInclude {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountInclude {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountIncludeTrait for AccountInclude { }
+
+impl AsInterface for AccountInclude {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountInclude> for Value {
+    fn from(value: AccountInclude) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountInclude {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountInclude> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountInclude)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountInclude {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountInclude {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountInclude {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountListRelationFilterTrait: Interface {
+    /// ## Every
+    ///
+    /// This synthesized field doesn't have a description.
+    fn every(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("every")?).unwrap())
+    }
+    /// ## Every
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_every(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("every".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("every");
+            },
+        }
+    }
+    /// ## None
+    ///
+    /// This synthesized field doesn't have a description.
+    fn none(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("none")?).unwrap())
+    }
+    /// ## None
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_none(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("none".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("none");
+            },
+        }
+    }
+    /// ## Some
+    ///
+    /// This synthesized field doesn't have a description.
+    fn some(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("some")?).unwrap())
+    }
+    /// ## Some
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_some(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("some".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("some");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountListRelationFilter {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountListRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountListRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountListRelationFilter {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountListRelationFilterTrait for AccountListRelationFilter { }
+
+impl AsInterface for AccountListRelationFilter {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountListRelationFilter> for Value {
+    fn from(value: AccountListRelationFilter) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountListRelationFilter {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountListRelationFilter> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountListRelationFilter)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountListRelationFilter {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountListRelationFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountListRelationFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountMaxAggregateInputTypeTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountMaxAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountMaxAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountMaxAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountMaxAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountMaxAggregateInputTypeTrait for AccountMaxAggregateInputType { }
+
+impl AsInterface for AccountMaxAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountMaxAggregateInputType> for Value {
+    fn from(value: AccountMaxAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountMaxAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountMaxAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountMaxAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountMaxAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountMaxAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountMaxAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountMaxAggregateResultTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&admin::Role> {
+        Some(admin::Role::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<admin::Role>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountMaxAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountMaxAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountMaxAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountMaxAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountMaxAggregateResultTrait for AccountMaxAggregateResult { }
+
+impl AsInterface for AccountMaxAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountMaxAggregateResult> for Value {
+    fn from(value: AccountMaxAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountMaxAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountMaxAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountMaxAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountMaxAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountMaxAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountMaxAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountMinAggregateInputTypeTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountMinAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountMinAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountMinAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountMinAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountMinAggregateInputTypeTrait for AccountMinAggregateInputType { }
+
+impl AsInterface for AccountMinAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountMinAggregateInputType> for Value {
+    fn from(value: AccountMinAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountMinAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountMinAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountMinAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountMinAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountMinAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountMinAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountMinAggregateResultTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&admin::Role> {
+        Some(admin::Role::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<admin::Role>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountMinAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountMinAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountMinAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountMinAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountMinAggregateResultTrait for AccountMinAggregateResult { }
+
+impl AsInterface for AccountMinAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountMinAggregateResult> for Value {
+    fn from(value: AccountMinAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountMinAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountMinAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountMinAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountMinAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountMinAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountMinAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountOrderByInputTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&stdlib::Sort> {
+        Some(stdlib::Sort::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<stdlib::Sort>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountOrderByInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountOrderByInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountOrderByInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountOrderByInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountOrderByInputTrait for AccountOrderByInput { }
+
+impl AsInterface for AccountOrderByInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountOrderByInput> for Value {
+    fn from(value: AccountOrderByInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountOrderByInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountOrderByInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountOrderByInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountOrderByInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountOrderByInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountOrderByInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountRelationFilterTrait: Interface {
+    /// ## Is
+    ///
+    /// This synthesized field doesn't have a description.
+    fn is(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("is")?).unwrap())
+    }
+    /// ## Is
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_is(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("is".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("is");
+            },
+        }
+    }
+    /// ## Is Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn is_not(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("isNot")?).unwrap())
+    }
+    /// ## Is Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_is_not(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("isNot".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("isNot");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountRelationFilter {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountRelationFilter {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountRelationFilter {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountRelationFilterTrait for AccountRelationFilter { }
+
+impl AsInterface for AccountRelationFilter {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountRelationFilter> for Value {
+    fn from(value: AccountRelationFilter) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountRelationFilter {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountRelationFilter> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountRelationFilter)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountRelationFilter {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountRelationFilter {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountRelationFilter {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountResultTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("id").unwrap()).unwrap()
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> &String {
+        String::from_value_ref(self.inner().get("name").unwrap()).unwrap()
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: String) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> &admin::Role {
+        admin::Role::from_value_ref(self.inner().get("role").unwrap()).unwrap()
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: admin::Role) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountResultTrait for AccountResult { }
+
+impl AsInterface for AccountResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountResult> for Value {
+    fn from(value: AccountResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountScalarUpdateInputTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i32> {
+        Some(i32::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i32>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&admin::Role> {
+        Some(admin::Role::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<admin::Role>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountScalarUpdateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountScalarUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountScalarUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountScalarUpdateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountScalarUpdateInputTrait for AccountScalarUpdateInput { }
+
+impl AsInterface for AccountScalarUpdateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountScalarUpdateInput> for Value {
+    fn from(value: AccountScalarUpdateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountScalarUpdateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountScalarUpdateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountScalarUpdateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountScalarUpdateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountScalarUpdateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountScalarUpdateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountScalarWhereWithAggregatesInputTrait: Interface {
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn and(&self) -> Option<Vec<&AccountWhereInput>> {
+        Some(Vec::<&AccountWhereInput>::from_value_ref_vec(self.inner().get("AND")?).unwrap())
+    }
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_and(&mut self, new_value: Option<Vec<AccountWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("AND".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("AND");
+            },
+        }
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn not(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("NOT")?).unwrap())
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_not(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("NOT".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("NOT");
+            },
+        }
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn or(&self) -> Option<Vec<&AccountWhereInput>> {
+        Some(Vec::<&AccountWhereInput>::from_value_ref_vec(self.inner().get("OR")?).unwrap())
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_or(&mut self, new_value: Option<Vec<AccountWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("OR".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("OR");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountScalarWhereWithAggregatesInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountScalarWhereWithAggregatesInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountScalarWhereWithAggregatesInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountScalarWhereWithAggregatesInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountScalarWhereWithAggregatesInputTrait for AccountScalarWhereWithAggregatesInput { }
+
+impl AsInterface for AccountScalarWhereWithAggregatesInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountScalarWhereWithAggregatesInput> for Value {
+    fn from(value: AccountScalarWhereWithAggregatesInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountScalarWhereWithAggregatesInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountScalarWhereWithAggregatesInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountScalarWhereWithAggregatesInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountScalarWhereWithAggregatesInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountScalarWhereWithAggregatesInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountScalarWhereWithAggregatesInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountSelectTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountSelect {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountSelect {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountSelect {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountSelect {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountSelectTrait for AccountSelect { }
+
+impl AsInterface for AccountSelect {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountSelect> for Value {
+    fn from(value: AccountSelect) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountSelect {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountSelect> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountSelect)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountSelect {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountSelect {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountSelect {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountSignInArgsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct AccountSignInArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountSignInArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountSignInArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountSignInArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountSignInArgsTrait for AccountSignInArgs { }
+
+impl AsInterface for AccountSignInArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountSignInArgs> for Value {
+    fn from(value: AccountSignInArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountSignInArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountSignInArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountSignInArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountSignInArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountSignInArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountSignInArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountSignInCheckerCompanionsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct AccountSignInCheckerCompanions {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountSignInCheckerCompanions {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountSignInCheckerCompanions {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountSignInCheckerCompanions {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountSignInCheckerCompanionsTrait for AccountSignInCheckerCompanions { }
+
+impl AsInterface for AccountSignInCheckerCompanions {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountSignInCheckerCompanions> for Value {
+    fn from(value: AccountSignInCheckerCompanions) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountSignInCheckerCompanions {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountSignInCheckerCompanions> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountSignInCheckerCompanions)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountSignInCheckerCompanions {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountSignInCheckerCompanions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountSignInCheckerCompanions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountSignInCheckerIdsTrait: Interface {
+}
+
+#[repr(transparent)]
+pub struct AccountSignInCheckerIds {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountSignInCheckerIds {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountSignInCheckerIds {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountSignInCheckerIds {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountSignInCheckerIdsTrait for AccountSignInCheckerIds { }
+
+impl AsInterface for AccountSignInCheckerIds {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountSignInCheckerIds> for Value {
+    fn from(value: AccountSignInCheckerIds) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountSignInCheckerIds {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountSignInCheckerIds> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountSignInCheckerIds)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountSignInCheckerIds {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountSignInCheckerIds {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountSignInCheckerIds {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountSignInInputTrait: Interface {
+    /// ## Credentials
+    ///
+    /// This synthesized field doesn't have a description.
+    fn credentials(&self) -> &AccountSignInArgs {
+        AccountSignInArgs::from_value_ref(self.inner().get("credentials").unwrap()).unwrap()
+    }
+    /// ## Credentials
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_credentials(&mut self, new_value: AccountSignInArgs) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("credentials".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountSignInInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountSignInInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountSignInInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountSignInInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountSignInInputTrait for AccountSignInInput { }
+
+impl AsInterface for AccountSignInInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountSignInInput> for Value {
+    fn from(value: AccountSignInInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountSignInInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountSignInInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountSignInInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountSignInInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountSignInInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountSignInInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountSumAggregateInputTypeTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountSumAggregateInputType {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountSumAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountSumAggregateInputType {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountSumAggregateInputType {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountSumAggregateInputTypeTrait for AccountSumAggregateInputType { }
+
+impl AsInterface for AccountSumAggregateInputType {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountSumAggregateInputType> for Value {
+    fn from(value: AccountSumAggregateInputType) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountSumAggregateInputType {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountSumAggregateInputType> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountSumAggregateInputType)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountSumAggregateInputType {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountSumAggregateInputType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountSumAggregateInputType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountSumAggregateResultTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&i64> {
+        Some(i64::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<i64>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountSumAggregateResult {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountSumAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountSumAggregateResult {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountSumAggregateResult {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountSumAggregateResultTrait for AccountSumAggregateResult { }
+
+impl AsInterface for AccountSumAggregateResult {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountSumAggregateResult> for Value {
+    fn from(value: AccountSumAggregateResult) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountSumAggregateResult {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountSumAggregateResult> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountSumAggregateResult)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountSumAggregateResult {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountSumAggregateResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountSumAggregateResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountUpdateArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AccountUpdateInput {
+        AccountUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AccountUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereUniqueInput {
+        AccountWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountUpdateArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountUpdateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountUpdateArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountUpdateArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountUpdateArgsTrait for AccountUpdateArgs { }
+
+impl AsInterface for AccountUpdateArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountUpdateArgs> for Value {
+    fn from(value: AccountUpdateArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountUpdateArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountUpdateArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountUpdateArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountUpdateArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountUpdateArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountUpdateArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountUpdateInputTrait: Interface {
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&String> {
+        Some(String::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<String>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&admin::Role> {
+        Some(admin::Role::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<admin::Role>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountUpdateInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountUpdateInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountUpdateInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountUpdateInputTrait for AccountUpdateInput { }
+
+impl AsInterface for AccountUpdateInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountUpdateInput> for Value {
+    fn from(value: AccountUpdateInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountUpdateInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountUpdateInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountUpdateInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountUpdateInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountUpdateInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountUpdateInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountUpdateManyArgsTrait: Interface {
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AccountUpdateInput {
+        AccountUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AccountUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereInput {
+        AccountWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountUpdateManyArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountUpdateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountUpdateManyArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountUpdateManyArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountUpdateManyArgsTrait for AccountUpdateManyArgs { }
+
+impl AsInterface for AccountUpdateManyArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountUpdateManyArgs> for Value {
+    fn from(value: AccountUpdateManyArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountUpdateManyArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountUpdateManyArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountUpdateManyArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountUpdateManyArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountUpdateManyArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountUpdateManyArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountUpdateManyWithWhereInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AccountUpdateInput {
+        AccountUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AccountUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereInput {
+        AccountWhereInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountUpdateManyWithWhereInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountUpdateManyWithWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountUpdateManyWithWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountUpdateManyWithWhereInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountUpdateManyWithWhereInputTrait for AccountUpdateManyWithWhereInput { }
+
+impl AsInterface for AccountUpdateManyWithWhereInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountUpdateManyWithWhereInput> for Value {
+    fn from(value: AccountUpdateManyWithWhereInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountUpdateManyWithWhereInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountUpdateManyWithWhereInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountUpdateManyWithWhereInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountUpdateManyWithWhereInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountUpdateManyWithWhereInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountUpdateManyWithWhereInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountUpdateNestedManyInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("deleteMany")?).unwrap())
+    }
+    /// ## Delete Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("deleteMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("deleteMany");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update_many(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("updateMany")?).unwrap())
+    }
+    /// ## Update Many
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update_many(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("updateMany".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("updateMany");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountUpdateNestedManyInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountUpdateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountUpdateNestedManyInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountUpdateNestedManyInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountUpdateNestedManyInputTrait for AccountUpdateNestedManyInput { }
+
+impl AsInterface for AccountUpdateNestedManyInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountUpdateNestedManyInput> for Value {
+    fn from(value: AccountUpdateNestedManyInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountUpdateNestedManyInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountUpdateNestedManyInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountUpdateNestedManyInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountUpdateNestedManyInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountUpdateNestedManyInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountUpdateNestedManyInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountUpdateNestedOneInputTrait: Interface {
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect(&self) -> Option<&AccountWhereUniqueInput> {
+        Some(AccountWhereUniqueInput::from_value_ref(self.inner().get("connect")?).unwrap())
+    }
+    /// ## Connect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect(&mut self, new_value: Option<AccountWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connect");
+            },
+        }
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn connect_or_create(&self) -> Option<&AccountConnectOrCreateInput> {
+        Some(AccountConnectOrCreateInput::from_value_ref(self.inner().get("connectOrCreate")?).unwrap())
+    }
+    /// ## Connect Or Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_connect_or_create(&mut self, new_value: Option<AccountConnectOrCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("connectOrCreate".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("connectOrCreate");
+            },
+        }
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> Option<&AccountCreateInput> {
+        Some(AccountCreateInput::from_value_ref(self.inner().get("create")?).unwrap())
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: Option<AccountCreateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("create");
+            },
+        }
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn delete(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("delete")?).unwrap())
+    }
+    /// ## Delete
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_delete(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("delete".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("delete");
+            },
+        }
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn disconnect(&self) -> Option<&bool> {
+        Some(bool::from_value_ref(self.inner().get("disconnect")?).unwrap())
+    }
+    /// ## Disconnect
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_disconnect(&mut self, new_value: Option<bool>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("disconnect".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("disconnect");
+            },
+        }
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set(&self) -> Option<&AccountWhereUniqueInput> {
+        Some(AccountWhereUniqueInput::from_value_ref(self.inner().get("set")?).unwrap())
+    }
+    /// ## Set
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_set(&mut self, new_value: Option<AccountWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("set".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("set");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> Option<&AccountUpdateInput> {
+        Some(AccountUpdateInput::from_value_ref(self.inner().get("update")?).unwrap())
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: Option<AccountUpdateInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("update");
+            },
+        }
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn upsert(&self) -> Option<&AccountUpsertWithWhereUniqueInput> {
+        Some(AccountUpsertWithWhereUniqueInput::from_value_ref(self.inner().get("upsert")?).unwrap())
+    }
+    /// ## Upsert
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_upsert(&mut self, new_value: Option<AccountUpsertWithWhereUniqueInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("upsert".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("upsert");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountUpdateNestedOneInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountUpdateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountUpdateNestedOneInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountUpdateNestedOneInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountUpdateNestedOneInputTrait for AccountUpdateNestedOneInput { }
+
+impl AsInterface for AccountUpdateNestedOneInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountUpdateNestedOneInput> for Value {
+    fn from(value: AccountUpdateNestedOneInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountUpdateNestedOneInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountUpdateNestedOneInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountUpdateNestedOneInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountUpdateNestedOneInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountUpdateNestedOneInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountUpdateNestedOneInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountUpdateWithWhereUniqueInputTrait: Interface {
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AccountUpdateInput {
+        AccountUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AccountUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereUniqueInput {
+        AccountWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountUpdateWithWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountUpdateWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountUpdateWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountUpdateWithWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountUpdateWithWhereUniqueInputTrait for AccountUpdateWithWhereUniqueInput { }
+
+impl AsInterface for AccountUpdateWithWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountUpdateWithWhereUniqueInput> for Value {
+    fn from(value: AccountUpdateWithWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountUpdateWithWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountUpdateWithWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountUpdateWithWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountUpdateWithWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountUpdateWithWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountUpdateWithWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountUpsertArgsTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &AccountCreateInput {
+        AccountCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: AccountCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn include(&self) -> Option<&AccountInclude> {
+        Some(AccountInclude::from_value_ref(self.inner().get("include")?).unwrap())
+    }
+    /// ## Include
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_include(&mut self, new_value: Option<AccountInclude>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("include".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("include");
+            },
+        }
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn select(&self) -> Option<&AccountSelect> {
+        Some(AccountSelect::from_value_ref(self.inner().get("select")?).unwrap())
+    }
+    /// ## Select
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_select(&mut self, new_value: Option<AccountSelect>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("select".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("select");
+            },
+        }
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AccountUpdateInput {
+        AccountUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AccountUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereUniqueInput {
+        AccountWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountUpsertArgs {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountUpsertArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountUpsertArgs {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountUpsertArgs {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountUpsertArgsTrait for AccountUpsertArgs { }
+
+impl AsInterface for AccountUpsertArgs {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountUpsertArgs> for Value {
+    fn from(value: AccountUpsertArgs) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountUpsertArgs {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountUpsertArgs> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountUpsertArgs)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountUpsertArgs {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountUpsertArgs {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountUpsertArgs {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountUpsertWithWhereUniqueInputTrait: Interface {
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn create(&self) -> &AccountCreateInput {
+        AccountCreateInput::from_value_ref(self.inner().get("create").unwrap()).unwrap()
+    }
+    /// ## Create
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_create(&mut self, new_value: AccountCreateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("create".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn update(&self) -> &AccountUpdateInput {
+        AccountUpdateInput::from_value_ref(self.inner().get("update").unwrap()).unwrap()
+    }
+    /// ## Update
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_update(&mut self, new_value: AccountUpdateInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("update".to_owned(), new_value.into()).unwrap();
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn r#where(&self) -> &AccountWhereUniqueInput {
+        AccountWhereUniqueInput::from_value_ref(self.inner().get("where").unwrap()).unwrap()
+    }
+    /// ## Where
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_where(&mut self, new_value: AccountWhereUniqueInput) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("where".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountUpsertWithWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountUpsertWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountUpsertWithWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountUpsertWithWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountUpsertWithWhereUniqueInputTrait for AccountUpsertWithWhereUniqueInput { }
+
+impl AsInterface for AccountUpsertWithWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountUpsertWithWhereUniqueInput> for Value {
+    fn from(value: AccountUpsertWithWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountUpsertWithWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountUpsertWithWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountUpsertWithWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountUpsertWithWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountUpsertWithWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountUpsertWithWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountWhereInputTrait: Interface {
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn and(&self) -> Option<Vec<&AccountWhereInput>> {
+        Some(Vec::<&AccountWhereInput>::from_value_ref_vec(self.inner().get("AND")?).unwrap())
+    }
+    /// ## And
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_and(&mut self, new_value: Option<Vec<AccountWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("AND".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("AND");
+            },
+        }
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn not(&self) -> Option<&AccountWhereInput> {
+        Some(AccountWhereInput::from_value_ref(self.inner().get("NOT")?).unwrap())
+    }
+    /// ## Not
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_not(&mut self, new_value: Option<AccountWhereInput>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("NOT".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("NOT");
+            },
+        }
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn or(&self) -> Option<Vec<&AccountWhereInput>> {
+        Some(Vec::<&AccountWhereInput>::from_value_ref_vec(self.inner().get("OR")?).unwrap())
+    }
+    /// ## Or
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_or(&mut self, new_value: Option<Vec<AccountWhereInput>>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("OR".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("OR");
+            },
+        }
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("id")?).unwrap())
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("id");
+            },
+        }
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn name(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("name")?).unwrap())
+    }
+    /// ## Name
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_name(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("name".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("name");
+            },
+        }
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn role(&self) -> Option<&Value> {
+        Some(Value::from_value_ref(self.inner().get("role")?).unwrap())
+    }
+    /// ## Role
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_role(&mut self, new_value: Option<Value>) {
+    
+        match new_value {
+            Some(new_value) => {
+                self.inner_mut().as_dictionary_mut().unwrap().insert("role".to_owned(), new_value.into());
+            },
+            None => {
+                self.inner_mut().as_dictionary_mut().unwrap().shift_remove("role");
+            },
+        }
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountWhereInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountWhereInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountWhereInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountWhereInputTrait for AccountWhereInput { }
+
+impl AsInterface for AccountWhereInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountWhereInput> for Value {
+    fn from(value: AccountWhereInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountWhereInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountWhereInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountWhereInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountWhereInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountWhereInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountWhereInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub trait AccountWhereUniqueInputTrait: Interface {
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn id(&self) -> &i32 {
+        i32::from_value_ref(self.inner().get("id").unwrap()).unwrap()
+    }
+    /// ## Id
+    ///
+    /// This synthesized field doesn't have a description.
+    fn set_id(&mut self, new_value: i32) {
+    self.inner_mut().as_dictionary_mut().unwrap().insert("id".to_owned(), new_value.into()).unwrap();
+    }
+}
+
+#[repr(transparent)]
+pub struct AccountWhereUniqueInput {
+    inner: Value,
+    phantom_data: PhantomData<()>,
+}
+
+impl Borrow<Value> for AccountWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Borrow<Value> for &AccountWhereUniqueInput {
+    fn borrow(&self) -> &Value {
+        self.inner()
+    }
+}
+
+impl Interface for AccountWhereUniqueInput {
+    fn inner(&self) -> &Value {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Value {
+        &mut self.inner
+    }
+}
+
+impl AccountWhereUniqueInputTrait for AccountWhereUniqueInput { }
+
+impl AsInterface for AccountWhereUniqueInput {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(Self { inner: value, phantom_data: PhantomData::default() })
+    }
+}
+
+impl From<AccountWhereUniqueInput> for Value {
+    fn from(value: AccountWhereUniqueInput) -> Self {
+        value.inner
+    }
+}
+
+impl AsInterfaceRef for AccountWhereUniqueInput {
+
+    fn from_value_ref(value: &Value) -> Result<&AccountWhereUniqueInput> {
+        Ok(unsafe {
+            &*(value as *const Value as *const AccountWhereUniqueInput)
+        })
+    }
+}
+
+impl ExtractFromRequest for AccountWhereUniqueInput {
+    fn extract(request: &Request) -> Self {
+        Self::from_value(request.body_value().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl serde::Serialize for AccountWhereUniqueInput {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> where S: serde::Serializer {
+        serialize_teon(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "teo-serde")]
+impl<'de> serde::Deserialize<'de> for AccountWhereUniqueInput {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        Ok(Self { inner: deserialize_teon(deserializer)?, phantom_data: PhantomData::default() })
+    }
+}
+
+pub struct Teo {
+    pub(crate) ctx: transaction::Ctx,
+}
+
+impl From<transaction::Ctx> for Teo {
+    fn from(value: transaction::Ctx) -> Self {
+        Self { ctx: value }
+    }
+}
+
+impl Teo {
+
+    pub async fn transaction<F, C, Fut, R>(&self, f: F) -> Result<R> where
+        F: Fn(C) -> Fut,
+        C: for <'a> From<&'a transaction::Ctx>,
+        Fut: Future<Output = Result<R>> {
+        Ok(self.ctx.run_transaction(f).await?)
+    }
+    
+    pub fn account(&self) -> AccountModel {
+        AccountModel { ctx: self.ctx.model_ctx_for_model_at_path(&vec!["Account".to_owned()]).unwrap() }
+    }
+    pub fn admin(&self) -> AdminNamespace {
+        AdminNamespace { ctx: self.ctx.clone() }
+    }
+}
+
+
+impl ExtractFromTransactionCtx for Teo {
+    fn extract(ctx: &transaction::Ctx) -> Self {
+        Teo {
+            ctx: ctx.clone(),
+        }
+    }
+}
+
+impl ExtractFromRequest for Teo {
+    fn extract(request: &Request) -> Self {
+        Teo {
+            ctx: request.transaction_ctx().clone(),
+        }
+    }
+}
+
+impl ExtractFromPipelineCtx for Teo {
+    fn extract(ctx: &pipeline::Ctx) -> Self {
+        Teo {
+            ctx: ctx.transaction_ctx().clone(),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use teo::prelude::{
+    teon, model, Model, Value, Result, Error, transaction, Request, ExtractFromRequest, ExtractFromPipelineCtx, request, pipeline, ExtractFromTransactionCtx, File, Arguments,
+};
+use std::marker::PhantomData;
+use super::super::helpers::interface::{Interface, AsInterface, AsInterfaceRef, AsInterfaceVecRef};
+use super::super::helpers::query::{self, FieldFilter};
+use super::super::helpers::relations::{self, RelationCache};
+#[cfg(feature = "teo-serde")]
+use super::super::helpers::teon_serde::{serialize_teon, deserialize_teon};
+
+
+
+
+
+pub struct BcryptNamespace {
+    pub(crate) ctx: transaction::Ctx,
+}
+
+impl From<transaction::Ctx> for BcryptNamespace {
+    fn from(value: transaction::Ctx) -> Self {
+        Self { ctx: value }
+    }
+}
+
+impl BcryptNamespace {
+
+    
+}
+